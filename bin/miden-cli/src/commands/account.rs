@@ -1,8 +1,11 @@
-use clap::Parser;
+use std::{fs, path::PathBuf};
+
+use clap::{Parser, ValueEnum};
 use miden_client::{
-    accounts::{AccountId, AccountType, StorageSlot},
+    accounts::{AccountData, AccountId, AccountType, StorageSlot, UnlockStrategy},
     assets::Asset,
     crypto::FeltRng,
+    utils::Deserializable,
     Client, ZERO,
 };
 
@@ -25,6 +28,9 @@ pub struct AccountCmd {
     /// Show details of the account for the specified ID or hex prefix
     #[clap(short, long, group = "action", value_name = "ID")]
     show: Option<String>,
+    /// Print the shown account as a JSON report with its full contents instead of tables
+    #[clap(long, requires = "show")]
+    json: bool,
     /// Manages default account for transaction execution
     ///
     /// If no ID is provided it will display the current default account ID.
@@ -32,6 +38,27 @@ pub struct AccountCmd {
     /// it will set the default account to the provided ID
     #[clap(short, long, group = "action", value_name = "ID")]
     default: Option<Option<String>>,
+    /// Diagnose and unlock the locked account for the specified ID or hex prefix
+    ///
+    /// Without a strategy, only the diagnosis of the divergence is displayed.
+    #[clap(short, long, group = "action", value_name = "ID")]
+    unlock: Option<String>,
+    /// Strategy used to unlock the account
+    #[clap(long, value_enum, requires = "unlock")]
+    strategy: Option<UnlockStrategyArg>,
+    /// Path to the exported account file to import with the `import-state` strategy
+    #[clap(long, value_name = "PATH", requires = "strategy")]
+    account_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum UnlockStrategyArg {
+    /// Overwrite the local state with the one stored by the node (public accounts only)
+    AdoptRemote,
+    /// Overwrite the local state with the one in the provided account file
+    ImportState,
+    /// Keep the local state and accept the divergence
+    ForceKeepLocal,
 }
 
 impl AccountCmd {
@@ -40,15 +67,24 @@ impl AccountCmd {
             AccountCmd {
                 list: false,
                 show: Some(id),
+                json,
                 default: None,
+                unlock: None,
+                ..
             } => {
                 let account_id = parse_account_id(&client, id).await?;
-                show_account(client, account_id).await?;
+                if *json {
+                    print_account_report(client, account_id).await?;
+                } else {
+                    show_account(client, account_id).await?;
+                }
             },
             AccountCmd {
                 list: false,
                 show: None,
                 default: Some(id),
+                unlock: None,
+                ..
             } => {
                 match id {
                     None => {
@@ -78,6 +114,17 @@ impl AccountCmd {
                     },
                 }
             },
+            AccountCmd {
+                list: false,
+                show: None,
+                default: None,
+                unlock: Some(id),
+                strategy,
+                account_file,
+            } => {
+                let account_id = parse_account_id(&client, id).await?;
+                unlock_account(client, account_id, *strategy, account_file.as_ref()).await?;
+            },
             _ => {
                 list_accounts(client).await?;
             },
@@ -86,6 +133,62 @@ impl AccountCmd {
     }
 }
 
+// UNLOCK ACCOUNT
+// ================================================================================================
+
+async fn unlock_account<R: FeltRng>(
+    mut client: Client<R>,
+    account_id: AccountId,
+    strategy: Option<UnlockStrategyArg>,
+    account_file: Option<&PathBuf>,
+) -> Result<(), String> {
+    let diagnosis = client.diagnose_locked_account(account_id).await?;
+
+    let mut table = create_dynamic_table(&[
+        "Account ID",
+        "Locked",
+        "Local Hash",
+        "Remote Hash",
+        "Pending Transactions",
+    ]);
+    table.add_row(vec![
+        diagnosis.account_id.to_string(),
+        diagnosis.status.is_locked().to_string(),
+        diagnosis.local_hash.to_string(),
+        diagnosis.remote_hash.to_string(),
+        diagnosis.pending_transactions.len().to_string(),
+    ]);
+    println!("{table}");
+
+    if diagnosis.explained_by_pending_transactions {
+        println!(
+            "\nThe divergence is explained by pending transactions and will be resolved once \
+            they are committed."
+        );
+    }
+
+    let strategy = match (strategy, account_file) {
+        (None, _) => return Ok(()),
+        (Some(UnlockStrategyArg::AdoptRemote), _) => UnlockStrategy::AdoptRemote,
+        (Some(UnlockStrategyArg::ForceKeepLocal), _) => UnlockStrategy::ForceKeepLocal,
+        (Some(UnlockStrategyArg::ImportState), Some(account_file)) => {
+            let account_data_file_contents =
+                fs::read(account_file).map_err(|err| err.to_string())?;
+            let account_data = AccountData::read_from_bytes(&account_data_file_contents)
+                .map_err(|err| err.to_string())?;
+            UnlockStrategy::ImportState(account_data)
+        },
+        (Some(UnlockStrategyArg::ImportState), None) => {
+            return Err("The import-state strategy requires an --account-file".to_string());
+        },
+    };
+
+    client.unlock_account(account_id, strategy).await?;
+    println!("\nAccount {account_id} unlocked.");
+
+    Ok(())
+}
+
 // LIST ACCOUNTS
 // ================================================================================================
 
@@ -134,6 +237,13 @@ pub async fn show_account<R: FeltRng>(
     ]);
     println!("{table}\n");
 
+    if client.get_account_lock_status(account_id).await?.is_locked() {
+        println!(
+            "Warning: the account is locked because its state diverged from the node's. Run \
+            `{CLIENT_BINARY_NAME} account --unlock {account_id}` to diagnose it.\n"
+        );
+    }
+
     // Vault Table
     {
         let assets = account.vault().assets();
@@ -196,6 +306,18 @@ pub async fn show_account<R: FeltRng>(
     Ok(())
 }
 
+async fn print_account_report<R: FeltRng>(
+    client: Client<R>,
+    account_id: AccountId,
+) -> Result<(), String> {
+    let report = client.inspect_account(account_id).await?;
+    let report = serde_json::to_string_pretty(&report)
+        .map_err(|err| format!("Failed to serialize the account report: {err}"))?;
+    println!("{report}");
+
+    Ok(())
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -0,0 +1,865 @@
+use std::{collections::BTreeMap, net::SocketAddr, time::Duration};
+
+use clap::{Parser, ValueEnum};
+use miden_client::{
+    accounts::{AccountId, AccountReport, AccountTypeReport, AssetReport},
+    crypto::FeltRng,
+    notes::{NoteFile, NoteMetadata, NoteType as MidenNoteType},
+    store::{InputNoteRecord, NoteFilter as ClientNoteFilter, OutputNoteRecord},
+    sync::SyncSummary,
+    transactions::{PaymentTransactionData, TransactionRequest, TransactionResult},
+    utils::{Deserializable, Serializable},
+    Client, ClientError,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    time::timeout,
+};
+use tracing::{info, warn};
+
+use super::{export::ExportType, notes::NoteFilter};
+use crate::{
+    config::DaemonConfig,
+    utils::{get_input_acc_id_by_prefix_or_default, load_faucet_details_map, parse_account_id},
+};
+
+/// Maximum size of the body of a request. Imported note files are the largest payloads.
+const MAX_REQUEST_BODY_SIZE: usize = 1024 * 1024;
+
+/// Maximum size of the request line and headers of a request.
+const MAX_REQUEST_HEAD_SIZE: usize = 16 * 1024;
+
+/// Time a connection has to send its whole request before it's dropped. Requests are served one
+/// at a time, so a stalled connection would block every other caller.
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+// JSON-RPC error codes, as defined by the JSON-RPC 2.0 specification.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Error code of the requests that were valid but failed in the client.
+const CLIENT_ERROR: i64 = -32000;
+/// Error code of the requests without a valid auth token.
+const UNAUTHORIZED: i64 = -32001;
+
+// DAEMON COMMAND
+// ================================================================================================
+
+#[derive(Debug, Parser, Clone)]
+/// Serve the client over a JSON-RPC 2.0 interface on HTTP, so it can be driven by applications
+/// written in other languages.
+///
+/// Requests are sent as `POST /` with an `Authorization: Bearer <token>` header, where the token
+/// is the `daemon.auth_token` set in the config file. Requests are served one at a time.
+pub struct DaemonCmd {
+    /// Address to listen on, instead of the `daemon.bind_address` set in the config file
+    #[clap(long)]
+    bind: Option<SocketAddr>,
+    /// Allow listening on an address other than a loopback one, which exposes the client to the
+    /// network
+    #[clap(long, default_value_t = false)]
+    allow_remote: bool,
+}
+
+impl DaemonCmd {
+    pub async fn execute(
+        &self,
+        client: Client<impl FeltRng>,
+        config: &DaemonConfig,
+    ) -> Result<(), String> {
+        let auth_token = config.auth_token.clone().filter(|token| !token.is_empty()).ok_or(
+            "Set `auth_token` in the `daemon` section of the config file to start the daemon",
+        )?;
+
+        let bind_address = self.bind.unwrap_or(config.bind_address);
+        if !bind_address.ip().is_loopback() && !self.allow_remote {
+            return Err(format!(
+                "{bind_address} is not a loopback address. Use --allow-remote to listen on it"
+            ));
+        }
+
+        let listener = TcpListener::bind(bind_address)
+            .await
+            .map_err(|err| format!("Failed to listen on {bind_address}: {err}"))?;
+        println!("Listening on {bind_address}");
+
+        let mut daemon = Daemon {
+            client,
+            auth_token,
+            pending_transactions: BTreeMap::new(),
+        };
+
+        // Connections are served one after the other, which makes every call single-flight: the
+        // client is never used by two requests at the same time.
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(err) => {
+                    warn!("Failed to accept connection: {err}");
+                    continue;
+                },
+            };
+
+            if let Err(err) = daemon.serve_connection(stream).await {
+                warn!("Failed to serve connection from {peer}: {err}");
+            }
+        }
+    }
+}
+
+// DAEMON
+// ================================================================================================
+
+struct Daemon<R: FeltRng> {
+    client: Client<R>,
+    auth_token: String,
+    /// Transactions built by the `tx.mint`, `tx.send` and `tx.consume` methods, waiting to be
+    /// submitted or discarded, by transaction ID.
+    pending_transactions: BTreeMap<String, TransactionResult>,
+}
+
+impl<R: FeltRng> Daemon<R> {
+    async fn serve_connection(&mut self, mut stream: TcpStream) -> Result<(), String> {
+        let request = match timeout(REQUEST_READ_TIMEOUT, read_http_request(&mut stream)).await {
+            Ok(request) => request,
+            Err(_) => Err(HttpError::new(408, "Request Timeout")),
+        };
+
+        let (status, body) = match request {
+            Ok(request) if !self.is_authorized(&request) => (
+                401,
+                error_response(
+                    Value::Null,
+                    RpcError::new(UNAUTHORIZED, "Missing or invalid auth token"),
+                ),
+            ),
+            Ok(request) => (200, self.handle_body(&request.body).await),
+            Err(HttpError { status, reason }) => {
+                (status, error_response(Value::Null, RpcError::new(INVALID_REQUEST, reason)))
+            },
+        };
+
+        write_http_response(&mut stream, status, &body)
+            .await
+            .map_err(|err| err.to_string())
+    }
+
+    fn is_authorized(&self, request: &HttpRequest) -> bool {
+        request.bearer_token.as_deref().is_some_and(|token| {
+            // Compare every byte, so the time taken doesn't tell how much of the token matched
+            token.len() == self.auth_token.len()
+                && token
+                    .bytes()
+                    .zip(self.auth_token.bytes())
+                    .fold(0, |diff, (a, b)| diff | (a ^ b))
+                    == 0
+        })
+    }
+
+    /// Handles the JSON-RPC request in `body` and returns the serialized response.
+    async fn handle_body(&mut self, body: &[u8]) -> Value {
+        let request: Value = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(err) => {
+                return error_response(
+                    Value::Null,
+                    RpcError::new(PARSE_ERROR, format!("Invalid JSON: {err}")),
+                )
+            },
+        };
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+
+        let request: RpcRequest = match serde_json::from_value(request) {
+            Ok(request) if request.jsonrpc == "2.0" => request,
+            Ok(_) => {
+                return error_response(
+                    id,
+                    RpcError::new(INVALID_REQUEST, "`jsonrpc` must be \"2.0\""),
+                )
+            },
+            Err(err) => return error_response(id, RpcError::new(INVALID_REQUEST, err.to_string())),
+        };
+
+        info!("Serving `{}` request", request.method);
+        match self.dispatch(&request.method, request.params).await {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(err) => error_response(id, err),
+        }
+    }
+
+    async fn dispatch(&mut self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "sync" => self.sync().await,
+            "accounts.list" => self.list_accounts().await,
+            "accounts.show" => self.show_account(parse_params(params)?).await,
+            "notes.list" => self.list_notes(parse_params(params)?).await,
+            "notes.show" => self.show_note(parse_params(params)?).await,
+            "notes.import" => self.import_note(parse_params(params)?).await,
+            "notes.export" => self.export_note(parse_params(params)?).await,
+            "tx.mint" => self.build_mint(parse_params(params)?).await,
+            "tx.send" => self.build_send(parse_params(params)?).await,
+            "tx.consume" => self.build_consume(parse_params(params)?).await,
+            "tx.submit" => self.submit_transaction(parse_params(params)?).await,
+            "tx.discard" => self.discard_transaction(parse_params(params)?),
+            _ => Err(RpcError::new(METHOD_NOT_FOUND, format!("Unknown method `{method}`"))),
+        }
+    }
+
+    // METHODS
+    // --------------------------------------------------------------------------------------------
+
+    async fn sync(&mut self) -> Result<Value, RpcError> {
+        let summary = self.client.sync_state().await?;
+        to_result(SyncSummaryView::from(summary))
+    }
+
+    async fn list_accounts(&self) -> Result<Value, RpcError> {
+        let accounts: Vec<AccountHeaderView> = self
+            .client
+            .get_account_headers()
+            .await?
+            .into_iter()
+            .map(|(header, seed)| AccountHeaderView {
+                id: header.id().to_hex(),
+                account_type: header.id().account_type().into(),
+                storage_mode: header.id().storage_mode().to_string(),
+                nonce: header.nonce().as_int(),
+                hash: header.hash().to_hex(),
+                is_new: seed.is_some(),
+            })
+            .collect();
+
+        to_result(accounts)
+    }
+
+    async fn show_account(&self, params: AccountParams) -> Result<Value, RpcError> {
+        let account_id = parse_account_id(&self.client, &params.account_id)
+            .await
+            .map_err(invalid_params)?;
+        let report: AccountReport = self.client.inspect_account(account_id).await?;
+        to_result(report)
+    }
+
+    async fn list_notes(&self, params: ListNotesParams) -> Result<Value, RpcError> {
+        let filter = NoteFilter::from_str(&params.filter, true)
+            .map_err(|_| invalid_params(format!("Unknown note filter `{}`", params.filter)))?;
+
+        let notes: Vec<NoteView> = match filter {
+            NoteFilter::Consumable => {
+                let account_id = match &params.account_id {
+                    Some(account_id) => Some(
+                        parse_account_id(&self.client, account_id).await.map_err(invalid_params)?,
+                    ),
+                    None => None,
+                };
+                self.client
+                    .get_consumable_notes(account_id, params.include_processing)
+                    .await?
+                    .iter()
+                    .map(|(note, _)| NoteView::from(note))
+                    .collect()
+            },
+            filter => {
+                let filter: ClientNoteFilter = filter.try_into().map_err(invalid_params)?;
+                self.client.get_input_notes(filter).await?.iter().map(NoteView::from).collect()
+            },
+        };
+
+        to_result(notes)
+    }
+
+    async fn show_note(&self, params: NoteParams) -> Result<Value, RpcError> {
+        let note =
+            self.client.find_note_by_prefix(&params.note_id).await.map_err(invalid_params)?;
+
+        to_result(json!({
+            "input_note": note.input_note.as_ref().map(NoteView::from),
+            "output_note": note.output_note.as_ref().map(NoteView::from),
+        }))
+    }
+
+    async fn import_note(&mut self, params: ImportNoteParams) -> Result<Value, RpcError> {
+        let bytes = decode_hex(&params.note_file).map_err(invalid_params)?;
+        let note_file = NoteFile::read_from_bytes(&bytes)
+            .map_err(|err| invalid_params(format!("Invalid note file: {err}")))?;
+
+        let note_id = self.client.import_note(note_file).await?;
+        to_result(json!({ "note_id": note_id.to_hex() }))
+    }
+
+    async fn export_note(&self, params: ExportNoteParams) -> Result<Value, RpcError> {
+        let export_type = ExportType::from_str(&params.export_type, true)
+            .map_err(|_| invalid_params(format!("Unknown export type `{}`", params.export_type)))?;
+
+        let output_note = self
+            .client
+            .find_note_by_prefix(&params.note_id)
+            .await
+            .map_err(invalid_params)?
+            .output_note
+            .ok_or_else(|| {
+                invalid_params(format!("Note {} is not an output note", params.note_id))
+            })?;
+        let note_file = output_note
+            .into_note_file(export_type.into())
+            .map_err(|err| RpcError::new(CLIENT_ERROR, err.to_string()))?;
+
+        to_result(json!({ "note_file": encode_hex(&note_file.to_bytes()) }))
+    }
+
+    async fn build_mint(&mut self, params: MintParams) -> Result<Value, RpcError> {
+        let fungible_asset = load_faucet_details_map()
+            .and_then(|map| map.parse_fungible_asset(&params.asset))
+            .map_err(invalid_params)?;
+        let target_account_id = parse_account_id(&self.client, &params.target_account_id)
+            .await
+            .map_err(invalid_params)?;
+
+        let transaction_request = TransactionRequest::mint_fungible_asset(
+            fungible_asset,
+            target_account_id,
+            params.note_type.into(),
+            self.client.rng(),
+        )
+        .map_err(invalid_params)?;
+
+        self.build_transaction(fungible_asset.faucet_id(), transaction_request).await
+    }
+
+    async fn build_send(&mut self, params: SendParams) -> Result<Value, RpcError> {
+        let fungible_asset = load_faucet_details_map()
+            .and_then(|map| map.parse_fungible_asset(&params.asset))
+            .map_err(invalid_params)?;
+        let sender_account_id =
+            get_input_acc_id_by_prefix_or_default(&self.client, params.sender_account_id)
+                .await
+                .map_err(invalid_params)?;
+        let target_account_id = parse_account_id(&self.client, &params.target_account_id)
+            .await
+            .map_err(invalid_params)?;
+
+        let payment_transaction = PaymentTransactionData::new(
+            vec![fungible_asset.into()],
+            sender_account_id,
+            target_account_id,
+        );
+        let transaction_request = TransactionRequest::pay_to_id(
+            payment_transaction,
+            params.recall_height,
+            params.note_type.into(),
+            self.client.rng(),
+        )
+        .map_err(invalid_params)?;
+
+        self.build_transaction(sender_account_id, transaction_request).await
+    }
+
+    async fn build_consume(&mut self, params: ConsumeParams) -> Result<Value, RpcError> {
+        let account_id = get_input_acc_id_by_prefix_or_default(&self.client, params.account_id)
+            .await
+            .map_err(invalid_params)?;
+
+        let mut note_ids = Vec::new();
+        for note_id in &params.note_ids {
+            let note_record = self
+                .client
+                .find_note_by_prefix(note_id)
+                .await
+                .map_err(invalid_params)?
+                .input_note
+                .ok_or_else(|| invalid_params(format!("Note {note_id} is not an input note")))?;
+            note_ids.push(note_record.id());
+        }
+
+        if note_ids.is_empty() {
+            let consumable_notes =
+                self.client.get_consumable_notes(Some(account_id), false).await?;
+            note_ids.extend(consumable_notes.iter().map(|(note, _)| note.id()));
+        }
+
+        if note_ids.is_empty() {
+            return Err(invalid_params(format!(
+                "No note IDs were provided and there are no notes consumable by {account_id}"
+            )));
+        }
+
+        let transaction_request = TransactionRequest::consume_notes(note_ids);
+        self.build_transaction(account_id, transaction_request).await
+    }
+
+    /// Executes the transaction and keeps its result until it's submitted or discarded.
+    async fn build_transaction(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
+    ) -> Result<Value, RpcError> {
+        let transaction_result =
+            self.client.new_transaction(account_id, transaction_request).await?;
+
+        let view = TransactionView::from(&transaction_result);
+        self.pending_transactions
+            .insert(view.transaction_id.clone(), transaction_result);
+
+        to_result(view)
+    }
+
+    async fn submit_transaction(&mut self, params: TransactionParams) -> Result<Value, RpcError> {
+        let transaction_result =
+            self.pending_transactions.remove(&params.transaction_id).ok_or_else(|| {
+                invalid_params(format!(
+                    "Transaction {} was not built or was already submitted",
+                    params.transaction_id
+                ))
+            })?;
+
+        self.client.submit_transaction(transaction_result).await?;
+        to_result(json!({ "transaction_id": params.transaction_id }))
+    }
+
+    fn discard_transaction(&mut self, params: TransactionParams) -> Result<Value, RpcError> {
+        self.pending_transactions.remove(&params.transaction_id).ok_or_else(|| {
+            invalid_params(format!("Transaction {} is not pending", params.transaction_id))
+        })?;
+
+        to_result(json!({ "transaction_id": params.transaction_id }))
+    }
+}
+
+// PARAMS
+// ================================================================================================
+
+#[derive(Deserialize)]
+struct RpcRequest {
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AccountParams {
+    account_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ListNotesParams {
+    #[serde(default = "default_note_filter")]
+    filter: String,
+    /// Account whose consumable notes are listed, for the `consumable` filter.
+    account_id: Option<String>,
+    #[serde(default)]
+    include_processing: bool,
+}
+
+fn default_note_filter() -> String {
+    "all".to_string()
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NoteParams {
+    note_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ImportNoteParams {
+    /// Hex encoded note file, as returned by `notes.export` or written by `miden export`.
+    note_file: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExportNoteParams {
+    note_id: String,
+    #[serde(default = "default_export_type")]
+    export_type: String,
+}
+
+fn default_export_type() -> String {
+    "full".to_string()
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MintParams {
+    target_account_id: String,
+    asset: String,
+    note_type: NoteTypeParam,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SendParams {
+    /// Sender of the payment. The default account is used if it's not provided.
+    sender_account_id: Option<String>,
+    target_account_id: String,
+    asset: String,
+    note_type: NoteTypeParam,
+    recall_height: Option<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConsumeParams {
+    /// Account that consumes the notes. The default account is used if it's not provided.
+    account_id: Option<String>,
+    /// Notes to consume. Every note consumable by the account is consumed if none is provided.
+    #[serde(default)]
+    note_ids: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TransactionParams {
+    transaction_id: String,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum NoteTypeParam {
+    Public,
+    Private,
+}
+
+impl From<NoteTypeParam> for MidenNoteType {
+    fn from(note_type: NoteTypeParam) -> Self {
+        match note_type {
+            NoteTypeParam::Public => MidenNoteType::Public,
+            NoteTypeParam::Private => MidenNoteType::Private,
+        }
+    }
+}
+
+/// Deserializes the params of a request. Missing params are treated as an empty object, so
+/// methods whose params are all optional can be called without them.
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|err| invalid_params(format!("Invalid params: {err}")))
+}
+
+// VIEWS
+// ================================================================================================
+
+#[derive(Serialize)]
+struct SyncSummaryView {
+    block_num: u32,
+    received_notes: Vec<String>,
+    committed_notes: Vec<String>,
+    consumed_notes: Vec<String>,
+    updated_accounts: Vec<String>,
+    committed_transactions: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl From<SyncSummary> for SyncSummaryView {
+    fn from(summary: SyncSummary) -> Self {
+        Self {
+            block_num: summary.block_num,
+            received_notes: summary.received_notes.iter().map(|id| id.to_hex()).collect(),
+            committed_notes: summary.committed_notes.iter().map(|id| id.to_hex()).collect(),
+            consumed_notes: summary.consumed_notes.iter().map(|id| id.to_hex()).collect(),
+            updated_accounts: summary.updated_accounts.iter().map(|id| id.to_hex()).collect(),
+            committed_transactions: summary
+                .committed_transactions
+                .iter()
+                .map(|id| id.to_string())
+                .collect(),
+            warnings: summary.warnings,
+        }
+    }
+}
+
+/// Summary of a tracked account, as listed by `accounts.list`. The full contents of an account
+/// are returned by `accounts.show` as an [AccountReport].
+#[derive(Serialize)]
+struct AccountHeaderView {
+    id: String,
+    account_type: AccountTypeReport,
+    storage_mode: String,
+    nonce: u64,
+    hash: String,
+    is_new: bool,
+}
+
+#[derive(Serialize)]
+struct NoteView {
+    id: String,
+    /// Stable code of the note state, like `committed` or `consumed_local`.
+    state: String,
+    /// Description of the note state, including details like the block of its commitment.
+    state_details: String,
+    assets: Vec<AssetReport>,
+    /// Metadata of the note, if the client knows it.
+    metadata: Option<NoteMetadataView>,
+}
+
+impl From<&InputNoteRecord> for NoteView {
+    fn from(note: &InputNoteRecord) -> Self {
+        Self {
+            id: note.id().to_hex(),
+            state: note.state().code().as_str().to_string(),
+            state_details: note.state().to_string(),
+            assets: note.assets().iter().copied().map(AssetReport::from).collect(),
+            metadata: note.metadata().map(NoteMetadataView::from),
+        }
+    }
+}
+
+impl From<&OutputNoteRecord> for NoteView {
+    fn from(note: &OutputNoteRecord) -> Self {
+        Self {
+            id: note.id().to_hex(),
+            state: note.state().code().as_str().to_string(),
+            state_details: note.state().to_string(),
+            assets: note.assets().iter().copied().map(AssetReport::from).collect(),
+            metadata: Some(NoteMetadataView::from(note.metadata())),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct NoteMetadataView {
+    sender: String,
+    note_type: String,
+    tag: u32,
+    aux: u64,
+}
+
+impl From<&NoteMetadata> for NoteMetadataView {
+    fn from(metadata: &NoteMetadata) -> Self {
+        let note_type = match metadata.note_type() {
+            MidenNoteType::Public => "public",
+            MidenNoteType::Private => "private",
+            MidenNoteType::Encrypted => "encrypted",
+        };
+
+        Self {
+            sender: metadata.sender().to_hex(),
+            note_type: note_type.to_string(),
+            tag: metadata.tag().into(),
+            aux: metadata.aux().as_int(),
+        }
+    }
+}
+
+/// Transaction built by `tx.mint`, `tx.send` or `tx.consume`, which can be submitted with
+/// `tx.submit`.
+#[derive(Serialize)]
+struct TransactionView {
+    transaction_id: String,
+    account_id: String,
+    consumed_notes: Vec<String>,
+    created_notes: Vec<String>,
+}
+
+impl From<&TransactionResult> for TransactionView {
+    fn from(transaction_result: &TransactionResult) -> Self {
+        let executed_transaction = transaction_result.executed_transaction();
+
+        Self {
+            transaction_id: executed_transaction.id().to_string(),
+            account_id: executed_transaction.account_id().to_hex(),
+            consumed_notes: executed_transaction
+                .input_notes()
+                .iter()
+                .map(|note| note.id().to_hex())
+                .collect(),
+            created_notes: transaction_result
+                .created_notes()
+                .iter()
+                .map(|note| note.id().to_hex())
+                .collect(),
+        }
+    }
+}
+
+fn to_result(view: impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(view)
+        .map_err(|err| RpcError::new(CLIENT_ERROR, format!("Failed to serialize result: {err}")))
+}
+
+// ERRORS
+// ================================================================================================
+
+/// Error object of a JSON-RPC response.
+#[derive(Debug, Serialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+}
+
+impl From<ClientError> for RpcError {
+    fn from(err: ClientError) -> Self {
+        Self::new(CLIENT_ERROR, err.to_string())
+    }
+}
+
+fn invalid_params(err: impl ToString) -> RpcError {
+    RpcError::new(INVALID_PARAMS, err.to_string())
+}
+
+fn error_response(id: Value, err: RpcError) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": err })
+}
+
+// HTTP
+// ================================================================================================
+
+struct HttpRequest {
+    bearer_token: Option<String>,
+    body: Vec<u8>,
+}
+
+struct HttpError {
+    status: u16,
+    reason: String,
+}
+
+impl HttpError {
+    fn new(status: u16, reason: impl Into<String>) -> Self {
+        Self { status, reason: reason.into() }
+    }
+}
+
+/// Reads a `POST /` request with a JSON body from the stream.
+async fn read_http_request(stream: &mut TcpStream) -> Result<HttpRequest, HttpError> {
+    let mut reader = BufReader::new(stream);
+    let mut head_size = 0;
+
+    let request_line = read_head_line(&mut reader, &mut head_size).await?;
+    let mut request_line = request_line.split_whitespace();
+    match (request_line.next(), request_line.next()) {
+        (Some("POST"), Some("/")) => {},
+        (Some("POST"), Some(path)) => {
+            return Err(HttpError::new(404, format!("Unknown path {path}")))
+        },
+        _ => return Err(HttpError::new(405, "Only POST requests are supported")),
+    }
+
+    let mut content_length = None;
+    let mut bearer_token = None;
+    loop {
+        let line = read_head_line(&mut reader, &mut head_size).await?;
+        if line.is_empty() {
+            break;
+        }
+
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(HttpError::new(400, format!("Invalid header `{line}`")));
+        };
+        let value = value.trim();
+        match name.trim().to_lowercase().as_str() {
+            "content-length" => {
+                content_length = Some(
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| HttpError::new(400, "Invalid Content-Length header"))?,
+                );
+            },
+            "authorization" => {
+                bearer_token = value.strip_prefix("Bearer ").map(|token| token.trim().to_string());
+            },
+            _ => {},
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| HttpError::new(411, "Content-Length header is required"))?;
+    if content_length > MAX_REQUEST_BODY_SIZE {
+        return Err(HttpError::new(
+            413,
+            format!("Request body is larger than {MAX_REQUEST_BODY_SIZE} bytes"),
+        ));
+    }
+
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .await
+        .map_err(|err| HttpError::new(400, format!("Failed to read request body: {err}")))?;
+
+    Ok(HttpRequest { bearer_token, body })
+}
+
+/// Reads a line of the request head, failing once the head grows over
+/// [MAX_REQUEST_HEAD_SIZE].
+async fn read_head_line(
+    reader: &mut BufReader<&mut TcpStream>,
+    head_size: &mut usize,
+) -> Result<String, HttpError> {
+    let mut line = String::new();
+    let read = reader
+        .take((MAX_REQUEST_HEAD_SIZE - *head_size) as u64)
+        .read_line(&mut line)
+        .await
+        .map_err(|err| HttpError::new(400, err.to_string()))?;
+    *head_size += read;
+    if !line.ends_with('\n') {
+        return Err(HttpError::new(431, "Request head is too large or incomplete"));
+    }
+
+    Ok(line.trim_end().to_string())
+}
+
+async fn write_http_response(
+    stream: &mut TcpStream,
+    status: u16,
+    body: &Value,
+) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        _ => "Bad Request",
+    };
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+// HELPERS
+// ================================================================================================
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 {
+        return Err("Hex string has an odd number of digits".to_string());
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|digits| {
+            core::str::from_utf8(digits)
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| "Invalid hex string".to_string())
+        })
+        .collect()
+}
@@ -5,7 +5,7 @@ use miden_client::{
 };
 use tracing::info;
 
-use crate::{get_output_note_with_id_prefix, utils::parse_account_id, Parser};
+use crate::{utils::parse_account_id, Parser};
 
 #[derive(Debug, Parser, Clone)]
 #[clap(about = "Export client output notes")]
@@ -22,6 +22,10 @@ pub struct ExportCmd {
     #[arg(long, conflicts_with = "note")]
     account: bool,
 
+    /// Export the account without its secret key, so that it can only be monitored
+    #[arg(long, requires = "account")]
+    watch_only: bool,
+
     /// Export note data (cannot be used with --account)
     #[arg(long, requires = "export_type", conflicts_with = "account")]
     note: bool,
@@ -51,7 +55,8 @@ impl From<ExportType> for NoteExportType {
 impl ExportCmd {
     pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
         if self.account {
-            export_account(&client, self.id.as_str(), self.filename.clone()).await?;
+            export_account(&client, self.id.as_str(), self.filename.clone(), self.watch_only)
+                .await?;
         } else if let Some(export_type) = &self.export_type {
             export_note(&mut client, self.id.as_str(), self.filename.clone(), export_type.clone())
                 .await?;
@@ -69,14 +74,18 @@ async fn export_account<R: FeltRng>(
     client: &Client<R>,
     account_id: &str,
     filename: Option<PathBuf>,
+    watch_only: bool,
 ) -> Result<File, String> {
     let account_id = parse_account_id(client, account_id).await?;
 
-    let (account, account_seed) = client.get_account(account_id).await?;
-
-    let auth = client.get_account_auth(account_id).await?;
+    let account_bytes = if watch_only {
+        client.export_account_watch_only(account_id).await?
+    } else {
+        let (account, account_seed) = client.get_account(account_id).await?;
+        let auth = client.get_account_auth(account_id).await?;
 
-    let account_data = AccountData::new(account, account_seed, auth);
+        AccountData::new(account, account_seed, auth).to_bytes()
+    };
 
     let file_path = if let Some(filename) = filename {
         filename
@@ -87,7 +96,7 @@ async fn export_account<R: FeltRng>(
 
     info!("Writing file to {}", file_path.to_string_lossy());
     let mut file = File::create(file_path).map_err(|err| err.to_string())?;
-    account_data.write_into(&mut file);
+    file.write_all(&account_bytes).map_err(|err| err.to_string())?;
 
     println!("Succesfully exported account {}", account_id);
     Ok(file)
@@ -102,16 +111,12 @@ async fn export_note(
     filename: Option<PathBuf>,
     export_type: ExportType,
 ) -> Result<File, String> {
-    let note_id = get_output_note_with_id_prefix(client, note_id)
+    let output_note = client
+        .find_note_by_prefix(note_id)
         .await
         .map_err(|err| err.to_string())?
-        .id();
-
-    let output_note = client
-        .get_output_notes(miden_client::store::NoteFilter::Unique(note_id))
-        .await?
-        .pop()
-        .expect("should have an output note");
+        .output_note
+        .ok_or(format!("Note {note_id} is not an output note"))?;
 
     let note_file = output_note.into_note_file(export_type.into())?;
 
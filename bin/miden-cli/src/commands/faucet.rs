@@ -0,0 +1,74 @@
+use clap::Parser;
+use miden_client::{
+    accounts::{AccountId, ISSUANCE_WARNING_THRESHOLD_PERCENT},
+    crypto::FeltRng,
+    Client,
+};
+
+use crate::{create_dynamic_table, utils::parse_account_id};
+
+// FAUCET COMMAND
+// ================================================================================================
+
+#[derive(Debug, Clone, Parser)]
+/// View information about the fungible faucets tracked by this client.
+pub enum FaucetCmd {
+    /// Show the issuance of the faucet for the specified ID or hex prefix
+    Show {
+        /// ID or hex prefix of the faucet
+        #[clap(value_name = "ID")]
+        id: String,
+    },
+}
+
+impl FaucetCmd {
+    pub async fn execute<R: FeltRng>(&self, client: Client<R>) -> Result<(), String> {
+        match self {
+            FaucetCmd::Show { id } => {
+                let faucet_id = parse_account_id(&client, id).await?;
+                show_faucet(client, faucet_id).await
+            },
+        }
+    }
+}
+
+// SHOW FAUCET
+// ================================================================================================
+
+async fn show_faucet<R: FeltRng>(client: Client<R>, faucet_id: AccountId) -> Result<(), String> {
+    let issuance = client.get_faucet_issuance(faucet_id).await?;
+
+    let mut table = create_dynamic_table(&[
+        "Faucet ID",
+        "Max Supply",
+        "Issuance",
+        "Remaining",
+        "Minted Locally",
+        "Mint Transactions",
+    ]);
+    table.add_row(vec![
+        issuance.faucet_id.to_string(),
+        issuance.max_supply.to_string(),
+        issuance.storage_issuance.to_string(),
+        issuance.remaining().to_string(),
+        issuance.minted_locally.to_string(),
+        issuance.mint_transactions.to_string(),
+    ]);
+    println!("{table}");
+
+    if issuance.has_diverged() {
+        println!(
+            "\nWarning: the faucet's issuance differs from the amount minted by this client. Tokens \
+            may have been minted by another client instance."
+        );
+    }
+
+    if issuance.is_near_max_supply(ISSUANCE_WARNING_THRESHOLD_PERCENT) {
+        println!(
+            "\nWarning: the faucet has issued at least {ISSUANCE_WARNING_THRESHOLD_PERCENT}% of \
+            its max supply."
+        );
+    }
+
+    Ok(())
+}
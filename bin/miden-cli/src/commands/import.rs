@@ -5,7 +5,7 @@ use std::{
 };
 
 use miden_client::{
-    accounts::{AccountData, AccountId},
+    accounts::{AccountData, AccountId, WatchOnlyAccountFile},
     crypto::FeltRng,
     notes::NoteFile,
     utils::Deserializable,
@@ -21,6 +21,10 @@ pub struct ImportCmd {
     /// Paths to the files that contains the account/note data
     #[arg()]
     filenames: Vec<PathBuf>,
+
+    /// Import public accounts even if their state doesn't match the one the node has
+    #[clap(short, long, default_value_t = false)]
+    force: bool,
 }
 
 impl ImportCmd {
@@ -31,15 +35,26 @@ impl ImportCmd {
             let note_file = read_note_file(filename.clone());
 
             if let Ok(note_file) = note_file {
-                let note_id = client.import_note(note_file).await.map_err(|err| err.to_string())?;
-                println!("Succesfully imported note {}", note_id.inner());
+                let outcome = client
+                    .import_note_with_outcome(note_file)
+                    .await
+                    .map_err(|err| err.to_string())?;
+                println!("Succesfully imported note {}", outcome.note_id.inner());
+                if let Some(correction) = outcome.tag_correction {
+                    println!(
+                        "The note's tag {} was corrected to {}",
+                        correction.previous_tag, correction.corrected_tag
+                    );
+                }
             } else {
-                let account_id = import_account(&mut client, filename)
+                let account_id = import_account(&mut client, filename, self.force)
                     .await
                     .map_err(|_| format!("Failed to parse file {}", filename.to_string_lossy()))?;
                 println!("Succesfully imported account {}", account_id);
 
-                if account_id.is_regular_account() {
+                if account_id.is_regular_account()
+                    && !client.is_watch_only_account(account_id).await?
+                {
                     maybe_set_default_account(&mut current_config, account_id)?;
                 }
             }
@@ -54,17 +69,27 @@ impl ImportCmd {
 async fn import_account(
     client: &mut Client<impl FeltRng>,
     filename: &PathBuf,
+    force: bool,
 ) -> Result<AccountId, String> {
     info!(
         "Attempting to import account data from {}...",
         fs::canonicalize(filename).map_err(|err| err.to_string())?.as_path().display()
     );
     let account_data_file_contents = fs::read(filename).map_err(|err| err.to_string())?;
+
+    if let Ok(account_file) = WatchOnlyAccountFile::read_from_bytes(&account_data_file_contents) {
+        let account_id = account_file.account.id();
+        client.import_watch_only_account(account_file).await?;
+        println!("Account {} was imported as watch-only", account_id);
+
+        return Ok(account_id);
+    }
+
     let account_data =
         AccountData::read_from_bytes(&account_data_file_contents).map_err(|err| err.to_string())?;
     let account_id = account_data.account.id();
 
-    client.import_account(account_data).await?;
+    client.import_account(account_data, force).await?;
 
     Ok(account_id)
 }
@@ -0,0 +1,70 @@
+use clap::{Parser, ValueEnum};
+use miden_client::{
+    crypto::FeltRng,
+    maintenance::{MaintenanceReport, MaintenanceTask},
+    Client,
+};
+
+use crate::create_dynamic_table;
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum MaintenanceTaskArg {
+    ClearPendingNotes,
+    ArchiveNotes,
+    RemoveStaleTags,
+}
+
+impl From<&MaintenanceTaskArg> for MaintenanceTask {
+    fn from(task: &MaintenanceTaskArg) -> Self {
+        match task {
+            MaintenanceTaskArg::ClearPendingNotes => MaintenanceTask::ClearResolvedPendingNotes,
+            MaintenanceTaskArg::ArchiveNotes => MaintenanceTask::ArchiveConsumedNotes,
+            MaintenanceTaskArg::RemoveStaleTags => MaintenanceTask::RemoveStaleTags,
+        }
+    }
+}
+
+#[derive(Debug, Parser, Clone)]
+#[clap(about = "Run maintenance tasks on the client's store")]
+pub struct MaintainCmd {
+    /// Task to run. Can be repeated; every task is run if none is specified
+    #[clap(short, long, value_enum)]
+    task: Vec<MaintenanceTaskArg>,
+}
+
+impl MaintainCmd {
+    pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
+        let tasks: Vec<MaintenanceTask> = if self.task.is_empty() {
+            MaintenanceTask::ALL.to_vec()
+        } else {
+            self.task.iter().map(MaintenanceTask::from).collect()
+        };
+
+        let report = client.run_maintenance(&tasks).await;
+        print_maintenance_report(&report);
+
+        match report.failed_tasks().count() {
+            0 => Ok(()),
+            failed => Err(format!("{failed} maintenance task(s) failed")),
+        }
+    }
+}
+
+fn print_maintenance_report(report: &MaintenanceReport) {
+    let mut table = create_dynamic_table(&["Task", "Items Affected", "Duration"]);
+
+    for task_report in report.tasks.iter() {
+        let items = match &task_report.result {
+            Ok(items) => items.to_string(),
+            Err(err) => format!("failed: {err}"),
+        };
+        let duration = task_report
+            .duration
+            .map(|duration| format!("{} ms", duration.as_millis()))
+            .unwrap_or_else(|| "-".to_string());
+
+        table.add_row(vec![task_report.task.to_string(), items, duration]);
+    }
+
+    println!("{table}");
+}
@@ -1,10 +1,15 @@
 pub mod account;
+#[cfg(feature = "daemon")]
+pub mod daemon;
 pub mod export;
+pub mod faucet;
 pub mod import;
 pub mod init;
+pub mod maintain;
 pub mod new_account;
 pub mod new_transactions;
 pub mod notes;
+pub mod store;
 pub mod sync;
 pub mod tags;
 pub mod transactions;
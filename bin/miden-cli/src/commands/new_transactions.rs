@@ -5,9 +5,10 @@ use miden_client::{
     accounts::AccountId,
     assets::{FungibleAsset, NonFungibleDeltaAction},
     crypto::{Digest, FeltRng},
-    notes::{build_swap_tag, get_input_note_with_id_prefix, NoteType as MidenNoteType},
+    notes::NoteType as MidenNoteType,
     transactions::{
-        PaymentTransactionData, SwapTransactionData, TransactionRequest, TransactionResult,
+        PaymentTransactionData, SwapTransactionData, TransactionRequest, TransactionRequestError,
+        TransactionResult,
     },
     Client,
 };
@@ -69,7 +70,12 @@ impl MintCmd {
             (&self.note_type).into(),
             client.rng(),
         )
-        .map_err(|err| err.to_string())?;
+        .map_err(|err| match err {
+            TransactionRequestError::SelfTargetedNote(_) => {
+                format!("{err}. Faucets can't consume their own notes, mint to another account")
+            },
+            err => err.to_string(),
+        })?;
 
         execute_transaction(&mut client, fungible_asset.faucet_id(), transaction_request, force)
             .await
@@ -83,13 +89,14 @@ pub struct SendCmd {
     /// instead
     #[clap(short = 's', long = "sender")]
     sender_account_id: Option<String>,
-    /// Target account ID or its hex prefix
-    #[clap(short = 't', long = "target")]
-    target_account_id: String,
+    /// Target account ID or its hex prefix. Can be repeated to pay several accounts in the same
+    /// transaction, each receiving the asset in the same position
+    #[clap(short = 't', long = "target", required = true)]
+    target_account_ids: Vec<String>,
 
-    /// Asset to be sent.
-    #[clap(short, long, help=format!("Asset to be sent.\n{SHARED_TOKEN_DOCUMENTATION}"))]
-    asset: String,
+    /// Asset to be sent. Repeated once for each target.
+    #[clap(short, long, required = true, help=format!("Asset to be sent. Repeated once for each target.\n{SHARED_TOKEN_DOCUMENTATION}"))]
+    asset: Vec<String>,
 
     #[clap(short, long, value_enum)]
     note_type: NoteType,
@@ -102,34 +109,67 @@ pub struct SendCmd {
     /// Setting this flag turns the transaction from a PayToId to a PayToIdWithRecall.
     #[clap(short, long)]
     recall_height: Option<u32>,
+    /// Memo to attach to the payment. The target account can read it from the created note,
+    /// which can't have a recall height.
+    #[clap(short, long)]
+    memo: Option<String>,
+    /// Allow the sender account to be the target of the payment, for instance to split its assets
+    /// into several notes
+    #[clap(long, default_value_t = false)]
+    allow_self_transfer: bool,
 }
 
 impl SendCmd {
     pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
         let force = self.force;
 
-        let faucet_details_map = load_faucet_details_map()?;
+        if self.target_account_ids.len() != self.asset.len() {
+            return Err(format!(
+                "Each target needs its own asset, but {} targets and {} assets were provided",
+                self.target_account_ids.len(),
+                self.asset.len()
+            ));
+        }
 
-        let fungible_asset = faucet_details_map.parse_fungible_asset(&self.asset)?;
+        let faucet_details_map = load_faucet_details_map()?;
 
         // try to use either the provided argument or the default account
         let sender_account_id =
             get_input_acc_id_by_prefix_or_default(&client, self.sender_account_id.clone()).await?;
-        let target_account_id = parse_account_id(&client, self.target_account_id.as_str()).await?;
 
-        let payment_transaction = PaymentTransactionData::new(
-            vec![fungible_asset.into()],
-            sender_account_id,
-            target_account_id,
-        );
+        let mut payments = Vec::with_capacity(self.target_account_ids.len());
+        for (target_account_id, asset) in self.target_account_ids.iter().zip(self.asset.iter()) {
+            let fungible_asset = faucet_details_map.parse_fungible_asset(asset)?;
+            let target_account_id = parse_account_id(&client, target_account_id).await?;
+
+            let mut payment_transaction = PaymentTransactionData::new(
+                vec![fungible_asset.into()],
+                sender_account_id,
+                target_account_id,
+            );
+            if let Some(memo) = &self.memo {
+                payment_transaction = payment_transaction
+                    .with_memo(memo.as_bytes().to_vec())
+                    .map_err(|err| err.to_string())?;
+            }
+            if self.allow_self_transfer {
+                payment_transaction = payment_transaction.allow_self_transfer();
+            }
+            payments.push(payment_transaction);
+        }
 
-        let transaction_request = TransactionRequest::pay_to_id(
-            payment_transaction,
+        let transaction_request = TransactionRequest::pay_to_ids(
+            payments,
             self.recall_height,
             (&self.note_type).into(),
             client.rng(),
         )
-        .map_err(|err| err.to_string())?;
+        .map_err(|err| match err {
+            TransactionRequestError::SelfTargetedNote(_) => {
+                format!("{err}. Use --allow-self-transfer to send assets to the sender account")
+            },
+            err => err.to_string(),
+        })?;
 
         execute_transaction(&mut client, sender_account_id, transaction_request, force).await
     }
@@ -179,25 +219,19 @@ impl SwapCmd {
             requested_fungible_asset.into(),
         );
 
-        let transaction_request = TransactionRequest::swap(
-            swap_transaction.clone(),
-            (&self.note_type).into(),
-            client.rng(),
-        )
-        .map_err(|err| err.to_string())?;
+        let swap_tag = swap_transaction
+            .swap_tag((&self.note_type).into())
+            .map_err(|err| err.to_string())?;
+
+        let transaction_request =
+            TransactionRequest::swap(swap_transaction, (&self.note_type).into(), client.rng())
+                .map_err(|err| err.to_string())?;
 
         execute_transaction(&mut client, sender_account_id, transaction_request, force).await?;
 
-        let payback_note_tag: u32 = build_swap_tag(
-            (&self.note_type).into(),
-            &swap_transaction.offered_asset(),
-            &swap_transaction.requested_asset(),
-        )
-        .map_err(|err| err.to_string())?
-        .into();
         println!(
-            "To receive updates about the payback Swap Note run `miden tags add {}`",
-            payback_note_tag
+            "The swap note can be discovered by takers through its tag {}. The payback note will be picked up by `miden sync`.",
+            u32::from(swap_tag)
         );
 
         Ok(())
@@ -215,6 +249,11 @@ pub struct ConsumeNotesCmd {
     account_id: Option<String>,
     /// A list of note IDs or the hex prefixes of their corresponding IDs
     list_of_notes: Vec<String>,
+    /// ID or hex prefix of a note that isn't identified as consumable by the account, like a note
+    /// with a custom script, to consume anyway. The note is only consumed if a dry run shows
+    /// the account can consume it. Can be repeated
+    #[clap(long = "force-note")]
+    force_notes: Vec<String>,
     /// Flag to submit the executed transaction without asking for confirmation
     #[clap(short, long, default_value_t = false)]
     force: bool,
@@ -226,18 +265,39 @@ impl ConsumeNotesCmd {
 
         let mut list_of_notes = Vec::new();
         for note_id in &self.list_of_notes {
-            let note_record = get_input_note_with_id_prefix(&client, note_id)
+            let note_record = client
+                .find_note_by_prefix(note_id)
                 .await
-                .map_err(|err| err.to_string())?;
+                .map_err(|err| err.to_string())?
+                .input_note
+                .ok_or(format!("Note {note_id} is not an input note"))?;
             list_of_notes.push(note_record.id());
         }
 
         let account_id =
             get_input_acc_id_by_prefix_or_default(&client, self.account_id.clone()).await?;
 
+        for note_id in &self.force_notes {
+            let note_record = client
+                .find_note_by_prefix(note_id)
+                .await
+                .map_err(|err| err.to_string())?
+                .input_note
+                .ok_or(format!("Note {note_id} is not an input note"))?;
+
+            let verdict = client.can_consume(note_record.id(), account_id).await?;
+            if !verdict.is_consumable() {
+                return Err(format!(
+                    "Note {} can't be consumed by account {account_id}: {verdict}",
+                    note_record.id()
+                ));
+            }
+            list_of_notes.push(note_record.id());
+        }
+
         if list_of_notes.is_empty() {
             info!("No input note IDs provided, getting all notes consumable by {}", account_id);
-            let consumable_notes = client.get_consumable_notes(Some(account_id)).await?;
+            let consumable_notes = client.get_consumable_notes(Some(account_id), false).await?;
 
             list_of_notes.extend(consumable_notes.iter().map(|(note, _)| note.id()));
         }
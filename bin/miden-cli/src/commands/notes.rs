@@ -5,17 +5,16 @@ use miden_client::{
     assets::Asset,
     crypto::{Digest, FeltRng},
     notes::{
-        get_input_note_with_id_prefix,
+        memo::read_memo,
         script_roots::{P2ID, P2IDR, SWAP},
-        NoteConsumability, NoteInputs, NoteMetadata,
+        NoteConsumability, NoteConsumption, NoteId, NoteInputs, NoteMatch, NoteMetadata,
+        PendingNoteRecord,
     },
     store::{InputNoteRecord, NoteFilter as ClientNoteFilter, OutputNoteRecord},
     Client, ClientError, IdPrefixFetchError,
 };
 
-use crate::{
-    create_dynamic_table, get_output_note_with_id_prefix, utils::load_faucet_details_map, Parser,
-};
+use crate::{create_dynamic_table, utils::load_faucet_details_map, Parser};
 
 #[derive(Clone, Debug, ValueEnum)]
 pub enum NoteFilter {
@@ -24,6 +23,7 @@ pub enum NoteFilter {
     Committed,
     Consumed,
     Processing,
+    Rejected,
     Consumable,
 }
 
@@ -37,6 +37,7 @@ impl TryInto<ClientNoteFilter> for NoteFilter {
             NoteFilter::Committed => Ok(ClientNoteFilter::Committed),
             NoteFilter::Consumed => Ok(ClientNoteFilter::Consumed),
             NoteFilter::Processing => Ok(ClientNoteFilter::Processing),
+            NoteFilter::Rejected => Ok(ClientNoteFilter::Rejected),
             NoteFilter::Consumable => Err("Consumable filter is not supported".to_string()),
         }
     }
@@ -55,13 +56,21 @@ pub struct NotesCmd {
     /// consumable by this account will be shown.
     #[clap(short, long, value_name = "account_id")]
     account_id: Option<String>,
+    /// (only has effect on `--list consumable`) Also list notes that are already being consumed
+    /// by a local transaction that wasn't committed yet.
+    #[clap(long, default_value_t = false)]
+    include_processing: bool,
 }
 
 impl NotesCmd {
     pub async fn execute(&self, client: Client<impl FeltRng>) -> Result<(), String> {
         match self {
-            NotesCmd { list: Some(NoteFilter::Consumable), .. } => {
-                list_consumable_notes(client, &None).await?;
+            NotesCmd {
+                list: Some(NoteFilter::Consumable),
+                include_processing,
+                ..
+            } => {
+                list_consumable_notes(client, &None, *include_processing).await?;
             },
             NotesCmd { list: Some(filter), .. } => {
                 list_notes(
@@ -89,9 +98,16 @@ struct CliNoteSummary {
     serial_num: String,
     note_type: String,
     state: String,
+    state_details: String,
     tag: String,
+    aux: String,
     sender: String,
     exportable: bool,
+    created_at: String,
+    expected_height: String,
+    committed_at_block: String,
+    committed_timestamp: String,
+    consumed_at_block: String,
 }
 
 // LIST NOTES
@@ -110,42 +126,35 @@ async fn list_notes(client: Client<impl FeltRng>, filter: ClientNoteFilter) -> R
         .map(|output_note_record| note_summary(None, Some(&output_note_record)))
         .collect::<Result<Vec<CliNoteSummary>, String>>()?;
 
-    print_notes_summary(input_notes, "Input Notes")?;
-    print_notes_summary(output_notes, "Output Notes")
+    print_notes_summary(input_notes, "Input Notes", false)?;
+    print_notes_summary(output_notes, "Output Notes", true)?;
+
+    if matches!(filter, ClientNoteFilter::All | ClientNoteFilter::Expected) {
+        let pending_notes = client.get_pending_notes().await?;
+        if !pending_notes.is_empty() {
+            print_pending_notes_summary(&pending_notes);
+        }
+    }
+
+    Ok(())
 }
 
 // SHOW NOTE
 // ================================================================================================
 async fn show_note(client: Client<impl FeltRng>, note_id: String) -> Result<(), String> {
-    let input_note_record = get_input_note_with_id_prefix(&client, &note_id).await;
-    let output_note_record = get_output_note_with_id_prefix(&client, &note_id).await;
-
-    // If we don't find an input note nor an output note return an error
-    if matches!(input_note_record, Err(IdPrefixFetchError::NoMatch(_)))
-        && matches!(output_note_record, Err(IdPrefixFetchError::NoMatch(_)))
-    {
-        return Err("Couldn't find notes matching the specified note ID".to_string());
-    }
-
-    // If either one of the two match with multiple notes return an error
-    if matches!(input_note_record, Err(IdPrefixFetchError::MultipleMatches(_)))
-        || matches!(output_note_record, Err(IdPrefixFetchError::MultipleMatches(_)))
-    {
-        return Err("The specified note ID hex prefix matched with more than one note.".to_string());
-    }
-
-    let input_note_record = input_note_record.ok();
-    let output_note_record = output_note_record.ok();
-
-    // If we match one note as the input note and another one as the output note return an error
-    match (&input_note_record, &output_note_record) {
-        (Some(input_record), Some(output_record)) if input_record.id() != output_record.id() => {
-            return Err(
-                "The specified note ID hex prefix matched with more than one note.".to_string()
-            );
+    let NoteMatch {
+        input_note: input_note_record,
+        output_note: output_note_record,
+        ..
+    } = client.find_note_by_prefix(&note_id).await.map_err(|err| match err {
+        IdPrefixFetchError::NoMatch(_) => {
+            "Couldn't find notes matching the specified note ID".to_string()
         },
-        _ => {},
-    }
+        IdPrefixFetchError::MultipleMatches(_, candidates) => format!(
+            "The specified note ID hex prefix matched with more than one note: {}",
+            candidates.join(", ")
+        ),
+    })?;
 
     let mut table = create_dynamic_table(&["Note Information"]);
     table
@@ -160,15 +169,29 @@ async fn show_note(client: Client<impl FeltRng>, note_id: String) -> Result<(),
         serial_num,
         note_type,
         state,
+        state_details,
         tag,
+        aux,
         sender,
         exportable,
+        created_at,
+        expected_height,
+        committed_at_block,
+        committed_timestamp,
+        consumed_at_block,
     } = note_summary(input_note_record.as_ref(), output_note_record.as_ref())?;
     table.add_row(vec![Cell::new("ID"), Cell::new(id)]);
+
+    let memo = match (&input_note_record, &output_note_record) {
+        (Some(record), _) => read_memo(record.details().recipient()),
+        (_, Some(record)) => record.recipient().and_then(read_memo),
+        (None, None) => None,
+    };
     match script_hash.clone().as_str() {
         P2ID => script_hash += " (P2ID)",
         P2IDR => script_hash += " (P2IDR)",
         SWAP => script_hash += " (SWAP)",
+        _ if memo.is_some() => script_hash += " (P2ID with memo)",
         _ => {},
     };
 
@@ -178,12 +201,31 @@ async fn show_note(client: Client<impl FeltRng>, note_id: String) -> Result<(),
     table.add_row(vec![Cell::new("Serial Number"), Cell::new(serial_num)]);
     table.add_row(vec![Cell::new("Type"), Cell::new(note_type)]);
     table.add_row(vec![Cell::new("State"), Cell::new(state)]);
+    table.add_row(vec![Cell::new("State Details"), Cell::new(state_details)]);
     table.add_row(vec![Cell::new("Tag"), Cell::new(tag)]);
+    table.add_row(vec![Cell::new("Aux"), Cell::new(aux)]);
     table.add_row(vec![Cell::new("Sender"), Cell::new(sender)]);
     table.add_row(vec![Cell::new("Exportable"), Cell::new(if exportable { "✔" } else { "✘" })]);
+    table.add_row(vec![Cell::new("Created At"), Cell::new(created_at)]);
+    if output_note_record.is_some() {
+        table.add_row(vec![Cell::new("Expected Height"), Cell::new(expected_height)]);
+        table.add_row(vec![Cell::new("Committed At Block"), Cell::new(committed_at_block)]);
+        table.add_row(vec![Cell::new("Committed Timestamp"), Cell::new(committed_timestamp)]);
+        table.add_row(vec![Cell::new("Consumed At Block"), Cell::new(consumed_at_block)]);
+    }
+    if let Some(memo) = memo {
+        table.add_row(vec![Cell::new("Memo"), Cell::new(String::from_utf8_lossy(&memo))]);
+    }
 
     println!("{table}");
 
+    let note_id = input_note_record
+        .as_ref()
+        .map(InputNoteRecord::id)
+        .or(output_note_record.as_ref().map(OutputNoteRecord::id))
+        .expect("One of the two records should be Some");
+    print_note_history(&client, note_id).await?;
+
     let inputs = match (&input_note_record, &output_note_record) {
         (Some(record), _) => {
             let details = record.details();
@@ -252,24 +294,69 @@ async fn show_note(client: Client<impl FeltRng>, note_id: String) -> Result<(),
     Ok(())
 }
 
+/// Prints the transactions that created and consumed the note and the blocks in which that
+/// happened.
+async fn print_note_history(client: &Client<impl FeltRng>, note_id: NoteId) -> Result<(), String> {
+    let provenance = client.get_note_transactions(note_id).await?;
+
+    let mut table = create_dynamic_table(&["Note History"]);
+    table
+        .load_preset(presets::UTF8_HORIZONTAL_ONLY)
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth);
+
+    let creator = provenance
+        .creator_transaction
+        .map(|tx| tx.id.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    table.add_row(vec![Cell::new("Created By"), Cell::new(creator)]);
+
+    let inclusion_block = provenance
+        .inclusion_block_num
+        .map(|block_num| block_num.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    table.add_row(vec![Cell::new("Inclusion Block"), Cell::new(inclusion_block)]);
+
+    let (consumer, nullifier_block) = match provenance.consumption {
+        Some(NoteConsumption::Local { transaction, nullifier_block_num }) => {
+            (transaction.id.to_string(), nullifier_block_num)
+        },
+        Some(NoteConsumption::External { nullifier_block_num }) => {
+            ("External transaction".to_string(), Some(nullifier_block_num))
+        },
+        None => ("-".to_string(), None),
+    };
+    table.add_row(vec![Cell::new("Consumed By"), Cell::new(consumer)]);
+    table.add_row(vec![
+        Cell::new("Nullifier Block"),
+        Cell::new(nullifier_block.map_or_else(|| "-".to_string(), |block| block.to_string())),
+    ]);
+
+    println!("{table}");
+
+    Ok(())
+}
+
 // LIST CONSUMABLE INPUT NOTES
 // ================================================================================================
 async fn list_consumable_notes(
     client: Client<impl FeltRng>,
     account_id: &Option<String>,
+    include_processing: bool,
 ) -> Result<(), String> {
     let account_id = match account_id {
         Some(id) => Some(AccountId::from_hex(id.as_str()).map_err(|err| err.to_string())?),
         None => None,
     };
-    let notes = client.get_consumable_notes(account_id).await?;
+    let notes = client.get_consumable_notes(account_id, include_processing).await?;
     print_consumable_notes_summary(&notes)?;
     Ok(())
 }
 
 // HELPERS
 // ================================================================================================
-fn print_notes_summary<I>(notes: I, header: &str) -> Result<(), String>
+/// Prints a line per note. If `with_blocks` is set, the expected height of each note and the
+/// blocks in which it was committed and consumed are printed too.
+fn print_notes_summary<I>(notes: I, header: &str, with_blocks: bool) -> Result<(), String>
 where
     I: IntoIterator<Item = CliNoteSummary>,
 {
@@ -281,12 +368,37 @@ where
     println!("\n{table}");
 
     for summary in notes {
-        println!(" {} {}", summary.id, summary.state);
+        if with_blocks {
+            println!(
+                " {} {} (aux: {}, expected height: {}, committed at: {}, consumed at: {})",
+                summary.id,
+                summary.state,
+                summary.aux,
+                summary.expected_height,
+                summary.committed_at_block,
+                summary.consumed_at_block
+            );
+        } else {
+            println!(" {} {} (aux: {})", summary.id, summary.state, summary.aux);
+        }
     }
 
     Ok(())
 }
 
+fn print_pending_notes_summary(notes: &[PendingNoteRecord]) {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_NO_BORDERS)
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth);
+    table.set_header(vec![Cell::new("Pending Notes").add_attribute(Attribute::Bold)]);
+    println!("\n{table}");
+
+    for note in notes {
+        println!(" {} {}", note.note_id().to_hex(), note);
+    }
+}
+
 fn print_consumable_notes_summary<'a, I>(notes: I) -> Result<(), String>
 where
     I: IntoIterator<Item = &'a (InputNoteRecord, Vec<NoteConsumability>)>,
@@ -363,9 +475,9 @@ fn note_summary(
             .or(output_note_record.map(|record| record.metadata())),
     );
 
-    let state = input_note_record
-        .map(|record| record.state().to_string())
-        .or(output_note_record.map(|record| record.state().to_string()))
+    let (state_code, state_details) = input_note_record
+        .map(|record| (record.state().code(), record.state().to_string()))
+        .or(output_note_record.map(|record| (record.state().code(), record.state().to_string())))
         .expect("One of the two records should be Some");
 
     let note_metadata = input_note_record
@@ -377,10 +489,21 @@ fn note_summary(
         .map(|metadata| metadata.tag().to_string())
         .unwrap_or("-".to_string());
 
+    let note_aux_str = note_metadata
+        .map(|metadata| metadata.aux().to_string())
+        .unwrap_or("-".to_string());
+
     let note_sender_str = note_metadata
         .map(|metadata| metadata.sender().to_string())
         .unwrap_or("-".to_string());
 
+    let created_at = input_note_record
+        .map(|record| record.created_at())
+        .or(output_note_record.map(|record| record.created_at()))
+        .expect("One of the two records should be Some");
+
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
     Ok(CliNoteSummary {
         id: note_id.inner().to_string(),
         script_hash: script_hash_str,
@@ -388,9 +511,30 @@ fn note_summary(
         inputs_commitment: inputs_commitment_str,
         serial_num,
         note_type,
-        state,
+        state: state_code.human_label().to_string(),
+        state_details,
         tag: note_tag_str,
+        aux: note_aux_str,
         sender: note_sender_str,
         exportable: output_note_record.is_some(),
+        created_at: or_dash(created_at.map(|timestamp| timestamp.to_string())),
+        expected_height: or_dash(
+            output_note_record.map(|record| record.expected_height().to_string()),
+        ),
+        committed_at_block: or_dash(
+            output_note_record
+                .and_then(OutputNoteRecord::committed_at_block)
+                .map(|block_num| block_num.to_string()),
+        ),
+        committed_timestamp: or_dash(
+            output_note_record
+                .and_then(OutputNoteRecord::committed_timestamp)
+                .map(|timestamp| timestamp.to_string()),
+        ),
+        consumed_at_block: or_dash(
+            output_note_record
+                .and_then(OutputNoteRecord::consumed_at_block)
+                .map(|block_num| block_num.to_string()),
+        ),
     })
 }
@@ -0,0 +1,77 @@
+use clap::{Parser, ValueEnum};
+use miden_client::{
+    crypto::FeltRng,
+    recovery::{DerivedCategory, RebuildReport},
+    Client,
+};
+
+use crate::create_dynamic_table;
+
+// STORE COMMAND
+// ================================================================================================
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum DerivedCategoryArg {
+    AccountTags,
+    NoteTags,
+    ChainMmr,
+}
+
+impl From<&DerivedCategoryArg> for DerivedCategory {
+    fn from(category: &DerivedCategoryArg) -> Self {
+        match category {
+            DerivedCategoryArg::AccountTags => DerivedCategory::AccountTags,
+            DerivedCategoryArg::NoteTags => DerivedCategory::NoteTags,
+            DerivedCategoryArg::ChainMmr => DerivedCategory::ChainMmr,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Parser)]
+/// Manage the client's store.
+pub enum StoreCmd {
+    /// Rebuild the data derived from the store's primary records, like the tracked tags and the
+    /// partial chain MMR
+    Rebuild {
+        /// Category to rebuild. Can be repeated; every category is rebuilt if none is specified
+        #[clap(short, long, value_enum)]
+        category: Vec<DerivedCategoryArg>,
+    },
+}
+
+impl StoreCmd {
+    pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
+        match self {
+            StoreCmd::Rebuild { category } => {
+                let categories: Vec<DerivedCategory> = if category.is_empty() {
+                    DerivedCategory::ALL.to_vec()
+                } else {
+                    category.iter().map(DerivedCategory::from).collect()
+                };
+
+                let report = client.rebuild_derived_state(&categories).await;
+                print_rebuild_report(&report);
+
+                match report.failed_categories().count() {
+                    0 => Ok(()),
+                    failed => Err(format!("{failed} categories failed to rebuild")),
+                }
+            },
+        }
+    }
+}
+
+fn print_rebuild_report(report: &RebuildReport) {
+    let mut table = create_dynamic_table(&["Category", "Records Repaired"]);
+
+    for category_report in report.categories.iter() {
+        let repaired = match &category_report.result {
+            Ok(repaired) => repaired.to_string(),
+            Err(err) => format!("failed: {err}"),
+        };
+
+        table.add_row(vec![category_report.category.to_string(), repaired]);
+    }
+
+    println!("{table}");
+}
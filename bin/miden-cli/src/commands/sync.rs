@@ -1,13 +1,57 @@
+use std::io;
+
 use clap::Parser;
-use miden_client::{crypto::FeltRng, Client};
+use miden_client::{crypto::FeltRng, Client, ClientError};
 
 #[derive(Debug, Parser, Clone)]
 #[clap(about = "Sync this client with the latest state of the Miden network.")]
-pub struct SyncCmd {}
+pub struct SyncCmd {
+    /// Wipe the chain data of the client before syncing, so that it can sync against a node whose
+    /// chain was reset. Tracked notes go back to being expected and transactions are discarded.
+    #[clap(long, default_value_t = false)]
+    reset_chain: bool,
+
+    /// Remove the accounts and their keys along with the chain data. Only used with
+    /// `--reset-chain`.
+    #[clap(long, default_value_t = false, requires = "reset_chain")]
+    discard_accounts: bool,
+
+    /// Flag to reset the chain data without asking for confirmation
+    #[clap(long, default_value_t = false, requires = "reset_chain")]
+    force: bool,
+}
 
 impl SyncCmd {
     pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
-        let new_details = client.sync_state().await?;
+        if self.reset_chain {
+            if !self.force {
+                let accounts = if self.discard_accounts {
+                    "Accounts and their keys will be removed too."
+                } else {
+                    "Accounts and their keys will be kept."
+                };
+                println!("\nThis will wipe the client's chain data, revert its notes to expected and discard its transactions. {accounts} Continue? (Y/N)");
+                let mut proceed_str: String = String::new();
+                io::stdin().read_line(&mut proceed_str).expect("Should read line");
+
+                if proceed_str.trim().to_lowercase() != "y" {
+                    println!("Chain reset was cancelled.");
+                    return Ok(());
+                }
+            }
+
+            client.reset_chain_state(!self.discard_accounts).await?;
+            println!("Chain data was reset, syncing from the genesis block...");
+        }
+
+        let new_details = match client.sync_state().await {
+            Err(err @ ClientError::ChainBehindLocalState { .. }) => {
+                return Err(format!(
+                    "{err}. If the node was reset, run `miden sync --reset-chain` to sync against the new chain."
+                ));
+            },
+            result => result?,
+        };
 
         println!("State synced to block {}", new_details.block_num);
         println!("New public notes: {}", new_details.received_notes.len());
@@ -15,6 +59,24 @@ impl SyncCmd {
         println!("Tracked notes consumed: {}", new_details.consumed_notes.len());
         println!("Tracked accounts updated: {}", new_details.updated_accounts.len());
         println!("Commited transactions: {}", new_details.committed_transactions.len());
+        println!(
+            "Note inclusions screened: {} across {} tags",
+            new_details.screened_notes_per_tag.values().sum::<usize>(),
+            new_details.screened_notes_per_tag.len()
+        );
+        let traffic = new_details.total_rpc_traffic();
+        if traffic.total_bytes() > 0 {
+            println!(
+                "RPC traffic: {} bytes sent, {} bytes received",
+                traffic.request_bytes, traffic.response_bytes
+            );
+        }
+        for resolution in new_details.future_note_resolutions.iter() {
+            println!("{resolution}");
+        }
+        for warning in new_details.warnings.iter() {
+            println!("Warning: {warning}");
+        }
         Ok(())
     }
 }
@@ -1,5 +1,11 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
 use miden_client::{
-    crypto::FeltRng, store::TransactionFilter, transactions::TransactionRecord, Client,
+    crypto::FeltRng, ids::normalize_id_prefix, store::TransactionFilter,
+    transactions::TransactionRecord, Client,
 };
 
 use crate::{create_dynamic_table, Parser};
@@ -10,11 +16,40 @@ pub struct TransactionCmd {
     /// List currently tracked transactions
     #[clap(short, long, group = "action")]
     list: bool,
+
+    /// Show details of the transaction for the specified ID or ID prefix, including how it was
+    /// submitted to the network
+    #[clap(short, long, group = "action", value_name = "ID")]
+    show: Option<String>,
+
+    /// Export the proof of the submitted transaction for the specified ID or ID prefix, so that
+    /// it can be verified independently. Proofs are only kept if `retain_proven_transactions` is
+    /// set in the client config
+    #[clap(long, group = "action", value_name = "ID", requires = "output")]
+    export_proof: Option<String>,
+
+    /// File the exported proof is written to
+    #[clap(short, long, value_name = "FILE", requires = "export_proof")]
+    output: Option<PathBuf>,
+
+    /// Discard the pending local transaction for the specified ID or ID prefix, reverting its
+    /// account and notes. Only transactions that never reached the node can be discarded
+    #[clap(long, group = "action", value_name = "ID")]
+    discard: Option<String>,
+
+    /// Flag to discard the transaction without asking for confirmation
+    #[clap(long, default_value_t = false, requires = "discard")]
+    force: bool,
 }
 
 impl TransactionCmd {
     pub async fn execute(&self, client: Client<impl FeltRng>) -> Result<(), String> {
-        list_transactions(client).await?;
+        match (&self.show, &self.export_proof, &self.output, &self.discard) {
+            (Some(id), ..) => show_transaction(client, id).await?,
+            (_, Some(id), Some(output), _) => export_proof(client, id, output).await?,
+            (.., Some(id)) => discard_transaction(client, id, self.force).await?,
+            _ => list_transactions(client).await?,
+        }
         Ok(())
     }
 }
@@ -27,8 +62,115 @@ async fn list_transactions(client: Client<impl FeltRng>) -> Result<(), String> {
     Ok(())
 }
 
+// SHOW TRANSACTION
+// ================================================================================================
+async fn show_transaction(client: Client<impl FeltRng>, id_prefix: &str) -> Result<(), String> {
+    let tx = find_transaction(&client, id_prefix).await?;
+
+    print_transactions_summary([&tx]);
+
+    let mut table = create_dynamic_table(&["Provenance", ""]);
+    match tx.provenance() {
+        Some(provenance) => {
+            table.add_row(vec!["RPC Endpoint", provenance.rpc_endpoint.as_str()]);
+            table.add_row(vec!["Client Version", provenance.client_version.as_str()]);
+            table.add_row(vec!["Prover", provenance.prover.as_deref().unwrap_or("-")]);
+            table.add_row(vec![
+                "Submission Attempts".to_string(),
+                provenance.submission_attempts.to_string(),
+            ]);
+            table.add_row(vec![
+                "Submitted On First Attempt",
+                if provenance.submitted_on_first_attempt() {
+                    "yes"
+                } else {
+                    "no"
+                },
+            ]);
+        },
+        None => {
+            table.add_row(vec!["-", "Not recorded for this transaction"]);
+        },
+    }
+    if let Some(cause) = tx.discard_cause() {
+        table.add_row(vec!["Discard Cause".to_string(), cause.to_string()]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+// EXPORT PROOF
+// ================================================================================================
+async fn export_proof(
+    client: Client<impl FeltRng>,
+    id_prefix: &str,
+    output: &Path,
+) -> Result<(), String> {
+    let tx = find_transaction(&client, id_prefix).await?;
+
+    let proven_transaction = client.get_proven_transaction(tx.id).await?.ok_or(format!(
+        "The proof of transaction {} is not retained. Set `retain_proven_transactions = true` in \
+         the client config before submitting transactions to keep their proofs",
+        tx.id
+    ))?;
+    fs::write(output, proven_transaction).map_err(|err| err.to_string())?;
+
+    println!("Proof of transaction {} exported to {}", tx.id, output.display());
+    Ok(())
+}
+
+// DISCARD TRANSACTION
+// ================================================================================================
+async fn discard_transaction(
+    mut client: Client<impl FeltRng>,
+    id_prefix: &str,
+    force: bool,
+) -> Result<(), String> {
+    let tx = find_transaction(&client, id_prefix).await?;
+
+    if !force {
+        println!(
+            "\nThis will revert account {} to its state before transaction {}, revert the notes it \
+             consumed and remove the notes it created. Continue? (Y/N)",
+            tx.account_id, tx.id
+        );
+        let mut proceed_str: String = String::new();
+        io::stdin().read_line(&mut proceed_str).expect("Should read line");
+
+        if proceed_str.trim().to_lowercase() != "y" {
+            println!("Transaction discard was cancelled.");
+            return Ok(());
+        }
+    }
+
+    client.discard_local_transaction(tx.id).await?;
+
+    println!("Transaction {} was discarded", tx.id);
+    Ok(())
+}
+
 // HELPERS
 // ================================================================================================
+async fn find_transaction(
+    client: &Client<impl FeltRng>,
+    id_prefix: &str,
+) -> Result<TransactionRecord, String> {
+    let prefix = normalize_id_prefix(id_prefix)
+        .ok_or(format!("{id_prefix} is not a valid transaction ID prefix"))?;
+
+    let transactions = client.get_transactions(TransactionFilter::All).await?;
+    let mut matches = transactions.into_iter().filter(|tx| tx.id.to_hex().starts_with(&prefix));
+
+    match (matches.next(), matches.next()) {
+        (Some(tx), None) => Ok(tx),
+        (None, _) => Err(format!("No transaction found with ID prefix {id_prefix}")),
+        (Some(_), Some(_)) => {
+            Err(format!("More than one transaction found with ID prefix {id_prefix}"))
+        },
+    }
+}
+
 fn print_transactions_summary<'a, I>(executed_transactions: I)
 where
     I: IntoIterator<Item = &'a TransactionRecord>,
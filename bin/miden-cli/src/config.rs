@@ -1,5 +1,8 @@
 use core::fmt::Debug;
-use std::path::{Path, PathBuf};
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+};
 
 use figment::{
     value::{Dict, Map},
@@ -13,6 +16,9 @@ use serde::{Deserialize, Serialize};
 
 const TOKEN_SYMBOL_MAP_FILEPATH: &str = "token_symbol_map.toml";
 
+/// Port the `daemon` command listens on by default.
+const DEFAULT_DAEMON_PORT: u16 = 57293;
+
 // CLI CONFIG
 // ================================================================================================
 
@@ -28,6 +34,13 @@ pub struct CliConfig {
     pub token_symbol_map_filepath: PathBuf,
     /// RPC endpoint for the proving service. If this is not present, a local prover will be used.
     pub remote_prover_endpoint: Option<Endpoint>,
+    /// Whether the proofs of submitted transactions are kept in the store, so that they can be
+    /// exported with `miden tx --export-proof`.
+    #[serde(default)]
+    pub retain_proven_transactions: bool,
+    /// Describes settings related to the `daemon` command.
+    #[serde(default)]
+    pub daemon: DaemonConfig,
 }
 
 // Make `ClientConfig` a provider itself for composability.
@@ -54,6 +67,30 @@ impl Default for CliConfig {
             default_account_id: None,
             token_symbol_map_filepath: Path::new(TOKEN_SYMBOL_MAP_FILEPATH).to_path_buf(),
             remote_prover_endpoint: None,
+            retain_proven_transactions: false,
+            daemon: DaemonConfig::default(),
+        }
+    }
+}
+
+// DAEMON CONFIG
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DaemonConfig {
+    /// Address the daemon listens on. Only loopback addresses are accepted unless the daemon is
+    /// started with `--allow-remote`.
+    pub bind_address: SocketAddr,
+    /// Token that requests to the daemon must present as `Authorization: Bearer <token>`. The
+    /// daemon doesn't start if it's not set.
+    pub auth_token: Option<String>,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_DAEMON_PORT)),
+            auth_token: None,
         }
     }
 }
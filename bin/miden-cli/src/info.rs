@@ -9,6 +9,7 @@ pub async fn print_client_info(
     config: &CliConfig,
 ) -> Result<(), String> {
     println!("Client version: {}", env!("CARGO_PKG_VERSION"));
+    println!("Proving backend: {}", client.proving_backend_info());
     print_config_stats(config)?;
     print_client_stats(client).await
 }
@@ -29,6 +30,11 @@ async fn print_client_stats(client: &Client<impl FeltRng>) -> Result<(), String>
             .map_err(|e| e.to_string())?
             .len()
     );
+    let note_storage_stats = client.get_note_storage_stats().await.map_err(|e| e.to_string())?;
+    println!(
+        "Input notes: {} active, {} archived",
+        note_storage_stats.active_input_notes, note_storage_stats.archived_input_notes
+    );
     Ok(())
 }
 
@@ -2,28 +2,31 @@ use std::{env, sync::Arc};
 
 use clap::Parser;
 use comfy_table::{presets, Attribute, Cell, ContentArrangement, Table};
+#[cfg(feature = "local-prover")]
+use miden_client::transactions::LocalTransactionProver;
 use miden_client::{
-    accounts::AccountHeader,
-    crypto::{FeltRng, RpoRandomCoin},
+    crypto::RpoRandomCoin,
     rpc::TonicRpcClient,
-    store::{
-        sqlite_store::SqliteStore, NoteFilter as ClientNoteFilter, OutputNoteRecord, Store,
-        StoreAuthenticator,
-    },
-    transactions::{LocalTransactionProver, TransactionProver},
-    Client, ClientError, Felt, IdPrefixFetchError,
+    store::{sqlite_store::SqliteStore, Store, StoreAuthenticator},
+    transactions::TransactionProver,
+    Client, ClientError, Felt,
 };
 use miden_tx_prover::RemoteTransactionProver;
 use rand::Rng;
 mod commands;
+#[cfg(feature = "daemon")]
+use commands::daemon::DaemonCmd;
 use commands::{
     account::AccountCmd,
     export::ExportCmd,
+    faucet::FaucetCmd,
     import::ImportCmd,
     init::InitCmd,
+    maintain::MaintainCmd,
     new_account::{NewFaucetCmd, NewWalletCmd},
     new_transactions::{ConsumeNotesCmd, MintCmd, SendCmd, SwapCmd},
     notes::NotesCmd,
+    store::StoreCmd,
     sync::SyncCmd,
     tags::TagsCmd,
     transactions::TransactionCmd,
@@ -60,6 +63,8 @@ pub struct Cli {
 pub enum Command {
     Account(AccountCmd),
     NewFaucet(NewFaucetCmd),
+    #[clap(subcommand)]
+    Faucet(FaucetCmd),
     NewWallet(NewWalletCmd),
     Import(ImportCmd),
     Export(ExportCmd),
@@ -75,6 +80,11 @@ pub enum Command {
     Send(SendCmd),
     Swap(SwapCmd),
     ConsumeNotes(ConsumeNotesCmd),
+    Maintain(MaintainCmd),
+    #[clap(subcommand)]
+    Store(StoreCmd),
+    #[cfg(feature = "daemon")]
+    Daemon(DaemonCmd),
 }
 
 /// CLI entry point
@@ -110,24 +120,42 @@ impl Cli {
         let rng = RpoRandomCoin::new(coin_seed.map(Felt::new));
         let authenticator = StoreAuthenticator::new_with_rng(store.clone() as Arc<dyn Store>, rng);
 
-        let tx_prover: Arc<dyn TransactionProver> = match &cli_config.remote_prover_endpoint {
-            Some(proving_url) => Arc::new(RemoteTransactionProver::new(&proving_url.to_string())),
-            None => Arc::new(LocalTransactionProver::new(Default::default())),
-        };
-
-        let client = Client::new(
+        let (tx_prover, prover_description): (Arc<dyn TransactionProver>, String) =
+            match &cli_config.remote_prover_endpoint {
+                Some(proving_url) => (
+                    Arc::new(RemoteTransactionProver::new(&proving_url.to_string())),
+                    format!("remote ({proving_url})"),
+                ),
+                #[cfg(feature = "local-prover")]
+                None => {
+                    (Arc::new(LocalTransactionProver::new(Default::default())), "local".to_string())
+                },
+                #[cfg(not(feature = "local-prover"))]
+                None => {
+                    return Err(format!(
+                        "{}. Set `remote_prover_endpoint` in {CLIENT_CONFIG_FILE_NAME} to prove \
+                         transactions remotely.",
+                        ClientError::LocalProverUnavailable
+                    ));
+                },
+            };
+
+        let mut client = Client::new(
             Box::new(TonicRpcClient::new(&cli_config.rpc)),
             rng,
             store as Arc<dyn Store>,
             Arc::new(authenticator),
             tx_prover as Arc<dyn TransactionProver>,
             in_debug_mode,
-        );
+        )
+        .with_prover_description(prover_description);
+        client.set_retain_proven_transactions(cli_config.retain_proven_transactions);
 
         // Execute CLI command
         match &self.action {
             Command::Account(account) => account.execute(client).await,
             Command::NewFaucet(new_faucet) => new_faucet.execute(client).await,
+            Command::Faucet(faucet) => faucet.execute(client).await,
             Command::NewWallet(new_wallet) => new_wallet.execute(client).await,
             Command::Import(import) => import.execute(client).await,
             Command::Init(_) => Ok(()),
@@ -141,6 +169,10 @@ impl Cli {
             Command::Send(send) => send.execute(client).await,
             Command::Swap(swap) => swap.execute(client).await,
             Command::ConsumeNotes(consume_notes) => consume_notes.execute(client).await,
+            Command::Maintain(maintain) => maintain.execute(client).await,
+            Command::Store(store_cmd) => store_cmd.execute(client).await,
+            #[cfg(feature = "daemon")]
+            Command::Daemon(daemon) => daemon.execute(client, &cli_config.daemon).await,
         }
     }
 }
@@ -159,98 +191,3 @@ pub fn create_dynamic_table(headers: &[&str]) -> Table {
 
     table
 }
-
-/// Returns the client output note whose ID starts with `note_id_prefix`
-///
-/// # Errors
-///
-/// - Returns [IdPrefixFetchError::NoMatch] if we were unable to find any note where
-///   `note_id_prefix` is a prefix of its id.
-/// - Returns [IdPrefixFetchError::MultipleMatches] if there were more than one note found where
-///   `note_id_prefix` is a prefix of its id.
-pub(crate) async fn get_output_note_with_id_prefix(
-    client: &Client<impl FeltRng>,
-    note_id_prefix: &str,
-) -> Result<OutputNoteRecord, IdPrefixFetchError> {
-    let mut output_note_records = client
-        .get_output_notes(ClientNoteFilter::All)
-        .await
-        .map_err(|err| {
-            tracing::error!("Error when fetching all notes from the store: {err}");
-            IdPrefixFetchError::NoMatch(format!("note ID prefix {note_id_prefix}").to_string())
-        })?
-        .into_iter()
-        .filter(|note_record| note_record.id().to_hex().starts_with(note_id_prefix))
-        .collect::<Vec<_>>();
-
-    if output_note_records.is_empty() {
-        return Err(IdPrefixFetchError::NoMatch(
-            format!("note ID prefix {note_id_prefix}").to_string(),
-        ));
-    }
-    if output_note_records.len() > 1 {
-        let output_note_record_ids = output_note_records
-            .iter()
-            .map(|input_note_record| input_note_record.id())
-            .collect::<Vec<_>>();
-        tracing::error!(
-            "Multiple notes found for the prefix {}: {:?}",
-            note_id_prefix,
-            output_note_record_ids
-        );
-        return Err(IdPrefixFetchError::MultipleMatches(
-            format!("note ID prefix {note_id_prefix}").to_string(),
-        ));
-    }
-
-    Ok(output_note_records
-        .pop()
-        .expect("input_note_records should always have one element"))
-}
-
-/// Returns the client account whose ID starts with `account_id_prefix`
-///
-/// # Errors
-///
-/// - Returns [IdPrefixFetchError::NoMatch] if we were unable to find any account where
-///   `account_id_prefix` is a prefix of its id.
-/// - Returns [IdPrefixFetchError::MultipleMatches] if there were more than one account found where
-///   `account_id_prefix` is a prefix of its id.
-async fn get_account_with_id_prefix(
-    client: &Client<impl FeltRng>,
-    account_id_prefix: &str,
-) -> Result<AccountHeader, IdPrefixFetchError> {
-    let mut accounts = client
-        .get_account_headers()
-        .await
-        .map_err(|err| {
-            tracing::error!("Error when fetching all accounts from the store: {err}");
-            IdPrefixFetchError::NoMatch(
-                format!("account ID prefix {account_id_prefix}").to_string(),
-            )
-        })?
-        .into_iter()
-        .filter(|(account_header, _)| account_header.id().to_hex().starts_with(account_id_prefix))
-        .map(|(acc, _)| acc)
-        .collect::<Vec<_>>();
-
-    if accounts.is_empty() {
-        return Err(IdPrefixFetchError::NoMatch(
-            format!("account ID prefix {account_id_prefix}").to_string(),
-        ));
-    }
-    if accounts.len() > 1 {
-        let account_ids =
-            accounts.iter().map(|account_header| account_header.id()).collect::<Vec<_>>();
-        tracing::error!(
-            "Multiple accounts found for the prefix {}: {:?}",
-            account_id_prefix,
-            account_ids
-        );
-        return Err(IdPrefixFetchError::MultipleMatches(
-            format!("account ID prefix {account_id_prefix}").to_string(),
-        ));
-    }
-
-    Ok(accounts.pop().expect("account_ids should always have one element"))
-}
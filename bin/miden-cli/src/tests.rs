@@ -1,9 +1,12 @@
 use std::{
     env::{self, temp_dir},
     fs::File,
-    io::Read,
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
     path::Path,
+    process::Child,
     sync::Arc,
+    time::{Duration, Instant},
 };
 
 use assert_cmd::Command;
@@ -21,6 +24,7 @@ use miden_client::{
     Client, Felt,
 };
 use rand::Rng;
+use serde_json::{json, Value};
 use uuid::Uuid;
 
 /// CLI TESTS
@@ -423,6 +427,151 @@ async fn test_cli_export_import_account() {
     ));
 }
 
+// DAEMON TESTS
+// ================================================================================================
+
+const DAEMON_AUTH_TOKEN: &str = "test-daemon-token";
+
+/// Maximum time to wait for the daemon to start listening.
+const DAEMON_START_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Maximum time to wait for the response to a request to the daemon, which may prove a
+/// transaction.
+const DAEMON_REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Maximum time to wait for a note to reach the expected state through the daemon's syncs.
+const DAEMON_SYNC_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Kills the daemon process when dropped, so that it doesn't outlive a failing test.
+struct DaemonGuard(Child);
+
+impl Drop for DaemonGuard {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+// This tests that a mint and a consume can be driven through the daemon's JSON-RPC interface. To
+// do so it:
+//
+// 1. Creates a client with a faucet and a wallet, and starts a daemon for it
+// 2. Checks that requests with invalid tokens, methods or params get structured errors
+// 3. Builds and submits a mint transaction and syncs until its note is committed
+// 4. Builds and submits a transaction consuming the note and syncs until it's consumed
+#[tokio::test]
+async fn test_daemon_mint_and_consume() {
+    let store_path = create_test_store_path();
+    let mut temp_dir = temp_dir();
+    temp_dir.push(format!("{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir(temp_dir.clone()).unwrap();
+
+    let mut init_cmd = Command::cargo_bin("miden").unwrap();
+    init_cmd.args(["init", "--store-path", store_path.to_str().unwrap()]);
+    init_cmd.current_dir(&temp_dir).assert().success();
+
+    let mut create_faucet_cmd = Command::cargo_bin("miden").unwrap();
+    create_faucet_cmd.args([
+        "new-faucet",
+        "-s",
+        "private",
+        "-t",
+        "BTC",
+        "-d",
+        "8",
+        "-m",
+        "1000000000000",
+    ]);
+    create_faucet_cmd.current_dir(&temp_dir).assert().success();
+
+    let mut create_wallet_cmd = Command::cargo_bin("miden").unwrap();
+    create_wallet_cmd.args(["new-wallet", "-s", "private"]);
+    create_wallet_cmd.current_dir(&temp_dir).assert().success();
+
+    let (faucet_id, wallet_id) = {
+        let client = create_test_client_with_store_path(&store_path).await;
+        let accounts = client.get_account_headers().await.unwrap();
+        let faucet = accounts.iter().find(|(header, _)| header.id().is_faucet()).unwrap();
+        let wallet = accounts.iter().find(|(header, _)| !header.id().is_faucet()).unwrap();
+
+        (faucet.0.id().to_hex(), wallet.0.id().to_hex())
+    };
+
+    // Configure the daemon on a free port
+    let address = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap()
+    };
+    let mut config_path = temp_dir.clone();
+    config_path.push("miden-client.toml");
+    let mut config: toml::Table = std::fs::read_to_string(&config_path).unwrap().parse().unwrap();
+    let mut daemon_config = toml::Table::new();
+    daemon_config.insert("bind_address".into(), address.to_string().into());
+    daemon_config.insert("auth_token".into(), DAEMON_AUTH_TOKEN.into());
+    config.insert("daemon".into(), daemon_config.into());
+    std::fs::write(&config_path, config.to_string()).unwrap();
+
+    let _daemon = DaemonGuard(
+        std::process::Command::new(assert_cmd::cargo::cargo_bin("miden"))
+            .arg("daemon")
+            .current_dir(&temp_dir)
+            .spawn()
+            .unwrap(),
+    );
+    let started = Instant::now();
+    while TcpStream::connect(address).is_err() {
+        assert!(
+            started.elapsed() < DAEMON_START_TIMEOUT,
+            "the daemon didn't start listening on {address}"
+        );
+        std::thread::sleep(Duration::from_millis(200));
+    }
+
+    // Invalid requests get structured errors
+    let response = daemon_request(address, "wrong-token", "sync", json!({}));
+    assert_eq!(response["error"]["code"], -32001);
+    let response = daemon_request(address, DAEMON_AUTH_TOKEN, "unknown", json!({}));
+    assert_eq!(response["error"]["code"], -32601);
+    let response = daemon_request(address, DAEMON_AUTH_TOKEN, "accounts.show", json!({}));
+    assert_eq!(response["error"]["code"], -32602);
+
+    let accounts = daemon_call(address, "accounts.list", json!({}));
+    assert_eq!(accounts.as_array().unwrap().len(), 2);
+
+    daemon_call(address, "sync", json!({}));
+
+    // Mint a note to the wallet
+    let mint = daemon_call(
+        address,
+        "tx.mint",
+        json!({
+            "target_account_id": wallet_id,
+            "asset": format!("100::{faucet_id}"),
+            "note_type": "private",
+        }),
+    );
+    let note_id = mint["created_notes"][0].as_str().unwrap().to_string();
+    daemon_call(address, "tx.submit", json!({ "transaction_id": mint["transaction_id"] }));
+    daemon_sync_until_note_listed(address, "committed", &note_id);
+
+    // Consume the note with the wallet
+    let consume = daemon_call(
+        address,
+        "tx.consume",
+        json!({ "account_id": wallet_id, "note_ids": [note_id] }),
+    );
+    assert_eq!(consume["consumed_notes"][0], note_id.as_str());
+    daemon_call(address, "tx.submit", json!({ "transaction_id": consume["transaction_id"] }));
+    daemon_sync_until_note_listed(address, "consumed", &note_id);
+    let notes = daemon_call(address, "notes.list", json!({ "filter": "consumed" }));
+    let consumed_note =
+        notes.as_array().unwrap().iter().find(|note| note["id"] == note_id).unwrap();
+    assert_eq!(consumed_note["state"], "consumed_local");
+
+    let wallet = daemon_call(address, "accounts.show", json!({ "account_id": wallet_id }));
+    assert_eq!(wallet["vault_assets"][0]["amount"], 100);
+}
+
 #[test]
 fn test_cli_empty_commands() {
     let store_path = create_test_store_path();
@@ -535,6 +684,52 @@ fn consume_note_cli(cli_path: &Path, account_id: &str, note_ids: &[&str]) {
     consume_note_cmd.current_dir(cli_path).assert().success();
 }
 
+/// Sends a JSON-RPC request to the daemon listening on `address` and returns the response.
+fn daemon_request(address: SocketAddr, token: &str, method: &str, params: Value) -> Value {
+    let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }).to_string();
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.set_read_timeout(Some(DAEMON_REQUEST_TIMEOUT)).unwrap();
+    write!(
+        stream,
+        "POST / HTTP/1.1\r\nHost: {address}\r\nAuthorization: Bearer {token}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+
+    serde_json::from_str(body).unwrap()
+}
+
+/// Calls a method of the daemon listening on `address` and returns its result, panicking if the
+/// call failed.
+fn daemon_call(address: SocketAddr, method: &str, params: Value) -> Value {
+    let response = daemon_request(address, DAEMON_AUTH_TOKEN, method, params);
+    assert!(response["error"].is_null(), "`{method}` failed: {}", response["error"]);
+
+    response["result"].clone()
+}
+
+/// Syncs the daemon listening on `address` until the note is listed with the provided filter,
+/// panicking if it isn't listed within [DAEMON_SYNC_TIMEOUT].
+fn daemon_sync_until_note_listed(address: SocketAddr, filter: &str, note_id: &str) {
+    let started = Instant::now();
+    loop {
+        daemon_call(address, "sync", json!({}));
+        let notes = daemon_call(address, "notes.list", json!({ "filter": filter }));
+        if notes.as_array().unwrap().iter().any(|note| note["id"] == note_id) {
+            break;
+        }
+        assert!(
+            started.elapsed() < DAEMON_SYNC_TIMEOUT,
+            "note {note_id} wasn't listed as {filter} after {DAEMON_SYNC_TIMEOUT:?}"
+        );
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
+
 pub fn create_test_store_path() -> std::path::PathBuf {
     let mut temp_file = temp_dir();
     temp_file.push(format!("{}.sqlite3", Uuid::new_v4()));
@@ -8,10 +8,10 @@ use figment::{
     providers::{Format, Toml},
     Figment,
 };
-use miden_client::{accounts::AccountId, crypto::FeltRng, Client};
+use miden_client::{accounts::AccountId, crypto::FeltRng, Client, IdPrefixFetchError};
 use tracing::info;
 
-use super::{config::CliConfig, get_account_with_id_prefix, CLIENT_CONFIG_FILE_NAME};
+use super::{config::CliConfig, CLIENT_CONFIG_FILE_NAME};
 use crate::faucet_details_map::FaucetDetailsMap;
 
 pub(crate) const SHARED_TOKEN_DOCUMENTATION: &str = "There are two accepted formats for the asset:
@@ -60,11 +60,13 @@ pub(crate) async fn parse_account_id(
         return Ok(account_id);
     }
 
-    let account_id = get_account_with_id_prefix(client, account_id)
-    .await
-    .map_err(|_err| format!("Input account ID {account_id} is neither a valid Account ID nor a prefix of a known Account ID"))?
-    .id();
-    Ok(account_id)
+    let account_id = client.find_account_by_prefix(account_id).await.map_err(|err| match err {
+        IdPrefixFetchError::NoMatch(_) => format!(
+            "Input account ID {account_id} is neither a valid Account ID nor a prefix of a known Account ID"
+        ),
+        err => err.to_string(),
+    })?;
+    Ok(account_id.id())
 }
 
 pub(crate) fn update_config(config_path: &Path, client_config: CliConfig) -> Result<(), String> {
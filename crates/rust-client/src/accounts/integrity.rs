@@ -0,0 +1,159 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_objects::{accounts::AccountId, crypto::rand::FeltRng, Digest};
+use tracing::warn;
+
+use crate::{
+    store::{StoreError, TransactionFilter},
+    transactions::{TransactionId, TransactionStatus},
+    Client, ClientError,
+};
+
+impl<R: FeltRng> Client<R> {
+    /// Checks that the stored states of an account are consistent with each other and with the
+    /// transactions that produced them, to detect store corruption before it makes a transaction
+    /// fail.
+    ///
+    /// The store keeps every state of the account instead of the deltas between them, so the
+    /// check replays the account's history one state at a time, starting from its initial state:
+    ///
+    /// - The commitment of each state is recomputed from its code, storage and vault, and compared
+    ///   with the commitment stored for it.
+    /// - For each pair of consecutive states, the local transaction executed against the first one
+    ///   must have the second one as its final state. Transitions without a local transaction, like
+    ///   updates of public accounts received from the node, can't be verified and are only counted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::StoreError] if the account is not tracked by the client or if its
+    /// stored data can't be read.
+    pub async fn verify_account_integrity(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountIntegrityReport, ClientError> {
+        let history = self.store.get_account_history(account_id).await?;
+        let (current_state, stored_hash) =
+            history.last().ok_or(StoreError::AccountDataNotFound(account_id))?;
+        let computed_hash = current_state.hash();
+        let stored_hash = *stored_hash;
+
+        // Discarded transactions don't produce a stored state, so they're not part of the history
+        let transactions_by_init_state: BTreeMap<Digest, (TransactionId, Digest)> = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|tx| {
+                tx.account_id == account_id && tx.transaction_status != TransactionStatus::Discarded
+            })
+            .map(|tx| (tx.init_account_state, (tx.id, tx.final_account_state)))
+            .collect();
+
+        let mut issues = vec![];
+        for (state, stored_hash) in history.iter() {
+            let computed_hash = state.hash();
+            if computed_hash != *stored_hash {
+                issues.push(AccountIntegrityIssue::CorruptedState {
+                    nonce: state.nonce().as_int(),
+                    stored_hash: *stored_hash,
+                    computed_hash,
+                });
+            }
+        }
+
+        let mut verified_transitions = 0;
+        let mut unverified_transitions = 0;
+        for pair in history.windows(2) {
+            let (previous_state, next_state) = (&pair[0].0, &pair[1].0);
+            match transactions_by_init_state.get(&previous_state.hash()) {
+                Some((transaction_id, final_state)) if *final_state != next_state.hash() => {
+                    issues.push(AccountIntegrityIssue::DivergentTransaction {
+                        transaction_id: *transaction_id,
+                        expected_hash: *final_state,
+                        stored_hash: next_state.hash(),
+                    });
+                },
+                Some(_) => verified_transitions += 1,
+                None => unverified_transitions += 1,
+            }
+        }
+
+        if !issues.is_empty() {
+            warn!("Found {} integrity issues in the stored states of {account_id}", issues.len());
+        }
+
+        Ok(AccountIntegrityReport {
+            account_id,
+            states_checked: history.len(),
+            verified_transitions,
+            unverified_transitions,
+            computed_hash,
+            stored_hash,
+            issues,
+        })
+    }
+}
+
+// ACCOUNT INTEGRITY REPORT
+// ================================================================================================
+
+/// Result of [Client::verify_account_integrity].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountIntegrityReport {
+    /// ID of the checked account.
+    pub account_id: AccountId,
+    /// Number of stored states of the account that were checked.
+    pub states_checked: usize,
+    /// Number of transitions between consecutive states that match a local transaction.
+    pub verified_transitions: usize,
+    /// Number of transitions between consecutive states that have no local transaction, like the
+    /// ones received from the node.
+    pub unverified_transitions: usize,
+    /// Commitment of the current state, recomputed from its stored data.
+    pub computed_hash: Digest,
+    /// Commitment stored for the current state.
+    pub stored_hash: Digest,
+    /// Problems found while replaying the history of the account, oldest first.
+    pub issues: Vec<AccountIntegrityIssue>,
+}
+
+impl AccountIntegrityReport {
+    /// Returns whether no problem was found in the stored states of the account.
+    pub fn is_intact(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns whether the commitment recomputed for the current state matches the stored one.
+    pub fn current_state_matches(&self) -> bool {
+        self.computed_hash == self.stored_hash
+    }
+
+    /// Returns the first transaction whose final state doesn't match the state stored after it.
+    pub fn first_divergent_transaction(&self) -> Option<TransactionId> {
+        self.issues.iter().find_map(|issue| match issue {
+            AccountIntegrityIssue::DivergentTransaction { transaction_id, .. } => {
+                Some(*transaction_id)
+            },
+            AccountIntegrityIssue::CorruptedState { .. } => None,
+        })
+    }
+}
+
+/// Problem found by [Client::verify_account_integrity].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountIntegrityIssue {
+    /// The stored code, storage and vault of the state with this nonce don't match the
+    /// commitment stored for it.
+    CorruptedState {
+        nonce: u64,
+        stored_hash: Digest,
+        computed_hash: Digest,
+    },
+    /// The state stored after the transaction was executed is not the final state the
+    /// transaction recorded.
+    DivergentTransaction {
+        transaction_id: TransactionId,
+        expected_hash: Digest,
+        stored_hash: Digest,
+    },
+}
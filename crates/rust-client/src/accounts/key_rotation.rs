@@ -0,0 +1,165 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::{AccountComponent, AccountId, AccountType, AuthSecretKey},
+    crypto::rand::FeltRng,
+    transaction::{InputNote, InputNotes, TransactionId},
+    Digest, Word,
+};
+use tracing::info;
+
+use super::AUTH_PUB_KEY_SLOT;
+use crate::{
+    store::TransactionFilter,
+    transactions::{prepare_word, TransactionRequest, TransactionStatus},
+    Client, ClientError,
+};
+
+// AUTH KEY ROTATION COMPONENT
+// ================================================================================================
+
+/// Account component that lets the account replace the public key of its
+/// [RpoFalcon512](miden_lib::accounts::auth::RpoFalcon512) authentication component.
+///
+/// It exports a single `set_auth_key` procedure, which writes the word on top of the stack to the
+/// storage slot of the public key, so it's only supported by accounts with updatable code. The
+/// procedure is invoked by the transaction built with
+/// [TransactionRequest::rotate_auth_key](crate::transactions::TransactionRequest::rotate_auth_key).
+///
+/// The procedure fails if the transaction consumes any note, so it can only be called by the
+/// transaction script, which is authenticated with the current key. Otherwise, any note sent to the
+/// account could replace its key.
+///
+/// The component is only included in the wallets created with
+/// [AccountTemplate::RotatableWallet](crate::accounts::AccountTemplate::RotatableWallet).
+pub struct AuthKeyRotation;
+
+impl AuthKeyRotation {
+    /// Returns the MAST root of the `set_auth_key` procedure.
+    pub fn set_auth_key_root() -> Digest {
+        AccountComponent::from(AuthKeyRotation)
+            .mast_forest()
+            .procedure_digests()
+            .next()
+            .expect("The component should export a procedure")
+    }
+}
+
+impl From<AuthKeyRotation> for AccountComponent {
+    fn from(_: AuthKeyRotation) -> Self {
+        let empty_notes_commitment = InputNotes::<InputNote>::new(Vec::new())
+            .expect("An empty list of input notes should be valid")
+            .commitment();
+
+        let code = format!(
+            "
+            export.set_auth_key
+                # make sure that no note is consumed by the transaction, so that the key can only
+                # be set by the transaction script
+                exec.::miden::tx::get_input_notes_commitment
+                push.{empty_notes_commitment}
+                assert_eqw
+
+                push.{AUTH_PUB_KEY_SLOT}
+                exec.::miden::account::set_item
+                dropw dropw
+            end
+            ",
+            empty_notes_commitment = prepare_word(&Word::from(empty_notes_commitment)),
+        );
+
+        AccountComponent::compile(code, TransactionKernel::assembler(), vec![])
+            .expect("The auth key rotation component should be well formed")
+            .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // AUTH KEY ROTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Replaces the key the account with the specified ID is authenticated with by `new_key`.
+    ///
+    /// A transaction built with [TransactionRequest::rotate_auth_key] is executed, signed with
+    /// the current key, and submitted. `new_key` is stored as a pending key before the
+    /// submission, and only replaces the current key in the client's keystore once the sync
+    /// marks the transaction as committed. If the transaction is discarded, the pending key is
+    /// dropped and the current key keeps being used. Until then, both keys are kept, so the
+    /// account can be authenticated whatever the outcome of the transaction.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [ClientError::AuthKeyRotationUnsupported] if the account's code can't be updated
+    ///   or doesn't include the [AuthKeyRotation] component.
+    /// - Returns an error if the transaction can't be executed, proven or submitted. The pending
+    ///   key is kept if the submission fails, since the transaction might still have reached the
+    ///   node.
+    pub async fn rotate_auth_key(
+        &mut self,
+        account_id: AccountId,
+        new_key: AuthSecretKey,
+    ) -> Result<(), ClientError> {
+        let (account, _seed) = self.store.get_account(account_id).await?;
+        if account.account_type() != AccountType::RegularAccountUpdatableCode
+            || !account.code().has_procedure(AuthKeyRotation::set_auth_key_root())
+        {
+            return Err(ClientError::AuthKeyRotationUnsupported(account_id));
+        }
+
+        let new_pub_key = match &new_key {
+            AuthSecretKey::RpoFalcon512(secret_key) => secret_key.public_key(),
+        };
+        let transaction_request = TransactionRequest::rotate_auth_key(new_pub_key)?;
+        let transaction = self.new_transaction(account_id, transaction_request).await?;
+
+        let transaction_id = transaction.executed_transaction().id();
+        self.store
+            .insert_pending_account_auth(transaction_id, account_id, &new_key)
+            .await?;
+
+        self.submit_transaction(transaction).await
+    }
+
+    /// Resolves the pending keys of the key rotations whose transactions are no longer pending.
+    ///
+    /// The keys of committed rotations replace the current keys of their accounts, and the keys of
+    /// discarded rotations are dropped. Rotations whose transactions are pending or not tracked by
+    /// the client keep their keys pending.
+    pub(crate) async fn resolve_pending_auth_keys(&mut self) -> Result<(), ClientError> {
+        let pending_auths = self.store.get_pending_account_auths().await?;
+        if pending_auths.is_empty() {
+            return Ok(());
+        }
+
+        let transaction_statuses: BTreeMap<TransactionId, TransactionStatus> = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .map(|transaction| (transaction.id, transaction.transaction_status))
+            .collect();
+
+        for (transaction_id, account_id) in pending_auths {
+            let committed = match transaction_statuses.get(&transaction_id) {
+                Some(TransactionStatus::Committed(_)) => true,
+                Some(TransactionStatus::Discarded) => false,
+                Some(TransactionStatus::Pending) | None => continue,
+            };
+
+            self.store.resolve_pending_account_auth(transaction_id, committed).await?;
+            if committed {
+                info!(
+                    "The key of account {account_id} was rotated by transaction {transaction_id}"
+                );
+            } else {
+                info!(
+                    "Key rotation transaction {transaction_id} of account {account_id} was \
+                     discarded, its key was dropped"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
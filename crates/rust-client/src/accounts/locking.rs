@@ -0,0 +1,314 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use miden_objects::{
+    accounts::{AccountData, AccountId},
+    crypto::rand::FeltRng,
+    transaction::TransactionId,
+    Digest,
+};
+use tracing::{info, warn};
+
+use crate::{
+    rpc::AccountDetails,
+    store::{StoreError, TransactionFilter},
+    transactions::TransactionStatus,
+    Client, ClientError,
+};
+
+// ACCOUNT LOCKING
+// ================================================================================================
+
+/// Lock status of a tracked account.
+///
+/// Private accounts get locked when the node reports a commitment that doesn't match any state
+/// known by the client, and public accounts when the node reports a diverging state that is not
+/// newer than the local one. Transactions can't be executed against locked accounts until they
+/// are unlocked with [Client::unlock_account].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountLockStatus {
+    /// The local state of the account is consistent with the node.
+    Unlocked,
+    /// The account was locked because the node reported the contained commitment.
+    Locked { remote_hash: Digest },
+    /// The divergence from the contained commitment was accepted with
+    /// [UnlockStrategy::ForceKeepLocal]. The account gets locked again if the node reports a
+    /// different commitment.
+    DivergenceAccepted { remote_hash: Digest },
+}
+
+impl AccountLockStatus {
+    /// Returns whether the account is locked.
+    pub fn is_locked(&self) -> bool {
+        matches!(self, AccountLockStatus::Locked { .. })
+    }
+}
+
+/// How the client reacts when the node reports a commitment for a private account that doesn't
+/// match any of its local states. Set with [Client::with_private_account_mismatch_policy].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrivateAccountMismatchPolicy {
+    /// Locks the account until it's unlocked with [Client::unlock_account].
+    #[default]
+    Lock,
+    /// Logs a warning and keeps using the local state, which is considered authoritative.
+    WarnKeepLocal,
+    /// Fails the sync with a [ClientError::PrivateAccountMismatch].
+    Error,
+}
+
+/// Strategy used to unlock a locked account.
+pub enum UnlockStrategy {
+    /// Fetches the account's state from the node and overwrites the local state with it. Only
+    /// available for public accounts.
+    AdoptRemote,
+    /// Overwrites the local state with the provided one, after validating that its commitment
+    /// matches the one reported by the node. Meant for private accounts whose up-to-date state
+    /// was exported from another client.
+    ImportState(AccountData),
+    /// Keeps the local state and accepts the divergence from the node's commitment.
+    ForceKeepLocal,
+}
+
+impl UnlockStrategy {
+    /// Returns the kind of the strategy, as recorded in the unlock audit entries.
+    pub fn kind(&self) -> UnlockStrategyKind {
+        match self {
+            UnlockStrategy::AdoptRemote => UnlockStrategyKind::AdoptRemote,
+            UnlockStrategy::ImportState(_) => UnlockStrategyKind::ImportState,
+            UnlockStrategy::ForceKeepLocal => UnlockStrategyKind::ForceKeepLocal,
+        }
+    }
+}
+
+/// Kind of an [UnlockStrategy], without the data needed to apply it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockStrategyKind {
+    AdoptRemote,
+    ImportState,
+    ForceKeepLocal,
+}
+
+impl fmt::Display for UnlockStrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnlockStrategyKind::AdoptRemote => write!(f, "AdoptRemote"),
+            UnlockStrategyKind::ImportState => write!(f, "ImportState"),
+            UnlockStrategyKind::ForceKeepLocal => write!(f, "ForceKeepLocal"),
+        }
+    }
+}
+
+impl FromStr for UnlockStrategyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AdoptRemote" => Ok(UnlockStrategyKind::AdoptRemote),
+            "ImportState" => Ok(UnlockStrategyKind::ImportState),
+            "ForceKeepLocal" => Ok(UnlockStrategyKind::ForceKeepLocal),
+            _ => Err(format!("invalid unlock strategy: {s}")),
+        }
+    }
+}
+
+/// Audit entry recorded every time an account is unlocked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUnlockRecord {
+    /// ID of the unlocked account.
+    pub account_id: AccountId,
+    /// Strategy used to unlock the account.
+    pub strategy: UnlockStrategyKind,
+    /// Commitment of the local state of the account before unlocking it.
+    pub local_hash: Digest,
+    /// Commitment of the account reported by the node when it was unlocked.
+    pub remote_hash: Digest,
+    /// Sync height of the client when the account was unlocked.
+    pub block_num: u32,
+}
+
+/// Report on the divergence between the local state of an account and the one known by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockDiagnosis {
+    /// ID of the diagnosed account.
+    pub account_id: AccountId,
+    /// Lock status of the account.
+    pub status: AccountLockStatus,
+    /// Commitment of the latest local state of the account.
+    pub local_hash: Digest,
+    /// Commitment of the account's state reported by the node.
+    pub remote_hash: Digest,
+    /// Transactions executed against the account that haven't been committed yet.
+    pub pending_transactions: Vec<TransactionId>,
+    /// Whether the pending transactions explain the divergence, which is the case when the node's
+    /// commitment matches a local state that the pending transactions build upon. Such a
+    /// divergence resolves itself once the transactions are committed.
+    pub explained_by_pending_transactions: bool,
+}
+
+impl<R: FeltRng> Client<R> {
+    // ACCOUNT LOCKING
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets how the client reacts when the node reports a commitment for a private account that
+    /// doesn't match any of its local states. By default, the account is locked.
+    pub fn with_private_account_mismatch_policy(
+        mut self,
+        policy: PrivateAccountMismatchPolicy,
+    ) -> Self {
+        self.private_account_mismatch_policy = policy;
+        self
+    }
+
+    /// Returns the lock status of the account with the specified ID.
+    pub async fn get_account_lock_status(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountLockStatus, ClientError> {
+        self.store.get_account_lock_status(account_id).await.map_err(|err| err.into())
+    }
+
+    /// Compares the local state of the account with the one known by the node and reports
+    /// whether any pending local transactions explain the divergence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account is not tracked by the client or if the node can't be
+    /// reached.
+    pub async fn diagnose_locked_account(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<LockDiagnosis, ClientError> {
+        let (local_header, _) = self.store.get_account_header(account_id).await?;
+        let status = self.store.get_account_lock_status(account_id).await?;
+        let remote_hash = self.rpc_api.get_account_update(account_id).await?.hash();
+
+        let pending_transactions: Vec<_> = self
+            .store
+            .get_transactions(TransactionFilter::Uncomitted)
+            .await?
+            .into_iter()
+            .filter(|tx| {
+                tx.account_id == account_id && tx.transaction_status == TransactionStatus::Pending
+            })
+            .collect();
+
+        let explained_by_pending_transactions =
+            pending_transactions.iter().any(|tx| tx.init_account_state == remote_hash);
+
+        Ok(LockDiagnosis {
+            account_id,
+            status,
+            local_hash: local_header.hash(),
+            remote_hash,
+            pending_transactions: pending_transactions.iter().map(|tx| tx.id).collect(),
+            explained_by_pending_transactions,
+        })
+    }
+
+    /// Unlocks a locked account with the provided strategy and records an audit entry for it.
+    ///
+    /// # Errors
+    ///
+    /// - Returns a [ClientError::AccountNotLocked] if the account is not locked.
+    /// - Returns a [ClientError::AccountUnlockError] if the strategy can't be applied to the
+    ///   account, like adopting the remote state of a private account or importing a state whose
+    ///   commitment doesn't match the node's.
+    pub async fn unlock_account(
+        &mut self,
+        account_id: AccountId,
+        strategy: UnlockStrategy,
+    ) -> Result<(), ClientError> {
+        if !self.store.get_account_lock_status(account_id).await?.is_locked() {
+            return Err(ClientError::AccountNotLocked(account_id));
+        }
+
+        let (local_header, _) = self.store.get_account_header(account_id).await?;
+        let account_details = self.rpc_api.get_account_update(account_id).await?;
+        let remote_hash = account_details.hash();
+
+        let new_state = match &strategy {
+            UnlockStrategy::AdoptRemote => match account_details {
+                AccountDetails::Public(account, _) => Some(account),
+                AccountDetails::Private(..) => {
+                    return Err(ClientError::AccountUnlockError(
+                        "the remote state of private accounts is not available".to_string(),
+                    ));
+                },
+            },
+            UnlockStrategy::ImportState(account_data) => {
+                let account = &account_data.account;
+                if account.id() != account_id {
+                    return Err(ClientError::AccountUnlockError(format!(
+                        "the provided state belongs to account {}",
+                        account.id()
+                    )));
+                }
+
+                if account.hash() != remote_hash {
+                    return Err(ClientError::AccountUnlockError(format!(
+                        "the provided state has commitment {} but the node reports {}",
+                        account.hash(),
+                        remote_hash
+                    )));
+                }
+
+                Some(account.clone())
+            },
+            UnlockStrategy::ForceKeepLocal => None,
+        };
+
+        let record = AccountUnlockRecord {
+            account_id,
+            strategy: strategy.kind(),
+            local_hash: local_header.hash(),
+            remote_hash,
+            block_num: self.store.get_sync_height().await?,
+        };
+
+        self.store.apply_account_unlock(record, new_state).await?;
+        info!("Account {} unlocked with strategy {}.", account_id, strategy.kind());
+
+        Ok(())
+    }
+
+    /// Returns the audit entries of the unlocks of the account with the specified ID, from the
+    /// oldest to the most recent.
+    pub async fn get_account_unlock_records(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountUnlockRecord>, ClientError> {
+        self.store
+            .get_account_unlock_records(account_id)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Locks the account because the node reported the specified commitment, unless the
+    /// divergence from that commitment was already accepted.
+    pub(crate) async fn lock_account(
+        &self,
+        account_id: AccountId,
+        remote_hash: Digest,
+    ) -> Result<(), StoreError> {
+        match self.store.get_account_lock_status(account_id).await? {
+            AccountLockStatus::DivergenceAccepted { remote_hash: accepted_hash }
+                if accepted_hash == remote_hash =>
+            {
+                Ok(())
+            },
+            _ => {
+                warn!(
+                    "Locking account {} because the node reported an unknown commitment: {}",
+                    account_id, remote_hash
+                );
+                self.store
+                    .set_account_lock_status(account_id, AccountLockStatus::Locked { remote_hash })
+                    .await
+            },
+        }
+    }
+}
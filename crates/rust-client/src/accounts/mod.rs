@@ -0,0 +1,800 @@
+//! The `accounts` module provides types and client APIs for managing accounts within the Miden
+//! rollup network .
+//!
+//! Accounts can be created or imported. Once they are tracked by the client, their state will be
+//! updated accordingly on every transaction, and validated against the rollup on every sync.
+
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+
+use miden_lib::{
+    accounts::{auth::RpoFalcon512, wallets::BasicWallet},
+    AuthScheme,
+};
+pub use miden_objects::accounts::{
+    Account, AccountCode, AccountData, AccountHeader, AccountId, AccountStorage,
+    AccountStorageMode, AccountType, StorageSlot, StorageSlotType,
+};
+use miden_objects::{
+    accounts::{AccountBuilder, AuthSecretKey},
+    assets::{Asset, TokenSymbol},
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::FeltRng},
+    transaction::{OutputNotes, TransactionId},
+    Felt, Word,
+};
+use tracing::warn;
+
+use super::Client;
+use crate::{
+    ids::normalize_id_prefix, rpc::AccountStorageSlotData, store::StoreError, ClientError,
+    IdPrefixFetchError,
+};
+
+/// Index of the storage slot where the authentication component of the accounts created by the
+/// client keeps the account's public key.
+const AUTH_PUB_KEY_SLOT: u8 = 0;
+
+mod integrity;
+mod key_rotation;
+mod locking;
+mod removal;
+mod report;
+mod watch_only;
+pub use integrity::{AccountIntegrityIssue, AccountIntegrityReport};
+pub use key_rotation::AuthKeyRotation;
+pub use locking::{
+    AccountLockStatus, AccountUnlockRecord, LockDiagnosis, PrivateAccountMismatchPolicy,
+    UnlockStrategy, UnlockStrategyKind,
+};
+pub use removal::{AccountRemoval, RemoveOptions};
+pub use report::{
+    AccountReport, AccountTypeReport, AssetReport, AuthSchemeReport, StorageSlotKind,
+    StorageSlotReport,
+};
+pub use watch_only::WatchOnlyAccountFile;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Index of the storage slot reserved for faucet data. For fungible faucets, the last element of
+/// the slot holds the total issuance of the faucet.
+const FAUCET_RESERVED_SLOT: u8 = 0;
+
+/// Index of the storage slot in which basic fungible faucets keep their metadata, laid out as
+/// `[max_supply, decimals, token_symbol, 0]`.
+const BASIC_FUNGIBLE_FAUCET_METADATA_SLOT: u8 = 2;
+
+/// Percentage of a faucet's max supply above which the client warns about the remaining supply.
+pub const ISSUANCE_WARNING_THRESHOLD_PERCENT: u8 = 90;
+
+/// Defines templates for creating different types of Miden accounts.
+pub enum AccountTemplate {
+    /// The `BasicWallet` variant represents a regular wallet account.
+    BasicWallet {
+        /// A boolean indicating whether the account's code can be modified after creation.
+        mutable_code: bool,
+        /// Specifies the type of storage used by the account. This is defined by the
+        /// `AccountStorageMode` enum.
+        storage_mode: AccountStorageMode,
+    },
+
+    /// The `RotatableWallet` variant represents a regular wallet account with mutable code that
+    /// also includes the [AuthKeyRotation] component, so its key can be replaced with
+    /// [Client::rotate_auth_key].
+    RotatableWallet {
+        /// Specifies the type of storage used by the account.
+        storage_mode: AccountStorageMode,
+    },
+
+    /// The `FungibleFaucet` variant represents an account designed to issue fungible tokens.
+    FungibleFaucet {
+        /// The symbol of the token being issued by the faucet.
+        token_symbol: TokenSymbol,
+        /// The number of decimal places used by the token.
+        decimals: u8,
+        /// The maximum supply of tokens that the faucet can issue.
+        max_supply: u64,
+        /// Specifies the type of storage used by the account.
+        storage_mode: AccountStorageMode,
+    },
+}
+
+impl<R: FeltRng> Client<R> {
+    // ACCOUNT CREATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [Account] based on an [AccountTemplate] and saves it in the client's store. A
+    /// new tag derived from the account will start being tracked by the client.
+    pub async fn new_account(
+        &mut self,
+        template: AccountTemplate,
+    ) -> Result<(Account, Word), ClientError> {
+        let account_and_seed = match template {
+            AccountTemplate::BasicWallet { mutable_code, storage_mode } => {
+                self.new_basic_wallet(mutable_code, storage_mode).await
+            },
+            AccountTemplate::RotatableWallet { storage_mode } => {
+                self.new_rotatable_wallet(storage_mode).await
+            },
+            AccountTemplate::FungibleFaucet {
+                token_symbol,
+                decimals,
+                max_supply,
+                storage_mode,
+            } => self.new_fungible_faucet(token_symbol, decimals, max_supply, storage_mode).await,
+        }?;
+
+        self.store.add_note_tag((&account_and_seed.0).try_into()?).await?;
+
+        Ok(account_and_seed)
+    }
+
+    /// Saves in the store the [Account] corresponding to `account_data`.
+    ///
+    /// If the account is public and not new, its state is checked against the commitment the node
+    /// has for it, so that stale states are not tracked by mistake. When `force` is set, the
+    /// account is imported even if the check fails, and a warning is logged instead.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if trying to import a new account without providing its seed, or if
+    /// `force` isn't set and the node's commitment for the public account doesn't match the
+    /// imported state or can't be retrieved.
+    pub async fn import_account(
+        &mut self,
+        account_data: AccountData,
+        force: bool,
+    ) -> Result<(), ClientError> {
+        let account_seed = if !account_data.account.is_new() && account_data.account_seed.is_some()
+        {
+            tracing::warn!("Imported an existing account and still provided a seed when it is not needed. It's possible that the account's file was incorrectly generated. The seed will be ignored.");
+            // Ignore the seed since it's not a new account
+
+            // TODO: The alternative approach to this is to store the seed anyway, but
+            // ignore it at the point of executing against this transaction, but that
+            // approach seems a little bit more incorrect
+            None
+        } else {
+            account_data.account_seed
+        };
+
+        if account_data.account.is_public() && !account_data.account.is_new() {
+            match self.verify_public_account_state(&account_data.account).await {
+                Ok(()) => {},
+                Err(err) if force => {
+                    warn!(
+                        "Importing account {} without verifying its state: {err}",
+                        account_data.account.id()
+                    );
+                },
+                Err(err) => return Err(err),
+            }
+        }
+
+        self.insert_account(&account_data.account, account_seed, &account_data.auth_secret_key)
+            .await
+    }
+
+    /// Checks that the state of the public `account` matches the commitment the node has for it.
+    async fn verify_public_account_state(&mut self, account: &Account) -> Result<(), ClientError> {
+        let node_hash = self.rpc_api.get_account_update(account.id()).await?.hash();
+        if node_hash != account.hash() {
+            return Err(ClientError::ImportedAccountStateMismatch {
+                account_id: account.id(),
+                imported_hash: account.hash(),
+                node_hash,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new regular account and saves it in the store along with its seed and auth data
+    async fn new_basic_wallet(
+        &mut self,
+        mutable_code: bool,
+        account_storage_mode: AccountStorageMode,
+    ) -> Result<(Account, Word), ClientError> {
+        let key_pair = SecretKey::with_rng(&mut self.rng);
+
+        let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+
+        // we need to use an initial seed to create the wallet account
+        let mut init_seed = [0u8; 32];
+        self.rng.fill_bytes(&mut init_seed);
+
+        let (account, seed) = if !mutable_code {
+            miden_lib::accounts::wallets::create_basic_wallet(
+                init_seed,
+                auth_scheme,
+                AccountType::RegularAccountImmutableCode,
+                account_storage_mode,
+            )
+        } else {
+            miden_lib::accounts::wallets::create_basic_wallet(
+                init_seed,
+                auth_scheme,
+                AccountType::RegularAccountUpdatableCode,
+                account_storage_mode,
+            )
+        }?;
+
+        self.insert_created_account(&account, seed, AuthSecretKey::RpoFalcon512(key_pair))
+            .await?;
+        Ok((account, seed))
+    }
+
+    /// Creates a new regular account with mutable code that includes the [AuthKeyRotation]
+    /// component and saves it in the store along with its seed and auth data
+    async fn new_rotatable_wallet(
+        &mut self,
+        account_storage_mode: AccountStorageMode,
+    ) -> Result<(Account, Word), ClientError> {
+        let key_pair = SecretKey::with_rng(&mut self.rng);
+
+        // we need to use an initial seed to create the wallet account
+        let mut init_seed = [0u8; 32];
+        self.rng.fill_bytes(&mut init_seed);
+
+        // The auth component goes first so that its public key is kept in the slot the rotation
+        // component writes to
+        let (account, seed) = AccountBuilder::new()
+            .init_seed(init_seed)
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .storage_mode(account_storage_mode)
+            .with_component(RpoFalcon512::new(key_pair.public_key()))
+            .with_component(BasicWallet)
+            .with_component(AuthKeyRotation)
+            .build()?;
+
+        self.insert_created_account(&account, seed, AuthSecretKey::RpoFalcon512(key_pair))
+            .await?;
+        Ok((account, seed))
+    }
+
+    async fn new_fungible_faucet(
+        &mut self,
+        token_symbol: TokenSymbol,
+        decimals: u8,
+        max_supply: u64,
+        account_storage_mode: AccountStorageMode,
+    ) -> Result<(Account, Word), ClientError> {
+        let key_pair = SecretKey::with_rng(&mut self.rng);
+
+        let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+
+        // we need to use an initial seed to create the wallet account
+        let mut init_seed = [0u8; 32];
+        self.rng.fill_bytes(&mut init_seed);
+
+        let (account, seed) = miden_lib::accounts::faucets::create_basic_fungible_faucet(
+            init_seed,
+            token_symbol,
+            decimals,
+            Felt::try_from(max_supply.to_le_bytes().as_slice())
+                .expect("u64 can be safely converted to a field element"),
+            account_storage_mode,
+            auth_scheme,
+        )?;
+
+        self.insert_created_account(&account, seed, AuthSecretKey::RpoFalcon512(key_pair))
+            .await?;
+        Ok((account, seed))
+    }
+
+    /// Inserts an account created by the client along with its seed and key.
+    ///
+    /// The account's ID is reserved before anything is written, so that the creation fails
+    /// without storing the key if another account with the same ID is stored or being created
+    /// from a client sharing the store. The ID is derived from the key, so the key is generated
+    /// before the reservation. The reservation is released if the insertion fails.
+    async fn insert_created_account(
+        &mut self,
+        account: &Account,
+        account_seed: Word,
+        auth_info: AuthSecretKey,
+    ) -> Result<(), ClientError> {
+        self.store.reserve_account_id(account.id()).await?;
+
+        let result = self.insert_account(account, Some(account_seed), &auth_info).await;
+        if result.is_err() {
+            self.store.release_account_id(account.id()).await?;
+        }
+
+        result
+    }
+
+    /// Inserts a new account into the client's store.
+    ///
+    /// # Errors
+    ///
+    /// If an account is new and no seed is provided, the function errors out because the client
+    /// cannot execute transactions against new accounts for which it does not know the seed.
+    pub async fn insert_account(
+        &mut self,
+        account: &Account,
+        account_seed: Option<Word>,
+        auth_info: &AuthSecretKey,
+    ) -> Result<(), ClientError> {
+        if account.is_new() && account_seed.is_none() {
+            return Err(ClientError::ImportNewAccountWithoutSeed);
+        }
+
+        self.store
+            .insert_account(account, account_seed, Some(auth_info))
+            .await
+            .map_err(ClientError::StoreError)
+    }
+
+    /// Returns the IDs of the accounts whose keys are stored without the account, like the ones
+    /// left behind by a failed account insertion. Keys of accounts that are being created are
+    /// not included.
+    pub async fn find_orphaned_keys(&self) -> Result<Vec<AccountId>, ClientError> {
+        Ok(self.store.get_orphaned_account_auth_ids().await?)
+    }
+
+    /// Removes the keys returned by [Client::find_orphaned_keys] from the store and returns the
+    /// IDs of the accounts whose keys were removed.
+    ///
+    /// A key is kept if its account is inserted or its ID is reserved between the lookup and the
+    /// removal.
+    pub async fn cleanup_orphaned_keys(&mut self) -> Result<Vec<AccountId>, ClientError> {
+        let mut removed = vec![];
+        for account_id in self.store.get_orphaned_account_auth_ids().await? {
+            if self.store.remove_orphaned_account_auth(account_id).await? {
+                removed.push(account_id);
+            }
+        }
+
+        if !removed.is_empty() {
+            tracing::info!("Removed the orphaned keys of {} accounts", removed.len());
+        }
+
+        Ok(removed)
+    }
+
+    // ACCOUNT DATA RETRIEVAL
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a list of [AccountHeader] of all accounts stored in the database along with the
+    /// seeds used to create them.
+    ///
+    /// Said accounts' state is the state after the last performed sync.
+    pub async fn get_account_headers(
+        &self,
+    ) -> Result<Vec<(AccountHeader, Option<Word>)>, ClientError> {
+        self.store.get_account_headers().await.map_err(|err| err.into())
+    }
+
+    /// Retrieves a full [Account] object. The seed will be returned if the account is new,
+    /// otherwise it will be `None`.
+    ///
+    /// This function returns the [Account]'s latest state. If the account is new (that is, has
+    /// never executed a transaction), the returned seed will be `Some(Word)`; otherwise the seed
+    /// will be `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
+    pub async fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), ClientError> {
+        self.store.get_account(account_id).await.map_err(|err| err.into())
+    }
+
+    /// Returns an [AccountReport] with the full contents of the account's latest state: its type,
+    /// storage mode, nonce, vault assets, storage slots, code commitment and authentication
+    /// scheme.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
+    pub async fn inspect_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountReport, ClientError> {
+        let (account, seed) = self.store.get_account(account_id).await?;
+        let auth = match self.store.get_account_auth(account_id).await {
+            Ok(auth) => Some(auth),
+            Err(StoreError::AccountDataNotFound(_)) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        AccountReport::new(&account, seed, auth.as_ref())
+    }
+
+    /// Returns the IDs of the faucets whose assets the specified account has ever held, sent or
+    /// received, even if its current balance of them is zero.
+    ///
+    /// Besides the faucets reported by the store, the ones of the notes the account can consume
+    /// but hasn't consumed yet are included.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
+    pub async fn get_known_faucets_for_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountId>, ClientError> {
+        let mut faucets: BTreeSet<AccountId> = self
+            .store
+            .get_known_faucets_for_account(account_id)
+            .await?
+            .into_iter()
+            .collect();
+
+        for (note, _) in self.get_consumable_notes(Some(account_id), false).await? {
+            faucets.extend(note.assets().iter().map(Asset::faucet_id));
+        }
+        faucets.remove(&account_id);
+
+        Ok(faucets.into_iter().collect())
+    }
+
+    /// Retrieves an [AccountHeader] object for the specified [AccountId] along with the seed
+    /// used to create it. The seed will be returned if the account is new, otherwise it
+    /// will be `None`.
+    ///
+    /// Said account's state is the state according to the last sync performed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
+    pub async fn get_account_header_by_id(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(AccountHeader, Option<Word>), ClientError> {
+        self.store.get_account_header(account_id).await.map_err(|err| err.into())
+    }
+
+    /// Returns the [AccountHeader] of the account whose ID starts with the provided hex prefix.
+    ///
+    /// The prefix is normalized with [normalize_id_prefix], so the leading `0x` is optional and
+    /// the match is case insensitive.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [IdPrefixFetchError::NoMatch] if no tracked account ID starts with the prefix.
+    /// - Returns [IdPrefixFetchError::MultipleMatches] with the IDs of the matching accounts if
+    ///   more than one account ID starts with the prefix.
+    pub async fn find_account_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<AccountHeader, IdPrefixFetchError> {
+        let no_match = || IdPrefixFetchError::NoMatch(format!("account ID prefix {prefix}"));
+        let store_error = |err: StoreError| {
+            tracing::error!("Error when fetching accounts from the store: {err}");
+            no_match()
+        };
+
+        let normalized_prefix = normalize_id_prefix(prefix).ok_or_else(no_match)?;
+        let account_ids = self
+            .store
+            .get_account_ids_by_prefix(&normalized_prefix)
+            .await
+            .map_err(store_error)?;
+
+        match account_ids.as_slice() {
+            [] => Err(no_match()),
+            [account_id] => {
+                let (account_header, _) =
+                    self.store.get_account_header(*account_id).await.map_err(store_error)?;
+                Ok(account_header)
+            },
+            _ => {
+                let mut candidates: Vec<String> =
+                    account_ids.iter().map(AccountId::to_hex).collect();
+                candidates.sort();
+                Err(IdPrefixFetchError::MultipleMatches(
+                    format!("account ID prefix {prefix}"),
+                    candidates,
+                ))
+            },
+        }
+    }
+
+    /// Returns an [AuthSecretKey] object utilized to authenticate an account.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::StoreError] with a
+    /// [StoreError::AccountDataNotFound](crate::store::StoreError::AccountDataNotFound) if the
+    /// provided ID does not correspond to an existing account.
+    pub async fn get_account_auth(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AuthSecretKey, ClientError> {
+        self.store.get_account_auth(account_id).await.map_err(|err| err.into())
+    }
+
+    /// Returns whether the client can sign transactions for the account with the specified ID,
+    /// that is, whether it holds the secret key matching the public key of the account's
+    /// authentication component.
+    ///
+    /// This is not the case for watch-only accounts, nor for accounts whose public key was
+    /// changed by a transaction executed by another client.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
+    pub async fn can_sign_for(&self, account_id: AccountId) -> Result<bool, ClientError> {
+        let (account, _seed) = self.store.get_account(account_id).await?;
+        let auth = match self.store.get_account_auth(account_id).await {
+            Ok(auth) => auth,
+            Err(StoreError::AccountDataNotFound(_)) => return Ok(false),
+            Err(err) => return Err(err.into()),
+        };
+
+        let account_pub_key: Word = account.storage().get_item(AUTH_PUB_KEY_SLOT)?.into();
+        let can_sign = match auth {
+            AuthSecretKey::RpoFalcon512(secret_key) => {
+                Word::from(secret_key.public_key()) == account_pub_key
+            },
+        };
+        if can_sign {
+            return Ok(true);
+        }
+
+        // The local state of an account whose key rotation is pending already uses the new key
+        let has_pending_rotation = self
+            .store
+            .get_pending_account_auths()
+            .await?
+            .iter()
+            .any(|(_, pending_account_id)| *pending_account_id == account_id);
+
+        Ok(has_pending_rotation
+            && self.store.get_account_auth_by_pub_key(account_pub_key).await.is_ok())
+    }
+
+    /// Fetches a single storage slot of a public account from the node, without fetching the
+    /// rest of the account. For map slots, the values of the provided `keys` are returned along
+    /// with the map's root. The account doesn't need to be tracked by the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::RpcError] if the account is not public, if it doesn't have a slot
+    /// with the provided index, or if the node can't be reached.
+    pub async fn fetch_account_storage_slot(
+        &mut self,
+        account_id: AccountId,
+        slot_index: u8,
+        keys: &[Word],
+    ) -> Result<AccountStorageSlotData, ClientError> {
+        self.rpc_api
+            .get_account_storage_slot(account_id, slot_index, keys)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    // FAUCET ISSUANCE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an [IssuanceSummary] for the fungible faucet with the specified ID, comparing the
+    /// amounts minted by transactions executed by this client against the issuance recorded in
+    /// the faucet's storage.
+    ///
+    /// For public faucets the storage is updated on every sync, so a divergence means that tokens
+    /// were minted by another client instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::AccountIsNotFungibleFaucet] if the account is not a fungible faucet
+    /// and a [ClientError::StoreError] if it's not tracked by the client.
+    pub async fn get_faucet_issuance(
+        &self,
+        faucet_id: AccountId,
+    ) -> Result<IssuanceSummary, ClientError> {
+        if faucet_id.account_type() != AccountType::FungibleFaucet {
+            return Err(ClientError::AccountIsNotFungibleFaucet(faucet_id));
+        }
+
+        let (faucet, _) = self.store.get_account(faucet_id).await?;
+        let mints = self.store.get_faucet_mints(faucet_id).await?;
+
+        let storage_issuance =
+            Word::from(faucet.storage().get_item(FAUCET_RESERVED_SLOT)?)[3].as_int();
+        let max_supply =
+            Word::from(faucet.storage().get_item(BASIC_FUNGIBLE_FAUCET_METADATA_SLOT)?)[0].as_int();
+
+        Ok(IssuanceSummary {
+            faucet_id,
+            minted_locally: mints
+                .iter()
+                .fold(0u64, |total, mint| total.saturating_add(mint.amount)),
+            mint_transactions: mints.len(),
+            storage_issuance,
+            max_supply,
+        })
+    }
+
+    /// Checks the issuance of every tracked fungible faucet and logs a warning for faucets whose
+    /// local mint ledger diverges from their storage or that are close to their max supply.
+    ///
+    /// Faucets whose issuance can't be read, like those without the storage layout of a basic
+    /// fungible faucet, are skipped with a warning.
+    pub(crate) async fn check_faucets_issuance(&self) -> Result<(), ClientError> {
+        let faucet_ids = self
+            .store
+            .get_account_ids()
+            .await?
+            .into_iter()
+            .filter(|account_id| account_id.account_type() == AccountType::FungibleFaucet);
+
+        for faucet_id in faucet_ids {
+            match self.get_faucet_issuance(faucet_id).await {
+                Ok(issuance) => issuance.warn_if_needed(),
+                Err(err) => warn!("Couldn't check the issuance of faucet {faucet_id}: {err}"),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// FAUCET ISSUANCE
+// ================================================================================================
+
+/// A mint transaction executed by a fungible faucet tracked by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaucetMintRecord {
+    /// ID of the mint transaction.
+    pub transaction_id: TransactionId,
+    /// Amount of tokens minted by the transaction.
+    pub amount: u64,
+}
+
+/// Issuance accounting of a fungible faucet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssuanceSummary {
+    /// ID of the faucet.
+    pub faucet_id: AccountId,
+    /// Total amount of tokens minted by transactions executed by this client.
+    pub minted_locally: u64,
+    /// Number of mint transactions executed by this client.
+    pub mint_transactions: usize,
+    /// Total issuance recorded in the faucet's storage as of the last sync.
+    pub storage_issuance: u64,
+    /// Maximum supply of the faucet.
+    pub max_supply: u64,
+}
+
+impl IssuanceSummary {
+    /// Returns the amount of tokens that can still be minted.
+    pub fn remaining(&self) -> u64 {
+        self.max_supply.saturating_sub(self.storage_issuance)
+    }
+
+    /// Returns whether the issuance recorded in the faucet's storage differs from the amount
+    /// minted locally, which happens when other clients mint tokens from the same faucet.
+    pub fn has_diverged(&self) -> bool {
+        self.storage_issuance != self.minted_locally
+    }
+
+    /// Returns whether the faucet has issued at least `threshold_percent` percent of its max
+    /// supply.
+    pub fn is_near_max_supply(&self, threshold_percent: u8) -> bool {
+        self.storage_issuance as u128 * 100 >= self.max_supply as u128 * threshold_percent as u128
+    }
+
+    /// Logs a warning if the issuance has diverged or is close to the max supply.
+    pub(crate) fn warn_if_needed(&self) {
+        if self.has_diverged() {
+            warn!(
+                "Faucet {} has an issuance of {} but {} tokens were minted by this client.",
+                self.faucet_id, self.storage_issuance, self.minted_locally
+            );
+        }
+
+        if self.is_near_max_supply(ISSUANCE_WARNING_THRESHOLD_PERCENT) {
+            warn!(
+                "Faucet {} has issued {} out of its max supply of {} tokens.",
+                self.faucet_id, self.storage_issuance, self.max_supply
+            );
+        }
+    }
+}
+
+/// Returns the amount of tokens of the specified faucet contained in the provided output notes.
+/// Notes for which only the header is known are ignored.
+pub(crate) fn minted_amount(faucet_id: AccountId, output_notes: &OutputNotes) -> u64 {
+    output_notes
+        .iter()
+        .filter_map(|note| note.assets())
+        .flat_map(|assets| assets.iter())
+        .filter_map(|asset| match asset {
+            Asset::Fungible(asset) if asset.faucet_id() == faucet_id => Some(asset.amount()),
+            _ => None,
+        })
+        .fold(0, u64::saturating_add)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+pub mod tests {
+    use alloc::vec::Vec;
+
+    use miden_lib::transaction::TransactionKernel;
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            },
+            Account, AccountData, AuthSecretKey,
+        },
+        crypto::dsa::rpo_falcon512::SecretKey,
+        Felt, Word,
+    };
+
+    use crate::mock::create_test_client;
+
+    fn create_account_data(account_id: u64) -> AccountData {
+        let account =
+            Account::mock(account_id, Felt::new(2), TransactionKernel::testing_assembler());
+
+        AccountData::new(
+            account.clone(),
+            Some(Word::default()),
+            AuthSecretKey::RpoFalcon512(SecretKey::new()),
+        )
+    }
+
+    pub fn create_initial_accounts_data() -> Vec<AccountData> {
+        let account = create_account_data(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN);
+
+        let faucet_account = create_account_data(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN);
+
+        // Create Genesis state and save it to a file
+        let accounts = vec![account, faucet_account];
+
+        accounts
+    }
+
+    #[tokio::test]
+    pub async fn try_import_new_account() {
+        // generate test client
+        let (mut client, _rpc_api) = create_test_client().await;
+
+        let account = Account::mock(
+            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN,
+            Felt::new(0),
+            TransactionKernel::testing_assembler(),
+        );
+
+        let key_pair = SecretKey::new();
+
+        assert!(client
+            .insert_account(&account, None, &AuthSecretKey::RpoFalcon512(key_pair.clone()))
+            .await
+            .is_err());
+        assert!(client
+            .insert_account(&account, Some(Word::default()), &AuthSecretKey::RpoFalcon512(key_pair))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn load_accounts_test() {
+        // generate test client
+        let (mut client, _) = create_test_client().await;
+
+        let created_accounts_data = create_initial_accounts_data();
+
+        for account_data in created_accounts_data.clone() {
+            client.import_account(account_data).await.unwrap();
+        }
+
+        let expected_accounts: Vec<Account> = created_accounts_data
+            .into_iter()
+            .map(|account_data| account_data.account)
+            .collect();
+        let accounts = client.get_account_headers().await.unwrap();
+
+        assert_eq!(accounts.len(), 2);
+        for (client_acc, expected_acc) in accounts.iter().zip(expected_accounts.iter()) {
+            assert_eq!(client_acc.0.hash(), expected_acc.hash());
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    accounts::AccountId,
+    crypto::rand::FeltRng,
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
+};
+use tracing::info;
+
+use crate::{
+    store::{NoteFilter, TransactionFilter},
+    sync::{NoteTagRecord, NoteTagSource},
+    transactions::TransactionStatus,
+    Client, ClientError,
+};
+
+// REMOVE OPTIONS
+// ================================================================================================
+
+/// Options of [Client::remove_account].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RemoveOptions {
+    /// Whether the transactions of the account and the notes they consumed and created are kept.
+    /// Otherwise, they are removed along with the account.
+    pub keep_history: bool,
+    /// Whether the account is removed even if it has pending transactions, which are marked as
+    /// discarded if the history is kept.
+    pub force: bool,
+}
+
+impl RemoveOptions {
+    /// Sets whether the transactions and notes of the account are kept.
+    pub fn with_keep_history(mut self, keep_history: bool) -> Self {
+        self.keep_history = keep_history;
+        self
+    }
+
+    /// Sets whether the account is removed even if it has pending transactions.
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+}
+
+// ACCOUNT REMOVAL
+// ================================================================================================
+
+/// Changes applied to the store by [Client::remove_account], which are applied atomically along
+/// with removing the states, keys, locks, unlocks and storage slot watches of the account.
+pub struct AccountRemoval {
+    /// ID of the removed account.
+    pub account_id: AccountId,
+    /// Transactions of the account that are removed. Empty if the history is kept.
+    pub removed_transaction_ids: Vec<TransactionId>,
+    /// Pending transactions of the account that are marked as discarded because the history is
+    /// kept.
+    pub discarded_transaction_ids: Vec<TransactionId>,
+    /// Input notes consumed by the removed transactions.
+    pub removed_input_note_ids: Vec<NoteId>,
+    /// Output notes created by the removed transactions.
+    pub removed_output_note_ids: Vec<NoteId>,
+    /// Tags of the account and of the removed input notes.
+    pub tags_to_remove: Vec<NoteTagRecord>,
+}
+
+impl<R: FeltRng> Client<R> {
+    // ACCOUNT REMOVAL
+    // --------------------------------------------------------------------------------------------
+
+    /// Stops tracking the account with the specified ID, removing its states, its keys and its
+    /// tags from the store. The account drops out of the next syncs, while the other accounts,
+    /// notes and tags keep being synced.
+    ///
+    /// Unless [RemoveOptions::keep_history] is set, the transactions executed by the account are
+    /// removed too, along with the input notes they consumed and the output notes they created.
+    ///
+    /// The keys of the account can't be recovered once removed, so accounts holding assets
+    /// should be exported first.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [ClientError::AccountHasPendingTransactions] if the account has pending
+    ///   transactions and [RemoveOptions::force] isn't set.
+    /// - Returns an error if the account isn't tracked by the client.
+    pub async fn remove_account(
+        &mut self,
+        account_id: AccountId,
+        options: RemoveOptions,
+    ) -> Result<(), ClientError> {
+        self.store.get_account_header(account_id).await?;
+
+        let transactions: Vec<_> = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|transaction| transaction.account_id == account_id)
+            .collect();
+
+        let pending_transaction_ids: Vec<TransactionId> = transactions
+            .iter()
+            .filter(|transaction| transaction.transaction_status == TransactionStatus::Pending)
+            .map(|transaction| transaction.id)
+            .collect();
+        if !pending_transaction_ids.is_empty() && !options.force {
+            return Err(ClientError::AccountHasPendingTransactions {
+                account_id,
+                transaction_ids: pending_transaction_ids,
+            });
+        }
+
+        let (removed_transaction_ids, discarded_transaction_ids, removed_input_note_ids) =
+            if options.keep_history {
+                (vec![], pending_transaction_ids, vec![])
+            } else {
+                let nullifiers: Vec<Nullifier> = transactions
+                    .iter()
+                    .flat_map(|transaction| transaction.input_note_nullifiers.iter())
+                    .copied()
+                    .map(Nullifier::from)
+                    .collect();
+                let consumed_note_ids = if nullifiers.is_empty() {
+                    vec![]
+                } else {
+                    self.store
+                        .get_input_notes(NoteFilter::Nullifiers(nullifiers))
+                        .await?
+                        .iter()
+                        .map(|note| note.id())
+                        .collect()
+                };
+
+                (
+                    transactions.iter().map(|transaction| transaction.id).collect(),
+                    vec![],
+                    consumed_note_ids,
+                )
+            };
+
+        let removed_output_note_ids: Vec<NoteId> = if options.keep_history {
+            vec![]
+        } else {
+            transactions
+                .iter()
+                .flat_map(|transaction| transaction.output_notes.iter().map(|note| note.id()))
+                .collect()
+        };
+
+        let tags_to_remove = self
+            .store
+            .get_note_tags()
+            .await?
+            .into_iter()
+            .filter(|tag| match tag.source {
+                NoteTagSource::Account(id) => id == account_id,
+                NoteTagSource::Note(note_id) => removed_input_note_ids.contains(&note_id),
+                NoteTagSource::User => false,
+            })
+            .collect();
+
+        info!(
+            "Removing account {account_id}: {} transactions and {} notes removed, {} pending \
+             transactions discarded",
+            removed_transaction_ids.len(),
+            removed_input_note_ids.len() + removed_output_note_ids.len(),
+            discarded_transaction_ids.len()
+        );
+
+        self.store
+            .remove_account(AccountRemoval {
+                account_id,
+                removed_transaction_ids,
+                discarded_transaction_ids,
+                removed_input_note_ids,
+                removed_output_note_ids,
+                tags_to_remove,
+            })
+            .await?;
+
+        self.storage_change_callbacks
+            .retain(|(watched_account_id, _), _| *watched_account_id != account_id);
+        // The removed tags change the notes the node returns for a block, so a partially applied
+        // block is synced again from its first note
+        self.store.set_partial_sync_progress(None).await?;
+
+        Ok(())
+    }
+}
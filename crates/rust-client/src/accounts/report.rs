@@ -0,0 +1,188 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    accounts::{Account, AccountType, AuthSecretKey, StorageSlot},
+    assets::Asset,
+    Digest, Word,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::ClientError;
+
+// ACCOUNT REPORT
+// ================================================================================================
+
+/// Structured dump of the state of a tracked account, returned by
+/// [Client::inspect_account](crate::Client::inspect_account).
+///
+/// IDs, commitments and words are rendered as hex strings, so the report can be serialized into
+/// formats like JSON and consumed by tools that don't depend on the client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountReport {
+    /// ID of the account.
+    pub id: String,
+    /// Type of the account.
+    pub account_type: AccountTypeReport,
+    /// Storage mode of the account, either `public` or `private`.
+    pub storage_mode: String,
+    /// Nonce of the account.
+    pub nonce: u64,
+    /// Whether the account is new, that is, whether it has never executed a transaction and the
+    /// client still keeps its seed.
+    pub is_new: bool,
+    /// Commitment of the whole account state.
+    pub hash: String,
+    /// Assets held in the account's vault.
+    pub vault_assets: Vec<AssetReport>,
+    /// Slots of the account's storage, in index order.
+    pub storage_slots: Vec<StorageSlotReport>,
+    /// Commitment to the account's code.
+    pub code_commitment: String,
+    /// Scheme used to authenticate the account's transactions, or `None` if the client doesn't
+    /// have the account's secret key.
+    pub auth_scheme: Option<AuthSchemeReport>,
+}
+
+impl AccountReport {
+    /// Builds the report of the provided account. `auth` is the account's secret key, if the
+    /// client has it.
+    pub(crate) fn new(
+        account: &Account,
+        seed: Option<Word>,
+        auth: Option<&AuthSecretKey>,
+    ) -> Result<Self, ClientError> {
+        let storage = account.storage();
+        let storage_slots = storage
+            .slots()
+            .iter()
+            .enumerate()
+            .map(|(index, slot)| {
+                let index = index as u8;
+                let slot_type = match slot {
+                    StorageSlot::Value(_) => StorageSlotKind::Value,
+                    StorageSlot::Map(_) => StorageSlotKind::Map,
+                };
+
+                Ok(StorageSlotReport {
+                    index,
+                    slot_type,
+                    value: storage.get_item(index)?.to_hex(),
+                })
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+
+        Ok(Self {
+            id: account.id().to_hex(),
+            account_type: account.id().account_type().into(),
+            storage_mode: account.id().storage_mode().to_string(),
+            nonce: account.nonce().as_int(),
+            is_new: seed.is_some(),
+            hash: account.hash().to_hex(),
+            vault_assets: account.vault().assets().map(AssetReport::from).collect(),
+            storage_slots,
+            code_commitment: account.code().commitment().to_hex(),
+            auth_scheme: auth.map(AuthSchemeReport::from),
+        })
+    }
+}
+
+/// Type of an account, as listed in an [AccountReport].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountTypeReport {
+    FungibleFaucet,
+    NonFungibleFaucet,
+    RegularAccountImmutableCode,
+    RegularAccountUpdatableCode,
+}
+
+impl From<AccountType> for AccountTypeReport {
+    fn from(account_type: AccountType) -> Self {
+        match account_type {
+            AccountType::FungibleFaucet => AccountTypeReport::FungibleFaucet,
+            AccountType::NonFungibleFaucet => AccountTypeReport::NonFungibleFaucet,
+            AccountType::RegularAccountImmutableCode => {
+                AccountTypeReport::RegularAccountImmutableCode
+            },
+            AccountType::RegularAccountUpdatableCode => {
+                AccountTypeReport::RegularAccountUpdatableCode
+            },
+        }
+    }
+}
+
+/// Asset held in the vault of an account, as listed in an [AccountReport].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AssetReport {
+    Fungible {
+        /// ID of the faucet that issued the asset.
+        faucet_id: String,
+        /// Amount of the asset held by the account.
+        amount: u64,
+    },
+    NonFungible {
+        /// ID of the faucet that issued the asset.
+        faucet_id: String,
+        /// Word that represents the asset.
+        value: String,
+    },
+}
+
+impl From<Asset> for AssetReport {
+    fn from(asset: Asset) -> Self {
+        match asset {
+            Asset::Fungible(asset) => AssetReport::Fungible {
+                faucet_id: asset.faucet_id().to_hex(),
+                amount: asset.amount(),
+            },
+            Asset::NonFungible(asset) => AssetReport::NonFungible {
+                faucet_id: asset.faucet_id().to_hex(),
+                value: Digest::from(Word::from(asset)).to_hex(),
+            },
+        }
+    }
+}
+
+/// Slot of the storage of an account, as listed in an [AccountReport].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageSlotReport {
+    /// Index of the slot.
+    pub index: u8,
+    /// Whether the slot holds a value or a map.
+    pub slot_type: StorageSlotKind,
+    /// Value held by the slot, or the root of the map for map slots.
+    pub value: String,
+}
+
+/// Kind of a [StorageSlotReport].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageSlotKind {
+    Value,
+    Map,
+}
+
+/// Authentication scheme of an account, as listed in an [AccountReport]. Only the public part of
+/// the account's key is included.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "scheme", rename_all = "snake_case")]
+pub enum AuthSchemeReport {
+    RpoFalcon512 {
+        /// Public key of the account.
+        public_key: String,
+    },
+}
+
+impl From<&AuthSecretKey> for AuthSchemeReport {
+    fn from(auth: &AuthSecretKey) -> Self {
+        match auth {
+            AuthSecretKey::RpoFalcon512(secret_key) => AuthSchemeReport::RpoFalcon512 {
+                public_key: Digest::from(Word::from(secret_key.public_key())).to_hex(),
+            },
+        }
+    }
+}
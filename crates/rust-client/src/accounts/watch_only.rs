@@ -0,0 +1,115 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    crypto::rand::FeltRng,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Word,
+};
+
+use crate::{store::StoreError, Client, ClientError};
+
+/// Bytes that prefix a serialized [WatchOnlyAccountFile], used to tell it apart from a regular
+/// account file.
+const WATCH_ONLY_MAGIC: &[u8; 5] = b"watch";
+
+// WATCH-ONLY ACCOUNT FILE
+// ================================================================================================
+
+/// Exported account that doesn't include the account's secret key.
+///
+/// Importing it lets a client monitor the account without being able to sign transactions for
+/// it. Accounts imported this way are watch-only, and executing transactions against them fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchOnlyAccountFile {
+    /// The account's state at the moment it was exported.
+    pub account: Account,
+    /// Seed used to create the account. Only needed while the account is new.
+    pub account_seed: Option<Word>,
+}
+
+impl WatchOnlyAccountFile {
+    /// Returns a new [WatchOnlyAccountFile] for the provided account.
+    pub fn new(account: Account, account_seed: Option<Word>) -> Self {
+        Self { account, account_seed }
+    }
+}
+
+impl Serializable for WatchOnlyAccountFile {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(WATCH_ONLY_MAGIC);
+        self.account.write_into(target);
+        self.account_seed.write_into(target);
+    }
+}
+
+impl Deserializable for WatchOnlyAccountFile {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic = source.read_vec(WATCH_ONLY_MAGIC.len())?;
+        if magic != WATCH_ONLY_MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "not a watch-only account file".to_string(),
+            ));
+        }
+
+        let account = Account::read_from(source)?;
+        let account_seed = Option::<Word>::read_from(source)?;
+
+        Ok(Self { account, account_seed })
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // WATCH-ONLY ACCOUNTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Exports the account with the specified ID without its secret key. The returned bytes can
+    /// be deserialized into a [WatchOnlyAccountFile] and imported with
+    /// [Client::import_watch_only_account] to monitor the account from another client.
+    pub async fn export_account_watch_only(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<u8>, ClientError> {
+        let (account, account_seed) = self.store.get_account(account_id).await?;
+
+        Ok(WatchOnlyAccountFile::new(account, account_seed).to_bytes())
+    }
+
+    /// Imports an account without its secret key. The account is tracked as any other account,
+    /// but transactions can't be executed against it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account is new and the file doesn't include its seed.
+    pub async fn import_watch_only_account(
+        &mut self,
+        account_file: WatchOnlyAccountFile,
+    ) -> Result<(), ClientError> {
+        let WatchOnlyAccountFile { account, account_seed } = account_file;
+        if account.is_new() && account_seed.is_none() {
+            return Err(ClientError::ImportNewAccountWithoutSeed);
+        }
+
+        self.store
+            .insert_account(&account, account_seed, None)
+            .await
+            .map_err(ClientError::StoreError)
+    }
+
+    /// Returns whether the account with the specified ID is watch-only, that is, whether the
+    /// client doesn't have its secret key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account is not tracked by the client.
+    pub async fn is_watch_only_account(&self, account_id: AccountId) -> Result<bool, ClientError> {
+        // Make sure the account is tracked before checking its auth data
+        self.store.get_account_header(account_id).await?;
+
+        match self.store.get_account_auth(account_id).await {
+            Ok(_) => Ok(false),
+            Err(StoreError::AccountDataNotFound(_)) => Ok(true),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
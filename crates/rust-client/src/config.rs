@@ -5,6 +5,8 @@ use core::fmt::{self, Debug};
 
 use serde::{Deserialize, Serialize};
 
+use crate::rpc::NodeRpcClientEndpoint;
+
 // ENDPOINT
 // ================================================================================================
 
@@ -127,6 +129,9 @@ pub struct RpcConfig {
     /// Timeout for the RPC api requests, in milliseconds.
     #[serde(default = "default_timeout")]
     pub timeout_ms: u64,
+    /// Policy for retrying requests that fail because the node is unavailable.
+    #[serde(default)]
+    pub retry: RetryPolicy,
 }
 
 const fn default_timeout() -> u64 {
@@ -138,15 +143,172 @@ impl Default for RpcConfig {
         Self {
             endpoint: Endpoint::default(),
             timeout_ms: 10000,
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+// RETRY POLICY
+// ================================================================================================
+
+/// Policy for retrying RPC requests that fail because the node couldn't be reached or answered
+/// with an `Unavailable` status.
+///
+/// Failed requests are retried with exponential backoff: the n-th retry waits `base_delay_ms *
+/// 2^(n - 1)` milliseconds, capped at `max_delay_ms`. Only idempotent reads (`sync_state`,
+/// `get_block_header_by_number` and `get_notes_by_id`) are retried by default. Proven transaction
+/// submissions are only retried if [RetryPolicy::retry_submissions] is set, as a submission that
+/// timed out may still have reached the node.
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts of a request, including the first one. Values of 0 and 1
+    /// disable retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry, in milliseconds.
+    pub base_delay_ms: u64,
+    /// Upper bound of the delay between retries, in milliseconds.
+    pub max_delay_ms: u64,
+    /// Whether each delay is replaced by a random one between half of it and all of it, so that
+    /// clients failing at the same time don't retry in lockstep.
+    pub jitter: bool,
+    /// Whether proven transaction submissions are retried too.
+    pub retry_submissions: bool,
+}
+
+impl RetryPolicy {
+    /// Returns a policy that never retries requests.
+    pub const fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay_ms: 0,
+            max_delay_ms: 0,
+            jitter: false,
+            retry_submissions: false,
+        }
+    }
+
+    /// Sets the maximum number of attempts of a request, including the first one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry and the upper bound of the delays, in milliseconds.
+    pub fn with_delays(mut self, base_delay_ms: u64, max_delay_ms: u64) -> Self {
+        self.base_delay_ms = base_delay_ms;
+        self.max_delay_ms = max_delay_ms;
+        self
+    }
+
+    /// Sets whether the delays between retries are randomized.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether proven transaction submissions are retried.
+    pub fn with_retry_submissions(mut self, retry_submissions: bool) -> Self {
+        self.retry_submissions = retry_submissions;
+        self
+    }
+
+    /// Returns the maximum number of attempts of a request to the specified endpoint, which is 1
+    /// for endpoints that aren't retried.
+    pub fn max_attempts_for(&self, endpoint: NodeRpcClientEndpoint) -> u32 {
+        let retried = match endpoint {
+            NodeRpcClientEndpoint::SyncState
+            | NodeRpcClientEndpoint::GetBlockHeaderByNumber
+            | NodeRpcClientEndpoint::GetNotesById => true,
+            NodeRpcClientEndpoint::SubmitProvenTx => self.retry_submissions,
+            _ => false,
+        };
+
+        if retried {
+            self.max_attempts.max(1)
+        } else {
+            1
         }
     }
+
+    /// Returns the delay before the specified retry, starting at 1, in milliseconds, without
+    /// jitter.
+    pub fn backoff_delay_ms(&self, retry: u32) -> u64 {
+        let factor = 1u64.checked_shl(retry.saturating_sub(1)).unwrap_or(u64::MAX);
+        self.base_delay_ms.saturating_mul(factor).min(self.max_delay_ms)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 200,
+            max_delay_ms: 5000,
+            jitter: true,
+            retry_submissions: false,
+        }
+    }
+}
+
+// CLIENT PROFILE
+// ================================================================================================
+
+/// Preset of client settings for a common environment, applied with
+/// [Client::with_profile](crate::Client::with_profile).
+///
+/// A profile only pre-populates settings that can also be changed one by one, so any setting
+/// changed after selecting a profile overrides the profile's value. Settings not listed for a
+/// profile keep their current values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientProfile {
+    /// For end-user wallets that sync in the background and may be restarted at any time:
+    /// - Up to 256 notes are applied per sync iteration, so that syncs are split into short store
+    ///   writes.
+    /// - The MMR checkpoint is refreshed after every sync that advances the chain.
+    /// - Up to 1000 expected notes are tracked, bounding what imported note files can add.
+    /// - Accounts whose private state doesn't match the node's commitment are locked.
+    Wallet,
+    /// For long-running services that track many accounts and notes:
+    /// - Up to 4096 notes are applied per sync iteration and up to 250 note IDs are sent per
+    ///   request.
+    /// - The MMR checkpoint is refreshed every 16 blocks.
+    /// - The number of expected notes isn't limited.
+    /// - The sync journal records the last 64 sync iterations.
+    Server,
+    /// For tests, where failures should surface as soon as possible:
+    /// - Up to 16 notes are applied per sync iteration, so that batched syncs are exercised.
+    /// - The MMR checkpoint is refreshed after every sync that advances the chain.
+    /// - Mismatches between private account states and the node's commitments fail the sync.
+    /// - The sync journal records the last 16 sync iterations.
+    Testing,
+}
+
+impl ClientProfile {
+    /// Returns the name of the profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ClientProfile::Wallet => "wallet",
+            ClientProfile::Server => "server",
+            ClientProfile::Testing => "testing",
+        }
+    }
+}
+
+impl fmt::Display for ClientProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use alloc::string::ToString;
+    use alloc::{string::ToString, vec::Vec};
 
-    use crate::config::{Endpoint, MIDEN_NODE_PORT};
+    use crate::{
+        config::{Endpoint, RetryPolicy, MIDEN_NODE_PORT},
+        rpc::NodeRpcClientEndpoint,
+    };
 
     #[test]
     fn test_endpoint_parsing_with_hostname_only() {
@@ -249,4 +411,27 @@ mod test {
         let endpoint = Endpoint::try_from("some.test.domain:8000/hello");
         assert!(endpoint.is_err());
     }
+
+    #[test]
+    fn test_retry_backoff_doubles_up_to_max_delay() {
+        let policy = RetryPolicy::default().with_delays(100, 1000);
+
+        let delays: Vec<u64> = (1..=6).map(|retry| policy.backoff_delay_ms(retry)).collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(policy.backoff_delay_ms(100), 1000);
+    }
+
+    #[test]
+    fn test_retry_policy_only_retries_reads_by_default() {
+        let policy = RetryPolicy::default().with_max_attempts(5);
+
+        assert_eq!(policy.max_attempts_for(NodeRpcClientEndpoint::SyncState), 5);
+        assert_eq!(policy.max_attempts_for(NodeRpcClientEndpoint::GetNotesById), 5);
+        assert_eq!(policy.max_attempts_for(NodeRpcClientEndpoint::SubmitProvenTx), 1);
+        assert_eq!(policy.max_attempts_for(NodeRpcClientEndpoint::GetAccountDetails), 1);
+
+        let policy = policy.with_retry_submissions(true);
+        assert_eq!(policy.max_attempts_for(NodeRpcClientEndpoint::SubmitProvenTx), 5);
+        assert_eq!(RetryPolicy::disabled().max_attempts_for(NodeRpcClientEndpoint::SyncState), 1);
+    }
 }
@@ -1,12 +1,16 @@
 use alloc::{
+    boxed::Box,
     string::{String, ToString},
     vec::Vec,
 };
 use core::fmt;
 
 use miden_objects::{
-    accounts::AccountId, crypto::merkle::MerkleError, notes::NoteId, AccountError, AssetError,
-    NoteError, TransactionScriptError,
+    accounts::AccountId,
+    crypto::merkle::MerkleError,
+    notes::{NoteId, NoteMetadata, NoteTag},
+    transaction::TransactionId,
+    AccountError, AssetError, Digest, NoteError, TransactionScriptError,
 };
 use miden_tx::{
     utils::{DeserializationError, HexParseError},
@@ -15,9 +19,13 @@ use miden_tx::{
 
 use crate::{
     notes::NoteScreenerError,
+    recovery::DerivedCategory,
     rpc::RpcError,
-    store::{NoteRecordError, StoreError},
-    transactions::{TransactionRequestError, TransactionScriptBuilderError},
+    store::{NoteConversionError, NoteRecordError, StoreError},
+    sync::StorageSlotWatch,
+    transactions::{
+        ProvingBackend, TransactionRequestError, TransactionScriptBuilderError, ViewCallError,
+    },
 };
 
 // CLIENT ERROR
@@ -27,35 +35,196 @@ use crate::{
 #[derive(Debug)]
 pub enum ClientError {
     AccountError(AccountError),
+    /// The account can't be removed because its pending transactions would be left behind.
+    AccountHasPendingTransactions {
+        account_id: AccountId,
+        transaction_ids: Vec<TransactionId>,
+    },
+    AccountIsNotFungibleFaucet(AccountId),
+    AccountIsWatchOnly(AccountId),
+    AccountLocked(AccountId),
+    AccountNotLocked(AccountId),
+    AccountUnlockError(String),
     AssetError(AssetError),
+    /// The account's key can't be rotated because its code can't be updated or doesn't include
+    /// the [AuthKeyRotation](crate::accounts::AuthKeyRotation) component.
+    AuthKeyRotationUnsupported(AccountId),
+    /// The node's chain tip is below the block the client is synced to, which happens when the
+    /// node was reset. The client's chain state can be wiped with
+    /// [Client::reset_chain_state](crate::Client::reset_chain_state) to sync against the new
+    /// chain.
+    ChainBehindLocalState {
+        local: u32,
+        node: u32,
+    },
     DataDeserializationError(DeserializationError),
+    /// A category of derived data doesn't satisfy its invariants after being rebuilt with
+    /// [Client::rebuild_derived_state](crate::Client::rebuild_derived_state).
+    DerivedStateInconsistent {
+        category: DerivedCategory,
+        reason: String,
+    },
+    /// The discarded transaction can't be executed again with
+    /// [Client::retry_discarded_transaction](crate::Client::retry_discarded_transaction) because
+    /// its inputs are no longer available.
+    DiscardedTransactionNotRetriable {
+        transaction_id: TransactionId,
+        reason: String,
+    },
+    ExpectedNoteLimitReached(usize),
+    /// The header of the block the foreign account proofs of a transaction were retrieved at
+    /// couldn't be retrieved and authenticated, so the transaction can't be executed against it.
+    ForeignAccountBlockUnavailable {
+        block_num: u32,
+    },
+    /// An authenticated input note of a transaction was committed after the block its foreign
+    /// account data was retrieved at. The transaction is executed against that block, so the
+    /// note's inclusion proof can't be verified against it.
+    FpiBlockMismatch {
+        fpi_block_num: u32,
+        note_id: NoteId,
+        note_block_num: u32,
+    },
     NoteNotFoundOnChain(NoteId),
     HexParseError(HexParseError),
     ImportNewAccountWithoutSeed,
+    ImportedAccountStateMismatch {
+        account_id: AccountId,
+        imported_hash: Digest,
+        node_hash: Digest,
+    },
+    /// The endpoint of the remote prover set with
+    /// [Client::with_remote_prover](crate::Client::with_remote_prover) is malformed.
+    InvalidProverEndpoint {
+        endpoint: String,
+        reason: String,
+    },
+    InvalidStorageSlotWatch {
+        watch: StorageSlotWatch,
+        reason: String,
+    },
+    /// The operation requires the local prover, but the client was compiled without the
+    /// `local-prover` feature.
+    LocalProverUnavailable,
+    /// The local transaction can't be discarded without leaving the store inconsistent, or
+    /// because it already reached the chain.
+    LocalTransactionNotDiscardable {
+        transaction_id: TransactionId,
+        reason: String,
+    },
     MerkleError(MerkleError),
-    MissingOutputNotes(Vec<NoteId>),
+    MissingOutputNotes(Vec<OutputNoteMismatch>),
+    /// An input note record is missing the data needed to convert it into a note.
+    NoteConversionError(NoteConversionError),
     NoteError(NoteError),
     NoteImportError(String),
+    /// The note isn't in the expected state anymore, so the tag it's tracked with can't change.
+    NoteNotExpected(NoteId),
     NoteRecordError(NoteRecordError),
+    /// The tag of an imported note file doesn't match the one derived from the note's details,
+    /// and the client's [NoteTagPolicy](crate::notes::NoteTagPolicy) is
+    /// [Strict](crate::notes::NoteTagPolicy::Strict).
+    NoteTagMismatch {
+        provided: NoteTag,
+        derived: NoteTag,
+    },
     NoConsumableNoteForAccount(AccountId),
     RpcError(RpcError),
     NoteScreenerError(NoteScreenerError),
+    /// An account with pending transactions was left out of a selective sync, which would miss
+    /// their commitment.
+    PendingTransactionsExcludedFromSync {
+        account_id: AccountId,
+        transaction_ids: Vec<TransactionId>,
+    },
+    /// The node reported a commitment for a private account that doesn't match any of its local
+    /// states, and the client's mismatch policy is
+    /// [PrivateAccountMismatchPolicy::Error](crate::accounts::PrivateAccountMismatchPolicy::Error).
+    PrivateAccountMismatch {
+        account_id: AccountId,
+        remote_hash: Digest,
+    },
+    ProvingBackendUnavailable(ProvingBackend),
     StoreError(StoreError),
+    /// The sync failed after the store was advanced to block `synced_to`, so the progress made
+    /// before the error is kept.
+    SyncIncomplete {
+        synced_to: u32,
+        source: Box<ClientError>,
+    },
     TransactionExecutorError(TransactionExecutorError),
+    TransactionNotDiscarded(TransactionId),
+    TransactionNotFound(TransactionId),
     TransactionProvingError(TransactionProverError),
     TransactionRequestError(TransactionRequestError),
     TransactionScriptBuilderError(TransactionScriptBuilderError),
     TransactionScriptError(TransactionScriptError),
+    ViewCallError(ViewCallError),
 }
 
 impl fmt::Display for ClientError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ClientError::AccountError(err) => write!(f, "Account error: {err}"),
+            ClientError::AccountHasPendingTransactions { account_id, transaction_ids } => write!(
+                f,
+                "Account {account_id} has {} pending transactions, wait for them to be committed \
+                 or force its removal",
+                transaction_ids.len()
+            ),
+            ClientError::AccountIsNotFungibleFaucet(account_id) => {
+                write!(f, "Account {account_id} is not a fungible faucet")
+            },
+            ClientError::AccountIsWatchOnly(account_id) => {
+                write!(
+                    f,
+                    "Account {account_id} is watch-only and can't be used to execute transactions"
+                )
+            },
+            ClientError::AccountLocked(account_id) => {
+                write!(
+                    f,
+                    "Account {account_id} is locked because its state diverged from the node's"
+                )
+            },
+            ClientError::AccountNotLocked(account_id) => {
+                write!(f, "Account {account_id} is not locked")
+            },
+            ClientError::AccountUnlockError(err) => write!(f, "Error unlocking account: {err}"),
             ClientError::AssetError(err) => write!(f, "Asset error: {err}"),
+            ClientError::AuthKeyRotationUnsupported(account_id) => {
+                write!(f, "Account {account_id} doesn't support rotating its authentication key")
+            },
+            ClientError::ChainBehindLocalState { local, node } => {
+                write!(
+                    f,
+                    "The node's chain tip (block {node}) is behind the client's sync height (block {local}), the node might have been reset"
+                )
+            },
             ClientError::DataDeserializationError(err) => {
                 write!(f, "Data deserialization error: {err}")
             },
+            ClientError::DerivedStateInconsistent { category, reason } => {
+                write!(f, "The rebuilt {category} are inconsistent: {reason}")
+            },
+            ClientError::DiscardedTransactionNotRetriable { transaction_id, reason } => {
+                write!(f, "Discarded transaction {transaction_id} can't be retried: {reason}")
+            },
+            ClientError::ExpectedNoteLimitReached(limit) => write!(
+                f,
+                "Can't import more expected notes: the client already tracks the maximum of {limit}"
+            ),
+            ClientError::ForeignAccountBlockUnavailable { block_num } => write!(
+                f,
+                "The header of block {block_num}, which the foreign account data was retrieved at, \
+                 couldn't be retrieved and authenticated"
+            ),
+            ClientError::FpiBlockMismatch { fpi_block_num, note_id, note_block_num } => write!(
+                f,
+                "Input note {} was committed at block {note_block_num}, after block {fpi_block_num} \
+                 which the foreign account data is at",
+                note_id.to_hex()
+            ),
             ClientError::NoteNotFoundOnChain(note_id) => {
                 write!(f, "The note with ID {note_id} doesn't exist in the chain")
             },
@@ -64,28 +233,83 @@ impl fmt::Display for ClientError {
                 f,
                 "Import account error: can't import a new account without its initial seed"
             ),
+            ClientError::ImportedAccountStateMismatch { account_id, imported_hash, node_hash } => {
+                write!(
+                    f,
+                    "Import account error: the imported state of account {account_id} ({imported_hash}) doesn't match the node's ({node_hash}); force the import to track it anyway"
+                )
+            },
+            ClientError::InvalidProverEndpoint { endpoint, reason } => {
+                write!(f, "Invalid remote prover endpoint {endpoint}: {reason}")
+            },
+            ClientError::InvalidStorageSlotWatch { watch, reason } => write!(
+                f,
+                "Can't watch storage slot {} of account {}: {reason}",
+                watch.slot_index, watch.account_id
+            ),
             ClientError::MerkleError(merkle_error) => {
                 write!(f, "Error with merkle path: {merkle_error}")
             },
-            ClientError::MissingOutputNotes(note_ids) => {
+            ClientError::MissingOutputNotes(mismatches) => {
                 write!(
                     f,
-                    "Transaction error: The transaction did not produce the expected notes corresponding to Note IDs: {}",
-                    note_ids.iter().map(|&id| id.to_hex()).collect::<Vec<_>>().join(", ")
+                    "Transaction error: The transaction did not produce the expected notes: {}",
+                    mismatches.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
                 )
             },
             ClientError::NoConsumableNoteForAccount(account_id) => {
                 write!(f, "No consumable note for account ID {}", account_id)
             },
+            ClientError::NoteConversionError(err) => write!(f, "Note conversion error: {err}"),
             ClientError::NoteError(err) => write!(f, "Note error: {err}"),
             ClientError::NoteImportError(err) => write!(f, "Error importing note: {err}"),
+            ClientError::NoteNotExpected(note_id) => {
+                write!(f, "Note {note_id} is not expected anymore, its tag can't be updated")
+            },
             ClientError::NoteRecordError(err) => write!(f, "Note record error: {err}"),
+            ClientError::NoteTagMismatch { provided, derived } => write!(
+                f,
+                "The note file has tag {provided}, but the note's details are meant for tag {derived}"
+            ),
             ClientError::RpcError(err) => write!(f, "RPC api error: {err}"),
             ClientError::NoteScreenerError(err) => write!(f, "Note screener error: {err}"),
+            ClientError::LocalProverUnavailable => write!(
+                f,
+                "The local prover is not included in this build of the client, a remote prover \
+                 must be used instead"
+            ),
+            ClientError::LocalTransactionNotDiscardable { transaction_id, reason } => {
+                write!(f, "Transaction {transaction_id} can't be discarded: {reason}")
+            },
+            ClientError::PendingTransactionsExcludedFromSync { account_id, transaction_ids } => {
+                write!(
+                    f,
+                    "Account {account_id} has {} pending transactions and must be included in the \
+                     sync",
+                    transaction_ids.len()
+                )
+            },
+            ClientError::PrivateAccountMismatch { account_id, remote_hash } => write!(
+                f,
+                "The node reported commitment {remote_hash} for private account {account_id}, \
+                 which doesn't match any of its local states"
+            ),
+            ClientError::ProvingBackendUnavailable(backend) => {
+                write!(f, "The {backend} proving backend is not available in this build")
+            },
             ClientError::StoreError(err) => write!(f, "Store error: {err}"),
+            ClientError::SyncIncomplete { synced_to, source } => {
+                write!(f, "Sync failed after advancing to block {synced_to}: {source}")
+            },
             ClientError::TransactionExecutorError(err) => {
                 write!(f, "Transaction executor error: {err}")
             },
+            ClientError::TransactionNotDiscarded(transaction_id) => {
+                write!(f, "Transaction {transaction_id} was not discarded")
+            },
+            ClientError::TransactionNotFound(transaction_id) => {
+                write!(f, "Transaction {transaction_id} is not tracked by the client")
+            },
             ClientError::TransactionProvingError(err) => {
                 write!(f, "Transaction prover error: {err}")
             },
@@ -98,6 +322,7 @@ impl fmt::Display for ClientError {
             ClientError::TransactionScriptError(err) => {
                 write!(f, "Transaction script error: {err}")
             },
+            ClientError::ViewCallError(err) => write!(f, "View call error: {err}"),
         }
     }
 }
@@ -129,6 +354,12 @@ impl From<NoteError> for ClientError {
     }
 }
 
+impl From<NoteConversionError> for ClientError {
+    fn from(err: NoteConversionError) -> Self {
+        Self::NoteConversionError(err)
+    }
+}
+
 impl From<NoteRecordError> for ClientError {
     fn from(err: NoteRecordError) -> Self {
         Self::NoteRecordError(err)
@@ -177,6 +408,12 @@ impl From<TransactionRequestError> for ClientError {
     }
 }
 
+impl From<ViewCallError> for ClientError {
+    fn from(err: ViewCallError) -> Self {
+        Self::ViewCallError(err)
+    }
+}
+
 impl From<ClientError> for String {
     fn from(err: ClientError) -> String {
         err.to_string()
@@ -189,6 +426,75 @@ impl From<TransactionScriptBuilderError> for ClientError {
     }
 }
 
+// OUTPUT NOTE MISMATCH
+// ================================================================================================
+
+/// Difference between a note expected by a transaction request and the notes produced by the
+/// executed transaction.
+///
+/// Notes are compared by their commitment, which covers the note ID and metadata. A note whose ID
+/// matches an output note but whose metadata doesn't is reported as
+/// [OutputNoteMismatch::Mismatched], while a note whose ID doesn't match any output note is
+/// reported as [OutputNoteMismatch::Missing].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputNoteMismatch {
+    /// The transaction didn't produce any note with the expected ID.
+    Missing {
+        note_id: NoteId,
+        expected_commitment: Digest,
+    },
+    /// The transaction produced a note with the expected ID but different metadata.
+    Mismatched {
+        note_id: NoteId,
+        expected_commitment: Digest,
+        actual_commitment: Digest,
+        expected_metadata: NoteMetadata,
+        actual_metadata: NoteMetadata,
+    },
+}
+
+impl OutputNoteMismatch {
+    /// Returns the ID of the expected note.
+    pub fn note_id(&self) -> NoteId {
+        match self {
+            OutputNoteMismatch::Missing { note_id, .. }
+            | OutputNoteMismatch::Mismatched { note_id, .. } => *note_id,
+        }
+    }
+}
+
+impl fmt::Display for OutputNoteMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputNoteMismatch::Missing { note_id, expected_commitment } => {
+                write!(
+                    f,
+                    "note {} was not produced (expected commitment {})",
+                    note_id.to_hex(),
+                    expected_commitment.to_hex()
+                )
+            },
+            OutputNoteMismatch::Mismatched {
+                note_id,
+                expected_commitment,
+                actual_commitment,
+                expected_metadata,
+                actual_metadata,
+            } => {
+                write!(
+                    f,
+                    "note {} was produced with commitment {} instead of {} (expected metadata {:?}, actual metadata {:?})",
+                    note_id.to_hex(),
+                    actual_commitment.to_hex(),
+                    expected_commitment.to_hex(),
+                    expected_metadata,
+                    actual_metadata
+                )
+            },
+        }
+    }
+}
+
 // ID PREFIX FETCH ERROR
 // ================================================================================================
 
@@ -197,8 +503,9 @@ impl From<TransactionScriptBuilderError> for ClientError {
 pub enum IdPrefixFetchError {
     /// No matches were found for the ID prefix
     NoMatch(String),
-    /// Multiple entities matched with the ID prefix
-    MultipleMatches(String),
+    /// Multiple entities matched with the ID prefix. Contains the IDs of the matching entities,
+    /// so that they can be shown to disambiguate the prefix.
+    MultipleMatches(String, Vec<String>),
 }
 
 impl fmt::Display for IdPrefixFetchError {
@@ -207,10 +514,11 @@ impl fmt::Display for IdPrefixFetchError {
             IdPrefixFetchError::NoMatch(id) => {
                 write!(f, "No matches were found with the {id}.")
             },
-            IdPrefixFetchError::MultipleMatches(id) => {
+            IdPrefixFetchError::MultipleMatches(id, candidates) => {
                 write!(
                     f,
-                    "Found more than one element for the provided {id} and only one match is expected."
+                    "Found more than one element for the provided {id} and only one match is expected. Candidates: {}",
+                    candidates.join(", ")
                 )
             },
         }
@@ -0,0 +1,46 @@
+//! Provides helpers to work with the hex representation of the IDs tracked by the client.
+//!
+//! Note and account IDs are rendered as lowercase hex strings starting with `0x`, which is the
+//! format returned by `to_hex` and the one used by the stores to persist them. User-provided
+//! prefixes are normalized to the same format with [normalize_id_prefix] before being resolved
+//! with [Client::find_note_by_prefix](crate::Client::find_note_by_prefix) or
+//! [Client::find_account_by_prefix](crate::Client::find_account_by_prefix).
+
+use alloc::string::String;
+
+/// Normalizes a user-provided ID prefix: surrounding whitespace and an optional `0x`/`0X` prefix
+/// are removed, the hex digits are lowercased and the result is prefixed with `0x`.
+///
+/// Returns `None` if the prefix contains characters that are not hex digits, in which case it
+/// can't match any ID.
+pub fn normalize_id_prefix(prefix: &str) -> Option<String> {
+    let prefix = prefix.trim();
+    let digits = prefix
+        .strip_prefix("0x")
+        .or_else(|| prefix.strip_prefix("0X"))
+        .unwrap_or(prefix);
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(format!("0x{}", digits.to_ascii_lowercase()))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_id_prefix;
+
+    #[test]
+    fn test_normalize_id_prefix() {
+        assert_eq!(normalize_id_prefix("0xABcd").as_deref(), Some("0xabcd"));
+        assert_eq!(normalize_id_prefix("0XAbCd").as_deref(), Some("0xabcd"));
+        assert_eq!(normalize_id_prefix(" abcd ").as_deref(), Some("0xabcd"));
+        assert_eq!(normalize_id_prefix("0x").as_deref(), Some("0x"));
+        assert_eq!(normalize_id_prefix("0xabz"), None);
+        assert_eq!(normalize_id_prefix("ab%"), None);
+    }
+}
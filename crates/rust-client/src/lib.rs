@@ -10,10 +10,14 @@ extern crate std;
 
 pub mod accounts;
 pub mod config;
+pub mod ids;
+pub mod maintenance;
 pub mod notes;
+pub mod recovery;
 pub mod rpc;
 pub mod store;
 pub mod sync;
+pub mod time;
 pub mod transactions;
 
 #[cfg(test)]
@@ -65,7 +69,7 @@ pub mod crypto {
     };
 }
 
-pub use errors::{ClientError, IdPrefixFetchError};
+pub use errors::{ClientError, IdPrefixFetchError, OutputNoteMismatch};
 pub use miden_objects::{Felt, StarkField, Word, ONE, ZERO};
 
 /// Provides various utilities that are commonly used throughout the Miden
@@ -85,12 +89,13 @@ pub mod testing {
     pub use miden_objects::{accounts::account_id::testing::*, testing::*};
 }
 
-use alloc::sync::Arc;
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
 
-use miden_objects::crypto::rand::FeltRng;
+use miden_objects::{accounts::AccountId, crypto::rand::FeltRng};
 use miden_tx::{auth::TransactionAuthenticator, DataStore, TransactionExecutor, TransactionProver};
 use rpc::NodeRpcClient;
 use store::{data_store::ClientDataStore, Store};
+use time::TimeSource;
 use tracing::info;
 
 // MIDEN CLIENT
@@ -115,7 +120,46 @@ pub struct Client<R: FeltRng> {
     rpc_api: Box<dyn NodeRpcClient + Send>,
     /// An instance of [TransactionProver] which delegates proving.
     tx_prover: Arc<dyn TransactionProver>,
+    /// Description of `tx_prover`, recorded in the provenance of submitted transactions.
+    tx_prover_description: Option<String>,
+    /// Backend used to prove transactions locally.
+    proving_backend: transactions::ProvingBackendInfo,
     tx_executor: TransactionExecutor,
+    /// Whether transactions are executed in debug mode.
+    in_debug_mode: bool,
+    /// Maximum number of committed notes that are processed in a single sync iteration.
+    max_notes_per_sync_iteration: usize,
+    /// Maximum number of note IDs sent to the node in a single request.
+    max_notes_per_request: usize,
+    /// Number of blocks the sync height has to advance before the MMR checkpoint is refreshed.
+    mmr_checkpoint_interval: u32,
+    /// Which untracked public notes received during a sync have their details fetched.
+    note_detail_fetch_policy: sync::NoteDetailFetchPolicy,
+    /// Maximum number of expected notes that can be tracked at once, if limited.
+    max_expected_notes: Option<usize>,
+    /// How imported note details whose tag doesn't match the derived one are handled.
+    note_tag_policy: notes::NoteTagPolicy,
+    /// Maximum size of the advice data of the transaction requests executed by the client.
+    max_advice_data_size: usize,
+    /// Whether the proofs of submitted transactions are retained in the store.
+    retain_proven_transactions: bool,
+    /// Maximum size of the proofs retained in the store.
+    max_retained_proofs_size: usize,
+    /// Log of the most recent sync iterations, if enabled.
+    sync_journal: Option<sync::SyncJournal>,
+    /// Blocks advanced and time taken by the most recent sync iterations.
+    sync_rate: sync::SyncRateTracker,
+    /// Callbacks invoked with the changes of watched value slots, by account and slot index.
+    storage_change_callbacks: BTreeMap<(AccountId, u8), Vec<sync::StorageChangeCallback>>,
+    /// Sources polled for notes at the end of every sync, besides the node.
+    note_sources: Vec<notes::RegisteredNoteSource>,
+    /// How the client reacts when the node reports an unknown commitment for a private account.
+    private_account_mismatch_policy: accounts::PrivateAccountMismatchPolicy,
+    /// Source of the wall-clock time used to timestamp records. If it's not set, timestamps are
+    /// not recorded.
+    time_source: Option<Arc<dyn TimeSource>>,
+    /// Profile the client settings were populated from, if any.
+    profile: Option<config::ClientProfile>,
 }
 
 impl<R: FeltRng> Client<R> {
@@ -165,8 +209,132 @@ impl<R: FeltRng> Client<R> {
             rng,
             rpc_api,
             tx_executor,
+            in_debug_mode,
             tx_prover,
+            tx_prover_description: None,
+            proving_backend: transactions::ProvingBackendInfo {
+                requested: transactions::ProvingBackend::Auto,
+                acceleration: transactions::Acceleration::available(),
+            },
+            max_notes_per_sync_iteration: sync::DEFAULT_MAX_NOTES_PER_SYNC_ITERATION,
+            max_notes_per_request: sync::DEFAULT_MAX_NOTES_PER_REQUEST,
+            mmr_checkpoint_interval: sync::DEFAULT_MMR_CHECKPOINT_INTERVAL,
+            note_detail_fetch_policy: sync::NoteDetailFetchPolicy::default(),
+            max_expected_notes: None,
+            note_tag_policy: notes::NoteTagPolicy::default(),
+            max_advice_data_size: transactions::DEFAULT_MAX_ADVICE_DATA_SIZE,
+            retain_proven_transactions: false,
+            max_retained_proofs_size: transactions::DEFAULT_MAX_RETAINED_PROOFS_SIZE,
+            sync_journal: None,
+            sync_rate: sync::SyncRateTracker::default(),
+            storage_change_callbacks: BTreeMap::new(),
+            note_sources: Vec::new(),
+            private_account_mismatch_policy: accounts::PrivateAccountMismatchPolicy::default(),
+            time_source: time::default_time_source(),
+            profile: None,
+        }
+    }
+
+    /// Sets the [TimeSource] used by the client to timestamp records, replacing the default one.
+    ///
+    /// This is mostly useful for `no_std` targets, where there's no default time source and
+    /// timestamps are otherwise unavailable.
+    pub fn with_time_source(mut self, time_source: Arc<dyn TimeSource>) -> Self {
+        self.time_source = Some(time_source);
+        self
+    }
+
+    /// Replaces the [TransactionProver] used to prove the transactions submitted with
+    /// [Client::submit_transaction]. The description of the previous prover is cleared, so a new
+    /// one should be set with [Client::with_prover_description] if the provenance of the proven
+    /// transactions has to record it.
+    pub fn with_prover(mut self, tx_prover: Arc<dyn TransactionProver>) -> Self {
+        self.tx_prover = tx_prover;
+        self.tx_prover_description = None;
+        self
+    }
+
+    /// Proves the transactions submitted with [Client::submit_transaction] with the remote
+    /// proving service at `endpoint`, which is also recorded as the prover's description.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ClientError::InvalidProverEndpoint] if the endpoint can't be parsed. The service
+    /// is only reached when a transaction is proven.
+    #[cfg(feature = "remote-prover")]
+    pub fn with_remote_prover(self, endpoint: &str) -> Result<Self, ClientError> {
+        let endpoint = config::Endpoint::try_from(endpoint).map_err(|reason| {
+            ClientError::InvalidProverEndpoint { endpoint: String::from(endpoint), reason }
+        })?;
+        let tx_prover = transactions::RemoteTransactionProver::new(&format!("{endpoint}"));
+
+        Ok(self
+            .with_prover(Arc::new(tx_prover))
+            .with_prover_description(format!("remote ({endpoint})")))
+    }
+
+    /// Sets a description of the client's [TransactionProver], like `local` or the endpoint of a
+    /// remote prover, which is recorded in the provenance of the transactions it proves.
+    pub fn with_prover_description(mut self, description: impl Into<String>) -> Self {
+        self.tx_prover_description = Some(description.into());
+        self
+    }
+
+    /// Returns the [TransactionProver] used to prove the transactions submitted with
+    /// [Client::submit_transaction].
+    pub fn prover(&self) -> Arc<dyn TransactionProver> {
+        self.tx_prover.clone()
+    }
+
+    /// Returns the description of the client's [TransactionProver], if one was set.
+    pub fn prover_description(&self) -> Option<&str> {
+        self.tx_prover_description.as_deref()
+    }
+
+    /// Populates the client settings from a [ClientProfile](config::ClientProfile), whose
+    /// documentation lists the values it sets. The profile should be selected before changing
+    /// individual settings, which then override the profile's values.
+    pub fn with_profile(mut self, profile: config::ClientProfile) -> Self {
+        use accounts::PrivateAccountMismatchPolicy;
+        use config::ClientProfile;
+
+        match profile {
+            ClientProfile::Wallet => {
+                self.set_max_notes_per_sync_iteration(256);
+                self.set_mmr_checkpoint_interval(1);
+                self.max_expected_notes = Some(1000);
+                self.private_account_mismatch_policy = PrivateAccountMismatchPolicy::Lock;
+            },
+            ClientProfile::Server => {
+                self.set_max_notes_per_sync_iteration(4096);
+                self.set_max_notes_per_request(250);
+                self.set_mmr_checkpoint_interval(16);
+                self.max_expected_notes = None;
+                self = self.with_sync_journal(64);
+            },
+            ClientProfile::Testing => {
+                self.set_max_notes_per_sync_iteration(16);
+                self.set_mmr_checkpoint_interval(1);
+                self.private_account_mismatch_policy = PrivateAccountMismatchPolicy::Error;
+                self = self.with_sync_journal(16);
+            },
         }
+        info!("Using the {profile} client profile.");
+        self.profile = Some(profile);
+
+        self
+    }
+
+    /// Returns the name of the profile the client settings were populated from, or `None` if no
+    /// profile was selected.
+    pub fn profile_name(&self) -> Option<&'static str> {
+        self.profile.map(|profile| profile.name())
+    }
+
+    /// Returns the current Unix timestamp in seconds according to the client's [TimeSource], or
+    /// `None` if the client doesn't have one.
+    pub fn current_timestamp(&self) -> Option<u64> {
+        self.time_source.as_ref().map(|time_source| time_source.now())
     }
 
     /// Returns a reference to the client's random number generator. This can be used to generate
@@ -175,6 +343,23 @@ impl<R: FeltRng> Client<R> {
         &mut self.rng
     }
 
+    // SHUTDOWN
+    // --------------------------------------------------------------------------------------------
+
+    /// Flushes the store and shuts the client down. The client is consumed, so it can't be used
+    /// after calling this method.
+    ///
+    /// Unlike dropping the client, this reports errors that happen while persisting the store's
+    /// pending writes. Every client operation needs a reference to the client, so no sync or
+    /// transaction can be in flight when it's called. A sync left in the middle of a block is
+    /// continued from its last applied batch of notes by the next client that syncs the store.
+    pub async fn shutdown(self) -> Result<(), ClientError> {
+        self.store.flush().await?;
+        info!("Client store flushed, shutting down.");
+
+        Ok(())
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -0,0 +1,288 @@
+//! Provides the maintenance tasks that keep the client's store tidy, and a [MaintenanceScheduler]
+//! to run them periodically under `std`.
+//!
+//! Each task processes at most [MAX_ITEMS_PER_MAINTENANCE_TASK] items per run, so a run is
+//! bounded regardless of the size of the store. Items left over are handled by the next run.
+
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::{fmt, time::Duration};
+
+use miden_objects::{accounts::AccountId, crypto::rand::FeltRng, notes::NoteId};
+use tracing::{info, warn};
+
+use crate::{
+    store::{InputNoteRecord, NoteFilter},
+    sync::NoteTagSource,
+    time::Stopwatch,
+    Client, ClientError,
+};
+
+/// Maximum number of items a maintenance task processes in a single run.
+pub const MAX_ITEMS_PER_MAINTENANCE_TASK: usize = 500;
+
+// MAINTENANCE TASK
+// ================================================================================================
+
+/// Maintenance task that can be run with [Client::run_maintenance].
+///
+/// Tasks are ordered in the order they are run, so that a task never leaves behind work for one
+/// that already ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MaintenanceTask {
+    /// Removes the pending notes that were already imported as input notes, along with their
+    /// tags.
+    ClearResolvedPendingNotes,
+    /// Moves the consumed input notes to the archive.
+    ArchiveConsumedNotes,
+    /// Stops tracking the tags of notes that are no longer expected and of accounts that are no
+    /// longer tracked.
+    RemoveStaleTags,
+}
+
+impl MaintenanceTask {
+    /// Every maintenance task, in the order they are run.
+    pub const ALL: [MaintenanceTask; 3] = [
+        MaintenanceTask::ClearResolvedPendingNotes,
+        MaintenanceTask::ArchiveConsumedNotes,
+        MaintenanceTask::RemoveStaleTags,
+    ];
+}
+
+impl fmt::Display for MaintenanceTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaintenanceTask::ClearResolvedPendingNotes => write!(f, "clear resolved pending notes"),
+            MaintenanceTask::ArchiveConsumedNotes => write!(f, "archive consumed notes"),
+            MaintenanceTask::RemoveStaleTags => write!(f, "remove stale tags"),
+        }
+    }
+}
+
+// MAINTENANCE REPORT
+// ================================================================================================
+
+/// Result of running a single [MaintenanceTask].
+#[derive(Debug)]
+pub struct MaintenanceTaskReport {
+    /// Task that was run.
+    pub task: MaintenanceTask,
+    /// Number of items affected by the task, or the error that stopped it.
+    pub result: Result<usize, ClientError>,
+    /// Time spent running the task. Only measured when the `std` feature is enabled.
+    pub duration: Option<Duration>,
+}
+
+/// Results of the tasks run by [Client::run_maintenance], in the order they were run.
+#[derive(Debug, Default)]
+pub struct MaintenanceReport {
+    pub tasks: Vec<MaintenanceTaskReport>,
+}
+
+impl MaintenanceReport {
+    /// Returns the report of the specified task, if it was run.
+    pub fn task(&self, task: MaintenanceTask) -> Option<&MaintenanceTaskReport> {
+        self.tasks.iter().find(|report| report.task == task)
+    }
+
+    /// Returns the total number of items affected by the tasks that succeeded.
+    pub fn items_affected(&self) -> usize {
+        self.tasks.iter().filter_map(|report| report.result.as_ref().ok()).sum()
+    }
+
+    /// Returns the reports of the tasks that failed.
+    pub fn failed_tasks(&self) -> impl Iterator<Item = &MaintenanceTaskReport> {
+        self.tasks.iter().filter(|report| report.result.is_err())
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // MAINTENANCE
+    // --------------------------------------------------------------------------------------------
+
+    /// Runs the specified maintenance tasks and reports the items each one affected.
+    ///
+    /// Tasks are run once each in the order of [MaintenanceTask::ALL], regardless of the order in
+    /// which they are provided. A task that fails doesn't prevent the rest from running; its
+    /// error is included in the report.
+    pub async fn run_maintenance(&mut self, tasks: &[MaintenanceTask]) -> MaintenanceReport {
+        let tasks: BTreeSet<MaintenanceTask> = tasks.iter().copied().collect();
+        let mut report = MaintenanceReport::default();
+
+        for task in tasks {
+            let stopwatch = Stopwatch::start();
+            let result = match task {
+                MaintenanceTask::ClearResolvedPendingNotes => {
+                    self.clear_resolved_pending_notes().await
+                },
+                MaintenanceTask::ArchiveConsumedNotes => self.archive_consumed_notes().await,
+                MaintenanceTask::RemoveStaleTags => self.remove_stale_tags().await,
+            };
+
+            match &result {
+                Ok(items) => info!("Maintenance task '{task}' affected {items} items."),
+                Err(err) => warn!("Maintenance task '{task}' failed: {err}"),
+            }
+            report.tasks.push(MaintenanceTaskReport {
+                task,
+                result,
+                duration: stopwatch.elapsed(),
+            });
+        }
+
+        report
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes the pending notes that are already input notes and returns how many were removed.
+    async fn clear_resolved_pending_notes(&mut self) -> Result<usize, ClientError> {
+        let pending_notes = self.store.get_pending_notes().await?;
+        if pending_notes.is_empty() {
+            return Ok(0);
+        }
+
+        let input_note_ids = self
+            .input_note_ids(NoteFilter::List(
+                pending_notes.iter().map(|note| note.note_id()).collect(),
+            ))
+            .await?;
+
+        let mut cleared = 0;
+        for pending_note in pending_notes
+            .into_iter()
+            .filter(|note| input_note_ids.contains(&note.note_id()))
+            .take(MAX_ITEMS_PER_MAINTENANCE_TASK)
+        {
+            self.store.remove_pending_note(pending_note.note_id()).await?;
+            if let Some(tag_record) = pending_note.tag_record() {
+                self.store.remove_note_tag(tag_record).await?;
+            }
+            cleared += 1;
+        }
+
+        Ok(cleared)
+    }
+
+    /// Archives the consumed input notes and returns how many were archived.
+    async fn archive_consumed_notes(&mut self) -> Result<usize, ClientError> {
+        let mut notes = self.store.get_input_notes(NoteFilter::Consumed).await?;
+        notes.truncate(MAX_ITEMS_PER_MAINTENANCE_TASK);
+
+        self.store.archive_input_notes(&notes).await?;
+
+        Ok(notes.len())
+    }
+
+    /// Removes the tag records whose source note is no longer expected or pending, or whose
+    /// source account is no longer tracked, and returns how many were removed. Tags added by the
+    /// user are kept.
+    async fn remove_stale_tags(&mut self) -> Result<usize, ClientError> {
+        let expected_note_ids = self.input_note_ids(NoteFilter::Expected).await?;
+        let pending_note_ids: BTreeSet<NoteId> = self
+            .store
+            .get_pending_notes()
+            .await?
+            .iter()
+            .map(|note| note.note_id())
+            .collect();
+        let account_ids: BTreeSet<AccountId> =
+            self.store.get_account_ids().await?.into_iter().collect();
+
+        let stale_tags: Vec<_> = self
+            .store
+            .get_note_tags()
+            .await?
+            .into_iter()
+            .filter(|tag_record| match tag_record.source {
+                NoteTagSource::Note(note_id) => {
+                    !expected_note_ids.contains(&note_id) && !pending_note_ids.contains(&note_id)
+                },
+                NoteTagSource::Account(account_id) => !account_ids.contains(&account_id),
+                NoteTagSource::User => false,
+            })
+            .take(MAX_ITEMS_PER_MAINTENANCE_TASK)
+            .collect();
+
+        for tag_record in stale_tags.iter() {
+            self.store.remove_note_tag(*tag_record).await?;
+        }
+
+        Ok(stale_tags.len())
+    }
+
+    /// Returns the IDs of the input notes that match the filter.
+    async fn input_note_ids(&self, filter: NoteFilter) -> Result<BTreeSet<NoteId>, ClientError> {
+        Ok(self
+            .store
+            .get_input_notes(filter)
+            .await?
+            .iter()
+            .map(InputNoteRecord::id)
+            .collect())
+    }
+}
+
+// MAINTENANCE SCHEDULER
+// ================================================================================================
+
+/// Runs a set of maintenance tasks at most once per interval.
+///
+/// The application calls [MaintenanceScheduler::tick] from the loop that drives its background
+/// sync. A run that is due is postponed while the client is in the middle of syncing a block or
+/// has transactions that are not committed yet.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct MaintenanceScheduler {
+    tasks: Vec<MaintenanceTask>,
+    interval: Duration,
+    last_run: Option<std::time::Instant>,
+}
+
+#[cfg(feature = "std")]
+impl MaintenanceScheduler {
+    /// Returns a scheduler that runs `tasks` every `interval`. The first run is due right away.
+    pub fn new(tasks: Vec<MaintenanceTask>, interval: Duration) -> Self {
+        Self { tasks, interval, last_run: None }
+    }
+
+    /// Returns whether the interval elapsed since the last run.
+    pub fn is_due(&self) -> bool {
+        self.last_run.map_or(true, |last_run| last_run.elapsed() >= self.interval)
+    }
+
+    /// Runs the scheduled tasks if a run is due and the client is idle, and returns the report
+    /// of the run. Returns `None` if no run took place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client's uncommitted transactions or sync progress can't be
+    /// retrieved.
+    pub async fn tick<R: FeltRng>(
+        &mut self,
+        client: &mut Client<R>,
+    ) -> Result<Option<MaintenanceReport>, ClientError> {
+        if !self.is_due() {
+            return Ok(None);
+        }
+
+        if client.store.get_partial_sync_progress().await?.is_some() {
+            info!("Skipping maintenance while a block is partially synced.");
+            return Ok(None);
+        }
+
+        if !client
+            .store
+            .get_transactions(crate::store::TransactionFilter::Uncomitted)
+            .await?
+            .is_empty()
+        {
+            info!("Skipping maintenance while transactions are in flight.");
+            return Ok(None);
+        }
+
+        self.last_run = Some(std::time::Instant::now());
+
+        Ok(Some(client.run_maintenance(&self.tasks).await))
+    }
+}
@@ -1,9 +1,10 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
     sync::Arc,
     vec::Vec,
 };
-use std::env::temp_dir;
+use std::{env::temp_dir, time::Duration};
 
 use async_trait::async_trait;
 use miden_lib::transaction::TransactionKernel;
@@ -12,7 +13,7 @@ use miden_objects::{
         account_id::testing::{
             ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
         },
-        AccountId,
+        Account, AccountId,
     },
     assets::{FungibleAsset, NonFungibleAsset},
     block::Block,
@@ -20,12 +21,15 @@ use miden_objects::{
         merkle::{Mmr, MmrProof},
         rand::RpoRandomCoin,
     },
-    notes::{Note, NoteId, NoteTag},
+    notes::{Note, NoteId, NoteMetadata, NoteTag},
     testing::notes::NoteBuilder,
     transaction::{InputNote, ProvenTransaction},
     BlockHeader, Digest, Felt, Word,
 };
-use miden_tx::{testing::mock_chain::MockChain, LocalTransactionProver};
+#[cfg(feature = "local-prover")]
+use miden_tx::LocalTransactionProver;
+use miden_tx::{testing::mock_chain::MockChain, TransactionProver};
+use prost::Message;
 use rand::Rng;
 use tonic::Response;
 use uuid::Uuid;
@@ -33,15 +37,19 @@ use uuid::Uuid;
 use crate::{
     rpc::{
         generated::{
+            account::AccountSummary,
             note::NoteSyncRecord,
+            requests::SyncStateRequest,
             responses::{NullifierUpdate, SyncNoteResponse, SyncStateResponse},
+            transaction::{TransactionId as ProtoTransactionId, TransactionSummary},
         },
-        AccountDetails, AccountProofs, NodeRpcClient, NoteDetails, NoteInclusionDetails, RpcError,
-        StateSyncInfo,
+        AccountDetails, AccountProofs, AccountUpdateSummary, NodeRpcClient, NodeRpcClientEndpoint,
+        NoteDetails, NoteInclusionDetails, RpcError, RpcLatencyTracker, RpcMethodStats, RpcTraffic,
+        RpcTrafficCounter, StateSyncInfo, SubmissionInfo, TransactionUpdate,
     },
     store::{
         sqlite_store::{config::SqliteStoreConfig, SqliteStore},
-        StoreAuthenticator,
+        Store, StoreAuthenticator,
     },
     Client,
 };
@@ -57,6 +65,37 @@ pub struct MockRpcApi {
     pub notes: BTreeMap<NoteId, InputNote>,
     pub blocks: Vec<Block>,
     pub mock_chain: MockChain,
+    pub updated_accounts: BTreeMap<AccountId, Account>,
+    /// Block in which each account added with [MockRpcApi::add_block_with_account_update] was
+    /// last updated. Like the node, its update is only reported by the sync responses that cover
+    /// that block. The accounts inserted in `updated_accounts` directly are reported by every
+    /// sync response.
+    account_update_blocks: BTreeMap<AccountId, u32>,
+    /// Maximum number of note IDs accepted in a single `GetNotesById` request, if any.
+    pub max_notes_per_request: Option<usize>,
+    /// Block number from which `SyncState` requests fail, if any.
+    pub fail_sync_from_block: Option<u32>,
+    /// Block numbers whose `GetBlockHeaderByNumber` requests fail.
+    pub fail_block_header_requests: BTreeSet<u32>,
+    /// Block number reported by `GetAccountProofs` instead of the chain tip, if any.
+    pub account_proofs_block_num: Option<u32>,
+    /// Delay injected in the responses of each RPC method, which is also recorded as the latency
+    /// of the call.
+    pub response_delays: BTreeMap<NodeRpcClientEndpoint, Duration>,
+    /// Metadata reported in the sync responses for the specified notes instead of the one they
+    /// were committed with.
+    pub doctored_note_metadata: BTreeMap<NoteId, NoteMetadata>,
+    /// Transactions reported as committed in the sync responses that cover their block.
+    pub committed_transactions: Vec<TransactionUpdate>,
+    /// Notes whose full details are returned by `GetNotesById`, as the node does for public
+    /// notes. The rest of the notes are returned as private.
+    pub public_note_details: BTreeSet<NoteId>,
+    /// Number of upcoming `SubmitProvenTransaction` requests that fail as if they timed out.
+    pub failing_submissions: u32,
+    /// Whether `GetAccountDetails` requests fail as if the node couldn't process them.
+    pub fail_account_details_requests: bool,
+    latencies: RpcLatencyTracker,
+    traffic: RpcTrafficCounter,
 }
 impl Default for MockRpcApi {
     fn default() -> Self {
@@ -71,6 +110,20 @@ impl MockRpcApi {
             notes: BTreeMap::new(),
             blocks: vec![],
             mock_chain,
+            updated_accounts: BTreeMap::new(),
+            account_update_blocks: BTreeMap::new(),
+            max_notes_per_request: None,
+            fail_sync_from_block: None,
+            fail_block_header_requests: BTreeSet::new(),
+            account_proofs_block_num: None,
+            response_delays: BTreeMap::new(),
+            doctored_note_metadata: BTreeMap::new(),
+            committed_transactions: vec![],
+            public_note_details: BTreeSet::new(),
+            failing_submissions: 0,
+            fail_account_details_requests: false,
+            latencies: RpcLatencyTracker::default(),
+            traffic: RpcTrafficCounter::default(),
         };
 
         let note_first = NoteBuilder::new(
@@ -116,6 +169,26 @@ impl MockRpcApi {
         self.blocks.push(block);
     }
 
+    /// Seals a new block containing the given notes and makes them available to the API.
+    pub fn add_block_with_notes(&mut self, notes: Vec<Note>) {
+        self.seal_block(notes, vec![]);
+        self.notes =
+            self.mock_chain.available_notes().iter().map(|n| (n.id(), n.clone())).collect();
+    }
+
+    /// Seals a new block in which the given nullifiers are added.
+    pub fn add_block_with_nullifiers(&mut self, nullifiers: Vec<miden_objects::notes::Nullifier>) {
+        self.seal_block(vec![], nullifiers);
+    }
+
+    /// Seals a new block in which the given account is updated to the provided state. Only the
+    /// commitment of private accounts is reported by the node.
+    pub fn add_block_with_account_update(&mut self, account: Account) {
+        self.seal_block(vec![], vec![]);
+        self.account_update_blocks.insert(account.id(), self.get_chain_tip_block_num());
+        self.updated_accounts.insert(account.id(), account);
+    }
+
     /// Returns the current MMR of the blockchain.
     pub fn get_mmr(&self) -> Mmr {
         self.blocks.iter().map(Block::hash).into()
@@ -138,6 +211,18 @@ impl MockRpcApi {
 
     /// Generates a sync state response based on the request block number.
     pub fn get_sync_state_request(&self, request_block_num: u32) -> SyncStateResponse {
+        // A node whose chain was reset reports its own tip, which is behind the requested block
+        let chain_tip = self.get_chain_tip_block_num();
+        if request_block_num > chain_tip {
+            let forest = self.blocks.len();
+            return SyncStateResponse {
+                chain_tip,
+                block_header: Some(self.blocks.last().unwrap().header().into()),
+                mmr_delta: self.get_mmr().get_delta(forest, forest).ok().map(Into::into),
+                ..Default::default()
+            };
+        }
+
         // Determine the next block number to sync
         let next_block_num = self
             .notes
@@ -173,12 +258,42 @@ impl MockRpcApi {
             })
             .collect();
 
+        // Report the latest commitment of every account updated up to the next block
+        let accounts = self
+            .updated_accounts
+            .values()
+            .filter(|account| {
+                self.account_update_blocks.get(&account.id()).map_or(true, |&block_num| {
+                    block_num > request_block_num && block_num <= next_block_num
+                })
+            })
+            .map(|account| AccountSummary {
+                account_id: Some(account.id().into()),
+                account_hash: Some(account.hash().into()),
+                block_num: next_block_num,
+            })
+            .collect();
+
+        // Report the transactions committed up to the next block
+        let transactions = self
+            .committed_transactions
+            .iter()
+            .filter(|tx| tx.block_num > request_block_num && tx.block_num <= next_block_num)
+            .map(|tx| TransactionSummary {
+                transaction_id: Some(ProtoTransactionId {
+                    id: Some(tx.transaction_id.inner().into()),
+                }),
+                block_num: tx.block_num,
+                account_id: Some(tx.account_id.into()),
+            })
+            .collect();
+
         SyncStateResponse {
             chain_tip: self.get_chain_tip_block_num(),
             block_header: Some(next_block.header().into()),
             mmr_delta,
-            accounts: vec![],
-            transactions: vec![],
+            accounts,
+            transactions,
             notes,
             nullifiers,
         }
@@ -189,10 +304,15 @@ impl MockRpcApi {
         self.notes.values().filter_map(move |note| {
             if note.location().map_or(false, |loc| loc.block_num() == block_num) {
                 let proof = note.proof()?;
+                let metadata = self
+                    .doctored_note_metadata
+                    .get(&note.id())
+                    .copied()
+                    .unwrap_or(*note.note().metadata());
                 Some(NoteSyncRecord {
-                    note_index: 0,
+                    note_index: proof.location().node_index_in_block().into(),
                     note_id: Some(note.id().into()),
-                    metadata: Some((*note.note().metadata()).into()),
+                    metadata: Some(metadata.into()),
                     merkle_path: Some(proof.note_path().clone().into()),
                 })
             } else {
@@ -200,19 +320,50 @@ impl MockRpcApi {
             }
         })
     }
+
+    /// Waits for the delay injected for the specified method, if any, and records it as the
+    /// latency of the call.
+    fn simulate_latency(&mut self, method: NodeRpcClientEndpoint) {
+        let delay = self.response_delays.get(&method).copied().unwrap_or_default();
+        std::thread::sleep(delay);
+        self.latencies.record(method, delay);
+    }
 }
 use alloc::boxed::Box;
 #[async_trait(?Send)]
 impl NodeRpcClient for MockRpcApi {
+    /// Returns the notes matching the tags in the first block after `block_num` that has any, or
+    /// the chain tip if there is none.
     async fn sync_notes(
         &mut self,
-        _block_num: u32,
-        _note_tags: &[NoteTag],
+        block_num: u32,
+        note_tags: &[NoteTag],
     ) -> Result<crate::rpc::NoteSyncInfo, RpcError> {
+        self.simulate_latency(NodeRpcClientEndpoint::SyncNotes);
+        let matching_notes: BTreeSet<NoteId> = self
+            .notes
+            .values()
+            .filter(|note| note_tags.contains(&note.note().metadata().tag()))
+            .map(InputNote::id)
+            .collect();
+        let next_block_num = self
+            .notes
+            .values()
+            .filter(|note| matching_notes.contains(&note.id()))
+            .filter_map(|note| note.location().map(|location| location.block_num()))
+            .filter(|&note_block_num| note_block_num > block_num)
+            .min()
+            .unwrap_or_else(|| self.get_chain_tip_block_num());
+
         let response = SyncNoteResponse {
-            chain_tip: self.blocks.len() as u32,
-            notes: vec![],
-            block_header: Some(self.blocks.last().unwrap().header().into()),
+            chain_tip: self.get_chain_tip_block_num(),
+            notes: self
+                .get_notes_in_block(next_block_num)
+                .filter(|note| {
+                    matching_notes.iter().any(|note_id| note.note_id == Some((*note_id).into()))
+                })
+                .collect(),
+            block_header: Some(self.blocks[next_block_num as usize].header().into()),
             mmr_path: Some(Default::default()),
         };
         let response = Response::new(response.clone());
@@ -223,34 +374,59 @@ impl NodeRpcClient for MockRpcApi {
     async fn sync_state(
         &mut self,
         block_num: u32,
-        _account_ids: &[AccountId],
-        _note_tags: &[NoteTag],
-        _nullifiers_tags: &[u16],
+        account_ids: &[AccountId],
+        note_tags: &[NoteTag],
+        nullifiers_tags: &[u16],
     ) -> Result<StateSyncInfo, RpcError> {
-        // Match request -> response through block_num
-        let response = self.get_sync_state_request(block_num);
+        if self.fail_sync_from_block.is_some_and(|from_block| block_num >= from_block) {
+            return Err(RpcError::ConnectionError("mock node is unreachable".to_string()));
+        }
+        self.simulate_latency(NodeRpcClientEndpoint::SyncState);
+
+        // Match request -> response through block_num. Like the node, only the requested
+        // accounts are reported
+        let mut response = self.get_sync_state_request(block_num);
+        response.accounts.retain(|summary| {
+            account_ids
+                .iter()
+                .any(|account_id| summary.account_id == Some((*account_id).into()))
+        });
+
+        // Record the sizes the messages would have over the wire
+        let request = SyncStateRequest {
+            block_num,
+            account_ids: account_ids.iter().map(|account_id| (*account_id).into()).collect(),
+            note_tags: note_tags.iter().map(|&note_tag| note_tag.into()).collect(),
+            nullifiers: nullifiers_tags.iter().map(|&nullifier| nullifier as u32).collect(),
+        };
+        self.traffic.record(
+            NodeRpcClientEndpoint::SyncState,
+            request.encoded_len(),
+            response.encoded_len(),
+        );
 
         Ok(response.try_into().unwrap())
     }
 
     /// Creates and executes a [GetBlockHeaderByNumberRequest].
-    /// Only used for retrieving genesis block right now so that's the only case we need to cover.
+    /// When no block number is provided, the header of the last block is returned.
     async fn get_block_header_by_number(
         &mut self,
         block_num: Option<u32>,
         include_mmr_proof: bool,
     ) -> Result<(BlockHeader, Option<MmrProof>), RpcError> {
+        self.simulate_latency(NodeRpcClientEndpoint::GetBlockHeaderByNumber);
         if block_num == Some(0) {
             return Ok((self.blocks.first().unwrap().header(), None));
         }
-        let block = self
-            .blocks
-            .iter()
-            .find(|b| b.header().block_num() == block_num.unwrap())
-            .unwrap();
+        let block_num = block_num.unwrap_or_else(|| self.get_chain_tip_block_num());
+        if self.fail_block_header_requests.contains(&block_num) {
+            return Err(RpcError::ConnectionError("mock node is unreachable".to_string()));
+        }
+        let block = self.blocks.iter().find(|b| b.header().block_num() == block_num).unwrap();
 
         let mmr_proof = if include_mmr_proof {
-            Some(self.get_mmr().open(block_num.unwrap() as usize).unwrap())
+            Some(self.get_mmr().open(block_num as usize).unwrap())
         } else {
             None
         };
@@ -259,6 +435,14 @@ impl NodeRpcClient for MockRpcApi {
     }
 
     async fn get_notes_by_id(&mut self, note_ids: &[NoteId]) -> Result<Vec<NoteDetails>, RpcError> {
+        self.simulate_latency(NodeRpcClientEndpoint::GetNotesById);
+        if self.max_notes_per_request.is_some_and(|max_notes| note_ids.len() > max_notes) {
+            return Err(RpcError::RequestError(
+                "GetNotesById".to_string(),
+                format!("too many note IDs in request: {}", note_ids.len()),
+            ));
+        }
+
         // assume all off-chain notes for now
         let hit_notes = note_ids.iter().filter_map(|id| self.notes.get(id));
         let mut return_notes = vec![];
@@ -274,11 +458,15 @@ impl NodeRpcClient for MockRpcApi {
                     .node_index_in_block(),
                 note.proof().expect("Note should have an inclusion proof").note_path().clone(),
             );
-            return_notes.push(NoteDetails::Private(
-                note.id(),
-                *note.note().metadata(),
-                inclusion_details,
-            ));
+            if self.public_note_details.contains(&note.id()) {
+                return_notes.push(NoteDetails::Public(note.note().clone(), inclusion_details));
+            } else {
+                return_notes.push(NoteDetails::Private(
+                    note.id(),
+                    *note.note().metadata(),
+                    inclusion_details,
+                ));
+            }
         }
         Ok(return_notes)
     }
@@ -286,16 +474,39 @@ impl NodeRpcClient for MockRpcApi {
     async fn submit_proven_transaction(
         &mut self,
         _proven_transaction: ProvenTransaction,
-    ) -> std::result::Result<(), RpcError> {
+    ) -> std::result::Result<SubmissionInfo, RpcError> {
         // TODO: add some basic validations to test error cases
-        Ok(())
+        if self.failing_submissions > 0 {
+            self.failing_submissions -= 1;
+            return Err(RpcError::ConnectionError("the request timed out".to_string()));
+        }
+
+        Ok(SubmissionInfo::first_attempt("mock"))
     }
 
     async fn get_account_update(
         &mut self,
-        _account_id: AccountId,
+        account_id: AccountId,
     ) -> Result<AccountDetails, RpcError> {
-        panic!("shouldn't be used for now")
+        if self.fail_account_details_requests {
+            return Err(RpcError::RequestError(
+                "GetAccountDetails".to_string(),
+                "internal error".to_string(),
+            ));
+        }
+        let account = self.updated_accounts.get(&account_id).cloned().ok_or_else(|| {
+            RpcError::NotFound(
+                "GetAccountDetails".to_string(),
+                format!("the node has no state for account {account_id}"),
+            )
+        })?;
+        let summary = AccountUpdateSummary::new(account.hash(), self.get_chain_tip_block_num());
+
+        if account.is_public() {
+            Ok(AccountDetails::Public(account, summary))
+        } else {
+            Ok(AccountDetails::Private(account_id, summary))
+        }
     }
 
     async fn get_account_proofs(
@@ -305,7 +516,10 @@ impl NodeRpcClient for MockRpcApi {
         _include_headers: bool,
     ) -> Result<AccountProofs, RpcError> {
         // TODO: Implement fully
-        Ok((self.blocks.last().unwrap().header().block_num(), vec![]))
+        let block_num = self
+            .account_proofs_block_num
+            .unwrap_or_else(|| self.blocks.last().unwrap().header().block_num());
+        Ok((block_num, vec![]))
     }
 
     async fn check_nullifiers_by_prefix(
@@ -315,12 +529,31 @@ impl NodeRpcClient for MockRpcApi {
         // Always return an empty list for now since it's only used when importing
         Ok(vec![])
     }
+
+    fn endpoint(&self) -> Option<String> {
+        Some("mock".to_string())
+    }
+
+    fn latency_stats(&self) -> Vec<RpcMethodStats> {
+        self.latencies.stats()
+    }
+
+    fn traffic(&self) -> BTreeMap<NodeRpcClientEndpoint, RpcTraffic> {
+        self.traffic.traffic()
+    }
 }
 
 // HELPERS
 // ================================================================================================
 
 pub async fn create_test_client() -> (MockClient, MockRpcApi) {
+    let rpc_api = MockRpcApi::new();
+    let client = create_test_client_with_rpc(rpc_api.clone()).await;
+    (client, rpc_api)
+}
+
+/// Creates a test client backed by a new store that connects to the provided mock node.
+pub async fn create_test_client_with_rpc(rpc_api: MockRpcApi) -> MockClient {
     let store: SqliteStoreConfig = create_test_store_path()
         .into_os_string()
         .into_string()
@@ -329,21 +562,45 @@ pub async fn create_test_client() -> (MockClient, MockRpcApi) {
         .unwrap();
 
     let store = SqliteStore::new(&store).await.unwrap();
-    let store = Arc::new(store);
 
+    create_test_client_with_store(rpc_api, Arc::new(store))
+}
+
+/// Creates a test client that connects to the provided mock node and uses the provided store,
+/// which can be shared with other clients.
+pub fn create_test_client_with_store(rpc_api: MockRpcApi, store: Arc<dyn Store>) -> MockClient {
     let mut rng = rand::thread_rng();
     let coin_seed: [u64; 4] = rng.gen();
 
     let rng = RpoRandomCoin::new(coin_seed.map(Felt::new));
 
     let authenticator = StoreAuthenticator::new_with_rng(store.clone(), rng);
-    let rpc_api = MockRpcApi::new();
-    let boxed_rpc_api = Box::new(rpc_api.clone());
+    let boxed_rpc_api = Box::new(rpc_api);
 
-    let prover = Arc::new(LocalTransactionProver::default());
+    #[cfg(feature = "local-prover")]
+    let prover: Arc<dyn TransactionProver> = Arc::new(LocalTransactionProver::default());
+    #[cfg(not(feature = "local-prover"))]
+    let prover: Arc<dyn TransactionProver> = Arc::new(UnavailableProver);
 
-    let client = MockClient::new(boxed_rpc_api, rng, store, Arc::new(authenticator), prover, true);
-    (client, rpc_api)
+    MockClient::new(boxed_rpc_api, rng, store, Arc::new(authenticator), prover, true)
+}
+
+/// Prover used by the test clients of builds without the `local-prover` feature, where tests that
+/// prove transactions are not compiled. Fails to prove any transaction.
+#[cfg(not(feature = "local-prover"))]
+struct UnavailableProver;
+
+#[cfg(not(feature = "local-prover"))]
+#[async_trait(?Send)]
+impl TransactionProver for UnavailableProver {
+    async fn prove(
+        &self,
+        _tx_witness: miden_objects::transaction::TransactionWitness,
+    ) -> Result<ProvenTransaction, miden_tx::TransactionProverError> {
+        Err(miden_tx::TransactionProverError::InternalError(
+            "the test client has no prover".to_string(),
+        ))
+    }
 }
 
 pub fn create_test_store_path() -> std::path::PathBuf {
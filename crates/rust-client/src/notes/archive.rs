@@ -0,0 +1,136 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_objects::{crypto::rand::FeltRng, notes::NoteId};
+
+use crate::{
+    store::{InputNoteRecord, InputNoteState, NoteFilter},
+    Client, ClientError,
+};
+
+// NOTE STORAGE STATS
+// ================================================================================================
+
+/// Number of input notes kept in each storage tier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteStorageStats {
+    /// Input notes returned by the regular [NoteFilter] queries.
+    pub active_input_notes: usize,
+    /// Input notes moved to the archive with [Client::archive_notes].
+    pub archived_input_notes: usize,
+}
+
+/// Number of active input notes in each state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoteStateCounts {
+    /// Notes that are expected to be created but haven't been verified against the chain.
+    pub expected: usize,
+    /// Notes whose inclusion proof hasn't been verified yet.
+    pub unverified: usize,
+    /// Notes committed in the chain.
+    pub committed: usize,
+    /// Notes whose inclusion proof doesn't match the block they were committed in.
+    pub invalid: usize,
+    /// Notes being consumed by a local transaction.
+    pub processing: usize,
+    /// Notes consumed either locally or by an external account.
+    pub consumed: usize,
+    /// Notes whose details can never match the note committed in the chain.
+    pub rejected: usize,
+}
+
+impl NoteStateCounts {
+    /// Returns the total number of active input notes.
+    pub fn total(&self) -> usize {
+        self.expected
+            + self.unverified
+            + self.committed
+            + self.invalid
+            + self.processing
+            + self.consumed
+            + self.rejected
+    }
+}
+
+impl From<BTreeMap<u8, usize>> for NoteStateCounts {
+    fn from(counts: BTreeMap<u8, usize>) -> Self {
+        let mut state_counts = NoteStateCounts::default();
+        for (state, count) in counts {
+            let state_count = match state {
+                InputNoteState::STATE_EXPECTED => &mut state_counts.expected,
+                InputNoteState::STATE_UNVERIFIED => &mut state_counts.unverified,
+                InputNoteState::STATE_COMMITTED => &mut state_counts.committed,
+                InputNoteState::STATE_INVALID => &mut state_counts.invalid,
+                InputNoteState::STATE_PROCESSING_AUTHENTICATED
+                | InputNoteState::STATE_PROCESSING_UNAUTHENTICATED => &mut state_counts.processing,
+                InputNoteState::STATE_CONSUMED_AUTHENTICATED_LOCAL
+                | InputNoteState::STATE_CONSUMED_UNAUTHENTICATED_LOCAL
+                | InputNoteState::STATE_CONSUMED_EXTERNAL => &mut state_counts.consumed,
+                InputNoteState::STATE_REJECTED => &mut state_counts.rejected,
+                _ => continue,
+            };
+            *state_count += count;
+        }
+
+        state_counts
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // NOTE ARCHIVE
+    // --------------------------------------------------------------------------------------------
+
+    /// Moves the input notes that match the filter and are consumed to the archive, and returns
+    /// their IDs. Notes that are not consumed yet are skipped.
+    ///
+    /// Archived notes only keep their serialized record and are excluded from every [NoteFilter]
+    /// query. They can be looked up with [Client::search_archived_notes] and brought back with
+    /// [Client::restore_archived_note].
+    pub async fn archive_notes(&mut self, filter: NoteFilter) -> Result<Vec<NoteId>, ClientError> {
+        let notes: Vec<InputNoteRecord> = self
+            .store
+            .get_input_notes(filter)
+            .await?
+            .into_iter()
+            .filter(InputNoteRecord::is_consumed)
+            .collect();
+
+        self.store.archive_input_notes(&notes).await?;
+
+        Ok(notes.iter().map(InputNoteRecord::id).collect())
+    }
+
+    /// Returns the archived input note with the specified ID, if there is one.
+    pub async fn search_archived_notes(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, ClientError> {
+        self.store.get_archived_input_note(note_id).await.map_err(|err| err.into())
+    }
+
+    /// Moves the archived input note with the specified ID back to the input notes and returns
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::StoreError] if there is no archived note with the provided ID.
+    pub async fn restore_archived_note(
+        &mut self,
+        note_id: NoteId,
+    ) -> Result<InputNoteRecord, ClientError> {
+        self.store.restore_archived_input_note(note_id).await.map_err(|err| err.into())
+    }
+
+    /// Returns how many input notes are active and how many are archived.
+    pub async fn get_note_storage_stats(&self) -> Result<NoteStorageStats, ClientError> {
+        Ok(NoteStorageStats {
+            active_input_notes: self.store.get_input_notes(NoteFilter::All).await?.len(),
+            archived_input_notes: self.store.get_archived_input_note_count().await?,
+        })
+    }
+
+    /// Returns how many active input notes are in each state. Unlike filtering the notes by state,
+    /// this doesn't load the note records.
+    pub async fn count_notes_by_state(&self) -> Result<NoteStateCounts, ClientError> {
+        Ok(self.store.count_input_notes_by_state().await?.into())
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use async_trait::async_trait;
+use miden_objects::notes::NoteFile;
+use miden_tx::utils::{ByteReader, SliceReader};
+
+use super::{NoteDiscoveryError, NoteDiscoveryProvider};
+
+// HTTP NOTE SOURCE
+// ================================================================================================
+
+/// Default maximum size, in bytes, of a relay response.
+pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Default maximum number of notes in a relay response.
+pub const DEFAULT_MAX_NOTES_PER_RESPONSE: usize = 1024;
+
+/// [NoteDiscoveryProvider] that polls a relay service over HTTP.
+///
+/// The notes are requested with a `GET` request to the relay's URL, with the `since` timestamp as
+/// a query parameter when there is one. The relay is expected to answer with a serialized
+/// `Vec<NoteFile>`.
+///
+/// Responses larger than the source's maximum response size, or with more notes than its maximum
+/// number of notes, are rejected with [NoteDiscoveryError::InvalidResponse] before they're
+/// deserialized.
+pub struct HttpNoteSource {
+    url: String,
+    http_client: reqwest::Client,
+    max_response_size: usize,
+    max_notes: usize,
+}
+
+impl HttpNoteSource {
+    /// Returns a source that polls the relay at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            http_client: reqwest::Client::new(),
+            max_response_size: DEFAULT_MAX_RESPONSE_SIZE,
+            max_notes: DEFAULT_MAX_NOTES_PER_RESPONSE,
+        }
+    }
+
+    /// Sets the maximum size, in bytes, of a relay response.
+    pub fn with_max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = max_response_size;
+        self
+    }
+
+    /// Sets the maximum number of notes in a relay response.
+    pub fn with_max_notes(mut self, max_notes: usize) -> Self {
+        self.max_notes = max_notes;
+        self
+    }
+
+    fn response_too_large(&self) -> NoteDiscoveryError {
+        NoteDiscoveryError::InvalidResponse(format!(
+            "response is larger than {} bytes",
+            self.max_response_size
+        ))
+    }
+}
+
+#[async_trait(?Send)]
+impl NoteDiscoveryProvider for HttpNoteSource {
+    async fn fetch_notes(&self, since: Option<u64>) -> Result<Vec<NoteFile>, NoteDiscoveryError> {
+        let mut request = self.http_client.get(&self.url);
+        if let Some(since) = since {
+            request = request.query(&[("since", since)]);
+        }
+
+        let mut response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| NoteDiscoveryError::Unavailable(err.to_string()))?;
+
+        // The declared length may be missing or wrong, so the body is also checked as it's read
+        if response.content_length().is_some_and(|len| len > self.max_response_size as u64) {
+            return Err(self.response_too_large());
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| NoteDiscoveryError::Unavailable(err.to_string()))?
+        {
+            if body.len() + chunk.len() > self.max_response_size {
+                return Err(self.response_too_large());
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        let mut reader = SliceReader::new(&body);
+        let num_notes = reader
+            .read_usize()
+            .map_err(|err| NoteDiscoveryError::InvalidResponse(err.to_string()))?;
+        if num_notes > self.max_notes {
+            return Err(NoteDiscoveryError::InvalidResponse(format!(
+                "response has {num_notes} notes, but at most {} notes are allowed",
+                self.max_notes
+            )));
+        }
+
+        reader
+            .read_many::<NoteFile>(num_notes)
+            .map_err(|err| NoteDiscoveryError::InvalidResponse(err.to_string()))
+    }
+}
@@ -0,0 +1,224 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt;
+
+use async_trait::async_trait;
+use miden_objects::{
+    crypto::rand::FeltRng,
+    notes::{NoteFile, NoteId},
+};
+use tracing::{info, warn};
+
+use super::import::note_file_id;
+use crate::{
+    store::{InputNoteRecord, NoteFilter},
+    Client, ClientError,
+};
+
+#[cfg(feature = "http-note-source")]
+mod http;
+#[cfg(feature = "http-note-source")]
+pub use http::{HttpNoteSource, DEFAULT_MAX_NOTES_PER_RESPONSE, DEFAULT_MAX_RESPONSE_SIZE};
+
+// NOTE DISCOVERY PROVIDER
+// ================================================================================================
+
+/// Source of notes other than the node, like a relay service where senders leave the details of
+/// the private notes they create for their recipients.
+///
+/// Sources are registered with [Client::add_note_source] and polled at the end of every
+/// [Client::sync_state], or on demand with [Client::fetch_external_notes]. Every note they return
+/// is imported with [Client::import_note], so a source may return notes the client already tracks.
+#[async_trait(?Send)]
+pub trait NoteDiscoveryProvider: Send + Sync {
+    /// Returns the notes made available by the source since the `since` Unix timestamp, in
+    /// seconds, or all of them if it's `None`.
+    ///
+    /// The client passes the time of the last successful poll, so the returned notes can overlap
+    /// with the ones returned before.
+    async fn fetch_notes(&self, since: Option<u64>) -> Result<Vec<NoteFile>, NoteDiscoveryError>;
+}
+
+/// Errors returned by a [NoteDiscoveryProvider].
+#[derive(Debug)]
+pub enum NoteDiscoveryError {
+    /// The source couldn't be reached.
+    Unavailable(String),
+    /// The source answered with data that couldn't be read.
+    InvalidResponse(String),
+}
+
+impl fmt::Display for NoteDiscoveryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteDiscoveryError::Unavailable(err) => {
+                write!(f, "note source is unavailable: {err}")
+            },
+            NoteDiscoveryError::InvalidResponse(err) => {
+                write!(f, "note source returned an invalid response: {err}")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoteDiscoveryError {}
+
+/// Note source registered on the client, along with the time it was last polled successfully.
+#[derive(Clone)]
+pub(crate) struct RegisteredNoteSource {
+    provider: Arc<dyn NoteDiscoveryProvider>,
+    last_fetch: Option<u64>,
+}
+
+// EXTERNAL NOTES SUMMARY
+// ================================================================================================
+
+/// Result of polling the client's note sources with [Client::fetch_external_notes].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExternalNotesSummary {
+    /// Notes that were imported or updated. Notes the client already tracked with the same
+    /// information are left out.
+    pub imported_notes: Vec<NoteId>,
+    /// Failures to poll a source or to import one of the notes it returned.
+    pub warnings: Vec<String>,
+}
+
+// IN-MEMORY NOTE SOURCE
+// ================================================================================================
+
+/// [NoteDiscoveryProvider] that keeps the notes published to it in memory. Useful for tests and
+/// as a reference for implementing other sources.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct InMemoryNoteSource {
+    /// Published notes, with the time they were published at.
+    notes: std::sync::RwLock<Vec<(u64, NoteFile)>>,
+    unavailable: core::sync::atomic::AtomicBool,
+}
+
+#[cfg(feature = "std")]
+impl InMemoryNoteSource {
+    /// Returns a new source without notes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the note available to the clients polling the source, as published at the
+    /// `published_at` Unix timestamp, in seconds.
+    pub fn publish(&self, note_file: NoteFile, published_at: u64) {
+        self.notes
+            .write()
+            .expect("The lock shouldn't be poisoned")
+            .push((published_at, note_file));
+    }
+
+    /// Sets whether the source fails the polls with [NoteDiscoveryError::Unavailable].
+    pub fn set_unavailable(&self, unavailable: bool) {
+        self.unavailable.store(unavailable, core::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+impl NoteDiscoveryProvider for InMemoryNoteSource {
+    async fn fetch_notes(&self, since: Option<u64>) -> Result<Vec<NoteFile>, NoteDiscoveryError> {
+        if self.unavailable.load(core::sync::atomic::Ordering::Relaxed) {
+            return Err(NoteDiscoveryError::Unavailable("the source was disabled".to_string()));
+        }
+
+        Ok(self
+            .notes
+            .read()
+            .expect("The lock shouldn't be poisoned")
+            .iter()
+            .filter(|(published_at, _)| since.map_or(true, |since| *published_at >= since))
+            .map(|(_, note_file)| note_file.clone())
+            .collect())
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // EXTERNAL NOTE SOURCES
+    // --------------------------------------------------------------------------------------------
+
+    /// Registers a source the client polls for notes at the end of every sync, besides the node.
+    pub fn add_note_source(&mut self, provider: Arc<dyn NoteDiscoveryProvider>) {
+        self.note_sources.push(RegisteredNoteSource { provider, last_fetch: None });
+    }
+
+    /// Polls the registered note sources and imports the notes they return with
+    /// [Client::import_note].
+    ///
+    /// Each source is asked for the notes made available since its last successful poll, if the
+    /// client has a [TimeSource](crate::time::TimeSource), or for all of its notes otherwise.
+    /// Importing a note the client already tracks is harmless, so notes returned more than once,
+    /// by the same or several sources, are only reported once.
+    ///
+    /// # Errors
+    ///
+    /// Sources that can't be polled and notes that can't be imported don't fail the call, and are
+    /// reported in [ExternalNotesSummary::warnings]. Returns an error if the store can't be read.
+    pub async fn fetch_external_notes(&mut self) -> Result<ExternalNotesSummary, ClientError> {
+        let mut summary = ExternalNotesSummary::default();
+        if self.note_sources.is_empty() {
+            return Ok(summary);
+        }
+
+        let fetch_time = self.current_timestamp();
+        for source_index in 0..self.note_sources.len() {
+            let source = self.note_sources[source_index].clone();
+            let note_files = match source.provider.fetch_notes(source.last_fetch).await {
+                Ok(note_files) => note_files,
+                Err(err) => {
+                    let warning = format!("Note source #{source_index} couldn't be polled: {err}");
+                    warn!("{warning}");
+                    summary.warnings.push(warning);
+                    continue;
+                },
+            };
+
+            for note_file in note_files {
+                let note_id = note_file_id(&note_file);
+                let previous_note = self.get_tracked_input_note(note_id).await?;
+                if let Err(err) = self.import_note(note_file).await {
+                    let warning = format!(
+                        "Note {note_id} from note source #{source_index} couldn't be imported: \
+                         {err}"
+                    );
+                    warn!("{warning}");
+                    summary.warnings.push(warning);
+                    continue;
+                }
+
+                if self.get_tracked_input_note(note_id).await? != previous_note
+                    && !summary.imported_notes.contains(&note_id)
+                {
+                    summary.imported_notes.push(note_id);
+                }
+            }
+
+            self.note_sources[source_index].last_fetch = fetch_time;
+        }
+
+        info!(
+            "Imported {} notes from {} external note sources",
+            summary.imported_notes.len(),
+            self.note_sources.len()
+        );
+
+        Ok(summary)
+    }
+
+    /// Returns the input note with the specified ID, or `None` if it isn't tracked.
+    async fn get_tracked_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, ClientError> {
+        Ok(self.store.get_input_notes(NoteFilter::List(vec![note_id])).await?.pop())
+    }
+}
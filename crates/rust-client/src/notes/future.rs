@@ -0,0 +1,189 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use miden_objects::{accounts::AccountId, notes::NoteId, transaction::OutputNote};
+use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use crate::store::{InputNoteRecord, InputNoteState};
+
+// FUTURE NOTE LINK
+// ================================================================================================
+
+/// Link between a note created by a transaction and the expected note that consuming it creates,
+/// like a SWAP note and its payback note.
+///
+/// Links are stored when a transaction that expects future notes is applied, and are resolved by
+/// the sync that receives the nullifier of the source note, as described by
+/// [FutureNoteResolution].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FutureNoteLink {
+    source_note_id: NoteId,
+    future_note_id: NoteId,
+    creator_account_id: AccountId,
+}
+
+impl FutureNoteLink {
+    /// Returns a new link between the specified notes.
+    pub fn new(
+        source_note_id: NoteId,
+        future_note_id: NoteId,
+        creator_account_id: AccountId,
+    ) -> Self {
+        Self {
+            source_note_id,
+            future_note_id,
+            creator_account_id,
+        }
+    }
+
+    /// Returns the ID of the note whose consumption creates the future note.
+    pub fn source_note_id(&self) -> NoteId {
+        self.source_note_id
+    }
+
+    /// Returns the ID of the expected future note.
+    pub fn future_note_id(&self) -> NoteId {
+        self.future_note_id
+    }
+
+    /// Returns the ID of the account that created the source note.
+    pub fn creator_account_id(&self) -> AccountId {
+        self.creator_account_id
+    }
+
+    /// Returns the links between the created notes of a transaction executed by `account_id` and
+    /// the expected notes it tracks. A created note is linked to an expected note if its inputs
+    /// start with the expected note's recipient digest, which is how SWAP notes commit to their
+    /// payback note. Only notes with known recipients can be linked.
+    pub(crate) fn from_created_notes<'a>(
+        account_id: AccountId,
+        created_notes: impl Iterator<Item = &'a OutputNote>,
+        expected_notes: &[InputNoteRecord],
+    ) -> Vec<Self> {
+        let mut links = vec![];
+        for created_note in created_notes {
+            let OutputNote::Full(note) = created_note else {
+                continue;
+            };
+            let inputs = note.recipient().inputs().values();
+            if inputs.len() < 4 {
+                continue;
+            }
+
+            let future_note = expected_notes.iter().find(|expected_note| {
+                matches!(expected_note.state(), InputNoteState::Expected(_))
+                    && expected_note.details().recipient().digest().as_elements() == &inputs[..4]
+            });
+            if let Some(future_note) = future_note {
+                links.push(Self::new(note.id(), future_note.id(), account_id));
+            }
+        }
+
+        links
+    }
+}
+
+// FUTURE NOTE RESOLUTION
+// ================================================================================================
+
+/// Outcome of a [FutureNoteLink] once its source note is consumed, reported in the
+/// [SyncSummary](crate::sync::SyncSummary) of the sync that received the nullifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FutureNoteResolution {
+    /// The source note was consumed by another account, so the future note was created in the
+    /// same block. The expected note is kept and is expected to be committed after the block
+    /// before the consumption.
+    Promoted {
+        source_note_id: NoteId,
+        future_note_id: NoteId,
+        block_num: u32,
+    },
+    /// The source note was consumed by the account that created it without creating the future
+    /// note, which will never be created. The expected note and its tag are removed.
+    Cancelled {
+        source_note_id: NoteId,
+        future_note_id: NoteId,
+    },
+}
+
+impl FutureNoteResolution {
+    /// Returns the ID of the source note of the resolved link.
+    pub fn source_note_id(&self) -> NoteId {
+        match self {
+            FutureNoteResolution::Promoted { source_note_id, .. }
+            | FutureNoteResolution::Cancelled { source_note_id, .. } => *source_note_id,
+        }
+    }
+
+    /// Returns the ID of the future note of the resolved link.
+    pub fn future_note_id(&self) -> NoteId {
+        match self {
+            FutureNoteResolution::Promoted { future_note_id, .. }
+            | FutureNoteResolution::Cancelled { future_note_id, .. } => *future_note_id,
+        }
+    }
+}
+
+impl fmt::Display for FutureNoteResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FutureNoteResolution::Promoted {
+                source_note_id,
+                future_note_id,
+                block_num,
+            } => write!(
+                f,
+                "Note {} was consumed at block {block_num}, expecting note {}",
+                source_note_id.to_hex(),
+                future_note_id.to_hex()
+            ),
+            FutureNoteResolution::Cancelled { source_note_id, future_note_id } => write!(
+                f,
+                "Note {} was consumed by its creator, note {} is no longer expected",
+                source_note_id.to_hex(),
+                future_note_id.to_hex()
+            ),
+        }
+    }
+}
+
+impl Serializable for FutureNoteResolution {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            FutureNoteResolution::Promoted {
+                source_note_id,
+                future_note_id,
+                block_num,
+            } => {
+                target.write_u8(0);
+                source_note_id.write_into(target);
+                future_note_id.write_into(target);
+                block_num.write_into(target);
+            },
+            FutureNoteResolution::Cancelled { source_note_id, future_note_id } => {
+                target.write_u8(1);
+                source_note_id.write_into(target);
+                future_note_id.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for FutureNoteResolution {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let discriminant = source.read_u8()?;
+        let source_note_id = NoteId::read_from(source)?;
+        let future_note_id = NoteId::read_from(source)?;
+        match discriminant {
+            0 => Ok(FutureNoteResolution::Promoted {
+                source_note_id,
+                future_note_id,
+                block_num: u32::read_from(source)?,
+            }),
+            1 => Ok(FutureNoteResolution::Cancelled { source_note_id, future_note_id }),
+            _ => Err(DeserializationError::InvalidValue(format!(
+                "Invalid future note resolution discriminant: {discriminant}"
+            ))),
+        }
+    }
+}
@@ -1,16 +1,55 @@
 use alloc::string::ToString;
 
 use miden_objects::{
+    accounts::AccountId,
     crypto::rand::FeltRng,
-    notes::{Note, NoteDetails, NoteFile, NoteId, NoteInclusionProof, NoteMetadata, NoteTag},
+    notes::{
+        Note, NoteDetails, NoteExecutionMode, NoteFile, NoteId, NoteInclusionProof, NoteMetadata,
+        NoteTag,
+    },
 };
+use tracing::warn;
 
+use super::{
+    memo::p2id_memo_script,
+    script_roots::{P2ID, P2IDR},
+};
 use crate::{
-    store::{input_note_states::ExpectedNoteState, InputNoteRecord, InputNoteState},
-    sync::NoteTagRecord,
+    store::{input_note_states::ExpectedNoteState, InputNoteRecord},
+    sync::{NoteTagCorrection, NoteTagRecord},
     Client, ClientError,
 };
 
+// NOTE TAG POLICY
+// ================================================================================================
+
+/// How the client handles an imported [NoteFile::NoteDetails] whose tag doesn't match the one
+/// derived from the note's details. Set with [Client::with_note_tag_policy].
+///
+/// The tag can only be derived for notes with a well-known script whose inputs name the target
+/// account, like P2ID, P2IDR and P2ID notes with a memo. The tags of other notes are not checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NoteTagPolicy {
+    /// Tracks the note with the derived tag instead of the provided one, and reports the
+    /// correction in the [NoteImportOutcome].
+    #[default]
+    Correct,
+    /// Fails the import with a [ClientError::NoteTagMismatch].
+    Strict,
+}
+
+// NOTE IMPORT OUTCOME
+// ================================================================================================
+
+/// Result of importing a note file with [Client::import_note_with_outcome].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteImportOutcome {
+    pub note_id: NoteId,
+    /// Correction of the tag provided by the note file, if it didn't match the one derived from
+    /// the note's details.
+    pub tag_correction: Option<NoteTagCorrection>,
+}
+
 impl<R: FeltRng> Client<R> {
     // INPUT NOTE CREATION
     // --------------------------------------------------------------------------------------------
@@ -26,15 +65,38 @@ impl<R: FeltRng> Client<R> {
     /// - If the note file is a [NoteFile::NoteWithProof], the note is stored with the provided
     ///   inclusion proof and metadata. The block header data is only fetched from the node if the
     ///   note is committed in the past relative to the client.
+    ///
+    /// Importing the details of a note that is already tracked doesn't change it. If the number
+    /// of expected notes is limited with [Client::with_max_expected_notes], importing the details
+    /// of a new note fails with [ClientError::ExpectedNoteLimitReached] once the limit is reached,
+    /// without changing the store.
+    ///
+    /// The tag of a [NoteFile::NoteDetails] is validated according to the client's
+    /// [NoteTagPolicy]. Use [Client::import_note_with_outcome] to learn whether it was corrected.
     pub async fn import_note(&mut self, note_file: NoteFile) -> Result<NoteId, ClientError> {
-        let id = match &note_file {
-            NoteFile::NoteId(id) => *id,
-            NoteFile::NoteDetails { details, .. } => details.id(),
-            NoteFile::NoteWithProof(note, _) => note.id(),
-        };
+        self.import_note_with_outcome(note_file).await.map(|outcome| outcome.note_id)
+    }
+
+    /// Imports a new input note like [Client::import_note], and returns the outcome of the
+    /// import.
+    ///
+    /// If the note file is a [NoteFile::NoteDetails] whose tag doesn't match the one derived from
+    /// the note's details, the note is tracked with the derived tag and the correction is
+    /// reported, unless the client's [NoteTagPolicy] is [NoteTagPolicy::Strict], in which case
+    /// the import fails with [ClientError::NoteTagMismatch] without changing the store.
+    pub async fn import_note_with_outcome(
+        &mut self,
+        note_file: NoteFile,
+    ) -> Result<NoteImportOutcome, ClientError> {
+        let (note_file, tag_correction) = self.validate_note_file_tag(note_file)?;
+        let id = note_file_id(&note_file);
 
         let previous_note = self.get_input_note(id).await.ok();
 
+        if previous_note.is_none() && matches!(note_file, NoteFile::NoteDetails { .. }) {
+            self.check_expected_note_limit().await?;
+        }
+
         let note = match note_file {
             NoteFile::NoteId(id) => self.import_note_record_by_id(previous_note, id).await?,
             NoteFile::NoteDetails { details, after_block_num, tag } => {
@@ -47,21 +109,103 @@ impl<R: FeltRng> Client<R> {
         };
 
         if let Some(note) = note {
-            if let InputNoteState::Expected(ExpectedNoteState { tag: Some(tag), .. }) = note.state()
-            {
-                self.store
-                    .add_note_tag(NoteTagRecord::with_note_source(*tag, note.id()))
-                    .await?;
+            let tag_record = NoteTagRecord::for_expected_note(&note);
+
+            let tag_added = match tag_record {
+                Some(tag_record) => self.store.add_note_tag(tag_record).await?,
+                None => false,
+            };
+
+            if let Err(err) = self.store.upsert_input_notes(&[note]).await {
+                // Don't keep tracking the tag of a note that couldn't be stored
+                if let Some(tag_record) = tag_record.filter(|_| tag_added) {
+                    self.store.remove_note_tag(tag_record).await?;
+                }
+                return Err(err.into());
             }
-            self.store.upsert_input_notes(&[note]).await?;
+
+            self.remove_pending_note(id, tag_record).await?;
+        }
+
+        if let Some(correction) = tag_correction {
+            warn!(
+                "Note {id} was imported with tag {}, which was corrected to {}",
+                correction.previous_tag, correction.corrected_tag
+            );
         }
 
-        Ok(id)
+        Ok(NoteImportOutcome { note_id: id, tag_correction })
+    }
+
+    /// Limits the number of expected notes tracked by the client to `max_expected_notes`. Expected
+    /// notes are imported from note details that haven't been verified against the chain, so the
+    /// limit bounds the notes and tags that untrusted note files can add to the store.
+    pub fn with_max_expected_notes(mut self, max_expected_notes: usize) -> Self {
+        self.max_expected_notes = Some(max_expected_notes);
+        self
+    }
+
+    /// Sets how the client handles imported note details whose tag doesn't match the one derived
+    /// from the details. By default, the tag is corrected.
+    pub fn with_note_tag_policy(mut self, policy: NoteTagPolicy) -> Self {
+        self.note_tag_policy = policy;
+        self
     }
 
     // HELPERS
     // ================================================================================================
 
+    /// Checks the tag of a [NoteFile::NoteDetails] against the one derived from the note's
+    /// details, if it can be derived, and applies the client's [NoteTagPolicy] on a mismatch.
+    /// Returns the note file to import, along with the correction of its tag if there was one.
+    fn validate_note_file_tag(
+        &self,
+        note_file: NoteFile,
+    ) -> Result<(NoteFile, Option<NoteTagCorrection>), ClientError> {
+        let NoteFile::NoteDetails {
+            details,
+            after_block_num,
+            tag: Some(provided),
+        } = &note_file
+        else {
+            return Ok((note_file, None));
+        };
+        let Some(derived) = derive_note_tag(details).filter(|derived| derived != provided) else {
+            return Ok((note_file, None));
+        };
+
+        if self.note_tag_policy == NoteTagPolicy::Strict {
+            return Err(ClientError::NoteTagMismatch { provided: *provided, derived });
+        }
+
+        let correction = NoteTagCorrection {
+            note_id: details.id(),
+            previous_tag: *provided,
+            corrected_tag: derived,
+        };
+        let note_file = NoteFile::NoteDetails {
+            details: details.clone(),
+            after_block_num: *after_block_num,
+            tag: Some(derived),
+        };
+
+        Ok((note_file, Some(correction)))
+    }
+
+    /// Returns a [ClientError::ExpectedNoteLimitReached] if the client is already tracking the
+    /// maximum number of expected notes.
+    async fn check_expected_note_limit(&self) -> Result<(), ClientError> {
+        let Some(max_expected_notes) = self.max_expected_notes else {
+            return Ok(());
+        };
+
+        if self.count_notes_by_state().await?.expected >= max_expected_notes {
+            return Err(ClientError::ExpectedNoteLimitReached(max_expected_notes));
+        }
+
+        Ok(())
+    }
+
     /// Builds a note record from the note ID. If a note with the same ID was already stored it is
     /// passed via `previous_note` so it can be updated. The note information is fetched from the
     /// node and stored in the client's store.
@@ -74,7 +218,7 @@ impl<R: FeltRng> Client<R> {
         previous_note: Option<InputNoteRecord>,
         id: NoteId,
     ) -> Result<Option<InputNoteRecord>, ClientError> {
-        let mut chain_notes = self.rpc_api.get_notes_by_id(&[id]).await?;
+        let mut chain_notes = self.get_notes_by_id_in_batches(&[id]).await?;
         if chain_notes.is_empty() {
             return Err(ClientError::NoteNotFoundOnChain(id));
         }
@@ -93,10 +237,16 @@ impl<R: FeltRng> Client<R> {
 
         match previous_note {
             Some(mut previous_note) => {
+                let expected_tag = NoteTagRecord::for_expected_note(&previous_note);
                 if previous_note
                     .inclusion_proof_received(inclusion_proof, *note_details.metadata())?
                 {
-                    self.store.remove_note_tag((&previous_note).try_into()?).await?;
+                    if !previous_note.is_rejected() {
+                        self.store.remove_note_tag((&previous_note).try_into()?).await?;
+                    } else if let Some(expected_tag) = expected_tag {
+                        // The note was expected with a tag that may differ from the committed one
+                        self.store.remove_note_tag(expected_tag).await?;
+                    }
 
                     Ok(Some(previous_note))
                 } else {
@@ -188,6 +338,7 @@ impl<R: FeltRng> Client<R> {
         after_block_num: u32,
         tag: Option<NoteTag>,
     ) -> Result<Option<InputNoteRecord>, ClientError> {
+        let is_new_note = previous_note.is_none();
         let mut note_record = previous_note.unwrap_or({
             InputNoteRecord::new(
                 details,
@@ -215,7 +366,7 @@ impl<R: FeltRng> Client<R> {
                 let note_changed =
                     note_record.inclusion_proof_received(inclusion_proof, metadata)?;
 
-                if note_record.block_header_received(block_header)? | note_changed {
+                if note_record.chain_block_header_received(block_header)? | note_changed {
                     self.store
                         .remove_note_tag(NoteTagRecord::with_note_source(
                             metadata.tag(),
@@ -228,7 +379,8 @@ impl<R: FeltRng> Client<R> {
                     Ok(None)
                 }
             },
-            None => Ok(Some(note_record)),
+            // Importing the details of a tracked note again doesn't change it
+            None => Ok(is_new_note.then_some(note_record)),
         }
     }
 
@@ -284,3 +436,27 @@ impl<R: FeltRng> Client<R> {
         }
     }
 }
+
+/// Returns the tag of the note with the provided details, if it can be derived from its script and
+/// inputs. Notes whose first input is their target account, like P2ID, P2IDR and P2ID notes with a
+/// memo, are tagged for that account.
+fn derive_note_tag(details: &NoteDetails) -> Option<NoteTag> {
+    let script_root = details.script().hash();
+    let is_targeted = matches!(script_root.to_string().as_str(), P2ID | P2IDR)
+        || script_root == p2id_memo_script().hash();
+    if !is_targeted {
+        return None;
+    }
+
+    let target = AccountId::try_from(*details.inputs().values().first()?).ok()?;
+    NoteTag::from_account_id(target, NoteExecutionMode::Local).ok()
+}
+
+/// Returns the ID of the note described by the note file.
+pub(crate) fn note_file_id(note_file: &NoteFile) -> NoteId {
+    match note_file {
+        NoteFile::NoteId(id) => *id,
+        NoteFile::NoteDetails { details, .. } => details.id(),
+        NoteFile::NoteWithProof(note, _) => note.id(),
+    }
+}
@@ -0,0 +1,137 @@
+//! Provides P2ID notes that carry a memo for the target account.
+//!
+//! The standard P2ID script only accepts the target account ID as note input, so notes with a memo
+//! use their own script, which accepts the memo as additional inputs. The memo is public to anyone
+//! who knows the note's details, the same as the rest of its inputs.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    crypto::rand::FeltRng,
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata,
+        NoteRecipient, NoteScript, NoteTag, NoteType,
+    },
+    Felt, NoteError, MAX_INPUTS_PER_NOTE,
+};
+use once_cell::race::OnceBox;
+
+/// Number of memo bytes packed into each note input. Seven bytes always fit in a field element.
+const MEMO_BYTES_PER_INPUT: usize = 7;
+
+/// Maximum length of a memo, in bytes. Two of the note inputs hold the target account ID and the
+/// memo length, and the memo is packed into the rest.
+pub const MAX_MEMO_LEN: usize = (MAX_INPUTS_PER_NOTE - 2) * MEMO_BYTES_PER_INPUT;
+
+const P2ID_MEMO_SCRIPT_SOURCE: &str = include_str!("asm/p2id_memo.masm");
+
+/// Script of P2ID notes with a memo, compiled the first time it's needed.
+static P2ID_MEMO_SCRIPT: OnceBox<NoteScript> = OnceBox::new();
+
+/// Returns the script of P2ID notes with a memo.
+pub fn p2id_memo_script() -> NoteScript {
+    P2ID_MEMO_SCRIPT
+        .get_or_init(|| {
+            let script =
+                NoteScript::compile(P2ID_MEMO_SCRIPT_SOURCE, TransactionKernel::assembler())
+                    .expect("P2ID memo script should compile");
+            Box::new(script)
+        })
+        .clone()
+}
+
+/// Creates a note that can only be consumed by the target account, like a P2ID note, and that
+/// carries the provided memo in its inputs.
+///
+/// # Errors
+///
+/// Returns an error if the memo is longer than [MAX_MEMO_LEN] or if the note can't be built from
+/// the provided data.
+pub fn create_p2id_memo_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    memo: &[u8],
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let mut inputs = vec![target.into()];
+    inputs.extend(encode_memo(memo));
+
+    let recipient =
+        NoteRecipient::new(rng.draw_word(), p2id_memo_script(), NoteInputs::new(inputs)?);
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+
+    Ok(Note::new(NoteAssets::new(assets)?, metadata, recipient))
+}
+
+/// Returns the memo carried by the note with the provided recipient, or `None` if it's not a P2ID
+/// note with a memo.
+pub fn read_memo(recipient: &NoteRecipient) -> Option<Vec<u8>> {
+    if recipient.script().hash() != p2id_memo_script().hash() {
+        return None;
+    }
+
+    // The first input is the target account ID
+    decode_memo(recipient.inputs().values().get(1..)?)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Encodes the memo as note inputs: its length followed by its bytes, packed in little-endian
+/// order.
+fn encode_memo(memo: &[u8]) -> Vec<Felt> {
+    let mut inputs = vec![Felt::new(memo.len() as u64)];
+    inputs.extend(memo.chunks(MEMO_BYTES_PER_INPUT).map(|chunk| {
+        let mut bytes = [0u8; 8];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        Felt::new(u64::from_le_bytes(bytes))
+    }));
+
+    inputs
+}
+
+/// Decodes a memo encoded with [encode_memo]. Returns `None` if the inputs are malformed.
+fn decode_memo(inputs: &[Felt]) -> Option<Vec<u8>> {
+    let (memo_len, packed_memo) = inputs.split_first()?;
+    let memo_len = usize::try_from(memo_len.as_int()).ok()?;
+
+    let mut memo: Vec<u8> = packed_memo
+        .iter()
+        .flat_map(|input| input.as_int().to_le_bytes().into_iter().take(MEMO_BYTES_PER_INPUT))
+        .collect();
+    if memo.len() < memo_len {
+        return None;
+    }
+    memo.truncate(memo_len);
+
+    Some(memo)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_memo, encode_memo, MAX_MEMO_LEN};
+
+    #[test]
+    fn test_memo_encoding_round_trip() {
+        for memo in [&b""[..], b"thanks", b"invoice #42, due next week", &[0xff; MAX_MEMO_LEN]] {
+            let inputs = encode_memo(memo);
+            assert_eq!(inputs.len(), 1 + memo.len().div_ceil(7));
+            assert_eq!(decode_memo(&inputs).as_deref(), Some(memo));
+        }
+
+        // the declared length can't exceed the packed bytes
+        let mut inputs = encode_memo(b"memo");
+        inputs[0] = miden_objects::Felt::new(8);
+        assert_eq!(decode_memo(&inputs), None);
+    }
+}
@@ -1,24 +1,43 @@
 //! Contains the Client APIs related to notes. Notes can contain assets and scripts that are
 //! executed as part of transactions.
 
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
 
 use miden_lib::transaction::TransactionKernel;
-use miden_objects::{accounts::AccountId, crypto::rand::FeltRng};
+use miden_objects::{
+    accounts::AccountId, assets::FungibleAsset, crypto::rand::FeltRng, transaction::TransactionId,
+};
 
 use crate::{
-    store::{InputNoteRecord, NoteFilter, OutputNoteRecord},
+    ids::normalize_id_prefix,
+    store::{fungible_amount, InputNoteRecord, NoteFilter, OutputNoteRecord, StoreError},
     Client, ClientError, IdPrefixFetchError,
 };
 
+pub mod memo;
 pub mod script_roots;
 
+mod archive;
+mod discovery;
+mod future;
 mod import;
 mod note_screener;
+mod pending;
+mod provenance;
+mod search;
 
 // RE-EXPORTS
 // ================================================================================================
 
+pub use archive::{NoteStateCounts, NoteStorageStats};
+#[cfg(feature = "http-note-source")]
+pub use discovery::HttpNoteSource;
+#[cfg(feature = "std")]
+pub use discovery::InMemoryNoteSource;
+pub(crate) use discovery::RegisteredNoteSource;
+pub use discovery::{ExternalNotesSummary, NoteDiscoveryError, NoteDiscoveryProvider};
+pub use future::{FutureNoteLink, FutureNoteResolution};
+pub use import::{NoteImportOutcome, NoteTagPolicy};
 pub use miden_lib::notes::{
     create_p2id_note, create_p2idr_note, create_swap_note,
     utils::{build_p2id_recipient, build_swap_tag},
@@ -32,6 +51,8 @@ pub use miden_objects::{
     NoteError,
 };
 pub use note_screener::{NoteConsumability, NoteRelevance, NoteScreener, NoteScreenerError};
+pub use pending::{ImportOutcome, PendingNoteRecord};
+pub use provenance::{NoteConsumption, NoteProvenance};
 
 // MIDEN CLIENT
 // ================================================================================================
@@ -53,21 +74,49 @@ impl<R: FeltRng> Client<R> {
         self.store.get_input_notes(filter).await.map_err(|err| err.into())
     }
 
+    /// Retrieves up to `limit` of the input notes matched by the filter, skipping the first
+    /// `offset` ones, along with the total number of notes the filter matches. Unlike
+    /// [Client::get_input_notes], only the notes of the page are loaded from the store.
+    ///
+    /// The notes are ordered by ID, so a client tracking many notes can walk through them by
+    /// increasing `offset` by `limit` until it reaches the total.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::StoreError] if the filter is [NoteFilter::Unique] and there is no
+    /// Note with the provided ID
+    pub async fn get_input_notes_page(
+        &self,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), ClientError> {
+        Ok(self.store.get_input_notes_page(filter, offset, limit).await?)
+    }
+
     /// Returns the input notes and their consumability.
     ///
     /// If account_id is None then all consumable input notes are returned.
+    ///
+    /// Notes that are already being consumed by a local transaction that hasn't been committed
+    /// yet are in the `Processing` state. Building another transaction that consumes them would
+    /// fail, so they're only returned if `include_processing` is true.
     pub async fn get_consumable_notes(
         &self,
         account_id: Option<AccountId>,
+        include_processing: bool,
     ) -> Result<Vec<(InputNoteRecord, Vec<NoteConsumability>)>, ClientError> {
-        let commited_notes = self.store.get_input_notes(NoteFilter::Committed).await?;
+        let mut candidate_notes = self.store.get_input_notes(NoteFilter::Committed).await?;
+        if include_processing {
+            candidate_notes.extend(self.store.get_input_notes(NoteFilter::Processing).await?);
+        }
 
         let note_screener = NoteScreener::new(self.store.clone());
 
         let mut relevant_notes = Vec::new();
-        for input_note in commited_notes {
+        for input_note in candidate_notes {
             let mut account_relevance =
-                note_screener.check_relevance(&input_note.clone().try_into()?).await?;
+                note_screener.check_relevance(&input_note.to_note()?).await?;
 
             if let Some(account_id) = account_id {
                 account_relevance.retain(|(id, _)| *id == account_id);
@@ -83,16 +132,76 @@ impl<R: FeltRng> Client<R> {
         Ok(relevant_notes)
     }
 
+    /// Selects committed notes that the specified account can consume at the current sync height
+    /// and that together hold at least the provided amount of the asset's faucet. Notes holding
+    /// larger amounts are selected first, so that as few notes as possible are used.
+    ///
+    /// The notes are selected in the store with [Store::get_consumable_notes_for_faucet] and then
+    /// screened for the account. If the screened notes don't add up to the amount, more notes are
+    /// requested from the store. If the account can't consume enough notes, all the consumable
+    /// ones are returned.
+    ///
+    /// [Store::get_consumable_notes_for_faucet]: crate::store::Store::get_consumable_notes_for_faucet
+    pub async fn select_notes_for_amount(
+        &self,
+        account_id: AccountId,
+        asset: FungibleAsset,
+    ) -> Result<Vec<InputNoteRecord>, ClientError> {
+        let sync_height = self.store.get_sync_height().await?;
+        let note_screener = NoteScreener::new(self.store.clone());
+
+        let mut min_total = asset.amount();
+        let mut num_candidates = None;
+        let mut selected_notes = vec![];
+        loop {
+            let candidates =
+                self.store.get_consumable_notes_for_faucet(asset.faucet_id(), min_total).await?;
+            // No new notes were returned, so the last selection already includes every consumable
+            // note
+            if num_candidates == Some(candidates.len()) {
+                return Ok(selected_notes);
+            }
+            num_candidates = Some(candidates.len());
+
+            selected_notes.clear();
+            let mut selected_total = 0u64;
+            let mut skipped_total = 0u64;
+            for note in candidates {
+                let amount = fungible_amount(note.assets(), asset.faucet_id()).unwrap_or_default();
+                let consumable =
+                    note_screener.check_relevance(&note.to_note()?).await?.into_iter().any(
+                        |(consumer_id, relevance)| {
+                            consumer_id == account_id
+                                && match relevance {
+                                    NoteRelevance::Always => true,
+                                    NoteRelevance::After(block_num) => block_num <= sync_height,
+                                }
+                        },
+                    );
+
+                if !consumable {
+                    skipped_total = skipped_total.saturating_add(amount);
+                } else if selected_total < asset.amount() {
+                    selected_total = selected_total.saturating_add(amount);
+                    selected_notes.push(note);
+                }
+            }
+
+            if selected_total >= asset.amount() || skipped_total == 0 {
+                return Ok(selected_notes);
+            }
+
+            min_total = asset.amount().saturating_add(skipped_total);
+        }
+    }
+
     /// Returns the consumability of the provided note.
     pub async fn get_note_consumability(
         &self,
         note: InputNoteRecord,
     ) -> Result<Vec<NoteConsumability>, ClientError> {
         let note_screener = NoteScreener::new(self.store.clone());
-        note_screener
-            .check_relevance(&note.clone().try_into()?)
-            .await
-            .map_err(|err| err.into())
+        note_screener.check_relevance(&note.to_note()?).await.map_err(|err| err.into())
     }
 
     /// Retrieves the input note given a [NoteId]
@@ -109,6 +218,30 @@ impl<R: FeltRng> Client<R> {
             .expect("The vector always has one element for NoteFilter::Unique"))
     }
 
+    /// Retrieves the input notes created by the transaction with the specified ID, like the notes
+    /// a faucet minted to one of the client's accounts. Notes created by the transaction that the
+    /// client doesn't track as input notes are not returned.
+    pub async fn get_input_notes_created_by_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Vec<InputNoteRecord>, ClientError> {
+        self.get_input_notes(NoteFilter::CreatedByTransaction(transaction_id)).await
+    }
+
+    /// Returns the ID of the local transaction that consumed the input note with the specified ID,
+    /// or that is consuming it if the note is being processed. Returns `None` if the note wasn't
+    /// consumed by a local transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no input note with the provided ID.
+    pub async fn get_note_consumer(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<TransactionId>, ClientError> {
+        Ok(self.get_input_note(note_id).await?.consumer_transaction_id().copied())
+    }
+
     // OUTPUT NOTE DATA RETRIEVAL
     // --------------------------------------------------------------------------------------------
 
@@ -130,6 +263,60 @@ impl<R: FeltRng> Client<R> {
             .expect("The vector always has one element for NoteFilter::Unique"))
     }
 
+    /// Returns the note whose ID starts with the provided hex prefix, looking into both the input
+    /// and the output notes.
+    ///
+    /// The prefix is normalized with [normalize_id_prefix], so the leading `0x` is optional and
+    /// the match is case insensitive.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [IdPrefixFetchError::NoMatch] if no tracked note ID starts with the prefix.
+    /// - Returns [IdPrefixFetchError::MultipleMatches] with the IDs of the matching notes if more
+    ///   than one note ID starts with the prefix.
+    pub async fn find_note_by_prefix(&self, prefix: &str) -> Result<NoteMatch, IdPrefixFetchError> {
+        let no_match = || IdPrefixFetchError::NoMatch(format!("note ID prefix {prefix}"));
+        let store_error = |err: StoreError| {
+            tracing::error!("Error when fetching notes from the store: {err}");
+            no_match()
+        };
+
+        let normalized_prefix = normalize_id_prefix(prefix).ok_or_else(no_match)?;
+        let note_ids = self
+            .store
+            .get_note_ids_by_prefix(&normalized_prefix)
+            .await
+            .map_err(store_error)?;
+
+        let note_id = match note_ids.as_slice() {
+            [] => return Err(no_match()),
+            [note_id] => *note_id,
+            _ => {
+                let mut candidates: Vec<String> = note_ids.iter().map(NoteId::to_hex).collect();
+                candidates.sort();
+                return Err(IdPrefixFetchError::MultipleMatches(
+                    format!("note ID prefix {prefix}"),
+                    candidates,
+                ));
+            },
+        };
+
+        let input_note = self
+            .store
+            .get_input_notes(NoteFilter::List(vec![note_id]))
+            .await
+            .map_err(store_error)?
+            .pop();
+        let output_note = self
+            .store
+            .get_output_notes(NoteFilter::List(vec![note_id]))
+            .await
+            .map_err(store_error)?
+            .pop();
+
+        Ok(NoteMatch { id: note_id, input_note, output_note })
+    }
+
     /// Compiles the provided program into a [NoteScript]
     pub fn compile_note_script(&self, note_script_ast: &str) -> Result<NoteScript, ClientError> {
         NoteScript::compile(note_script_ast, TransactionKernel::assembler())
@@ -137,52 +324,21 @@ impl<R: FeltRng> Client<R> {
     }
 }
 
-/// Returns the client input note whose ID starts with `note_id_prefix`
-///
-/// # Errors
-///
-/// - Returns [IdPrefixFetchError::NoMatch] if we were unable to find any note where
-///   `note_id_prefix` is a prefix of its id.
-/// - Returns [IdPrefixFetchError::MultipleMatches] if there were more than one note found where
-///   `note_id_prefix` is a prefix of its id.
-pub async fn get_input_note_with_id_prefix<R: FeltRng>(
-    client: &Client<R>,
-    note_id_prefix: &str,
-) -> Result<InputNoteRecord, IdPrefixFetchError> {
-    let mut input_note_records = client
-        .get_input_notes(NoteFilter::All)
-        .await
-        .map_err(|err| {
-            tracing::error!("Error when fetching all notes from the store: {err}");
-            IdPrefixFetchError::NoMatch(format!("note ID prefix {note_id_prefix}").to_string())
-        })?
-        .into_iter()
-        .filter(|note_record| note_record.id().to_hex().starts_with(note_id_prefix))
-        .collect::<Vec<_>>();
-
-    if input_note_records.is_empty() {
-        return Err(IdPrefixFetchError::NoMatch(
-            format!("note ID prefix {note_id_prefix}").to_string(),
-        ));
-    }
-    if input_note_records.len() > 1 {
-        let input_note_record_ids = input_note_records
-            .iter()
-            .map(|input_note_record| input_note_record.id())
-            .collect::<Vec<_>>();
-        tracing::error!(
-            "Multiple notes found for the prefix {}: {:?}",
-            note_id_prefix,
-            input_note_record_ids
-        );
-        return Err(IdPrefixFetchError::MultipleMatches(
-            format!("note ID prefix {note_id_prefix}").to_string(),
-        ));
-    }
+// NOTE MATCH
+// ------------------------------------------------------------------------------------------------
 
-    Ok(input_note_records
-        .pop()
-        .expect("input_note_records should always have one element"))
+/// Note found with [Client::find_note_by_prefix], with the records tracked by the client for it.
+///
+/// A note is tracked as an input note, an output note or both, so at least one of the records is
+/// always present.
+#[derive(Debug, Clone)]
+pub struct NoteMatch {
+    /// ID of the matching note.
+    pub id: NoteId,
+    /// Input note record of the matching note, if the note is tracked as an input note.
+    pub input_note: Option<InputNoteRecord>,
+    /// Output note record of the matching note, if the note is tracked as an output note.
+    pub output_note: Option<OutputNoteRecord>,
 }
 
 // NOTE UPDATES
@@ -8,7 +8,10 @@ use miden_objects::{
     AccountError, AssetError, Word,
 };
 
-use super::script_roots::{P2ID, P2IDR, SWAP};
+use super::{
+    memo::p2id_memo_script,
+    script_roots::{P2ID, P2IDR, SWAP},
+};
 use crate::store::{Store, StoreError};
 
 /// Describes the relevance of a note based on the screening.
@@ -53,21 +56,34 @@ impl NoteScreener {
     /// Returns a vector of tuples describing the relevance of the provided note to the
     /// accounts monitored by this screener.
     ///
-    /// Does a fast check for known scripts (P2ID, P2IDR, SWAP). We're currently
-    /// unable to execute notes that are not committed so a slow check for other scripts is
-    /// currently not available.
+    /// Does a fast check for known scripts (P2ID, P2IDR, SWAP and P2ID with a memo). We're
+    /// currently unable to execute notes that are not committed so a slow check for other scripts
+    /// is currently not available.
     pub async fn check_relevance(
         &self,
         note: &Note,
     ) -> Result<Vec<NoteConsumability>, NoteScreenerError> {
         let account_ids = BTreeSet::from_iter(self.store.get_account_ids().await?);
 
+        self.check_relevance_for_accounts(note, &account_ids).await
+    }
+
+    /// Returns the relevance of the provided note to the specified accounts only, without reading
+    /// the tracked accounts from the store.
+    pub(crate) async fn check_relevance_for_accounts(
+        &self,
+        note: &Note,
+        account_ids: &BTreeSet<AccountId>,
+    ) -> Result<Vec<NoteConsumability>, NoteScreenerError> {
         let script_hash = note.script().hash().to_string();
         let note_relevance = match script_hash.as_str() {
-            P2ID => Self::check_p2id_relevance(note, &account_ids)?,
-            P2IDR => Self::check_p2idr_relevance(note, &account_ids)?,
-            SWAP => self.check_swap_relevance(note, &account_ids).await?,
-            _ => self.check_script_relevance(note, &account_ids)?,
+            P2ID => Self::check_p2id_relevance(note, account_ids)?,
+            P2IDR => Self::check_p2idr_relevance(note, account_ids)?,
+            SWAP => self.check_swap_relevance(note, account_ids).await?,
+            _ if note.script().hash() == p2id_memo_script().hash() => {
+                Self::check_p2id_memo_relevance(note, account_ids)?
+            },
+            _ => self.check_script_relevance(note, account_ids)?,
         };
 
         Ok(note_relevance)
@@ -95,6 +111,26 @@ impl NoteScreener {
         Ok(vec![(account_id, NoteRelevance::Always)])
     }
 
+    /// Checks the relevance of a P2ID note with a memo. Its first input is the target account ID,
+    /// followed by the memo.
+    fn check_p2id_memo_relevance(
+        note: &Note,
+        account_ids: &BTreeSet<AccountId>,
+    ) -> Result<Vec<NoteConsumability>, NoteScreenerError> {
+        let inputs = note.inputs().values();
+        if inputs.len() < 2 {
+            return Err(InvalidNoteInputsError::WrongNumInputs(note.id(), 2).into());
+        }
+
+        let account_id = AccountId::try_from(inputs[0])
+            .map_err(|err| InvalidNoteInputsError::AccountError(note.id(), err))?;
+
+        if !account_ids.contains(&account_id) {
+            return Ok(vec![]);
+        }
+        Ok(vec![(account_id, NoteRelevance::Always)])
+    }
+
     fn check_p2idr_relevance(
         note: &Note,
         account_ids: &BTreeSet<AccountId>,
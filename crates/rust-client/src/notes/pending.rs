@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use miden_objects::{
+    crypto::rand::FeltRng,
+    notes::{NoteFile, NoteId, NoteTag},
+};
+use tracing::info;
+
+use crate::{store::NoteFilter, sync::NoteTagRecord, Client, ClientError};
+
+// PENDING NOTE RECORD
+// ================================================================================================
+
+/// Note that is expected to be committed in the chain, of which the client only knows the ID.
+///
+/// Pending notes are imported with [Client::import_note_id] when the node doesn't know the note
+/// yet. If the note's tag is tracked, the sync that receives the note's inclusion either imports
+/// it as a committed input note, if it's public, or marks the pending note as committed, if it's
+/// private. The details of a committed private note must be imported separately to consume it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingNoteRecord {
+    note_id: NoteId,
+    tag: Option<NoteTag>,
+    inclusion_block_num: Option<u32>,
+}
+
+impl PendingNoteRecord {
+    /// Returns a new pending note that is awaiting its commitment.
+    pub fn new(note_id: NoteId, tag: Option<NoteTag>) -> Self {
+        Self { note_id, tag, inclusion_block_num: None }
+    }
+
+    /// Returns a pending note with all its fields. Used by the stores to rebuild the record.
+    pub fn from_parts(
+        note_id: NoteId,
+        tag: Option<NoteTag>,
+        inclusion_block_num: Option<u32>,
+    ) -> Self {
+        Self { note_id, tag, inclusion_block_num }
+    }
+
+    /// Returns the ID of the note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the tag tracked to receive the note's inclusion, if one was provided.
+    pub fn tag(&self) -> Option<NoteTag> {
+        self.tag
+    }
+
+    /// Returns the block in which the note was committed, if it was.
+    pub fn inclusion_block_num(&self) -> Option<u32> {
+        self.inclusion_block_num
+    }
+
+    /// Returns whether the note was committed in the chain.
+    pub fn is_committed(&self) -> bool {
+        self.inclusion_block_num.is_some()
+    }
+
+    /// Returns the record tracking the note's tag, if it has one.
+    pub(crate) fn tag_record(&self) -> Option<NoteTagRecord> {
+        self.tag.map(|tag| NoteTagRecord::with_note_source(tag, self.note_id))
+    }
+}
+
+impl fmt::Display for PendingNoteRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.inclusion_block_num {
+            Some(block_num) => write!(f, "Committed at block {block_num}, details missing"),
+            None => write!(f, "Awaiting commitment"),
+        }
+    }
+}
+
+// IMPORT OUTCOME
+// ================================================================================================
+
+/// Result of importing a note by its ID with [Client::import_note_id].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportOutcome {
+    /// The note was found on the node and imported as an input note.
+    Imported(NoteId),
+    /// The node doesn't know the note yet, so it's tracked as a [PendingNoteRecord] until it's
+    /// committed.
+    PendingCommitment(NoteId),
+}
+
+impl ImportOutcome {
+    /// Returns the ID of the imported note.
+    pub fn note_id(&self) -> NoteId {
+        match self {
+            ImportOutcome::Imported(note_id) | ImportOutcome::PendingCommitment(note_id) => {
+                *note_id
+            },
+        }
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // PENDING NOTES
+    // --------------------------------------------------------------------------------------------
+
+    /// Imports the note with the specified ID. Unlike importing a [NoteFile::NoteId], the import
+    /// doesn't fail if the node doesn't know the note yet. Instead, the ID is stored as a
+    /// [PendingNoteRecord] and `tag`, if provided, starts being tracked so that a later sync can
+    /// receive the note's inclusion.
+    ///
+    /// If no tag is provided, the note is only received if its tag is tracked for another reason,
+    /// like being derived from one of the client's accounts.
+    pub async fn import_note_id(
+        &mut self,
+        note_id: NoteId,
+        tag: Option<NoteTag>,
+    ) -> Result<ImportOutcome, ClientError> {
+        if !self.get_notes_by_id_in_batches(&[note_id]).await?.is_empty() {
+            return self.import_note(NoteFile::NoteId(note_id)).await.map(ImportOutcome::Imported);
+        }
+
+        let pending_note = PendingNoteRecord::new(note_id, tag);
+        if let Some(tag_record) = pending_note.tag_record() {
+            self.store.add_note_tag(tag_record).await?;
+        }
+        self.store.upsert_pending_note(pending_note).await?;
+        info!("Note {note_id} is not committed yet, tracking it as a pending note.");
+
+        Ok(ImportOutcome::PendingCommitment(note_id))
+    }
+
+    /// Returns the notes imported by ID that are awaiting their commitment or whose details are
+    /// missing.
+    pub async fn get_pending_notes(&self) -> Result<Vec<PendingNoteRecord>, ClientError> {
+        self.store.get_pending_notes().await.map_err(|err| err.into())
+    }
+
+    /// Stops tracking the pending note with the specified ID, if there is one, because the note
+    /// was imported as an input note. The pending note's tag is kept if it's `imported_tag`, the
+    /// tag record added for the imported note.
+    pub(crate) async fn remove_pending_note(
+        &mut self,
+        note_id: NoteId,
+        imported_tag: Option<NoteTagRecord>,
+    ) -> Result<(), ClientError> {
+        let Some(pending_note) = self
+            .store
+            .get_pending_notes()
+            .await?
+            .into_iter()
+            .find(|note| note.note_id == note_id)
+        else {
+            return Ok(());
+        };
+
+        self.store.remove_pending_note(note_id).await?;
+        if let Some(tag_record) = pending_note.tag_record().filter(|tag| Some(*tag) != imported_tag)
+        {
+            self.store.remove_note_tag(tag_record).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the pending notes committed in the block with the provided number, after the
+    /// block's note updates were applied to the store.
+    ///
+    /// Pending notes that were imported as input notes, because they are public, are removed.
+    /// The rest are marked as committed. In both cases their tags stop being tracked.
+    pub(crate) async fn update_committed_pending_notes(
+        &mut self,
+        committed_note_ids: &[NoteId],
+        block_num: u32,
+    ) -> Result<(), ClientError> {
+        let pending_notes: Vec<PendingNoteRecord> = self
+            .store
+            .get_pending_notes()
+            .await?
+            .into_iter()
+            .filter(|note| !note.is_committed() && committed_note_ids.contains(&note.note_id()))
+            .collect();
+        if pending_notes.is_empty() {
+            return Ok(());
+        }
+
+        let imported_note_ids: Vec<NoteId> = self
+            .store
+            .get_input_notes(NoteFilter::List(
+                pending_notes.iter().map(PendingNoteRecord::note_id).collect(),
+            ))
+            .await?
+            .iter()
+            .map(|note| note.id())
+            .collect();
+
+        for mut pending_note in pending_notes {
+            if imported_note_ids.contains(&pending_note.note_id) {
+                self.store.remove_pending_note(pending_note.note_id).await?;
+            } else {
+                pending_note.inclusion_block_num = Some(block_num);
+                self.store.upsert_pending_note(pending_note).await?;
+                info!(
+                    "Pending note {} was committed at block {block_num}, but its details are missing.",
+                    pending_note.note_id
+                );
+            }
+
+            if let Some(tag_record) = pending_note.tag_record() {
+                self.store.remove_note_tag(tag_record).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
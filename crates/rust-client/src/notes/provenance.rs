@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+
+use miden_objects::{crypto::rand::FeltRng, notes::NoteId};
+
+use crate::{
+    store::{
+        InputNoteRecord, InputNoteState, NoteFilter, OutputNoteRecord, OutputNoteState, StoreError,
+    },
+    transactions::{TransactionRecord, TransactionStatus},
+    Client, ClientError,
+};
+
+// NOTE PROVENANCE
+// ================================================================================================
+
+/// History of a note as known by the client: the transactions that created and consumed it and
+/// the blocks in which that happened.
+#[derive(Debug, Clone)]
+pub struct NoteProvenance {
+    /// ID of the note.
+    pub note_id: NoteId,
+    /// Local transaction that created the note. `None` if the note was created by another account
+    /// or imported.
+    pub creator_transaction: Option<TransactionRecord>,
+    /// How the note was consumed, if it was.
+    pub consumption: Option<NoteConsumption>,
+    /// Block in which the note was included in the chain, if it's known.
+    pub inclusion_block_num: Option<u32>,
+}
+
+/// Consumption of a note, as reported by [NoteProvenance].
+#[derive(Debug, Clone)]
+pub enum NoteConsumption {
+    /// The note was consumed by a local transaction. The block in which the note's nullifier was
+    /// committed is known once the transaction is committed and the client synced.
+    Local {
+        transaction: TransactionRecord,
+        nullifier_block_num: Option<u32>,
+    },
+    /// The note was consumed by a transaction that is not tracked by the client, and its
+    /// nullifier was committed in the contained block.
+    External { nullifier_block_num: u32 },
+}
+
+impl<R: FeltRng> Client<R> {
+    // NOTE PROVENANCE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transactions that created and consumed the note with the specified ID, along
+    /// with the blocks in which the note was included and nullified. Discarded transactions are
+    /// not taken into account.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::StoreError] if the note is neither an input nor an output note of
+    /// the client.
+    pub async fn get_note_transactions(
+        &self,
+        note_id: NoteId,
+    ) -> Result<NoteProvenance, ClientError> {
+        let input_note = self.store.get_input_notes(NoteFilter::List(vec![note_id])).await?.pop();
+        let output_note = self.store.get_output_notes(NoteFilter::List(vec![note_id])).await?.pop();
+        if input_note.is_none() && output_note.is_none() {
+            return Err(StoreError::NoteNotFound(note_id).into());
+        }
+
+        let nullifier = input_note
+            .as_ref()
+            .map(InputNoteRecord::nullifier)
+            .or_else(|| output_note.as_ref().and_then(OutputNoteRecord::nullifier));
+
+        let transactions: Vec<TransactionRecord> = self
+            .store
+            .get_note_transactions(note_id, nullifier)
+            .await?
+            .into_iter()
+            .filter(|tx| tx.transaction_status != TransactionStatus::Discarded)
+            .collect();
+
+        let creator_transaction = transactions
+            .iter()
+            .find(|tx| tx.output_notes.iter().any(|note| note.id() == note_id))
+            .cloned();
+        let consumer_transaction = nullifier.and_then(|nullifier| {
+            transactions
+                .into_iter()
+                .find(|tx| tx.input_note_nullifiers.contains(&nullifier.inner()))
+        });
+
+        let nullifier_block_num =
+            input_note.as_ref().and_then(input_nullifier_block_num).or_else(|| {
+                output_note.as_ref().and_then(|note| match note.state() {
+                    OutputNoteState::Consumed { block_height, .. } => Some(*block_height),
+                    _ => None,
+                })
+            });
+        let consumption = match (consumer_transaction, nullifier_block_num) {
+            (Some(transaction), nullifier_block_num) => {
+                Some(NoteConsumption::Local { transaction, nullifier_block_num })
+            },
+            (None, Some(nullifier_block_num)) => {
+                Some(NoteConsumption::External { nullifier_block_num })
+            },
+            (None, None) => None,
+        };
+
+        let inclusion_block_num = input_note
+            .as_ref()
+            .and_then(InputNoteRecord::inclusion_proof)
+            .or_else(|| output_note.as_ref().and_then(OutputNoteRecord::inclusion_proof))
+            .map(|proof| proof.location().block_num());
+
+        Ok(NoteProvenance {
+            note_id,
+            creator_transaction,
+            consumption,
+            inclusion_block_num,
+        })
+    }
+}
+
+/// Returns the block in which the nullifier of the input note was committed, if the note is
+/// consumed.
+fn input_nullifier_block_num(note: &InputNoteRecord) -> Option<u32> {
+    match note.state() {
+        InputNoteState::ConsumedAuthenticatedLocal(state) => Some(state.nullifier_block_height),
+        InputNoteState::ConsumedUnauthenticatedLocal(state) => Some(state.nullifier_block_height),
+        InputNoteState::ConsumedExternal(state) => Some(state.nullifier_block_height),
+        _ => None,
+    }
+}
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    crypto::rand::FeltRng,
+    notes::{NoteId, NoteInclusionProof, NoteTag, NoteType},
+    transaction::InputNote,
+};
+use tracing::{info, warn};
+
+use super::NoteScreener;
+use crate::{rpc::NoteDetails, store::InputNoteRecord, Client, ClientError};
+
+impl<R: FeltRng> Client<R> {
+    // PUBLIC NOTE SEARCH
+    // --------------------------------------------------------------------------------------------
+
+    /// Searches the blocks from `from_block` to `to_block`, both included, for public notes with
+    /// any of the provided tags, and returns the ones that can be consumed by the client's
+    /// accounts.
+    ///
+    /// This helps recover a public note when only its tag and the approximate block it was
+    /// committed in are known. The notes are returned with their inclusion proofs, in the
+    /// [Unverified](crate::store::InputNoteState::Unverified) state, but aren't stored: a returned
+    /// note can be tracked by importing it with [Client::import_note]. Private notes in the range
+    /// are skipped, as the node doesn't have their details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node can't be reached or if the client's accounts can't be read to
+    /// screen the notes.
+    pub async fn find_public_notes_in_range(
+        &mut self,
+        tags: &[NoteTag],
+        from_block: u32,
+        to_block: u32,
+    ) -> Result<Vec<InputNoteRecord>, ClientError> {
+        if tags.is_empty() || from_block > to_block {
+            return Ok(vec![]);
+        }
+
+        // The node returns the notes of the first block after the requested one that has any
+        let mut request_block_num = from_block.saturating_sub(1);
+        let mut public_note_ids: Vec<NoteId> = vec![];
+        loop {
+            let response = self.rpc_api.sync_notes(request_block_num, tags).await?;
+            let block_num = response.block_header.block_num();
+            if block_num > to_block {
+                break;
+            }
+
+            if block_num >= from_block {
+                public_note_ids.extend(
+                    response
+                        .notes
+                        .iter()
+                        .filter(|note| note.metadata().note_type() == NoteType::Public)
+                        .map(|note| *note.note_id()),
+                );
+            }
+
+            if block_num >= to_block
+                || block_num >= response.chain_tip
+                || block_num <= request_block_num
+            {
+                break;
+            }
+            request_block_num = block_num;
+        }
+
+        if public_note_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let note_screener = NoteScreener::new(self.store.clone());
+        let mut relevant_notes: Vec<InputNoteRecord> = vec![];
+        for note_details in self.get_notes_by_id_in_batches(&public_note_ids).await? {
+            let NoteDetails::Public(note, inclusion_details) = note_details else {
+                continue;
+            };
+
+            match note_screener.check_relevance(&note).await {
+                Ok(relevance) if relevance.is_empty() => continue,
+                Ok(_) => {},
+                Err(err) => {
+                    warn!("Skipping note {} that couldn't be screened: {err}", note.id());
+                    continue;
+                },
+            }
+
+            let inclusion_proof = NoteInclusionProof::new(
+                inclusion_details.block_num,
+                inclusion_details.note_index,
+                inclusion_details.merkle_path.clone(),
+            )?;
+            relevant_notes.push(InputNote::authenticated(note, inclusion_proof).into());
+        }
+
+        info!(
+            "Found {} relevant public notes out of {} between blocks {from_block} and {to_block}",
+            relevant_notes.len(),
+            public_note_ids.len()
+        );
+
+        Ok(relevant_notes)
+    }
+}
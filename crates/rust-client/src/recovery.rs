@@ -0,0 +1,358 @@
+//! Provides [Client::rebuild_derived_state], which regenerates the data the client derives from
+//! its primary records when it gets out of sync with them, for example after a crash or a faulty
+//! migration.
+//!
+//! Each [DerivedCategory] is rebuilt with a single store update and then checked against the
+//! invariants that tie it to the primary records.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+
+use miden_objects::{
+    crypto::{hash::rpo::Rpo256, merkle::InOrderIndex, rand::FeltRng},
+    notes::{NoteExecutionMode, NoteTag},
+    Digest,
+};
+use tracing::{info, warn};
+
+use crate::{
+    store::{ChainMmrNodeFilter, NoteFilter},
+    sync::{NoteTagRecord, NoteTagSource},
+    Client, ClientError,
+};
+
+// DERIVED CATEGORY
+// ================================================================================================
+
+/// Category of derived data that can be rebuilt with [Client::rebuild_derived_state].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DerivedCategory {
+    /// Tags tracked on behalf of the client's accounts, derived from the tracked accounts.
+    AccountTags,
+    /// Tags tracked on behalf of notes, derived from the expected notes and the pending notes
+    /// that weren't committed yet.
+    NoteTags,
+    /// Nodes of the persisted partial chain MMR that can be computed from the stored block
+    /// headers and the rest of the authentication nodes.
+    ChainMmr,
+}
+
+impl DerivedCategory {
+    /// Every derived category, in the order they are rebuilt.
+    pub const ALL: [DerivedCategory; 3] = [
+        DerivedCategory::AccountTags,
+        DerivedCategory::NoteTags,
+        DerivedCategory::ChainMmr,
+    ];
+}
+
+impl fmt::Display for DerivedCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DerivedCategory::AccountTags => write!(f, "account tags"),
+            DerivedCategory::NoteTags => write!(f, "note tags"),
+            DerivedCategory::ChainMmr => write!(f, "chain MMR"),
+        }
+    }
+}
+
+// REBUILD REPORT
+// ================================================================================================
+
+/// Result of rebuilding a single [DerivedCategory].
+#[derive(Debug)]
+pub struct CategoryRebuildReport {
+    /// Category that was rebuilt.
+    pub category: DerivedCategory,
+    /// Number of records that were added, removed or overwritten, or the error that stopped the
+    /// rebuild or its verification.
+    pub result: Result<usize, ClientError>,
+}
+
+/// Results of the categories rebuilt by [Client::rebuild_derived_state], in the order they were
+/// rebuilt.
+#[derive(Debug, Default)]
+pub struct RebuildReport {
+    pub categories: Vec<CategoryRebuildReport>,
+}
+
+impl RebuildReport {
+    /// Returns the report of the specified category, if it was rebuilt.
+    pub fn category(&self, category: DerivedCategory) -> Option<&CategoryRebuildReport> {
+        self.categories.iter().find(|report| report.category == category)
+    }
+
+    /// Returns the total number of records repaired in the categories that were rebuilt
+    /// successfully.
+    pub fn records_repaired(&self) -> usize {
+        self.categories.iter().filter_map(|report| report.result.as_ref().ok()).sum()
+    }
+
+    /// Returns the reports of the categories whose rebuild failed.
+    pub fn failed_categories(&self) -> impl Iterator<Item = &CategoryRebuildReport> {
+        self.categories.iter().filter(|report| report.result.is_err())
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // DERIVED STATE RECOVERY
+    // --------------------------------------------------------------------------------------------
+
+    /// Rebuilds the specified categories of derived data from the client's primary records, and
+    /// reports the number of records repaired in each one.
+    ///
+    /// Categories are rebuilt once each in the order of [DerivedCategory::ALL], regardless of the
+    /// order in which they are provided. A category whose rebuild fails, or that doesn't satisfy
+    /// its invariants once rebuilt, doesn't prevent the rest from being rebuilt; its error is
+    /// included in the report.
+    pub async fn rebuild_derived_state(&mut self, categories: &[DerivedCategory]) -> RebuildReport {
+        let categories: BTreeSet<DerivedCategory> = categories.iter().copied().collect();
+        let mut report = RebuildReport::default();
+
+        for category in categories {
+            let result = match category {
+                DerivedCategory::AccountTags => self.rebuild_account_tags().await,
+                DerivedCategory::NoteTags => self.rebuild_note_tags().await,
+                DerivedCategory::ChainMmr => self.rebuild_chain_mmr().await,
+            };
+
+            match &result {
+                Ok(repaired) => info!("Rebuilt {category}, repairing {repaired} records."),
+                Err(err) => warn!("Failed to rebuild {category}: {err}"),
+            }
+            report.categories.push(CategoryRebuildReport { category, result });
+        }
+
+        report
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Replaces the account-sourced tag records with the ones derived from the tracked accounts.
+    async fn rebuild_account_tags(&mut self) -> Result<usize, ClientError> {
+        let derived_tags = self
+            .store
+            .get_account_ids()
+            .await?
+            .into_iter()
+            .map(|account_id| {
+                Ok(NoteTagRecord::with_account_source(
+                    NoteTag::from_account_id(account_id, NoteExecutionMode::Local)?,
+                    account_id,
+                ))
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+
+        self.replace_derived_tags(DerivedCategory::AccountTags, derived_tags, |source| {
+            matches!(source, NoteTagSource::Account(_))
+        })
+        .await
+    }
+
+    /// Replaces the note-sourced tag records with the ones derived from the expected notes and
+    /// the uncommitted pending notes.
+    async fn rebuild_note_tags(&mut self) -> Result<usize, ClientError> {
+        let mut derived_tags: Vec<NoteTagRecord> = self
+            .store
+            .get_pending_notes()
+            .await?
+            .iter()
+            .filter(|note| !note.is_committed())
+            .filter_map(|note| note.tag_record())
+            .collect();
+
+        // When a pending note is imported with the same tag, the pending note's record is kept
+        // for the imported note, so the records of pending notes take precedence
+        for tag_record in self
+            .store
+            .get_input_notes(NoteFilter::Expected)
+            .await?
+            .iter()
+            .filter_map(NoteTagRecord::for_expected_note)
+        {
+            if !derived_tags.contains(&tag_record) {
+                derived_tags.push(tag_record);
+            }
+        }
+
+        self.replace_derived_tags(DerivedCategory::NoteTags, derived_tags, |source| {
+            matches!(source, NoteTagSource::Note(_))
+        })
+        .await
+    }
+
+    /// Replaces the tag records whose source matches `is_derived_source` with `derived_tags`,
+    /// leaving the records that already match untouched, and checks that the stored records match
+    /// the derived ones afterwards. Returns the number of records removed or added.
+    async fn replace_derived_tags(
+        &mut self,
+        category: DerivedCategory,
+        derived_tags: Vec<NoteTagRecord>,
+        is_derived_source: impl Fn(&NoteTagSource) -> bool,
+    ) -> Result<usize, ClientError> {
+        let stored_tags: Vec<NoteTagRecord> = self
+            .store
+            .get_note_tags()
+            .await?
+            .into_iter()
+            .filter(|tag_record| is_derived_source(&tag_record.source))
+            .collect();
+
+        // Records are compared with their expiry, so that a record with the wrong expiry is
+        // replaced as well. Duplicated records are stale too.
+        let stale_tags: Vec<NoteTagRecord> = stored_tags
+            .iter()
+            .enumerate()
+            .filter(|(position, tag_record)| {
+                !contains_tag_record(&derived_tags, tag_record)
+                    || contains_tag_record(&stored_tags[..*position], tag_record)
+            })
+            .map(|(_, tag_record)| *tag_record)
+            .collect();
+        // Removing a stale record removes every record with the same tag and source, so those
+        // are added back even if one of them was correct
+        let missing_tags: Vec<NoteTagRecord> = derived_tags
+            .iter()
+            .filter(|tag_record| {
+                !contains_tag_record(&stored_tags, tag_record) || stale_tags.contains(tag_record)
+            })
+            .copied()
+            .collect();
+        let repaired = stale_tags.len() + missing_tags.len();
+
+        if repaired > 0 {
+            self.store.replace_note_tags(stale_tags, missing_tags).await?;
+        }
+
+        let rebuilt_tags: Vec<NoteTagRecord> = self
+            .store
+            .get_note_tags()
+            .await?
+            .into_iter()
+            .filter(|tag_record| is_derived_source(&tag_record.source))
+            .collect();
+        if rebuilt_tags.len() != derived_tags.len()
+            || !derived_tags
+                .iter()
+                .all(|tag_record| contains_tag_record(&rebuilt_tags, tag_record))
+        {
+            return Err(ClientError::DerivedStateInconsistent {
+                category,
+                reason: String::from("the stored tag records don't match the derived ones"),
+            });
+        }
+
+        Ok(repaired)
+    }
+
+    /// Recomputes the nodes of the persisted partial chain MMR that can be derived, and checks
+    /// that the resulting MMR authenticates every tracked block header. Returns the number of
+    /// nodes that were overwritten.
+    ///
+    /// A leaf node is the hash of the block header at its position, if the header is stored, and
+    /// an inner node is the merge of its children, if both of them are stored.
+    async fn rebuild_chain_mmr(&mut self) -> Result<usize, ClientError> {
+        let mut nodes = self.store.get_chain_mmr_nodes(ChainMmrNodeFilter::All).await?;
+
+        let leaf_block_nums: Vec<u32> = nodes
+            .keys()
+            .filter(|index| index.is_leaf())
+            .map(|index| ((u64::from(*index) - 1) / 2) as u32)
+            .collect();
+        let mut derived_nodes: BTreeMap<InOrderIndex, Digest> = self
+            .store
+            .get_block_headers(&leaf_block_nums)
+            .await?
+            .into_iter()
+            .map(|(header, _)| {
+                (InOrderIndex::from_leaf_pos(header.block_num() as usize), header.hash())
+            })
+            .collect();
+        nodes.extend(derived_nodes.iter().map(|(index, node)| (*index, *node)));
+
+        // Inner nodes are visited from the lowest level up, so that their children are already
+        // derived when they are merged
+        let mut inner_indices: Vec<InOrderIndex> =
+            nodes.keys().filter(|index| !index.is_leaf()).copied().collect();
+        inner_indices.sort_by_key(|index| index.level());
+        for index in inner_indices {
+            if let (Some(left), Some(right)) =
+                (nodes.get(&index.left_child()), nodes.get(&index.right_child()))
+            {
+                let node = Rpo256::merge(&[*left, *right]);
+                nodes.insert(index, node);
+                derived_nodes.insert(index, node);
+            }
+        }
+
+        let stored_nodes = self.store.get_chain_mmr_nodes(ChainMmrNodeFilter::All).await?;
+        let repaired_nodes: Vec<(InOrderIndex, Digest)> = derived_nodes
+            .into_iter()
+            .filter(|(index, node)| stored_nodes.get(index) != Some(node))
+            .collect();
+        if !repaired_nodes.is_empty() {
+            self.store.replace_chain_mmr_nodes(&repaired_nodes).await?;
+        }
+
+        self.verify_chain_mmr().await?;
+
+        Ok(repaired_nodes.len())
+    }
+
+    /// Checks that the peaks at the sync height match the chain commitment of the latest block
+    /// header, and that the partial MMR authenticates every tracked block header against them.
+    async fn verify_chain_mmr(&self) -> Result<(), ClientError> {
+        let inconsistency = |reason: String| ClientError::DerivedStateInconsistent {
+            category: DerivedCategory::ChainMmr,
+            reason,
+        };
+
+        let sync_height = self.store.get_sync_height().await?;
+        if sync_height == 0 {
+            return Ok(());
+        }
+
+        let partial_mmr = self.build_current_partial_mmr(false).await?;
+        let peaks = partial_mmr.peaks();
+        let (latest_header, _) = self.store.get_block_header_by_num(sync_height).await?;
+        if peaks.hash_peaks() != latest_header.chain_root() {
+            return Err(inconsistency(format!(
+                "the peaks at block {sync_height} don't match its chain commitment"
+            )));
+        }
+
+        for header in self
+            .store
+            .get_tracked_block_headers()
+            .await?
+            .into_iter()
+            .filter(|header| header.block_num() < sync_height)
+        {
+            let block_num = header.block_num();
+            let proof = partial_mmr
+                .open(block_num as usize)
+                .map_err(|err| inconsistency(format!("block {block_num} can't be opened: {err}")))?
+                .ok_or_else(|| inconsistency(format!("block {block_num} is not tracked")))?;
+
+            peaks.verify(header.hash(), proof).map_err(|err| {
+                inconsistency(format!("block {block_num} is not authenticated: {err}"))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether `tag_records` contains a record with the same tag, source and expiry as
+/// `tag_record`.
+fn contains_tag_record(tag_records: &[NoteTagRecord], tag_record: &NoteTagRecord) -> bool {
+    tag_records
+        .iter()
+        .any(|record| record == tag_record && record.expires_after == tag_record.expires_after)
+}
@@ -13,6 +13,7 @@ pub enum RpcError {
     DeserializationError(String),
     ExpectedDataMissing(String),
     InvalidResponse(String),
+    NotFound(String, String),
     RequestError(String, String),
 }
 
@@ -38,6 +39,9 @@ impl fmt::Display for RpcError {
             RpcError::InvalidResponse(err) => {
                 write!(f, "RPC API response is invalidw: {err}")
             },
+            RpcError::NotFound(endpoint, err) => {
+                write!(f, "RPC request for {endpoint} found no data: {err}")
+            },
             RpcError::RequestError(endpoint, err) => {
                 write!(f, "RPC request failed for {endpoint}: {err}")
             },
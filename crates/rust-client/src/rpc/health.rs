@@ -0,0 +1,78 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::time::Duration;
+
+use miden_objects::crypto::rand::FeltRng;
+
+use super::{NodeRpcClientEndpoint, RpcMethodStats, RpcTraffic};
+use crate::{store::StoreError, time::Stopwatch, Client, ClientError};
+
+// CONNECTION HEALTH
+// ================================================================================================
+
+/// State of the connection with the node, as reported by [Client::check_connection].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionHealth {
+    /// Endpoint of the node that answered, if the RPC client reports it.
+    pub endpoint: Option<String>,
+    /// Round-trip time of the request for the latest block header. Only measured when the `std`
+    /// feature is enabled.
+    pub latency: Option<Duration>,
+    /// Number of the latest block known by the node.
+    pub chain_tip: u32,
+    /// Whether the node's genesis block matches the one in the store. `None` if the client never
+    /// synced, so the store has no genesis block to compare against.
+    pub genesis_matches: Option<bool>,
+}
+
+impl<R: FeltRng> Client<R> {
+    // CONNECTION HEALTH
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks the connection with the node by requesting the latest block header, without its MMR
+    /// proof, and measuring how long the request takes.
+    ///
+    /// If the store has a genesis block, the node's genesis block is also requested to check that
+    /// the node belongs to the same chain as the one the client synced with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node can't be reached or fails to answer either request.
+    pub async fn check_connection(&mut self) -> Result<ConnectionHealth, ClientError> {
+        let stopwatch = Stopwatch::start();
+        let (chain_tip_header, _) = self.rpc_api.get_block_header_by_number(None, false).await?;
+        let latency = stopwatch.elapsed();
+
+        let genesis_matches = match self.store.get_block_header_by_num(0).await {
+            Ok((stored_genesis, _)) => {
+                let (node_genesis, _) =
+                    self.rpc_api.get_block_header_by_number(Some(0), false).await?;
+                Some(node_genesis.hash() == stored_genesis.hash())
+            },
+            Err(StoreError::BlockHeaderNotFound(0)) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(ConnectionHealth {
+            endpoint: self.rpc_api.endpoint(),
+            latency,
+            chain_tip: chain_tip_header.block_num(),
+            genesis_matches,
+        })
+    }
+
+    /// Returns the latency stats of the recent calls made to each RPC method, as collected by the
+    /// client's RPC implementation. The list is empty if the implementation doesn't measure its
+    /// calls.
+    pub fn rpc_stats(&self) -> Vec<RpcMethodStats> {
+        self.rpc_api.latency_stats()
+    }
+
+    /// Returns the bytes exchanged with the node by the calls made so far to each RPC method, as
+    /// collected by the client's RPC implementation. The map is empty if the implementation
+    /// doesn't measure its calls.
+    ///
+    /// The traffic of each sync is also reported in its [SyncSummary](crate::sync::SyncSummary).
+    pub fn rpc_traffic(&self) -> BTreeMap<NodeRpcClientEndpoint, RpcTraffic> {
+        self.rpc_api.traffic()
+    }
+}
@@ -2,8 +2,13 @@
 //! Remote Procedure Calls (RPC). It facilitates syncing with the network and submitting
 //! transactions.
 
-use alloc::{boxed::Box, collections::BTreeSet, vec::Vec};
-use core::fmt;
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, time::Duration};
 
 use async_trait::async_trait;
 
@@ -11,12 +16,15 @@ mod errors;
 pub(crate) use errors::RpcConversionError;
 pub use errors::RpcError;
 use miden_objects::{
-    accounts::{Account, AccountCode, AccountHeader, AccountId, AccountStorageHeader},
+    accounts::{
+        Account, AccountCode, AccountHeader, AccountId, AccountStorageHeader, StorageSlotType,
+    },
     crypto::merkle::{MerklePath, MmrDelta, MmrProof},
     notes::{Note, NoteId, NoteMetadata, NoteTag, Nullifier},
     transaction::{ProvenTransaction, TransactionId},
-    BlockHeader, Digest,
+    BlockHeader, Digest, Word,
 };
+use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 #[cfg(all(feature = "tonic", feature = "web-tonic"))]
 compile_error!("features `tonic` and `web-tonic` are mutually exclusive");
@@ -36,6 +44,12 @@ mod web_tonic_client;
 #[cfg(feature = "web-tonic")]
 pub use web_tonic_client::WebTonicRpcClient;
 
+mod health;
+pub use health::ConnectionHealth;
+
+mod multi_node_client;
+pub use multi_node_client::{MultiNodeRpcClient, NodeSubmissionResult};
+
 use crate::sync::get_nullifier_prefix;
 
 // NOTE DETAILS
@@ -95,6 +109,13 @@ impl AccountDetails {
             Self::Public(account, _) => account.id(),
         }
     }
+
+    /// Returns the hash of the account's latest state known by the node.
+    pub fn hash(&self) -> Digest {
+        match self {
+            Self::Private(_, summary) | Self::Public(_, summary) => summary.hash,
+        }
+    }
 }
 
 /// Contains public updated information about the account requested.
@@ -236,6 +257,195 @@ impl fmt::Display for AccountProofError {
     }
 }
 
+// SUBMISSION INFO
+// ================================================================================================
+
+/// Information about how a proven transaction reached the network, returned by
+/// [NodeRpcClient::submit_proven_transaction].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionInfo {
+    /// Endpoint of the node that accepted the transaction.
+    pub endpoint: String,
+    /// Number of submission attempts made until the transaction was accepted, starting at 1.
+    pub attempts: u32,
+}
+
+impl SubmissionInfo {
+    /// Returns a new [SubmissionInfo] for a transaction accepted on the first attempt.
+    pub fn first_attempt(endpoint: impl Into<String>) -> Self {
+        Self { endpoint: endpoint.into(), attempts: 1 }
+    }
+}
+
+// ACCOUNT STORAGE SLOT
+// ================================================================================================
+
+/// Data of a single storage slot of a public account, as returned by
+/// [NodeRpcClient::get_account_storage_slot].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountStorageSlotData {
+    /// Value stored in a value slot.
+    Value(Word),
+    /// Root of a map slot, together with the values of the requested keys.
+    Map { root: Word, entries: Vec<(Word, Word)> },
+}
+
+// RPC LATENCY STATS
+// ================================================================================================
+
+/// Number of recent call durations kept for each RPC method by an [RpcLatencyTracker].
+pub const LATENCY_SAMPLES_PER_METHOD: usize = 32;
+
+/// Latencies of the recent successful calls to an RPC method, as returned by
+/// [NodeRpcClient::latency_stats].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcMethodStats {
+    /// RPC method the calls were made to.
+    pub method: NodeRpcClientEndpoint,
+    /// Number of calls the stats are computed from, at most [LATENCY_SAMPLES_PER_METHOD].
+    pub samples: usize,
+    /// Duration of the most recent call.
+    pub last: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+}
+
+/// Ring buffer with the durations of the most recent calls to each RPC method.
+///
+/// Used by [NodeRpcClient] implementations to collect the stats returned by
+/// [NodeRpcClient::latency_stats]. Only the last [LATENCY_SAMPLES_PER_METHOD] calls to each method
+/// are kept, so the stats follow the current state of the connection.
+#[derive(Debug, Clone, Default)]
+pub struct RpcLatencyTracker {
+    samples: BTreeMap<NodeRpcClientEndpoint, VecDeque<Duration>>,
+}
+
+impl RpcLatencyTracker {
+    /// Records the duration of a call to the specified method, dropping the oldest one if the
+    /// method's buffer is full.
+    pub fn record(&mut self, method: NodeRpcClientEndpoint, duration: Duration) {
+        let samples = self.samples.entry(method).or_default();
+        if samples.len() == LATENCY_SAMPLES_PER_METHOD {
+            samples.pop_front();
+        }
+        samples.push_back(duration);
+    }
+
+    /// Returns the stats of every method with recorded calls.
+    pub fn stats(&self) -> Vec<RpcMethodStats> {
+        self.samples
+            .iter()
+            .filter_map(|(method, samples)| {
+                let last = *samples.back()?;
+                let total: Duration = samples.iter().sum();
+
+                Some(RpcMethodStats {
+                    method: *method,
+                    samples: samples.len(),
+                    last,
+                    min: samples.iter().copied().min()?,
+                    max: samples.iter().copied().max()?,
+                    mean: total / samples.len() as u32,
+                })
+            })
+            .collect()
+    }
+}
+
+// RPC TRAFFIC
+// ================================================================================================
+
+/// Bytes exchanged with the node by the calls to an RPC method, as returned by
+/// [NodeRpcClient::traffic].
+///
+/// The sizes are the ones of the encoded request and response messages of the successful calls,
+/// so the framing and headers added by the transport are not included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RpcTraffic {
+    pub request_bytes: u64,
+    pub response_bytes: u64,
+}
+
+impl RpcTraffic {
+    /// Returns the bytes sent and received.
+    pub fn total_bytes(&self) -> u64 {
+        self.request_bytes + self.response_bytes
+    }
+
+    /// Adds the bytes of `other` to this traffic.
+    pub fn add(&mut self, other: RpcTraffic) {
+        self.request_bytes += other.request_bytes;
+        self.response_bytes += other.response_bytes;
+    }
+}
+
+impl Serializable for RpcTraffic {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.request_bytes);
+        target.write_u64(self.response_bytes);
+    }
+}
+
+impl Deserializable for RpcTraffic {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            request_bytes: source.read_u64()?,
+            response_bytes: source.read_u64()?,
+        })
+    }
+}
+
+/// Counters of the bytes exchanged with the node by the calls to each RPC method.
+///
+/// Used by [NodeRpcClient] implementations to collect the traffic returned by
+/// [NodeRpcClient::traffic]. Unlike the latencies kept by an [RpcLatencyTracker], the counters
+/// accumulate every call.
+#[derive(Debug, Clone, Default)]
+pub struct RpcTrafficCounter {
+    traffic: BTreeMap<NodeRpcClientEndpoint, RpcTraffic>,
+}
+
+impl RpcTrafficCounter {
+    /// Records the sizes of the request and response of a call to the specified method.
+    pub fn record(
+        &mut self,
+        method: NodeRpcClientEndpoint,
+        request_bytes: usize,
+        response_bytes: usize,
+    ) {
+        self.traffic.entry(method).or_default().add(RpcTraffic {
+            request_bytes: request_bytes as u64,
+            response_bytes: response_bytes as u64,
+        });
+    }
+
+    /// Returns the traffic of every method with recorded calls.
+    pub fn traffic(&self) -> BTreeMap<NodeRpcClientEndpoint, RpcTraffic> {
+        self.traffic.clone()
+    }
+}
+
+/// Returns the traffic of each method that grew from `earlier` to `current`, two snapshots of the
+/// traffic reported by a [NodeRpcClient].
+pub(crate) fn traffic_since(
+    current: &BTreeMap<NodeRpcClientEndpoint, RpcTraffic>,
+    earlier: &BTreeMap<NodeRpcClientEndpoint, RpcTraffic>,
+) -> BTreeMap<NodeRpcClientEndpoint, RpcTraffic> {
+    current
+        .iter()
+        .filter_map(|(method, traffic)| {
+            let earlier = earlier.get(method).copied().unwrap_or_default();
+            let delta = RpcTraffic {
+                request_bytes: traffic.request_bytes.saturating_sub(earlier.request_bytes),
+                response_bytes: traffic.response_bytes.saturating_sub(earlier.response_bytes),
+            };
+
+            (delta.total_bytes() > 0).then_some((*method, delta))
+        })
+        .collect()
+}
+
 // NODE RPC CLIENT TRAIT
 // ================================================================================================
 
@@ -248,10 +458,13 @@ impl fmt::Display for AccountProofError {
 pub trait NodeRpcClient {
     /// Given a Proven Transaction, send it to the node for it to be included in a future block
     /// using the `/SubmitProvenTransaction` RPC endpoint.
+    ///
+    /// On success, returns the endpoint that accepted the transaction and the number of attempts
+    /// it took, which the client records as part of the transaction's provenance.
     async fn submit_proven_transaction(
         &mut self,
         proven_transaction: ProvenTransaction,
-    ) -> Result<(), RpcError>;
+    ) -> Result<SubmissionInfo, RpcError>;
 
     /// Given a block number, fetches the block header corresponding to that height from the node
     /// using the `/GetBlockHeaderByNumber` endpoint.
@@ -321,6 +534,66 @@ pub trait NodeRpcClient {
         include_headers: bool,
     ) -> Result<AccountProofs, RpcError>;
 
+    /// Fetches a single storage slot of a public account using the `/GetAccountProofs` RPC
+    /// endpoint. For map slots, `keys` are the keys whose values are returned alongside the root.
+    ///
+    /// The default implementation reads the slot from the storage header of
+    /// [NodeRpcClient::get_account_proofs]. The node doesn't serve single map entries, so if any
+    /// key is requested the account is fetched with [NodeRpcClient::get_account_update] to read
+    /// them.
+    async fn get_account_storage_slot(
+        &mut self,
+        account_id: AccountId,
+        slot_index: u8,
+        keys: &[Word],
+    ) -> Result<AccountStorageSlotData, RpcError> {
+        let (_, account_proofs) =
+            self.get_account_proofs(&BTreeSet::from([account_id]), &[], true).await?;
+        let storage_header =
+            account_proofs.first().and_then(AccountProof::storage_header).ok_or_else(|| {
+                RpcError::ExpectedDataMissing(format!("storage header of account {account_id}"))
+            })?;
+        let (slot_type, value) =
+            storage_header.slots().nth(slot_index as usize).ok_or_else(|| {
+                RpcError::ExpectedDataMissing(format!(
+                    "storage slot {slot_index} of account {account_id}"
+                ))
+            })?;
+
+        match slot_type {
+            StorageSlotType::Value => Ok(AccountStorageSlotData::Value(*value)),
+            StorageSlotType::Map if keys.is_empty() => {
+                Ok(AccountStorageSlotData::Map { root: *value, entries: vec![] })
+            },
+            StorageSlotType::Map => {
+                let account = match self.get_account_update(account_id).await? {
+                    AccountDetails::Public(account, _) => account,
+                    AccountDetails::Private(..) => {
+                        return Err(RpcError::ExpectedDataMissing(format!(
+                            "public state of account {account_id}"
+                        )))
+                    },
+                };
+
+                let storage = account.storage();
+                let root = storage
+                    .get_item(slot_index)
+                    .map_err(|err| RpcError::InvalidResponse(err.to_string()))?;
+                let entries = keys
+                    .iter()
+                    .map(|key| {
+                        storage
+                            .get_map_item(slot_index, *key)
+                            .map(|value| (*key, value))
+                            .map_err(|err| RpcError::InvalidResponse(err.to_string()))
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(AccountStorageSlotData::Map { root: root.into(), entries })
+            },
+        }
+    }
+
     /// Fetches the commit height where the nullifier was consumed. If the nullifier is not found,
     /// then `None` is returned.
     ///
@@ -334,6 +607,25 @@ pub trait NodeRpcClient {
 
         Ok(nullifiers.iter().find(|(n, _)| n == nullifier).map(|(_, block_num)| *block_num))
     }
+
+    /// Returns the endpoint of the node the requests are sent to, if the implementation knows it.
+    fn endpoint(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the latency stats of the recent calls to each RPC method.
+    ///
+    /// The default implementation doesn't measure its calls and returns an empty list.
+    fn latency_stats(&self) -> Vec<RpcMethodStats> {
+        vec![]
+    }
+
+    /// Returns the bytes exchanged with the node by the calls made to each RPC method so far.
+    ///
+    /// The default implementation doesn't measure its calls and returns an empty map.
+    fn traffic(&self) -> BTreeMap<NodeRpcClientEndpoint, RpcTraffic> {
+        BTreeMap::new()
+    }
 }
 
 // SYNC NOTE
@@ -382,6 +674,7 @@ pub struct StateSyncInfo {
 }
 
 /// Represents a transaction that was included in the node at a certain block.
+#[derive(Debug, Clone)]
 pub struct TransactionUpdate {
     /// The transaction Identifier
     pub transaction_id: TransactionId,
@@ -451,12 +744,13 @@ impl CommittedNote {
 // ================================================================================================
 //
 /// RPC methods for the Miden protocol.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum NodeRpcClientEndpoint {
     CheckNullifiersByPrefix,
     GetAccountDetails,
     GetAccountProofs,
     GetBlockHeaderByNumber,
+    GetNotesById,
     SyncState,
     SubmitProvenTx,
     SyncNotes,
@@ -473,9 +767,44 @@ impl fmt::Display for NodeRpcClientEndpoint {
             NodeRpcClientEndpoint::GetBlockHeaderByNumber => {
                 write!(f, "get_block_header_by_number")
             },
+            NodeRpcClientEndpoint::GetNotesById => write!(f, "get_notes_by_id"),
             NodeRpcClientEndpoint::SyncState => write!(f, "sync_state"),
             NodeRpcClientEndpoint::SubmitProvenTx => write!(f, "submit_proven_transaction"),
             NodeRpcClientEndpoint::SyncNotes => write!(f, "sync_notes"),
         }
     }
 }
+
+impl Serializable for NodeRpcClientEndpoint {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let tag: u8 = match self {
+            NodeRpcClientEndpoint::CheckNullifiersByPrefix => 0,
+            NodeRpcClientEndpoint::GetAccountDetails => 1,
+            NodeRpcClientEndpoint::GetAccountProofs => 2,
+            NodeRpcClientEndpoint::GetBlockHeaderByNumber => 3,
+            NodeRpcClientEndpoint::GetNotesById => 4,
+            NodeRpcClientEndpoint::SyncState => 5,
+            NodeRpcClientEndpoint::SubmitProvenTx => 6,
+            NodeRpcClientEndpoint::SyncNotes => 7,
+        };
+        target.write_u8(tag);
+    }
+}
+
+impl Deserializable for NodeRpcClientEndpoint {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(NodeRpcClientEndpoint::CheckNullifiersByPrefix),
+            1 => Ok(NodeRpcClientEndpoint::GetAccountDetails),
+            2 => Ok(NodeRpcClientEndpoint::GetAccountProofs),
+            3 => Ok(NodeRpcClientEndpoint::GetBlockHeaderByNumber),
+            4 => Ok(NodeRpcClientEndpoint::GetNotesById),
+            5 => Ok(NodeRpcClientEndpoint::SyncState),
+            6 => Ok(NodeRpcClientEndpoint::SubmitProvenTx),
+            7 => Ok(NodeRpcClientEndpoint::SyncNotes),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid RPC endpoint tag: {tag}")))
+            },
+        }
+    }
+}
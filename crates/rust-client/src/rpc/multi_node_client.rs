@@ -0,0 +1,294 @@
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use miden_objects::{
+    accounts::AccountId,
+    crypto::merkle::MmrProof,
+    notes::{NoteId, NoteTag, Nullifier},
+    transaction::ProvenTransaction,
+    BlockHeader, Digest,
+};
+use tracing::{info, warn};
+
+use super::{
+    AccountDetails, AccountProofs, NodeRpcClient, NodeRpcClientEndpoint, NoteDetails, NoteSyncInfo,
+    RpcError, RpcMethodStats, RpcTraffic, StateSyncInfo, SubmissionInfo,
+};
+
+// MULTI NODE RPC CLIENT
+// ================================================================================================
+
+/// RPC client that communicates with several Miden nodes at once.
+///
+/// Proven transactions are broadcast to every node and the submission succeeds if at least one of
+/// them accepts it. The rest of the requests are sent to the first healthy node: if a node can't be
+/// reached or fails to process a request, the next one is tried and, on success, it becomes the
+/// node used for subsequent requests.
+pub struct MultiNodeRpcClient {
+    /// The wrapped clients, each identified by a name used for reporting.
+    nodes: Vec<(String, Box<dyn NodeRpcClient + Send>)>,
+    /// Index of the node that last answered a request successfully.
+    healthy_node: usize,
+}
+
+/// Outcome of submitting a proven transaction to a single node.
+#[derive(Debug)]
+pub struct NodeSubmissionResult {
+    /// Name of the node the transaction was submitted to.
+    pub node: String,
+    /// Result returned by the node.
+    pub result: Result<SubmissionInfo, RpcError>,
+}
+
+impl MultiNodeRpcClient {
+    /// Returns a new [MultiNodeRpcClient] wrapping the provided named clients. The order of the
+    /// nodes determines the order in which they are tried for requests other than transaction
+    /// submissions.
+    pub fn new(nodes: impl IntoIterator<Item = (String, Box<dyn NodeRpcClient + Send>)>) -> Self {
+        Self {
+            nodes: nodes.into_iter().collect(),
+            healthy_node: 0,
+        }
+    }
+
+    /// Returns a new [MultiNodeRpcClient] with a [TonicRpcClient](super::TonicRpcClient) for each
+    /// of the provided endpoints.
+    #[cfg(feature = "tonic")]
+    pub fn from_endpoints(
+        endpoints: impl IntoIterator<Item = crate::config::Endpoint>,
+        timeout_ms: u64,
+    ) -> Self {
+        Self::new(endpoints.into_iter().map(|endpoint| {
+            let name = endpoint.to_string();
+            let config = crate::config::RpcConfig {
+                endpoint,
+                timeout_ms,
+                ..Default::default()
+            };
+            let client: Box<dyn NodeRpcClient + Send> =
+                Box::new(super::TonicRpcClient::new(&config));
+
+            (name, client)
+        }))
+    }
+
+    /// Returns the names of the wrapped nodes.
+    pub fn node_names(&self) -> impl Iterator<Item = &str> {
+        self.nodes.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Submits the proven transaction to every node concurrently and returns the result reported
+    /// by each of them, in the same order in which the nodes were provided.
+    pub async fn broadcast_proven_transaction(
+        &mut self,
+        proven_transaction: ProvenTransaction,
+    ) -> Vec<NodeSubmissionResult> {
+        let transaction_id = proven_transaction.id();
+        let submissions = self.nodes.iter_mut().map(|(name, node)| {
+            let proven_transaction = proven_transaction.clone();
+            async move {
+                let result = node.submit_proven_transaction(proven_transaction).await;
+                match &result {
+                    Ok(_) => info!("Transaction {} accepted by node {}.", transaction_id, name),
+                    Err(err) => {
+                        warn!("Transaction {} rejected by node {}: {}", transaction_id, name, err)
+                    },
+                }
+
+                NodeSubmissionResult { node: name.clone(), result }
+            }
+        });
+
+        join_all(submissions).await
+    }
+}
+
+/// Calls the given method on the first node that responds successfully, starting from the last
+/// known healthy node. Connection and request errors make the client move on to the next node,
+/// while any other error is returned right away.
+macro_rules! call_healthy_node {
+    ($self:ident, $node:ident => $call:expr) => {{
+        let mut last_error = None;
+        for offset in 0..$self.nodes.len() {
+            let index = ($self.healthy_node + offset) % $self.nodes.len();
+            let (name, $node) = &mut $self.nodes[index];
+            match $call.await {
+                Ok(response) => {
+                    $self.healthy_node = index;
+                    return Ok(response);
+                },
+                Err(
+                    err @ (RpcError::ConnectionError(_)
+                    | RpcError::NotFound(..)
+                    | RpcError::RequestError(..)),
+                ) => {
+                    warn!("Node {} failed to process the request: {}", name, err);
+                    last_error = Some(err);
+                },
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| RpcError::ConnectionError("no nodes were configured".to_string())))
+    }};
+}
+
+#[async_trait(?Send)]
+impl NodeRpcClient for MultiNodeRpcClient {
+    async fn submit_proven_transaction(
+        &mut self,
+        proven_transaction: ProvenTransaction,
+    ) -> Result<SubmissionInfo, RpcError> {
+        let results = self.broadcast_proven_transaction(proven_transaction).await;
+
+        // The first node that accepted the transaction, in the order the nodes were provided, is
+        // reported, and the nodes before it that rejected the transaction count as failed attempts
+        if let Some((index, info)) = results
+            .iter()
+            .enumerate()
+            .find_map(|(index, node_result)| Some((index, node_result.result.as_ref().ok()?)))
+        {
+            return Ok(SubmissionInfo {
+                endpoint: info.endpoint.clone(),
+                attempts: index as u32 + info.attempts,
+            });
+        }
+
+        let errors: Vec<String> = results
+            .into_iter()
+            .filter_map(|node_result| {
+                node_result.result.err().map(|err| format!("{}: {}", node_result.node, err))
+            })
+            .collect();
+
+        Err(RpcError::RequestError(
+            NodeRpcClientEndpoint::SubmitProvenTx.to_string(),
+            format!("no node accepted the transaction ({})", errors.join("; ")),
+        ))
+    }
+
+    async fn get_block_header_by_number(
+        &mut self,
+        block_num: Option<u32>,
+        include_mmr_proof: bool,
+    ) -> Result<(BlockHeader, Option<MmrProof>), RpcError> {
+        call_healthy_node!(self, node => node.get_block_header_by_number(block_num, include_mmr_proof))
+    }
+
+    async fn get_notes_by_id(&mut self, note_ids: &[NoteId]) -> Result<Vec<NoteDetails>, RpcError> {
+        call_healthy_node!(self, node => node.get_notes_by_id(note_ids))
+    }
+
+    async fn sync_state(
+        &mut self,
+        block_num: u32,
+        account_ids: &[AccountId],
+        note_tags: &[NoteTag],
+        nullifiers_tags: &[u16],
+    ) -> Result<StateSyncInfo, RpcError> {
+        call_healthy_node!(self, node => node.sync_state(block_num, account_ids, note_tags, nullifiers_tags))
+    }
+
+    async fn get_account_update(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<AccountDetails, RpcError> {
+        call_healthy_node!(self, node => node.get_account_update(account_id))
+    }
+
+    async fn sync_notes(
+        &mut self,
+        block_num: u32,
+        note_tags: &[NoteTag],
+    ) -> Result<NoteSyncInfo, RpcError> {
+        call_healthy_node!(self, node => node.sync_notes(block_num, note_tags))
+    }
+
+    async fn check_nullifiers_by_prefix(
+        &mut self,
+        prefix: &[u16],
+    ) -> Result<Vec<(Nullifier, u32)>, RpcError> {
+        call_healthy_node!(self, node => node.check_nullifiers_by_prefix(prefix))
+    }
+
+    async fn get_account_proofs(
+        &mut self,
+        account_ids: &BTreeSet<AccountId>,
+        code_commitments: &[Digest],
+        include_headers: bool,
+    ) -> Result<AccountProofs, RpcError> {
+        call_healthy_node!(self, node => node.get_account_proofs(account_ids, code_commitments, include_headers))
+    }
+
+    /// Returns the endpoint of the node currently used for requests, or its name if the node
+    /// doesn't report one.
+    fn endpoint(&self) -> Option<String> {
+        let (name, node) = self.nodes.get(self.healthy_node)?;
+        node.endpoint().or_else(|| Some(name.clone()))
+    }
+
+    /// Returns the latency stats of the node currently used for requests.
+    fn latency_stats(&self) -> Vec<RpcMethodStats> {
+        self.nodes
+            .get(self.healthy_node)
+            .map(|(_, node)| node.latency_stats())
+            .unwrap_or_default()
+    }
+
+    /// Returns the traffic of every node, as requests may have been sent to several of them.
+    fn traffic(&self) -> BTreeMap<NodeRpcClientEndpoint, RpcTraffic> {
+        let mut traffic = BTreeMap::<NodeRpcClientEndpoint, RpcTraffic>::new();
+        for (_, node) in &self.nodes {
+            for (method, node_traffic) in node.traffic() {
+                traffic.entry(method).or_default().add(node_traffic);
+            }
+        }
+
+        traffic
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "tonic"))]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::MultiNodeRpcClient;
+    use crate::{
+        config::{Endpoint, RetryPolicy, RpcConfig},
+        mock::MockRpcApi,
+        rpc::{NodeRpcClient, TonicRpcClient},
+    };
+
+    #[tokio::test]
+    async fn test_requests_fall_back_to_healthy_node() {
+        let unreachable_node = TonicRpcClient::new(&RpcConfig {
+            endpoint: Endpoint::new("http".to_string(), "127.0.0.1".to_string(), 1),
+            timeout_ms: 1000,
+            retry: RetryPolicy::disabled(),
+        });
+        let mock_node = MockRpcApi::new();
+        let genesis = mock_node.blocks[0].header();
+
+        let mut client = MultiNodeRpcClient::new([
+            (
+                "unreachable".to_string(),
+                Box::new(unreachable_node) as Box<dyn NodeRpcClient + Send>,
+            ),
+            ("mock".to_string(), Box::new(mock_node)),
+        ]);
+
+        let (block_header, _) = client.get_block_header_by_number(Some(0), false).await.unwrap();
+        assert_eq!(block_header, genesis);
+        assert_eq!(client.healthy_node, 1);
+    }
+}
@@ -1,9 +1,10 @@
 use alloc::{
     boxed::Box,
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
+use core::future::Future;
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -25,54 +26,113 @@ use miden_objects::{
     BlockHeader, Digest,
 };
 use miden_tx::utils::Serializable;
+use prost::Message;
+use retry::{with_retries, FailedAttempt};
+use tokio::sync::OnceCell;
 use tonic::transport::Channel;
 use tracing::info;
 
 use super::{
     AccountDetails, AccountProof, AccountProofs, AccountUpdateSummary, CommittedNote,
     NodeRpcClient, NodeRpcClientEndpoint, NoteDetails, NoteInclusionDetails, NoteSyncInfo,
-    NullifierUpdate, StateSyncInfo, TransactionUpdate,
+    NullifierUpdate, RpcLatencyTracker, RpcMethodStats, RpcTraffic, RpcTrafficCounter,
+    StateSyncInfo, SubmissionInfo, TransactionUpdate,
+};
+use crate::{
+    config::{RetryPolicy, RpcConfig},
+    rpc::RpcError,
 };
-use crate::{config::RpcConfig, rpc::RpcError};
 #[rustfmt::skip]
 pub mod generated;
+mod retry;
 
 // TONIC RPC CLIENT
 // ================================================================================================
 
 /// Client for the Node RPC API using tonic
 ///
-/// Wraps the ApiClient which defers establishing a connection with a node until necessary
+/// Wraps the ApiClient which defers establishing a connection with a node until necessary. The
+/// duration of every successful request is recorded to report [NodeRpcClient::latency_stats],
+/// and the encoded sizes of its request and response to report [NodeRpcClient::traffic].
+///
+/// Requests that fail because the node can't be reached are retried according to the
+/// [RetryPolicy] of the [RpcConfig].
 pub struct TonicRpcClient {
-    rpc_api: Option<ApiClient<Channel>>,
+    rpc_api: OnceCell<ApiClient<Channel>>,
     endpoint: String,
     timeout_ms: u64,
+    retry_policy: RetryPolicy,
+    latencies: RpcLatencyTracker,
+    traffic: RpcTrafficCounter,
 }
 
 impl TonicRpcClient {
     /// Returns a new instance of [TonicRpcClient] that'll do calls the `config_endpoint` provided
     pub fn new(config: &RpcConfig) -> TonicRpcClient {
         TonicRpcClient {
-            rpc_api: None,
+            rpc_api: OnceCell::new(),
             endpoint: config.endpoint.to_string(),
             timeout_ms: config.timeout_ms,
+            retry_policy: config.retry,
+            latencies: RpcLatencyTracker::default(),
+            traffic: RpcTrafficCounter::default(),
         }
     }
 
-    /// Takes care of establishing the RPC connection if not connected yet and returns a reference
-    /// to the inner ApiClient
-    async fn rpc_api(&mut self) -> Result<&mut ApiClient<Channel>, RpcError> {
-        if self.rpc_api.is_some() {
-            Ok(self.rpc_api.as_mut().unwrap())
-        } else {
-            let endpoint = tonic::transport::Endpoint::try_from(self.endpoint.clone())
-                .map_err(|err| RpcError::ConnectionError(err.to_string()))?
-                .timeout(Duration::from_millis(self.timeout_ms));
-            let rpc_api = ApiClient::connect(endpoint)
+    /// Replaces the retry policy taken from the [RpcConfig].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Takes care of establishing the RPC connection if not connected yet and returns a handle to
+    /// the inner ApiClient, which shares its connection.
+    async fn rpc_api(&self) -> Result<ApiClient<Channel>, RpcError> {
+        let rpc_api = self
+            .rpc_api
+            .get_or_try_init(|| async {
+                let endpoint = tonic::transport::Endpoint::try_from(self.endpoint.clone())
+                    .map_err(|err| RpcError::ConnectionError(err.to_string()))?
+                    .timeout(Duration::from_millis(self.timeout_ms));
+                ApiClient::connect(endpoint)
+                    .await
+                    .map_err(|err| RpcError::ConnectionError(err.to_string()))
+            })
+            .await?;
+
+        Ok(rpc_api.clone())
+    }
+
+    /// Sends `request` to the endpoint through `send`, retrying it as the retry policy allows if
+    /// the node can't be reached. Returns the response along with the number of attempts made,
+    /// and records the latency and the request and response sizes of the successful attempt.
+    async fn call<Req, T, F, Fut>(
+        &mut self,
+        endpoint: NodeRpcClientEndpoint,
+        request: Req,
+        send: F,
+    ) -> Result<(T, u32), RpcError>
+    where
+        Req: Message + Clone,
+        T: Message,
+        F: Fn(ApiClient<Channel>, Req) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<T>, tonic::Status>>,
+    {
+        let client = &*self;
+        let (request, send) = (&request, &send);
+        let retried = with_retries(&self.retry_policy, endpoint, move || async move {
+            let rpc_api = client.rpc_api().await.map_err(FailedAttempt::Transient)?;
+            send(rpc_api, request.clone())
                 .await
-                .map_err(|err| RpcError::ConnectionError(err.to_string()))?;
-            Ok(self.rpc_api.insert(rpc_api))
-        }
+                .map(tonic::Response::into_inner)
+                .map_err(|status| FailedAttempt::from_status(endpoint, status))
+        })
+        .await?;
+        self.latencies.record(endpoint, retried.latency);
+        self.traffic
+            .record(endpoint, request.encoded_len(), retried.response.encoded_len());
+
+        Ok((retried.response, retried.attempts))
     }
 }
 
@@ -81,19 +141,18 @@ impl NodeRpcClient for TonicRpcClient {
     async fn submit_proven_transaction(
         &mut self,
         proven_transaction: ProvenTransaction,
-    ) -> Result<(), RpcError> {
+    ) -> Result<SubmissionInfo, RpcError> {
         let request = SubmitProvenTransactionRequest {
             transaction: proven_transaction.to_bytes(),
         };
-        let rpc_api = self.rpc_api().await?;
-        rpc_api.submit_proven_transaction(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::SubmitProvenTx.to_string(),
-                err.to_string(),
-            )
-        })?;
+        let (_, attempts) = self
+            .call(NodeRpcClientEndpoint::SubmitProvenTx, request, |mut rpc_api, request| async move { rpc_api.submit_proven_transaction(request).await })
+            .await?;
 
-        Ok(())
+        Ok(SubmissionInfo {
+            endpoint: self.endpoint.clone(),
+            attempts,
+        })
     }
 
     async fn get_block_header_by_number(
@@ -108,15 +167,9 @@ impl NodeRpcClient for TonicRpcClient {
 
         info!("Calling GetBlockHeaderByNumber: {:?}", request);
 
-        let rpc_api = self.rpc_api().await?;
-        let api_response = rpc_api.get_block_header_by_number(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::GetBlockHeaderByNumber.to_string(),
-                err.to_string(),
-            )
-        })?;
-
-        let response = api_response.into_inner();
+        let (response, _) = self
+            .call(NodeRpcClientEndpoint::GetBlockHeaderByNumber, request, |mut rpc_api, request| async move { rpc_api.get_block_header_by_number(request).await })
+            .await?;
 
         let block_header: BlockHeader = response
             .block_header
@@ -148,15 +201,15 @@ impl NodeRpcClient for TonicRpcClient {
         let request = GetNotesByIdRequest {
             note_ids: note_ids.iter().map(|id| id.inner().into()).collect(),
         };
-        let rpc_api = self.rpc_api().await?;
-        let api_response = rpc_api.get_notes_by_id(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::GetBlockHeaderByNumber.to_string(),
-                err.to_string(),
+        let (response, _) = self
+            .call(
+                NodeRpcClientEndpoint::GetNotesById,
+                request,
+                |mut rpc_api, request| async move { rpc_api.get_notes_by_id(request).await },
             )
-        })?;
+            .await?;
 
-        let rpc_notes = api_response.into_inner().notes;
+        let rpc_notes = response.notes;
         let mut response_notes = Vec::with_capacity(rpc_notes.len());
         for note in rpc_notes {
             let inclusion_details = {
@@ -217,11 +270,12 @@ impl NodeRpcClient for TonicRpcClient {
             nullifiers,
         };
 
-        let rpc_api = self.rpc_api().await?;
-        let response = rpc_api.sync_state(request).await.map_err(|err| {
-            RpcError::RequestError(NodeRpcClientEndpoint::SyncState.to_string(), err.to_string())
-        })?;
-        response.into_inner().try_into()
+        let (response, _) = self
+            .call(NodeRpcClientEndpoint::SyncState, request, |mut rpc_api, request| async move {
+                rpc_api.sync_state(request).await
+            })
+            .await?;
+        response.try_into()
     }
 
     /// Sends a `GetAccountDetailsRequest` to the Miden node, and extracts an [AccountDetails] from
@@ -241,15 +295,13 @@ impl NodeRpcClient for TonicRpcClient {
     ) -> Result<AccountDetails, RpcError> {
         let request = GetAccountDetailsRequest { account_id: Some(account_id.into()) };
 
-        let rpc_api = self.rpc_api().await?;
-
-        let response = rpc_api.get_account_details(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::GetAccountDetails.to_string(),
-                err.to_string(),
+        let (response, _) = self
+            .call(
+                NodeRpcClientEndpoint::GetAccountDetails,
+                request,
+                |mut rpc_api, request| async move { rpc_api.get_account_details(request).await },
             )
-        })?;
-        let response = response.into_inner();
+            .await?;
         let account_info = response.details.ok_or(RpcError::ExpectedDataMissing(
             "GetAccountDetails response should have an `account`".to_string(),
         ))?;
@@ -313,17 +365,13 @@ impl NodeRpcClient for TonicRpcClient {
             code_commitments: code_commitments.iter().map(|c| c.into()).collect(),
         };
 
-        let rpc_api = self.rpc_api().await?;
-        let response = rpc_api
-            .get_account_proofs(request)
-            .await
-            .map_err(|err| {
-                RpcError::RequestError(
-                    NodeRpcClientEndpoint::GetAccountProofs.to_string(),
-                    err.to_string(),
-                )
-            })?
-            .into_inner();
+        let (response, _) = self
+            .call(
+                NodeRpcClientEndpoint::GetAccountProofs,
+                request,
+                |mut rpc_api, request| async move { rpc_api.get_account_proofs(request).await },
+            )
+            .await?;
 
         let mut account_proofs = Vec::with_capacity(response.account_proofs.len());
         let block_num = response.block_num;
@@ -377,13 +425,13 @@ impl NodeRpcClient for TonicRpcClient {
 
         let request = SyncNoteRequest { block_num, note_tags };
 
-        let rpc_api = self.rpc_api().await?;
-
-        let response = rpc_api.sync_notes(request).await.map_err(|err| {
-            RpcError::RequestError(NodeRpcClientEndpoint::SyncNotes.to_string(), err.to_string())
-        })?;
+        let (response, _) = self
+            .call(NodeRpcClientEndpoint::SyncNotes, request, |mut rpc_api, request| async move {
+                rpc_api.sync_notes(request).await
+            })
+            .await?;
 
-        response.into_inner().try_into()
+        response.try_into()
     }
 
     async fn check_nullifiers_by_prefix(
@@ -394,14 +442,9 @@ impl NodeRpcClient for TonicRpcClient {
             nullifiers: prefixes.iter().map(|&x| x as u32).collect(),
             prefix_len: 16,
         };
-        let rpc_api = self.rpc_api().await?;
-        let response = rpc_api.check_nullifiers_by_prefix(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::CheckNullifiersByPrefix.to_string(),
-                err.to_string(),
-            )
-        })?;
-        let response = response.into_inner();
+        let (response, _) = self
+            .call(NodeRpcClientEndpoint::CheckNullifiersByPrefix, request, |mut rpc_api, request| async move { rpc_api.check_nullifiers_by_prefix(request).await })
+            .await?;
         let nullifiers = response
             .nullifiers
             .iter()
@@ -414,6 +457,18 @@ impl NodeRpcClient for TonicRpcClient {
             .collect::<Result<Vec<(Nullifier, u32)>, RpcError>>()?;
         Ok(nullifiers)
     }
+
+    fn endpoint(&self) -> Option<String> {
+        Some(self.endpoint.clone())
+    }
+
+    fn latency_stats(&self) -> Vec<RpcMethodStats> {
+        self.latencies.stats()
+    }
+
+    fn traffic(&self) -> BTreeMap<NodeRpcClientEndpoint, RpcTraffic> {
+        self.traffic.traffic()
+    }
 }
 
 // NOTE SYNC INFO CONVERSION
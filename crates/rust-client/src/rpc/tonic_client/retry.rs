@@ -0,0 +1,199 @@
+use alloc::string::ToString;
+use core::future::Future;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use tracing::warn;
+
+use crate::{
+    config::RetryPolicy,
+    rpc::{NodeRpcClientEndpoint, RpcError},
+};
+
+// FAILED ATTEMPT
+// ================================================================================================
+
+/// Error of a single attempt of a request, classified by whether the request can be retried.
+pub(super) enum FailedAttempt {
+    /// The node couldn't be reached or was unavailable, so the request may succeed if retried.
+    Transient(RpcError),
+    /// The node processed the request and rejected it, so retrying it would fail the same way.
+    Permanent(RpcError),
+}
+
+impl FailedAttempt {
+    /// Classifies the status returned by the node for a request to the specified endpoint. Only
+    /// `Unavailable` statuses, which tonic also returns for transport failures, are transient.
+    /// `NotFound` statuses are reported as [RpcError::NotFound].
+    pub fn from_status(endpoint: NodeRpcClientEndpoint, status: tonic::Status) -> Self {
+        match status.code() {
+            tonic::Code::Unavailable => {
+                Self::Transient(RpcError::RequestError(endpoint.to_string(), status.to_string()))
+            },
+            tonic::Code::NotFound => {
+                Self::Permanent(RpcError::NotFound(endpoint.to_string(), status.to_string()))
+            },
+            _ => Self::Permanent(RpcError::RequestError(endpoint.to_string(), status.to_string())),
+        }
+    }
+}
+
+// RETRIES
+// ================================================================================================
+
+/// Response of a request that succeeded after one or more attempts.
+pub(super) struct RetriedResponse<T> {
+    pub response: T,
+    /// Number of attempts made, including the successful one.
+    pub attempts: u32,
+    /// Duration of the successful attempt.
+    pub latency: Duration,
+}
+
+/// Runs `attempt` until it succeeds, it fails with a permanent error or the maximum number of
+/// attempts the policy allows for the endpoint is reached, waiting with exponential backoff
+/// between attempts.
+pub(super) async fn with_retries<T, F, Fut>(
+    policy: &RetryPolicy,
+    endpoint: NodeRpcClientEndpoint,
+    mut attempt: F,
+) -> Result<RetriedResponse<T>, RpcError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, FailedAttempt>>,
+{
+    let max_attempts = policy.max_attempts_for(endpoint);
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let started = Instant::now();
+        match attempt().await {
+            Ok(response) => {
+                return Ok(RetriedResponse {
+                    response,
+                    attempts,
+                    latency: started.elapsed(),
+                })
+            },
+            Err(FailedAttempt::Transient(err)) if attempts < max_attempts => {
+                let delay = retry_delay(policy, attempts);
+                warn!(
+                    "Attempt {attempts} of {max_attempts} of {endpoint} request failed, retrying in \
+                     {}ms: {err}",
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+            },
+            Err(FailedAttempt::Transient(err) | FailedAttempt::Permanent(err)) => return Err(err),
+        }
+    }
+}
+
+/// Returns the delay before the specified retry, randomized if the policy has jitter enabled.
+fn retry_delay(policy: &RetryPolicy, retry: u32) -> Duration {
+    let delay_ms = policy.backoff_delay_ms(retry);
+    let delay_ms = if policy.jitter && delay_ms > 1 {
+        rand::thread_rng().gen_range(delay_ms / 2..=delay_ms)
+    } else {
+        delay_ms
+    };
+
+    Duration::from_millis(delay_ms)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::{with_retries, FailedAttempt};
+    use crate::{
+        config::RetryPolicy,
+        rpc::{NodeRpcClientEndpoint, RpcError},
+    };
+
+    /// Node that fails the first requests it receives with the provided status and answers the
+    /// rest.
+    struct FlakyNode {
+        failures_left: Cell<u32>,
+        failure: tonic::Code,
+        requests: Cell<u32>,
+    }
+
+    impl FlakyNode {
+        fn new(failures: u32, failure: tonic::Code) -> Self {
+            Self {
+                failures_left: Cell::new(failures),
+                failure,
+                requests: Cell::new(0),
+            }
+        }
+
+        async fn request(&self, endpoint: NodeRpcClientEndpoint) -> Result<u32, FailedAttempt> {
+            self.requests.set(self.requests.get() + 1);
+            if self.failures_left.get() > 0 {
+                self.failures_left.set(self.failures_left.get() - 1);
+                let status = tonic::Status::new(self.failure, "node is restarting");
+                return Err(FailedAttempt::from_status(endpoint, status));
+            }
+
+            Ok(self.requests.get())
+        }
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy::default().with_delays(1, 5).with_jitter(false)
+    }
+
+    #[tokio::test]
+    async fn test_read_is_retried_until_node_answers() {
+        let node = FlakyNode::new(2, tonic::Code::Unavailable);
+        let endpoint = NodeRpcClientEndpoint::GetBlockHeaderByNumber;
+
+        let retried =
+            with_retries(&fast_policy(), endpoint, || node.request(endpoint)).await.unwrap();
+
+        assert_eq!(retried.response, 3);
+        assert_eq!(retried.attempts, 3);
+        assert_eq!(node.requests.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retries_stop_after_max_attempts() {
+        let node = FlakyNode::new(3, tonic::Code::Unavailable);
+        let endpoint = NodeRpcClientEndpoint::SyncState;
+
+        let result = with_retries(&fast_policy(), endpoint, || node.request(endpoint)).await;
+
+        assert!(matches!(result, Err(RpcError::RequestError(..))));
+        assert_eq!(node.requests.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_are_not_retried() {
+        let node = FlakyNode::new(1, tonic::Code::InvalidArgument);
+        let endpoint = NodeRpcClientEndpoint::GetNotesById;
+
+        let result = with_retries(&fast_policy(), endpoint, || node.request(endpoint)).await;
+
+        assert!(result.is_err());
+        assert_eq!(node.requests.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_submissions_are_only_retried_when_enabled() {
+        let endpoint = NodeRpcClientEndpoint::SubmitProvenTx;
+
+        let node = FlakyNode::new(2, tonic::Code::Unavailable);
+        let result = with_retries(&fast_policy(), endpoint, || node.request(endpoint)).await;
+        assert!(result.is_err());
+        assert_eq!(node.requests.get(), 1);
+
+        let node = FlakyNode::new(2, tonic::Code::Unavailable);
+        let policy = fast_policy().with_retry_submissions(true);
+        let retried = with_retries(&policy, endpoint, || node.request(endpoint)).await.unwrap();
+        assert_eq!(retried.attempts, 3);
+    }
+}
@@ -29,7 +29,8 @@ use tonic_web_wasm_client::Client;
 use super::{AccountProof, AccountProofs, NoteSyncInfo};
 use crate::rpc::{
     AccountDetails, AccountUpdateSummary, CommittedNote, NodeRpcClient, NodeRpcClientEndpoint,
-    NoteDetails, NoteInclusionDetails, NullifierUpdate, RpcError, StateSyncInfo, TransactionUpdate,
+    NoteDetails, NoteInclusionDetails, NullifierUpdate, RpcError, StateSyncInfo, SubmissionInfo,
+    TransactionUpdate,
 };
 
 #[rustfmt::skip]
@@ -55,7 +56,7 @@ impl NodeRpcClient for WebTonicRpcClient {
     async fn submit_proven_transaction(
         &mut self,
         proven_transaction: ProvenTransaction,
-    ) -> Result<(), RpcError> {
+    ) -> Result<SubmissionInfo, RpcError> {
         let mut query_client = self.build_api_client();
 
         let request = SubmitProvenTransactionRequest {
@@ -69,7 +70,7 @@ impl NodeRpcClient for WebTonicRpcClient {
             )
         })?;
 
-        Ok(())
+        Ok(SubmissionInfo::first_attempt(self.endpoint.clone()))
     }
 
     async fn get_block_header_by_number(
@@ -398,6 +399,10 @@ impl NodeRpcClient for WebTonicRpcClient {
             .collect::<Result<Vec<(Nullifier, u32)>, RpcError>>()?;
         Ok(nullifiers)
     }
+
+    fn endpoint(&self) -> Option<String> {
+        Some(self.endpoint.clone())
+    }
 }
 
 // NOTE SYNC INFO CONVERSION
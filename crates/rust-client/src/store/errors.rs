@@ -1,5 +1,5 @@
 use alloc::string::{String, ToString};
-use core::fmt;
+use core::{fmt, time::Duration};
 
 use miden_objects::{
     accounts::AccountId,
@@ -24,6 +24,7 @@ pub enum StoreError {
     AccountDataNotFound(AccountId),
     AccountError(AccountError),
     AccountHashMismatch(AccountId),
+    AccountIdUnavailable(AccountId),
     AccountKeyNotFound(Word),
     AccountStorageNotFound(Digest),
     BlockHeaderNotFound(u32),
@@ -38,6 +39,8 @@ pub enum StoreError {
     NoteTagAlreadyTracked(u64),
     ParsingError(String),
     QueryError(String),
+    /// A [StoreSnapshot](super::StoreSnapshot) was used after the contained timeout elapsed.
+    SnapshotExpired(Duration),
     TransactionScriptError(TransactionScriptError),
     VaultDataNotFound(Digest),
 }
@@ -107,6 +110,9 @@ impl fmt::Display for StoreError {
             AccountHashMismatch(account_id) => {
                 write!(f, "account hash mismatch for account {account_id}")
             },
+            AccountIdUnavailable(account_id) => {
+                write!(f, "account ID {account_id} is already reserved or in use")
+            },
             AccountKeyNotFound(pub_key) => {
                 write!(f, "error: Public Key {} not found", Digest::from(pub_key))
             },
@@ -139,6 +145,9 @@ impl fmt::Display for StoreError {
                 write!(f, "failed to parse data retrieved from the database: {err}")
             },
             QueryError(err) => write!(f, "failed to retrieve data from the database: {err}"),
+            SnapshotExpired(timeout) => {
+                write!(f, "store snapshot expired after {timeout:?}, a new one must be opened")
+            },
             TransactionScriptError(err) => {
                 write!(f, "error instantiating transaction script: {err}")
             },
@@ -0,0 +1,300 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_objects::{
+    accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
+    transaction::TransactionId,
+    Digest, Word,
+};
+
+use super::MemoryStoreState;
+use crate::{
+    accounts::{AccountLockStatus, AccountRemoval, AccountUnlockRecord},
+    store::StoreError,
+    transactions::DiscardCause,
+};
+
+// ACCOUNTS
+// ================================================================================================
+
+impl MemoryStoreState {
+    /// Retrieves the IDs of the accounts starting with the provided prefix. Like in the SQLite
+    /// store, the prefix is matched against the hex digits of the IDs, case insensitively.
+    pub(super) fn get_account_ids_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<AccountId>, StoreError> {
+        let digits = prefix.trim_start_matches("0x");
+        if digits.len() > 16 {
+            return Ok(Vec::new());
+        }
+        if !digits.is_empty() {
+            u64::from_str_radix(digits, 16).map_err(|err| {
+                StoreError::QueryError(format!("invalid account ID prefix {prefix}: {err}"))
+            })?;
+        }
+
+        let digits = digits.to_ascii_lowercase();
+        Ok(self
+            .accounts
+            .keys()
+            .filter(|account_id| format!("{:016x}", u64::from(**account_id)).starts_with(&digits))
+            .copied()
+            .collect())
+    }
+
+    /// Returns the latest state of the account, along with the seed it was stored with.
+    pub(super) fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<&(Account, Option<Word>), StoreError> {
+        self.accounts
+            .get(&account_id)
+            .and_then(|states| states.values().next_back())
+            .ok_or(StoreError::AccountDataNotFound(account_id))
+    }
+
+    pub(super) fn get_account_headers(&self) -> Vec<(AccountHeader, Option<Word>)> {
+        self.accounts
+            .values()
+            .filter_map(|states| states.values().next_back())
+            .map(|(account, seed)| (account.into(), *seed))
+            .collect()
+    }
+
+    pub(super) fn get_account_header_by_hash(&self, account_hash: Digest) -> Option<AccountHeader> {
+        self.accounts
+            .values()
+            .flat_map(|states| states.values())
+            .find(|(account, _)| account.hash() == account_hash)
+            .map(|(account, _)| account.into())
+    }
+
+    /// Returns the stored states of the account, from the oldest to the latest, along with their
+    /// commitments.
+    pub(super) fn get_account_history(&self, account_id: AccountId) -> Vec<(Account, Digest)> {
+        self.accounts
+            .get(&account_id)
+            .into_iter()
+            .flat_map(|states| states.values())
+            .map(|(account, _)| (account.clone(), account.hash()))
+            .collect()
+    }
+
+    /// Retrieves the IDs of the faucets that issued the assets held by any of the stored states
+    /// of the account.
+    pub(super) fn get_account_vault_faucets(
+        &self,
+        account_id: AccountId,
+    ) -> Result<BTreeSet<AccountId>, StoreError> {
+        let states = self
+            .accounts
+            .get(&account_id)
+            .ok_or(StoreError::AccountDataNotFound(account_id))?;
+
+        Ok(states
+            .values()
+            .flat_map(|(account, _)| account.vault().assets())
+            .map(|asset| asset.faucet_id())
+            .collect())
+    }
+
+    pub(super) fn get_account_auth_by_pub_key(
+        &self,
+        pub_key: Word,
+    ) -> Result<AuthSecretKey, StoreError> {
+        // Keys of pending rotations are also returned, as the local state of the account already
+        // uses them
+        self.account_auths
+            .values()
+            .chain(self.pending_account_auths.values().map(|(_, auth_info)| auth_info))
+            .find(|auth_info| auth_pub_key(auth_info) == pub_key)
+            .cloned()
+            .ok_or(StoreError::AccountKeyNotFound(pub_key))
+    }
+
+    pub(super) fn resolve_pending_account_auth(
+        &mut self,
+        transaction_id: TransactionId,
+        committed: bool,
+    ) {
+        if let Some((account_id, auth_info)) = self.pending_account_auths.remove(&transaction_id) {
+            if committed {
+                self.account_auths.insert(account_id, auth_info);
+            }
+        }
+    }
+
+    pub(super) fn insert_account(
+        &mut self,
+        account: &Account,
+        account_seed: Option<Word>,
+        auth_info: Option<&AuthSecretKey>,
+    ) -> Result<(), StoreError> {
+        self.insert_account_state(account, account_seed)?;
+        if let Some(auth_info) = auth_info {
+            if self.account_auths.contains_key(&account.id()) {
+                return Err(StoreError::DatabaseError(format!(
+                    "the keys of account {} are already stored",
+                    account.id()
+                )));
+            }
+            self.account_auths.insert(account.id(), auth_info.clone());
+        }
+        self.account_reservations.remove(&account.id());
+
+        Ok(())
+    }
+
+    /// Stores a new state of the account, enforcing the same constraints as the accounts table of
+    /// the SQLite store: states are unique by nonce and by commitment, and the state with nonce
+    /// zero must have a seed.
+    pub(super) fn insert_account_state(
+        &mut self,
+        account: &Account,
+        account_seed: Option<Word>,
+    ) -> Result<(), StoreError> {
+        let nonce = account.nonce().as_int();
+        if nonce == 0 && account_seed.is_none() {
+            return Err(StoreError::DatabaseError(format!(
+                "the new state of account {} must have a seed",
+                account.id()
+            )));
+        }
+
+        let account_hash = account.hash();
+        let is_stored = self
+            .accounts
+            .values()
+            .flat_map(|states| states.values())
+            .any(|(stored_account, _)| stored_account.hash() == account_hash);
+        let states = self.accounts.entry(account.id()).or_default();
+        if is_stored || states.contains_key(&nonce) {
+            return Err(StoreError::DatabaseError(format!(
+                "the state of account {} with nonce {nonce} is already stored",
+                account.id()
+            )));
+        }
+        states.insert(nonce, (account.clone(), account_seed));
+
+        Ok(())
+    }
+
+    pub(super) fn reserve_account_id(&mut self, account_id: AccountId) -> Result<(), StoreError> {
+        if self.account_reservations.contains(&account_id)
+            || self.accounts.contains_key(&account_id)
+            || self.account_auths.contains_key(&account_id)
+        {
+            return Err(StoreError::AccountIdUnavailable(account_id));
+        }
+        self.account_reservations.insert(account_id);
+
+        Ok(())
+    }
+
+    pub(super) fn get_orphaned_account_auth_ids(&self) -> Vec<AccountId> {
+        self.account_auths
+            .keys()
+            .filter(|account_id| self.is_orphaned(**account_id))
+            .copied()
+            .collect()
+    }
+
+    pub(super) fn remove_orphaned_account_auth(&mut self, account_id: AccountId) -> bool {
+        self.is_orphaned(account_id) && self.account_auths.remove(&account_id).is_some()
+    }
+
+    pub(super) fn remove_account(&mut self, removal: AccountRemoval) {
+        let AccountRemoval {
+            account_id,
+            removed_transaction_ids,
+            discarded_transaction_ids,
+            removed_input_note_ids,
+            removed_output_note_ids,
+            tags_to_remove,
+        } = removal;
+
+        self.accounts.remove(&account_id);
+        self.account_auths.remove(&account_id);
+        self.pending_account_auths
+            .retain(|_, (pending_account_id, _)| *pending_account_id != account_id);
+        self.account_locks.remove(&account_id);
+        self.account_unlocks.retain(|record| record.account_id != account_id);
+        self.storage_slot_watches.retain(|watch| watch.account_id != account_id);
+        self.submission_attempts
+            .retain(|_, (attempt_account_id, _)| *attempt_account_id != account_id);
+
+        self.transactions
+            .retain(|transaction| !removed_transaction_ids.contains(&transaction.record.id));
+        for transaction_id in &removed_transaction_ids {
+            self.proven_transactions.remove(transaction_id);
+        }
+        self.mark_transactions_as_discarded(
+            &discarded_transaction_ids,
+            DiscardCause::AccountRemoved,
+        );
+
+        for note_id in &removed_input_note_ids {
+            self.input_notes.remove(note_id);
+        }
+        for note_id in &removed_output_note_ids {
+            self.output_notes.remove(note_id);
+        }
+
+        for tag in tags_to_remove {
+            self.remove_note_tag(tag);
+        }
+    }
+
+    // ACCOUNT LOCKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the lock status of an account, removing its lock entry if it's unlocked.
+    pub(super) fn set_account_lock_status(
+        &mut self,
+        account_id: AccountId,
+        status: AccountLockStatus,
+    ) {
+        match status {
+            AccountLockStatus::Unlocked => self.account_locks.remove(&account_id),
+            _ => self.account_locks.insert(account_id, status),
+        };
+    }
+
+    pub(super) fn apply_account_unlock(
+        &mut self,
+        record: AccountUnlockRecord,
+        new_state: Option<&Account>,
+    ) -> Result<(), StoreError> {
+        let status = match new_state {
+            Some(account) => {
+                // The local state is replaced, along with the states with a higher nonce
+                let nonce = account.nonce().as_int();
+                if let Some(states) = self.accounts.get_mut(&account.id()) {
+                    states.retain(|state_nonce, _| *state_nonce < nonce);
+                }
+                self.insert_account_state(account, None)?;
+                AccountLockStatus::Unlocked
+            },
+            None => AccountLockStatus::DivergenceAccepted { remote_hash: record.remote_hash },
+        };
+        self.set_account_lock_status(record.account_id, status);
+        self.account_unlocks.push(record);
+
+        Ok(())
+    }
+
+    /// Returns whether the account has stored keys but neither stored states nor a reservation.
+    fn is_orphaned(&self, account_id: AccountId) -> bool {
+        self.account_auths.contains_key(&account_id)
+            && !self.accounts.contains_key(&account_id)
+            && !self.account_reservations.contains(&account_id)
+    }
+}
+
+/// Returns the public key of the provided [AuthSecretKey], as stored by the SQLite store to look
+/// the key up.
+fn auth_pub_key(auth_info: &AuthSecretKey) -> Word {
+    match auth_info {
+        AuthSecretKey::RpoFalcon512(secret) => Word::from(secret.public_key()),
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_objects::{
+    crypto::merkle::{InOrderIndex, MmrPeaks},
+    BlockHeader, Digest,
+};
+
+use super::MemoryStoreState;
+use crate::store::{ChainMmrNodeFilter, StoreError};
+
+// CHAIN DATA
+// ================================================================================================
+
+impl MemoryStoreState {
+    /// Inserts the block header, if it isn't stored yet, and marks it as having notes relevant to
+    /// the client if `has_client_notes` is set.
+    pub(super) fn insert_block_header(
+        &mut self,
+        block_header: BlockHeader,
+        chain_mmr_peaks: MmrPeaks,
+        has_client_notes: bool,
+    ) {
+        let (_, _, stored_has_client_notes) = self
+            .block_headers
+            .entry(block_header.block_num())
+            .or_insert((block_header, chain_mmr_peaks, has_client_notes));
+        *stored_has_client_notes |= has_client_notes;
+    }
+
+    pub(super) fn get_block_headers(&self, block_numbers: &[u32]) -> Vec<(BlockHeader, bool)> {
+        self.block_headers
+            .iter()
+            .filter(|(block_num, _)| block_numbers.contains(block_num))
+            .map(|(_, (block_header, _, has_client_notes))| (*block_header, *has_client_notes))
+            .collect()
+    }
+
+    pub(super) fn get_tracked_block_headers(&self) -> Vec<BlockHeader> {
+        self.block_headers
+            .values()
+            .filter(|(_, _, has_client_notes)| *has_client_notes)
+            .map(|(block_header, ..)| *block_header)
+            .collect()
+    }
+
+    pub(super) fn get_chain_mmr_nodes(
+        &self,
+        filter: &ChainMmrNodeFilter,
+    ) -> BTreeMap<InOrderIndex, Digest> {
+        match filter {
+            ChainMmrNodeFilter::All => self.chain_mmr_nodes.clone(),
+            ChainMmrNodeFilter::List(ids) => ids
+                .iter()
+                .filter_map(|id| self.chain_mmr_nodes.get(id).map(|node| (*id, *node)))
+                .collect(),
+        }
+    }
+
+    /// Returns the chain MMR peaks stored with the block header, or empty peaks if the block
+    /// header isn't stored.
+    pub(super) fn get_chain_mmr_peaks_by_block_num(
+        &self,
+        block_num: u32,
+    ) -> Result<MmrPeaks, StoreError> {
+        match self.block_headers.get(&block_num) {
+            Some((_, chain_mmr_peaks, _)) => Ok(chain_mmr_peaks.clone()),
+            None => Ok(MmrPeaks::new(0, vec![])?),
+        }
+    }
+
+    /// Inserts the chain MMR nodes that aren't stored yet.
+    pub(super) fn insert_chain_mmr_nodes(&mut self, nodes: &[(InOrderIndex, Digest)]) {
+        for (index, node) in nodes {
+            self.chain_mmr_nodes.entry(*index).or_insert(*node);
+        }
+    }
+}
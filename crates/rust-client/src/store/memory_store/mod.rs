@@ -0,0 +1,823 @@
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
+use core::time::Duration;
+
+use async_trait::async_trait;
+use miden_objects::{
+    accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
+    crypto::merkle::{InOrderIndex, MmrPeaks, PartialMmr},
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
+    BlockHeader, Digest, Word,
+};
+use miden_tx::utils::sync::RwLock;
+
+use self::{snapshot::MemorySnapshot, transactions::StoredTransaction};
+use super::{
+    transaction_note_faucets, ChainMmrNodeFilter, InputNoteRecord, NoteFilter, OutputNoteRecord,
+    Store, StoreError, StoreSnapshot, TransactionFilter,
+};
+use crate::{
+    accounts::{AccountLockStatus, AccountRemoval, AccountUnlockRecord},
+    notes::{FutureNoteLink, NoteUpdates, PendingNoteRecord},
+    sync::{
+        ChainStateReset, NoteTagRecord, PartialSyncProgress, StateSyncUpdate, StorageSlotWatch,
+        SyncSummary,
+    },
+    time::{default_time_source, TimeSource},
+    transactions::{LocalTransactionDiscard, TransactionRecord, TransactionStoreUpdate},
+};
+
+mod accounts;
+mod chain_data;
+mod notes;
+mod snapshot;
+mod sync;
+mod transactions;
+
+// MEMORY STORE
+// ================================================================================================
+
+/// [Store] that keeps all of its data in memory, so it's lost once the store is dropped. Useful
+/// for tests and for ephemeral clients, like the ones that only build and submit a transaction,
+/// and available on every target, including `no_std` ones.
+///
+/// The data is held in ordered maps behind a single lock. Updates that involve several entities
+/// are applied to a copy of the data, which replaces the current one only if the whole update
+/// succeeds, so a failed update leaves the store untouched.
+///
+/// Like in the SQLite store, input notes are timestamped with the time they are stored at, as
+/// reported by the store's [TimeSource]. Without a time source, the timestamps of the records are
+/// kept.
+pub struct MemoryStore {
+    state: RwLock<MemoryStoreState>,
+    time_source: Option<Arc<dyn TimeSource>>,
+}
+
+impl MemoryStore {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty store, which timestamps input notes with the system clock under `std`.
+    pub fn new() -> Self {
+        Self {
+            state: RwLock::new(MemoryStoreState::default()),
+            time_source: default_time_source(),
+        }
+    }
+
+    /// Returns the store with input notes timestamped by the provided [TimeSource].
+    pub fn with_time_source(mut self, time_source: Arc<dyn TimeSource>) -> Self {
+        self.time_source = Some(time_source);
+        self
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Runs a query against the current data.
+    fn query<R>(
+        &self,
+        f: impl FnOnce(&MemoryStoreState) -> Result<R, StoreError>,
+    ) -> Result<R, StoreError> {
+        f(&self.state.read())
+    }
+
+    /// Applies an update to a copy of the data, which replaces the current data only if the
+    /// update succeeds.
+    fn update<R>(
+        &self,
+        f: impl FnOnce(&mut MemoryStoreState) -> Result<R, StoreError>,
+    ) -> Result<R, StoreError> {
+        let mut state = self.state.write();
+        let mut updated_state = state.clone();
+        let result = f(&mut updated_state)?;
+        *state = updated_state;
+
+        Ok(result)
+    }
+
+    /// Applies an update that can't fail halfway, like one that only touches a single entity,
+    /// directly to the data.
+    fn update_in_place<R>(
+        &self,
+        f: impl FnOnce(&mut MemoryStoreState) -> Result<R, StoreError>,
+    ) -> Result<R, StoreError> {
+        f(&mut self.state.write())
+    }
+
+    /// Returns the current time as reported by the store's time source, if it has one.
+    fn now(&self) -> Option<u64> {
+        self.time_source.as_ref().map(|time_source| time_source.now())
+    }
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Data held by a [MemoryStore]. Each group of fields mirrors the tables the SQLite store keeps
+/// for the same entities.
+#[derive(Clone, Default)]
+struct MemoryStoreState {
+    // ACCOUNTS
+    /// Stored states of every account, keyed by nonce, along with the seed they were stored
+    /// with.
+    accounts: BTreeMap<AccountId, BTreeMap<u64, (Account, Option<Word>)>>,
+    account_auths: BTreeMap<AccountId, AuthSecretKey>,
+    /// Keys that replace the ones in `account_auths` once the transactions setting them are
+    /// committed, along with the account they belong to.
+    pending_account_auths: BTreeMap<TransactionId, (AccountId, AuthSecretKey)>,
+    account_reservations: BTreeSet<AccountId>,
+    account_locks: BTreeMap<AccountId, AccountLockStatus>,
+    /// Unlock records of every account, from the oldest to the most recent.
+    account_unlocks: Vec<AccountUnlockRecord>,
+
+    // TRANSACTIONS
+    /// Stored transactions, in the order they were applied.
+    transactions: Vec<StoredTransaction>,
+    /// Retained proven transactions, along with the last time they were used.
+    proven_transactions: BTreeMap<TransactionId, (Vec<u8>, u64)>,
+    /// Attempts to submit each transaction that aren't confirmed yet, along with the account the
+    /// transaction was executed against.
+    submission_attempts: BTreeMap<TransactionId, (AccountId, u32)>,
+
+    // NOTES
+    input_notes: BTreeMap<NoteId, InputNoteRecord>,
+    output_notes: BTreeMap<NoteId, OutputNoteRecord>,
+    archived_input_notes: BTreeMap<NoteId, InputNoteRecord>,
+    pending_notes: BTreeMap<NoteId, PendingNoteRecord>,
+    /// Unresolved future note links, keyed by their source note.
+    future_note_links: BTreeMap<NoteId, FutureNoteLink>,
+
+    // CHAIN DATA
+    /// Stored block headers, along with the chain MMR peaks at the block and whether the block
+    /// has notes relevant to the client.
+    block_headers: BTreeMap<u32, (BlockHeader, MmrPeaks, bool)>,
+    chain_mmr_nodes: BTreeMap<InOrderIndex, Digest>,
+    mmr_checkpoint: Option<(u32, PartialMmr)>,
+
+    // SYNC
+    sync_height: u32,
+    last_sync_summary: Option<SyncSummary>,
+    partial_sync_progress: Option<PartialSyncProgress>,
+    note_tags: Vec<NoteTagRecord>,
+    storage_slot_watches: Vec<StorageSlotWatch>,
+}
+
+// In-memory implementation of the Store trait
+//
+// Like the SQLite store, the implementations rely on inner MemoryStoreState functions that map
+// 1:1 by name, grouped by entity types in their own sub-modules
+#[async_trait(?Send)]
+impl Store for MemoryStore {
+    // TRANSACTIONS
+    // --------------------------------------------------------------------------------------------
+
+    async fn get_transactions(
+        &self,
+        filter: TransactionFilter,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        self.query(|state| Ok(state.get_transactions(&filter)))
+    }
+
+    async fn apply_transaction(&self, tx_update: TransactionStoreUpdate) -> Result<(), StoreError> {
+        let now = self.now();
+        self.update(|state| state.apply_transaction(&tx_update, now))
+    }
+
+    async fn discard_local_transaction(
+        &self,
+        discard: LocalTransactionDiscard,
+    ) -> Result<(), StoreError> {
+        let now = self.now();
+        self.update(|state| {
+            state.discard_local_transaction(discard, now);
+            Ok(())
+        })
+    }
+
+    async fn insert_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.insert_proven_transaction(transaction_id, proven_transaction, max_total_size);
+            Ok(())
+        })
+    }
+
+    async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<Vec<u8>>, StoreError> {
+        self.update_in_place(|state| Ok(state.get_proven_transaction(transaction_id)))
+    }
+
+    async fn record_submission_attempt(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+    ) -> Result<u32, StoreError> {
+        self.update_in_place(
+            |state| Ok(state.record_submission_attempt(transaction_id, account_id)),
+        )
+    }
+
+    async fn get_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<u32, StoreError> {
+        self.query(|state| Ok(state.get_submission_attempts(transaction_id)))
+    }
+
+    async fn remove_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.submission_attempts.remove(&transaction_id);
+            Ok(())
+        })
+    }
+
+    async fn get_note_transactions(
+        &self,
+        note_id: NoteId,
+        _nullifier: Option<Nullifier>,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        self.query(|state| Ok(state.get_note_transactions(note_id)))
+    }
+
+    async fn get_known_faucets_for_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountId>, StoreError> {
+        let mut faucets = self.query(|state| state.get_account_vault_faucets(account_id))?;
+        faucets.extend(transaction_note_faucets(self, account_id).await?);
+        faucets.remove(&account_id);
+
+        Ok(faucets.into_iter().collect())
+    }
+
+    // NOTES
+    // --------------------------------------------------------------------------------------------
+
+    async fn get_input_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        self.query(|state| state.get_input_notes(filter))
+    }
+
+    async fn get_input_notes_page(
+        &self,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), StoreError> {
+        self.query(|state| state.get_input_notes_page(filter, offset, limit))
+    }
+
+    async fn get_output_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<OutputNoteRecord>, StoreError> {
+        self.query(|state| state.get_output_notes(filter))
+    }
+
+    async fn get_note_ids_by_prefix(&self, prefix: &str) -> Result<Vec<NoteId>, StoreError> {
+        self.query(|state| Ok(state.get_note_ids_by_prefix(prefix)))
+    }
+
+    async fn get_unspent_input_note_nullifiers(&self) -> Result<Vec<Nullifier>, StoreError> {
+        self.query(|state| Ok(state.get_unspent_input_note_nullifiers()))
+    }
+
+    async fn upsert_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError> {
+        let now = self.now();
+        self.update_in_place(|state| {
+            for note in notes {
+                state.upsert_input_note(note, now);
+            }
+            Ok(())
+        })
+    }
+
+    async fn apply_note_batch(
+        &self,
+        note_updates: NoteUpdates,
+        progress: PartialSyncProgress,
+    ) -> Result<(), StoreError> {
+        let now = self.now();
+        self.update_in_place(|state| {
+            state.apply_note_updates(&note_updates, now);
+            state.partial_sync_progress = Some(progress);
+            Ok(())
+        })
+    }
+
+    async fn archive_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.archive_input_notes(notes);
+            Ok(())
+        })
+    }
+
+    async fn get_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, StoreError> {
+        self.query(|state| Ok(state.archived_input_notes.get(&note_id).cloned()))
+    }
+
+    async fn restore_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<InputNoteRecord, StoreError> {
+        let now = self.now();
+        self.update_in_place(|state| state.restore_archived_input_note(note_id, now))
+    }
+
+    async fn get_archived_input_note_count(&self) -> Result<usize, StoreError> {
+        self.query(|state| Ok(state.archived_input_notes.len()))
+    }
+
+    async fn get_pending_notes(&self) -> Result<Vec<PendingNoteRecord>, StoreError> {
+        self.query(|state| Ok(state.pending_notes.values().copied().collect()))
+    }
+
+    async fn upsert_pending_note(&self, note: PendingNoteRecord) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.pending_notes.insert(note.note_id(), note);
+            Ok(())
+        })
+    }
+
+    async fn remove_pending_note(&self, note_id: NoteId) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.pending_notes.remove(&note_id);
+            Ok(())
+        })
+    }
+
+    async fn get_future_note_links(&self) -> Result<Vec<FutureNoteLink>, StoreError> {
+        self.query(|state| Ok(state.future_note_links.values().copied().collect()))
+    }
+
+    // CHAIN DATA
+    // --------------------------------------------------------------------------------------------
+
+    async fn get_block_headers(
+        &self,
+        block_numbers: &[u32],
+    ) -> Result<Vec<(BlockHeader, bool)>, StoreError> {
+        self.query(|state| Ok(state.get_block_headers(block_numbers)))
+    }
+
+    async fn get_tracked_block_headers(&self) -> Result<Vec<BlockHeader>, StoreError> {
+        self.query(|state| Ok(state.get_tracked_block_headers()))
+    }
+
+    async fn get_chain_mmr_nodes(
+        &self,
+        filter: ChainMmrNodeFilter,
+    ) -> Result<BTreeMap<InOrderIndex, Digest>, StoreError> {
+        self.query(|state| Ok(state.get_chain_mmr_nodes(&filter)))
+    }
+
+    async fn insert_chain_mmr_nodes(
+        &self,
+        nodes: &[(InOrderIndex, Digest)],
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.insert_chain_mmr_nodes(nodes);
+            state.mmr_checkpoint = None;
+            Ok(())
+        })
+    }
+
+    async fn replace_chain_mmr_nodes(
+        &self,
+        nodes: &[(InOrderIndex, Digest)],
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.chain_mmr_nodes.extend(nodes.iter().copied());
+            state.mmr_checkpoint = None;
+            Ok(())
+        })
+    }
+
+    async fn get_chain_mmr_peaks_by_block_num(
+        &self,
+        block_num: u32,
+    ) -> Result<MmrPeaks, StoreError> {
+        self.query(|state| state.get_chain_mmr_peaks_by_block_num(block_num))
+    }
+
+    async fn insert_block_header(
+        &self,
+        block_header: BlockHeader,
+        chain_mmr_peaks: MmrPeaks,
+        has_client_notes: bool,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.insert_block_header(block_header, chain_mmr_peaks, has_client_notes);
+            state.mmr_checkpoint = None;
+            Ok(())
+        })
+    }
+
+    async fn save_mmr_checkpoint(
+        &self,
+        block_num: u32,
+        partial_mmr: &PartialMmr,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.mmr_checkpoint = Some((block_num, partial_mmr.clone()));
+            Ok(())
+        })
+    }
+
+    async fn load_mmr_checkpoint(&self) -> Result<Option<(u32, PartialMmr)>, StoreError> {
+        self.query(|state| Ok(state.mmr_checkpoint.clone()))
+    }
+
+    // ACCOUNT
+    // --------------------------------------------------------------------------------------------
+
+    async fn get_account_ids(&self) -> Result<Vec<AccountId>, StoreError> {
+        self.query(|state| Ok(state.accounts.keys().copied().collect()))
+    }
+
+    async fn get_account_ids_by_prefix(&self, prefix: &str) -> Result<Vec<AccountId>, StoreError> {
+        self.query(|state| state.get_account_ids_by_prefix(prefix))
+    }
+
+    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError> {
+        self.query(|state| Ok(state.get_account_headers()))
+    }
+
+    async fn get_account_header(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(AccountHeader, Option<Word>), StoreError> {
+        self.query(|state| {
+            let (account, seed) = state.get_account(account_id)?;
+            Ok((account.into(), *seed))
+        })
+    }
+
+    async fn get_account_header_by_hash(
+        &self,
+        account_hash: Digest,
+    ) -> Result<Option<AccountHeader>, StoreError> {
+        self.query(|state| Ok(state.get_account_header_by_hash(account_hash)))
+    }
+
+    async fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), StoreError> {
+        self.query(|state| {
+            let (account, seed) = state.get_account(account_id)?;
+            Ok((account.clone(), *seed))
+        })
+    }
+
+    async fn get_account_history(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<(Account, Digest)>, StoreError> {
+        self.query(|state| Ok(state.get_account_history(account_id)))
+    }
+
+    async fn get_account_auth_by_pub_key(
+        &self,
+        pub_key: Word,
+    ) -> Result<AuthSecretKey, StoreError> {
+        self.query(|state| state.get_account_auth_by_pub_key(pub_key))
+    }
+
+    async fn get_account_auth(&self, account_id: AccountId) -> Result<AuthSecretKey, StoreError> {
+        self.query(|state| {
+            state
+                .account_auths
+                .get(&account_id)
+                .cloned()
+                .ok_or(StoreError::AccountDataNotFound(account_id))
+        })
+    }
+
+    async fn insert_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+        auth_info: &AuthSecretKey,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state
+                .pending_account_auths
+                .insert(transaction_id, (account_id, auth_info.clone()));
+            Ok(())
+        })
+    }
+
+    async fn get_pending_account_auths(
+        &self,
+    ) -> Result<Vec<(TransactionId, AccountId)>, StoreError> {
+        self.query(|state| {
+            Ok(state
+                .pending_account_auths
+                .iter()
+                .map(|(transaction_id, (account_id, _))| (*transaction_id, *account_id))
+                .collect())
+        })
+    }
+
+    async fn resolve_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        committed: bool,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.resolve_pending_account_auth(transaction_id, committed);
+            Ok(())
+        })
+    }
+
+    async fn insert_account(
+        &self,
+        account: &Account,
+        account_seed: Option<Word>,
+        auth_info: Option<&AuthSecretKey>,
+    ) -> Result<(), StoreError> {
+        self.update(|state| state.insert_account(account, account_seed, auth_info))
+    }
+
+    async fn reserve_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.update_in_place(|state| state.reserve_account_id(account_id))
+    }
+
+    async fn release_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.account_reservations.remove(&account_id);
+            Ok(())
+        })
+    }
+
+    async fn remove_account(&self, removal: AccountRemoval) -> Result<(), StoreError> {
+        self.update(|state| {
+            state.remove_account(removal);
+            Ok(())
+        })
+    }
+
+    async fn get_orphaned_account_auth_ids(&self) -> Result<Vec<AccountId>, StoreError> {
+        self.query(|state| Ok(state.get_orphaned_account_auth_ids()))
+    }
+
+    async fn remove_orphaned_account_auth(
+        &self,
+        account_id: AccountId,
+    ) -> Result<bool, StoreError> {
+        self.update_in_place(|state| Ok(state.remove_orphaned_account_auth(account_id)))
+    }
+
+    async fn get_account_lock_status(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountLockStatus, StoreError> {
+        self.query(|state| {
+            Ok(state
+                .account_locks
+                .get(&account_id)
+                .copied()
+                .unwrap_or(AccountLockStatus::Unlocked))
+        })
+    }
+
+    async fn set_account_lock_status(
+        &self,
+        account_id: AccountId,
+        status: AccountLockStatus,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.set_account_lock_status(account_id, status);
+            Ok(())
+        })
+    }
+
+    async fn apply_account_unlock(
+        &self,
+        record: AccountUnlockRecord,
+        new_state: Option<Account>,
+    ) -> Result<(), StoreError> {
+        self.update(|state| state.apply_account_unlock(record, new_state.as_ref()))
+    }
+
+    async fn get_account_unlock_records(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountUnlockRecord>, StoreError> {
+        self.query(|state| {
+            Ok(state
+                .account_unlocks
+                .iter()
+                .filter(|record| record.account_id == account_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    // SYNC
+    // --------------------------------------------------------------------------------------------
+
+    async fn get_note_tags(&self) -> Result<Vec<NoteTagRecord>, StoreError> {
+        self.query(|state| Ok(state.note_tags.clone()))
+    }
+
+    async fn add_note_tag(&self, tag: NoteTagRecord) -> Result<bool, StoreError> {
+        self.update_in_place(|state| Ok(state.add_note_tag(tag)))
+    }
+
+    async fn remove_note_tag(&self, tag: NoteTagRecord) -> Result<usize, StoreError> {
+        self.update_in_place(|state| Ok(state.remove_note_tag(tag)))
+    }
+
+    async fn replace_note_tags(
+        &self,
+        removed: Vec<NoteTagRecord>,
+        added: Vec<NoteTagRecord>,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            for tag in removed {
+                state.remove_note_tag(tag);
+            }
+            state.note_tags.extend(added);
+            Ok(())
+        })
+    }
+
+    async fn get_storage_slot_watches(&self) -> Result<Vec<StorageSlotWatch>, StoreError> {
+        self.query(|state| Ok(state.storage_slot_watches.clone()))
+    }
+
+    async fn add_storage_slot_watch(&self, watch: StorageSlotWatch) -> Result<bool, StoreError> {
+        self.update_in_place(|state| Ok(state.add_storage_slot_watch(watch)))
+    }
+
+    async fn remove_storage_slot_watch(
+        &self,
+        watch: StorageSlotWatch,
+    ) -> Result<usize, StoreError> {
+        self.update_in_place(|state| Ok(state.remove_storage_slot_watch(watch)))
+    }
+
+    async fn get_sync_height(&self) -> Result<u32, StoreError> {
+        self.query(|state| Ok(state.sync_height))
+    }
+
+    async fn apply_state_sync(&self, state_sync_update: StateSyncUpdate) -> Result<(), StoreError> {
+        let now = self.now();
+        self.update(|state| state.apply_state_sync(state_sync_update, now))
+    }
+
+    async fn reset_chain_state(&self, reset: ChainStateReset) -> Result<(), StoreError> {
+        let now = self.now();
+        self.update(|state| {
+            state.reset_chain_state(reset, now);
+            Ok(())
+        })
+    }
+
+    async fn get_last_sync_summary(&self) -> Result<Option<SyncSummary>, StoreError> {
+        self.query(|state| Ok(state.last_sync_summary.clone()))
+    }
+
+    async fn set_last_sync_summary(&self, summary: SyncSummary) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.last_sync_summary = Some(summary);
+            Ok(())
+        })
+    }
+
+    async fn get_partial_sync_progress(&self) -> Result<Option<PartialSyncProgress>, StoreError> {
+        self.query(|state| Ok(state.partial_sync_progress.clone()))
+    }
+
+    async fn set_partial_sync_progress(
+        &self,
+        progress: Option<PartialSyncProgress>,
+    ) -> Result<(), StoreError> {
+        self.update_in_place(|state| {
+            state.partial_sync_progress = progress;
+            Ok(())
+        })
+    }
+
+    // SNAPSHOTS
+    // --------------------------------------------------------------------------------------------
+
+    async fn read_snapshot(
+        &self,
+        _timeout: Duration,
+    ) -> Result<Box<dyn StoreSnapshot>, StoreError> {
+        self.query(|state| Ok(Box::new(MemorySnapshot::new(state)) as Box<dyn StoreSnapshot>))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_lib::transaction::TransactionKernel;
+    use miden_objects::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, Account,
+            AuthSecretKey,
+        },
+        crypto::dsa::rpo_falcon512::SecretKey,
+        transaction::TransactionId,
+        Digest, Felt, Word,
+    };
+
+    use super::MemoryStore;
+    use crate::{
+        accounts::{AccountLockStatus, AccountUnlockRecord, UnlockStrategyKind},
+        store::{Store, StoreError},
+    };
+
+    #[tokio::test]
+    async fn test_failed_update_leaves_store_untouched() {
+        let store = MemoryStore::new();
+        let account = Account::mock(
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            Felt::new(0),
+            TransactionKernel::testing_assembler(),
+        );
+        let auth_info = AuthSecretKey::RpoFalcon512(SecretKey::new());
+        store
+            .insert_account(&account, Some(Word::default()), Some(&auth_info))
+            .await
+            .unwrap();
+
+        // The new state of the account is inserted after its stored states are removed, and it
+        // fails because a new account must have a seed
+        let record = AccountUnlockRecord {
+            account_id: account.id(),
+            strategy: UnlockStrategyKind::ImportState,
+            local_hash: account.hash(),
+            remote_hash: account.hash(),
+            block_num: 0,
+        };
+        assert!(matches!(
+            store.apply_account_unlock(record, Some(account.clone())).await,
+            Err(StoreError::DatabaseError(_))
+        ));
+
+        let (stored_account, seed) = store.get_account(account.id()).await.unwrap();
+        assert_eq!(stored_account.hash(), account.hash());
+        assert_eq!(seed, Some(Word::default()));
+        assert!(store.get_account_unlock_records(account.id()).await.unwrap().is_empty());
+        assert_eq!(
+            store.get_account_lock_status(account.id()).await.unwrap(),
+            AccountLockStatus::Unlocked
+        );
+    }
+
+    #[tokio::test]
+    async fn test_proven_transactions_are_evicted_from_least_recently_used() {
+        let store = MemoryStore::new();
+        let transaction_ids: Vec<TransactionId> = (0..3)
+            .map(|index| TransactionId::from(Digest::from([Felt::new(index); 4])))
+            .collect();
+
+        store
+            .insert_proven_transaction(transaction_ids[0], vec![0; 10], 25)
+            .await
+            .unwrap();
+        store
+            .insert_proven_transaction(transaction_ids[1], vec![1; 10], 25)
+            .await
+            .unwrap();
+        // Retrieving the first proof makes the second one the least recently used
+        assert!(store.get_proven_transaction(transaction_ids[0]).await.unwrap().is_some());
+        store
+            .insert_proven_transaction(transaction_ids[2], vec![2; 10], 25)
+            .await
+            .unwrap();
+
+        assert!(store.get_proven_transaction(transaction_ids[0]).await.unwrap().is_some());
+        assert!(store.get_proven_transaction(transaction_ids[1]).await.unwrap().is_none());
+        assert!(store.get_proven_transaction(transaction_ids[2]).await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,267 @@
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+
+use miden_objects::notes::{NoteId, Nullifier};
+
+use super::MemoryStoreState;
+use crate::{
+    notes::{FutureNoteLink, FutureNoteResolution, NoteUpdates},
+    store::{
+        InputNoteRecord, InputNoteState, NoteFilter, OutputNoteRecord, OutputNoteState, StoreError,
+    },
+};
+
+// NOTE FILTERS
+// ================================================================================================
+
+/// Returns whether the input note is matched by the filter. [NoteFilter::CreatedByTransaction]
+/// filters are expected to be replaced beforehand with the list of notes they match.
+fn matches_input_note(filter: &NoteFilter, note: &InputNoteRecord) -> bool {
+    let state = note.state().discriminant();
+    match filter {
+        NoteFilter::All => true,
+        NoteFilter::Aux(aux) => note.metadata().is_some_and(|metadata| metadata.aux() == *aux),
+        NoteFilter::Committed => state == InputNoteState::STATE_COMMITTED,
+        NoteFilter::Consumed => [
+            InputNoteState::STATE_CONSUMED_AUTHENTICATED_LOCAL,
+            InputNoteState::STATE_CONSUMED_UNAUTHENTICATED_LOCAL,
+            InputNoteState::STATE_CONSUMED_EXTERNAL,
+        ]
+        .contains(&state),
+        NoteFilter::Expected => state == InputNoteState::STATE_EXPECTED,
+        NoteFilter::Processing => [
+            InputNoteState::STATE_PROCESSING_AUTHENTICATED,
+            InputNoteState::STATE_PROCESSING_UNAUTHENTICATED,
+        ]
+        .contains(&state),
+        NoteFilter::Rejected => state == InputNoteState::STATE_REJECTED,
+        NoteFilter::Unverified => state == InputNoteState::STATE_UNVERIFIED,
+        NoteFilter::Unspent => [
+            InputNoteState::STATE_EXPECTED,
+            InputNoteState::STATE_PROCESSING_AUTHENTICATED,
+            InputNoteState::STATE_PROCESSING_UNAUTHENTICATED,
+            InputNoteState::STATE_UNVERIFIED,
+            InputNoteState::STATE_COMMITTED,
+        ]
+        .contains(&state),
+        NoteFilter::Unique(note_id) => note.id() == *note_id,
+        NoteFilter::List(note_ids) => note_ids.contains(&note.id()),
+        NoteFilter::Nullifiers(nullifiers) => nullifiers.contains(&note.nullifier()),
+        NoteFilter::CreatedByTransaction(_) => false,
+    }
+}
+
+/// Returns whether the output note is matched by the filter. [NoteFilter::CreatedByTransaction]
+/// filters are expected to be replaced beforehand with the list of notes they match.
+fn matches_output_note(filter: &NoteFilter, note: &OutputNoteRecord) -> bool {
+    let state = note.state().discriminant();
+    match filter {
+        NoteFilter::All => true,
+        NoteFilter::Aux(aux) => note.metadata().aux() == *aux,
+        NoteFilter::Committed => {
+            [OutputNoteState::STATE_COMMITTED_PARTIAL, OutputNoteState::STATE_COMMITTED_FULL]
+                .contains(&state)
+        },
+        NoteFilter::Consumed => state == OutputNoteState::STATE_CONSUMED,
+        NoteFilter::Expected => {
+            [OutputNoteState::STATE_EXPECTED_PARTIAL, OutputNoteState::STATE_EXPECTED_FULL]
+                .contains(&state)
+        },
+        NoteFilter::Unspent => {
+            [OutputNoteState::STATE_EXPECTED_FULL, OutputNoteState::STATE_COMMITTED_FULL]
+                .contains(&state)
+        },
+        // There are no processing, rejected or unverified output notes
+        NoteFilter::Processing
+        | NoteFilter::Rejected
+        | NoteFilter::Unverified
+        | NoteFilter::CreatedByTransaction(_) => false,
+        NoteFilter::Unique(note_id) => note.id() == *note_id,
+        NoteFilter::List(note_ids) => note_ids.contains(&note.id()),
+        NoteFilter::Nullifiers(nullifiers) => {
+            note.nullifier().is_some_and(|nullifier| nullifiers.contains(&nullifier))
+        },
+    }
+}
+
+// NOTES
+// ================================================================================================
+
+impl MemoryStoreState {
+    pub(super) fn get_input_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        let filter = self.resolve_created_by_transaction(filter);
+        let notes: Vec<InputNoteRecord> = self
+            .input_notes
+            .values()
+            .filter(|note| matches_input_note(&filter, note))
+            .cloned()
+            .collect();
+
+        match filter {
+            NoteFilter::Unique(note_id) if notes.is_empty() => {
+                Err(StoreError::NoteNotFound(note_id))
+            },
+            _ => Ok(notes),
+        }
+    }
+
+    /// Retrieves a page of the input notes matched by the filter, ordered by the hex
+    /// representation of their IDs like in the SQLite store, along with the number of notes the
+    /// filter matches.
+    pub(super) fn get_input_notes_page(
+        &self,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), StoreError> {
+        let filter = self.resolve_created_by_transaction(filter);
+        let mut notes: Vec<&InputNoteRecord> = self
+            .input_notes
+            .values()
+            .filter(|note| matches_input_note(&filter, note))
+            .collect();
+
+        if let NoteFilter::Unique(note_id) = filter {
+            if notes.is_empty() {
+                return Err(StoreError::NoteNotFound(note_id));
+            }
+        }
+
+        let total = notes.len();
+        notes.sort_by_cached_key(|note| note.id().inner().to_string());
+        let page = notes.into_iter().skip(offset).take(limit).cloned().collect();
+
+        Ok((page, total))
+    }
+
+    pub(super) fn get_output_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<OutputNoteRecord>, StoreError> {
+        let filter = self.resolve_created_by_transaction(filter);
+        let notes: Vec<OutputNoteRecord> = self
+            .output_notes
+            .values()
+            .filter(|note| matches_output_note(&filter, note))
+            .cloned()
+            .collect();
+
+        match filter {
+            NoteFilter::Unique(note_id) if notes.is_empty() => {
+                Err(StoreError::NoteNotFound(note_id))
+            },
+            _ => Ok(notes),
+        }
+    }
+
+    /// Retrieves the IDs of the input and output notes starting with the provided prefix.
+    pub(super) fn get_note_ids_by_prefix(&self, prefix: &str) -> Vec<NoteId> {
+        self.input_notes
+            .keys()
+            .chain(self.output_notes.keys())
+            .filter(|note_id| note_id.inner().to_string().starts_with(prefix))
+            .copied()
+            .collect::<BTreeSet<NoteId>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the nullifiers of the input notes that aren't consumed.
+    pub(super) fn get_unspent_input_note_nullifiers(&self) -> Vec<Nullifier> {
+        self.input_notes
+            .values()
+            .filter(|note| !note.is_consumed())
+            .map(|note| note.nullifier())
+            .collect()
+    }
+
+    /// Inserts the input note, replacing the stored one with the same ID. The note is timestamped
+    /// with `now`, if provided.
+    pub(super) fn upsert_input_note(&mut self, note: &InputNoteRecord, now: Option<u64>) {
+        let note = InputNoteRecord::new(
+            note.details().clone(),
+            now.or(note.created_at()),
+            note.state().clone(),
+        );
+        self.input_notes.insert(note.id(), note);
+    }
+
+    pub(super) fn apply_note_updates(&mut self, note_updates: &NoteUpdates, now: Option<u64>) {
+        for input_note in
+            note_updates.new_input_notes().iter().chain(note_updates.updated_input_notes())
+        {
+            self.upsert_input_note(input_note, now);
+        }
+
+        for output_note in note_updates
+            .new_output_notes()
+            .iter()
+            .chain(note_updates.updated_output_notes())
+        {
+            self.output_notes.insert(output_note.id(), output_note.clone());
+        }
+    }
+
+    pub(super) fn archive_input_notes(&mut self, notes: &[InputNoteRecord]) {
+        for note in notes {
+            self.input_notes.remove(&note.id());
+            self.archived_input_notes.insert(note.id(), note.clone());
+        }
+    }
+
+    /// Moves the archived input note back to the input notes, keeping the time it was originally
+    /// stored at.
+    pub(super) fn restore_archived_input_note(
+        &mut self,
+        note_id: NoteId,
+        now: Option<u64>,
+    ) -> Result<InputNoteRecord, StoreError> {
+        let note = self
+            .archived_input_notes
+            .remove(&note_id)
+            .ok_or(StoreError::NoteNotFound(note_id))?;
+        self.upsert_input_note(&note, note.created_at().or(now));
+
+        Ok(note)
+    }
+
+    /// Inserts the provided future note links, replacing the ones with the same source notes.
+    pub(super) fn insert_future_note_links(&mut self, links: &[FutureNoteLink]) {
+        self.future_note_links
+            .extend(links.iter().map(|link| (link.source_note_id(), *link)));
+    }
+
+    /// Removes the links of the provided resolutions, along with the input notes of the cancelled
+    /// ones that are still expected.
+    pub(super) fn apply_future_note_resolutions(&mut self, resolutions: &[FutureNoteResolution]) {
+        for resolution in resolutions {
+            self.future_note_links.remove(&resolution.source_note_id());
+
+            if let FutureNoteResolution::Cancelled { future_note_id, .. } = resolution {
+                let is_expected = self.input_notes.get(future_note_id).is_some_and(|note| {
+                    note.state().discriminant() == InputNoteState::STATE_EXPECTED
+                });
+                if is_expected {
+                    self.input_notes.remove(future_note_id);
+                }
+            }
+        }
+    }
+
+    /// Replaces a [NoteFilter::CreatedByTransaction] with the list of the notes in the stored
+    /// output notes of the transaction. Other filters are returned unchanged.
+    fn resolve_created_by_transaction(&self, filter: NoteFilter) -> NoteFilter {
+        let NoteFilter::CreatedByTransaction(transaction_id) = filter else {
+            return filter;
+        };
+
+        let note_ids = self
+            .get_transaction(transaction_id)
+            .map(|transaction| transaction.output_notes.iter().map(|note| note.id()).collect())
+            .unwrap_or_default();
+
+        NoteFilter::List(note_ids)
+    }
+}
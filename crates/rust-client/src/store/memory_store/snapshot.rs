@@ -0,0 +1,54 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use async_trait::async_trait;
+use miden_objects::{accounts::AccountHeader, Word};
+
+use super::MemoryStoreState;
+use crate::{
+    store::{InputNoteRecord, NoteFilter, StoreError, StoreSnapshot, TransactionFilter},
+    transactions::TransactionRecord,
+};
+
+// MEMORY SNAPSHOT
+// ================================================================================================
+
+/// [StoreSnapshot] holding a copy of the accounts, input notes and transactions of a
+/// [MemoryStore](super::MemoryStore) taken when it's opened. The snapshot holds no resources of
+/// the store, so it never blocks writers and doesn't expire.
+pub(super) struct MemorySnapshot {
+    state: MemoryStoreState,
+}
+
+impl MemorySnapshot {
+    pub(super) fn new(state: &MemoryStoreState) -> Self {
+        Self {
+            state: MemoryStoreState {
+                accounts: state.accounts.clone(),
+                input_notes: state.input_notes.clone(),
+                transactions: state.transactions.clone(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreSnapshot for MemorySnapshot {
+    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError> {
+        Ok(self.state.get_account_headers())
+    }
+
+    async fn get_input_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        self.state.get_input_notes(filter)
+    }
+
+    async fn get_transactions(
+        &self,
+        filter: TransactionFilter,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        Ok(self.state.get_transactions(&filter))
+    }
+}
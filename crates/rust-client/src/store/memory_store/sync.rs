@@ -0,0 +1,125 @@
+use super::MemoryStoreState;
+use crate::{
+    store::StoreError,
+    sync::{ChainStateReset, NoteTagRecord, StateSyncUpdate, StorageSlotWatch},
+    transactions::DiscardCause,
+};
+
+// SYNC
+// ================================================================================================
+
+impl MemoryStoreState {
+    /// Adds the tag record, unless an equal one is already tracked. Returns whether it was added.
+    pub(super) fn add_note_tag(&mut self, tag: NoteTagRecord) -> bool {
+        if self.note_tags.contains(&tag) {
+            return false;
+        }
+        self.note_tags.push(tag);
+
+        true
+    }
+
+    /// Removes the tag records with the same tag and source as `tag`, returning how many were
+    /// removed.
+    pub(super) fn remove_note_tag(&mut self, tag: NoteTagRecord) -> usize {
+        let tag_count = self.note_tags.len();
+        self.note_tags.retain(|tag_record| *tag_record != tag);
+
+        tag_count - self.note_tags.len()
+    }
+
+    pub(super) fn add_storage_slot_watch(&mut self, watch: StorageSlotWatch) -> bool {
+        if self.storage_slot_watches.contains(&watch) {
+            return false;
+        }
+        self.storage_slot_watches.push(watch);
+
+        true
+    }
+
+    pub(super) fn remove_storage_slot_watch(&mut self, watch: StorageSlotWatch) -> usize {
+        let watch_count = self.storage_slot_watches.len();
+        self.storage_slot_watches.retain(|stored_watch| *stored_watch != watch);
+
+        watch_count - self.storage_slot_watches.len()
+    }
+
+    pub(super) fn apply_state_sync(
+        &mut self,
+        state_sync_update: StateSyncUpdate,
+        now: Option<u64>,
+    ) -> Result<(), StoreError> {
+        let StateSyncUpdate {
+            block_header,
+            note_updates,
+            transactions_to_commit: committed_transactions,
+            new_mmr_peaks,
+            new_authentication_nodes,
+            updated_onchain_accounts,
+            block_has_relevant_notes,
+            transactions_to_discard: discarded_transactions,
+            tags_to_remove,
+            future_note_resolutions,
+        } = state_sync_update;
+
+        self.sync_height = block_header.block_num();
+        self.partial_sync_progress = None;
+        self.insert_block_header(block_header, new_mmr_peaks, block_has_relevant_notes);
+
+        self.apply_note_updates(&note_updates, now);
+        self.apply_future_note_resolutions(&future_note_resolutions);
+
+        for tag in tags_to_remove {
+            self.remove_note_tag(tag);
+        }
+
+        self.insert_chain_mmr_nodes(&new_authentication_nodes);
+
+        self.mark_transactions_as_committed(&committed_transactions);
+        self.mark_transactions_as_discarded(
+            &discarded_transactions,
+            DiscardCause::InputNoteConsumedExternally,
+        );
+
+        for account in &updated_onchain_accounts {
+            self.insert_account_state(account, None)?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn reset_chain_state(&mut self, reset: ChainStateReset, now: Option<u64>) {
+        let ChainStateReset {
+            note_updates,
+            transactions_to_discard,
+            keep_accounts,
+            tags_to_add,
+            tags_to_remove,
+        } = reset;
+
+        self.block_headers.clear();
+        self.chain_mmr_nodes.clear();
+        self.mmr_checkpoint = None;
+        self.account_locks.clear();
+        self.sync_height = 0;
+        self.last_sync_summary = None;
+        self.partial_sync_progress = None;
+
+        self.apply_note_updates(&note_updates, now);
+        self.note_tags.extend(tags_to_add);
+        self.mark_transactions_as_discarded(&transactions_to_discard, DiscardCause::ChainReset);
+
+        if !keep_accounts {
+            for tag in tags_to_remove {
+                self.remove_note_tag(tag);
+            }
+
+            self.transactions.clear();
+            self.storage_slot_watches.clear();
+            self.account_unlocks.clear();
+            self.account_auths.clear();
+            self.pending_account_auths.clear();
+            self.accounts.clear();
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_objects::{accounts::AccountId, notes::NoteId, transaction::TransactionId};
+
+use super::MemoryStoreState;
+use crate::{
+    rpc::TransactionUpdate,
+    store::{StoreError, TransactionFilter},
+    transactions::{
+        DiscardCause, LocalTransactionDiscard, TransactionRecord, TransactionStatus,
+        TransactionStoreUpdate,
+    },
+};
+
+// STORED TRANSACTION
+// ================================================================================================
+
+/// Transaction tracked by a [MemoryStore](super::MemoryStore).
+#[derive(Clone)]
+pub(super) struct StoredTransaction {
+    pub(super) record: TransactionRecord,
+    /// Block the transaction was committed at, kept apart from the status of the record because
+    /// a discarded transaction can still be committed.
+    pub(super) commit_height: Option<u32>,
+    /// IDs of the notes consumed and created by the transaction.
+    pub(super) note_ids: BTreeSet<NoteId>,
+}
+
+// TRANSACTIONS
+// ================================================================================================
+
+impl MemoryStoreState {
+    pub(super) fn get_transactions(&self, filter: &TransactionFilter) -> Vec<TransactionRecord> {
+        self.transactions
+            .iter()
+            .filter(|transaction| match filter {
+                TransactionFilter::All => true,
+                TransactionFilter::Uncomitted => transaction.commit_height.is_none(),
+            })
+            .map(|transaction| transaction.record.clone())
+            .collect()
+    }
+
+    pub(super) fn get_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Option<&TransactionRecord> {
+        self.transactions
+            .iter()
+            .map(|transaction| &transaction.record)
+            .find(|record| record.id == transaction_id)
+    }
+
+    /// Inserts a transaction and updates the current state based on the `tx_update` changes.
+    pub(super) fn apply_transaction(
+        &mut self,
+        tx_update: &TransactionStoreUpdate,
+        now: Option<u64>,
+    ) -> Result<(), StoreError> {
+        let executed_transaction = tx_update.executed_transaction();
+        if self.get_transaction(executed_transaction.id()).is_some() {
+            return Err(StoreError::DatabaseError(format!(
+                "transaction {} is already stored",
+                executed_transaction.id()
+            )));
+        }
+
+        let mut record = TransactionRecord::new(
+            executed_transaction.id(),
+            executed_transaction.account_id(),
+            executed_transaction.initial_account().hash(),
+            executed_transaction.final_account().hash(),
+            executed_transaction
+                .input_notes()
+                .iter()
+                .map(|note| note.nullifier().inner())
+                .collect(),
+            executed_transaction.output_notes().clone(),
+            executed_transaction.tx_args().tx_script().cloned(),
+            executed_transaction.block_header().block_num(),
+            TransactionStatus::Pending,
+        );
+        record.provenance = tx_update.provenance().cloned();
+
+        let note_ids = executed_transaction
+            .input_notes()
+            .iter()
+            .map(|note| note.id())
+            .chain(executed_transaction.output_notes().iter().map(|note| note.id()))
+            .collect();
+        self.transactions
+            .push(StoredTransaction { record, commit_height: None, note_ids });
+
+        self.insert_account_state(tx_update.updated_account(), None)?;
+        self.apply_note_updates(tx_update.note_updates(), now);
+        self.note_tags.extend(tx_update.new_tags());
+        self.insert_future_note_links(tx_update.future_note_links());
+
+        Ok(())
+    }
+
+    /// Reverts the changes of a local transaction and marks it as discarded.
+    pub(super) fn discard_local_transaction(
+        &mut self,
+        discard: LocalTransactionDiscard,
+        now: Option<u64>,
+    ) {
+        let LocalTransactionDiscard {
+            transaction_id,
+            account_id,
+            init_account_nonce,
+            note_updates,
+            removed_note_ids,
+            tags_to_add,
+            tags_to_remove,
+        } = discard;
+
+        if let Some(states) = self.accounts.get_mut(&account_id) {
+            states.retain(|nonce, _| *nonce <= init_account_nonce);
+        }
+
+        self.apply_note_updates(&note_updates, now);
+        for note_id in &removed_note_ids {
+            self.input_notes.remove(note_id);
+            self.output_notes.remove(note_id);
+            self.future_note_links.remove(note_id);
+        }
+
+        self.note_tags.extend(tags_to_add);
+        for tag in tags_to_remove {
+            self.remove_note_tag(tag);
+        }
+
+        self.mark_transactions_as_discarded(&[transaction_id], DiscardCause::ManuallyDiscarded);
+    }
+
+    /// Retrieves the transactions that consumed or created the note with the specified ID.
+    pub(super) fn get_note_transactions(&self, note_id: NoteId) -> Vec<TransactionRecord> {
+        self.transactions
+            .iter()
+            .filter(|transaction| transaction.note_ids.contains(&note_id))
+            .map(|transaction| transaction.record.clone())
+            .collect()
+    }
+
+    /// Stores the serialized proven transaction as the most recently used one, and evicts the
+    /// least recently used proofs until the retained ones take up to `max_total_size` bytes.
+    pub(super) fn insert_proven_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
+    ) {
+        let last_used = self.next_proven_transaction_use();
+        self.proven_transactions.insert(transaction_id, (proven_transaction, last_used));
+
+        let mut retained: Vec<(TransactionId, usize, u64)> = self
+            .proven_transactions
+            .iter()
+            .map(|(transaction_id, (proof, last_used))| (*transaction_id, proof.len(), *last_used))
+            .collect();
+        retained.sort_by_key(|(_, _, last_used)| core::cmp::Reverse(*last_used));
+
+        let mut total_size = 0;
+        for (evicted_id, size, _) in retained {
+            total_size += size;
+            if total_size > max_total_size {
+                self.proven_transactions.remove(&evicted_id);
+            }
+        }
+    }
+
+    /// Retrieves the serialized proven transaction, if it's retained, and marks it as the most
+    /// recently used one.
+    pub(super) fn get_proven_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Option<Vec<u8>> {
+        let last_used = self.next_proven_transaction_use();
+        let (proof, proof_last_used) = self.proven_transactions.get_mut(&transaction_id)?;
+        *proof_last_used = last_used;
+
+        Some(proof.clone())
+    }
+
+    /// Records a new attempt to submit the transaction and returns the number of attempts
+    /// recorded for it.
+    pub(super) fn record_submission_attempt(
+        &mut self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+    ) -> u32 {
+        let (_, attempts) =
+            self.submission_attempts.entry(transaction_id).or_insert((account_id, 0));
+        *attempts += 1;
+
+        *attempts
+    }
+
+    pub(super) fn get_submission_attempts(&self, transaction_id: TransactionId) -> u32 {
+        self.submission_attempts
+            .get(&transaction_id)
+            .map(|(_, attempts)| *attempts)
+            .unwrap_or(0)
+    }
+
+    /// Sets the provided transactions as committed. Discarded transactions keep their status.
+    pub(super) fn mark_transactions_as_committed(
+        &mut self,
+        transactions_to_commit: &[TransactionUpdate],
+    ) {
+        for transaction_update in transactions_to_commit {
+            let transaction = self
+                .transactions
+                .iter_mut()
+                .find(|transaction| transaction.record.id == transaction_update.transaction_id);
+            if let Some(transaction) = transaction {
+                transaction.commit_height = Some(transaction_update.block_num);
+                if transaction.record.transaction_status != TransactionStatus::Discarded {
+                    transaction.record.transaction_status =
+                        TransactionStatus::Committed(transaction_update.block_num);
+                }
+            }
+        }
+    }
+
+    /// Sets the provided transactions as discarded, recording the cause of the discard.
+    pub(super) fn mark_transactions_as_discarded(
+        &mut self,
+        transactions_to_discard: &[TransactionId],
+        cause: DiscardCause,
+    ) {
+        for transaction in self
+            .transactions
+            .iter_mut()
+            .filter(|transaction| transactions_to_discard.contains(&transaction.record.id))
+        {
+            transaction.record.transaction_status = TransactionStatus::Discarded;
+            transaction.record.discard_cause = Some(cause);
+        }
+    }
+
+    /// Returns the use counter for the next insertion or retrieval of a proven transaction.
+    fn next_proven_transaction_use(&self) -> u64 {
+        self.proven_transactions
+            .values()
+            .map(|(_, last_used)| *last_used)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+}
@@ -5,19 +5,30 @@ use alloc::{
     collections::{BTreeMap, BTreeSet},
     vec::Vec,
 };
-use core::fmt::Debug;
+use core::{cmp::Reverse, fmt::Debug, time::Duration};
 
 use async_trait::async_trait;
 use miden_objects::{
     accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
-    crypto::merkle::{InOrderIndex, MmrPeaks},
-    notes::{NoteId, NoteTag, Nullifier},
-    BlockHeader, Digest, Word,
+    assets::Asset,
+    crypto::merkle::{InOrderIndex, MmrPeaks, PartialMmr},
+    notes::{NoteAssets, NoteId, NoteTag, Nullifier},
+    transaction::TransactionId,
+    BlockHeader, Digest, Felt, Word,
 };
 
 use crate::{
-    sync::{NoteTagRecord, StateSyncUpdate},
-    transactions::{TransactionRecord, TransactionStoreUpdate},
+    accounts::{
+        minted_amount, AccountLockStatus, AccountRemoval, AccountUnlockRecord, FaucetMintRecord,
+    },
+    notes::{FutureNoteLink, NoteUpdates, PendingNoteRecord},
+    sync::{
+        ChainStateReset, NoteTagRecord, PartialSyncProgress, StateSyncUpdate, StorageSlotWatch,
+        SyncSummary,
+    },
+    transactions::{
+        LocalTransactionDiscard, TransactionRecord, TransactionStatus, TransactionStoreUpdate,
+    },
 };
 
 /// Contains [ClientDataStore] to automatically implement [DataStore] for anything that implements
@@ -33,6 +44,11 @@ pub use authenticator::StoreAuthenticator;
 mod errors;
 pub use errors::*;
 
+mod snapshot;
+pub use snapshot::{StoreSnapshot, DEFAULT_SNAPSHOT_TIMEOUT};
+
+pub mod memory_store;
+
 #[cfg(all(feature = "sqlite", feature = "idxdb"))]
 compile_error!("features `sqlite` and `idxdb` are mutually exclusive");
 
@@ -44,8 +60,8 @@ pub mod web_store;
 
 mod note_record;
 pub use note_record::{
-    input_note_states, InputNoteRecord, InputNoteState, NoteExportType, NoteRecordError,
-    OutputNoteRecord, OutputNoteState,
+    input_note_states, InputNoteRecord, InputNoteState, NoteConversionError, NoteExportType,
+    NoteRecordError, NoteStateCode, OutputNoteRecord, OutputNoteState,
 };
 
 // STORE TRAIT
@@ -81,9 +97,139 @@ pub trait Store: Send + Sync {
     ///   execution
     /// - Updating the input notes that are being processed by the transaction
     /// - Inserting the new tracked tags into the store
+    /// - Inserting the links between the created notes and the expected notes their consumption
+    ///   creates
     /// - Inserting the transaction into the store to track
     async fn apply_transaction(&self, tx_update: TransactionStoreUpdate) -> Result<(), StoreError>;
 
+    /// Atomically reverts the changes a local transaction applied to the store. A discard
+    /// involves:
+    ///
+    /// - Removing the states of the account newer than the one the transaction was executed against
+    /// - Updating the input notes reverted to their states before the transaction, and tracking the
+    ///   tags of the expected ones again
+    /// - Removing the notes created by the transaction, along with their tags and future note links
+    /// - Marking the transaction as `discarded` with the
+    ///   [DiscardCause::ManuallyDiscarded](crate::transactions::DiscardCause::ManuallyDiscarded)
+    ///   cause
+    async fn discard_local_transaction(
+        &self,
+        discard: LocalTransactionDiscard,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the mints executed by the fungible faucet with the specified ID, excluding those
+    /// of discarded transactions.
+    ///
+    /// The default implementation derives the mints from the output notes of the stored
+    /// transactions.
+    async fn get_faucet_mints(
+        &self,
+        faucet_id: AccountId,
+    ) -> Result<Vec<FaucetMintRecord>, StoreError> {
+        Ok(self
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|tx| {
+                tx.account_id == faucet_id && tx.transaction_status != TransactionStatus::Discarded
+            })
+            .map(|tx| FaucetMintRecord {
+                transaction_id: tx.id,
+                amount: minted_amount(faucet_id, &tx.output_notes),
+            })
+            .filter(|mint| mint.amount > 0)
+            .collect())
+    }
+
+    /// Stores the serialized [ProvenTransaction](miden_objects::transaction::ProvenTransaction)
+    /// of the transaction with the specified ID, replacing any previous one.
+    ///
+    /// The retained proofs are evicted from the least recently used, considering both insertions
+    /// and retrievals, until they take up to `max_total_size` bytes. A proof larger than
+    /// `max_total_size` is not retained.
+    async fn insert_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the serialized proven transaction of the transaction with the specified ID, if
+    /// it's retained, and marks it as recently used.
+    async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<Vec<u8>>, StoreError>;
+
+    /// Records a new attempt to submit the transaction with the specified ID, executed against the
+    /// specified account, and returns the number of attempts recorded for it, including this one.
+    ///
+    /// The recorded attempts are removed along with the account.
+    async fn record_submission_attempt(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+    ) -> Result<u32, StoreError>;
+
+    /// Returns the number of attempts recorded to submit the transaction with the specified ID, or
+    /// 0 if there are none.
+    async fn get_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<u32, StoreError>;
+
+    /// Removes the recorded attempts to submit the transaction with the specified ID, once its
+    /// submission is confirmed.
+    async fn remove_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the transactions that created the note with the specified ID or consumed the
+    /// note with the specified nullifier.
+    ///
+    /// The default implementation scans the output notes and the input note nullifiers of every
+    /// stored transaction.
+    async fn get_note_transactions(
+        &self,
+        note_id: NoteId,
+        nullifier: Option<Nullifier>,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        Ok(self
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|tx| {
+                tx.output_notes.iter().any(|note| note.id() == note_id)
+                    || nullifier.is_some_and(|nullifier| {
+                        tx.input_note_nullifiers.contains(&nullifier.inner())
+                    })
+            })
+            .collect())
+    }
+
+    /// Retrieves the IDs of the faucets that issued assets the account with the specified ID has
+    /// ever held or exchanged, sorted and without duplicates.
+    ///
+    /// The default implementation takes the faucets of the assets in the account's current vault
+    /// and in the notes created or consumed by its non-discarded transactions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
+    async fn get_known_faucets_for_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountId>, StoreError> {
+        let (account, _) = self.get_account(account_id).await?;
+
+        let mut faucets = transaction_note_faucets(self, account_id).await?;
+        faucets.extend(account.vault().assets().map(|asset| asset.faucet_id()));
+        faucets.remove(&account_id);
+
+        Ok(faucets.into_iter().collect())
+    }
+
     // NOTES
     // --------------------------------------------------------------------------------------------
 
@@ -96,6 +242,23 @@ pub trait Store: Send + Sync {
     async fn get_input_notes(&self, filter: NoteFilter)
         -> Result<Vec<InputNoteRecord>, StoreError>;
 
+    /// Retrieves up to `limit` of the input notes matched by the filter, skipping the first
+    /// `offset` ones, along with the total number of notes the filter matches.
+    ///
+    /// The notes are ordered by ID, so the pages are stable as long as the matched notes don't
+    /// change between the calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [StoreError::NoteNotFound] if the filter is [NoteFilter::Unique] and there is no
+    /// Note with the provided ID
+    async fn get_input_notes_page(
+        &self,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), StoreError>;
+
     /// Retrieves the output notes from the store
     ///
     /// # Errors
@@ -107,6 +270,13 @@ pub trait Store: Send + Sync {
         filter: NoteFilter,
     ) -> Result<Vec<OutputNoteRecord>, StoreError>;
 
+    /// Returns the IDs of the input and output notes whose hex representation starts with
+    /// `prefix`. IDs shared by an input and an output note are returned only once.
+    ///
+    /// The prefix is expected to be normalized with
+    /// [normalize_id_prefix](crate::ids::normalize_id_prefix).
+    async fn get_note_ids_by_prefix(&self, prefix: &str) -> Result<Vec<NoteId>, StoreError>;
+
     /// Returns the nullifiers of all unspent input notes
     ///
     /// The default implementation of this method uses [Store::get_input_notes].
@@ -121,10 +291,112 @@ pub trait Store: Send + Sync {
         nullifiers
     }
 
+    /// Retrieves the committed input notes holding fungible assets issued by `faucet_id`, from the
+    /// largest to the smallest amount, until their total amount reaches `min_total`. If all the
+    /// notes together hold less than `min_total`, all of them are returned.
+    ///
+    /// Notes with the same amount are ordered by their ID. The notes are not screened, so they may
+    /// include notes that none of the tracked accounts can consume.
+    ///
+    /// The default implementation of this method uses [Store::get_input_notes].
+    async fn get_consumable_notes_for_faucet(
+        &self,
+        faucet_id: AccountId,
+        min_total: u64,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        let mut notes: Vec<(u64, InputNoteRecord)> = self
+            .get_input_notes(NoteFilter::Committed)
+            .await?
+            .into_iter()
+            .filter_map(|note| Some((fungible_amount(note.assets(), faucet_id)?, note)))
+            .collect();
+        notes.sort_by_cached_key(|(amount, note)| (Reverse(*amount), note.id().to_hex()));
+
+        let mut total = 0u64;
+        Ok(notes
+            .into_iter()
+            .take_while(|(amount, _)| {
+                let needed = total < min_total;
+                total = total.saturating_add(*amount);
+                needed
+            })
+            .map(|(_, note)| note)
+            .collect())
+    }
+
     /// Inserts the provided input notes into the database. If a note with the same ID already
     /// exists, it will be replaced.
     async fn upsert_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError>;
 
+    /// Atomically applies the provided [NoteUpdates] to the store, inserting the new input/output
+    /// notes and updating the tracked ones, and replaces the progress of the partially synced
+    /// block with `progress`. Unlike [Store::apply_state_sync], this does not update the sync
+    /// height nor any chain data.
+    ///
+    /// This is used to persist the notes of a block in batches when a single sync response
+    /// contains more notes than the client is configured to process at once. Storing the notes
+    /// and the progress together makes sure that an interrupted sync neither skips nor repeats a
+    /// batch.
+    async fn apply_note_batch(
+        &self,
+        note_updates: NoteUpdates,
+        progress: PartialSyncProgress,
+    ) -> Result<(), StoreError>;
+
+    /// Moves the provided input notes to the archive, where only their serialized record is kept.
+    /// Archived notes are removed from the input notes and are not returned by any [NoteFilter].
+    async fn archive_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError>;
+
+    /// Retrieves the archived input note with the specified ID, if there is one.
+    async fn get_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, StoreError>;
+
+    /// Moves the archived input note with the specified ID back to the input notes and returns
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [StoreError::NoteNotFound] if there is no archived note with the provided ID.
+    async fn restore_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<InputNoteRecord, StoreError>;
+
+    /// Returns the number of archived input notes.
+    async fn get_archived_input_note_count(&self) -> Result<usize, StoreError>;
+
+    /// Returns the number of input notes in each state, keyed by the state discriminants defined
+    /// by [InputNoteState] (such as [InputNoteState::STATE_EXPECTED]). States without notes are
+    /// not included.
+    ///
+    /// The default implementation of this method uses [Store::get_input_notes].
+    async fn count_input_notes_by_state(&self) -> Result<BTreeMap<u8, usize>, StoreError> {
+        let mut counts = BTreeMap::new();
+        for note in self.get_input_notes(NoteFilter::All).await? {
+            *counts.entry(note.state().discriminant()).or_insert(0) += 1;
+        }
+
+        Ok(counts)
+    }
+
+    /// Retrieves the notes imported by ID that are awaiting their commitment or whose details are
+    /// missing.
+    async fn get_pending_notes(&self) -> Result<Vec<PendingNoteRecord>, StoreError>;
+
+    /// Inserts the provided pending note into the database. If a pending note with the same ID
+    /// already exists, it will be replaced.
+    async fn upsert_pending_note(&self, note: PendingNoteRecord) -> Result<(), StoreError>;
+
+    /// Removes the pending note with the specified ID, if there is one.
+    async fn remove_pending_note(&self, note_id: NoteId) -> Result<(), StoreError>;
+
+    /// Retrieves the links between created notes and the expected notes their consumption
+    /// creates that haven't been resolved yet. Links are inserted by [Store::apply_transaction]
+    /// and removed by [Store::apply_state_sync] when they're resolved.
+    async fn get_future_note_links(&self) -> Result<Vec<FutureNoteLink>, StoreError>;
+
     // CHAIN DATA
     // --------------------------------------------------------------------------------------------
 
@@ -176,6 +448,13 @@ pub trait Store: Send + Sync {
         nodes: &[(InOrderIndex, Digest)],
     ) -> Result<(), StoreError>;
 
+    /// Inserts MMR authentication nodes, overwriting the value of the ones whose [InOrderIndex]
+    /// already exists on the table.
+    async fn replace_chain_mmr_nodes(
+        &self,
+        nodes: &[(InOrderIndex, Digest)],
+    ) -> Result<(), StoreError>;
+
     /// Returns peaks information from the blockchain by a specific block number.
     ///
     /// If there is no chain MMR info stored for the provided block returns an empty [MmrPeaks]
@@ -197,12 +476,33 @@ pub trait Store: Send + Sync {
         has_client_notes: bool,
     ) -> Result<(), StoreError>;
 
+    /// Stores a checkpoint of the chain's [PartialMmr] at `block_num`, replacing the previous one.
+    ///
+    /// The checkpoint is discarded by the store whenever block headers or MMR authentication
+    /// nodes are inserted outside of [Store::apply_state_sync], as the checkpointed MMR might no
+    /// longer match the stored chain data.
+    async fn save_mmr_checkpoint(
+        &self,
+        block_num: u32,
+        partial_mmr: &PartialMmr,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the last checkpoint of the chain's [PartialMmr] along with the block number it
+    /// was taken at, if there's one.
+    async fn load_mmr_checkpoint(&self) -> Result<Option<(u32, PartialMmr)>, StoreError>;
+
     // ACCOUNT
     // --------------------------------------------------------------------------------------------
 
     /// Returns the account IDs of all accounts stored in the database
     async fn get_account_ids(&self) -> Result<Vec<AccountId>, StoreError>;
 
+    /// Returns the IDs of the accounts whose hex representation starts with `prefix`.
+    ///
+    /// The prefix is expected to be normalized with
+    /// [normalize_id_prefix](crate::ids::normalize_id_prefix).
+    async fn get_account_ids_by_prefix(&self, prefix: &str) -> Result<Vec<AccountId>, StoreError>;
+
     /// Returns a list of [AccountHeader] of all accounts stored in the database along with the
     /// seeds used to create them.
     ///
@@ -245,6 +545,16 @@ pub trait Store: Send + Sync {
         account_id: AccountId,
     ) -> Result<(Account, Option<Word>), StoreError>;
 
+    /// Retrieves every stored state of an account, ordered by ascending nonce, along with the
+    /// commitment that was stored for each state.
+    ///
+    /// The account's data is parsed but the commitments are not checked against it, so that
+    /// corrupted states can be detected by the caller.
+    async fn get_account_history(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<(Account, Digest)>, StoreError>;
+
     /// Retrieves an account's [AuthSecretKey] by pub key, utilized to authenticate the account.
     /// This is mainly used for authentication in transactions.
     ///
@@ -261,14 +571,109 @@ pub trait Store: Send + Sync {
     /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
     async fn get_account_auth(&self, account_id: AccountId) -> Result<AuthSecretKey, StoreError>;
 
-    /// Inserts an [Account] along with the seed used to create it and its [AuthSecretKey]
+    /// Stores `auth_info` as a pending [AuthSecretKey] of the account with the specified ID, which
+    /// replaces the account's current key once the transaction with the specified ID is
+    /// committed. Until then, both keys are kept.
+    ///
+    /// Pending keys are also returned by [Store::get_account_auth_by_pub_key], so that the
+    /// transactions executed against the account's updated local state can be authenticated.
+    async fn insert_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+        auth_info: &AuthSecretKey,
+    ) -> Result<(), StoreError>;
+
+    /// Returns the IDs of the transactions with a pending [AuthSecretKey], along with the ID of
+    /// the account whose key they replace.
+    async fn get_pending_account_auths(
+        &self,
+    ) -> Result<Vec<(TransactionId, AccountId)>, StoreError>;
+
+    /// Removes the pending [AuthSecretKey] of the transaction with the specified ID. If
+    /// `committed` is set, the pending key replaces the current key of its account in the same
+    /// write.
+    async fn resolve_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        committed: bool,
+    ) -> Result<(), StoreError>;
+
+    /// Inserts an [Account] along with the seed used to create it and its [AuthSecretKey]. If no
+    /// [AuthSecretKey] is provided, the account is stored as watch-only.
+    ///
+    /// Any reservation of the account's ID made with [Store::reserve_account_id] is removed along
+    /// with the insertion.
     async fn insert_account(
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
+    ) -> Result<(), StoreError>;
+
+    /// Reserves the ID of an account that is being created, so that no other account with the
+    /// same ID can be reserved until the reservation is released with
+    /// [Store::release_account_id] or the account is inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StoreError::AccountIdUnavailable` if the ID is already reserved, or if an
+    /// account or an auth key with the ID is stored.
+    async fn reserve_account_id(&self, account_id: AccountId) -> Result<(), StoreError>;
+
+    /// Releases the reservation of an account ID made with [Store::reserve_account_id]. Releasing
+    /// an ID that isn't reserved has no effect.
+    async fn release_account_id(&self, account_id: AccountId) -> Result<(), StoreError>;
+
+    /// Atomically removes an account from the store. A removal involves:
+    ///
+    /// - Removing the states, key, lock, unlocks and storage slot watches of the account
+    /// - Removing the transactions of the account along with the notes they consumed and created,
+    ///   or marking its pending transactions as `discarded` if its history is kept
+    /// - Removing the tags of the account and of its removed notes
+    async fn remove_account(&self, removal: AccountRemoval) -> Result<(), StoreError>;
+
+    /// Returns the IDs of the [AuthSecretKey]s stored without an account record, excluding the
+    /// ones whose ID is reserved.
+    async fn get_orphaned_account_auth_ids(&self) -> Result<Vec<AccountId>, StoreError>;
+
+    /// Removes the [AuthSecretKey] stored for the account ID if there is no account record or
+    /// reservation for it. Returns whether a key was removed.
+    async fn remove_orphaned_account_auth(&self, account_id: AccountId)
+        -> Result<bool, StoreError>;
+
+    /// Returns the lock status of the account with the specified ID.
+    async fn get_account_lock_status(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountLockStatus, StoreError>;
+
+    /// Sets the lock status of the account with the specified ID.
+    async fn set_account_lock_status(
+        &self,
+        account_id: AccountId,
+        status: AccountLockStatus,
     ) -> Result<(), StoreError>;
 
+    /// Atomically unlocks an account and records the provided audit entry.
+    ///
+    /// If a new state is provided, it replaces the local state of the account (including any
+    /// local states with the same or a higher nonce) and the account becomes
+    /// [AccountLockStatus::Unlocked]. Otherwise the local state is kept and the divergence from
+    /// the remote hash of the record is accepted.
+    async fn apply_account_unlock(
+        &self,
+        record: AccountUnlockRecord,
+        new_state: Option<Account>,
+    ) -> Result<(), StoreError>;
+
+    /// Returns the unlock audit entries of the account with the specified ID, from the oldest to
+    /// the most recent.
+    async fn get_account_unlock_records(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountUnlockRecord>, StoreError>;
+
     // SYNC
     // --------------------------------------------------------------------------------------------
 
@@ -292,6 +697,38 @@ pub trait Store: Send + Sync {
     /// Otherwise returns true.
     async fn remove_note_tag(&self, tag: NoteTagRecord) -> Result<usize, StoreError>;
 
+    /// Stops tracking the `removed` note tag records and starts tracking the `added` ones.
+    ///
+    /// The default implementation removes and adds the records one at a time with
+    /// [Store::remove_note_tag] and [Store::add_note_tag], so it's not atomic.
+    async fn replace_note_tags(
+        &self,
+        removed: Vec<NoteTagRecord>,
+        added: Vec<NoteTagRecord>,
+    ) -> Result<(), StoreError> {
+        for tag_record in removed {
+            self.remove_note_tag(tag_record).await?;
+        }
+        for tag_record in added {
+            self.add_note_tag(tag_record).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the storage slots watched by the client.
+    async fn get_storage_slot_watches(&self) -> Result<Vec<StorageSlotWatch>, StoreError>;
+
+    /// Starts watching a storage slot.
+    ///
+    /// If the slot was already being watched, returns false since no new watch was actually
+    /// added. Otherwise true.
+    async fn add_storage_slot_watch(&self, watch: StorageSlotWatch) -> Result<bool, StoreError>;
+
+    /// Stops watching a storage slot, returning the number of removed watches.
+    async fn remove_storage_slot_watch(&self, watch: StorageSlotWatch)
+        -> Result<usize, StoreError>;
+
     /// Returns the block number of the last state sync block.
     async fn get_sync_height(&self) -> Result<u32, StoreError>;
 
@@ -303,7 +740,63 @@ pub trait Store: Send + Sync {
     /// - Updating transactions in the store, marking as `committed` or `discarded`
     /// - Storing new MMR authentication nodes
     /// - Updating the tracked on-chain accounts
+    /// - Removing the resolved future note links, along with the expected notes of the cancelled
+    ///   ones
+    /// - Removing the progress of the partially synced block, if any
     async fn apply_state_sync(&self, state_sync_update: StateSyncUpdate) -> Result<(), StoreError>;
+
+    /// Atomically wipes the chain data so that the client can sync from the genesis of a new
+    /// chain. A reset involves:
+    ///
+    /// - Removing the block headers, the MMR authentication nodes and the MMR checkpoint
+    /// - Setting the sync height back to 0 and removing the summary of the last sync and the
+    ///   progress of the partially synced block
+    /// - Updating the notes reverted to their expected states and tracking their tags again
+    /// - Marking the transactions as `discarded` and removing the account locks
+    /// - If the accounts aren't kept, removing them along with their keys, transactions, tags and
+    ///   storage slot watches
+    async fn reset_chain_state(&self, reset: ChainStateReset) -> Result<(), StoreError>;
+
+    /// Returns the summary of the last state sync, or `None` if the client was never synced.
+    async fn get_last_sync_summary(&self) -> Result<Option<SyncSummary>, StoreError>;
+
+    /// Replaces the stored summary of the last state sync with the provided one.
+    async fn set_last_sync_summary(&self, summary: SyncSummary) -> Result<(), StoreError>;
+
+    /// Returns the progress of the block whose notes are being applied across several sync
+    /// iterations, or `None` if no block is partially synced.
+    async fn get_partial_sync_progress(&self) -> Result<Option<PartialSyncProgress>, StoreError>;
+
+    /// Replaces the stored progress of the partially synced block, removing it if `progress` is
+    /// `None`.
+    async fn set_partial_sync_progress(
+        &self,
+        progress: Option<PartialSyncProgress>,
+    ) -> Result<(), StoreError>;
+
+    // SNAPSHOTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Opens a [StoreSnapshot] from which the accounts, notes and transactions can be read as
+    /// they are at the moment of the call, regardless of the writes made afterwards.
+    ///
+    /// Reads made through the snapshot after `timeout` has elapsed must fail with
+    /// [StoreError::SnapshotExpired]. The expiration may be checked lazily, on the first read made
+    /// after the timeout, as long as the resources held by the snapshot are released by that read
+    /// or when the snapshot is dropped.
+    async fn read_snapshot(&self, timeout: Duration) -> Result<Box<dyn StoreSnapshot>, StoreError>;
+
+    // FLUSH
+    // --------------------------------------------------------------------------------------------
+
+    /// Makes sure that every write performed so far is durably persisted. Called by
+    /// [Client::shutdown](crate::Client::shutdown) before the client is dropped.
+    ///
+    /// The default implementation does nothing, which is enough for stores that persist each
+    /// write as soon as it's made.
+    async fn flush(&self) -> Result<(), StoreError> {
+        Ok(())
+    }
 }
 
 // CHAIN MMR NODE FILTER
@@ -338,6 +831,9 @@ pub enum TransactionFilter {
 pub enum NoteFilter {
     /// Return a list of all notes ([InputNoteRecord] or [OutputNoteRecord]).
     All,
+    /// Return a list of notes whose metadata has the provided `aux` value. Input notes whose
+    /// metadata is not known yet don't match.
+    Aux(Felt),
     /// Return a list of committed notes ([InputNoteRecord] or [OutputNoteRecord]). These represent
     /// notes that the blockchain has included in a block, and for which we are storing anchor
     /// data.
@@ -345,6 +841,10 @@ pub enum NoteFilter {
     /// Filter by consumed notes ([InputNoteRecord] or [OutputNoteRecord]). notes that have been
     /// used as inputs in transactions.
     Consumed,
+    /// Return a list of the notes created by the transaction with the provided [TransactionId],
+    /// as recorded in the stored transaction. Notes that aren't tracked in the requested table
+    /// are not returned, and neither are any notes if the transaction isn't stored.
+    CreatedByTransaction(TransactionId),
     /// Return a list of expected notes ([InputNoteRecord] or [OutputNoteRecord]). These represent
     /// notes for which the store does not have anchor data.
     Expected,
@@ -355,6 +855,9 @@ pub enum NoteFilter {
     /// Return a list of notes that are currently being processed. This filter doesn't apply to
     /// output notes.
     Processing,
+    /// Return a list of notes whose details turned out to never match the note committed in the
+    /// chain. This filter doesn't apply to output notes.
+    Rejected,
     /// Return a list containing the note that matches with the provided [NoteId]. The query will
     /// return an error if the note is not found.
     Unique(NoteId),
@@ -365,3 +868,50 @@ pub enum NoteFilter {
     /// to output notes.
     Unverified,
 }
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the amount of the fungible asset issued by `faucet_id` among the provided assets, if
+/// there is one.
+pub(crate) fn fungible_amount(assets: &NoteAssets, faucet_id: AccountId) -> Option<u64> {
+    assets.iter().find_map(|asset| match asset {
+        Asset::Fungible(asset) if asset.faucet_id() == faucet_id => Some(asset.amount()),
+        _ => None,
+    })
+}
+
+/// Returns the IDs of the faucets that issued the assets of the notes created or consumed by the
+/// non-discarded transactions of the specified account.
+pub(crate) async fn transaction_note_faucets(
+    store: &(impl Store + ?Sized),
+    account_id: AccountId,
+) -> Result<BTreeSet<AccountId>, StoreError> {
+    let transactions: Vec<TransactionRecord> = store
+        .get_transactions(TransactionFilter::All)
+        .await?
+        .into_iter()
+        .filter(|tx| {
+            tx.account_id == account_id && tx.transaction_status != TransactionStatus::Discarded
+        })
+        .collect();
+
+    let mut faucets: BTreeSet<AccountId> = transactions
+        .iter()
+        .flat_map(|tx| tx.output_notes.iter())
+        .filter_map(|note| note.assets())
+        .flat_map(|assets| assets.iter().map(Asset::faucet_id))
+        .collect();
+
+    let nullifiers: Vec<Nullifier> = transactions
+        .iter()
+        .flat_map(|tx| tx.input_note_nullifiers.iter().copied().map(Nullifier::from))
+        .collect();
+    if !nullifiers.is_empty() {
+        for note in store.get_input_notes(NoteFilter::Nullifiers(nullifiers)).await? {
+            faucets.extend(note.assets().iter().map(Asset::faucet_id));
+        }
+    }
+
+    Ok(faucets)
+}
@@ -8,13 +8,13 @@ use miden_objects::{
     BlockHeader, Digest,
 };
 
-use super::NoteRecordError;
+use super::{NoteConversionError, NoteRecordError};
 
 mod states;
 pub use states::{
     CommittedNoteState, ConsumedAuthenticatedLocalNoteState, ExpectedNoteState, InputNoteState,
     InvalidNoteState, ProcessingAuthenticatedNoteState, ProcessingUnauthenticatedNoteState,
-    UnverifiedNoteState,
+    RejectedNoteState, UnverifiedNoteState,
 };
 
 // INPUT NOTE RECORD
@@ -80,10 +80,16 @@ impl InputNoteRecord {
         self.state.inclusion_proof()
     }
 
+    pub fn created_at(&self) -> Option<u64> {
+        self.created_at
+    }
+
     pub fn details(&self) -> &NoteDetails {
         &self.details
     }
 
+    /// Returns the ID of the local transaction that consumed the note, or that is consuming it if
+    /// the note is being processed. Notes consumed by external transactions don't have one.
     pub fn consumer_transaction_id(&self) -> Option<&TransactionId> {
         self.state.consumer_transaction_id()
     }
@@ -124,6 +130,101 @@ impl InputNoteRecord {
         matches!(self.state, InputNoteState::Committed { .. })
     }
 
+    /// Returns true if the note's details turned out to never match the note committed in the
+    /// chain.
+    pub fn is_rejected(&self) -> bool {
+        matches!(self.state, InputNoteState::Rejected { .. })
+    }
+
+    // CONVERSIONS
+    // ================================================================================================
+
+    /// Returns the [Note] of the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NoteConversionError::NoMetadata] if the metadata of the note isn't known yet.
+    pub fn to_note(&self) -> Result<Note, NoteConversionError> {
+        let metadata = self.metadata().ok_or(NoteConversionError::NoMetadata {
+            note_id: self.id(),
+            state: self.state.code(),
+        })?;
+
+        Ok(Note::new(
+            self.details.assets().clone(),
+            *metadata,
+            self.details.recipient().clone(),
+        ))
+    }
+
+    /// Returns the [NoteDetails] of the record, which are known in every state.
+    pub fn to_note_details(&self) -> NoteDetails {
+        self.details.clone()
+    }
+
+    /// Returns the record as an [InputNote], which is authenticated if the record has a usable
+    /// inclusion proof and unauthenticated otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NoteConversionError::NoMetadata] if the metadata of the note isn't known yet.
+    pub fn as_input_note(&self) -> Result<InputNote, NoteConversionError> {
+        let note = self.to_note()?;
+        match self.usable_inclusion_proof() {
+            Some(inclusion_proof) => Ok(InputNote::authenticated(note, inclusion_proof.clone())),
+            None => Ok(InputNote::unauthenticated(note)),
+        }
+    }
+
+    /// Returns the record as an authenticated [InputNote], along with its inclusion proof.
+    ///
+    /// The proof of [unverified](InputNoteState::Unverified) notes is used as is, so it may not
+    /// have been checked against the chain yet.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [NoteConversionError::NoMetadata] if the metadata of the note isn't known yet.
+    /// - Returns [NoteConversionError::InclusionProofRequired] if the note has no inclusion proof,
+    ///   or if its proof turned out to be [invalid](InputNoteState::Invalid).
+    pub fn as_authenticated_input_note(&self) -> Result<InputNote, NoteConversionError> {
+        let note = self.to_note()?;
+        let inclusion_proof =
+            self.usable_inclusion_proof()
+                .ok_or(NoteConversionError::InclusionProofRequired {
+                    note_id: self.id(),
+                    state: self.state.code(),
+                })?;
+
+        Ok(InputNote::authenticated(note, inclusion_proof.clone()))
+    }
+
+    /// Returns the record as an unauthenticated [InputNote], without an inclusion proof.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [NoteConversionError::NoMetadata] if the metadata of the note isn't known yet.
+    /// - Returns [NoteConversionError::InclusionProofPresent] if the note is
+    ///   [authenticated](InputNoteRecord::is_authenticated).
+    pub fn as_unauthenticated_input_note(&self) -> Result<InputNote, NoteConversionError> {
+        let note = self.to_note()?;
+        if self.is_authenticated() {
+            return Err(NoteConversionError::InclusionProofPresent {
+                note_id: self.id(),
+                state: self.state.code(),
+            });
+        }
+
+        Ok(InputNote::unauthenticated(note))
+    }
+
+    /// Returns the inclusion proof of the note unless it was found to be invalid.
+    fn usable_inclusion_proof(&self) -> Option<&NoteInclusionProof> {
+        match self.state {
+            InputNoteState::Invalid(_) => None,
+            _ => self.inclusion_proof(),
+        }
+    }
+
     // TRANSITIONS
     // ================================================================================================
 
@@ -160,6 +261,29 @@ impl InputNoteRecord {
         }
     }
 
+    /// Modifies the state of the note record to reflect that the node provided the header of the
+    /// block in which the note was committed, along with the note's inclusion proof. Unlike with
+    /// [InputNoteRecord::block_header_received], an inclusion proof that doesn't match the header
+    /// can't be fixed by a later proof, so the note is rejected instead of marked as invalid.
+    /// Returns `true` if the state was changed.
+    pub(crate) fn chain_block_header_received(
+        &mut self,
+        block_header: BlockHeader,
+    ) -> Result<bool, NoteRecordError> {
+        let state_changed = self.block_header_received(block_header)?;
+        if let InputNoteState::Invalid(state) = &self.state {
+            self.state = RejectedNoteState {
+                reason: "the note's commitment doesn't match the one committed in the chain"
+                    .to_string(),
+                block_num: state.invalid_inclusion_proof.location().block_num(),
+            }
+            .into();
+            return Ok(true);
+        }
+
+        Ok(state_changed)
+    }
+
     /// Modifies the state of the note record to reflect that the note has been consumed by an
     /// external transaction. Returns `true` if the state was changed.
     ///
@@ -186,13 +310,17 @@ impl InputNoteRecord {
     }
 
     /// Modifies the state of the note record to reflect that the client began processing the note
-    /// to be consumed. Returns `true` if the state was changed.
+    /// to be consumed, at the provided Unix timestamp if it's known. Returns `true` if the state
+    /// was changed.
     pub(crate) fn consumed_locally(
         &mut self,
         consumer_account: AccountId,
         consumer_transaction: TransactionId,
+        submitted_at: Option<u64>,
     ) -> Result<bool, NoteRecordError> {
-        let new_state = self.state.consumed_locally(consumer_account, consumer_transaction)?;
+        let new_state =
+            self.state
+                .consumed_locally(consumer_account, consumer_transaction, submitted_at)?;
         if let Some(new_state) = new_state {
             self.state = new_state;
             Ok(true)
@@ -216,6 +344,18 @@ impl InputNoteRecord {
             Ok(false)
         }
     }
+
+    /// Modifies the state of the note record to revert its consumption by a local transaction
+    /// that is being discarded. Returns `true` if the state was changed.
+    pub(crate) fn transaction_discarded(&mut self, transaction_id: TransactionId) -> bool {
+        match self.state.transaction_discarded(transaction_id) {
+            Some(new_state) => {
+                self.state = new_state;
+                true
+            },
+            None => false,
+        }
+    }
 }
 
 // SERIALIZATION
@@ -278,24 +418,7 @@ impl TryInto<InputNote> for InputNoteRecord {
     type Error = NoteRecordError;
 
     fn try_into(self) -> Result<InputNote, Self::Error> {
-        match (self.metadata(), self.inclusion_proof()) {
-            (Some(metadata), Some(inclusion_proof)) => Ok(InputNote::authenticated(
-                Note::new(
-                    self.details.assets().clone(),
-                    *metadata,
-                    self.details.recipient().clone(),
-                ),
-                inclusion_proof.clone(),
-            )),
-            (Some(metadata), None) => Ok(InputNote::unauthenticated(Note::new(
-                self.details.assets().clone(),
-                *metadata,
-                self.details.recipient().clone(),
-            ))),
-            _ => Err(NoteRecordError::ConversionError(
-                "Input Note Record does not contain metadata".to_string(),
-            )),
-        }
+        Ok(self.as_input_note()?)
     }
 }
 
@@ -303,16 +426,7 @@ impl TryInto<Note> for InputNoteRecord {
     type Error = NoteRecordError;
 
     fn try_into(self) -> Result<Note, Self::Error> {
-        match self.metadata().cloned() {
-            Some(metadata) => Ok(Note::new(
-                self.details.assets().clone(),
-                metadata,
-                self.details.recipient().clone(),
-            )),
-            None => Err(NoteRecordError::ConversionError(
-                "Input Note Record does not contain metadata".to_string(),
-            )),
-        }
+        Ok(self.to_note()?)
     }
 }
 
@@ -320,16 +434,7 @@ impl TryInto<Note> for &InputNoteRecord {
     type Error = NoteRecordError;
 
     fn try_into(self) -> Result<Note, Self::Error> {
-        match self.metadata().cloned() {
-            Some(metadata) => Ok(Note::new(
-                self.details.assets().clone(),
-                metadata,
-                self.details.recipient().clone(),
-            )),
-            None => Err(NoteRecordError::ConversionError(
-                "Input Note Record does not contain metadata".to_string(),
-            )),
-        }
+        Ok(self.to_note()?)
     }
 }
 
@@ -338,3 +443,224 @@ impl From<InputNoteRecord> for NoteDetails {
         value.details
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec, vec::Vec};
+
+    use miden_lib::transaction::TransactionKernel;
+    use miden_objects::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, AccountId,
+        },
+        crypto::{merkle::MerklePath, rand::RpoRandomCoin},
+        notes::{Note, NoteInclusionProof},
+        testing::notes::NoteBuilder,
+        transaction::{InputNote, TransactionId},
+        Digest, Felt,
+    };
+
+    use super::{
+        states::{
+            CommittedNoteState, ConsumedAuthenticatedLocalNoteState, ConsumedExternalNoteState,
+            ConsumedUnauthenticatedLocalNoteState, ExpectedNoteState, InvalidNoteState,
+            NoteSubmissionData, ProcessingAuthenticatedNoteState,
+            ProcessingUnauthenticatedNoteState, RejectedNoteState, UnverifiedNoteState,
+        },
+        InputNoteRecord, InputNoteState,
+    };
+    use crate::store::{NoteConversionError, NoteStateCode};
+
+    /// Outcome of converting a record into an [InputNote].
+    #[derive(Debug, PartialEq)]
+    enum Conversion {
+        Authenticated,
+        Unauthenticated,
+        Failed(NoteConversionError),
+    }
+
+    impl From<Result<InputNote, NoteConversionError>> for Conversion {
+        fn from(result: Result<InputNote, NoteConversionError>) -> Self {
+            match result {
+                Ok(InputNote::Authenticated { .. }) => Conversion::Authenticated,
+                Ok(InputNote::Unauthenticated { .. }) => Conversion::Unauthenticated,
+                Err(err) => Conversion::Failed(err),
+            }
+        }
+    }
+
+    #[test]
+    fn test_input_note_record_conversions_in_every_state() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let note: Note = NoteBuilder::new(sender, RpoRandomCoin::new([Felt::new(1); 4]))
+            .build(&TransactionKernel::testing_assembler())
+            .unwrap();
+        let note_id = note.id();
+        let metadata = *note.metadata();
+        let inclusion_proof = NoteInclusionProof::new(1, 0, MerklePath::new(vec![])).unwrap();
+        let submission_data = NoteSubmissionData {
+            submitted_at: None,
+            consumer_account: sender,
+            consumer_transaction: TransactionId::from(Digest::default()),
+        };
+
+        let no_metadata =
+            |state| Conversion::Failed(NoteConversionError::NoMetadata { note_id, state });
+        let proof_required = |state| {
+            Conversion::Failed(NoteConversionError::InclusionProofRequired { note_id, state })
+        };
+        let proof_present = |state| {
+            Conversion::Failed(NoteConversionError::InclusionProofPresent { note_id, state })
+        };
+
+        use Conversion::{Authenticated, Unauthenticated};
+        // (state, as_input_note, as_authenticated_input_note, as_unauthenticated_input_note)
+        let cases: Vec<(InputNoteState, Conversion, Conversion, Conversion)> = vec![
+            (
+                ExpectedNoteState {
+                    metadata: Some(metadata),
+                    after_block_num: 0,
+                    tag: Some(metadata.tag()),
+                }
+                .into(),
+                Unauthenticated,
+                proof_required(NoteStateCode::Expected),
+                Unauthenticated,
+            ),
+            (
+                ExpectedNoteState {
+                    metadata: None,
+                    after_block_num: 0,
+                    tag: Some(metadata.tag()),
+                }
+                .into(),
+                no_metadata(NoteStateCode::Expected),
+                no_metadata(NoteStateCode::Expected),
+                no_metadata(NoteStateCode::Expected),
+            ),
+            (
+                UnverifiedNoteState {
+                    metadata,
+                    inclusion_proof: inclusion_proof.clone(),
+                }
+                .into(),
+                Authenticated,
+                Authenticated,
+                Unauthenticated,
+            ),
+            (
+                CommittedNoteState {
+                    metadata,
+                    inclusion_proof: inclusion_proof.clone(),
+                    block_note_root: Digest::default(),
+                }
+                .into(),
+                Authenticated,
+                Authenticated,
+                proof_present(NoteStateCode::Committed),
+            ),
+            (
+                InvalidNoteState {
+                    metadata,
+                    invalid_inclusion_proof: inclusion_proof.clone(),
+                    block_note_root: Digest::default(),
+                }
+                .into(),
+                Unauthenticated,
+                proof_required(NoteStateCode::Invalid),
+                Unauthenticated,
+            ),
+            (
+                ProcessingAuthenticatedNoteState {
+                    metadata,
+                    inclusion_proof: inclusion_proof.clone(),
+                    block_note_root: Digest::default(),
+                    submission_data,
+                }
+                .into(),
+                Authenticated,
+                Authenticated,
+                proof_present(NoteStateCode::Processing),
+            ),
+            (
+                ProcessingUnauthenticatedNoteState {
+                    metadata,
+                    after_block_num: 0,
+                    submission_data,
+                }
+                .into(),
+                Unauthenticated,
+                proof_required(NoteStateCode::Processing),
+                Unauthenticated,
+            ),
+            (
+                ConsumedAuthenticatedLocalNoteState {
+                    metadata,
+                    inclusion_proof: inclusion_proof.clone(),
+                    block_note_root: Digest::default(),
+                    nullifier_block_height: 2,
+                    submission_data,
+                }
+                .into(),
+                Authenticated,
+                Authenticated,
+                proof_present(NoteStateCode::ConsumedLocal),
+            ),
+            (
+                ConsumedUnauthenticatedLocalNoteState {
+                    metadata,
+                    nullifier_block_height: 2,
+                    submission_data,
+                }
+                .into(),
+                Unauthenticated,
+                proof_required(NoteStateCode::ConsumedLocal),
+                Unauthenticated,
+            ),
+            (
+                ConsumedExternalNoteState { nullifier_block_height: 2 }.into(),
+                no_metadata(NoteStateCode::ConsumedExternal),
+                no_metadata(NoteStateCode::ConsumedExternal),
+                no_metadata(NoteStateCode::ConsumedExternal),
+            ),
+            (
+                RejectedNoteState {
+                    reason: "the note's metadata didn't match".to_string(),
+                    block_num: 1,
+                }
+                .into(),
+                no_metadata(NoteStateCode::Rejected),
+                no_metadata(NoteStateCode::Rejected),
+                no_metadata(NoteStateCode::Rejected),
+            ),
+        ];
+
+        for (state, input_note, authenticated, unauthenticated) in cases {
+            let record = InputNoteRecord::new(note.clone().into(), None, state);
+            let code = record.state().code();
+
+            // The details are known in every state
+            assert_eq!(&record.to_note_details(), record.details());
+
+            match &input_note {
+                Conversion::Failed(err) => assert_eq!(record.to_note(), Err(err.clone())),
+                _ => assert_eq!(record.to_note(), Ok(note.clone()), "state {code}"),
+            }
+            assert_eq!(Conversion::from(record.as_input_note()), input_note, "state {code}");
+            assert_eq!(
+                Conversion::from(record.as_authenticated_input_note()),
+                authenticated,
+                "state {code}"
+            );
+            assert_eq!(
+                Conversion::from(record.as_unauthenticated_input_note()),
+                unauthenticated,
+                "state {code}"
+            );
+        }
+    }
+}
@@ -9,7 +9,7 @@ use miden_objects::{
 
 use super::{
     ConsumedExternalNoteState, InputNoteState, NoteStateHandler, NoteSubmissionData,
-    ProcessingUnauthenticatedNoteState, UnverifiedNoteState,
+    ProcessingUnauthenticatedNoteState, RejectedNoteState, UnverifiedNoteState,
 };
 use crate::store::NoteRecordError;
 
@@ -32,6 +32,18 @@ impl NoteStateHandler for ExpectedNoteState {
         inclusion_proof: NoteInclusionProof,
         metadata: NoteMetadata,
     ) -> Result<Option<InputNoteState>, NoteRecordError> {
+        // A note committed with other metadata than the expected one will never match the details
+        if self.metadata.is_some_and(|expected_metadata| expected_metadata != metadata) {
+            return Ok(Some(
+                RejectedNoteState {
+                    reason: "the note was committed with different metadata than expected"
+                        .to_string(),
+                    block_num: inclusion_proof.location().block_num(),
+                }
+                .into(),
+            ));
+        }
+
         Ok(Some(UnverifiedNoteState { metadata, inclusion_proof }.into()))
     }
 
@@ -56,6 +68,7 @@ impl NoteStateHandler for ExpectedNoteState {
         &self,
         consumer_account: AccountId,
         consumer_transaction: TransactionId,
+        submitted_at: Option<u64>,
     ) -> Result<Option<InputNoteState>, NoteRecordError> {
         match self.metadata {
             None => Err(NoteRecordError::NoteNotConsumable(
@@ -63,7 +76,7 @@ impl NoteStateHandler for ExpectedNoteState {
             )),
             Some(metadata) => {
                 let submission_data = NoteSubmissionData {
-                    submitted_at: None,
+                    submitted_at,
                     consumer_account,
                     consumer_transaction,
                 };
@@ -20,6 +20,7 @@ mod expected;
 mod invalid;
 mod processing_authenticated;
 mod processing_unauthenticated;
+mod rejected;
 mod unverified;
 
 pub use committed::CommittedNoteState;
@@ -30,9 +31,10 @@ pub use expected::ExpectedNoteState;
 pub use invalid::InvalidNoteState;
 pub use processing_authenticated::ProcessingAuthenticatedNoteState;
 pub use processing_unauthenticated::ProcessingUnauthenticatedNoteState;
+pub use rejected::RejectedNoteState;
 pub use unverified::UnverifiedNoteState;
 
-use super::NoteRecordError;
+use super::{NoteRecordError, NoteStateCode};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum InputNoteState {
@@ -54,6 +56,9 @@ pub enum InputNoteState {
     ConsumedUnauthenticatedLocal(ConsumedUnauthenticatedLocalNoteState),
     /// Note consumed in chain by an external account (e.g. an account not tracked by the client).
     ConsumedExternal(ConsumedExternalNoteState),
+    /// Note whose details don't match the note committed in the chain with the same ID or tag.
+    /// Rejected notes can't be committed or consumed anymore.
+    Rejected(RejectedNoteState),
 }
 
 impl InputNoteState {
@@ -66,6 +71,7 @@ impl InputNoteState {
     pub const STATE_CONSUMED_AUTHENTICATED_LOCAL: u8 = 6;
     pub const STATE_CONSUMED_UNAUTHENTICATED_LOCAL: u8 = 7;
     pub const STATE_CONSUMED_EXTERNAL: u8 = 8;
+    pub const STATE_REJECTED: u8 = 9;
 
     /// Returns the inner state handler that implements state transitions.
     fn inner(&self) -> &dyn NoteStateHandler {
@@ -79,6 +85,7 @@ impl InputNoteState {
             InputNoteState::ConsumedAuthenticatedLocal(inner) => inner,
             InputNoteState::ConsumedUnauthenticatedLocal(inner) => inner,
             InputNoteState::ConsumedExternal(inner) => inner,
+            InputNoteState::Rejected(inner) => inner,
         }
     }
 
@@ -90,7 +97,9 @@ impl InputNoteState {
         self.inner().inclusion_proof()
     }
 
-    pub(crate) fn consumer_transaction_id(&self) -> Option<&TransactionId> {
+    /// Returns the ID of the local transaction that is consuming or consumed the note, if there
+    /// is one.
+    pub fn consumer_transaction_id(&self) -> Option<&TransactionId> {
         self.inner().consumer_transaction_id()
     }
 
@@ -110,6 +119,24 @@ impl InputNoteState {
                 Self::STATE_CONSUMED_UNAUTHENTICATED_LOCAL
             },
             InputNoteState::ConsumedExternal(_) => Self::STATE_CONSUMED_EXTERNAL,
+            InputNoteState::Rejected(_) => Self::STATE_REJECTED,
+        }
+    }
+
+    /// Returns the stable [NoteStateCode] of the state. The `Display` output of the state keeps
+    /// the details of the state, like the blocks in which the note was committed or consumed.
+    pub fn code(&self) -> NoteStateCode {
+        match self {
+            InputNoteState::Expected(_) => NoteStateCode::Expected,
+            InputNoteState::Unverified(_) => NoteStateCode::Unverified,
+            InputNoteState::Committed(_) => NoteStateCode::Committed,
+            InputNoteState::Invalid(_) => NoteStateCode::Invalid,
+            InputNoteState::ProcessingAuthenticated(_)
+            | InputNoteState::ProcessingUnauthenticated(_) => NoteStateCode::Processing,
+            InputNoteState::ConsumedAuthenticatedLocal(_)
+            | InputNoteState::ConsumedUnauthenticatedLocal(_) => NoteStateCode::ConsumedLocal,
+            InputNoteState::ConsumedExternal(_) => NoteStateCode::ConsumedExternal,
+            InputNoteState::Rejected(_) => NoteStateCode::Rejected,
         }
     }
 
@@ -151,8 +178,10 @@ impl InputNoteState {
         &self,
         consumer_account: AccountId,
         consumer_transaction: TransactionId,
+        submitted_at: Option<u64>,
     ) -> Result<Option<InputNoteState>, NoteRecordError> {
-        self.inner().consumed_locally(consumer_account, consumer_transaction)
+        self.inner()
+            .consumed_locally(consumer_account, consumer_transaction, submitted_at)
     }
 
     /// Returns a new state to reflect that the transaction currently consuming the note was
@@ -164,6 +193,39 @@ impl InputNoteState {
     ) -> Result<Option<InputNoteState>, NoteRecordError> {
         self.inner().transaction_committed(transaction_id, block_height)
     }
+
+    /// Returns the state the note had before being consumed by the specified local transaction,
+    /// which is being discarded. Authenticated notes go back to being committed, while
+    /// unauthenticated notes go back to being expected, as their inclusion proofs weren't
+    /// verified. If the note isn't being consumed by the transaction, `None` is returned.
+    pub(crate) fn transaction_discarded(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Option<InputNoteState> {
+        if self.consumer_transaction_id() != Some(&transaction_id) {
+            return None;
+        }
+
+        match self {
+            InputNoteState::ProcessingAuthenticated(state) => Some(
+                CommittedNoteState {
+                    metadata: state.metadata,
+                    inclusion_proof: state.inclusion_proof.clone(),
+                    block_note_root: state.block_note_root,
+                }
+                .into(),
+            ),
+            InputNoteState::ProcessingUnauthenticated(state) => Some(
+                ExpectedNoteState {
+                    metadata: Some(state.metadata),
+                    after_block_num: state.after_block_num,
+                    tag: Some(state.metadata.tag()),
+                }
+                .into(),
+            ),
+            _ => None,
+        }
+    }
 }
 
 impl Serializable for InputNoteState {
@@ -179,6 +241,7 @@ impl Serializable for InputNoteState {
             InputNoteState::ConsumedAuthenticatedLocal(inner) => inner.write_into(target),
             InputNoteState::ConsumedUnauthenticatedLocal(inner) => inner.write_into(target),
             InputNoteState::ConsumedExternal(inner) => inner.write_into(target),
+            InputNoteState::Rejected(inner) => inner.write_into(target),
         }
     }
 }
@@ -206,6 +269,7 @@ impl Deserializable for InputNoteState {
             Self::STATE_CONSUMED_EXTERNAL => {
                 Ok(ConsumedExternalNoteState::read_from(source)?.into())
             },
+            Self::STATE_REJECTED => Ok(RejectedNoteState::read_from(source)?.into()),
             _ => Err(DeserializationError::InvalidValue(format!(
                 "Invalid NoteState discriminant: {}",
                 discriminant
@@ -286,6 +350,9 @@ impl Display for InputNoteState {
             InputNoteState::ConsumedExternal(state) => {
                 write!(f, "Consumed (at block {})", state.nullifier_block_height)
             },
+            InputNoteState::Rejected(state) => {
+                write!(f, "Rejected (at block {}: {})", state.block_num, state.reason)
+            },
         }
     }
 }
@@ -318,6 +385,7 @@ pub trait NoteStateHandler {
         &self,
         consumer_account: AccountId,
         consumer_transaction: TransactionId,
+        submitted_at: Option<u64>,
     ) -> Result<Option<InputNoteState>, NoteRecordError>;
 
     fn transaction_committed(
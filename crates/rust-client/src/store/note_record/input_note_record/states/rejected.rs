@@ -0,0 +1,99 @@
+use alloc::string::{String, ToString};
+
+use miden_objects::{
+    notes::{NoteId, NoteInclusionProof, NoteMetadata},
+    transaction::TransactionId,
+    BlockHeader,
+};
+
+use super::{InputNoteState, NoteStateHandler};
+use crate::store::NoteRecordError;
+
+/// Terminal state of notes whose stored details can never match the note committed in the chain,
+/// for example because the recipient was computed with the wrong inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RejectedNoteState {
+    /// Why the note can't match the committed note.
+    pub reason: String,
+    /// Number of the block in which the mismatching note was committed.
+    pub block_num: u32,
+}
+
+impl NoteStateHandler for RejectedNoteState {
+    fn inclusion_proof_received(
+        &self,
+        _inclusion_proof: NoteInclusionProof,
+        _metadata: NoteMetadata,
+    ) -> Result<Option<InputNoteState>, NoteRecordError> {
+        Ok(None)
+    }
+
+    fn consumed_externally(
+        &self,
+        _nullifier_block_height: u32,
+    ) -> Result<Option<InputNoteState>, NoteRecordError> {
+        Ok(None)
+    }
+
+    fn block_header_received(
+        &self,
+        _note_id: NoteId,
+        _block_header: BlockHeader,
+    ) -> Result<Option<InputNoteState>, NoteRecordError> {
+        Ok(None)
+    }
+
+    fn consumed_locally(
+        &self,
+        _consumer_account: miden_objects::accounts::AccountId,
+        _consumer_transaction: miden_objects::transaction::TransactionId,
+        _submitted_at: Option<u64>,
+    ) -> Result<Option<InputNoteState>, NoteRecordError> {
+        Err(NoteRecordError::NoteNotConsumable("Can't consume rejected note".to_string()))
+    }
+
+    fn transaction_committed(
+        &self,
+        _transaction_id: TransactionId,
+        _block_height: u32,
+    ) -> Result<Option<InputNoteState>, NoteRecordError> {
+        Err(NoteRecordError::InvalidStateTransition(
+            "Only processing notes can be committed in a local transaction".to_string(),
+        ))
+    }
+
+    fn metadata(&self) -> Option<&NoteMetadata> {
+        None
+    }
+
+    fn inclusion_proof(&self) -> Option<&NoteInclusionProof> {
+        None
+    }
+
+    fn consumer_transaction_id(&self) -> Option<&TransactionId> {
+        None
+    }
+}
+
+impl miden_tx::utils::Serializable for RejectedNoteState {
+    fn write_into<W: miden_tx::utils::ByteWriter>(&self, target: &mut W) {
+        self.reason.write_into(target);
+        self.block_num.write_into(target);
+    }
+}
+
+impl miden_tx::utils::Deserializable for RejectedNoteState {
+    fn read_from<R: miden_tx::utils::ByteReader>(
+        source: &mut R,
+    ) -> Result<Self, miden_tx::utils::DeserializationError> {
+        let reason = String::read_from(source)?;
+        let block_num = u32::read_from(source)?;
+        Ok(RejectedNoteState { reason, block_num })
+    }
+}
+
+impl From<RejectedNoteState> for InputNoteState {
+    fn from(state: RejectedNoteState) -> Self {
+        InputNoteState::Rejected(state)
+    }
+}
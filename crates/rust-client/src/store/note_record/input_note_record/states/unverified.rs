@@ -76,9 +76,10 @@ impl NoteStateHandler for UnverifiedNoteState {
         &self,
         consumer_account: miden_objects::accounts::AccountId,
         consumer_transaction: miden_objects::transaction::TransactionId,
+        submitted_at: Option<u64>,
     ) -> Result<Option<InputNoteState>, NoteRecordError> {
         let submission_data = NoteSubmissionData {
-            submitted_at: None,
+            submitted_at,
             consumer_account,
             consumer_transaction,
         };
@@ -31,7 +31,7 @@
 use alloc::string::{String, ToString};
 use core::fmt;
 
-use miden_objects::NoteError;
+use miden_objects::{notes::NoteId, NoteError};
 
 mod input_note_record;
 mod output_note_record;
@@ -42,9 +42,92 @@ pub mod input_note_states {
     pub use super::input_note_record::{
         CommittedNoteState, ConsumedAuthenticatedLocalNoteState, ExpectedNoteState,
         InvalidNoteState, ProcessingAuthenticatedNoteState, ProcessingUnauthenticatedNoteState,
+        RejectedNoteState,
     };
 }
 
+// NOTE STATE CODE
+// ================================================================================================
+
+/// Stable, coarse-grained code for the state of an input or output note, as returned by
+/// [InputNoteState::code] and [OutputNoteState::code].
+///
+/// Unlike the `Display` output of the note states, which includes state details like block
+/// numbers, codes are meant to be logged, serialized and matched on by other tools. The string of
+/// a code never changes once released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoteStateCode {
+    /// The note is expected to be committed on chain.
+    Expected,
+    /// The note was committed, but its inclusion proof wasn't verified yet.
+    Unverified,
+    /// The note was committed on chain.
+    Committed,
+    /// The note is being consumed by a transaction submitted by the client.
+    Processing,
+    /// The note was consumed by a transaction of the client.
+    ConsumedLocal,
+    /// The note was consumed by an account not tracked by the client.
+    ConsumedExternal,
+    /// The note was consumed, without the client knowing by whom. Only used for output notes.
+    Consumed,
+    /// The inclusion proof of the note was invalid.
+    Invalid,
+    /// The details of the note don't match the note committed on chain.
+    Rejected,
+}
+
+impl NoteStateCode {
+    /// Every note state code, in lifecycle order.
+    pub const ALL: [NoteStateCode; 9] = [
+        NoteStateCode::Expected,
+        NoteStateCode::Unverified,
+        NoteStateCode::Committed,
+        NoteStateCode::Processing,
+        NoteStateCode::ConsumedLocal,
+        NoteStateCode::ConsumedExternal,
+        NoteStateCode::Consumed,
+        NoteStateCode::Invalid,
+        NoteStateCode::Rejected,
+    ];
+
+    /// Returns the machine-readable string of the code.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteStateCode::Expected => "expected",
+            NoteStateCode::Unverified => "unverified",
+            NoteStateCode::Committed => "committed",
+            NoteStateCode::Processing => "processing",
+            NoteStateCode::ConsumedLocal => "consumed_local",
+            NoteStateCode::ConsumedExternal => "consumed_external",
+            NoteStateCode::Consumed => "consumed",
+            NoteStateCode::Invalid => "invalid",
+            NoteStateCode::Rejected => "rejected",
+        }
+    }
+
+    /// Returns a label of the code to display to users.
+    pub fn human_label(&self) -> &'static str {
+        match self {
+            NoteStateCode::Expected => "Expected",
+            NoteStateCode::Unverified => "Unverified",
+            NoteStateCode::Committed => "Committed",
+            NoteStateCode::Processing => "Processing",
+            NoteStateCode::ConsumedLocal => "Consumed (local)",
+            NoteStateCode::ConsumedExternal => "Consumed (external)",
+            NoteStateCode::Consumed => "Consumed",
+            NoteStateCode::Invalid => "Invalid",
+            NoteStateCode::Rejected => "Rejected",
+        }
+    }
+}
+
+impl fmt::Display for NoteStateCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 // NOTE RECORD ERROR
 // ================================================================================================
 
@@ -90,3 +173,50 @@ impl From<NoteRecordError> for String {
         err.to_string()
     }
 }
+
+impl From<NoteConversionError> for NoteRecordError {
+    fn from(err: NoteConversionError) -> Self {
+        NoteRecordError::ConversionError(err.to_string())
+    }
+}
+
+// NOTE CONVERSION ERROR
+// ================================================================================================
+
+/// Errors generated when an [InputNoteRecord] is missing the data needed to convert it into a
+/// [Note](miden_objects::notes::Note) or an [InputNote](miden_objects::transaction::InputNote).
+///
+/// Each variant keeps the state the note was in, which tells how the missing data can be obtained.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteConversionError {
+    /// The metadata of the note isn't known yet. This is the case for notes imported from their
+    /// details only, until a sync finds them committed.
+    NoMetadata { note_id: NoteId, state: NoteStateCode },
+    /// An authenticated input note was requested, but the note has no inclusion proof.
+    InclusionProofRequired { note_id: NoteId, state: NoteStateCode },
+    /// An unauthenticated input note was requested, but the note is authenticated, so it has to
+    /// be used along with its inclusion proof.
+    InclusionProofPresent { note_id: NoteId, state: NoteStateCode },
+}
+
+impl fmt::Display for NoteConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteConversionError::NoMetadata { note_id, state } => write!(
+                f,
+                "Note {note_id} has no metadata in state {state}, sync the client until the note \
+                 is committed or import it along with its metadata"
+            ),
+            NoteConversionError::InclusionProofRequired { note_id, state } => write!(
+                f,
+                "Note {note_id} has no inclusion proof in state {state}, sync the client until the \
+                 note is committed or import it along with its proof"
+            ),
+            NoteConversionError::InclusionProofPresent { note_id, state } => write!(
+                f,
+                "Note {note_id} is authenticated in state {state}, it has to be used with its \
+                 inclusion proof"
+            ),
+        }
+    }
+}
@@ -11,7 +11,7 @@ use miden_objects::{
 };
 use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
-use super::NoteRecordError;
+use super::{NoteRecordError, NoteStateCode};
 
 // OUTPUT NOTE RECORD
 // ================================================================================================
@@ -37,6 +37,8 @@ pub struct OutputNoteRecord {
     state: OutputNoteState,
     /// The expected block height at which the note should be included in the chain.
     expected_height: u32,
+    /// Timestamp of the moment the transaction that created the note was applied, if known.
+    created_at: Option<u64>,
 }
 
 impl OutputNoteRecord {
@@ -46,6 +48,7 @@ impl OutputNoteRecord {
         metadata: NoteMetadata,
         state: OutputNoteState,
         expected_height: u32,
+        created_at: Option<u64>,
     ) -> OutputNoteRecord {
         OutputNoteRecord {
             recipient_digest,
@@ -53,6 +56,7 @@ impl OutputNoteRecord {
             state,
             metadata,
             expected_height,
+            created_at,
         }
     }
 
@@ -98,6 +102,25 @@ impl OutputNoteRecord {
         self.expected_height
     }
 
+    pub fn created_at(&self) -> Option<u64> {
+        self.created_at
+    }
+
+    /// Returns the number of the block in which the note was committed, if it was committed.
+    pub fn committed_at_block(&self) -> Option<u32> {
+        self.state.committed_at_block()
+    }
+
+    /// Returns the timestamp of the block in which the note was committed, if it's known.
+    pub fn committed_timestamp(&self) -> Option<u64> {
+        self.state.committed_timestamp()
+    }
+
+    /// Returns the number of the block in which the note was nullified, if it was consumed.
+    pub fn consumed_at_block(&self) -> Option<u32> {
+        self.state.consumed_at_block()
+    }
+
     /// Returns true if the note has been nullified on chain.
     pub fn is_consumed(&self) -> bool {
         matches!(self.state, OutputNoteState::Consumed { .. })
@@ -118,8 +141,10 @@ impl OutputNoteRecord {
     pub(crate) fn inclusion_proof_received(
         &mut self,
         inclusion_proof: NoteInclusionProof,
+        committed_timestamp: u64,
     ) -> Result<bool, NoteRecordError> {
-        let new_state = self.state.inclusion_proof_received(inclusion_proof)?;
+        let new_state =
+            self.state.inclusion_proof_received(inclusion_proof, committed_timestamp)?;
         if let Some(new_state) = new_state {
             self.state = new_state;
             Ok(true)
@@ -151,6 +176,40 @@ impl OutputNoteRecord {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for OutputNoteRecord {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.recipient_digest.write_into(target);
+        self.assets.write_into(target);
+        self.metadata.write_into(target);
+        self.expected_height.write_into(target);
+        self.created_at.write_into(target);
+        self.state.write_into(target);
+    }
+}
+
+impl Deserializable for OutputNoteRecord {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let recipient_digest = Digest::read_from(source)?;
+        let assets = NoteAssets::read_from(source)?;
+        let metadata = NoteMetadata::read_from(source)?;
+        let expected_height = u32::read_from(source)?;
+        let created_at = Option::<u64>::read_from(source)?;
+        let state = OutputNoteState::read_from(source)?;
+
+        Ok(OutputNoteRecord {
+            recipient_digest,
+            assets,
+            metadata,
+            state,
+            expected_height,
+            created_at,
+        })
+    }
+}
+
 // CONVERSIONS
 // ================================================================================================
 
@@ -165,6 +224,7 @@ impl OutputNoteRecord {
             metadata: *header.metadata(),
             state: OutputNoteState::ExpectedFull { recipient },
             expected_height,
+            created_at: None,
         }
     }
 
@@ -175,6 +235,7 @@ impl OutputNoteRecord {
             metadata: *partial_note.metadata(),
             state: OutputNoteState::ExpectedPartial,
             expected_height,
+            created_at: None,
         }
     }
 
@@ -182,6 +243,9 @@ impl OutputNoteRecord {
     /// [OutputNote::Full] or [OutputNote::Partial] and always fail the conversion if it's
     /// [OutputNote::Header]. This also mean that `output_note.try_from()` can also be used as a way
     /// to filter the full and partial output notes
+    ///
+    /// The creation timestamp of the record is left empty, it can be set with
+    /// [OutputNoteRecord::with_created_at].
     pub fn try_from_output_note(
         output_note: OutputNote,
         expected_height: u32,
@@ -196,6 +260,14 @@ impl OutputNoteRecord {
     }
 }
 
+impl OutputNoteRecord {
+    /// Returns the record with its creation timestamp set to `created_at`.
+    pub fn with_created_at(mut self, created_at: Option<u64>) -> Self {
+        self.created_at = created_at;
+        self
+    }
+}
+
 impl TryFrom<OutputNoteRecord> for NoteDetails {
     type Error = NoteRecordError;
     fn try_from(value: OutputNoteRecord) -> Result<Self, Self::Error> {
@@ -288,6 +360,9 @@ pub enum OutputNoteState {
     CommittedPartial {
         /// Inclusion proof for the note inside the chain block.
         inclusion_proof: NoteInclusionProof,
+        /// Timestamp of the block in which the note was committed. Unknown for notes committed
+        /// before it was tracked.
+        committed_timestamp: Option<u64>,
     },
     /// Note with known recipient has been committed on chain, and can be consumed in a
     /// transaction.
@@ -296,6 +371,9 @@ pub enum OutputNoteState {
         recipient: NoteRecipient,
         /// Inclusion proof for the note inside the chain block.
         inclusion_proof: NoteInclusionProof,
+        /// Timestamp of the block in which the note was committed. Unknown for notes committed
+        /// before it was tracked.
+        committed_timestamp: Option<u64>,
     },
     /// Note has been nullified on chain.
     Consumed {
@@ -303,6 +381,11 @@ pub enum OutputNoteState {
         block_height: u32,
         /// Details needed to consume the note.
         recipient: NoteRecipient,
+        /// Block height at which the note was committed, if the client saw the note committed
+        /// before it was nullified.
+        committed_at_block: Option<u32>,
+        /// Timestamp of the block in which the note was committed, if it's known.
+        committed_timestamp: Option<u64>,
     },
 }
 
@@ -324,6 +407,21 @@ impl OutputNoteState {
         }
     }
 
+    /// Returns the stable [NoteStateCode] of the state. Whether the recipient of the note is known
+    /// doesn't affect the code, and consumed output notes use [NoteStateCode::Consumed] as the
+    /// client doesn't know which account consumed them.
+    pub fn code(&self) -> NoteStateCode {
+        match self {
+            OutputNoteState::ExpectedPartial | OutputNoteState::ExpectedFull { .. } => {
+                NoteStateCode::Expected
+            },
+            OutputNoteState::CommittedPartial { .. } | OutputNoteState::CommittedFull { .. } => {
+                NoteStateCode::Committed
+            },
+            OutputNoteState::Consumed { .. } => NoteStateCode::Consumed,
+        }
+    }
+
     pub fn recipient(&self) -> Option<&NoteRecipient> {
         match self {
             OutputNoteState::ExpectedFull { recipient, .. } => Some(recipient),
@@ -341,21 +439,56 @@ impl OutputNoteState {
         }
     }
 
+    /// Returns the number of the block in which the note was committed, if it's known.
+    pub fn committed_at_block(&self) -> Option<u32> {
+        match self {
+            OutputNoteState::CommittedPartial { inclusion_proof, .. }
+            | OutputNoteState::CommittedFull { inclusion_proof, .. } => {
+                Some(inclusion_proof.location().block_num())
+            },
+            OutputNoteState::Consumed { committed_at_block, .. } => *committed_at_block,
+            _ => None,
+        }
+    }
+
+    /// Returns the timestamp of the block in which the note was committed, if it's known.
+    pub fn committed_timestamp(&self) -> Option<u64> {
+        match self {
+            OutputNoteState::CommittedPartial { committed_timestamp, .. }
+            | OutputNoteState::CommittedFull { committed_timestamp, .. }
+            | OutputNoteState::Consumed { committed_timestamp, .. } => *committed_timestamp,
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the block in which the note was nullified, if it was consumed.
+    pub fn consumed_at_block(&self) -> Option<u32> {
+        match self {
+            OutputNoteState::Consumed { block_height, .. } => Some(*block_height),
+            _ => None,
+        }
+    }
+
     pub fn inclusion_proof_received(
         &self,
         inclusion_proof: NoteInclusionProof,
+        committed_timestamp: u64,
     ) -> Result<Option<OutputNoteState>, NoteRecordError> {
+        let committed_timestamp = Some(committed_timestamp);
         match self {
             OutputNoteState::ExpectedPartial { .. } => {
-                Ok(Some(OutputNoteState::CommittedPartial { inclusion_proof }))
+                Ok(Some(OutputNoteState::CommittedPartial { inclusion_proof, committed_timestamp }))
             },
             OutputNoteState::ExpectedFull { recipient, .. } => {
                 Ok(Some(OutputNoteState::CommittedFull {
                     recipient: recipient.clone(),
                     inclusion_proof,
+                    committed_timestamp,
                 }))
             },
-            OutputNoteState::CommittedPartial { inclusion_proof: prev_inclusion_proof }
+            OutputNoteState::CommittedPartial {
+                inclusion_proof: prev_inclusion_proof, ..
+            }
             | OutputNoteState::CommittedFull {
                 inclusion_proof: prev_inclusion_proof, ..
             } => {
@@ -382,6 +515,8 @@ impl OutputNoteState {
                 Ok(Some(OutputNoteState::Consumed {
                     block_height,
                     recipient: recipient.clone(),
+                    committed_at_block: self.committed_at_block(),
+                    committed_timestamp: self.committed_timestamp(),
                 }))
             },
             OutputNoteState::ExpectedPartial { .. } | OutputNoteState::CommittedPartial { .. } => {
@@ -401,16 +536,29 @@ impl Serializable for OutputNoteState {
             OutputNoteState::ExpectedFull { recipient } => {
                 recipient.write_into(target);
             },
-            OutputNoteState::CommittedPartial { inclusion_proof } => {
+            OutputNoteState::CommittedPartial { inclusion_proof, committed_timestamp } => {
                 inclusion_proof.write_into(target);
+                committed_timestamp.write_into(target);
             },
-            OutputNoteState::CommittedFull { recipient, inclusion_proof } => {
+            OutputNoteState::CommittedFull {
+                recipient,
+                inclusion_proof,
+                committed_timestamp,
+            } => {
                 recipient.write_into(target);
                 inclusion_proof.write_into(target);
+                committed_timestamp.write_into(target);
             },
-            OutputNoteState::Consumed { block_height, recipient } => {
+            OutputNoteState::Consumed {
+                block_height,
+                recipient,
+                committed_at_block,
+                committed_timestamp,
+            } => {
                 block_height.write_into(target);
                 recipient.write_into(target);
+                committed_at_block.write_into(target);
+                committed_timestamp.write_into(target);
             },
         }
     }
@@ -427,17 +575,30 @@ impl Deserializable for OutputNoteState {
             },
             Self::STATE_COMMITTED_PARTIAL => {
                 let inclusion_proof = NoteInclusionProof::read_from(source)?;
-                Ok(OutputNoteState::CommittedPartial { inclusion_proof })
+                let committed_timestamp = Option::<u64>::read_from(source)?;
+                Ok(OutputNoteState::CommittedPartial { inclusion_proof, committed_timestamp })
             },
             Self::STATE_COMMITTED_FULL => {
                 let recipient = NoteRecipient::read_from(source)?;
                 let inclusion_proof = NoteInclusionProof::read_from(source)?;
-                Ok(OutputNoteState::CommittedFull { recipient, inclusion_proof })
+                let committed_timestamp = Option::<u64>::read_from(source)?;
+                Ok(OutputNoteState::CommittedFull {
+                    recipient,
+                    inclusion_proof,
+                    committed_timestamp,
+                })
             },
             Self::STATE_CONSUMED => {
                 let block_height = source.read_u32()?;
                 let recipient = NoteRecipient::read_from(source)?;
-                Ok(OutputNoteState::Consumed { block_height, recipient })
+                let committed_at_block = Option::<u32>::read_from(source)?;
+                let committed_timestamp = Option::<u64>::read_from(source)?;
+                Ok(OutputNoteState::Consumed {
+                    block_height,
+                    recipient,
+                    committed_at_block,
+                    committed_timestamp,
+                })
             },
             _ => Err(DeserializationError::InvalidValue("OutputNoteState".to_string())),
         }
@@ -453,7 +614,7 @@ impl Display for OutputNoteState {
             OutputNoteState::ExpectedFull { .. } => {
                 write!(f, "Expected Full")
             },
-            OutputNoteState::CommittedPartial { inclusion_proof } => {
+            OutputNoteState::CommittedPartial { inclusion_proof, .. } => {
                 write!(
                     f,
                     "Committed Partial (at block height {})",
@@ -0,0 +1,68 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::time::Duration;
+
+use async_trait::async_trait;
+use miden_objects::{accounts::AccountHeader, crypto::rand::FeltRng, Word};
+
+use super::{InputNoteRecord, NoteFilter, StoreError, TransactionFilter};
+use crate::{transactions::TransactionRecord, Client, ClientError};
+
+/// Time a [StoreSnapshot] opened with [Client::read_snapshot] can be read from before expiring.
+pub const DEFAULT_SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(30);
+
+// STORE SNAPSHOT
+// ================================================================================================
+
+/// Consistent read-only view of a [Store](super::Store), opened with
+/// [Store::read_snapshot](super::Store::read_snapshot).
+///
+/// Every read made through the snapshot returns the data as it was when the snapshot was opened,
+/// even if the store is written to in the meantime, so a group of queries never observes the
+/// store in the middle of a sync or a transaction. Snapshots are meant to be short-lived: once
+/// their timeout elapses, reads fail with [StoreError::SnapshotExpired].
+///
+/// The timeout is only checked when the snapshot is read. The resources held by the snapshot are
+/// released by the first read made after the timeout or when the snapshot is dropped, so a
+/// snapshot that is kept around without being read holds them until it's dropped.
+#[async_trait(?Send)]
+pub trait StoreSnapshot {
+    /// Returns the [AccountHeader] of every account, along with its seed if the account is new,
+    /// as of the snapshot.
+    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError>;
+
+    /// Returns the input notes that match the filter as of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [StoreError::NoteNotFound] if the filter is [NoteFilter::Unique] and there is no
+    /// note with the provided ID.
+    async fn get_input_notes(&self, filter: NoteFilter)
+        -> Result<Vec<InputNoteRecord>, StoreError>;
+
+    /// Returns the transactions that match the filter as of the snapshot.
+    async fn get_transactions(
+        &self,
+        filter: TransactionFilter,
+    ) -> Result<Vec<TransactionRecord>, StoreError>;
+}
+
+impl<R: FeltRng> Client<R> {
+    // STORE SNAPSHOTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Opens a [StoreSnapshot] that gives a consistent view of the client's accounts, notes and
+    /// transactions across several reads, even if the client syncs in the meantime.
+    ///
+    /// The snapshot expires after [DEFAULT_SNAPSHOT_TIMEOUT].
+    pub async fn read_snapshot(&self) -> Result<Box<dyn StoreSnapshot>, ClientError> {
+        self.read_snapshot_with_timeout(DEFAULT_SNAPSHOT_TIMEOUT).await
+    }
+
+    /// Opens a [StoreSnapshot] like [Client::read_snapshot] that expires after `timeout`.
+    pub async fn read_snapshot_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Box<dyn StoreSnapshot>, ClientError> {
+        self.store.read_snapshot(timeout).await.map_err(|err| err.into())
+    }
+}
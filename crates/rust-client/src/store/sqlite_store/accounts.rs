@@ -1,4 +1,5 @@
 use alloc::{
+    collections::BTreeSet,
     string::{String, ToString},
     vec::Vec,
 };
@@ -6,13 +7,19 @@ use alloc::{
 use miden_objects::{
     accounts::{Account, AccountCode, AccountHeader, AccountId, AccountStorage, AuthSecretKey},
     assets::{Asset, AssetVault},
+    transaction::TransactionId,
     Digest, Felt, Word,
 };
 use miden_tx::utils::{Deserializable, Serializable};
 use rusqlite::{params, Connection, Transaction};
+use tracing::info;
 
-use super::SqliteStore;
-use crate::store::StoreError;
+use super::{notes::DELETE_NOTE_ASSETS_QUERY, sync::remove_note_tag_tx, SqliteStore};
+use crate::{
+    accounts::{AccountLockStatus, AccountRemoval, AccountUnlockRecord},
+    store::StoreError,
+    transactions::DiscardCause,
+};
 
 // TYPES
 // ================================================================================================
@@ -47,6 +54,39 @@ impl SqliteStore {
             .collect::<Result<Vec<AccountId>, StoreError>>()
     }
 
+    /// Retrieves the IDs of the accounts starting with the provided prefix.
+    pub(super) fn get_account_ids_by_prefix(
+        conn: &mut Connection,
+        prefix: &str,
+    ) -> Result<Vec<AccountId>, StoreError> {
+        let digits = prefix.trim_start_matches("0x");
+        if digits.is_empty() {
+            return Self::get_account_ids(conn);
+        }
+        if digits.len() > 16 {
+            return Ok(Vec::new());
+        }
+
+        // IDs are stored as integers, so the prefix is turned into the range of IDs starting with
+        // it, which can be looked up with the primary key index. Casting the bounds to `i64` keeps
+        // the range ordered, as all the IDs in it share the leading hex digit and thus the sign.
+        let prefix_value = u64::from_str_radix(digits, 16).map_err(|err| {
+            StoreError::QueryError(format!("invalid account ID prefix {prefix}: {err}"))
+        })?;
+        let free_bits = 4 * (16 - digits.len() as u32);
+        let range_start = prefix_value << free_bits;
+        let range_end = range_start | u64::MAX.checked_shr(64 - free_bits).unwrap_or(0);
+
+        const QUERY: &str = "SELECT DISTINCT id FROM accounts WHERE id BETWEEN ? AND ?";
+        conn.prepare(QUERY)?
+            .query_map(params![range_start as i64, range_end as i64], |row| row.get(0))?
+            .map(|result| {
+                Ok(result
+                    .map(|id: i64| AccountId::try_from(id as u64).expect("account id is valid"))?)
+            })
+            .collect::<Result<Vec<AccountId>, StoreError>>()
+    }
+
     pub(super) fn get_account_headers(
         conn: &mut Connection,
     ) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError> {
@@ -121,6 +161,60 @@ impl SqliteStore {
         Ok((account, account_seed))
     }
 
+    pub(crate) fn get_account_history(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<Vec<(Account, Digest)>, StoreError> {
+        let account_id_int: u64 = account_id.into();
+        const QUERY: &str = "SELECT accounts.id, accounts.nonce, accounts.account_seed, account_code.code, account_storage.slots, account_vaults.assets, accounts.account_hash \
+                            FROM accounts \
+                            JOIN account_code ON accounts.code_root = account_code.root \
+                            JOIN account_storage ON accounts.storage_root = account_storage.root \
+                            JOIN account_vaults ON accounts.vault_root = account_vaults.root \
+                            WHERE accounts.id = ? \
+                            ORDER BY accounts.nonce ASC";
+
+        conn.prepare(QUERY)?
+            .query_map(params![account_id_int as i64], |row| {
+                Ok((parse_account_columns(row)?, row.get::<_, String>(6)?))
+            })?
+            .map(|result| {
+                let (account_parts, account_hash) = result?;
+                let (account, _seed) = parse_account(account_parts)?;
+                Ok((account, Digest::try_from(&account_hash)?))
+            })
+            .collect()
+    }
+
+    /// Retrieves the IDs of the faucets that issued the assets held by any of the stored states
+    /// of the account.
+    pub(crate) fn get_account_vault_faucets(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<BTreeSet<AccountId>, StoreError> {
+        let account_id_int: u64 = account_id.into();
+        const QUERY: &str = "SELECT DISTINCT account_vaults.assets \
+                            FROM accounts \
+                            JOIN account_vaults ON accounts.vault_root = account_vaults.root \
+                            WHERE accounts.id = ?";
+
+        let vaults = conn
+            .prepare(QUERY)?
+            .query_map(params![account_id_int as i64], |row| row.get::<_, Vec<u8>>(0))?
+            .collect::<Result<Vec<Vec<u8>>, _>>()?;
+        if vaults.is_empty() {
+            return Err(StoreError::AccountDataNotFound(account_id));
+        }
+
+        let mut faucets = BTreeSet::new();
+        for assets in vaults {
+            let assets = Vec::<Asset>::read_from_bytes(&assets)?;
+            faucets.extend(assets.iter().map(Asset::faucet_id));
+        }
+
+        Ok(faucets)
+    }
+
     /// Retrieve account keys data by Account Id
     pub(crate) fn get_account_auth(
         conn: &mut Connection,
@@ -135,11 +229,60 @@ impl SqliteStore {
             .ok_or(StoreError::AccountDataNotFound(account_id))?
     }
 
+    pub(crate) fn insert_pending_account_auth(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+        auth_info: &AuthSecretKey,
+    ) -> Result<(), StoreError> {
+        let (account_id, auth_info, pub_key) = serialize_account_auth(account_id, auth_info)?;
+        const QUERY: &str = "INSERT OR REPLACE INTO pending_account_auth (transaction_id, account_id, auth_info, pub_key) VALUES (?, ?, ?, ?)";
+
+        conn.execute(
+            QUERY,
+            params![transaction_id.inner().to_string(), account_id, auth_info, pub_key],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn get_pending_account_auths(
+        conn: &mut Connection,
+    ) -> Result<Vec<(TransactionId, AccountId)>, StoreError> {
+        const QUERY: &str = "SELECT transaction_id, account_id FROM pending_account_auth";
+        conn.prepare(QUERY)?
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))?
+            .map(|result| {
+                let (transaction_id, account_id) = result?;
+                let transaction_id: Digest = transaction_id.try_into()?;
+                Ok((transaction_id.into(), AccountId::try_from(account_id as u64)?))
+            })
+            .collect()
+    }
+
+    pub(crate) fn resolve_pending_account_auth(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+        committed: bool,
+    ) -> Result<(), StoreError> {
+        const REPLACE_QUERY: &str = "INSERT OR REPLACE INTO account_auth (account_id, auth_info, pub_key) SELECT account_id, auth_info, pub_key FROM pending_account_auth WHERE transaction_id = ?";
+        const DELETE_QUERY: &str = "DELETE FROM pending_account_auth WHERE transaction_id = ?";
+
+        let transaction_id = transaction_id.inner().to_string();
+        let tx = conn.transaction()?;
+        if committed {
+            tx.execute(REPLACE_QUERY, params![transaction_id])?;
+        }
+        tx.execute(DELETE_QUERY, params![transaction_id])?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
     pub(crate) fn insert_account(
         conn: &mut Connection,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         let tx = conn.transaction()?;
 
@@ -147,20 +290,253 @@ impl SqliteStore {
         insert_account_storage(&tx, account.storage())?;
         insert_account_asset_vault(&tx, account.vault())?;
         insert_account_record(&tx, account, account_seed)?;
-        insert_account_auth(&tx, account.id(), auth_info)?;
+        if let Some(auth_info) = auth_info {
+            insert_account_auth(&tx, account.id(), auth_info)?;
+        }
+
+        const QUERY: &str = "DELETE FROM account_reservations WHERE account_id = ?";
+        tx.execute(QUERY, params![u64::from(account.id()) as i64])?;
 
         Ok(tx.commit()?)
     }
 
+    // ACCOUNT RESERVATIONS
+    // --------------------------------------------------------------------------------------------
+
+    pub(crate) fn reserve_account_id(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<(), StoreError> {
+        // A single statement, so that two connections can't both see the ID as free
+        const QUERY: &str = "INSERT OR IGNORE INTO account_reservations (account_id) SELECT ?1 \
+            WHERE NOT EXISTS (SELECT 1 FROM accounts WHERE id = ?1) \
+            AND NOT EXISTS (SELECT 1 FROM account_auth WHERE account_id = ?1)";
+
+        let inserted = conn.execute(QUERY, params![u64::from(account_id) as i64])?;
+        if inserted == 0 {
+            return Err(StoreError::AccountIdUnavailable(account_id));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn release_account_id(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "DELETE FROM account_reservations WHERE account_id = ?";
+        conn.execute(QUERY, params![u64::from(account_id) as i64])?;
+        Ok(())
+    }
+
+    pub(crate) fn get_orphaned_account_auth_ids(
+        conn: &mut Connection,
+    ) -> Result<Vec<AccountId>, StoreError> {
+        const QUERY: &str = "SELECT account_id FROM account_auth \
+            WHERE account_id NOT IN (SELECT id FROM accounts) \
+            AND account_id NOT IN (SELECT account_id FROM account_reservations)";
+
+        conn.prepare(QUERY)?
+            .query_map([], |row| row.get(0))?
+            .map(|result| {
+                let id: i64 = result?;
+                Ok(AccountId::try_from(id as u64)?)
+            })
+            .collect()
+    }
+
+    pub(crate) fn remove_orphaned_account_auth(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<bool, StoreError> {
+        const QUERY: &str = "DELETE FROM account_auth WHERE account_id = ?1 \
+            AND NOT EXISTS (SELECT 1 FROM accounts WHERE id = ?1) \
+            AND NOT EXISTS (SELECT 1 FROM account_reservations WHERE account_id = ?1)";
+
+        let removed = conn.execute(QUERY, params![u64::from(account_id) as i64])?;
+        Ok(removed > 0)
+    }
+
+    pub(crate) fn remove_account(
+        conn: &mut Connection,
+        removal: AccountRemoval,
+    ) -> Result<(), StoreError> {
+        const DELETE_ACCOUNT_QUERIES: [&str; 7] = [
+            "DELETE FROM accounts WHERE id = ?",
+            "DELETE FROM account_auth WHERE account_id = ?",
+            "DELETE FROM pending_account_auth WHERE account_id = ?",
+            "DELETE FROM account_locks WHERE account_id = ?",
+            "DELETE FROM account_unlocks WHERE account_id = ?",
+            "DELETE FROM storage_slot_watches WHERE account_id = ?",
+            "DELETE FROM transaction_submissions WHERE account_id = ?",
+        ];
+        const DELETE_TRANSACTION_QUERIES: [&str; 4] = [
+            "DELETE FROM faucet_mints WHERE transaction_id = ?",
+            "DELETE FROM transaction_notes WHERE transaction_id = ?",
+            "DELETE FROM proven_transactions WHERE transaction_id = ?",
+            "DELETE FROM transactions WHERE id = ?",
+        ];
+        const DELETE_INPUT_NOTE_QUERY: &str = "DELETE FROM input_notes WHERE note_id = ?";
+        const DELETE_OUTPUT_NOTE_QUERY: &str = "DELETE FROM output_notes WHERE note_id = ?";
+
+        let AccountRemoval {
+            account_id,
+            removed_transaction_ids,
+            discarded_transaction_ids,
+            removed_input_note_ids,
+            removed_output_note_ids,
+            tags_to_remove,
+        } = removal;
+
+        let tx = conn.transaction()?;
+
+        let account_id = u64::from(account_id) as i64;
+        for query in DELETE_ACCOUNT_QUERIES {
+            tx.execute(query, params![account_id])?;
+        }
+
+        for transaction_id in removed_transaction_ids {
+            let transaction_id = transaction_id.inner().to_string();
+            for query in DELETE_TRANSACTION_QUERIES {
+                tx.execute(query, params![transaction_id])?;
+            }
+        }
+        Self::mark_transactions_as_discarded(
+            &tx,
+            &discarded_transaction_ids,
+            DiscardCause::AccountRemoved,
+        )?;
+
+        for note_id in removed_input_note_ids {
+            let note_id = note_id.inner().to_string();
+            tx.execute(DELETE_INPUT_NOTE_QUERY, params![note_id])?;
+            tx.execute(DELETE_NOTE_ASSETS_QUERY, params![note_id])?;
+        }
+        for note_id in removed_output_note_ids {
+            tx.execute(DELETE_OUTPUT_NOTE_QUERY, params![note_id.inner().to_string()])?;
+        }
+
+        for tag in tags_to_remove {
+            remove_note_tag_tx(&tx, tag)?;
+        }
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    // ACCOUNT LOCKS
+    // --------------------------------------------------------------------------------------------
+
+    pub(crate) fn get_account_lock_status(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<AccountLockStatus, StoreError> {
+        const QUERY: &str =
+            "SELECT remote_hash, divergence_accepted FROM account_locks WHERE account_id = ?";
+
+        conn.prepare(QUERY)?
+            .query_map(params![u64::from(account_id) as i64], |row| {
+                let remote_hash: String = row.get(0)?;
+                let divergence_accepted: bool = row.get(1)?;
+                Ok((remote_hash, divergence_accepted))
+            })?
+            .map(|result| {
+                let (remote_hash, divergence_accepted) = result?;
+                let remote_hash = Digest::try_from(&remote_hash)?;
+                Ok(if divergence_accepted {
+                    AccountLockStatus::DivergenceAccepted { remote_hash }
+                } else {
+                    AccountLockStatus::Locked { remote_hash }
+                })
+            })
+            .next()
+            .unwrap_or(Ok(AccountLockStatus::Unlocked))
+    }
+
+    pub(crate) fn set_account_lock_status(
+        conn: &mut Connection,
+        account_id: AccountId,
+        status: AccountLockStatus,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction()?;
+        set_account_lock_status_tx(&tx, account_id, status)?;
+        Ok(tx.commit()?)
+    }
+
+    pub(crate) fn apply_account_unlock(
+        conn: &mut Connection,
+        record: &AccountUnlockRecord,
+        new_state: Option<&Account>,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction()?;
+
+        let status = match new_state {
+            Some(account) => {
+                overwrite_account_state(&tx, account)?;
+                AccountLockStatus::Unlocked
+            },
+            None => AccountLockStatus::DivergenceAccepted { remote_hash: record.remote_hash },
+        };
+        set_account_lock_status_tx(&tx, record.account_id, status)?;
+
+        const QUERY: &str = "INSERT INTO account_unlocks \
+            (account_id, strategy, local_hash, remote_hash, block_num) VALUES (?, ?, ?, ?, ?)";
+        tx.execute(
+            QUERY,
+            params![
+                u64::from(record.account_id) as i64,
+                record.strategy.to_string(),
+                record.local_hash.to_string(),
+                record.remote_hash.to_string(),
+                record.block_num,
+            ],
+        )?;
+
+        Ok(tx.commit()?)
+    }
+
+    pub(crate) fn get_account_unlock_records(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountUnlockRecord>, StoreError> {
+        const QUERY: &str = "SELECT strategy, local_hash, remote_hash, block_num \
+            FROM account_unlocks WHERE account_id = ? ORDER BY rowid";
+
+        conn.prepare(QUERY)?
+            .query_map(params![u64::from(account_id) as i64], |row| {
+                let strategy: String = row.get(0)?;
+                let local_hash: String = row.get(1)?;
+                let remote_hash: String = row.get(2)?;
+                let block_num: u32 = row.get(3)?;
+                Ok((strategy, local_hash, remote_hash, block_num))
+            })?
+            .map(|result| {
+                let (strategy, local_hash, remote_hash, block_num) = result?;
+                Ok(AccountUnlockRecord {
+                    account_id,
+                    strategy: strategy.parse().map_err(StoreError::ParsingError)?,
+                    local_hash: Digest::try_from(&local_hash)?,
+                    remote_hash: Digest::try_from(&remote_hash)?,
+                    block_num,
+                })
+            })
+            .collect()
+    }
+
     /// Returns an [AuthSecretKey] by a public key represented by a [Word]
     pub fn get_account_auth_by_pub_key(
         conn: &mut Connection,
         pub_key: Word,
     ) -> Result<AuthSecretKey, StoreError> {
         let pub_key_bytes = pub_key.to_bytes();
-        const QUERY: &str = "SELECT account_id, auth_info FROM account_auth WHERE pub_key = ?";
+        // Keys of pending rotations are also returned, as the local state of the account already
+        // uses them
+        const QUERY: &str = "SELECT account_id, auth_info FROM account_auth WHERE pub_key = ?
+            UNION ALL
+            SELECT account_id, auth_info FROM pending_account_auth WHERE pub_key = ?";
         conn.prepare(QUERY)?
-            .query_map(params![pub_key_bytes], parse_account_auth_columns)?
+            .query_map(params![pub_key_bytes, pub_key_bytes], parse_account_auth_columns)?
             .map(|result| Ok(result?).and_then(parse_account_auth))
             .next()
             .ok_or(StoreError::AccountKeyNotFound(pub_key))?
@@ -184,6 +560,45 @@ pub(crate) fn update_account(
     insert_account_record(tx, new_account_state, None)
 }
 
+/// Replaces the local state of an account with the provided one, removing the stored states with
+/// the same or a higher nonce.
+fn overwrite_account_state(tx: &Transaction<'_>, account: &Account) -> Result<(), StoreError> {
+    const QUERY: &str = "DELETE FROM accounts WHERE id = ? AND nonce >= ?";
+    tx.execute(QUERY, params![u64::from(account.id()) as i64, account.nonce().as_int() as i64])?;
+
+    insert_account_code(tx, account.code())?;
+    update_account(tx, account)
+}
+
+/// Sets the lock status of an account, removing its lock entry if it's unlocked.
+fn set_account_lock_status_tx(
+    tx: &Transaction<'_>,
+    account_id: AccountId,
+    status: AccountLockStatus,
+) -> Result<(), StoreError> {
+    let account_id = u64::from(account_id) as i64;
+    match status {
+        AccountLockStatus::Unlocked => {
+            tx.execute("DELETE FROM account_locks WHERE account_id = ?", params![account_id])?;
+        },
+        AccountLockStatus::Locked { remote_hash }
+        | AccountLockStatus::DivergenceAccepted { remote_hash } => {
+            const QUERY: &str = "INSERT OR REPLACE INTO account_locks \
+                (account_id, remote_hash, divergence_accepted) VALUES (?, ?, ?)";
+            tx.execute(
+                QUERY,
+                params![
+                    account_id,
+                    remote_hash.to_string(),
+                    matches!(status, AccountLockStatus::DivergenceAccepted { .. })
+                ],
+            )?;
+        },
+    }
+
+    Ok(())
+}
+
 pub(super) fn insert_account_record(
     tx: &Transaction<'_>,
     account: &Account,
@@ -202,6 +617,78 @@ pub(super) fn insert_account_record(
     Ok(())
 }
 
+/// Creates the `account_reservations` table in stores created before account IDs were reserved
+/// during account creation.
+pub(super) fn create_account_reservations_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'account_reservations'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the account_reservations table");
+    conn.execute_batch(
+        "CREATE TABLE account_reservations (
+            account_id UNSIGNED BIG INT NOT NULL,
+            PRIMARY KEY (account_id)
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the account_locks and account_unlocks tables in databases created before accounts
+/// could be locked. Accounts of existing databases start unlocked.
+pub(super) fn create_account_lock_tables(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'account_unlocks'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the account_locks and account_unlocks tables");
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS account_locks (
+            account_id UNSIGNED BIG INT NOT NULL,
+            remote_hash TEXT NOT NULL,
+            divergence_accepted BOOLEAN NOT NULL,
+            PRIMARY KEY (account_id)
+        );
+        CREATE TABLE IF NOT EXISTS account_unlocks (
+            account_id UNSIGNED BIG INT NOT NULL,
+            strategy TEXT NOT NULL,
+            local_hash TEXT NOT NULL,
+            remote_hash TEXT NOT NULL,
+            block_num UNSIGNED BIG INT NOT NULL
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the `pending_account_auth` table in databases created before key rotations kept the
+/// new keys pending until their transactions are committed.
+pub(super) fn create_pending_account_auth_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'pending_account_auth'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the pending_account_auth table");
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS pending_account_auth (
+            transaction_id TEXT NOT NULL,
+            account_id UNSIGNED BIG INT NOT NULL,
+            auth_info BLOB NOT NULL,
+            pub_key BLOB NOT NULL,
+            PRIMARY KEY (transaction_id)
+        );",
+    )?;
+
+    Ok(())
+}
+
 /// Inserts an [AccountCode]
 fn insert_account_code(tx: &Transaction<'_>, account_code: &AccountCode) -> Result<(), StoreError> {
     let (code_root, code) = serialize_account_code(account_code)?;
@@ -397,7 +884,13 @@ pub(super) fn parse_account_columns(
 #[cfg(test)]
 mod tests {
     use miden_objects::{
-        accounts::{AccountCode, AccountComponent, AccountId},
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountCode, AccountComponent, AccountId,
+        },
         crypto::dsa::rpo_falcon512::SecretKey,
         testing::account_component::BASIC_WALLET_CODE,
     };
@@ -406,7 +899,7 @@ mod tests {
     use super::{insert_account_auth, AuthSecretKey};
     use crate::store::{
         sqlite_store::{accounts::insert_account_code, tests::create_test_store},
-        Store,
+        Store, StoreError,
     };
 
     #[tokio::test]
@@ -499,4 +992,56 @@ mod tests {
             },
         }
     }
+
+    #[tokio::test]
+    async fn test_orphaned_account_auth_cleanup() {
+        let store = create_test_store().await;
+
+        let orphan_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let reserved_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        store
+            .interact_with_connection(move |conn| {
+                let tx = conn.transaction().unwrap();
+                for account_id in [orphan_id, reserved_id] {
+                    insert_account_auth(
+                        &tx,
+                        account_id,
+                        &AuthSecretKey::RpoFalcon512(SecretKey::new()),
+                    )
+                    .unwrap();
+                }
+                tx.commit().unwrap();
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        // IDs with a stored key can't be reserved for new accounts
+        assert!(matches!(
+            store.reserve_account_id(orphan_id).await,
+            Err(StoreError::AccountIdUnavailable(_))
+        ));
+
+        // Keys of reserved IDs belong to accounts that are being created
+        store
+            .interact_with_connection(move |conn| {
+                conn.execute(
+                    "INSERT INTO account_reservations (account_id) VALUES (?)",
+                    [u64::from(reserved_id) as i64],
+                )?;
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(store.get_orphaned_account_auth_ids().await.unwrap(), vec![orphan_id]);
+        assert!(!store.remove_orphaned_account_auth(reserved_id).await.unwrap());
+        assert!(store.remove_orphaned_account_auth(orphan_id).await.unwrap());
+
+        assert!(store.get_orphaned_account_auth_ids().await.unwrap().is_empty());
+        assert!(Store::get_account_auth(&store, orphan_id).await.is_err());
+        assert!(Store::get_account_auth(&store, reserved_id).await.is_ok());
+    }
 }
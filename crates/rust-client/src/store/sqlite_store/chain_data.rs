@@ -2,13 +2,14 @@ use alloc::{collections::BTreeMap, rc::Rc, string::String, vec::Vec};
 use std::num::NonZeroUsize;
 
 use miden_objects::{
-    crypto::merkle::{InOrderIndex, MmrPeaks},
+    crypto::merkle::{InOrderIndex, MmrPeaks, PartialMmr},
     BlockHeader, Digest,
 };
 use miden_tx::utils::{Deserializable, Serializable};
 use rusqlite::{
     params, params_from_iter, types::Value, Connection, OptionalExtension, Transaction,
 };
+use tracing::info;
 
 use super::SqliteStore;
 use crate::store::{ChainMmrNodeFilter, StoreError};
@@ -44,6 +45,7 @@ impl SqliteStore {
         let tx = conn.transaction()?;
 
         Self::insert_block_header_tx(&tx, block_header, chain_mmr_peaks, has_client_notes)?;
+        discard_mmr_checkpoint(&tx)?;
 
         tx.commit()?;
         Ok(())
@@ -124,10 +126,55 @@ impl SqliteStore {
         let tx = conn.transaction()?;
 
         Self::insert_chain_mmr_nodes_tx(&tx, nodes)?;
+        discard_mmr_checkpoint(&tx)?;
 
         Ok(tx.commit().map(|_| ())?)
     }
 
+    pub(crate) fn replace_chain_mmr_nodes(
+        conn: &mut Connection,
+        nodes: &[(InOrderIndex, Digest)],
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction()?;
+
+        const QUERY: &str = "INSERT OR REPLACE INTO chain_mmr_nodes (id, node) VALUES (?, ?)";
+        for (index, node) in nodes {
+            let (id, node) = serialize_chain_mmr_node(*index, *node)?;
+            tx.execute(QUERY, params![id, node])?;
+        }
+        discard_mmr_checkpoint(&tx)?;
+
+        Ok(tx.commit().map(|_| ())?)
+    }
+
+    pub(crate) fn save_mmr_checkpoint(
+        conn: &mut Connection,
+        block_num: u32,
+        partial_mmr: &PartialMmr,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction()?;
+
+        discard_mmr_checkpoint(&tx)?;
+        const QUERY: &str = "INSERT INTO mmr_checkpoint (block_num, partial_mmr) VALUES (?, ?)";
+        tx.execute(QUERY, params![block_num, partial_mmr.to_bytes()])?;
+
+        Ok(tx.commit().map(|_| ())?)
+    }
+
+    pub(crate) fn load_mmr_checkpoint(
+        conn: &mut Connection,
+    ) -> Result<Option<(u32, PartialMmr)>, StoreError> {
+        const QUERY: &str = "SELECT block_num, partial_mmr FROM mmr_checkpoint";
+
+        conn.prepare(QUERY)?
+            .query_row([], |row| Ok((row.get::<_, u32>(0)?, row.get::<_, Vec<u8>>(1)?)))
+            .optional()?
+            .map(|(block_num, partial_mmr)| {
+                Ok((block_num, PartialMmr::read_from_bytes(&partial_mmr)?))
+            })
+            .transpose()
+    }
+
     /// Inserts a list of MMR authentication nodes to the Chain MMR nodes table.
     pub(crate) fn insert_chain_mmr_nodes_tx(
         tx: &Transaction<'_>,
@@ -166,6 +213,12 @@ impl SqliteStore {
 // HELPERS
 // ================================================================================================
 
+/// Removes the stored MMR checkpoint, if any.
+fn discard_mmr_checkpoint(tx: &Transaction<'_>) -> Result<(), StoreError> {
+    tx.execute("DELETE FROM mmr_checkpoint", [])?;
+    Ok(())
+}
+
 /// Inserts a node represented by its in-order index and the node value.
 fn insert_chain_mmr_node(
     tx: &Transaction<'_>,
@@ -256,6 +309,26 @@ fn set_block_header_has_client_notes(
     Ok(())
 }
 
+/// Creates the MMR checkpoint table in databases created before it was introduced.
+pub(super) fn create_mmr_checkpoint_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'mmr_checkpoint'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the mmr_checkpoint table");
+    conn.execute_batch(
+        "CREATE TABLE mmr_checkpoint (
+            block_num UNSIGNED BIG INT NOT NULL,
+            partial_mmr BLOB NOT NULL,
+            PRIMARY KEY (block_num)
+        );",
+    )?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use alloc::vec::Vec;
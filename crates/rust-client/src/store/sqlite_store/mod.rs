@@ -3,26 +3,34 @@ use alloc::{
     collections::{BTreeMap, BTreeSet},
     vec::Vec,
 };
+use core::time::Duration;
 use std::{path::Path, string::ToString};
 
 use deadpool_sqlite::{Config, Hook, HookError, Pool, Runtime};
 use miden_objects::{
     accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
-    crypto::merkle::{InOrderIndex, MmrPeaks},
-    notes::{NoteTag, Nullifier},
+    crypto::merkle::{InOrderIndex, MmrPeaks, PartialMmr},
+    notes::{NoteId, NoteTag, Nullifier},
+    transaction::TransactionId,
     BlockHeader, Digest, Word,
 };
 use rusqlite::{vtab::array, Connection};
 use tonic::async_trait;
 
-use self::config::SqliteStoreConfig;
+use self::{config::SqliteStoreConfig, snapshot::SqliteSnapshot};
 use super::{
-    ChainMmrNodeFilter, InputNoteRecord, NoteFilter, OutputNoteRecord, Store, TransactionFilter,
+    transaction_note_faucets, ChainMmrNodeFilter, InputNoteRecord, NoteFilter, OutputNoteRecord,
+    Store, StoreSnapshot, TransactionFilter,
 };
 use crate::{
+    accounts::{AccountLockStatus, AccountRemoval, AccountUnlockRecord, FaucetMintRecord},
+    notes::{FutureNoteLink, NoteUpdates, PendingNoteRecord},
     store::StoreError,
-    sync::{NoteTagRecord, StateSyncUpdate},
-    transactions::{TransactionRecord, TransactionStoreUpdate},
+    sync::{
+        ChainStateReset, NoteTagRecord, PartialSyncProgress, StateSyncUpdate, StorageSlotWatch,
+        SyncSummary,
+    },
+    transactions::{LocalTransactionDiscard, TransactionRecord, TransactionStoreUpdate},
 };
 
 mod accounts;
@@ -30,6 +38,7 @@ mod chain_data;
 pub mod config;
 mod errors;
 mod notes;
+mod snapshot;
 mod sync;
 mod transactions;
 
@@ -66,6 +75,14 @@ impl SqliteStore {
                         .await
                         .map_err(|_| HookError::message("Loading rarray module failed"))?;
 
+                    // In WAL mode, the read transactions held by snapshots don't block writers
+                    conn.interact(|conn| {
+                        conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))
+                    })
+                    .await
+                    .map_err(|_| HookError::message("Enabling WAL mode failed"))?
+                    .map_err(|_| HookError::message("Enabling WAL mode failed"))?;
+
                     Ok(())
                 })
             }))
@@ -79,6 +96,13 @@ impl SqliteStore {
                 .interact(|conn| conn.execute_batch(include_str!("store.sql")))
                 .await
                 .map_err(|err| StoreError::DatabaseError(err.to_string()))??;
+        } else {
+            pool.get()
+                .await
+                .map_err(|err| StoreError::DatabaseError(err.to_string()))?
+                .interact(migrate)
+                .await
+                .map_err(|err| StoreError::DatabaseError(err.to_string()))??;
         }
 
         Ok(Self { pool })
@@ -105,6 +129,38 @@ impl SqliteStore {
     }
 }
 
+/// Brings databases created by previous versions of the client up to date with `store.sql`.
+fn migrate(conn: &mut Connection) -> Result<(), StoreError> {
+    accounts::create_account_reservations_table(conn)?;
+    accounts::create_account_lock_tables(conn)?;
+    accounts::create_pending_account_auth_table(conn)?;
+    chain_data::create_mmr_checkpoint_table(conn)?;
+    transactions::add_provenance_columns(conn)?;
+    transactions::add_discard_cause_column(conn)?;
+    transactions::create_faucet_mints_table(conn)?;
+    transactions::create_transaction_notes_table(conn)?;
+    transactions::create_proven_transactions_table(conn)?;
+    transactions::create_transaction_submissions_table(conn)?;
+    notes::create_archived_input_notes_table(conn)?;
+    notes::create_input_note_assets_table(conn)?;
+    notes::create_pending_notes_table(conn)?;
+    notes::create_future_note_links_table(conn)?;
+    notes::add_note_aux_columns(conn)?;
+    notes::add_output_note_timestamps(conn)?;
+    sync::add_last_sync_summary_column(conn)?;
+    sync::add_partial_sync_progress_column(conn)?;
+    sync::add_tag_expiry_column(conn)?;
+    sync::add_tag_subscription_columns(conn)?;
+    sync::create_storage_slot_watches_table(conn)
+}
+
+/// Moves the content of the write-ahead log into the database file and truncates the log. Pages
+/// still needed by an open snapshot are left in the log.
+fn checkpoint_wal(conn: &mut Connection) -> Result<(), StoreError> {
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+    Ok(())
+}
+
 // SQLite implementation of the Store trait
 //
 // To simplify, all implementations rely on inner SqliteStore functions that map 1:1 by name
@@ -129,6 +185,36 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn replace_note_tags(
+        &self,
+        removed: Vec<NoteTagRecord>,
+        added: Vec<NoteTagRecord>,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::replace_note_tags(conn, &removed, &added)
+        })
+        .await
+    }
+
+    async fn get_storage_slot_watches(&self) -> Result<Vec<StorageSlotWatch>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_storage_slot_watches).await
+    }
+
+    async fn add_storage_slot_watch(&self, watch: StorageSlotWatch) -> Result<bool, StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::add_storage_slot_watch(conn, watch))
+            .await
+    }
+
+    async fn remove_storage_slot_watch(
+        &self,
+        watch: StorageSlotWatch,
+    ) -> Result<usize, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::remove_storage_slot_watch(conn, watch)
+        })
+        .await
+    }
+
     async fn get_sync_height(&self) -> Result<u32, StoreError> {
         self.interact_with_connection(SqliteStore::get_sync_height).await
     }
@@ -140,6 +226,36 @@ impl Store for SqliteStore {
         .await
     }
 
+    async fn reset_chain_state(&self, reset: ChainStateReset) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::reset_chain_state(conn, reset))
+            .await
+    }
+
+    async fn get_last_sync_summary(&self) -> Result<Option<SyncSummary>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_last_sync_summary).await
+    }
+
+    async fn set_last_sync_summary(&self, summary: SyncSummary) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::set_last_sync_summary(conn, &summary)
+        })
+        .await
+    }
+
+    async fn get_partial_sync_progress(&self) -> Result<Option<PartialSyncProgress>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_partial_sync_progress).await
+    }
+
+    async fn set_partial_sync_progress(
+        &self,
+        progress: Option<PartialSyncProgress>,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::set_partial_sync_progress(conn, progress.as_ref())
+        })
+        .await
+    }
+
     async fn get_transactions(
         &self,
         transaction_filter: TransactionFilter,
@@ -155,6 +271,106 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn discard_local_transaction(
+        &self,
+        discard: LocalTransactionDiscard,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::discard_local_transaction(conn, discard)
+        })
+        .await
+    }
+
+    async fn get_faucet_mints(
+        &self,
+        faucet_id: AccountId,
+    ) -> Result<Vec<FaucetMintRecord>, StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::get_faucet_mints(conn, faucet_id))
+            .await
+    }
+
+    async fn get_known_faucets_for_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountId>, StoreError> {
+        let mut faucets = self
+            .interact_with_connection(move |conn| {
+                SqliteStore::get_account_vault_faucets(conn, account_id)
+            })
+            .await?;
+        faucets.extend(transaction_note_faucets(self, account_id).await?);
+        faucets.remove(&account_id);
+
+        Ok(faucets.into_iter().collect())
+    }
+
+    async fn insert_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::insert_proven_transaction(
+                conn,
+                transaction_id,
+                proven_transaction,
+                max_total_size,
+            )
+        })
+        .await
+    }
+
+    async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<Vec<u8>>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_proven_transaction(conn, transaction_id)
+        })
+        .await
+    }
+
+    async fn record_submission_attempt(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+    ) -> Result<u32, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::record_submission_attempt(conn, transaction_id, account_id)
+        })
+        .await
+    }
+
+    async fn get_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<u32, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_submission_attempts(conn, transaction_id)
+        })
+        .await
+    }
+
+    async fn remove_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::remove_submission_attempts(conn, transaction_id)
+        })
+        .await
+    }
+
+    async fn get_note_transactions(
+        &self,
+        note_id: NoteId,
+        _nullifier: Option<Nullifier>,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::get_note_transactions(conn, note_id))
+            .await
+    }
+
     async fn get_input_notes(
         &self,
         filter: NoteFilter,
@@ -163,6 +379,18 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_input_notes_page(
+        &self,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_input_notes_page(conn, filter, offset, limit)
+        })
+        .await
+    }
+
     async fn get_output_notes(
         &self,
         note_filter: NoteFilter,
@@ -171,12 +399,83 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_note_ids_by_prefix(&self, prefix: &str) -> Result<Vec<NoteId>, StoreError> {
+        let prefix = prefix.to_string();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_note_ids_by_prefix(conn, &prefix)
+        })
+        .await
+    }
+
     async fn upsert_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError> {
         let notes = notes.to_vec();
         self.interact_with_connection(move |conn| SqliteStore::upsert_input_notes(conn, &notes))
             .await
     }
 
+    async fn apply_note_batch(
+        &self,
+        note_updates: NoteUpdates,
+        progress: PartialSyncProgress,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::apply_note_batch(conn, &note_updates, &progress)
+        })
+        .await
+    }
+
+    async fn archive_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError> {
+        let notes = notes.to_vec();
+        self.interact_with_connection(move |conn| SqliteStore::archive_input_notes(conn, &notes))
+            .await
+    }
+
+    async fn get_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_archived_input_note(conn, note_id)
+        })
+        .await
+    }
+
+    async fn restore_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<InputNoteRecord, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::restore_archived_input_note(conn, note_id)
+        })
+        .await
+    }
+
+    async fn get_archived_input_note_count(&self) -> Result<usize, StoreError> {
+        self.interact_with_connection(SqliteStore::get_archived_input_note_count).await
+    }
+
+    async fn count_input_notes_by_state(&self) -> Result<BTreeMap<u8, usize>, StoreError> {
+        self.interact_with_connection(SqliteStore::count_input_notes_by_state).await
+    }
+
+    async fn get_pending_notes(&self) -> Result<Vec<PendingNoteRecord>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_pending_notes).await
+    }
+
+    async fn upsert_pending_note(&self, note: PendingNoteRecord) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::upsert_pending_note(conn, note))
+            .await
+    }
+
+    async fn remove_pending_note(&self, note_id: NoteId) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::remove_pending_note(conn, note_id))
+            .await
+    }
+
+    async fn get_future_note_links(&self) -> Result<Vec<FutureNoteLink>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_future_note_links).await
+    }
+
     async fn insert_block_header(
         &self,
         block_header: BlockHeader,
@@ -221,6 +520,17 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn replace_chain_mmr_nodes(
+        &self,
+        nodes: &[(InOrderIndex, Digest)],
+    ) -> Result<(), StoreError> {
+        let nodes = nodes.to_vec();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::replace_chain_mmr_nodes(conn, &nodes)
+        })
+        .await
+    }
+
     async fn get_chain_mmr_peaks_by_block_num(
         &self,
         block_num: u32,
@@ -231,17 +541,104 @@ impl Store for SqliteStore {
         .await
     }
 
+    async fn save_mmr_checkpoint(
+        &self,
+        block_num: u32,
+        partial_mmr: &PartialMmr,
+    ) -> Result<(), StoreError> {
+        let partial_mmr = partial_mmr.clone();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::save_mmr_checkpoint(conn, block_num, &partial_mmr)
+        })
+        .await
+    }
+
+    async fn load_mmr_checkpoint(&self) -> Result<Option<(u32, PartialMmr)>, StoreError> {
+        self.interact_with_connection(SqliteStore::load_mmr_checkpoint).await
+    }
+
     async fn insert_account(
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         let account = account.clone();
-        let auth_info = auth_info.clone();
+        let auth_info = auth_info.cloned();
 
         self.interact_with_connection(move |conn| {
-            SqliteStore::insert_account(conn, &account, account_seed, &auth_info)
+            SqliteStore::insert_account(conn, &account, account_seed, auth_info.as_ref())
+        })
+        .await
+    }
+
+    async fn reserve_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::reserve_account_id(conn, account_id))
+            .await
+    }
+
+    async fn release_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::release_account_id(conn, account_id))
+            .await
+    }
+
+    async fn remove_account(&self, removal: AccountRemoval) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::remove_account(conn, removal))
+            .await
+    }
+
+    async fn get_orphaned_account_auth_ids(&self) -> Result<Vec<AccountId>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_orphaned_account_auth_ids).await
+    }
+
+    async fn remove_orphaned_account_auth(
+        &self,
+        account_id: AccountId,
+    ) -> Result<bool, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::remove_orphaned_account_auth(conn, account_id)
+        })
+        .await
+    }
+
+    async fn get_account_lock_status(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountLockStatus, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_account_lock_status(conn, account_id)
+        })
+        .await
+    }
+
+    async fn set_account_lock_status(
+        &self,
+        account_id: AccountId,
+        status: AccountLockStatus,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::set_account_lock_status(conn, account_id, status)
+        })
+        .await
+    }
+
+    async fn apply_account_unlock(
+        &self,
+        record: AccountUnlockRecord,
+        new_state: Option<Account>,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::apply_account_unlock(conn, &record, new_state.as_ref())
+        })
+        .await
+    }
+
+    async fn get_account_unlock_records(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountUnlockRecord>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_account_unlock_records(conn, account_id)
         })
         .await
     }
@@ -250,6 +647,14 @@ impl Store for SqliteStore {
         self.interact_with_connection(SqliteStore::get_account_ids).await
     }
 
+    async fn get_account_ids_by_prefix(&self, prefix: &str) -> Result<Vec<AccountId>, StoreError> {
+        let prefix = prefix.to_string();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_account_ids_by_prefix(conn, &prefix)
+        })
+        .await
+    }
+
     async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError> {
         self.interact_with_connection(SqliteStore::get_account_headers).await
     }
@@ -290,15 +695,80 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_account_history(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<(Account, Digest)>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_account_history(conn, account_id)
+        })
+        .await
+    }
+
     async fn get_account_auth(&self, account_id: AccountId) -> Result<AuthSecretKey, StoreError> {
         self.interact_with_connection(move |conn| SqliteStore::get_account_auth(conn, account_id))
             .await
     }
 
+    async fn insert_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+        auth_info: &AuthSecretKey,
+    ) -> Result<(), StoreError> {
+        let auth_info = auth_info.clone();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::insert_pending_account_auth(conn, transaction_id, account_id, &auth_info)
+        })
+        .await
+    }
+
+    async fn get_pending_account_auths(
+        &self,
+    ) -> Result<Vec<(TransactionId, AccountId)>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_pending_account_auths).await
+    }
+
+    async fn resolve_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        committed: bool,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::resolve_pending_account_auth(conn, transaction_id, committed)
+        })
+        .await
+    }
+
+    async fn get_consumable_notes_for_faucet(
+        &self,
+        faucet_id: AccountId,
+        min_total: u64,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_consumable_notes_for_faucet(conn, faucet_id, min_total)
+        })
+        .await
+    }
+
     async fn get_unspent_input_note_nullifiers(&self) -> Result<Vec<Nullifier>, StoreError> {
         self.interact_with_connection(SqliteStore::get_unspent_input_note_nullifiers)
             .await
     }
+
+    async fn read_snapshot(&self, timeout: Duration) -> Result<Box<dyn StoreSnapshot>, StoreError> {
+        let conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|err| StoreError::DatabaseError(err.to_string()))?;
+
+        Ok(Box::new(SqliteSnapshot::open(conn, timeout).await?))
+    }
+
+    async fn flush(&self) -> Result<(), StoreError> {
+        self.interact_with_connection(checkpoint_wal).await
+    }
 }
 
 // TESTS
@@ -308,8 +778,18 @@ impl Store for SqliteStore {
 pub mod tests {
     use std::string::ToString;
 
+    use miden_objects::accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        AccountId,
+    };
+
     use super::{config::SqliteStoreConfig, SqliteStore};
-    use crate::mock::create_test_store_path;
+    use crate::{
+        accounts::AccountLockStatus, mock::create_test_store_path, store::Store, sync::SyncSummary,
+    };
 
     pub(crate) async fn create_test_store() -> SqliteStore {
         let temp_file = create_test_store_path();
@@ -320,4 +800,43 @@ pub mod tests {
         .await
         .unwrap()
     }
+
+    #[tokio::test]
+    async fn test_migrations_create_missing_tables() {
+        let store_path = create_test_store_path();
+        let store_config = SqliteStoreConfig {
+            database_filepath: store_path.to_string_lossy().to_string(),
+        };
+        drop(SqliteStore::new(&store_config).await.unwrap());
+
+        // Remove what databases created by previous versions of the client don't have
+        let conn = rusqlite::Connection::open(&store_path).unwrap();
+        conn.execute_batch(
+            "DROP TABLE account_locks;
+            DROP TABLE account_unlocks;
+            DROP TABLE pending_account_auth;
+            DROP TABLE faucet_mints;
+            ALTER TABLE state_sync DROP COLUMN last_sync_summary;
+            ALTER TABLE state_sync DROP COLUMN partial_sync_progress;",
+        )
+        .unwrap();
+        drop(conn);
+
+        let store = SqliteStore::new(&store_config).await.unwrap();
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        assert_eq!(
+            store.get_account_lock_status(account_id).await.unwrap(),
+            AccountLockStatus::Unlocked
+        );
+        assert!(store.get_account_unlock_records(account_id).await.unwrap().is_empty());
+        assert!(store.get_pending_account_auths().await.unwrap().is_empty());
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        assert!(store.get_faucet_mints(faucet_id).await.unwrap().is_empty());
+
+        assert_eq!(store.get_last_sync_summary().await.unwrap(), None);
+        store.set_last_sync_summary(SyncSummary::new_empty(0)).await.unwrap();
+        assert_eq!(store.get_last_sync_summary().await.unwrap(), Some(SyncSummary::new_empty(0)));
+        assert_eq!(store.get_partial_sync_progress().await.unwrap(), None);
+    }
 }
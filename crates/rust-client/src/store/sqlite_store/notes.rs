@@ -1,27 +1,38 @@
 use alloc::{
+    collections::BTreeMap,
     rc::Rc,
     string::{String, ToString},
     vec::Vec,
 };
 
 use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
     crypto::utils::{Deserializable, Serializable},
     notes::{
-        NoteAssets, NoteDetails, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, Nullifier,
+        NoteAssets, NoteDetails, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+        NoteTag, Nullifier,
     },
+    transaction::OutputNotes,
     Digest, Word,
 };
-use rusqlite::{named_params, params, params_from_iter, types::Value, Connection, Transaction};
+use rusqlite::{
+    named_params, params, params_from_iter, types::Value, Connection, OptionalExtension,
+    Transaction,
+};
+use tracing::info;
 
 use super::SqliteStore;
 use crate::{
-    notes::NoteUpdates,
+    notes::{FutureNoteLink, FutureNoteResolution, NoteUpdates, PendingNoteRecord},
     store::{
         note_record::OutputNoteState, InputNoteRecord, InputNoteState, NoteFilter,
         OutputNoteRecord, StoreError,
     },
 };
 
+pub(super) const DELETE_NOTE_ASSETS_QUERY: &str = "DELETE FROM input_note_assets WHERE note_id = ?";
+
 // TYPES
 // ================================================================================================
 
@@ -36,6 +47,7 @@ struct SerializedInputNoteData {
     pub nullifier: String,
     pub state_discriminant: u8,
     pub state: Vec<u8>,
+    pub aux: Option<i64>,
 }
 
 /// Represents an `OutputNoteRecord` serialized to be stored in the database
@@ -48,6 +60,8 @@ struct SerializedOutputNoteData {
     pub expected_height: u32,
     pub state_discriminant: u8,
     pub state: Vec<u8>,
+    pub aux: i64,
+    pub created_at: Option<u64>,
 }
 
 /// Represents the parts retrieved from the database to build an `InputNoteRecord`
@@ -67,6 +81,7 @@ struct SerializedOutputNoteParts {
     pub recipient_digest: String,
     pub expected_height: u32,
     pub state: Vec<u8>,
+    pub created_at: Option<u64>,
 }
 
 // NOTE FILTER
@@ -81,7 +96,8 @@ impl NoteFilter {
                     note.assets,
                     note.metadata,
                     note.expected_height,
-                    note.state
+                    note.state,
+                    note.created_at
                     from output_notes AS note";
 
         let (condition, params) = self.output_notes_condition();
@@ -96,6 +112,10 @@ impl NoteFilter {
         let mut params = Vec::new();
         let condition = match self {
             NoteFilter::All => "1 = 1".to_string(),
+            NoteFilter::Aux(aux) => {
+                params.push(Rc::new(vec![Value::Integer(aux.as_int() as i64)]));
+                "note.aux IN rarray(?)".to_string()
+            },
             NoteFilter::Committed => {
                 format!(
                     "state_discriminant in ({}, {})",
@@ -113,7 +133,12 @@ impl NoteFilter {
                     OutputNoteState::STATE_EXPECTED_FULL
                 )
             },
-            NoteFilter::Processing | NoteFilter::Unverified => "1 = 0".to_string(), /* There are no processing or unverified output notes */
+            // There are no processing, rejected or unverified output notes. Filters by creator
+            // transaction are replaced with a list of note IDs before the query is built.
+            NoteFilter::Processing
+            | NoteFilter::Rejected
+            | NoteFilter::Unverified
+            | NoteFilter::CreatedByTransaction(_) => "1 = 0".to_string(),
             NoteFilter::Unique(note_id) => {
                 let note_ids_list = vec![Value::Text(note_id.inner().to_string())];
                 params.push(Rc::new(note_ids_list));
@@ -175,6 +200,10 @@ impl NoteFilter {
         let mut params = Vec::new();
         let condition = match self {
             NoteFilter::All => "(1 = 1)".to_string(),
+            NoteFilter::Aux(aux) => {
+                params.push(Rc::new(vec![Value::Integer(aux.as_int() as i64)]));
+                "(note.aux IN rarray(?))".to_string()
+            },
             NoteFilter::Committed => {
                 format!("(state_discriminant = {})", InputNoteState::STATE_COMMITTED)
             },
@@ -219,6 +248,11 @@ impl NoteFilter {
                 params.push(Rc::new(nullifiers_list));
                 "(note.nullifier IN rarray(?))".to_string()
             },
+            NoteFilter::Rejected => {
+                format!("(state_discriminant = {})", InputNoteState::STATE_REJECTED)
+            },
+            // Replaced with a list of note IDs before the query is built
+            NoteFilter::CreatedByTransaction(_) => "(1 = 0)".to_string(),
             NoteFilter::Unverified => {
                 format!("(state_discriminant = {})", InputNoteState::STATE_UNVERIFIED)
             },
@@ -246,6 +280,7 @@ impl SqliteStore {
         conn: &mut Connection,
         filter: NoteFilter,
     ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        let filter = resolve_created_by_transaction(conn, filter)?;
         let (query, params) = filter.to_query_input_notes();
         let notes = conn
             .prepare(query.as_str())?
@@ -263,11 +298,50 @@ impl SqliteStore {
         Ok(notes)
     }
 
+    /// Retrieves a page of the input notes matched by the filter, ordered by ID, along with the
+    /// number of notes the filter matches. Both the count and the page are computed by the
+    /// database.
+    pub(crate) fn get_input_notes_page(
+        conn: &mut Connection,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), StoreError> {
+        let filter = resolve_created_by_transaction(conn, filter)?;
+
+        let (condition, params) = filter.input_notes_condition();
+        let count_query = format!("SELECT COUNT(*) FROM input_notes AS note WHERE {condition}");
+        let total: i64 = conn
+            .prepare(count_query.as_str())?
+            .query_row(params_from_iter(params), |row| row.get(0))?;
+
+        if let NoteFilter::Unique(note_id) = filter {
+            if total == 0 {
+                return Err(StoreError::NoteNotFound(note_id));
+            }
+        }
+
+        // SQLite limits are signed, so larger values are capped, which doesn't change the result
+        let limit = limit.min(i64::MAX as usize);
+        let offset = offset.min(i64::MAX as usize);
+        let (query, params) = filter.to_query_input_notes();
+        let query = format!("{query} ORDER BY note.note_id LIMIT {limit} OFFSET {offset}");
+        let notes = conn
+            .prepare(query.as_str())?
+            .query_map(params_from_iter(params), parse_input_note_columns)
+            .expect("no binding parameters used in query")
+            .map(|result| Ok(result?).and_then(parse_input_note))
+            .collect::<Result<Vec<InputNoteRecord>, _>>()?;
+
+        Ok((notes, total as usize))
+    }
+
     /// Retrieves the output notes from the database
     pub(crate) fn get_output_notes(
         conn: &mut Connection,
         filter: NoteFilter,
     ) -> Result<Vec<OutputNoteRecord>, StoreError> {
+        let filter = resolve_created_by_transaction(conn, filter)?;
         let (query, params) = filter.to_query_output_notes();
         let notes = conn
             .prepare(&query)?
@@ -285,6 +359,25 @@ impl SqliteStore {
         Ok(notes)
     }
 
+    /// Retrieves the IDs of the input and output notes starting with the provided prefix.
+    pub(crate) fn get_note_ids_by_prefix(
+        conn: &mut Connection,
+        prefix: &str,
+    ) -> Result<Vec<NoteId>, StoreError> {
+        // Unlike `LIKE`, `GLOB` is case sensitive, which lets SQLite use the primary key index of
+        // both tables for the prefix search
+        const QUERY: &str = "SELECT note_id FROM input_notes WHERE note_id GLOB ?1 \
+            UNION SELECT note_id FROM output_notes WHERE note_id GLOB ?1";
+
+        conn.prepare(QUERY)?
+            .query_map(params![format!("{prefix}*")], |row| row.get(0))?
+            .map(|result| {
+                let note_id: String = result?;
+                Digest::try_from(note_id).map(NoteId::from).map_err(StoreError::HexParseError)
+            })
+            .collect()
+    }
+
     pub(crate) fn upsert_input_notes(
         conn: &mut Connection,
         notes: &[InputNoteRecord],
@@ -298,6 +391,202 @@ impl SqliteStore {
         Ok(tx.commit()?)
     }
 
+    pub(crate) fn archive_input_notes(
+        conn: &mut Connection,
+        notes: &[InputNoteRecord],
+    ) -> Result<(), StoreError> {
+        const ARCHIVE_QUERY: &str =
+            "INSERT OR REPLACE INTO archived_input_notes (note_id, record) VALUES (?, ?)";
+        const DELETE_QUERY: &str = "DELETE FROM input_notes WHERE note_id = ?";
+
+        let tx = conn.transaction()?;
+
+        for note in notes {
+            let note_id = note.id().inner().to_string();
+            tx.execute(ARCHIVE_QUERY, params![note_id, note.to_bytes()])?;
+            tx.execute(DELETE_QUERY, params![note_id])?;
+            tx.execute(DELETE_NOTE_ASSETS_QUERY, params![note_id])?;
+        }
+
+        Ok(tx.commit()?)
+    }
+
+    pub(crate) fn get_archived_input_note(
+        conn: &mut Connection,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, StoreError> {
+        query_archived_input_note(conn, note_id)
+    }
+
+    pub(crate) fn restore_archived_input_note(
+        conn: &mut Connection,
+        note_id: NoteId,
+    ) -> Result<InputNoteRecord, StoreError> {
+        const DELETE_QUERY: &str = "DELETE FROM archived_input_notes WHERE note_id = ?";
+        // `upsert_input_note_tx` sets the creation timestamp to the current time, so the original
+        // one is restored afterwards
+        const CREATED_AT_QUERY: &str = "UPDATE input_notes SET created_at = ? WHERE note_id = ?";
+
+        let tx = conn.transaction()?;
+
+        let note =
+            query_archived_input_note(&tx, note_id)?.ok_or(StoreError::NoteNotFound(note_id))?;
+        upsert_input_note_tx(&tx, &note)?;
+        if let Some(created_at) = note.created_at() {
+            tx.execute(CREATED_AT_QUERY, params![created_at, note_id.inner().to_string()])?;
+        }
+        tx.execute(DELETE_QUERY, params![note_id.inner().to_string()])?;
+
+        tx.commit()?;
+        Ok(note)
+    }
+
+    pub(crate) fn get_archived_input_note_count(
+        conn: &mut Connection,
+    ) -> Result<usize, StoreError> {
+        const QUERY: &str = "SELECT COUNT(*) FROM archived_input_notes";
+        let count: i64 = conn.query_row(QUERY, [], |row| row.get(0))?;
+
+        Ok(count as usize)
+    }
+
+    pub(crate) fn get_pending_notes(
+        conn: &mut Connection,
+    ) -> Result<Vec<PendingNoteRecord>, StoreError> {
+        const QUERY: &str = "SELECT note_id, tag, inclusion_block_num FROM pending_notes";
+
+        conn.prepare(QUERY)?
+            .query_map([], |row| {
+                let note_id: String = row.get(0)?;
+                let tag: Option<u32> = row.get(1)?;
+                let inclusion_block_num: Option<u32> = row.get(2)?;
+                Ok((note_id, tag, inclusion_block_num))
+            })?
+            .map(|result| -> Result<PendingNoteRecord, StoreError> {
+                let (note_id, tag, inclusion_block_num) = result?;
+                let note_id = Digest::try_from(note_id)?.into();
+                Ok(PendingNoteRecord::from_parts(
+                    note_id,
+                    tag.map(NoteTag::from),
+                    inclusion_block_num,
+                ))
+            })
+            .collect()
+    }
+
+    pub(crate) fn upsert_pending_note(
+        conn: &mut Connection,
+        note: PendingNoteRecord,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "INSERT OR REPLACE INTO pending_notes (note_id, tag, inclusion_block_num) VALUES (?, ?, ?)";
+
+        conn.execute(
+            QUERY,
+            params![
+                note.note_id().inner().to_string(),
+                note.tag().map(u32::from),
+                note.inclusion_block_num()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn remove_pending_note(
+        conn: &mut Connection,
+        note_id: NoteId,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "DELETE FROM pending_notes WHERE note_id = ?";
+        conn.execute(QUERY, params![note_id.inner().to_string()])?;
+
+        Ok(())
+    }
+
+    pub(crate) fn get_future_note_links(
+        conn: &mut Connection,
+    ) -> Result<Vec<FutureNoteLink>, StoreError> {
+        const QUERY: &str =
+            "SELECT source_note_id, future_note_id, creator_account_id FROM future_note_links";
+
+        conn.prepare(QUERY)?
+            .query_map([], |row| {
+                let source_note_id: String = row.get(0)?;
+                let future_note_id: String = row.get(1)?;
+                let creator_account_id: i64 = row.get(2)?;
+                Ok((source_note_id, future_note_id, creator_account_id))
+            })?
+            .map(|result| -> Result<FutureNoteLink, StoreError> {
+                let (source_note_id, future_note_id, creator_account_id) = result?;
+                Ok(FutureNoteLink::new(
+                    Digest::try_from(source_note_id)?.into(),
+                    Digest::try_from(future_note_id)?.into(),
+                    AccountId::try_from(creator_account_id as u64)?,
+                ))
+            })
+            .collect()
+    }
+
+    pub(crate) fn count_input_notes_by_state(
+        conn: &mut Connection,
+    ) -> Result<BTreeMap<u8, usize>, StoreError> {
+        const QUERY: &str =
+            "SELECT state_discriminant, COUNT(*) FROM input_notes GROUP BY state_discriminant";
+
+        conn.prepare(QUERY)?
+            .query_map([], |row| {
+                let state: u8 = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((state, count as usize))
+            })?
+            .map(|result| Ok(result?))
+            .collect()
+    }
+
+    pub(crate) fn get_consumable_notes_for_faucet(
+        conn: &mut Connection,
+        faucet_id: AccountId,
+        min_total: u64,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        // The running total of each note excludes its own amount, so the note that reaches
+        // `min_total` is the last one selected
+        const QUERY: &str = "
+            SELECT note_id FROM (
+                SELECT
+                    asset.note_id,
+                    SUM(asset.amount) OVER (
+                        ORDER BY asset.amount DESC, asset.note_id
+                        ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING
+                    ) AS previous_total
+                FROM input_note_assets AS asset
+                JOIN input_notes AS note ON note.note_id = asset.note_id
+                WHERE asset.faucet_id = ? AND note.state_discriminant = ?
+                ORDER BY asset.amount DESC, asset.note_id
+            )
+            WHERE COALESCE(previous_total, 0) < ?";
+
+        let note_ids = conn
+            .prepare(QUERY)?
+            .query_map(
+                params![
+                    u64::from(faucet_id) as i64,
+                    InputNoteState::STATE_COMMITTED,
+                    min_total as i64
+                ],
+                |row| row.get::<_, String>(0),
+            )?
+            .map(|result| Ok(Digest::try_from(result?).map(NoteId::from)?))
+            .collect::<Result<Vec<NoteId>, StoreError>>()?;
+
+        if note_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut notes = SqliteStore::get_input_notes(conn, NoteFilter::List(note_ids.clone()))?;
+        notes.sort_by_key(|note| note_ids.iter().position(|note_id| *note_id == note.id()));
+
+        Ok(notes)
+    }
+
     pub(crate) fn get_unspent_input_note_nullifiers(
         conn: &mut Connection,
     ) -> Result<Vec<Nullifier>, StoreError> {
@@ -325,6 +614,232 @@ impl SqliteStore {
 // HELPERS
 // ================================================================================================
 
+/// Creates the archived input notes table in databases created before it was introduced.
+pub(super) fn create_archived_input_notes_table(conn: &mut Connection) -> Result<(), StoreError> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS archived_input_notes (
+            note_id TEXT NOT NULL,
+            record BLOB NOT NULL,
+            PRIMARY KEY (note_id)
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the table and indexes used to select notes by their fungible assets in databases
+/// created before they were introduced, indexing the assets of the stored input notes.
+pub(super) fn create_input_note_assets_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'input_note_assets'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the input_note_assets table");
+    let tx = conn.transaction()?;
+    tx.execute_batch(
+        "CREATE INDEX IF NOT EXISTS idx_input_notes_state ON input_notes(state_discriminant);
+        CREATE TABLE input_note_assets (
+            note_id TEXT NOT NULL,
+            faucet_id UNSIGNED BIG INT NOT NULL,
+            amount UNSIGNED BIG INT NOT NULL,
+            PRIMARY KEY (note_id, faucet_id)
+        );
+        CREATE INDEX idx_input_note_assets_faucet ON input_note_assets(faucet_id, amount);",
+    )?;
+
+    let notes = tx
+        .prepare("SELECT note_id, assets FROM input_notes")?
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (note_id, assets) in notes {
+        insert_note_assets_tx(&tx, &note_id, &NoteAssets::read_from_bytes(&assets)?)?;
+    }
+
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Adds the indexed `aux` column to the input and output notes tables of databases created before
+/// notes could be filtered by the `aux` of their metadata, filling it from the stored notes.
+pub(super) fn add_note_aux_columns(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str = "SELECT 1 FROM pragma_table_info('input_notes') WHERE name = 'aux'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding the aux column to the notes tables");
+    let tx = conn.transaction()?;
+    tx.execute_batch(
+        "ALTER TABLE input_notes ADD COLUMN aux UNSIGNED BIG INT NULL;
+        ALTER TABLE output_notes ADD COLUMN aux UNSIGNED BIG INT NULL;
+        CREATE INDEX idx_input_notes_aux ON input_notes(aux);
+        CREATE INDEX idx_output_notes_aux ON output_notes(aux);",
+    )?;
+
+    let input_notes = tx
+        .prepare("SELECT note_id, state FROM input_notes")?
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    for (note_id, state) in input_notes {
+        if let Some(metadata) = InputNoteState::read_from_bytes(&state)?.metadata() {
+            tx.execute(
+                "UPDATE input_notes SET aux = ? WHERE note_id = ?",
+                params![metadata.aux().as_int() as i64, note_id],
+            )?;
+        }
+    }
+
+    let output_notes = tx
+        .prepare("SELECT note_id, metadata FROM output_notes")?
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    for (note_id, metadata) in output_notes {
+        tx.execute(
+            "UPDATE output_notes SET aux = ? WHERE note_id = ?",
+            params![NoteMetadata::read_from_bytes(&metadata)?.aux().as_int() as i64, note_id],
+        )?;
+    }
+
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Adds the `created_at` column to the output notes table of databases created before output
+/// notes kept their creation timestamp, and appends the committed block fields to the stored
+/// committed and consumed states. They are left empty, as they weren't tracked.
+pub(super) fn add_output_note_timestamps(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str = "SELECT 1 FROM pragma_table_info('output_notes') WHERE name = 'created_at'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding the committed block fields to the output notes");
+    let tx = conn.transaction()?;
+    tx.execute_batch(&format!(
+        "ALTER TABLE output_notes ADD COLUMN created_at UNSIGNED BIG INT NULL;
+        UPDATE output_notes SET state = state || x'00' WHERE state_discriminant IN ({}, {});
+        UPDATE output_notes SET state = state || x'0000' WHERE state_discriminant = {};",
+        OutputNoteState::STATE_COMMITTED_PARTIAL,
+        OutputNoteState::STATE_COMMITTED_FULL,
+        OutputNoteState::STATE_CONSUMED,
+    ))?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Creates the `pending_notes` table in stores created before notes could be imported by ID
+/// ahead of their commitment.
+pub(super) fn create_pending_notes_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'pending_notes'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the pending_notes table");
+    conn.execute_batch(
+        "CREATE TABLE pending_notes (
+            note_id TEXT NOT NULL,
+            tag UNSIGNED INT NULL,
+            inclusion_block_num UNSIGNED BIG INT NULL,
+            PRIMARY KEY (note_id)
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the `future_note_links` table in stores created before the expected notes of swaps
+/// were resolved when the swap notes are consumed.
+pub(super) fn create_future_note_links_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'future_note_links'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the future_note_links table");
+    conn.execute_batch(
+        "CREATE TABLE future_note_links (
+            source_note_id TEXT NOT NULL,
+            future_note_id TEXT NOT NULL,
+            creator_account_id UNSIGNED BIG INT NOT NULL,
+            PRIMARY KEY (source_note_id)
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Indexes the fungible assets of the input note with the provided ID, replacing the ones indexed
+/// before.
+fn insert_note_assets_tx(
+    tx: &Transaction<'_>,
+    note_id: &str,
+    assets: &NoteAssets,
+) -> Result<(), StoreError> {
+    const INSERT_QUERY: &str =
+        "INSERT INTO input_note_assets (note_id, faucet_id, amount) VALUES (?, ?, ?)";
+
+    tx.execute(DELETE_NOTE_ASSETS_QUERY, params![note_id])?;
+    for asset in assets.iter() {
+        if let Asset::Fungible(asset) = asset {
+            tx.execute(
+                INSERT_QUERY,
+                params![note_id, u64::from(asset.faucet_id()) as i64, asset.amount() as i64],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Retrieves the archived input note with the provided ID, if there is one.
+fn query_archived_input_note(
+    conn: &Connection,
+    note_id: NoteId,
+) -> Result<Option<InputNoteRecord>, StoreError> {
+    const QUERY: &str = "SELECT record FROM archived_input_notes WHERE note_id = ?";
+
+    let record: Option<Vec<u8>> = conn
+        .query_row(QUERY, params![note_id.inner().to_string()], |row| row.get(0))
+        .optional()?;
+
+    Ok(record.map(|record| InputNoteRecord::read_from_bytes(&record)).transpose()?)
+}
+
+/// Replaces a [NoteFilter::CreatedByTransaction] with the list of the notes in the stored output
+/// notes of the transaction. Other filters are returned unchanged.
+fn resolve_created_by_transaction(
+    conn: &Connection,
+    filter: NoteFilter,
+) -> Result<NoteFilter, StoreError> {
+    const QUERY: &str = "SELECT output_notes FROM transactions WHERE id = ?";
+
+    let NoteFilter::CreatedByTransaction(transaction_id) = filter else {
+        return Ok(filter);
+    };
+
+    let output_notes: Option<Vec<u8>> = conn
+        .query_row(QUERY, params![transaction_id.inner().to_string()], |row| row.get(0))
+        .optional()?;
+    let note_ids = match output_notes {
+        Some(output_notes) => OutputNotes::read_from_bytes(&output_notes)?
+            .iter()
+            .map(|note| note.id())
+            .collect(),
+        None => vec![],
+    };
+
+    Ok(NoteFilter::List(note_ids))
+}
+
 /// Inserts the provided input note into the database, if the note already exists, it will be
 /// replaced.
 pub(super) fn upsert_input_note_tx(
@@ -341,6 +856,7 @@ pub(super) fn upsert_input_note_tx(
         nullifier,
         state_discriminant,
         state,
+        aux,
     } = serialize_input_note(note)?;
 
     const SCRIPT_QUERY: &str =
@@ -357,7 +873,8 @@ pub(super) fn upsert_input_note_tx(
             nullifier,
             state_discriminant,
             state,
-            created_at
+            created_at,
+            aux
         ) VALUES (
             :note_id,
             :assets,
@@ -367,7 +884,8 @@ pub(super) fn upsert_input_note_tx(
             :nullifier,
             :state_discriminant,
             :state,
-            unixepoch(current_timestamp));
+            unixepoch(current_timestamp),
+            :aux);
     ";
 
     tx.execute(
@@ -381,10 +899,12 @@ pub(super) fn upsert_input_note_tx(
             ":nullifier": nullifier,
             ":state_discriminant": state_discriminant,
             ":state": state,
+            ":aux": aux,
         },
     )
-    .map_err(|err| StoreError::QueryError(err.to_string()))
-    .map(|_| ())
+    .map_err(|err| StoreError::QueryError(err.to_string()))?;
+
+    insert_note_assets_tx(tx, &id, note.assets())
 }
 
 /// Inserts the provided input note into the database
@@ -401,7 +921,9 @@ pub fn upsert_output_note_tx(
             nullifier,
             expected_height,
             state_discriminant,
-            state
+            state,
+            aux,
+            created_at
         ) VALUES (
             :note_id,
             :assets,
@@ -410,7 +932,9 @@ pub fn upsert_output_note_tx(
             :nullifier,
             :expected_height,
             :state_discriminant,
-            :state
+            :state,
+            :aux,
+            :created_at
         );";
 
     let SerializedOutputNoteData {
@@ -422,6 +946,8 @@ pub fn upsert_output_note_tx(
         expected_height,
         state_discriminant,
         state,
+        aux,
+        created_at,
     } = serialize_output_note(note)?;
 
     tx.execute(
@@ -435,6 +961,8 @@ pub fn upsert_output_note_tx(
             ":expected_height": expected_height,
             ":state_discriminant": state_discriminant,
             ":state": state,
+            ":aux": aux,
+            ":created_at": created_at,
         },
     )?;
 
@@ -506,6 +1034,7 @@ fn serialize_input_note(note: &InputNoteRecord) -> Result<SerializedInputNoteDat
 
     let state_discriminant = note.state().discriminant();
     let state = note.state().to_bytes();
+    let aux = note.metadata().map(|metadata| metadata.aux().as_int() as i64);
 
     Ok(SerializedInputNoteData {
         id,
@@ -517,6 +1046,7 @@ fn serialize_input_note(note: &InputNoteRecord) -> Result<SerializedInputNoteDat
         nullifier,
         state_discriminant,
         state,
+        aux,
     })
 }
 
@@ -529,6 +1059,7 @@ fn parse_output_note_columns(
     let metadata: Vec<u8> = row.get(2)?;
     let expected_height: u32 = row.get(3)?;
     let state: Vec<u8> = row.get(4)?;
+    let created_at: Option<u64> = row.get(5)?;
 
     Ok(SerializedOutputNoteParts {
         recipient_digest,
@@ -536,6 +1067,7 @@ fn parse_output_note_columns(
         metadata,
         expected_height,
         state,
+        created_at,
     })
 }
 
@@ -549,6 +1081,7 @@ fn parse_output_note(
         metadata,
         expected_height,
         state,
+        created_at,
     } = serialized_output_note_parts;
 
     let recipient_digest = Digest::try_from(recipient_digest)?;
@@ -562,6 +1095,7 @@ fn parse_output_note(
         metadata,
         state,
         expected_height,
+        created_at,
     ))
 }
 
@@ -586,6 +1120,8 @@ fn serialize_output_note(note: &OutputNoteRecord) -> Result<SerializedOutputNote
         expected_height: note.expected_height(),
         state_discriminant,
         state,
+        aux: note.metadata().aux().as_int() as i64,
+        created_at: note.created_at(),
     })
 }
 
@@ -609,3 +1145,49 @@ pub(crate) fn apply_note_updates_tx(
 
     Ok(())
 }
+
+/// Inserts the provided future note links, replacing the ones with the same source notes.
+pub(crate) fn insert_future_note_links_tx(
+    tx: &Transaction,
+    links: &[FutureNoteLink],
+) -> Result<(), StoreError> {
+    const QUERY: &str = "INSERT OR REPLACE INTO future_note_links (source_note_id, future_note_id, creator_account_id) VALUES (?, ?, ?)";
+
+    for link in links {
+        tx.execute(
+            QUERY,
+            params![
+                link.source_note_id().inner().to_string(),
+                link.future_note_id().inner().to_string(),
+                u64::from(link.creator_account_id()) as i64
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Removes the links of the provided resolutions, along with the input notes of the cancelled
+/// ones that are still expected.
+pub(crate) fn apply_future_note_resolutions_tx(
+    tx: &Transaction,
+    resolutions: &[FutureNoteResolution],
+) -> Result<(), StoreError> {
+    const DELETE_LINK_QUERY: &str = "DELETE FROM future_note_links WHERE source_note_id = ?";
+    const DELETE_NOTE_QUERY: &str =
+        "DELETE FROM input_notes WHERE note_id = ? AND state_discriminant = ?";
+
+    for resolution in resolutions {
+        tx.execute(DELETE_LINK_QUERY, params![resolution.source_note_id().inner().to_string()])?;
+
+        if let FutureNoteResolution::Cancelled { future_note_id, .. } = resolution {
+            let note_id = future_note_id.inner().to_string();
+            if tx.execute(DELETE_NOTE_QUERY, params![note_id, InputNoteState::STATE_EXPECTED])? > 0
+            {
+                tx.execute(DELETE_NOTE_ASSETS_QUERY, params![note_id])?;
+            }
+        }
+    }
+
+    Ok(())
+}
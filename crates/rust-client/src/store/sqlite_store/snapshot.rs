@@ -0,0 +1,107 @@
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+use core::time::Duration;
+use std::time::Instant;
+
+use deadpool_sqlite::Object;
+use miden_objects::{accounts::AccountHeader, Word};
+use rusqlite::Connection;
+use tonic::async_trait;
+
+use super::SqliteStore;
+use crate::{
+    store::{InputNoteRecord, NoteFilter, StoreError, StoreSnapshot, TransactionFilter},
+    transactions::TransactionRecord,
+};
+
+// SQLITE SNAPSHOT
+// ================================================================================================
+
+/// [StoreSnapshot] backed by a read transaction held on a connection taken from the pool.
+///
+/// The database is in WAL mode, so the read transaction doesn't block writers, but it prevents
+/// the log from being checkpointed past the snapshot. To bound that, the transaction is rolled
+/// back by the first read made after the timeout, and the connection is closed instead of being
+/// returned to the pool when the snapshot is dropped.
+///
+/// The timeout is checked lazily: no timer rolls the transaction back, so a snapshot that isn't
+/// read after its timeout keeps the log from being checkpointed until it's dropped.
+pub(crate) struct SqliteSnapshot {
+    conn: Option<Object>,
+    opened_at: Instant,
+    timeout: Duration,
+}
+
+impl SqliteSnapshot {
+    /// Begins a read transaction on `conn` and returns a snapshot that reads through it.
+    pub(super) async fn open(conn: Object, timeout: Duration) -> Result<Self, StoreError> {
+        conn.interact(begin_read_transaction)
+            .await
+            .map_err(|err| StoreError::DatabaseError(err.to_string()))??;
+
+        Ok(Self {
+            conn: Some(conn),
+            opened_at: Instant::now(),
+            timeout,
+        })
+    }
+
+    /// Runs the provided query within the snapshot's read transaction, unless the snapshot
+    /// expired, in which case the transaction is rolled back.
+    async fn read<F, R>(&self, f: F) -> Result<R, StoreError>
+    where
+        F: FnOnce(&mut Connection) -> Result<R, StoreError> + Send + 'static,
+        R: Send + 'static,
+    {
+        let conn = self.conn.as_ref().expect("the connection is only taken on drop");
+
+        if self.opened_at.elapsed() >= self.timeout {
+            // The transaction may have been rolled back by a previous read already
+            let _ = conn.interact(|conn| conn.execute_batch("ROLLBACK")).await;
+            return Err(StoreError::SnapshotExpired(self.timeout));
+        }
+
+        conn.interact(f)
+            .await
+            .map_err(|err| StoreError::DatabaseError(err.to_string()))?
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreSnapshot for SqliteSnapshot {
+    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError> {
+        self.read(SqliteStore::get_account_headers).await
+    }
+
+    async fn get_input_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        self.read(move |conn| SqliteStore::get_input_notes(conn, filter)).await
+    }
+
+    async fn get_transactions(
+        &self,
+        filter: TransactionFilter,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        self.read(move |conn| SqliteStore::get_transactions(conn, filter)).await
+    }
+}
+
+impl Drop for SqliteSnapshot {
+    fn drop(&mut self) {
+        // Returning the connection to the pool would leave the read transaction open for its next
+        // user, so it's detached from the pool and closed, which ends the transaction.
+        if let Some(conn) = self.conn.take() {
+            drop(Object::take(conn));
+        }
+    }
+}
+
+/// Begins a deferred transaction and reads from the database, so that the snapshot the
+/// transaction reads from is taken right away instead of on the first query.
+fn begin_read_transaction(conn: &mut Connection) -> Result<(), StoreError> {
+    conn.execute_batch("BEGIN DEFERRED")?;
+    conn.query_row("SELECT COUNT(*) FROM sqlite_master", [], |_| Ok(()))?;
+
+    Ok(())
+}
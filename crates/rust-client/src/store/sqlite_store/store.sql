@@ -27,6 +27,21 @@ CREATE TABLE account_auth (
     PRIMARY KEY (account_id)
 );
 
+-- Create pending_account_auth table, with the keys that replace the ones in account_auth once the transactions setting them are committed
+CREATE TABLE pending_account_auth (
+    transaction_id TEXT NOT NULL,          -- ID of the transaction that sets the key in the account.
+    account_id UNSIGNED BIG INT NOT NULL,  -- ID of the account
+    auth_info BLOB NOT NULL,               -- Serialized representation of information needed for authentication
+    pub_key BLOB NOT NULL,                 -- Public key for easier authenticator use
+    PRIMARY KEY (transaction_id)
+);
+
+-- Create account_reservations table
+CREATE TABLE account_reservations (
+    account_id UNSIGNED BIG INT NOT NULL,  -- ID of an account that is being created.
+    PRIMARY KEY (account_id)
+);
+
 -- Create accounts table
 CREATE TABLE accounts (
     id UNSIGNED BIG INT NOT NULL,  -- Account ID.
@@ -47,6 +62,23 @@ CREATE TABLE accounts (
 
 CREATE UNIQUE INDEX idx_account_hash ON accounts(account_hash);
 
+-- Create account_locks table
+CREATE TABLE account_locks (
+    account_id UNSIGNED BIG INT NOT NULL,   -- ID of the account whose state diverged from the node's.
+    remote_hash TEXT NOT NULL,              -- Account hash reported by the node.
+    divergence_accepted BOOLEAN NOT NULL,   -- True if the divergence was accepted, false if the account is locked.
+    PRIMARY KEY (account_id)
+);
+
+-- Create account_unlocks table
+CREATE TABLE account_unlocks (
+    account_id UNSIGNED BIG INT NOT NULL,   -- ID of the unlocked account.
+    strategy TEXT NOT NULL,                 -- Strategy used to unlock the account.
+    local_hash TEXT NOT NULL,               -- Hash of the local account state before unlocking it.
+    remote_hash TEXT NOT NULL,              -- Account hash reported by the node when unlocking it.
+    block_num UNSIGNED BIG INT NOT NULL     -- Sync height of the client when the account was unlocked.
+);
+
 -- Create transactions table
 CREATE TABLE transactions (
     id TEXT NOT NULL,                                -- Transaction ID (hash of various components)
@@ -59,10 +91,34 @@ CREATE TABLE transactions (
     block_num UNSIGNED BIG INT,                      -- Block number for the block against which the transaction was executed.
     commit_height UNSIGNED BIG INT NULL,             -- Block number of the block at which the transaction was included in the chain.
     discarded BOOLEAN NOT NULL,                      -- Boolean indicating if the transaction is discarded
+    rpc_endpoint TEXT NULL,                          -- Endpoint of the node that accepted the transaction.
+    client_version TEXT NULL,                        -- Version of the client that submitted the transaction.
+    prover TEXT NULL,                                -- Description of the prover used to prove the transaction.
+    submission_attempts UNSIGNED INT NULL,           -- Number of attempts it took to submit the transaction.
+    discard_cause BLOB NULL,                         -- Serialized cause of the discard, if the transaction is discarded.
     FOREIGN KEY (script_hash) REFERENCES transaction_scripts(script_hash),
     PRIMARY KEY (id)
 );
 
+-- Create faucet_mints table
+CREATE TABLE faucet_mints (
+    transaction_id TEXT NOT NULL,                    -- ID of the mint transaction.
+    faucet_id UNSIGNED BIG INT NOT NULL,             -- ID of the faucet that executed the transaction.
+    amount UNSIGNED BIG INT NOT NULL,                -- Amount of tokens minted by the transaction.
+    PRIMARY KEY (transaction_id),
+    FOREIGN KEY (transaction_id) REFERENCES transactions(id)
+);
+
+-- Create transaction_notes table
+CREATE TABLE transaction_notes (
+    transaction_id TEXT NOT NULL,                    -- ID of the transaction.
+    note_id TEXT NOT NULL,                           -- ID of a note consumed or created by the transaction.
+    PRIMARY KEY (transaction_id, note_id),
+    FOREIGN KEY (transaction_id) REFERENCES transactions(id)
+);
+
+CREATE INDEX idx_transaction_notes_note_id ON transaction_notes(note_id);
+
 CREATE TABLE transaction_scripts (
     script_hash TEXT NOT NULL,                       -- Transaction script Hash
     script BLOB,                                     -- serialized Transaction script
@@ -70,6 +126,22 @@ CREATE TABLE transaction_scripts (
     PRIMARY KEY (script_hash)
 );
 
+-- Create proven_transactions table, retaining the proofs of submitted transactions
+CREATE TABLE proven_transactions (
+    transaction_id TEXT NOT NULL,                    -- ID of the transaction.
+    proven_transaction BLOB NOT NULL,                -- Serialized proven transaction.
+    last_used UNSIGNED BIG INT NOT NULL,             -- Sequence number of the last insertion or retrieval, used to evict the least recently used proofs.
+    PRIMARY KEY (transaction_id)
+);
+
+-- Create transaction_submissions table, recording the attempts to submit transactions that aren't confirmed yet
+CREATE TABLE transaction_submissions (
+    transaction_id TEXT NOT NULL,                    -- ID of the transaction, used as the idempotency key of its submission.
+    account_id UNSIGNED BIG INT NOT NULL,            -- ID of the account the transaction was executed against.
+    attempts UNSIGNED INT NOT NULL,                  -- Number of attempts made to submit the transaction.
+    PRIMARY KEY (transaction_id)
+);
+
 -- Create input notes table
 CREATE TABLE input_notes (
     note_id TEXT NOT NULL,                                  -- the note id
@@ -81,11 +153,26 @@ CREATE TABLE input_notes (
     state_discriminant UNSIGNED INT NOT NULL,               -- state discriminant of the note, used to query by state
     state BLOB NOT NULL,                                    -- serialized note state
     created_at UNSIGNED BIG INT NOT NULL,                   -- timestamp of the note creation/import
+    aux UNSIGNED BIG INT NULL,                              -- aux of the note metadata, if known, used to query by aux
 
     PRIMARY KEY (note_id)
     FOREIGN KEY (script_hash) REFERENCES notes_scripts(script_hash)
 );
 
+CREATE INDEX idx_input_notes_state ON input_notes(state_discriminant);
+CREATE INDEX idx_input_notes_aux ON input_notes(aux);
+
+-- Create input_note_assets table, indexing the fungible assets held by the input notes
+CREATE TABLE input_note_assets (
+    note_id TEXT NOT NULL,                                  -- the note id
+    faucet_id UNSIGNED BIG INT NOT NULL,                    -- ID of the faucet that issued the asset
+    amount UNSIGNED BIG INT NOT NULL,                       -- amount of the asset held by the note
+
+    PRIMARY KEY (note_id, faucet_id)
+);
+
+CREATE INDEX idx_input_note_assets_faucet ON input_note_assets(faucet_id, amount);
+
 -- Create output notes table
 CREATE TABLE output_notes (
     note_id TEXT NOT NULL,                                  -- the note id
@@ -98,10 +185,40 @@ CREATE TABLE output_notes (
 --     script_hash TEXT NULL,
     state_discriminant UNSIGNED INT NOT NULL,               -- state discriminant of the note, used to query by state
     state BLOB NOT NULL,                                    -- serialized note state
+    aux UNSIGNED BIG INT NOT NULL,                          -- aux of the note metadata, used to query by aux
+    created_at UNSIGNED BIG INT NULL,                       -- timestamp of the application of the transaction that created the note
+
+    PRIMARY KEY (note_id)
+);
+
+CREATE INDEX idx_output_notes_aux ON output_notes(aux);
+
+-- Create archived input notes table, holding terminal-state notes moved out of input_notes
+CREATE TABLE archived_input_notes (
+    note_id TEXT NOT NULL,                                  -- the note id
+    record BLOB NOT NULL,                                   -- the serialized InputNoteRecord
+
+    PRIMARY KEY (note_id)
+);
+
+-- Create pending notes table, tracking notes imported by ID before they are committed
+CREATE TABLE pending_notes (
+    note_id TEXT NOT NULL,                                  -- the note id
+    tag UNSIGNED INT NULL,                                  -- tag tracked to receive the note's inclusion, if any
+    inclusion_block_num UNSIGNED BIG INT NULL,              -- block in which the note was committed, if it was
 
     PRIMARY KEY (note_id)
 );
 
+-- Create future note links table, linking created notes to the expected notes their consumption creates
+CREATE TABLE future_note_links (
+    source_note_id TEXT NOT NULL,                           -- the id of the created note, like a swap note
+    future_note_id TEXT NOT NULL,                           -- the id of the expected note, like a swap payback note
+    creator_account_id UNSIGNED BIG INT NOT NULL,           -- the account that created the source note
+
+    PRIMARY KEY (source_note_id)
+);
+
 -- Create note's scripts table, used for both input and output notes
 CREATE TABLE notes_scripts (
     script_hash TEXT NOT NULL,                       -- Note script Hash
@@ -113,13 +230,25 @@ CREATE TABLE notes_scripts (
 -- Create state sync table
 CREATE TABLE state_sync (
     block_num UNSIGNED BIG INT NOT NULL,    -- the block number of the most recent state sync
+    last_sync_summary BLOB NULL,            -- serialized summary of the most recent call to sync the state
+    partial_sync_progress BLOB NULL,        -- serialized progress of the block whose notes are partially applied
     PRIMARY KEY (block_num)
 );
 
 -- Create tags table
 CREATE TABLE tags (
     tag BLOB NOT NULL,                  -- the serialized tag
-    source BLOB NOT NULL               -- the serialized tag source
+    source BLOB NOT NULL,               -- the serialized tag source
+    expires_after UNSIGNED BIG INT NULL, -- block after which the tag can be removed once its note is consumed or invalid
+    keep_all BOOLEAN NOT NULL DEFAULT 1, -- whether every untracked public note received with the tag is stored
+    aux_filter UNSIGNED BIG INT NULL     -- metadata aux of the untracked public notes stored when keep_all is not set
+);
+
+-- Create storage slot watches table
+CREATE TABLE storage_slot_watches (
+    account_id UNSIGNED BIG INT NOT NULL,   -- ID of the watched account
+    slot_index UNSIGNED INT NOT NULL,       -- index of the watched storage slot
+    map_key BLOB NULL                       -- serialized watched key, for map slots
 );
 
 -- insert initial row into state_sync table
@@ -143,4 +272,11 @@ CREATE TABLE chain_mmr_nodes (
     id UNSIGNED BIG INT NOT NULL,   -- in-order index of the internal MMR node
     node BLOB NOT NULL,             -- internal node value (hash)
     PRIMARY KEY (id)
-)
+);
+
+-- Create mmr checkpoint table
+CREATE TABLE mmr_checkpoint (
+    block_num UNSIGNED BIG INT NOT NULL,  -- sync height at which the checkpoint was taken
+    partial_mmr BLOB NOT NULL,            -- serialized partial MMR at the sync height
+    PRIMARY KEY (block_num)
+);
@@ -1,34 +1,58 @@
 use alloc::{collections::BTreeSet, vec::Vec};
 
-use miden_objects::notes::NoteTag;
+use miden_objects::{accounts::AccountId, notes::NoteTag, Felt, Word};
 use miden_tx::utils::{Deserializable, Serializable};
 use rusqlite::{params, Connection, Transaction};
+use tracing::info;
 
 use super::SqliteStore;
 use crate::{
+    notes::NoteUpdates,
     store::{
-        sqlite_store::{accounts::update_account, notes::apply_note_updates_tx},
+        sqlite_store::{
+            accounts::update_account,
+            notes::{apply_future_note_resolutions_tx, apply_note_updates_tx},
+        },
         StoreError,
     },
-    sync::{NoteTagRecord, NoteTagSource, StateSyncUpdate},
+    sync::{
+        ChainStateReset, NoteTagRecord, NoteTagSource, PartialSyncProgress, StateSyncUpdate,
+        StorageSlotWatch, SyncSummary,
+    },
+    transactions::DiscardCause,
 };
 
+const SET_PARTIAL_SYNC_PROGRESS_QUERY: &str = "UPDATE state_sync SET partial_sync_progress = ?";
+
 impl SqliteStore {
     pub(crate) fn get_note_tags(conn: &mut Connection) -> Result<Vec<NoteTagRecord>, StoreError> {
-        const QUERY: &str = "SELECT tag, source FROM tags";
+        const QUERY: &str = "SELECT tag, source, expires_after, keep_all, aux_filter FROM tags";
 
         conn.prepare(QUERY)?
-            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .query_map([], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+            })
             .expect("no binding parameters used in query")
             .map(|result| {
-                Ok(result?).and_then(|(tag, source): (Vec<u8>, Vec<u8>)| {
-                    Ok(NoteTagRecord {
-                        tag: NoteTag::read_from_bytes(&tag)
-                            .map_err(StoreError::DataDeserializationError)?,
-                        source: NoteTagSource::read_from_bytes(&source)
-                            .map_err(StoreError::DataDeserializationError)?,
-                    })
-                })
+                Ok(result?).and_then(
+                    |(tag, source, expires_after, keep_all, aux_filter): (
+                        Vec<u8>,
+                        Vec<u8>,
+                        Option<u32>,
+                        bool,
+                        Option<i64>,
+                    )| {
+                        Ok(NoteTagRecord {
+                            tag: NoteTag::read_from_bytes(&tag)
+                                .map_err(StoreError::DataDeserializationError)?,
+                            source: NoteTagSource::read_from_bytes(&source)
+                                .map_err(StoreError::DataDeserializationError)?,
+                            expires_after,
+                            keep_all,
+                            aux_filter: aux_filter.map(|aux| Felt::new(aux as u64)),
+                        })
+                    },
+                )
             })
             .collect::<Result<Vec<NoteTagRecord>, _>>()
     }
@@ -77,6 +101,90 @@ impl SqliteStore {
         Ok(removed_tags)
     }
 
+    pub(super) fn replace_note_tags(
+        conn: &mut Connection,
+        removed: &[NoteTagRecord],
+        added: &[NoteTagRecord],
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction()?;
+
+        for tag in removed {
+            remove_note_tag_tx(&tx, *tag)?;
+        }
+        for tag in added {
+            add_note_tag_tx(&tx, tag)?;
+        }
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    pub(super) fn get_storage_slot_watches(
+        conn: &mut Connection,
+    ) -> Result<Vec<StorageSlotWatch>, StoreError> {
+        const QUERY: &str = "SELECT account_id, slot_index, map_key FROM storage_slot_watches";
+
+        conn.prepare(QUERY)?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .expect("no binding parameters used in query")
+            .map(|result| {
+                Ok(result?).and_then(
+                    |(account_id, slot_index, map_key): (i64, u8, Option<Vec<u8>>)| {
+                        Ok(StorageSlotWatch {
+                            account_id: AccountId::try_from(account_id as u64)?,
+                            slot_index,
+                            key: map_key
+                                .map(|key| Word::read_from_bytes(&key))
+                                .transpose()
+                                .map_err(StoreError::DataDeserializationError)?,
+                        })
+                    },
+                )
+            })
+            .collect::<Result<Vec<StorageSlotWatch>, _>>()
+    }
+
+    pub(super) fn add_storage_slot_watch(
+        conn: &mut Connection,
+        watch: StorageSlotWatch,
+    ) -> Result<bool, StoreError> {
+        if Self::get_storage_slot_watches(conn)?.contains(&watch) {
+            return Ok(false);
+        }
+
+        const QUERY: &str =
+            "INSERT INTO storage_slot_watches (account_id, slot_index, map_key) VALUES (?, ?, ?)";
+        conn.execute(
+            QUERY,
+            params![
+                u64::from(watch.account_id) as i64,
+                watch.slot_index,
+                watch.key.map(|key| key.to_bytes())
+            ],
+        )?;
+
+        Ok(true)
+    }
+
+    pub(super) fn remove_storage_slot_watch(
+        conn: &mut Connection,
+        watch: StorageSlotWatch,
+    ) -> Result<usize, StoreError> {
+        const QUERY: &str = "DELETE FROM storage_slot_watches
+            WHERE account_id = ? AND slot_index = ? AND map_key IS ?";
+        let removed_watches = conn.execute(
+            QUERY,
+            params![
+                u64::from(watch.account_id) as i64,
+                watch.slot_index,
+                watch.key.map(|key| key.to_bytes())
+            ],
+        )?;
+
+        Ok(removed_watches)
+    }
+
     pub(super) fn get_sync_height(conn: &mut Connection) -> Result<u32, StoreError> {
         const QUERY: &str = "SELECT block_num FROM state_sync";
 
@@ -88,6 +196,80 @@ impl SqliteStore {
             .expect("state sync block number exists")
     }
 
+    pub(super) fn get_last_sync_summary(
+        conn: &mut Connection,
+    ) -> Result<Option<SyncSummary>, StoreError> {
+        const QUERY: &str = "SELECT last_sync_summary FROM state_sync";
+
+        let summary: Option<Vec<u8>> = conn
+            .prepare(QUERY)?
+            .query_map([], |row| row.get(0))
+            .expect("no binding parameters used in query")
+            .next()
+            .expect("state sync row exists")?;
+
+        summary
+            .map(|summary| {
+                SyncSummary::read_from_bytes(&summary).map_err(StoreError::DataDeserializationError)
+            })
+            .transpose()
+    }
+
+    pub(super) fn set_last_sync_summary(
+        conn: &mut Connection,
+        summary: &SyncSummary,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "UPDATE state_sync SET last_sync_summary = ?";
+        conn.execute(QUERY, params![summary.to_bytes()])?;
+
+        Ok(())
+    }
+
+    pub(super) fn get_partial_sync_progress(
+        conn: &mut Connection,
+    ) -> Result<Option<PartialSyncProgress>, StoreError> {
+        const QUERY: &str = "SELECT partial_sync_progress FROM state_sync";
+
+        let progress: Option<Vec<u8>> = conn
+            .prepare(QUERY)?
+            .query_map([], |row| row.get(0))
+            .expect("no binding parameters used in query")
+            .next()
+            .expect("state sync row exists")?;
+
+        progress
+            .map(|progress| {
+                PartialSyncProgress::read_from_bytes(&progress)
+                    .map_err(StoreError::DataDeserializationError)
+            })
+            .transpose()
+    }
+
+    pub(super) fn set_partial_sync_progress(
+        conn: &mut Connection,
+        progress: Option<&PartialSyncProgress>,
+    ) -> Result<(), StoreError> {
+        conn.execute(
+            SET_PARTIAL_SYNC_PROGRESS_QUERY,
+            params![progress.map(|progress| progress.to_bytes())],
+        )?;
+
+        Ok(())
+    }
+
+    pub(super) fn apply_note_batch(
+        conn: &mut Connection,
+        note_updates: &NoteUpdates,
+        progress: &PartialSyncProgress,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction()?;
+
+        apply_note_updates_tx(&tx, note_updates)?;
+        tx.execute(SET_PARTIAL_SYNC_PROGRESS_QUERY, params![progress.to_bytes()])?;
+
+        Ok(tx.commit()?)
+    }
+
     pub(super) fn apply_state_sync(
         conn: &mut Connection,
         state_sync_update: StateSyncUpdate,
@@ -102,18 +284,21 @@ impl SqliteStore {
             block_has_relevant_notes,
             transactions_to_discard: discarded_transactions,
             tags_to_remove,
+            future_note_resolutions,
         } = state_sync_update;
 
         let tx = conn.transaction()?;
 
-        // Update state sync block number
-        const BLOCK_NUMBER_QUERY: &str = "UPDATE state_sync SET block_num = ?";
+        // Update state sync block number, which completes the partially synced block if any
+        const BLOCK_NUMBER_QUERY: &str =
+            "UPDATE state_sync SET block_num = ?, partial_sync_progress = NULL";
         tx.execute(BLOCK_NUMBER_QUERY, params![block_header.block_num()])?;
 
         Self::insert_block_header_tx(&tx, block_header, new_mmr_peaks, block_has_relevant_notes)?;
 
         // Update notes
         apply_note_updates_tx(&tx, &note_updates)?;
+        apply_future_note_resolutions_tx(&tx, &future_note_resolutions)?;
 
         // Remove tags
         for tag in tags_to_remove {
@@ -127,7 +312,11 @@ impl SqliteStore {
         Self::mark_transactions_as_committed(&tx, &committed_transactions)?;
 
         // Marc transactions as discarded
-        Self::mark_transactions_as_discarded(&tx, &discarded_transactions)?;
+        Self::mark_transactions_as_discarded(
+            &tx,
+            &discarded_transactions,
+            DiscardCause::InputNoteConsumedExternally,
+        )?;
 
         // Update onchain accounts on the db that have been updated onchain
         for account in updated_onchain_accounts {
@@ -139,11 +328,79 @@ impl SqliteStore {
 
         Ok(())
     }
+
+    pub(super) fn reset_chain_state(
+        conn: &mut Connection,
+        reset: ChainStateReset,
+    ) -> Result<(), StoreError> {
+        let ChainStateReset {
+            note_updates,
+            transactions_to_discard,
+            keep_accounts,
+            tags_to_add,
+            tags_to_remove,
+        } = reset;
+
+        let tx = conn.transaction()?;
+
+        tx.execute_batch(
+            "DELETE FROM block_headers;
+            DELETE FROM chain_mmr_nodes;
+            DELETE FROM mmr_checkpoint;
+            DELETE FROM account_locks;
+            UPDATE state_sync
+                SET block_num = 0, last_sync_summary = NULL, partial_sync_progress = NULL;",
+        )?;
+
+        apply_note_updates_tx(&tx, &note_updates)?;
+        for tag in &tags_to_add {
+            add_note_tag_tx(&tx, tag)?;
+        }
+        Self::mark_transactions_as_discarded(
+            &tx,
+            &transactions_to_discard,
+            DiscardCause::ChainReset,
+        )?;
+
+        if !keep_accounts {
+            for tag in tags_to_remove {
+                remove_note_tag_tx(&tx, tag)?;
+            }
+
+            tx.execute_batch(
+                "DELETE FROM faucet_mints;
+                DELETE FROM transaction_notes;
+                DELETE FROM transactions;
+                DELETE FROM storage_slot_watches;
+                DELETE FROM account_unlocks;
+                DELETE FROM account_auth;
+                DELETE FROM pending_account_auth;
+                DELETE FROM accounts;
+                DELETE FROM account_code;
+                DELETE FROM account_storage;
+                DELETE FROM account_vaults;",
+            )?;
+        }
+
+        tx.commit()?;
+
+        Ok(())
+    }
 }
 
 pub(super) fn add_note_tag_tx(tx: &Transaction<'_>, tag: &NoteTagRecord) -> Result<(), StoreError> {
-    const QUERY: &str = "INSERT INTO tags (tag, source) VALUES (?, ?)";
-    tx.execute(QUERY, params![tag.tag.to_bytes(), tag.source.to_bytes()])?;
+    const QUERY: &str =
+        "INSERT INTO tags (tag, source, expires_after, keep_all, aux_filter) VALUES (?, ?, ?, ?, ?)";
+    tx.execute(
+        QUERY,
+        params![
+            tag.tag.to_bytes(),
+            tag.source.to_bytes(),
+            tag.expires_after,
+            tag.keep_all,
+            tag.aux_filter.map(|aux| aux.as_int() as i64)
+        ],
+    )?;
 
     Ok(())
 }
@@ -157,3 +414,84 @@ pub(super) fn remove_note_tag_tx(
 
     Ok(removed_tags)
 }
+
+/// Adds the last sync summary column to the state_sync table of databases created before the
+/// summary of the last sync was persisted.
+pub(super) fn add_last_sync_summary_column(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM pragma_table_info('state_sync') WHERE name = 'last_sync_summary'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding the last sync summary column to the state_sync table");
+    conn.execute("ALTER TABLE state_sync ADD COLUMN last_sync_summary BLOB NULL", [])?;
+
+    Ok(())
+}
+
+/// Adds the partial sync progress column to the state_sync table of databases created before the
+/// progress of partially synced blocks was persisted.
+pub(super) fn add_partial_sync_progress_column(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM pragma_table_info('state_sync') WHERE name = 'partial_sync_progress'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding the partial sync progress column to the state_sync table");
+    conn.execute("ALTER TABLE state_sync ADD COLUMN partial_sync_progress BLOB NULL", [])?;
+
+    Ok(())
+}
+
+/// Adds the `expires_after` column to the tags table of databases created before it was
+/// introduced. Existing tags don't expire.
+pub(super) fn add_tag_expiry_column(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str = "SELECT 1 FROM pragma_table_info('tags') WHERE name = 'expires_after'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding the expiry column to the tags table");
+    conn.execute("ALTER TABLE tags ADD COLUMN expires_after UNSIGNED BIG INT NULL", [])?;
+
+    Ok(())
+}
+
+/// Adds the subscription columns to the tags table of databases created before tag subscriptions
+/// were introduced. Existing tags keep every note received with them.
+pub(super) fn add_tag_subscription_columns(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str = "SELECT 1 FROM pragma_table_info('tags') WHERE name = 'keep_all'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding the subscription columns to the tags table");
+    conn.execute_batch(
+        "ALTER TABLE tags ADD COLUMN keep_all BOOLEAN NOT NULL DEFAULT 1;
+        ALTER TABLE tags ADD COLUMN aux_filter UNSIGNED BIG INT NULL;",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the storage slot watches table in databases created before it was introduced.
+pub(super) fn create_storage_slot_watches_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'storage_slot_watches'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the storage_slot_watches table");
+    conn.execute_batch(
+        "CREATE TABLE storage_slot_watches (
+            account_id UNSIGNED BIG INT NOT NULL,
+            slot_index UNSIGNED INT NOT NULL,
+            map_key BLOB NULL
+        );",
+    )?;
+
+    Ok(())
+}
@@ -5,29 +5,44 @@ use alloc::{
 };
 
 use miden_objects::{
-    accounts::AccountId,
+    accounts::{AccountId, AccountType},
     crypto::utils::{Deserializable, Serializable},
+    notes::NoteId,
     transaction::{
         ExecutedTransaction, OutputNotes, ToInputNoteCommitments, TransactionId, TransactionScript,
     },
     Digest,
 };
-use rusqlite::{params, Connection, Transaction};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
 use tracing::info;
 
 use super::{
-    accounts::update_account, notes::apply_note_updates_tx, sync::add_note_tag_tx, SqliteStore,
+    accounts::update_account,
+    notes::{apply_note_updates_tx, insert_future_note_links_tx, DELETE_NOTE_ASSETS_QUERY},
+    sync::{add_note_tag_tx, remove_note_tag_tx},
+    SqliteStore,
 };
 use crate::{
+    accounts::{minted_amount, FaucetMintRecord},
     rpc::TransactionUpdate,
     store::{StoreError, TransactionFilter},
-    transactions::{TransactionRecord, TransactionStatus, TransactionStoreUpdate},
+    transactions::{
+        DiscardCause, LocalTransactionDiscard, TransactionProvenance, TransactionRecord,
+        TransactionStatus, TransactionStoreUpdate,
+    },
 };
 
 pub(crate) const INSERT_TRANSACTION_QUERY: &str =
     "INSERT INTO transactions (id, account_id, init_account_state, final_account_state, \
-    input_notes, output_notes, script_hash, block_num, commit_height, discarded) \
-    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)";
+    input_notes, output_notes, script_hash, block_num, commit_height, discarded, rpc_endpoint, \
+    client_version, prover, submission_attempts) \
+    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)";
+
+pub(crate) const INSERT_FAUCET_MINT_QUERY: &str =
+    "INSERT INTO faucet_mints (transaction_id, faucet_id, amount) VALUES (?, ?, ?)";
+
+pub(crate) const INSERT_TRANSACTION_NOTE_QUERY: &str =
+    "INSERT OR IGNORE INTO transaction_notes (transaction_id, note_id) VALUES (?, ?)";
 
 pub(crate) const INSERT_TRANSACTION_SCRIPT_QUERY: &str =
     "INSERT OR IGNORE INTO transaction_scripts (script_hash, script) \
@@ -41,7 +56,8 @@ impl TransactionFilter {
     pub fn to_query(&self) -> String {
         const QUERY: &str = "SELECT tx.id, tx.account_id, tx.init_account_state, tx.final_account_state, \
             tx.input_notes, tx.output_notes, tx.script_hash, script.script, tx.block_num, tx.commit_height, \
-            tx.discarded
+            tx.discarded, tx.rpc_endpoint, tx.client_version, tx.prover, tx.submission_attempts, \
+            tx.discard_cause
             FROM transactions AS tx LEFT JOIN transaction_scripts AS script ON tx.script_hash = script.script_hash";
         match self {
             TransactionFilter::All => QUERY.to_string(),
@@ -64,9 +80,12 @@ type SerializedTransactionData = (
     Option<Vec<u8>>,
     u32,
     Option<u32>,
-    bool,
+    (bool, Option<Vec<u8>>),
+    SerializedProvenanceData,
 );
 
+type SerializedProvenanceData = (Option<String>, Option<String>, Option<String>, Option<u32>);
+
 impl SqliteStore {
     /// Retrieves tracked transactions, filtered by [TransactionFilter].
     pub fn get_transactions(
@@ -88,7 +107,13 @@ impl SqliteStore {
         let tx = conn.transaction()?;
 
         // Transaction Data
-        insert_proven_transaction_data(&tx, tx_update.executed_transaction())?;
+        insert_proven_transaction_data(
+            &tx,
+            tx_update.executed_transaction(),
+            tx_update.provenance(),
+        )?;
+        insert_faucet_mint_tx(&tx, tx_update.executed_transaction())?;
+        insert_transaction_notes_tx(&tx, tx_update.executed_transaction())?;
 
         // Account Data
         update_account(&tx, tx_update.updated_account())?;
@@ -100,11 +125,214 @@ impl SqliteStore {
             add_note_tag_tx(&tx, tag_record)?;
         }
 
+        insert_future_note_links_tx(&tx, tx_update.future_note_links())?;
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Reverts the changes of a local transaction and marks it as discarded.
+    pub fn discard_local_transaction(
+        conn: &mut Connection,
+        discard: LocalTransactionDiscard,
+    ) -> Result<(), StoreError> {
+        const DELETE_ACCOUNT_STATES_QUERY: &str = "DELETE FROM accounts WHERE id = ? AND nonce > ?";
+        const DELETE_INPUT_NOTE_QUERY: &str = "DELETE FROM input_notes WHERE note_id = ?";
+        const DELETE_OUTPUT_NOTE_QUERY: &str = "DELETE FROM output_notes WHERE note_id = ?";
+        const DELETE_LINKS_QUERY: &str = "DELETE FROM future_note_links WHERE source_note_id = ?";
+
+        let LocalTransactionDiscard {
+            transaction_id,
+            account_id,
+            init_account_nonce,
+            note_updates,
+            removed_note_ids,
+            tags_to_add,
+            tags_to_remove,
+        } = discard;
+
+        let tx = conn.transaction()?;
+
+        tx.execute(
+            DELETE_ACCOUNT_STATES_QUERY,
+            params![u64::from(account_id) as i64, init_account_nonce as i64],
+        )?;
+
+        apply_note_updates_tx(&tx, &note_updates)?;
+        for note_id in removed_note_ids {
+            let note_id = note_id.inner().to_string();
+            tx.execute(DELETE_INPUT_NOTE_QUERY, params![note_id])?;
+            tx.execute(DELETE_NOTE_ASSETS_QUERY, params![note_id])?;
+            tx.execute(DELETE_OUTPUT_NOTE_QUERY, params![note_id])?;
+            tx.execute(DELETE_LINKS_QUERY, params![note_id])?;
+        }
+
+        for tag in &tags_to_add {
+            add_note_tag_tx(&tx, tag)?;
+        }
+        for tag in tags_to_remove {
+            remove_note_tag_tx(&tx, tag)?;
+        }
+
+        Self::mark_transactions_as_discarded(
+            &tx,
+            &[transaction_id],
+            DiscardCause::ManuallyDiscarded,
+        )?;
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Retrieves the mints executed by the specified faucet, excluding discarded transactions.
+    pub fn get_faucet_mints(
+        conn: &mut Connection,
+        faucet_id: AccountId,
+    ) -> Result<Vec<FaucetMintRecord>, StoreError> {
+        const QUERY: &str = "SELECT mint.transaction_id, mint.amount \
+            FROM faucet_mints AS mint JOIN transactions AS tx ON mint.transaction_id = tx.id \
+            WHERE mint.faucet_id = ? AND NOT tx.discarded";
+
+        conn.prepare(QUERY)?
+            .query_map(params![u64::from(faucet_id) as i64], |row| {
+                let transaction_id: String = row.get(0)?;
+                let amount: i64 = row.get(1)?;
+                Ok((transaction_id, amount))
+            })?
+            .map(|result| {
+                let (transaction_id, amount) = result?;
+                let transaction_id: Digest = transaction_id.try_into()?;
+                Ok(FaucetMintRecord {
+                    transaction_id: transaction_id.into(),
+                    amount: amount as u64,
+                })
+            })
+            .collect()
+    }
+
+    /// Retrieves the transactions that consumed or created the note with the specified ID.
+    pub fn get_note_transactions(
+        conn: &mut Connection,
+        note_id: NoteId,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        let query = format!(
+            "{} WHERE tx.id IN (SELECT transaction_id FROM transaction_notes WHERE note_id = ?)",
+            TransactionFilter::All.to_query()
+        );
+
+        conn.prepare(&query)?
+            .query_map(params![note_id.inner().to_string()], parse_transaction_columns)?
+            .map(|result| Ok(result?).and_then(parse_transaction))
+            .collect()
+    }
+
+    /// Stores the serialized proven transaction with the specified ID as the most recently used
+    /// one, and evicts the least recently used proofs until the retained ones take up to
+    /// `max_total_size` bytes.
+    pub fn insert_proven_transaction(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
+    ) -> Result<(), StoreError> {
+        const INSERT_QUERY: &str = "INSERT OR REPLACE INTO proven_transactions \
+            (transaction_id, proven_transaction, last_used) \
+            VALUES (?, ?, (SELECT COALESCE(MAX(last_used), 0) + 1 FROM proven_transactions))";
+        const SIZES_QUERY: &str = "SELECT transaction_id, length(proven_transaction) \
+            FROM proven_transactions ORDER BY last_used DESC";
+        const DELETE_QUERY: &str = "DELETE FROM proven_transactions WHERE transaction_id = ?";
+
+        let tx = conn.transaction()?;
+        tx.execute(INSERT_QUERY, params![transaction_id.inner().to_string(), proven_transaction])?;
+
+        let sizes = tx
+            .prepare(SIZES_QUERY)?
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut total_size = 0;
+        for (evicted_id, size) in sizes {
+            total_size += size as usize;
+            if total_size > max_total_size {
+                tx.execute(DELETE_QUERY, params![evicted_id])?;
+            }
+        }
+
         tx.commit()?;
 
         Ok(())
     }
 
+    /// Retrieves the serialized proven transaction with the specified ID, if it's retained, and
+    /// marks it as the most recently used one.
+    pub fn get_proven_transaction(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<Option<Vec<u8>>, StoreError> {
+        const QUERY: &str =
+            "SELECT proven_transaction FROM proven_transactions WHERE transaction_id = ?";
+        const TOUCH_QUERY: &str = "UPDATE proven_transactions \
+            SET last_used = (SELECT MAX(last_used) + 1 FROM proven_transactions) \
+            WHERE transaction_id = ?";
+
+        let transaction_id = transaction_id.inner().to_string();
+        let proven_transaction: Option<Vec<u8>> =
+            conn.query_row(QUERY, params![transaction_id], |row| row.get(0)).optional()?;
+        if proven_transaction.is_some() {
+            conn.execute(TOUCH_QUERY, params![transaction_id])?;
+        }
+
+        Ok(proven_transaction)
+    }
+
+    /// Records a new attempt to submit the transaction with the specified ID and returns the
+    /// number of attempts recorded for it.
+    pub fn record_submission_attempt(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+    ) -> Result<u32, StoreError> {
+        const QUERY: &str =
+            "INSERT INTO transaction_submissions (transaction_id, account_id, attempts) \
+            VALUES (?, ?, 1) \
+            ON CONFLICT(transaction_id) DO UPDATE SET attempts = attempts + 1 \
+            RETURNING attempts";
+
+        Ok(conn.query_row(
+            QUERY,
+            params![transaction_id.inner().to_string(), u64::from(account_id) as i64],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Retrieves the number of attempts recorded to submit the transaction with the specified ID.
+    pub fn get_submission_attempts(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<u32, StoreError> {
+        const QUERY: &str = "SELECT attempts FROM transaction_submissions WHERE transaction_id = ?";
+
+        let attempts: Option<u32> = conn
+            .query_row(QUERY, params![transaction_id.inner().to_string()], |row| row.get(0))
+            .optional()?;
+
+        Ok(attempts.unwrap_or(0))
+    }
+
+    /// Removes the recorded attempts to submit the transaction with the specified ID.
+    pub fn remove_submission_attempts(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "DELETE FROM transaction_submissions WHERE transaction_id = ?";
+
+        conn.execute(QUERY, params![transaction_id.inner().to_string()])?;
+
+        Ok(())
+    }
+
     /// Set the provided transactions as committed
     ///
     /// # Errors
@@ -131,7 +359,7 @@ impl SqliteStore {
         Ok(rows)
     }
 
-    /// Set the provided transactions as committed
+    /// Set the provided transactions as discarded, recording the cause of the discard
     ///
     /// # Errors
     ///
@@ -140,11 +368,13 @@ impl SqliteStore {
     pub(crate) fn mark_transactions_as_discarded(
         tx: &Transaction<'_>,
         transactions_to_discard: &[TransactionId],
+        cause: DiscardCause,
     ) -> Result<usize, StoreError> {
         let mut rows = 0;
         for transaction_id in transactions_to_discard {
-            const QUERY: &str = "UPDATE transactions set discarded=true where id=?";
-            rows += tx.execute(QUERY, params![transaction_id.to_string()])?;
+            const QUERY: &str =
+                "UPDATE transactions set discarded=true, discard_cause=? where id=?";
+            rows += tx.execute(QUERY, params![cause.to_bytes(), transaction_id.to_string()])?;
         }
 
         Ok(rows)
@@ -154,6 +384,7 @@ impl SqliteStore {
 pub(super) fn insert_proven_transaction_data(
     tx: &Transaction<'_>,
     executed_transaction: &ExecutedTransaction,
+    provenance: Option<&TransactionProvenance>,
 ) -> Result<(), StoreError> {
     let (
         transaction_id,
@@ -166,8 +397,9 @@ pub(super) fn insert_proven_transaction_data(
         tx_script,
         block_num,
         committed,
-        discarded,
-    ) = serialize_transaction_data(executed_transaction)?;
+        (discarded, _discard_cause),
+        (rpc_endpoint, client_version, prover, submission_attempts),
+    ) = serialize_transaction_data(executed_transaction, provenance)?;
 
     if let Some(hash) = script_hash.clone() {
         tx.execute(INSERT_TRANSACTION_SCRIPT_QUERY, params![hash, tx_script])?;
@@ -186,14 +418,63 @@ pub(super) fn insert_proven_transaction_data(
             block_num,
             committed,
             discarded,
+            rpc_endpoint,
+            client_version,
+            prover,
+            submission_attempts,
         ],
     )?;
 
     Ok(())
 }
 
+/// Records the amount minted by the transaction if it was executed by a fungible faucet.
+fn insert_faucet_mint_tx(
+    tx: &Transaction<'_>,
+    executed_transaction: &ExecutedTransaction,
+) -> Result<(), StoreError> {
+    let faucet_id = executed_transaction.account_id();
+    if faucet_id.account_type() != AccountType::FungibleFaucet {
+        return Ok(());
+    }
+
+    let amount = minted_amount(faucet_id, executed_transaction.output_notes());
+    if amount > 0 {
+        let transaction_id: String = executed_transaction.id().inner().into();
+        tx.execute(
+            INSERT_FAUCET_MINT_QUERY,
+            params![transaction_id, u64::from(faucet_id) as i64, amount as i64],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Records the IDs of the notes consumed and created by the transaction.
+fn insert_transaction_notes_tx(
+    tx: &Transaction<'_>,
+    executed_transaction: &ExecutedTransaction,
+) -> Result<(), StoreError> {
+    let transaction_id: String = executed_transaction.id().inner().into();
+    let note_ids = executed_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .chain(executed_transaction.output_notes().iter().map(|note| note.id()));
+
+    for note_id in note_ids {
+        tx.execute(
+            INSERT_TRANSACTION_NOTE_QUERY,
+            params![transaction_id, note_id.inner().to_string()],
+        )?;
+    }
+
+    Ok(())
+}
+
 pub(super) fn serialize_transaction_data(
     executed_transaction: &ExecutedTransaction,
+    provenance: Option<&TransactionProvenance>,
 ) -> Result<SerializedTransactionData, StoreError> {
     let transaction_id: String = executed_transaction.id().inner().into();
     let account_id: u64 = executed_transaction.account_id().into();
@@ -230,10 +511,175 @@ pub(super) fn serialize_transaction_data(
         tx_script,
         executed_transaction.block_header().block_num(),
         None,
-        false,
+        (false, None),
+        (
+            provenance.map(|provenance| provenance.rpc_endpoint.clone()),
+            provenance.map(|provenance| provenance.client_version.clone()),
+            provenance.and_then(|provenance| provenance.prover.clone()),
+            provenance.map(|provenance| provenance.submission_attempts),
+        ),
     ))
 }
 
+/// Adds the provenance columns to the transactions table of databases created before they were
+/// introduced.
+pub(super) fn add_provenance_columns(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM pragma_table_info('transactions') WHERE name = 'submission_attempts'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding provenance columns to the transactions table");
+    conn.execute_batch(
+        "ALTER TABLE transactions ADD COLUMN rpc_endpoint TEXT NULL; \
+        ALTER TABLE transactions ADD COLUMN client_version TEXT NULL; \
+        ALTER TABLE transactions ADD COLUMN prover TEXT NULL; \
+        ALTER TABLE transactions ADD COLUMN submission_attempts UNSIGNED INT NULL;",
+    )?;
+
+    Ok(())
+}
+
+/// Adds the column recording why a transaction was discarded to databases created before it was
+/// recorded. Transactions discarded before then have no cause.
+pub(super) fn add_discard_cause_column(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM pragma_table_info('transactions') WHERE name = 'discard_cause'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Adding the discard cause column to the transactions table");
+    conn.execute("ALTER TABLE transactions ADD COLUMN discard_cause BLOB NULL", [])?;
+
+    Ok(())
+}
+
+/// Creates the `faucet_mints` table in databases created before the issuance of faucets was
+/// tracked. Mints of transactions executed before the migration aren't recorded.
+pub(super) fn create_faucet_mints_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'faucet_mints'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the faucet_mints table");
+    conn.execute_batch(
+        "CREATE TABLE faucet_mints (
+            transaction_id TEXT NOT NULL,
+            faucet_id UNSIGNED BIG INT NOT NULL,
+            amount UNSIGNED BIG INT NOT NULL,
+            PRIMARY KEY (transaction_id),
+            FOREIGN KEY (transaction_id) REFERENCES transactions(id)
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the `proven_transactions` table in databases created before the proofs of submitted
+/// transactions could be retained.
+pub(super) fn create_proven_transactions_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'proven_transactions'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the proven_transactions table");
+    conn.execute_batch(
+        "CREATE TABLE proven_transactions (
+            transaction_id TEXT NOT NULL,
+            proven_transaction BLOB NOT NULL,
+            last_used UNSIGNED BIG INT NOT NULL,
+            PRIMARY KEY (transaction_id)
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the `transaction_submissions` table in databases created before the attempts to submit
+/// transactions were recorded.
+pub(super) fn create_transaction_submissions_table(
+    conn: &mut Connection,
+) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'transaction_submissions'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the transaction_submissions table");
+    conn.execute_batch(
+        "CREATE TABLE transaction_submissions (
+            transaction_id TEXT NOT NULL,
+            account_id UNSIGNED BIG INT NOT NULL,
+            attempts UNSIGNED INT NOT NULL,
+            PRIMARY KEY (transaction_id)
+        );",
+    )?;
+
+    Ok(())
+}
+
+/// Creates the `transaction_notes` table in databases created before it was introduced and fills
+/// it with the notes of the stored transactions. Consumed notes are only stored as nullifiers, so
+/// they are matched against the tracked input notes.
+pub(super) fn create_transaction_notes_table(conn: &mut Connection) -> Result<(), StoreError> {
+    const QUERY: &str =
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'transaction_notes'";
+    if conn.prepare(QUERY)?.exists([])? {
+        return Ok(());
+    }
+
+    info!("Creating the transaction_notes table");
+    let tx = conn.transaction()?;
+    tx.execute_batch(
+        "CREATE TABLE transaction_notes (
+            transaction_id TEXT NOT NULL,
+            note_id TEXT NOT NULL,
+            PRIMARY KEY (transaction_id, note_id),
+            FOREIGN KEY (transaction_id) REFERENCES transactions(id)
+        );
+        CREATE INDEX idx_transaction_notes_note_id ON transaction_notes(note_id);",
+    )?;
+
+    let transactions = tx
+        .prepare("SELECT id, input_notes, output_notes FROM transactions")?
+        .query_map([], |row| {
+            let id: String = row.get(0)?;
+            let input_notes: Vec<u8> = row.get(1)?;
+            let output_notes: Vec<u8> = row.get(2)?;
+            Ok((id, input_notes, output_notes))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (transaction_id, input_notes, output_notes) in transactions {
+        let nullifiers = Vec::<Digest>::read_from_bytes(&input_notes)?;
+        for nullifier in nullifiers {
+            tx.execute(
+                "INSERT OR IGNORE INTO transaction_notes (transaction_id, note_id) \
+                SELECT ?, note_id FROM input_notes WHERE nullifier = ?",
+                params![transaction_id, nullifier.to_string()],
+            )?;
+        }
+
+        for note in OutputNotes::read_from_bytes(&output_notes)?.iter() {
+            tx.execute(
+                INSERT_TRANSACTION_NOTE_QUERY,
+                params![transaction_id, note.id().inner().to_string()],
+            )?;
+        }
+    }
+
+    tx.commit()?;
+
+    Ok(())
+}
+
 fn parse_transaction_columns(
     row: &rusqlite::Row<'_>,
 ) -> Result<SerializedTransactionData, rusqlite::Error> {
@@ -248,6 +694,11 @@ fn parse_transaction_columns(
     let block_num: u32 = row.get(8)?;
     let commit_height: Option<u32> = row.get(9)?;
     let discarded: bool = row.get(10)?;
+    let rpc_endpoint: Option<String> = row.get(11)?;
+    let client_version: Option<String> = row.get(12)?;
+    let prover: Option<String> = row.get(13)?;
+    let submission_attempts: Option<u32> = row.get(14)?;
+    let discard_cause: Option<Vec<u8>> = row.get(15)?;
 
     Ok((
         id,
@@ -260,7 +711,8 @@ fn parse_transaction_columns(
         tx_script,
         block_num,
         commit_height,
-        discarded,
+        (discarded, discard_cause),
+        (rpc_endpoint, client_version, prover, submission_attempts),
     ))
 }
 
@@ -279,7 +731,8 @@ fn parse_transaction(
         tx_script,
         block_num,
         commit_height,
-        discarded,
+        (discarded, discard_cause),
+        (rpc_endpoint, client_version, prover, submission_attempts),
     ) = serialized_transaction;
     let account_id = AccountId::try_from(account_id as u64)?;
     let id: Digest = id.try_into()?;
@@ -296,12 +749,28 @@ fn parse_transaction(
         .map(|script| TransactionScript::read_from_bytes(&script))
         .transpose()?;
 
+    let discard_cause =
+        discard_cause.map(|cause| DiscardCause::read_from_bytes(&cause)).transpose()?;
+
     let transaction_status = if discarded {
         TransactionStatus::Discarded
     } else {
         commit_height.map_or(TransactionStatus::Pending, TransactionStatus::Committed)
     };
 
+    // All the provenance columns are set together when the transaction is submitted
+    let provenance = match (rpc_endpoint, client_version, submission_attempts) {
+        (Some(rpc_endpoint), Some(client_version), Some(submission_attempts)) => {
+            Some(TransactionProvenance {
+                rpc_endpoint,
+                client_version,
+                prover,
+                submission_attempts,
+            })
+        },
+        _ => None,
+    };
+
     Ok(TransactionRecord {
         id: id.into(),
         account_id,
@@ -312,5 +781,7 @@ fn parse_transaction(
         transaction_script,
         block_num,
         transaction_status,
+        provenance,
+        discard_cause,
     })
 }
@@ -11,12 +11,18 @@ extern "C" {
     #[wasm_bindgen(js_name = getAccountIds)]
     pub fn idxdb_get_account_ids() -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getAccountIdsByPrefix)]
+    pub fn idxdb_get_account_ids_by_prefix(prefix: String) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = getAllAccountHeaders)]
     pub fn idxdb_get_account_headers() -> js_sys::Promise;
 
     #[wasm_bindgen(js_name = getAccountHeader)]
     pub fn idxdb_get_account_header(account_id: String) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getAccountHistory)]
+    pub fn idxdb_get_account_history(account_id: String) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = getAccountHeaderByHash)]
     pub fn idxdb_get_account_header_by_hash(account_hash: String) -> js_sys::Promise;
 
@@ -38,6 +44,15 @@ extern "C" {
     #[wasm_bindgen(js_name = fetchAndCacheAccountAuthByPubKey)]
     pub fn idxdb_fetch_and_cache_account_auth_by_pub_key(account_id: String) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getAccountLock)]
+    pub fn idxdb_get_account_lock(account_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getAccountUnlocks)]
+    pub fn idxdb_get_account_unlocks(account_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getOrphanedAccountAuthIds)]
+    pub fn idxdb_get_orphaned_account_auth_ids() -> js_sys::Promise;
+
     // INSERTS
     // ================================================================================================
 
@@ -72,4 +87,68 @@ extern "C" {
         auth_info: Vec<u8>,
         pub_key: Vec<u8>,
     ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = insertPendingAccountAuth)]
+    pub fn idxdb_insert_pending_account_auth(
+        transaction_id: String,
+        account_id: String,
+        auth_info: Vec<u8>,
+        pub_key: Vec<u8>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getPendingAccountAuths)]
+    pub fn idxdb_get_pending_account_auths() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = resolvePendingAccountAuth)]
+    pub fn idxdb_resolve_pending_account_auth(
+        transaction_id: String,
+        committed: bool,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = upsertAccountLock)]
+    pub fn idxdb_upsert_account_lock(
+        account_id: String,
+        remote_hash: String,
+        divergence_accepted: bool,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = reserveAccountId)]
+    pub fn idxdb_reserve_account_id(account_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = insertAccountUnlock)]
+    pub fn idxdb_insert_account_unlock(
+        account_id: String,
+        strategy: String,
+        local_hash: String,
+        remote_hash: String,
+        block_num: String,
+    ) -> js_sys::Promise;
+
+    // DELETES
+    // ================================================================================================
+
+    #[wasm_bindgen(js_name = deleteAccountStatesFromNonce)]
+    pub fn idxdb_delete_account_states_from_nonce(
+        account_id: String,
+        nonce: String,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = removeAccountLock)]
+    pub fn idxdb_remove_account_lock(account_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = releaseAccountId)]
+    pub fn idxdb_release_account_id(account_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = removeOrphanedAccountAuth)]
+    pub fn idxdb_remove_orphaned_account_auth(account_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = removeAccount)]
+    pub fn idxdb_remove_account(
+        account_id: String,
+        removed_transaction_ids: Vec<String>,
+        discarded_transaction_ids: Vec<String>,
+        discard_cause: Vec<u8>,
+        removed_input_note_ids: Vec<String>,
+        removed_output_note_ids: Vec<String>,
+    ) -> js_sys::Promise;
 }
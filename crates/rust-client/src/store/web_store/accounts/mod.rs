@@ -6,19 +6,25 @@ use alloc::{
 use miden_objects::{
     accounts::{Account, AccountCode, AccountHeader, AccountId, AccountStorage, AuthSecretKey},
     assets::{Asset, AssetVault},
+    notes::NoteId,
+    transaction::TransactionId,
     Digest, Word,
 };
 use miden_tx::utils::{Deserializable, DeserializationError, Serializable};
 use serde_wasm_bindgen::from_value;
 use wasm_bindgen_futures::*;
 
-use super::WebStore;
-use crate::store::StoreError;
+use super::{transactions::utils::update_account, WebStore};
+use crate::{
+    accounts::{AccountLockStatus, AccountRemoval, AccountUnlockRecord},
+    store::StoreError,
+    transactions::DiscardCause,
+};
 
 mod js_bindings;
 use js_bindings::*;
 
-mod models;
+pub(crate) mod models;
 use models::*;
 
 pub(crate) mod utils;
@@ -38,6 +44,22 @@ impl WebStore {
         Ok(native_account_ids)
     }
 
+    pub(super) async fn get_account_ids_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<AccountId>, StoreError> {
+        let promise = idxdb_get_account_ids_by_prefix(prefix.to_string());
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let account_ids_as_strings: Vec<String> = from_value(js_value).unwrap();
+
+        let native_account_ids: Vec<AccountId> = account_ids_as_strings
+            .into_iter()
+            .map(|id| AccountId::from_hex(&id).unwrap())
+            .collect();
+
+        Ok(native_account_ids)
+    }
+
     pub(super) async fn get_account_headers(
         &self,
     ) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError> {
@@ -111,6 +133,43 @@ impl WebStore {
         account_id: AccountId,
     ) -> Result<(Account, Option<Word>), StoreError> {
         let (account_header, seed) = self.get_account_header(account_id).await?;
+        let account = self.get_account_from_header(&account_header).await?;
+
+        Ok((account, seed))
+    }
+
+    pub(crate) async fn get_account_history(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<(Account, Digest)>, StoreError> {
+        let promise = idxdb_get_account_history(account_id.to_string());
+        let js_value = JsFuture::from(promise)
+            .await
+            .map_err(|_| StoreError::AccountDataNotFound(account_id))?;
+        let history_idxdb: Vec<AccountHistoryIdxdbObject> =
+            from_value(js_value).map_err(|err| {
+                StoreError::DataDeserializationError(DeserializationError::InvalidValue(format!(
+                    "Failed to deserialize {:?}",
+                    err
+                )))
+            })?;
+
+        let mut history = Vec::with_capacity(history_idxdb.len());
+        for record in history_idxdb {
+            let account_hash = Digest::try_from(&record.account_hash)?;
+            let (account_header, _seed) = parse_account_record_idxdb_object(record.header)?;
+            let account = self.get_account_from_header(&account_header).await?;
+            history.push((account, account_hash));
+        }
+
+        Ok(history)
+    }
+
+    /// Builds the full state of an account from its stored header.
+    async fn get_account_from_header(
+        &self,
+        account_header: &AccountHeader,
+    ) -> Result<Account, StoreError> {
         let account_code = self.get_account_code(account_header.code_commitment()).await.unwrap();
 
         let account_storage =
@@ -126,7 +185,7 @@ impl WebStore {
             account_header.nonce(),
         );
 
-        Ok((account, seed))
+        Ok(account)
     }
 
     pub(super) async fn get_account_code(&self, root: Digest) -> Result<AccountCode, StoreError> {
@@ -198,11 +257,85 @@ impl WebStore {
         Ok(auth_info)
     }
 
+    pub(crate) async fn insert_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+        auth_info: &AuthSecretKey,
+    ) -> Result<(), StoreError> {
+        let pub_key = match auth_info {
+            AuthSecretKey::RpoFalcon512(secret) => Word::from(secret.public_key()),
+        }
+        .to_bytes();
+
+        let promise = idxdb_insert_pending_account_auth(
+            transaction_id.inner().to_string(),
+            account_id.to_string(),
+            auth_info.to_bytes(),
+            pub_key,
+        );
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!(
+                "Failed to insert pending account auth: {:?}",
+                js_error
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_pending_account_auths(
+        &self,
+    ) -> Result<Vec<(TransactionId, AccountId)>, StoreError> {
+        let promise = idxdb_get_pending_account_auths();
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!(
+                "Failed to fetch pending account auths: {:?}",
+                js_error
+            ))
+        })?;
+
+        let pending_auths_idxdb: Vec<PendingAccountAuthIdxdbObject> = from_value(js_value)
+            .map_err(|err| {
+                StoreError::DataDeserializationError(DeserializationError::InvalidValue(format!(
+                    "Failed to deserialize {:?}",
+                    err
+                )))
+            })?;
+
+        pending_auths_idxdb
+            .into_iter()
+            .map(|pending_auth| {
+                Ok((
+                    Digest::try_from(&pending_auth.transaction_id)?.into(),
+                    AccountId::from_hex(&pending_auth.account_id)?,
+                ))
+            })
+            .collect()
+    }
+
+    pub(crate) async fn resolve_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        committed: bool,
+    ) -> Result<(), StoreError> {
+        let promise =
+            idxdb_resolve_pending_account_auth(transaction_id.inner().to_string(), committed);
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!(
+                "Failed to resolve pending account auth: {:?}",
+                js_error
+            ))
+        })?;
+
+        Ok(())
+    }
+
     pub(crate) async fn insert_account(
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         insert_account_code(account.code()).await.unwrap();
 
@@ -212,11 +345,228 @@ impl WebStore {
 
         insert_account_record(account, account_seed).await.unwrap();
 
-        insert_account_auth(account.id(), auth_info).await.unwrap();
+        if let Some(auth_info) = auth_info {
+            insert_account_auth(account.id(), auth_info).await.unwrap();
+        }
+
+        self.release_account_id(account.id()).await
+    }
+
+    pub(crate) async fn reserve_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        let promise = idxdb_reserve_account_id(account_id.to_string());
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to reserve account ID: {:?}", js_error))
+        })?;
+
+        if js_value.as_bool() != Some(true) {
+            return Err(StoreError::AccountIdUnavailable(account_id));
+        }
 
         Ok(())
     }
 
+    pub(crate) async fn release_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        let promise = idxdb_release_account_id(account_id.to_string());
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to release account ID: {:?}", js_error))
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_orphaned_account_auth_ids(&self) -> Result<Vec<AccountId>, StoreError> {
+        let promise = idxdb_get_orphaned_account_auth_ids();
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to fetch orphaned auths: {:?}", js_error))
+        })?;
+
+        let account_ids: Vec<String> = from_value(js_value).map_err(|err| {
+            StoreError::DataDeserializationError(DeserializationError::InvalidValue(format!(
+                "Failed to deserialize {:?}",
+                err
+            )))
+        })?;
+
+        account_ids.iter().map(|id| Ok(AccountId::from_hex(id)?)).collect()
+    }
+
+    pub(crate) async fn remove_orphaned_account_auth(
+        &self,
+        account_id: AccountId,
+    ) -> Result<bool, StoreError> {
+        let promise = idxdb_remove_orphaned_account_auth(account_id.to_string());
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to remove account auth: {:?}", js_error))
+        })?;
+
+        Ok(js_value.as_bool() == Some(true))
+    }
+
+    pub(crate) async fn remove_account(&self, removal: AccountRemoval) -> Result<(), StoreError> {
+        let AccountRemoval {
+            account_id,
+            removed_transaction_ids,
+            discarded_transaction_ids,
+            removed_input_note_ids,
+            removed_output_note_ids,
+            tags_to_remove,
+        } = removal;
+
+        for watch in self.get_storage_slot_watches().await? {
+            if watch.account_id == account_id {
+                self.remove_storage_slot_watch(watch).await?;
+            }
+        }
+        for tag in tags_to_remove {
+            self.remove_note_tag(tag).await?;
+        }
+
+        let transaction_ids = |ids: Vec<TransactionId>| -> Vec<String> {
+            ids.iter().map(|id| id.inner().to_string()).collect()
+        };
+        let note_ids = |ids: Vec<NoteId>| -> Vec<String> {
+            ids.iter().map(|id| id.inner().to_string()).collect()
+        };
+        let promise = idxdb_remove_account(
+            account_id.to_string(),
+            transaction_ids(removed_transaction_ids),
+            transaction_ids(discarded_transaction_ids),
+            DiscardCause::AccountRemoved.to_bytes(),
+            note_ids(removed_input_note_ids),
+            note_ids(removed_output_note_ids),
+        );
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to remove account: {:?}", js_error))
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_account_lock_status(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountLockStatus, StoreError> {
+        let promise = idxdb_get_account_lock(account_id.to_string());
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to fetch account lock: {:?}", js_error))
+        })?;
+
+        let account_lock_idxdb: Option<AccountLockIdxdbObject> =
+            from_value(js_value).map_err(|err| {
+                StoreError::DataDeserializationError(DeserializationError::InvalidValue(format!(
+                    "Failed to deserialize {:?}",
+                    err
+                )))
+            })?;
+
+        match account_lock_idxdb {
+            None => Ok(AccountLockStatus::Unlocked),
+            Some(lock) => {
+                let remote_hash = Digest::try_from(&lock.remote_hash)?;
+                Ok(if lock.divergence_accepted {
+                    AccountLockStatus::DivergenceAccepted { remote_hash }
+                } else {
+                    AccountLockStatus::Locked { remote_hash }
+                })
+            },
+        }
+    }
+
+    pub(crate) async fn set_account_lock_status(
+        &self,
+        account_id: AccountId,
+        status: AccountLockStatus,
+    ) -> Result<(), StoreError> {
+        let promise = match status {
+            AccountLockStatus::Unlocked => idxdb_remove_account_lock(account_id.to_string()),
+            AccountLockStatus::Locked { remote_hash } => {
+                idxdb_upsert_account_lock(account_id.to_string(), remote_hash.to_string(), false)
+            },
+            AccountLockStatus::DivergenceAccepted { remote_hash } => {
+                idxdb_upsert_account_lock(account_id.to_string(), remote_hash.to_string(), true)
+            },
+        };
+
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to update account lock: {:?}", js_error))
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn apply_account_unlock(
+        &self,
+        record: AccountUnlockRecord,
+        new_state: Option<Account>,
+    ) -> Result<(), StoreError> {
+        let status = match new_state {
+            Some(account) => {
+                let promise = idxdb_delete_account_states_from_nonce(
+                    account.id().to_string(),
+                    account.nonce().to_string(),
+                );
+                JsFuture::from(promise).await.map_err(|js_error| {
+                    StoreError::DatabaseError(format!(
+                        "Failed to remove account states: {:?}",
+                        js_error
+                    ))
+                })?;
+
+                insert_account_code(account.code()).await.unwrap();
+                update_account(&account).await.unwrap();
+                AccountLockStatus::Unlocked
+            },
+            None => AccountLockStatus::DivergenceAccepted { remote_hash: record.remote_hash },
+        };
+        self.set_account_lock_status(record.account_id, status).await?;
+
+        let promise = idxdb_insert_account_unlock(
+            record.account_id.to_string(),
+            record.strategy.to_string(),
+            record.local_hash.to_string(),
+            record.remote_hash.to_string(),
+            record.block_num.to_string(),
+        );
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to record account unlock: {:?}", js_error))
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_account_unlock_records(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountUnlockRecord>, StoreError> {
+        let promise = idxdb_get_account_unlocks(account_id.to_string());
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to fetch account unlocks: {:?}", js_error))
+        })?;
+
+        let account_unlocks_idxdb: Vec<AccountUnlockIdxdbObject> =
+            from_value(js_value).map_err(|err| {
+                StoreError::DataDeserializationError(DeserializationError::InvalidValue(format!(
+                    "Failed to deserialize {:?}",
+                    err
+                )))
+            })?;
+
+        account_unlocks_idxdb
+            .into_iter()
+            .map(|unlock| {
+                Ok(AccountUnlockRecord {
+                    account_id,
+                    strategy: unlock.strategy.parse().map_err(StoreError::ParsingError)?,
+                    local_hash: Digest::try_from(&unlock.local_hash)?,
+                    remote_hash: Digest::try_from(&unlock.remote_hash)?,
+                    block_num: unlock.block_num.parse().map_err(
+                        |err: core::num::ParseIntError| StoreError::ParsingError(err.to_string()),
+                    )?,
+                })
+            })
+            .collect()
+    }
+
     /// Returns an [AuthSecretKey] by a public key represented by a [Word]
     pub fn get_account_auth_by_pub_key(&self, pub_key: Word) -> Result<AuthSecretKey, StoreError> {
         let pub_key_bytes = pub_key.to_bytes();
@@ -42,6 +42,35 @@ pub struct AccountRecordIdxdbOjbect {
     pub account_seed: Option<Vec<u8>>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct AccountHistoryIdxdbObject {
+    #[serde(flatten)]
+    pub header: AccountRecordIdxdbOjbect,
+    pub account_hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AccountLockIdxdbObject {
+    pub account_id: String,
+    pub remote_hash: String,
+    pub divergence_accepted: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AccountUnlockIdxdbObject {
+    pub account_id: String,
+    pub strategy: String,
+    pub local_hash: String,
+    pub remote_hash: String,
+    pub block_num: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PendingAccountAuthIdxdbObject {
+    pub transaction_id: String,
+    pub account_id: String,
+}
+
 fn base64_to_vec_u8_required<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
 where
     D: Deserializer<'de>,
@@ -24,6 +24,9 @@ extern "C" {
     #[wasm_bindgen(js_name = getChainMmrPeaksByBlockNum)]
     pub fn idxdb_get_chain_mmr_peaks_by_block_num(block_num: String) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = loadMmrCheckpoint)]
+    pub fn idxdb_load_mmr_checkpoint() -> js_sys::Promise;
+
     // INSERTS
     // ================================================================================================
 
@@ -37,4 +40,10 @@ extern "C" {
 
     #[wasm_bindgen(js_name = insertChainMmrNodes)]
     pub fn idxdb_insert_chain_mmr_nodes(ids: Vec<String>, nodes: Vec<String>) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = replaceChainMmrNodes)]
+    pub fn idxdb_replace_chain_mmr_nodes(ids: Vec<String>, nodes: Vec<String>) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = saveMmrCheckpoint)]
+    pub fn idxdb_save_mmr_checkpoint(block_num: String, partial_mmr: Vec<u8>) -> js_sys::Promise;
 }
@@ -5,10 +5,10 @@ use alloc::{
 };
 
 use miden_objects::{
-    crypto::merkle::{InOrderIndex, MmrPeaks},
+    crypto::merkle::{InOrderIndex, MmrPeaks, PartialMmr},
     BlockHeader, Digest,
 };
-use miden_tx::utils::Deserializable;
+use miden_tx::utils::{Deserializable, Serializable};
 use serde_wasm_bindgen::from_value;
 use wasm_bindgen_futures::JsFuture;
 
@@ -152,6 +152,53 @@ impl WebStore {
         Ok(())
     }
 
+    pub(crate) async fn replace_chain_mmr_nodes(
+        &self,
+        nodes: &[(InOrderIndex, Digest)],
+    ) -> Result<(), StoreError> {
+        let mut serialized_node_ids = Vec::new();
+        let mut serialized_nodes = Vec::new();
+        for (id, node) in nodes.iter() {
+            let serialized_data = serialize_chain_mmr_node(*id, *node)?;
+            serialized_node_ids.push(serialized_data.id);
+            serialized_nodes.push(serialized_data.node);
+        }
+
+        let promise = idxdb_replace_chain_mmr_nodes(serialized_node_ids, serialized_nodes);
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(crate) async fn save_mmr_checkpoint(
+        &self,
+        block_num: u32,
+        partial_mmr: &PartialMmr,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_save_mmr_checkpoint(block_num.to_string(), partial_mmr.to_bytes());
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(crate) async fn load_mmr_checkpoint(
+        &self,
+    ) -> Result<Option<(u32, PartialMmr)>, StoreError> {
+        let promise = idxdb_load_mmr_checkpoint();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let checkpoint_idxdb: Option<MmrCheckpointIdxdbObject> = from_value(js_value).unwrap();
+
+        checkpoint_idxdb
+            .map(|checkpoint| {
+                let block_num = checkpoint.block_num.parse::<u32>().map_err(|err| {
+                    StoreError::ParsingError(format!("invalid checkpoint block number: {err}"))
+                })?;
+
+                Ok((block_num, PartialMmr::read_from_bytes(&checkpoint.partial_mmr)?))
+            })
+            .transpose()
+    }
+
     /// This function is not used in this crate, rather it is used in the 'miden-client' crate.
     /// https://github.com/0xPolygonMiden/miden-client/blob/c273847726ed325d2e627e4db18bf9f3ab8c28ba/src/store/sqlite_store/sync.rs#L105
     /// It is duplicated here due to its reliance on the store.
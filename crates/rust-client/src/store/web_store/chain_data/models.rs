@@ -14,9 +14,9 @@ pub struct BlockHeaderIdxdbObject {
 }
 
 #[derive(Serialize, Deserialize)]
-pub struct ChainMmrNodeIdxdbObject {
-    pub id: String,
-    pub node: String,
+pub struct ChainMmrNodesIdxdbObject {
+    pub ids: String,
+    pub nodes: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -25,6 +25,13 @@ pub struct MmrPeaksIdxdbObject {
     pub peaks: Option<Vec<u8>>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct MmrCheckpointIdxdbObject {
+    pub block_num: String,
+    #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
+    pub partial_mmr: Vec<u8>,
+}
+
 fn base64_to_vec_u8_required<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
 where
     D: Deserializer<'de>,
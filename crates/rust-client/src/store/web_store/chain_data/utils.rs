@@ -10,7 +10,7 @@ use miden_tx::utils::Serializable;
 use serde_wasm_bindgen::from_value;
 use wasm_bindgen::JsValue;
 
-use crate::store::{web_store::chain_data::ChainMmrNodeIdxdbObject, StoreError};
+use crate::store::{web_store::chain_data::ChainMmrNodesIdxdbObject, StoreError};
 
 pub struct SerializedBlockHeaderData {
     pub block_num: String,
@@ -54,17 +54,17 @@ pub fn serialize_chain_mmr_node(
 pub fn process_chain_mmr_nodes_from_js_value(
     js_value: JsValue,
 ) -> Result<BTreeMap<InOrderIndex, Digest>, StoreError> {
-    let chain_mmr_nodes_idxdb: Vec<ChainMmrNodeIdxdbObject> = from_value(js_value).unwrap();
+    let chain_mmr_nodes_idxdb: ChainMmrNodesIdxdbObject = from_value(js_value).unwrap();
 
-    let results: Result<BTreeMap<InOrderIndex, Digest>, StoreError> = chain_mmr_nodes_idxdb
-        .into_iter()
-        .map(|record| {
-            let id_as_u64: u64 = record.id.parse::<u64>().unwrap();
+    chain_mmr_nodes_idxdb
+        .ids
+        .split_terminator(',')
+        .zip(chain_mmr_nodes_idxdb.nodes.split_terminator(','))
+        .map(|(id, node)| {
+            let id_as_u64: u64 = id.parse::<u64>().unwrap();
             let id = InOrderIndex::new(NonZeroUsize::new(id_as_u64 as usize).unwrap());
-            let node = Digest::try_from(&record.node)?;
+            let node = Digest::try_from(node)?;
             Ok((id, node))
         })
-        .collect();
-
-    results
+        .collect()
 }
@@ -1,9 +1,20 @@
+import Dexie from "dexie";
 import {
+  db,
   accountCodes,
   accountStorages,
   accountVaults,
   accountAuths,
+  pendingAccountAuths,
+  accountReservations,
   accounts,
+  accountLocks,
+  accountUnlocks,
+  inputNotes,
+  outputNotes,
+  provenTransactions,
+  transactions,
+  transactionSubmissions,
 } from "./schema.js";
 
 // GET FUNCTIONS
@@ -23,52 +34,71 @@ export async function getAccountIds() {
   }
 }
 
-export async function getAllAccountHeaders() {
+export async function getAccountIdsByPrefix(prefix) {
   try {
-    // Use a Map to track the latest record for each id based on nonce
-    const latestRecordsMap = new Map();
-
-    await accounts.each((record) => {
-      const existingRecord = latestRecordsMap.get(record.id);
-      if (
-        !existingRecord ||
-        BigInt(record.nonce) > BigInt(existingRecord.nonce)
-      ) {
-        latestRecordsMap.set(record.id, record);
-      }
-    });
-
-    // Extract the latest records from the Map
-    const latestRecords = Array.from(latestRecordsMap.values());
-
-    const resultObject = await Promise.all(
-      latestRecords.map(async (record) => {
-        let accountSeedBase64 = null;
-        if (record.accountSeed) {
-          // Ensure accountSeed is processed as a Uint8Array and converted to Base64
-          let accountSeedArrayBuffer = await record.accountSeed.arrayBuffer();
-          let accountSeedArray = new Uint8Array(accountSeedArrayBuffer);
-          accountSeedBase64 = uint8ArrayToBase64(accountSeedArray);
-        }
-
-        return {
-          id: record.id,
-          nonce: record.nonce,
-          vault_root: record.vaultRoot,
-          storage_root: record.storageRoot,
-          code_root: record.codeRoot,
-          account_seed: accountSeedBase64, // Now correctly formatted as Base64
-        };
-      })
-    );
+    // Accounts are only indexed by `[id+nonce]`, so the prefix is turned into a range over the
+    // first component of the compound key
+    const keys = await accounts
+      .where("[id+nonce]")
+      .between([prefix, Dexie.minKey], [prefix + "\uffff", Dexie.maxKey])
+      .primaryKeys();
+
+    return Array.from(new Set(keys.map(([id]) => id)));
+  } catch (error) {
+    console.error("Failed to retrieve account IDs by prefix: ", error);
+    throw error;
+  }
+}
 
-    return resultObject;
+export async function getAllAccountHeaders() {
+  try {
+    return await processAccountHeaders(await accounts.toArray());
   } catch (error) {
     console.error("Error fetching all latest account headers:", error);
     throw error;
   }
 }
 
+// Returns the header of the latest state of each account among the provided records
+export async function processAccountHeaders(records) {
+  // Use a Map to track the latest record for each id based on nonce
+  const latestRecordsMap = new Map();
+
+  records.forEach((record) => {
+    const existingRecord = latestRecordsMap.get(record.id);
+    if (
+      !existingRecord ||
+      BigInt(record.nonce) > BigInt(existingRecord.nonce)
+    ) {
+      latestRecordsMap.set(record.id, record);
+    }
+  });
+
+  // Extract the latest records from the Map
+  const latestRecords = Array.from(latestRecordsMap.values());
+
+  return await Promise.all(
+    latestRecords.map(async (record) => {
+      let accountSeedBase64 = null;
+      if (record.accountSeed) {
+        // Ensure accountSeed is processed as a Uint8Array and converted to Base64
+        let accountSeedArrayBuffer = await record.accountSeed.arrayBuffer();
+        let accountSeedArray = new Uint8Array(accountSeedArrayBuffer);
+        accountSeedBase64 = uint8ArrayToBase64(accountSeedArray);
+      }
+
+      return {
+        id: record.id,
+        nonce: record.nonce,
+        vault_root: record.vaultRoot,
+        storage_root: record.storageRoot,
+        code_root: record.codeRoot,
+        account_seed: accountSeedBase64, // Now correctly formatted as Base64
+      };
+    })
+  );
+}
+
 export async function getAccountHeader(accountId) {
   try {
     // Fetch all records matching the given id
@@ -116,6 +146,50 @@ export async function getAccountHeader(accountId) {
   }
 }
 
+export async function getAccountHistory(accountId) {
+  try {
+    const allMatchingRecords = await accounts
+      .where("id")
+      .equals(accountId)
+      .toArray();
+
+    if (allMatchingRecords.length === 0) {
+      console.log("No records found for given ID.");
+      throw new Error("No records found for given ID.");
+    }
+
+    // Oldest state first
+    const sortedRecords = allMatchingRecords.sort((a, b) => {
+      const bigIntA = BigInt(a.nonce);
+      const bigIntB = BigInt(b.nonce);
+      return bigIntA < bigIntB ? -1 : bigIntA > bigIntB ? 1 : 0;
+    });
+
+    return await Promise.all(
+      sortedRecords.map(async (record) => {
+        let accountSeedBase64 = null;
+        if (record.accountSeed) {
+          let accountSeedArrayBuffer = await record.accountSeed.arrayBuffer();
+          let accountSeedArray = new Uint8Array(accountSeedArrayBuffer);
+          accountSeedBase64 = uint8ArrayToBase64(accountSeedArray);
+        }
+        return {
+          id: record.id,
+          nonce: record.nonce,
+          vault_root: record.vaultRoot,
+          storage_root: record.storageRoot,
+          code_root: record.codeRoot,
+          account_seed: accountSeedBase64,
+          account_hash: record.accountHash,
+        };
+      })
+    );
+  } catch (error) {
+    console.error("Error fetching account history:", error);
+    throw error;
+  }
+}
+
 export async function getAccountHeaderByHash(accountHash) {
   try {
     // Fetch all records matching the given hash
@@ -325,6 +399,22 @@ export async function fetchAndCacheAccountAuthByPubKey(accountId) {
       auth_info: authInfoBase64,
     });
 
+    // Keys of pending rotations are cached as well, as the local state of the account already
+    // uses them
+    const pendingRecords = await pendingAccountAuths
+      .where("accountId")
+      .equals(accountId)
+      .toArray();
+    for (const pendingRecord of pendingRecords) {
+      const pendingAuthInfoArray = new Uint8Array(
+        await pendingRecord.authInfo.arrayBuffer()
+      );
+      ACCOUNT_AUTH_MAP.set(pendingRecord.pubKey, {
+        id: pendingRecord.accountId,
+        auth_info: uint8ArrayToBase64(pendingAuthInfoArray),
+      });
+    }
+
     return {
       id: authRecord.accountId,
       auth_info: authInfoBase64,
@@ -428,6 +518,110 @@ export async function insertAccountRecord(
   }
 }
 
+export async function deleteAccountStatesFromNonce(accountId, nonce) {
+  try {
+    await accounts
+      .where("id")
+      .equals(accountId)
+      .and((record) => BigInt(record.nonce) >= BigInt(nonce))
+      .delete();
+  } catch (error) {
+    console.error(
+      `Error deleting states of account ${accountId} from nonce ${nonce}:`,
+      error
+    );
+    throw error;
+  }
+}
+
+// ACCOUNT LOCKS
+
+export async function getAccountLock(accountId) {
+  try {
+    const lock = await accountLocks.get(accountId);
+    if (!lock) {
+      return null;
+    }
+
+    return {
+      account_id: lock.accountId,
+      remote_hash: lock.remoteHash,
+      divergence_accepted: lock.divergenceAccepted,
+    };
+  } catch (error) {
+    console.error(`Error fetching lock of account ${accountId}:`, error);
+    throw error;
+  }
+}
+
+export async function upsertAccountLock(
+  accountId,
+  remoteHash,
+  divergenceAccepted
+) {
+  try {
+    await accountLocks.put({
+      accountId: accountId,
+      remoteHash: remoteHash,
+      divergenceAccepted: divergenceAccepted,
+    });
+  } catch (error) {
+    console.error(`Error locking account ${accountId}:`, error);
+    throw error;
+  }
+}
+
+export async function removeAccountLock(accountId) {
+  try {
+    await accountLocks.delete(accountId);
+  } catch (error) {
+    console.error(`Error removing lock of account ${accountId}:`, error);
+    throw error;
+  }
+}
+
+export async function insertAccountUnlock(
+  accountId,
+  strategy,
+  localHash,
+  remoteHash,
+  blockNum
+) {
+  try {
+    await accountUnlocks.add({
+      accountId: accountId,
+      strategy: strategy,
+      localHash: localHash,
+      remoteHash: remoteHash,
+      blockNum: blockNum,
+    });
+  } catch (error) {
+    console.error(`Error recording unlock of account ${accountId}:`, error);
+    throw error;
+  }
+}
+
+export async function getAccountUnlocks(accountId) {
+  try {
+    // Records are returned in insertion order, as given by the auto-incremented key
+    const records = await accountUnlocks
+      .where("accountId")
+      .equals(accountId)
+      .sortBy("id");
+
+    return records.map((record) => ({
+      account_id: record.accountId,
+      strategy: record.strategy,
+      local_hash: record.localHash,
+      remote_hash: record.remoteHash,
+      block_num: record.blockNum,
+    }));
+  } catch (error) {
+    console.error(`Error fetching unlocks of account ${accountId}:`, error);
+    throw error;
+  }
+}
+
 export async function insertAccountAuth(accountId, authInfo, pubKey) {
   try {
     let authInfoBlob = new Blob([new Uint8Array(authInfo)]);
@@ -449,6 +643,242 @@ export async function insertAccountAuth(accountId, authInfo, pubKey) {
   }
 }
 
+export async function insertPendingAccountAuth(
+  transactionId,
+  accountId,
+  authInfo,
+  pubKey
+) {
+  try {
+    let authInfoBlob = new Blob([new Uint8Array(authInfo)]);
+    let pubKeyBase64 = uint8ArrayToBase64(new Uint8Array(pubKey));
+
+    await pendingAccountAuths.put({
+      transactionId: transactionId,
+      accountId: accountId,
+      authInfo: authInfoBlob,
+      pubKey: pubKeyBase64,
+    });
+  } catch (error) {
+    console.error(
+      `Error inserting pending auth for account: ${accountId}:`,
+      error
+    );
+    throw error;
+  }
+}
+
+export async function getPendingAccountAuths() {
+  try {
+    const records = await pendingAccountAuths.toArray();
+
+    return records.map((record) => ({
+      transaction_id: record.transactionId,
+      account_id: record.accountId,
+    }));
+  } catch (error) {
+    console.error("Error fetching pending account auths:", error);
+    throw error;
+  }
+}
+
+export async function resolvePendingAccountAuth(transactionId, committed) {
+  try {
+    const pendingRecord = await db.transaction(
+      "rw",
+      pendingAccountAuths,
+      accountAuths,
+      async () => {
+        const pendingRecord = await pendingAccountAuths.get(transactionId);
+        if (!pendingRecord) {
+          return undefined;
+        }
+
+        if (committed) {
+          await accountAuths.put({
+            accountId: pendingRecord.accountId,
+            authInfo: pendingRecord.authInfo,
+            pubKey: pendingRecord.pubKey,
+          });
+        }
+        await pendingAccountAuths.delete(transactionId);
+        return pendingRecord;
+      }
+    );
+
+    if (!pendingRecord) {
+      return;
+    }
+
+    // Keep the cached keys in sync, so the replaced or discarded key can't be used anymore
+    if (committed) {
+      for (const [cachedPubKey, cachedAuth] of ACCOUNT_AUTH_MAP) {
+        if (cachedAuth.id === pendingRecord.accountId) {
+          ACCOUNT_AUTH_MAP.delete(cachedPubKey);
+        }
+      }
+      const authInfoArray = new Uint8Array(
+        await pendingRecord.authInfo.arrayBuffer()
+      );
+      ACCOUNT_AUTH_MAP.set(pendingRecord.pubKey, {
+        id: pendingRecord.accountId,
+        auth_info: uint8ArrayToBase64(authInfoArray),
+      });
+    } else {
+      ACCOUNT_AUTH_MAP.delete(pendingRecord.pubKey);
+    }
+  } catch (error) {
+    console.error(
+      `Error resolving pending auth of transaction: ${transactionId}:`,
+      error
+    );
+    throw error;
+  }
+}
+
+// ACCOUNT RESERVATIONS
+
+export async function reserveAccountId(accountId) {
+  try {
+    // The checks and the insertion share a transaction, so that concurrent reservations of the
+    // same ID can't both succeed
+    return await db.transaction(
+      "rw",
+      accountReservations,
+      accounts,
+      accountAuths,
+      async () => {
+        const reserved = await accountReservations.get(accountId);
+        const accountCount = await accounts
+          .where("id")
+          .equals(accountId)
+          .count();
+        const auth = await accountAuths.get(accountId);
+        if (reserved || accountCount > 0 || auth) {
+          return false;
+        }
+
+        await accountReservations.add({ accountId: accountId });
+        return true;
+      }
+    );
+  } catch (error) {
+    console.error(`Error reserving account ID ${accountId}:`, error);
+    throw error;
+  }
+}
+
+export async function releaseAccountId(accountId) {
+  try {
+    await accountReservations.delete(accountId);
+  } catch (error) {
+    console.error(`Error releasing account ID ${accountId}:`, error);
+    throw error;
+  }
+}
+
+export async function getOrphanedAccountAuthIds() {
+  try {
+    const accountIds = new Set();
+    await accounts.each((account) => accountIds.add(account.id));
+    const reservedIds = new Set(await accountReservations.toCollection().primaryKeys());
+
+    const authIds = await accountAuths.toCollection().primaryKeys();
+    return authIds.filter((id) => !accountIds.has(id) && !reservedIds.has(id));
+  } catch (error) {
+    console.error("Error fetching orphaned account auths:", error);
+    throw error;
+  }
+}
+
+export async function removeOrphanedAccountAuth(accountId) {
+  try {
+    return await db.transaction(
+      "rw",
+      accountReservations,
+      accounts,
+      accountAuths,
+      async () => {
+        const reserved = await accountReservations.get(accountId);
+        const accountCount = await accounts
+          .where("id")
+          .equals(accountId)
+          .count();
+        const auth = await accountAuths.get(accountId);
+        if (reserved || accountCount > 0 || !auth) {
+          return false;
+        }
+
+        await accountAuths.delete(accountId);
+        return true;
+      }
+    );
+  } catch (error) {
+    console.error(`Error removing auth of account ${accountId}:`, error);
+    throw error;
+  }
+}
+
+// Removes the states, key, lock and unlocks of an account along with the specified transactions
+// and notes, and marks its kept pending transactions as discarded with the serialized
+// `discardCause`. The tags and storage slot watches of the account are removed separately.
+export async function removeAccount(
+  accountId,
+  removedTransactionIds,
+  discardedTransactionIds,
+  discardCause,
+  removedInputNoteIds,
+  removedOutputNoteIds
+) {
+  try {
+    await db.transaction(
+      "rw",
+      [
+        accounts,
+        accountAuths,
+        pendingAccountAuths,
+        accountLocks,
+        accountUnlocks,
+        transactions,
+        provenTransactions,
+        transactionSubmissions,
+        inputNotes,
+        outputNotes,
+      ],
+      async (tx) => {
+        await tx.accounts.where("id").equals(accountId).delete();
+        await tx.accountAuth.delete(accountId);
+        await tx.pendingAccountAuth
+          .where("accountId")
+          .equals(accountId)
+          .delete();
+        await tx.accountLocks.delete(accountId);
+        await tx.accountUnlocks.where("accountId").equals(accountId).delete();
+
+        await tx.transactions.bulkDelete(removedTransactionIds);
+        await tx.provenTransactions.bulkDelete(removedTransactionIds);
+        await tx.transactionSubmissions
+          .where("accountId")
+          .equals(accountId)
+          .delete();
+        const discardCauseBase64 = uint8ArrayToBase64(new Uint8Array(discardCause));
+        for (const transactionId of discardedTransactionIds) {
+          await tx.transactions.update(transactionId, {
+            discarded: true,
+            discardCause: discardCauseBase64,
+          });
+        }
+
+        await tx.inputNotes.where("noteId").anyOf(removedInputNoteIds).delete();
+        await tx.outputNotes.where("noteId").anyOf(removedOutputNoteIds).delete();
+      }
+    );
+  } catch (error) {
+    console.error(`Error removing account ${accountId}:`, error);
+    throw error;
+  }
+}
+
 function uint8ArrayToBase64(bytes) {
   const binary = bytes.reduce(
     (acc, byte) => acc + String.fromCharCode(byte),
@@ -1,4 +1,4 @@
-import { blockHeaders, chainMmrNodes } from "./schema.js";
+import { db, blockHeaders, chainMmrNodes, mmrCheckpoint } from "./schema.js";
 
 // INSERT FUNCTIONS
 export async function insertBlockHeader(
@@ -18,23 +18,27 @@ export async function insertBlockHeader(
       hasClientNotes: hasClientNotes.toString(),
     };
 
-    const existingBlockHeader = await blockHeaders.get(blockNum);
+    await db.transaction("rw", blockHeaders, mmrCheckpoint, async () => {
+      const existingBlockHeader = await blockHeaders.get(blockNum);
 
-    if (!existingBlockHeader) {
-      await blockHeaders.add(data);
-    } else {
-      console.log("Block header already exists, checking for update.");
-
-      // Update the hasClientNotes if the existing value is false
-      if (existingBlockHeader.hasClientNotes === "false" && hasClientNotes) {
-        await blockHeaders.update(blockNum, {
-          hasClientNotes: hasClientNotes.toString(),
-        });
-        console.log("Updated hasClientNotes to true.");
+      if (!existingBlockHeader) {
+        await blockHeaders.add(data);
       } else {
-        console.log("No update needed for hasClientNotes.");
+        console.log("Block header already exists, checking for update.");
+
+        // Update the hasClientNotes if the existing value is false
+        if (existingBlockHeader.hasClientNotes === "false" && hasClientNotes) {
+          await blockHeaders.update(blockNum, {
+            hasClientNotes: hasClientNotes.toString(),
+          });
+          console.log("Updated hasClientNotes to true.");
+        } else {
+          console.log("No update needed for hasClientNotes.");
+        }
       }
-    }
+
+      await mmrCheckpoint.clear();
+    });
   } catch (err) {
     console.error("Failed to insert block header: ", err);
     throw err;
@@ -50,13 +54,53 @@ export async function insertChainMmrNodes(ids, nodes) {
       };
     });
 
-    await chainMmrNodes.bulkAdd(data);
+    await db.transaction("rw", chainMmrNodes, mmrCheckpoint, async () => {
+      await chainMmrNodes.bulkAdd(data);
+      await mmrCheckpoint.clear();
+    });
   } catch (err) {
     console.error("Failed to insert chain mmr nodes: ", err);
     throw err;
   }
 }
 
+export async function replaceChainMmrNodes(ids, nodes) {
+  try {
+    const data = nodes.map((node, index) => {
+      return {
+        id: ids[index],
+        node: node,
+      };
+    });
+
+    await db.transaction("rw", chainMmrNodes, mmrCheckpoint, async () => {
+      await chainMmrNodes.bulkPut(data);
+      await mmrCheckpoint.clear();
+    });
+  } catch (err) {
+    console.error("Failed to replace chain mmr nodes: ", err);
+    throw err;
+  }
+}
+
+export async function saveMmrCheckpoint(blockNum, partialMmr) {
+  try {
+    const partialMmrBlob = new Blob([new Uint8Array(partialMmr)]);
+
+    await db.transaction("rw", mmrCheckpoint, async () => {
+      await mmrCheckpoint.clear();
+      await mmrCheckpoint.put({
+        id: 1,
+        blockNum: blockNum,
+        partialMmr: partialMmrBlob,
+      });
+    });
+  } catch (err) {
+    console.error("Failed to save mmr checkpoint: ", err);
+    throw err;
+  }
+}
+
 // GET FUNCTIONS
 export async function getBlockHeaders(blockNumbers) {
   try {
@@ -150,7 +194,7 @@ export async function getChainMmrPeaksByBlockNum(blockNum) {
 export async function getChainMmrNodesAll() {
   try {
     const chainMmrNodesAll = await chainMmrNodes.toArray();
-    return chainMmrNodesAll;
+    return packChainMmrNodes(chainMmrNodesAll);
   } catch (err) {
     console.error("Failed to get chain mmr nodes: ", err);
     throw err;
@@ -161,13 +205,42 @@ export async function getChainMmrNodes(ids) {
   try {
     const results = await chainMmrNodes.bulkGet(ids);
 
-    return results;
+    return packChainMmrNodes(results.filter((record) => record !== undefined));
   } catch (err) {
     console.error("Failed to get chain mmr nodes: ", err);
     throw err;
   }
 }
 
+export async function loadMmrCheckpoint() {
+  try {
+    const checkpoint = await mmrCheckpoint.get(1);
+    if (!checkpoint) {
+      return null;
+    }
+
+    const partialMmrArrayBuffer = await checkpoint.partialMmr.arrayBuffer();
+    const partialMmrArray = new Uint8Array(partialMmrArrayBuffer);
+
+    return {
+      block_num: checkpoint.blockNum,
+      partial_mmr: uint8ArrayToBase64(partialMmrArray),
+    };
+  } catch (err) {
+    console.error("Failed to load mmr checkpoint: ", err);
+    throw err;
+  }
+}
+
+// Packs the nodes into two comma-separated strings, so that a single value crosses to wasm no
+// matter how many nodes are read
+function packChainMmrNodes(records) {
+  return {
+    ids: records.map((record) => record.id).join(","),
+    nodes: records.map((record) => record.node).join(","),
+  };
+}
+
 function uint8ArrayToBase64(bytes) {
   const binary = bytes.reduce(
     (acc, byte) => acc + String.fromCharCode(byte),
@@ -2,7 +2,11 @@ import {
   db,
   inputNotes,
   outputNotes,
+  archivedInputNotes,
+  pendingNotes,
+  futureNoteLinks,
   notesScripts,
+  stateSync,
   transactions,
 } from "./schema.js";
 
@@ -48,6 +52,27 @@ export async function getInputNotes(states) {
   }
 }
 
+export async function getInputNotesPage(states, offset, limit) {
+  try {
+    // Notes are ordered by ID so that the pages are stable
+    let page = inputNotes.orderBy("noteId");
+    let total;
+    if (states.length === 0) {
+      total = await inputNotes.count();
+    } else {
+      page = page.filter((note) => states.includes(note.stateDiscriminant));
+      total = await inputNotes.where("stateDiscriminant").anyOf(states).count();
+    }
+
+    const notes = await page.offset(offset).limit(limit).toArray();
+
+    return { notes: await processInputNotes(notes), total };
+  } catch (err) {
+    console.error("Failed to get input notes page: ", err);
+    throw err;
+  }
+}
+
 export async function getInputNotesFromIds(noteIds) {
   try {
     let notes;
@@ -119,6 +144,26 @@ export async function getUnspentInputNoteNullifiers() {
   }
 }
 
+export async function getNoteIdsByPrefix(prefix) {
+  try {
+    // `noteId` is the primary key of both tables, so `startsWith` is resolved with a key range
+    // instead of loading every note
+    const inputNoteIds = await inputNotes
+      .where("noteId")
+      .startsWith(prefix)
+      .primaryKeys();
+    const outputNoteIds = await outputNotes
+      .where("noteId")
+      .startsWith(prefix)
+      .primaryKeys();
+
+    return Array.from(new Set([...inputNoteIds, ...outputNoteIds]));
+  } catch (err) {
+    console.error("Failed to get note IDs by prefix: ", err);
+    throw err;
+  }
+}
+
 export async function upsertInputNote(
   noteId,
   assets,
@@ -133,37 +178,19 @@ export async function upsertInputNote(
 ) {
   return db.transaction("rw", inputNotes, notesScripts, async (tx) => {
     try {
-      let assetsBlob = new Blob([new Uint8Array(assets)]);
-      let serialNumberBlob = new Blob([new Uint8Array(serialNumber)]);
-      let inputsBlob = new Blob([new Uint8Array(inputs)]);
-      let stateBlob = new Blob([new Uint8Array(state)]);
-
-      // Prepare the data object to insert
-      const data = {
-        noteId: noteId,
-        assets: assetsBlob,
-        serialNumber: serialNumberBlob,
-        inputs: inputsBlob,
-        noteScriptHash: noteScriptHash,
-        nullifier: nullifier,
-        state: stateBlob,
-        stateDiscriminant: stateDiscriminant,
-        createdAt: serializedCreatedAt,
-      };
-
-      // Perform the insert using Dexie
-      await tx.inputNotes.put(data);
-
-      let serializedNoteScriptBlob = new Blob([
-        new Uint8Array(serializedNoteScript),
-      ]);
-
-      const noteScriptData = {
-        scriptHash: noteScriptHash,
-        serializedNoteScript: serializedNoteScriptBlob,
-      };
-
-      await tx.notesScripts.put(noteScriptData);
+      await putInputNote(
+        tx,
+        noteId,
+        assets,
+        serialNumber,
+        inputs,
+        noteScriptHash,
+        serializedNoteScript,
+        nullifier,
+        serializedCreatedAt,
+        stateDiscriminant,
+        state
+      );
     } catch {
       console.error(`Error inserting note: ${noteId}:`, error);
       throw error; // Rethrow the error to handle it further up the call chain if needed
@@ -171,6 +198,243 @@ export async function upsertInputNote(
   });
 }
 
+export async function archiveInputNotes(noteIds, records) {
+  return db.transaction(
+    "rw",
+    inputNotes,
+    archivedInputNotes,
+    async (tx) => {
+      try {
+        for (let i = 0; i < noteIds.length; i++) {
+          await tx.archivedInputNotes.put({
+            noteId: noteIds[i],
+            record: records[i],
+          });
+          await tx.inputNotes.where("noteId").equals(noteIds[i]).delete();
+        }
+      } catch (err) {
+        console.error("Failed to archive input notes: ", err);
+        throw err;
+      }
+    }
+  );
+}
+
+export async function getArchivedInputNote(noteId) {
+  try {
+    const archivedNote = await archivedInputNotes
+      .where("noteId")
+      .equals(noteId)
+      .first();
+
+    return archivedNote ? { record: archivedNote.record } : null;
+  } catch (err) {
+    console.error("Failed to get archived input note: ", err);
+    throw err;
+  }
+}
+
+export async function countArchivedInputNotes() {
+  try {
+    return await archivedInputNotes.count();
+  } catch (err) {
+    console.error("Failed to count archived input notes: ", err);
+    throw err;
+  }
+}
+
+export async function countInputNotesByState() {
+  try {
+    // Counting each key of the state index doesn't load the note records
+    const states = await inputNotes.orderBy("stateDiscriminant").uniqueKeys();
+    return await Promise.all(
+      states.map(async (state) => [
+        state,
+        await inputNotes.where("stateDiscriminant").equals(state).count(),
+      ])
+    );
+  } catch (err) {
+    console.error("Failed to count input notes by state: ", err);
+    throw err;
+  }
+}
+
+export async function getPendingNotes() {
+  try {
+    const notes = await pendingNotes.toArray();
+
+    return notes.map((note) => ({
+      noteId: note.noteId,
+      tag: note.tag,
+      inclusionBlockNum: note.inclusionBlockNum,
+    }));
+  } catch (err) {
+    console.error("Failed to get pending notes: ", err);
+    throw err;
+  }
+}
+
+export async function upsertPendingNote(noteId, tag, inclusionBlockNum) {
+  try {
+    await pendingNotes.put({ noteId, tag, inclusionBlockNum });
+  } catch (err) {
+    console.error(`Failed to insert pending note ${noteId}: `, err);
+    throw err;
+  }
+}
+
+export async function removePendingNote(noteId) {
+  try {
+    await pendingNotes.where("noteId").equals(noteId).delete();
+  } catch (err) {
+    console.error(`Failed to remove pending note ${noteId}: `, err);
+    throw err;
+  }
+}
+
+export async function getFutureNoteLinks() {
+  try {
+    const links = await futureNoteLinks.toArray();
+
+    return links.map((link) => ({
+      sourceNoteId: link.sourceNoteId,
+      futureNoteId: link.futureNoteId,
+      creatorAccountId: link.creatorAccountId,
+    }));
+  } catch (err) {
+    console.error("Failed to get future note links: ", err);
+    throw err;
+  }
+}
+
+export async function insertFutureNoteLinks(
+  sourceNoteIds,
+  futureNoteIds,
+  creatorAccountIds
+) {
+  try {
+    await futureNoteLinks.bulkPut(
+      sourceNoteIds.map((sourceNoteId, index) => ({
+        sourceNoteId,
+        futureNoteId: futureNoteIds[index],
+        creatorAccountId: creatorAccountIds[index],
+      }))
+    );
+  } catch (err) {
+    console.error("Failed to insert future note links: ", err);
+    throw err;
+  }
+}
+
+export async function applyFutureNoteResolutions(
+  sourceNoteIds,
+  cancelledNoteIds,
+  expectedStateDiscriminant
+) {
+  try {
+    await db.transaction("rw", futureNoteLinks, inputNotes, async (tx) => {
+      await tx.futureNoteLinks.where("sourceNoteId").anyOf(sourceNoteIds).delete();
+
+      // Cancelled notes are only removed while they're still expected
+      await tx.inputNotes
+        .where("noteId")
+        .anyOf(cancelledNoteIds)
+        .and((note) => note.stateDiscriminant === expectedStateDiscriminant)
+        .delete();
+    });
+  } catch (err) {
+    console.error("Failed to apply future note resolutions: ", err);
+    throw err;
+  }
+}
+
+export async function restoreArchivedInputNote(
+  noteId,
+  assets,
+  serialNumber,
+  inputs,
+  noteScriptHash,
+  serializedNoteScript,
+  nullifier,
+  serializedCreatedAt,
+  stateDiscriminant,
+  state
+) {
+  return db.transaction(
+    "rw",
+    inputNotes,
+    notesScripts,
+    archivedInputNotes,
+    async (tx) => {
+      try {
+        await putInputNote(
+          tx,
+          noteId,
+          assets,
+          serialNumber,
+          inputs,
+          noteScriptHash,
+          serializedNoteScript,
+          nullifier,
+          serializedCreatedAt,
+          stateDiscriminant,
+          state
+        );
+        await tx.archivedInputNotes.where("noteId").equals(noteId).delete();
+      } catch (err) {
+        console.error(`Failed to restore archived note ${noteId}: `, err);
+        throw err;
+      }
+    }
+  );
+}
+
+async function putInputNote(
+  tx,
+  noteId,
+  assets,
+  serialNumber,
+  inputs,
+  noteScriptHash,
+  serializedNoteScript,
+  nullifier,
+  serializedCreatedAt,
+  stateDiscriminant,
+  state
+) {
+  let assetsBlob = new Blob([new Uint8Array(assets)]);
+  let serialNumberBlob = new Blob([new Uint8Array(serialNumber)]);
+  let inputsBlob = new Blob([new Uint8Array(inputs)]);
+  let stateBlob = new Blob([new Uint8Array(state)]);
+
+  // Prepare the data object to insert
+  const data = {
+    noteId: noteId,
+    assets: assetsBlob,
+    serialNumber: serialNumberBlob,
+    inputs: inputsBlob,
+    noteScriptHash: noteScriptHash,
+    nullifier: nullifier,
+    state: stateBlob,
+    stateDiscriminant: stateDiscriminant,
+    createdAt: serializedCreatedAt,
+  };
+
+  // Perform the insert using Dexie
+  await tx.inputNotes.put(data);
+
+  let serializedNoteScriptBlob = new Blob([
+    new Uint8Array(serializedNoteScript),
+  ]);
+
+  const noteScriptData = {
+    scriptHash: noteScriptHash,
+    serializedNoteScript: serializedNoteScriptBlob,
+  };
+
+  await tx.notesScripts.put(noteScriptData);
+}
+
 export async function upsertOutputNote(
   noteId,
   assets,
@@ -179,28 +443,23 @@ export async function upsertOutputNote(
   nullifier,
   expectedHeight,
   stateDiscriminant,
-  state
+  state,
+  createdAt
 ) {
   return db.transaction("rw", outputNotes, notesScripts, async (tx) => {
     try {
-      let assetsBlob = new Blob([new Uint8Array(assets)]);
-      let metadataBlob = new Blob([new Uint8Array(metadata)]);
-      let stateBlob = new Blob([new Uint8Array(state)]);
-
-      // Prepare the data object to insert
-      const data = {
-        noteId: noteId,
-        assets: assetsBlob,
-        recipientDigest: recipientDigest,
-        metadata: metadataBlob,
-        nullifier: nullifier ? nullifier : null,
-        expectedHeight: expectedHeight,
+      await putOutputNote(
+        tx,
+        noteId,
+        assets,
+        recipientDigest,
+        metadata,
+        nullifier,
+        expectedHeight,
         stateDiscriminant,
-        state: stateBlob,
-      };
-
-      // Perform the insert using Dexie
-      await tx.outputNotes.put(data);
+        state,
+        createdAt
+      );
     } catch {
       console.error(`Error inserting note: ${noteId}:`, error);
       throw error; // Rethrow the error to handle it further up the call chain if needed
@@ -208,7 +467,100 @@ export async function upsertOutputNote(
   });
 }
 
-async function processInputNotes(notes) {
+async function putOutputNote(
+  tx,
+  noteId,
+  assets,
+  recipientDigest,
+  metadata,
+  nullifier,
+  expectedHeight,
+  stateDiscriminant,
+  state,
+  createdAt
+) {
+  let assetsBlob = new Blob([new Uint8Array(assets)]);
+  let metadataBlob = new Blob([new Uint8Array(metadata)]);
+  let stateBlob = new Blob([new Uint8Array(state)]);
+
+  // Prepare the data object to insert
+  const data = {
+    noteId: noteId,
+    assets: assetsBlob,
+    recipientDigest: recipientDigest,
+    metadata: metadataBlob,
+    nullifier: nullifier ? nullifier : null,
+    expectedHeight: expectedHeight,
+    stateDiscriminant,
+    state: stateBlob,
+    createdAt: createdAt ? createdAt : null,
+  };
+
+  // Perform the insert using Dexie
+  await tx.outputNotes.put(data);
+}
+
+// Stores the notes of a batch of a partially synced block along with the progress of the block,
+// so that an interrupted sync neither skips nor repeats the batch.
+export async function applyNoteBatch(
+  inputNoteRecords,
+  outputNoteRecords,
+  partialSyncProgress
+) {
+  try {
+    await db.transaction(
+      "rw",
+      inputNotes,
+      outputNotes,
+      notesScripts,
+      stateSync,
+      async (tx) => {
+        for (const note of inputNoteRecords) {
+          await putInputNote(
+            tx,
+            note.note_id,
+            note.note_assets,
+            note.serial_number,
+            note.inputs,
+            note.note_script_hash,
+            note.note_script,
+            note.nullifier,
+            note.created_at,
+            note.state_discriminant,
+            note.state
+          );
+        }
+        for (const note of outputNoteRecords) {
+          await putOutputNote(
+            tx,
+            note.note_id,
+            note.note_assets,
+            note.recipient_digest,
+            note.metadata,
+            note.nullifier,
+            note.expected_height,
+            note.state_discriminant,
+            note.state,
+            note.created_at
+          );
+        }
+
+        await tx.stateSync.update(1, {
+          partialSyncProgress: uint8ArrayToBase64(
+            new Uint8Array(partialSyncProgress)
+          ),
+        });
+      }
+    );
+  } catch (err) {
+    console.error("Failed to apply note batch: ", err);
+    throw err;
+  }
+}
+
+// Converts the blobs of the input note records to base64. The note scripts are looked up in
+// `noteScripts`, a map from script hash to script record, if it's provided.
+export async function processInputNotes(notes, noteScripts = null) {
   // Fetch all scripts from the scripts table for joining
   const transactionRecords = await transactions.toArray();
   const transactionMap = new Map(
@@ -239,7 +591,9 @@ async function processInputNotes(notes) {
       // Convert the serialized note script blob to base64
       let serializedNoteScriptBase64 = null;
       if (note.noteScriptHash) {
-        let record = await notesScripts.get(note.noteScriptHash);
+        let record = noteScripts
+          ? noteScripts.get(note.noteScriptHash)
+          : await notesScripts.get(note.noteScriptHash);
         let serializedNoteScriptArrayBuffer =
           await record.serializedNoteScript.arrayBuffer();
         const serializedNoteScriptArray = new Uint8Array(
@@ -296,6 +650,7 @@ async function processOutputNotes(notes) {
         metadata: note.metadata,
         expected_height: note.expectedHeight,
         state: note.state,
+        created_at: note.createdAt ?? null,
       };
     })
   );
@@ -19,16 +19,27 @@ const Table = {
   AccountStorage: "accountStorage",
   AccountVaults: "accountVaults",
   AccountAuth: "accountAuth",
+  PendingAccountAuth: "pendingAccountAuth",
+  AccountReservations: "accountReservations",
   Accounts: "accounts",
+  AccountLocks: "accountLocks",
+  AccountUnlocks: "accountUnlocks",
   Transactions: "transactions",
   TransactionScripts: "transactionScripts",
+  ProvenTransactions: "provenTransactions",
+  TransactionSubmissions: "transactionSubmissions",
   InputNotes: "inputNotes",
   OutputNotes: "outputNotes",
+  ArchivedInputNotes: "archivedInputNotes",
+  PendingNotes: "pendingNotes",
+  FutureNoteLinks: "futureNoteLinks",
   NotesScripts: "notesScripts",
   StateSync: "stateSync",
   BlockHeaders: "blockHeaders",
   ChainMmrNodes: "chainMmrNodes",
+  MmrCheckpoint: "mmrCheckpoint",
   Tags: "tags",
+  StorageSlotWatches: "storageSlotWatches",
 };
 
 const db = new Dexie(DATABASE_NAME);
@@ -37,6 +48,8 @@ db.version(1).stores({
   [Table.AccountStorage]: indexes("root"),
   [Table.AccountVaults]: indexes("root"),
   [Table.AccountAuth]: indexes("accountId", "pubKey"),
+  [Table.PendingAccountAuth]: indexes("transactionId", "accountId", "pubKey"),
+  [Table.AccountReservations]: indexes("accountId"),
   [Table.Accounts]: indexes(
     "[id+nonce]",
     "codeRoot",
@@ -44,8 +57,12 @@ db.version(1).stores({
     "vaultRoot",
     "accountHash"
   ),
+  [Table.AccountLocks]: indexes("accountId"),
+  [Table.AccountUnlocks]: indexes("++id", "accountId"),
   [Table.Transactions]: indexes("id"),
   [Table.TransactionScripts]: indexes("scriptHash"),
+  [Table.ProvenTransactions]: indexes("transactionId", "lastUsed"),
+  [Table.TransactionSubmissions]: indexes("transactionId", "accountId"),
   [Table.InputNotes]: indexes("noteId", "nullifier", "stateDiscriminant"),
   [Table.OutputNotes]: indexes(
     "noteId",
@@ -53,11 +70,16 @@ db.version(1).stores({
     "stateDiscriminant",
     "nullifier"
   ),
+  [Table.ArchivedInputNotes]: indexes("noteId"),
+  [Table.PendingNotes]: indexes("noteId"),
+  [Table.FutureNoteLinks]: indexes("sourceNoteId"),
   [Table.NotesScripts]: indexes("scriptHash"),
   [Table.StateSync]: indexes("id"),
   [Table.BlockHeaders]: indexes("blockNum", "hasClientNotes"),
   [Table.ChainMmrNodes]: indexes("id"),
+  [Table.MmrCheckpoint]: indexes("id"),
   [Table.Tags]: indexes("id++", "tag", "source_note_id", "source_account_id"),
+  [Table.StorageSlotWatches]: indexes("watch"),
 });
 
 function indexes(...items) {
@@ -73,16 +95,27 @@ const accountCodes = db.table(Table.AccountCode);
 const accountStorages = db.table(Table.AccountStorage);
 const accountVaults = db.table(Table.AccountVaults);
 const accountAuths = db.table(Table.AccountAuth);
+const pendingAccountAuths = db.table(Table.PendingAccountAuth);
+const accountReservations = db.table(Table.AccountReservations);
 const accounts = db.table(Table.Accounts);
+const accountLocks = db.table(Table.AccountLocks);
+const accountUnlocks = db.table(Table.AccountUnlocks);
 const transactions = db.table(Table.Transactions);
 const transactionScripts = db.table(Table.TransactionScripts);
+const provenTransactions = db.table(Table.ProvenTransactions);
+const transactionSubmissions = db.table(Table.TransactionSubmissions);
 const inputNotes = db.table(Table.InputNotes);
 const outputNotes = db.table(Table.OutputNotes);
+const archivedInputNotes = db.table(Table.ArchivedInputNotes);
+const pendingNotes = db.table(Table.PendingNotes);
+const futureNoteLinks = db.table(Table.FutureNoteLinks);
 const notesScripts = db.table(Table.NotesScripts);
 const stateSync = db.table(Table.StateSync);
 const blockHeaders = db.table(Table.BlockHeaders);
 const chainMmrNodes = db.table(Table.ChainMmrNodes);
+const mmrCheckpoint = db.table(Table.MmrCheckpoint);
 const tags = db.table(Table.Tags);
+const storageSlotWatches = db.table(Table.StorageSlotWatches);
 
 export {
   db,
@@ -90,14 +123,25 @@ export {
   accountStorages,
   accountVaults,
   accountAuths,
+  pendingAccountAuths,
+  accountReservations,
   accounts,
+  accountLocks,
+  accountUnlocks,
   transactions,
   transactionScripts,
+  provenTransactions,
+  transactionSubmissions,
   inputNotes,
   outputNotes,
+  archivedInputNotes,
+  pendingNotes,
+  futureNoteLinks,
   notesScripts,
   stateSync,
   blockHeaders,
   chainMmrNodes,
+  mmrCheckpoint,
   tags,
+  storageSlotWatches,
 };
@@ -0,0 +1,55 @@
+import {
+  db,
+  accounts,
+  inputNotes,
+  notesScripts,
+  transactions,
+  transactionScripts,
+} from "./schema.js";
+import { processAccountHeaders } from "./accounts.js";
+import { processInputNotes } from "./notes.js";
+import { processTransactions } from "./transactions.js";
+
+export async function readSnapshot() {
+  try {
+    // Every record is read within a single readonly transaction, so that they all reflect the
+    // same state of the database. Their blobs are converted once the transaction is over.
+    const [
+      accountRecords,
+      inputNoteRecords,
+      noteScriptRecords,
+      transactionRecords,
+      transactionScriptRecords,
+    ] = await db.transaction(
+      "r",
+      [accounts, inputNotes, notesScripts, transactions, transactionScripts],
+      () =>
+        Promise.all([
+          accounts.toArray(),
+          inputNotes.toArray(),
+          notesScripts.toArray(),
+          transactions.toArray(),
+          transactionScripts.toArray(),
+        ])
+    );
+
+    const noteScripts = new Map(
+      noteScriptRecords.map((record) => [record.scriptHash, record])
+    );
+    const scriptMap = new Map(
+      transactionScriptRecords.map((script) => [
+        script.scriptHash,
+        script.txScript,
+      ])
+    );
+
+    return {
+      account_headers: await processAccountHeaders(accountRecords),
+      input_notes: await processInputNotes(inputNoteRecords, noteScripts),
+      transactions: await processTransactions(transactionRecords, scriptMap),
+    };
+  } catch (err) {
+    console.error("Failed to read store snapshot: ", err);
+    throw err;
+  }
+}
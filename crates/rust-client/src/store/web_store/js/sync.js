@@ -1,12 +1,22 @@
 import {
   db,
+  accountCodes,
+  accountStorages,
+  accountVaults,
+  accountAuths,
+  pendingAccountAuths,
+  accounts,
+  accountLocks,
+  accountUnlocks,
   stateSync,
   inputNotes,
   outputNotes,
   transactions,
   blockHeaders,
   chainMmrNodes,
+  mmrCheckpoint,
   tags,
+  storageSlotWatches,
 } from "./schema.js";
 
 export async function getNoteTags() {
@@ -28,6 +38,15 @@ export async function getNoteTags() {
   }
 }
 
+export async function getStorageSlotWatches() {
+  try {
+    return await storageSlotWatches.toArray();
+  } catch (error) {
+    console.error("Error fetching storage slot watches:", error.toString());
+    return null;
+  }
+}
+
 export async function getSyncHeight() {
   try {
     const record = await stateSync.get(1); // Since id is the primary key and always 1
@@ -45,7 +64,73 @@ export async function getSyncHeight() {
   }
 }
 
-export async function addNoteTag(tag, source_note_id, source_account_id) {
+export async function getLastSyncSummary() {
+  try {
+    const record = await stateSync.get(1); // Since id is the primary key and always 1
+    if (record) {
+      let data = {
+        last_sync_summary: record.lastSyncSummary ? record.lastSyncSummary : null,
+      };
+      return data;
+    } else {
+      return null;
+    }
+  } catch (error) {
+    console.error("Error fetching last sync summary:", error.toString());
+    return null;
+  }
+}
+
+export async function setLastSyncSummary(summary) {
+  try {
+    let summaryBase64 = uint8ArrayToBase64(new Uint8Array(summary));
+    await stateSync.update(1, { lastSyncSummary: summaryBase64 });
+  } catch (err) {
+    console.error("Failed to update last sync summary: ", err);
+    throw err;
+  }
+}
+
+export async function getPartialSyncProgress() {
+  try {
+    const record = await stateSync.get(1); // Since id is the primary key and always 1
+    if (record) {
+      let data = {
+        partial_sync_progress: record.partialSyncProgress
+          ? record.partialSyncProgress
+          : null,
+      };
+      return data;
+    } else {
+      return null;
+    }
+  } catch (error) {
+    console.error("Error fetching partial sync progress:", error.toString());
+    return null;
+  }
+}
+
+// Removes the stored progress if `progress` is undefined
+export async function setPartialSyncProgress(progress) {
+  try {
+    let progressBase64 = progress
+      ? uint8ArrayToBase64(new Uint8Array(progress))
+      : null;
+    await stateSync.update(1, { partialSyncProgress: progressBase64 });
+  } catch (err) {
+    console.error("Failed to update partial sync progress: ", err);
+    throw err;
+  }
+}
+
+export async function addNoteTag(
+  tag,
+  source_note_id,
+  source_account_id,
+  expires_after,
+  keep_all,
+  aux_filter
+) {
   try {
     let tagArray = new Uint8Array(tag);
     let tagBase64 = uint8ArrayToBase64(tagArray);
@@ -53,6 +138,9 @@ export async function addNoteTag(tag, source_note_id, source_account_id) {
       tag: tagBase64,
       source_note_id: source_note_id ? source_note_id : "",
       source_account_id: source_account_id ? source_account_id : "",
+      expires_after: expires_after ? expires_after : null,
+      keep_all: keep_all,
+      aux_filter: aux_filter ? aux_filter : null,
     });
   } catch (err) {
     console.error("Failed to add note tag: ", err);
@@ -78,6 +166,67 @@ export async function removeNoteTag(tag, source_note_id, source_account_id) {
   }
 }
 
+// Removes and adds note tags in a single transaction. The tags are encoded in base64, the
+// `keep_all` flags are passed as 0 or 1 and missing values as empty strings.
+export async function replaceNoteTags(
+  removedTags,
+  removedSourceNoteIds,
+  removedSourceAccountIds,
+  addedTags,
+  addedSourceNoteIds,
+  addedSourceAccountIds,
+  addedExpiresAfter,
+  addedKeepAll,
+  addedAuxFilters
+) {
+  try {
+    await db.transaction("rw", tags, async () => {
+      for (let i = 0; i < removedTags.length; i++) {
+        await tags
+          .where({
+            tag: removedTags[i],
+            source_note_id: removedSourceNoteIds[i],
+            source_account_id: removedSourceAccountIds[i],
+          })
+          .delete();
+      }
+
+      const addedRecords = addedTags.map((tag, i) => ({
+        tag: tag,
+        source_note_id: addedSourceNoteIds[i],
+        source_account_id: addedSourceAccountIds[i],
+        expires_after: addedExpiresAfter[i] ? addedExpiresAfter[i] : null,
+        keep_all: addedKeepAll[i] === 1,
+        aux_filter: addedAuxFilters[i] ? addedAuxFilters[i] : null,
+      }));
+      await tags.bulkAdd(addedRecords);
+    });
+  } catch (err) {
+    console.error("Failed to replace note tags: ", err);
+    throw err;
+  }
+}
+
+export async function addStorageSlotWatch(watch) {
+  try {
+    let watchBase64 = uint8ArrayToBase64(new Uint8Array(watch));
+    await storageSlotWatches.put({ watch: watchBase64 });
+  } catch (err) {
+    console.error("Failed to add storage slot watch: ", err);
+    throw err;
+  }
+}
+
+export async function removeStorageSlotWatch(watch) {
+  try {
+    let watchBase64 = uint8ArrayToBase64(new Uint8Array(watch));
+    return await storageSlotWatches.where({ watch: watchBase64 }).delete();
+  } catch (err) {
+    console.error("Failed to remove storage slot watch: ", err);
+    throw err;
+  }
+}
+
 export async function applyStateSync(
   blockNum,
   blockHeader,
@@ -118,9 +267,60 @@ export async function applyStateSync(
   );
 }
 
+export async function resetChainState(keepAccounts, accountTagIds) {
+  return db.transaction(
+    "rw",
+    [
+      stateSync,
+      blockHeaders,
+      chainMmrNodes,
+      mmrCheckpoint,
+      accountLocks,
+      tags,
+      transactions,
+      storageSlotWatches,
+      accountUnlocks,
+      accountAuths,
+      pendingAccountAuths,
+      accounts,
+      accountCodes,
+      accountStorages,
+      accountVaults,
+    ],
+    async (tx) => {
+      await tx.stateSync.update(1, {
+        blockNum: "0",
+        lastSyncSummary: null,
+        partialSyncProgress: null,
+      });
+      await tx.blockHeaders.clear();
+      await tx.chainMmrNodes.clear();
+      await tx.mmrCheckpoint.clear();
+      await tx.accountLocks.clear();
+
+      if (!keepAccounts) {
+        await tx.tags.where("source_account_id").anyOf(accountTagIds).delete();
+        await tx.transactions.clear();
+        await tx.storageSlotWatches.clear();
+        await tx.accountUnlocks.clear();
+        await tx.accountAuth.clear();
+        await tx.pendingAccountAuth.clear();
+        await tx.accounts.clear();
+        await tx.accountCode.clear();
+        await tx.accountStorage.clear();
+        await tx.accountVaults.clear();
+      }
+    }
+  );
+}
+
+// Applying a block completes its partial sync, if any
 async function updateSyncHeight(tx, blockNum) {
   try {
-    await tx.stateSync.update(1, { blockNum: blockNum });
+    await tx.stateSync.update(1, {
+      blockNum: blockNum,
+      partialSyncProgress: null,
+    });
   } catch (error) {
     console.error("Failed to update sync height: ", error);
     throw error;
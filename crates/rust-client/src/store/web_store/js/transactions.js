@@ -1,4 +1,14 @@
-import { transactions, transactionScripts } from "./schema.js";
+import {
+  accounts,
+  db,
+  futureNoteLinks,
+  inputNotes,
+  outputNotes,
+  provenTransactions,
+  transactions,
+  transactionScripts,
+  transactionSubmissions,
+} from "./schema.js";
 
 export async function getTransactions(filter) {
   let transactionRecords;
@@ -33,58 +43,71 @@ export async function getTransactions(filter) {
       scriptMap.set(script.scriptHash, script.txScript);
     });
 
-    const processedTransactions = await Promise.all(
-      transactionRecords.map(async (transactionRecord) => {
-        let txScriptBase64 = null;
+    return await processTransactions(transactionRecords, scriptMap);
+  } catch {
+    console.error("Failed to get transactions: ", err);
+    throw err;
+  }
+}
+
+// Converts the blobs of the transaction records to base64. The transaction scripts are looked up
+// in `scriptMap`, a map from script hash to script.
+export async function processTransactions(transactionRecords, scriptMap) {
+  const processedTransactions = await Promise.all(
+    transactionRecords.map(async (transactionRecord) => {
+      let txScriptBase64 = null;
 
-        if (transactionRecord.scriptHash) {
-          const txScript = scriptMap.get(transactionRecord.scriptHash);
+      if (transactionRecord.scriptHash) {
+        const txScript = scriptMap.get(transactionRecord.scriptHash);
 
-          if (txScript) {
-            let txScriptArrayBuffer = await txScript.arrayBuffer();
-            let txScriptArray = new Uint8Array(txScriptArrayBuffer);
-            txScriptBase64 = uint8ArrayToBase64(txScriptArray);
-          }
+        if (txScript) {
+          let txScriptArrayBuffer = await txScript.arrayBuffer();
+          let txScriptArray = new Uint8Array(txScriptArrayBuffer);
+          txScriptBase64 = uint8ArrayToBase64(txScriptArray);
         }
+      }
 
-        let inputNotesArrayBuffer =
-          await transactionRecord.inputNotes.arrayBuffer();
-        let inputNotesArray = new Uint8Array(inputNotesArrayBuffer);
-        let inputNotesBase64 = uint8ArrayToBase64(inputNotesArray);
-        transactionRecord.inputNotes = inputNotesBase64;
-
-        let outputNotesArrayBuffer =
-          await transactionRecord.outputNotes.arrayBuffer();
-        let outputNotesArray = new Uint8Array(outputNotesArrayBuffer);
-        let outputNotesBase64 = uint8ArrayToBase64(outputNotesArray);
-        transactionRecord.outputNotes = outputNotesBase64;
-
-        let data = {
-          id: transactionRecord.id,
-          account_id: transactionRecord.accountId,
-          init_account_state: transactionRecord.initAccountState,
-          final_account_state: transactionRecord.finalAccountState,
-          input_notes: transactionRecord.inputNotes,
-          output_notes: transactionRecord.outputNotes,
-          script_hash: transactionRecord.scriptHash
-            ? transactionRecord.scriptHash
-            : null,
-          tx_script: txScriptBase64,
-          block_num: transactionRecord.blockNum,
-          commit_height: transactionRecord.commitHeight
-            ? transactionRecord.commitHeight
-            : null,
-        };
-
-        return data;
-      })
-    );
+      let inputNotesArrayBuffer =
+        await transactionRecord.inputNotes.arrayBuffer();
+      let inputNotesArray = new Uint8Array(inputNotesArrayBuffer);
+      let inputNotesBase64 = uint8ArrayToBase64(inputNotesArray);
+      transactionRecord.inputNotes = inputNotesBase64;
 
-    return processedTransactions;
-  } catch {
-    console.error("Failed to get transactions: ", err);
-    throw err;
-  }
+      let outputNotesArrayBuffer =
+        await transactionRecord.outputNotes.arrayBuffer();
+      let outputNotesArray = new Uint8Array(outputNotesArrayBuffer);
+      let outputNotesBase64 = uint8ArrayToBase64(outputNotesArray);
+      transactionRecord.outputNotes = outputNotesBase64;
+
+      let data = {
+        id: transactionRecord.id,
+        account_id: transactionRecord.accountId,
+        init_account_state: transactionRecord.initAccountState,
+        final_account_state: transactionRecord.finalAccountState,
+        input_notes: transactionRecord.inputNotes,
+        output_notes: transactionRecord.outputNotes,
+        script_hash: transactionRecord.scriptHash
+          ? transactionRecord.scriptHash
+          : null,
+        tx_script: txScriptBase64,
+        block_num: transactionRecord.blockNum,
+        commit_height: transactionRecord.commitHeight
+          ? transactionRecord.commitHeight
+          : null,
+        provenance: transactionRecord.provenance
+          ? transactionRecord.provenance
+          : null,
+        discarded: transactionRecord.discarded ? true : false,
+        discard_cause: transactionRecord.discardCause
+          ? transactionRecord.discardCause
+          : null,
+      };
+
+      return data;
+    })
+  );
+
+  return processedTransactions;
 }
 
 export async function insertTransactionScript(scriptHash, txScript) {
@@ -136,7 +159,8 @@ export async function insertProvenTransactionData(
   outputNotes,
   scriptHash,
   blockNum,
-  committed
+  committed,
+  provenance
 ) {
   try {
     let inputNotesBlob = new Blob([new Uint8Array(inputNotes)]);
@@ -146,6 +170,10 @@ export async function insertProvenTransactionData(
       let scriptHashArray = new Uint8Array(scriptHash);
       scriptHashBase64 = uint8ArrayToBase64(scriptHashArray);
     }
+    let provenanceBase64 = null;
+    if (provenance) {
+      provenanceBase64 = uint8ArrayToBase64(new Uint8Array(provenance));
+    }
 
     const data = {
       id: transactionId,
@@ -157,6 +185,7 @@ export async function insertProvenTransactionData(
       scriptHash: scriptHashBase64,
       blockNum: blockNum,
       commitHeight: committed ? committed : null,
+      provenance: provenanceBase64,
     };
 
     await transactions.add(data);
@@ -166,6 +195,158 @@ export async function insertProvenTransactionData(
   }
 }
 
+// Reverts the records changed by a local transaction: the states of the account newer than
+// `initAccountNonce` and the notes it created are removed, and the transaction is marked as
+// discarded with the serialized `discardCause`. The reverted input notes and tags are updated
+// separately.
+export async function discardLocalTransaction(
+  transactionId,
+  accountId,
+  initAccountNonce,
+  removedNoteIds,
+  discardCause
+) {
+  try {
+    await db.transaction(
+      "rw",
+      accounts,
+      inputNotes,
+      outputNotes,
+      futureNoteLinks,
+      transactions,
+      async (tx) => {
+        await tx.accounts
+          .where("id")
+          .equals(accountId)
+          .and((record) => BigInt(record.nonce) > BigInt(initAccountNonce))
+          .delete();
+
+        await tx.inputNotes.where("noteId").anyOf(removedNoteIds).delete();
+        await tx.outputNotes.where("noteId").anyOf(removedNoteIds).delete();
+        await tx.futureNoteLinks
+          .where("sourceNoteId")
+          .anyOf(removedNoteIds)
+          .delete();
+
+        await tx.transactions.update(transactionId, {
+          discarded: true,
+          discardCause: uint8ArrayToBase64(new Uint8Array(discardCause)),
+        });
+      }
+    );
+  } catch (err) {
+    console.error(`Failed to discard transaction ${transactionId}: `, err);
+    throw err;
+  }
+}
+
+// Stores a serialized proven transaction as the most recently used one, then evicts the least
+// recently used proofs until the retained ones take up to `maxTotalSize` bytes.
+export async function insertProvenTransaction(
+  transactionId,
+  provenTransaction,
+  maxTotalSize
+) {
+  try {
+    await db.transaction("rw", provenTransactions, async () => {
+      await provenTransactions.put({
+        transactionId: transactionId,
+        provenTransaction: new Blob([new Uint8Array(provenTransaction)]),
+        size: provenTransaction.length,
+        lastUsed: await nextProvenTransactionUse(),
+      });
+
+      const retained = await provenTransactions
+        .orderBy("lastUsed")
+        .reverse()
+        .toArray();
+      let totalSize = 0;
+      for (const record of retained) {
+        totalSize += record.size;
+        if (totalSize > maxTotalSize) {
+          await provenTransactions.delete(record.transactionId);
+        }
+      }
+    });
+  } catch (err) {
+    console.error(`Failed to insert proven transaction ${transactionId}: `, err);
+    throw err;
+  }
+}
+
+// Returns the serialized proven transaction as base64, or null if it isn't retained, and marks it
+// as the most recently used one.
+export async function getProvenTransaction(transactionId) {
+  try {
+    return await db.transaction("rw", provenTransactions, async () => {
+      const record = await provenTransactions.get(transactionId);
+      if (!record) {
+        return null;
+      }
+
+      await provenTransactions.update(transactionId, {
+        lastUsed: await nextProvenTransactionUse(),
+      });
+
+      const provenTransactionArrayBuffer =
+        await record.provenTransaction.arrayBuffer();
+      return uint8ArrayToBase64(new Uint8Array(provenTransactionArrayBuffer));
+    });
+  } catch (err) {
+    console.error(`Failed to get proven transaction ${transactionId}: `, err);
+    throw err;
+  }
+}
+
+// Records a new attempt to submit the transaction and returns the number of attempts recorded
+export async function recordSubmissionAttempt(transactionId, accountId) {
+  try {
+    return await db.transaction("rw", transactionSubmissions, async () => {
+      const record = await transactionSubmissions.get(transactionId);
+      const attempts = record ? record.attempts + 1 : 1;
+      await transactionSubmissions.put({ transactionId, accountId, attempts });
+
+      return attempts;
+    });
+  } catch (err) {
+    console.error(
+      `Failed to record submission attempt of transaction ${transactionId}: `,
+      err
+    );
+    throw err;
+  }
+}
+
+export async function getSubmissionAttempts(transactionId) {
+  try {
+    const record = await transactionSubmissions.get(transactionId);
+    return record ? record.attempts : 0;
+  } catch (err) {
+    console.error(
+      `Failed to get submission attempts of transaction ${transactionId}: `,
+      err
+    );
+    throw err;
+  }
+}
+
+export async function removeSubmissionAttempts(transactionId) {
+  try {
+    await transactionSubmissions.delete(transactionId);
+  } catch (err) {
+    console.error(
+      `Failed to remove submission attempts of transaction ${transactionId}: `,
+      err
+    );
+    throw err;
+  }
+}
+
+async function nextProvenTransactionUse() {
+  const lastUsed = await provenTransactions.orderBy("lastUsed").last();
+  return lastUsed ? lastUsed.lastUsed + 1 : 1;
+}
+
 function uint8ArrayToBase64(bytes) {
   const binary = bytes.reduce(
     (acc, byte) => acc + String.fromCharCode(byte),
@@ -1,27 +1,36 @@
-use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, string::ToString, vec::Vec};
+use core::time::Duration;
 
 use miden_objects::{
     accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
-    crypto::merkle::{InOrderIndex, MmrPeaks},
-    notes::Nullifier,
+    crypto::merkle::{InOrderIndex, MmrPeaks, PartialMmr},
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
     BlockHeader, Digest, Word,
 };
 use tonic::async_trait;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::*;
 
+use self::snapshot::WebSnapshot;
 use super::{
     ChainMmrNodeFilter, InputNoteRecord, NoteFilter, OutputNoteRecord, Store, StoreError,
-    TransactionFilter,
+    StoreSnapshot, TransactionFilter,
 };
 use crate::{
-    sync::{NoteTagRecord, StateSyncUpdate},
-    transactions::{TransactionRecord, TransactionStoreUpdate},
+    accounts::{AccountLockStatus, AccountRemoval, AccountUnlockRecord},
+    notes::{FutureNoteLink, NoteUpdates, PendingNoteRecord},
+    sync::{
+        ChainStateReset, NoteTagRecord, PartialSyncProgress, StateSyncUpdate, StorageSlotWatch,
+        SyncSummary,
+    },
+    transactions::{LocalTransactionDiscard, TransactionRecord, TransactionStoreUpdate},
 };
 
 pub mod accounts;
 pub mod chain_data;
 pub mod notes;
+mod snapshot;
 pub mod sync;
 pub mod transactions;
 
@@ -35,10 +44,22 @@ extern "C" {
 pub struct WebStore {}
 
 impl WebStore {
+    /// Returns a new store without opening the database, which is opened by its first query.
     pub async fn new() -> Result<WebStore, ()> {
-        let _ = JsFuture::from(setup_indexed_db()).await;
         Ok(WebStore {})
     }
+
+    /// Opens the database ahead of its first query.
+    pub async fn warmup(&self) -> Result<(), StoreError> {
+        let opened = JsFuture::from(setup_indexed_db())
+            .await
+            .map_err(|err| StoreError::DatabaseError(format!("{err:?}")))?;
+
+        match opened.as_bool() {
+            Some(true) => Ok(()),
+            _ => Err(StoreError::DatabaseError("Failed to open the database".to_string())),
+        }
+    }
 }
 #[async_trait(?Send)]
 impl Store for WebStore {
@@ -56,6 +77,29 @@ impl Store for WebStore {
         self.remove_note_tag(tag).await
     }
 
+    async fn replace_note_tags(
+        &self,
+        removed: Vec<NoteTagRecord>,
+        added: Vec<NoteTagRecord>,
+    ) -> Result<(), StoreError> {
+        self.replace_note_tags(removed, added).await
+    }
+
+    async fn get_storage_slot_watches(&self) -> Result<Vec<StorageSlotWatch>, StoreError> {
+        self.get_storage_slot_watches().await
+    }
+
+    async fn add_storage_slot_watch(&self, watch: StorageSlotWatch) -> Result<bool, StoreError> {
+        self.add_storage_slot_watch(watch).await
+    }
+
+    async fn remove_storage_slot_watch(
+        &self,
+        watch: StorageSlotWatch,
+    ) -> Result<usize, StoreError> {
+        self.remove_storage_slot_watch(watch).await
+    }
+
     async fn get_sync_height(&self) -> Result<u32, StoreError> {
         self.get_sync_height().await
     }
@@ -64,6 +108,29 @@ impl Store for WebStore {
         self.apply_state_sync(state_sync_update).await
     }
 
+    async fn reset_chain_state(&self, reset: ChainStateReset) -> Result<(), StoreError> {
+        self.reset_chain_state(reset).await
+    }
+
+    async fn get_last_sync_summary(&self) -> Result<Option<SyncSummary>, StoreError> {
+        self.get_last_sync_summary().await
+    }
+
+    async fn set_last_sync_summary(&self, summary: SyncSummary) -> Result<(), StoreError> {
+        self.set_last_sync_summary(summary).await
+    }
+
+    async fn get_partial_sync_progress(&self) -> Result<Option<PartialSyncProgress>, StoreError> {
+        self.get_partial_sync_progress().await
+    }
+
+    async fn set_partial_sync_progress(
+        &self,
+        progress: Option<PartialSyncProgress>,
+    ) -> Result<(), StoreError> {
+        self.set_partial_sync_progress(progress).await
+    }
+
     // TRANSACTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -78,6 +145,52 @@ impl Store for WebStore {
         self.apply_transaction(tx_update).await
     }
 
+    async fn discard_local_transaction(
+        &self,
+        discard: LocalTransactionDiscard,
+    ) -> Result<(), StoreError> {
+        self.discard_local_transaction(discard).await
+    }
+
+    async fn insert_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
+    ) -> Result<(), StoreError> {
+        self.insert_proven_transaction(transaction_id, proven_transaction, max_total_size)
+            .await
+    }
+
+    async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<Vec<u8>>, StoreError> {
+        self.get_proven_transaction(transaction_id).await
+    }
+
+    async fn record_submission_attempt(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+    ) -> Result<u32, StoreError> {
+        self.record_submission_attempt(transaction_id, account_id).await
+    }
+
+    async fn get_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<u32, StoreError> {
+        self.get_submission_attempts(transaction_id).await
+    }
+
+    async fn remove_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        self.remove_submission_attempts(transaction_id).await
+    }
+
     // NOTES
     // --------------------------------------------------------------------------------------------
     async fn get_input_notes(
@@ -87,6 +200,15 @@ impl Store for WebStore {
         self.get_input_notes(filter).await
     }
 
+    async fn get_input_notes_page(
+        &self,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), StoreError> {
+        self.get_input_notes_page(filter, offset, limit).await
+    }
+
     async fn get_output_notes(
         &self,
         note_filter: NoteFilter,
@@ -94,10 +216,64 @@ impl Store for WebStore {
         self.get_output_notes(note_filter).await
     }
 
+    async fn get_note_ids_by_prefix(&self, prefix: &str) -> Result<Vec<NoteId>, StoreError> {
+        self.get_note_ids_by_prefix(prefix).await
+    }
+
     async fn upsert_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError> {
         self.upsert_input_notes(notes).await
     }
 
+    async fn apply_note_batch(
+        &self,
+        note_updates: NoteUpdates,
+        progress: PartialSyncProgress,
+    ) -> Result<(), StoreError> {
+        self.apply_note_batch(&note_updates, progress).await
+    }
+
+    async fn archive_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError> {
+        self.archive_input_notes(notes).await
+    }
+
+    async fn get_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, StoreError> {
+        self.get_archived_input_note(note_id).await
+    }
+
+    async fn restore_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<InputNoteRecord, StoreError> {
+        self.restore_archived_input_note(note_id).await
+    }
+
+    async fn get_archived_input_note_count(&self) -> Result<usize, StoreError> {
+        self.get_archived_input_note_count().await
+    }
+
+    async fn count_input_notes_by_state(&self) -> Result<BTreeMap<u8, usize>, StoreError> {
+        self.count_input_notes_by_state().await
+    }
+
+    async fn get_pending_notes(&self) -> Result<Vec<PendingNoteRecord>, StoreError> {
+        self.get_pending_notes().await
+    }
+
+    async fn upsert_pending_note(&self, note: PendingNoteRecord) -> Result<(), StoreError> {
+        self.upsert_pending_note(note).await
+    }
+
+    async fn remove_pending_note(&self, note_id: NoteId) -> Result<(), StoreError> {
+        self.remove_pending_note(note_id).await
+    }
+
+    async fn get_future_note_links(&self) -> Result<Vec<FutureNoteLink>, StoreError> {
+        self.get_future_note_links().await
+    }
+
     // CHAIN DATA
     // --------------------------------------------------------------------------------------------
 
@@ -135,6 +311,13 @@ impl Store for WebStore {
         self.insert_chain_mmr_nodes(nodes).await
     }
 
+    async fn replace_chain_mmr_nodes(
+        &self,
+        nodes: &[(InOrderIndex, Digest)],
+    ) -> Result<(), StoreError> {
+        self.replace_chain_mmr_nodes(nodes).await
+    }
+
     async fn get_chain_mmr_peaks_by_block_num(
         &self,
         block_num: u32,
@@ -142,6 +325,18 @@ impl Store for WebStore {
         self.get_chain_mmr_peaks_by_block_num(block_num).await
     }
 
+    async fn save_mmr_checkpoint(
+        &self,
+        block_num: u32,
+        partial_mmr: &PartialMmr,
+    ) -> Result<(), StoreError> {
+        self.save_mmr_checkpoint(block_num, partial_mmr).await
+    }
+
+    async fn load_mmr_checkpoint(&self) -> Result<Option<(u32, PartialMmr)>, StoreError> {
+        self.load_mmr_checkpoint().await
+    }
+
     // ACCOUNTS
     // --------------------------------------------------------------------------------------------
 
@@ -149,15 +344,72 @@ impl Store for WebStore {
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         self.insert_account(account, account_seed, auth_info).await
     }
 
+    async fn reserve_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.reserve_account_id(account_id).await
+    }
+
+    async fn release_account_id(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.release_account_id(account_id).await
+    }
+
+    async fn remove_account(&self, removal: AccountRemoval) -> Result<(), StoreError> {
+        self.remove_account(removal).await
+    }
+
+    async fn get_orphaned_account_auth_ids(&self) -> Result<Vec<AccountId>, StoreError> {
+        self.get_orphaned_account_auth_ids().await
+    }
+
+    async fn remove_orphaned_account_auth(
+        &self,
+        account_id: AccountId,
+    ) -> Result<bool, StoreError> {
+        self.remove_orphaned_account_auth(account_id).await
+    }
+
+    async fn get_account_lock_status(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountLockStatus, StoreError> {
+        self.get_account_lock_status(account_id).await
+    }
+
+    async fn set_account_lock_status(
+        &self,
+        account_id: AccountId,
+        status: AccountLockStatus,
+    ) -> Result<(), StoreError> {
+        self.set_account_lock_status(account_id, status).await
+    }
+
+    async fn apply_account_unlock(
+        &self,
+        record: AccountUnlockRecord,
+        new_state: Option<Account>,
+    ) -> Result<(), StoreError> {
+        self.apply_account_unlock(record, new_state).await
+    }
+
+    async fn get_account_unlock_records(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountUnlockRecord>, StoreError> {
+        self.get_account_unlock_records(account_id).await
+    }
+
     async fn get_account_ids(&self) -> Result<Vec<AccountId>, StoreError> {
         self.get_account_ids().await
     }
 
+    async fn get_account_ids_by_prefix(&self, prefix: &str) -> Result<Vec<AccountId>, StoreError> {
+        self.get_account_ids_by_prefix(prefix).await
+    }
+
     async fn get_account_auth_by_pub_key(
         &self,
         pub_key: Word,
@@ -190,11 +442,51 @@ impl Store for WebStore {
         self.get_account(account_id).await
     }
 
+    async fn get_account_history(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<(Account, Digest)>, StoreError> {
+        self.get_account_history(account_id).await
+    }
+
     async fn get_account_auth(&self, account_id: AccountId) -> Result<AuthSecretKey, StoreError> {
         self.get_account_auth(account_id).await
     }
 
+    async fn insert_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+        auth_info: &AuthSecretKey,
+    ) -> Result<(), StoreError> {
+        self.insert_pending_account_auth(transaction_id, account_id, auth_info).await
+    }
+
+    async fn get_pending_account_auths(
+        &self,
+    ) -> Result<Vec<(TransactionId, AccountId)>, StoreError> {
+        self.get_pending_account_auths().await
+    }
+
+    async fn resolve_pending_account_auth(
+        &self,
+        transaction_id: TransactionId,
+        committed: bool,
+    ) -> Result<(), StoreError> {
+        self.resolve_pending_account_auth(transaction_id, committed).await
+    }
+
     async fn get_unspent_input_note_nullifiers(&self) -> Result<Vec<Nullifier>, StoreError> {
         self.get_unspent_input_note_nullifiers().await
     }
+
+    // SNAPSHOTS
+    // --------------------------------------------------------------------------------------------
+
+    async fn read_snapshot(
+        &self,
+        _timeout: Duration,
+    ) -> Result<Box<dyn StoreSnapshot>, StoreError> {
+        Ok(Box::new(WebSnapshot::open().await?))
+    }
 }
@@ -13,12 +13,22 @@ extern "C" {
     #[wasm_bindgen(js_name = getInputNotes)]
     pub fn idxdb_get_input_notes(states: Vec<u8>) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getInputNotesPage)]
+    pub fn idxdb_get_input_notes_page(
+        states: Vec<u8>,
+        offset: usize,
+        limit: usize,
+    ) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = getInputNotesFromIds)]
     pub fn idxdb_get_input_notes_from_ids(note_ids: Vec<String>) -> js_sys::Promise;
 
     #[wasm_bindgen(js_name = getInputNotesFromNullifiers)]
     pub fn idxdb_get_input_notes_from_nullifiers(nullifiers: Vec<String>) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getNoteIdsByPrefix)]
+    pub fn idxdb_get_note_ids_by_prefix(prefix: String) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = getOutputNotes)]
     pub fn idxdb_get_output_notes(states: Vec<u8>) -> js_sys::Promise;
 
@@ -31,6 +41,21 @@ extern "C" {
     #[wasm_bindgen(js_name = getUnspentInputNoteNullifiers)]
     pub fn idxdb_get_unspent_input_note_nullifiers() -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getArchivedInputNote)]
+    pub fn idxdb_get_archived_input_note(note_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = countArchivedInputNotes)]
+    pub fn idxdb_count_archived_input_notes() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = countInputNotesByState)]
+    pub fn idxdb_count_input_notes_by_state() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getPendingNotes)]
+    pub fn idxdb_get_pending_notes() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getFutureNoteLinks)]
+    pub fn idxdb_get_future_note_links() -> js_sys::Promise;
+
     // INSERTS
     // ================================================================================================
 
@@ -48,6 +73,50 @@ extern "C" {
         state: Vec<u8>,
     ) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = archiveInputNotes)]
+    pub fn idxdb_archive_input_notes(
+        note_ids: Vec<String>,
+        records: Vec<String>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = upsertPendingNote)]
+    pub fn idxdb_upsert_pending_note(
+        note_id: String,
+        tag: Option<u32>,
+        inclusion_block_num: Option<u32>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = removePendingNote)]
+    pub fn idxdb_remove_pending_note(note_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = insertFutureNoteLinks)]
+    pub fn idxdb_insert_future_note_links(
+        source_note_ids: Vec<String>,
+        future_note_ids: Vec<String>,
+        creator_account_ids: Vec<String>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = applyFutureNoteResolutions)]
+    pub fn idxdb_apply_future_note_resolutions(
+        source_note_ids: Vec<String>,
+        cancelled_note_ids: Vec<String>,
+        expected_state_discriminant: u8,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = restoreArchivedInputNote)]
+    pub fn idxdb_restore_archived_input_note(
+        note_id: String,
+        assets: Vec<u8>,
+        serial_number: Vec<u8>,
+        inputs: Vec<u8>,
+        note_script_hash: String,
+        serialized_note_script: Vec<u8>,
+        nullifier: String,
+        serialized_created_at: String,
+        state_discriminant: u8,
+        state: Vec<u8>,
+    ) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = upsertOutputNote)]
     pub fn idxdb_upsert_output_note(
         note_id: String,
@@ -58,5 +127,13 @@ extern "C" {
         expected_height: u32,
         state_discriminant: u8,
         state: Vec<u8>,
+        serialized_created_at: Option<String>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = applyNoteBatch)]
+    pub fn idxdb_apply_note_batch(
+        input_notes: JsValue,
+        output_notes: JsValue,
+        partial_sync_progress: Vec<u8>,
     ) -> js_sys::Promise;
 }
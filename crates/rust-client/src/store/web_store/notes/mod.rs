@@ -1,23 +1,35 @@
 use alloc::{
+    collections::BTreeMap,
     string::{String, ToString},
     vec::Vec,
 };
 
+use base64::{engine::general_purpose, Engine as _};
 use js_sys::{Array, Promise};
-use miden_objects::{notes::Nullifier, Digest};
-use serde_wasm_bindgen::from_value;
+use miden_objects::{
+    accounts::AccountId,
+    notes::{NoteId, NoteTag, Nullifier},
+    utils::{Deserializable, Serializable},
+    Digest,
+};
+use serde_wasm_bindgen::{from_value, to_value};
 use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::*;
 
 use super::WebStore;
-use crate::store::{
-    InputNoteRecord, InputNoteState, NoteFilter, OutputNoteRecord, OutputNoteState, StoreError,
+use crate::{
+    notes::{FutureNoteLink, NoteUpdates, PendingNoteRecord},
+    store::{
+        InputNoteRecord, InputNoteState, NoteFilter, OutputNoteRecord, OutputNoteState, StoreError,
+        TransactionFilter,
+    },
+    sync::PartialSyncProgress,
 };
 
 mod js_bindings;
 use js_bindings::*;
 
-mod models;
+pub(crate) mod models;
 use models::*;
 
 pub(crate) mod utils;
@@ -28,12 +40,19 @@ impl WebStore {
         &self,
         filter: NoteFilter,
     ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        let filter = self.resolve_created_by_transaction(filter).await?;
         let js_value = JsFuture::from(filter.to_input_notes_promise()).await.unwrap();
         let input_notes_idxdb: Vec<InputNoteIdxdbObject> = from_value(js_value).unwrap();
 
         let native_input_notes: Result<Vec<InputNoteRecord>, StoreError> = input_notes_idxdb
             .into_iter()
             .map(parse_input_note_idxdb_object) // Simplified closure
+            .filter(|note| match (&filter, note) {
+                (NoteFilter::Aux(aux), Ok(note)) => {
+                    note.metadata().is_some_and(|metadata| metadata.aux() == *aux)
+                },
+                _ => true,
+            })
             .collect::<Result<Vec<_>, _>>(); // Collect results into a single Result
 
         match native_input_notes {
@@ -49,10 +68,41 @@ impl WebStore {
         native_input_notes
     }
 
+    pub(crate) async fn get_input_notes_page(
+        &self,
+        filter: NoteFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<InputNoteRecord>, usize), StoreError> {
+        // Filters by state are paginated by IndexedDB. The others match a bounded list of notes,
+        // except for the aux, which isn't indexed, so their notes are paginated after being
+        // fetched.
+        let Some(states) = filter.input_note_states() else {
+            let mut notes = self.get_input_notes(filter).await?;
+            let total = notes.len();
+            notes.sort_by_cached_key(|note| note.id().to_hex());
+
+            return Ok((notes.into_iter().skip(offset).take(limit).collect(), total));
+        };
+
+        let promise = idxdb_get_input_notes_page(states, offset, limit);
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let page: InputNotesPageIdxdbObject = from_value(js_value).unwrap();
+
+        let notes = page
+            .notes
+            .into_iter()
+            .map(parse_input_note_idxdb_object)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((notes, page.total))
+    }
+
     pub(crate) async fn get_output_notes(
         &self,
         filter: NoteFilter,
     ) -> Result<Vec<OutputNoteRecord>, StoreError> {
+        let filter = self.resolve_created_by_transaction(filter).await?;
         let js_value = JsFuture::from(filter.to_output_note_promise()).await.unwrap();
 
         let output_notes_idxdb: Vec<OutputNoteIdxdbObject> = from_value(js_value).unwrap();
@@ -60,6 +110,10 @@ impl WebStore {
         let native_output_notes: Result<Vec<OutputNoteRecord>, StoreError> = output_notes_idxdb
             .into_iter()
             .map(parse_output_note_idxdb_object) // Simplified closure
+            .filter(|note| match (&filter, note) {
+                (NoteFilter::Aux(aux), Ok(note)) => note.metadata().aux() == *aux,
+                _ => true,
+            })
             .collect::<Result<Vec<_>, _>>(); // Collect results into a single Result
 
         match native_output_notes {
@@ -75,6 +129,41 @@ impl WebStore {
         native_output_notes
     }
 
+    /// Replaces a [NoteFilter::CreatedByTransaction] with the list of the notes in the stored
+    /// output notes of the transaction. Other filters are returned unchanged.
+    async fn resolve_created_by_transaction(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<NoteFilter, StoreError> {
+        let NoteFilter::CreatedByTransaction(transaction_id) = filter else {
+            return Ok(filter);
+        };
+
+        let note_ids = self
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .find(|transaction| transaction.id == transaction_id)
+            .map(|transaction| transaction.output_notes.iter().map(|note| note.id()).collect())
+            .unwrap_or_default();
+
+        Ok(NoteFilter::List(note_ids))
+    }
+
+    pub(crate) async fn get_note_ids_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<NoteId>, StoreError> {
+        let promise = idxdb_get_note_ids_by_prefix(prefix.to_string());
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let note_ids_as_str: Vec<String> = from_value(js_value).unwrap();
+
+        note_ids_as_str
+            .into_iter()
+            .map(|s| Digest::try_from(s).map(NoteId::from).map_err(StoreError::HexParseError))
+            .collect::<Result<Vec<NoteId>, _>>()
+    }
+
     pub(crate) async fn get_unspent_input_note_nullifiers(
         &self,
     ) -> Result<Vec<Nullifier>, StoreError> {
@@ -98,9 +187,210 @@ impl WebStore {
 
         Ok(())
     }
+
+    pub(crate) async fn apply_note_batch(
+        &self,
+        note_updates: &NoteUpdates,
+        progress: PartialSyncProgress,
+    ) -> Result<(), StoreError> {
+        let input_notes = note_updates
+            .new_input_notes()
+            .iter()
+            .chain(note_updates.updated_input_notes())
+            .map(serialize_input_note)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output_notes = note_updates
+            .new_output_notes()
+            .iter()
+            .chain(note_updates.updated_output_notes())
+            .map(serialize_output_note)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let promise = idxdb_apply_note_batch(
+            to_value(&input_notes).unwrap(),
+            to_value(&output_notes).unwrap(),
+            progress.to_bytes(),
+        );
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to apply note batch: {js_error:?}"))
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn archive_input_notes(
+        &self,
+        notes: &[InputNoteRecord],
+    ) -> Result<(), StoreError> {
+        let note_ids = notes.iter().map(|note| note.id().inner().to_string()).collect();
+        let records = notes
+            .iter()
+            .map(|note| general_purpose::STANDARD.encode(note.to_bytes()))
+            .collect();
+
+        let promise = idxdb_archive_input_notes(note_ids, records);
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<Option<InputNoteRecord>, StoreError> {
+        let promise = idxdb_get_archived_input_note(note_id.inner().to_string());
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let archived_note: Option<ArchivedInputNoteIdxdbObject> = from_value(js_value).unwrap();
+
+        Ok(archived_note
+            .map(|archived_note| InputNoteRecord::read_from_bytes(&archived_note.record))
+            .transpose()?)
+    }
+
+    pub(crate) async fn restore_archived_input_note(
+        &self,
+        note_id: NoteId,
+    ) -> Result<InputNoteRecord, StoreError> {
+        let note = self
+            .get_archived_input_note(note_id)
+            .await?
+            .ok_or(StoreError::NoteNotFound(note_id))?;
+
+        let mut serialized_data = serialize_input_note(&note)?;
+        // Keep the original creation timestamp instead of the current time
+        if let Some(created_at) = note.created_at() {
+            serialized_data.created_at = created_at.to_string();
+        }
+
+        let promise = idxdb_restore_archived_input_note(
+            serialized_data.note_id,
+            serialized_data.note_assets,
+            serialized_data.serial_number,
+            serialized_data.inputs,
+            serialized_data.note_script_hash,
+            serialized_data.note_script,
+            serialized_data.nullifier,
+            serialized_data.created_at,
+            serialized_data.state_discriminant,
+            serialized_data.state,
+        );
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(note)
+    }
+
+    pub(crate) async fn get_archived_input_note_count(&self) -> Result<usize, StoreError> {
+        let promise = idxdb_count_archived_input_notes();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let count: usize = from_value(js_value).unwrap();
+
+        Ok(count)
+    }
+
+    pub(crate) async fn count_input_notes_by_state(
+        &self,
+    ) -> Result<BTreeMap<u8, usize>, StoreError> {
+        let promise = idxdb_count_input_notes_by_state();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let counts: Vec<(u8, usize)> = from_value(js_value).unwrap();
+
+        Ok(counts.into_iter().collect())
+    }
+
+    pub(crate) async fn get_pending_notes(&self) -> Result<Vec<PendingNoteRecord>, StoreError> {
+        let promise = idxdb_get_pending_notes();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let pending_notes: Vec<PendingNoteIdxdbObject> = from_value(js_value).unwrap();
+
+        pending_notes
+            .into_iter()
+            .map(|note| -> Result<PendingNoteRecord, StoreError> {
+                Ok(PendingNoteRecord::from_parts(
+                    Digest::try_from(note.note_id)?.into(),
+                    note.tag.map(NoteTag::from),
+                    note.inclusion_block_num,
+                ))
+            })
+            .collect()
+    }
+
+    pub(crate) async fn upsert_pending_note(
+        &self,
+        note: PendingNoteRecord,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_upsert_pending_note(
+            note.note_id().inner().to_string(),
+            note.tag().map(u32::from),
+            note.inclusion_block_num(),
+        );
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(crate) async fn remove_pending_note(&self, note_id: NoteId) -> Result<(), StoreError> {
+        let promise = idxdb_remove_pending_note(note_id.inner().to_string());
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_future_note_links(&self) -> Result<Vec<FutureNoteLink>, StoreError> {
+        let promise = idxdb_get_future_note_links();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let links: Vec<FutureNoteLinkIdxdbObject> = from_value(js_value).unwrap();
+
+        links
+            .into_iter()
+            .map(|link| -> Result<FutureNoteLink, StoreError> {
+                Ok(FutureNoteLink::new(
+                    Digest::try_from(link.source_note_id)?.into(),
+                    Digest::try_from(link.future_note_id)?.into(),
+                    AccountId::from_hex(&link.creator_account_id)?,
+                ))
+            })
+            .collect()
+    }
 }
 
 impl NoteFilter {
+    /// Returns the discriminants of the input note states matched by the filter, or `None` if the
+    /// filter doesn't match notes by their state. An empty list matches every state.
+    pub(crate) fn input_note_states(&self) -> Option<Vec<u8>> {
+        let states = match self {
+            NoteFilter::All => vec![],
+            NoteFilter::Consumed => vec![
+                InputNoteState::STATE_CONSUMED_AUTHENTICATED_LOCAL,
+                InputNoteState::STATE_CONSUMED_UNAUTHENTICATED_LOCAL,
+                InputNoteState::STATE_CONSUMED_EXTERNAL,
+            ],
+            NoteFilter::Committed => vec![InputNoteState::STATE_COMMITTED],
+            NoteFilter::Expected => vec![InputNoteState::STATE_EXPECTED],
+            NoteFilter::Processing => {
+                vec![
+                    InputNoteState::STATE_PROCESSING_AUTHENTICATED,
+                    InputNoteState::STATE_PROCESSING_UNAUTHENTICATED,
+                ]
+            },
+            NoteFilter::Rejected => vec![InputNoteState::STATE_REJECTED],
+            NoteFilter::Unverified => vec![InputNoteState::STATE_UNVERIFIED],
+            NoteFilter::Unspent => vec![
+                InputNoteState::STATE_EXPECTED,
+                InputNoteState::STATE_COMMITTED,
+                InputNoteState::STATE_UNVERIFIED,
+                InputNoteState::STATE_PROCESSING_AUTHENTICATED,
+                InputNoteState::STATE_PROCESSING_UNAUTHENTICATED,
+            ],
+            NoteFilter::Aux(_)
+            | NoteFilter::CreatedByTransaction(_)
+            | NoteFilter::List(_)
+            | NoteFilter::Unique(_)
+            | NoteFilter::Nullifiers(_) => return None,
+        };
+
+        Some(states)
+    }
+
     fn to_input_notes_promise(&self) -> Promise {
         match self {
             NoteFilter::All
@@ -108,38 +398,20 @@ impl NoteFilter {
             | NoteFilter::Committed
             | NoteFilter::Expected
             | NoteFilter::Processing
+            | NoteFilter::Rejected
             | NoteFilter::Unspent
             | NoteFilter::Unverified => {
-                let states: Vec<u8> = match self {
-                    NoteFilter::All => vec![],
-                    NoteFilter::Consumed => vec![
-                        InputNoteState::STATE_CONSUMED_AUTHENTICATED_LOCAL,
-                        InputNoteState::STATE_CONSUMED_UNAUTHENTICATED_LOCAL,
-                        InputNoteState::STATE_CONSUMED_EXTERNAL,
-                    ],
-                    NoteFilter::Committed => vec![InputNoteState::STATE_COMMITTED],
-                    NoteFilter::Expected => vec![InputNoteState::STATE_EXPECTED],
-                    NoteFilter::Processing => {
-                        vec![
-                            InputNoteState::STATE_PROCESSING_AUTHENTICATED,
-                            InputNoteState::STATE_PROCESSING_UNAUTHENTICATED,
-                        ]
-                    },
-                    NoteFilter::Unverified => vec![InputNoteState::STATE_UNVERIFIED],
-                    NoteFilter::Unspent => vec![
-                        InputNoteState::STATE_EXPECTED,
-                        InputNoteState::STATE_COMMITTED,
-                        InputNoteState::STATE_UNVERIFIED,
-                        InputNoteState::STATE_PROCESSING_AUTHENTICATED,
-                        InputNoteState::STATE_PROCESSING_UNAUTHENTICATED,
-                    ],
-                    _ => unreachable!(), // Safety net, should never be reached
-                };
+                let states =
+                    self.input_note_states().expect("filters by state have a list of states");
 
                 // Assuming `js_fetch_notes` is your JavaScript function that handles simple string
                 // filters
                 idxdb_get_input_notes(states)
             },
+            // The aux is not indexed, so the notes are filtered after being fetched
+            NoteFilter::Aux(_) => idxdb_get_input_notes(vec![]),
+            // Replaced with a list of note IDs before the notes are fetched
+            NoteFilter::CreatedByTransaction(_) => idxdb_get_input_notes_from_ids(vec![]),
             NoteFilter::List(ids) => {
                 let note_ids_as_str: Vec<String> =
                     ids.iter().map(|id| id.inner().to_string()).collect();
@@ -188,7 +460,10 @@ impl NoteFilter {
 
                 idxdb_get_output_notes(states)
             },
-            NoteFilter::Processing | NoteFilter::Unverified => {
+            NoteFilter::Aux(_) => idxdb_get_output_notes(vec![]),
+            // Replaced with a list of note IDs before the notes are fetched
+            NoteFilter::CreatedByTransaction(_) => idxdb_get_output_notes_from_ids(vec![]),
+            NoteFilter::Processing | NoteFilter::Rejected | NoteFilter::Unverified => {
                 Promise::resolve(&JsValue::from(Array::new()))
             },
             NoteFilter::List(ref ids) => {
@@ -18,6 +18,37 @@ pub struct InputNoteIdxdbObject {
     pub state: Vec<u8>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct InputNotesPageIdxdbObject {
+    pub notes: Vec<InputNoteIdxdbObject>,
+    pub total: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ArchivedInputNoteIdxdbObject {
+    #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
+    pub record: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PendingNoteIdxdbObject {
+    #[serde(rename = "noteId")]
+    pub note_id: String,
+    pub tag: Option<u32>,
+    #[serde(rename = "inclusionBlockNum")]
+    pub inclusion_block_num: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FutureNoteLinkIdxdbObject {
+    #[serde(rename = "sourceNoteId")]
+    pub source_note_id: String,
+    #[serde(rename = "futureNoteId")]
+    pub future_note_id: String,
+    #[serde(rename = "creatorAccountId")]
+    pub creator_account_id: String,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct OutputNoteIdxdbObject {
     #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
@@ -28,6 +59,7 @@ pub struct OutputNoteIdxdbObject {
     pub expected_height: u32,
     #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
     pub state: Vec<u8>,
+    pub created_at: Option<String>,
 }
 
 fn base64_to_vec_u8_required<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
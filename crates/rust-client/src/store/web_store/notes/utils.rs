@@ -10,17 +10,19 @@ use miden_objects::{
     Digest, Word,
 };
 use miden_tx::utils::Serializable;
+use serde::Serialize;
 use wasm_bindgen_futures::*;
 
 use super::{js_bindings::*, InputNoteIdxdbObject, OutputNoteIdxdbObject};
 use crate::{
-    notes::NoteUpdates,
+    notes::{FutureNoteLink, FutureNoteResolution, NoteUpdates},
     store::{InputNoteRecord, InputNoteState, OutputNoteRecord, OutputNoteState, StoreError},
 };
 
 // TYPES
 // ================================================================================================
 
+#[derive(Serialize)]
 pub struct SerializedInputNoteData {
     pub note_id: String,
     pub note_assets: Vec<u8>,
@@ -34,6 +36,7 @@ pub struct SerializedInputNoteData {
     pub created_at: String,
 }
 
+#[derive(Serialize)]
 pub struct SerializedOutputNoteData {
     pub note_id: String,
     pub note_assets: Vec<u8>,
@@ -43,6 +46,7 @@ pub struct SerializedOutputNoteData {
     pub expected_height: u32,
     pub state_discriminant: u8,
     pub state: Vec<u8>,
+    pub created_at: Option<String>,
 }
 
 // ================================================================================================
@@ -122,6 +126,7 @@ pub(crate) fn serialize_output_note(
         state_discriminant,
         state,
         expected_height: note.expected_height(),
+        created_at: note.created_at().map(|created_at| created_at.to_string()),
     })
 }
 
@@ -137,6 +142,7 @@ pub async fn upsert_output_note_tx(note: &OutputNoteRecord) -> Result<(), StoreE
         serialized_data.expected_height,
         serialized_data.state_discriminant,
         serialized_data.state,
+        serialized_data.created_at,
     ))
     .await;
     match result {
@@ -182,6 +188,11 @@ pub fn parse_output_note_idxdb_object(
     let note_assets = NoteAssets::read_from_bytes(&note_idxdb.assets)?;
     let recipient = Digest::try_from(note_idxdb.recipient_digest)?;
     let state = OutputNoteState::read_from_bytes(&note_idxdb.state)?;
+    let created_at = note_idxdb
+        .created_at
+        .map(|created_at| created_at.parse::<u64>())
+        .transpose()
+        .map_err(|_| StoreError::QueryError("Failed to parse created_at timestamp".to_string()))?;
 
     Ok(OutputNoteRecord::new(
         recipient,
@@ -189,6 +200,7 @@ pub fn parse_output_note_idxdb_object(
         note_metadata,
         state,
         note_idxdb.expected_height,
+        created_at,
     ))
 }
 
@@ -209,3 +221,47 @@ pub(crate) async fn apply_note_updates_tx(note_updates: &NoteUpdates) -> Result<
 
     Ok(())
 }
+
+pub(crate) async fn insert_future_note_links(links: &[FutureNoteLink]) -> Result<(), StoreError> {
+    if links.is_empty() {
+        return Ok(());
+    }
+
+    let promise = idxdb_insert_future_note_links(
+        links.iter().map(|link| link.source_note_id().inner().to_string()).collect(),
+        links.iter().map(|link| link.future_note_id().inner().to_string()).collect(),
+        links.iter().map(|link| link.creator_account_id().to_hex()).collect(),
+    );
+    JsFuture::from(promise).await.unwrap();
+
+    Ok(())
+}
+
+pub(crate) async fn apply_future_note_resolutions(
+    resolutions: &[FutureNoteResolution],
+) -> Result<(), StoreError> {
+    if resolutions.is_empty() {
+        return Ok(());
+    }
+
+    let cancelled_note_ids = resolutions
+        .iter()
+        .filter_map(|resolution| match resolution {
+            FutureNoteResolution::Cancelled { future_note_id, .. } => {
+                Some(future_note_id.inner().to_string())
+            },
+            FutureNoteResolution::Promoted { .. } => None,
+        })
+        .collect();
+    let promise = idxdb_apply_future_note_resolutions(
+        resolutions
+            .iter()
+            .map(|resolution| resolution.source_note_id().inner().to_string())
+            .collect(),
+        cancelled_note_ids,
+        InputNoteState::STATE_EXPECTED,
+    );
+    JsFuture::from(promise).await.unwrap();
+
+    Ok(())
+}
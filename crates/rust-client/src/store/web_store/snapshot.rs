@@ -0,0 +1,136 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use miden_objects::{accounts::AccountHeader, Word};
+use serde::Deserialize;
+use serde_wasm_bindgen::from_value;
+use tonic::async_trait;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::*;
+
+use super::{
+    accounts::{models::AccountRecordIdxdbOjbect, utils::parse_account_record_idxdb_object},
+    notes::{models::InputNoteIdxdbObject, utils::parse_input_note_idxdb_object},
+    transactions::{models::TransactionIdxdbObject, utils::parse_transaction_idxdb_object},
+};
+use crate::{
+    store::{InputNoteRecord, NoteFilter, StoreError, StoreSnapshot, TransactionFilter},
+    transactions::{TransactionRecord, TransactionStatus},
+};
+
+#[wasm_bindgen(module = "/src/store/web_store/js/snapshot.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = readSnapshot)]
+    fn idxdb_read_snapshot() -> js_sys::Promise;
+}
+
+#[derive(Deserialize)]
+struct SnapshotIdxdbObject {
+    account_headers: Vec<AccountRecordIdxdbOjbect>,
+    input_notes: Vec<InputNoteIdxdbObject>,
+    transactions: Vec<TransactionIdxdbObject>,
+}
+
+// WEB SNAPSHOT
+// ================================================================================================
+
+/// [StoreSnapshot] holding the records read within a single readonly IndexedDB transaction.
+///
+/// IndexedDB commits a transaction as soon as it has no pending requests, so it can't be held
+/// across the reads of the snapshot. Instead, the records served by the snapshot are read when
+/// it's opened and filtered in memory afterwards. The transaction never outlives the opening of
+/// the snapshot, so it never blocks writers and the snapshot doesn't expire.
+pub(crate) struct WebSnapshot {
+    account_headers: Vec<(AccountHeader, Option<Word>)>,
+    input_notes: Vec<InputNoteRecord>,
+    transactions: Vec<TransactionRecord>,
+}
+
+impl WebSnapshot {
+    pub(super) async fn open() -> Result<Self, StoreError> {
+        let js_value = JsFuture::from(idxdb_read_snapshot()).await.map_err(|err| {
+            StoreError::DatabaseError(format!("Failed to read store snapshot: {:?}", err))
+        })?;
+        let snapshot_idxdb: SnapshotIdxdbObject = from_value(js_value)
+            .map_err(|err| StoreError::ParsingError(format!("Failed to parse snapshot: {err}")))?;
+
+        Ok(Self {
+            account_headers: snapshot_idxdb
+                .account_headers
+                .into_iter()
+                .map(parse_account_record_idxdb_object)
+                .collect::<Result<_, _>>()?,
+            input_notes: snapshot_idxdb
+                .input_notes
+                .into_iter()
+                .map(parse_input_note_idxdb_object)
+                .collect::<Result<_, _>>()?,
+            transactions: snapshot_idxdb
+                .transactions
+                .into_iter()
+                .map(parse_transaction_idxdb_object)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreSnapshot for WebSnapshot {
+    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, Option<Word>)>, StoreError> {
+        Ok(self.account_headers.clone())
+    }
+
+    async fn get_input_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<InputNoteRecord>, StoreError> {
+        let notes: Vec<InputNoteRecord> = self
+            .input_notes
+            .iter()
+            .filter(|note| match &filter {
+                NoteFilter::List(note_ids) => note_ids.contains(&note.id()),
+                NoteFilter::Unique(note_id) => note.id() == *note_id,
+                NoteFilter::Nullifiers(nullifiers) => nullifiers.contains(&note.nullifier()),
+                NoteFilter::CreatedByTransaction(transaction_id) => {
+                    self.transactions.iter().any(|transaction| {
+                        transaction.id == *transaction_id
+                            && transaction
+                                .output_notes
+                                .iter()
+                                .any(|output| output.id() == note.id())
+                    })
+                },
+                NoteFilter::Aux(aux) => {
+                    note.metadata().is_some_and(|metadata| metadata.aux() == *aux)
+                },
+                _ => filter.input_note_states().is_some_and(|states| {
+                    states.is_empty() || states.contains(&note.state().discriminant())
+                }),
+            })
+            .cloned()
+            .collect();
+
+        match filter {
+            NoteFilter::Unique(note_id) if notes.is_empty() => {
+                Err(StoreError::NoteNotFound(note_id))
+            },
+            _ => Ok(notes),
+        }
+    }
+
+    async fn get_transactions(
+        &self,
+        filter: TransactionFilter,
+    ) -> Result<Vec<TransactionRecord>, StoreError> {
+        Ok(self
+            .transactions
+            .iter()
+            .filter(|tx| match filter {
+                TransactionFilter::All => true,
+                TransactionFilter::Uncomitted => {
+                    tx.transaction_status == TransactionStatus::Pending
+                },
+            })
+            .cloned()
+            .collect())
+    }
+}
@@ -16,6 +16,15 @@ extern "C" {
     #[wasm_bindgen(js_name = getNoteTags)]
     pub fn idxdb_get_note_tags() -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getStorageSlotWatches)]
+    pub fn idxdb_get_storage_slot_watches() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getLastSyncSummary)]
+    pub fn idxdb_get_last_sync_summary() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getPartialSyncProgress)]
+    pub fn idxdb_get_partial_sync_progress() -> js_sys::Promise;
+
     // INSERTS
     // ================================================================================================
 
@@ -24,8 +33,20 @@ extern "C" {
         tag: Vec<u8>,
         source_note_id: Option<String>,
         source_account_id: Option<String>,
+        expires_after: Option<String>,
+        keep_all: bool,
+        aux_filter: Option<String>,
     ) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = addStorageSlotWatch)]
+    pub fn idxdb_add_storage_slot_watch(watch: Vec<u8>) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = setLastSyncSummary)]
+    pub fn idxdb_set_last_sync_summary(summary: Vec<u8>) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = setPartialSyncProgress)]
+    pub fn idxdb_set_partial_sync_progress(progress: Option<Vec<u8>>) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = applyStateSync)]
     pub fn idxdb_apply_state_sync(
         block_num: String,
@@ -39,6 +60,12 @@ extern "C" {
         transactions_to_commit_block_nums: Vec<String>,
     ) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = resetChainState)]
+    pub fn idxdb_reset_chain_state(
+        keep_accounts: bool,
+        account_tag_ids: Vec<String>,
+    ) -> js_sys::Promise;
+
     // DELETES
     // ================================================================================================
     #[wasm_bindgen(js_name = removeNoteTag)]
@@ -47,4 +74,20 @@ extern "C" {
         source_note_id: Option<String>,
         source_account_id: Option<String>,
     ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = replaceNoteTags)]
+    pub fn idxdb_replace_note_tags(
+        removed_tags: Vec<String>,
+        removed_source_note_ids: Vec<String>,
+        removed_source_account_ids: Vec<String>,
+        added_tags: Vec<String>,
+        added_source_note_ids: Vec<String>,
+        added_source_account_ids: Vec<String>,
+        added_expires_after: Vec<String>,
+        added_keep_all: Vec<u8>,
+        added_aux_filters: Vec<String>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = removeStorageSlotWatch)]
+    pub fn idxdb_remove_storage_slot_watch(watch: Vec<u8>) -> js_sys::Promise;
 }
@@ -3,21 +3,28 @@ use alloc::{
     vec::Vec,
 };
 
+use base64::{engine::general_purpose, Engine as _};
 use miden_objects::{
     accounts::AccountId,
     notes::{NoteId, NoteTag},
+    Felt,
 };
 use miden_tx::utils::{Deserializable, Serializable};
 use serde_wasm_bindgen::from_value;
 use wasm_bindgen_futures::*;
 
 use super::{
-    chain_data::utils::serialize_chain_mmr_node, notes::utils::apply_note_updates_tx,
-    transactions::utils::update_account, WebStore,
+    chain_data::utils::serialize_chain_mmr_node,
+    notes::utils::{apply_future_note_resolutions, apply_note_updates_tx},
+    transactions::utils::update_account,
+    WebStore,
 };
 use crate::{
     store::StoreError,
-    sync::{NoteTagRecord, NoteTagSource, StateSyncUpdate},
+    sync::{
+        ChainStateReset, NoteTagRecord, NoteTagSource, PartialSyncProgress, StateSyncUpdate,
+        StorageSlotWatch, SyncSummary,
+    },
 };
 
 mod js_bindings;
@@ -46,9 +53,31 @@ impl WebStore {
                     _ => return Err(StoreError::ParsingError("Invalid NoteTagSource".to_string())),
                 };
 
+                let expires_after = t
+                    .expires_after
+                    .map(|block_num| {
+                        block_num.parse::<u32>().map_err(|err| {
+                            StoreError::ParsingError(format!("Invalid tag expiry: {err}"))
+                        })
+                    })
+                    .transpose()?;
+
+                let aux_filter = t
+                    .aux_filter
+                    .map(|aux| {
+                        aux.parse::<u64>().map(Felt::new).map_err(|err| {
+                            StoreError::ParsingError(format!("Invalid tag aux filter: {err}"))
+                        })
+                    })
+                    .transpose()?;
+
                 Ok(NoteTagRecord {
                     tag: NoteTag::read_from_bytes(&t.tag)?,
                     source,
+                    expires_after,
+                    // Tags stored before subscriptions were introduced keep every note
+                    keep_all: t.keep_all.unwrap_or(true),
+                    aux_filter,
                 })
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -65,6 +94,52 @@ impl WebStore {
         Ok(block_num_as_u32)
     }
 
+    pub(super) async fn get_last_sync_summary(&self) -> Result<Option<SyncSummary>, StoreError> {
+        let promise = idxdb_get_last_sync_summary();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let summary_idxdb: LastSyncSummaryIdxdbObject = from_value(js_value).unwrap();
+
+        summary_idxdb
+            .last_sync_summary
+            .map(|summary| SyncSummary::read_from_bytes(&summary))
+            .transpose()
+            .map_err(StoreError::DataDeserializationError)
+    }
+
+    pub(super) async fn set_last_sync_summary(
+        &self,
+        summary: SyncSummary,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_set_last_sync_summary(summary.to_bytes());
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(super) async fn get_partial_sync_progress(
+        &self,
+    ) -> Result<Option<PartialSyncProgress>, StoreError> {
+        let promise = idxdb_get_partial_sync_progress();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let progress_idxdb: PartialSyncProgressIdxdbObject = from_value(js_value).unwrap();
+
+        progress_idxdb
+            .partial_sync_progress
+            .map(|progress| PartialSyncProgress::read_from_bytes(&progress))
+            .transpose()
+            .map_err(StoreError::DataDeserializationError)
+    }
+
+    pub(super) async fn set_partial_sync_progress(
+        &self,
+        progress: Option<PartialSyncProgress>,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_set_partial_sync_progress(progress.map(|progress| progress.to_bytes()));
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
     pub(super) async fn add_note_tag(&self, tag: NoteTagRecord) -> Result<bool, StoreError> {
         if self.get_note_tags().await?.contains(&tag) {
             return Ok(false);
@@ -76,7 +151,14 @@ impl WebStore {
             NoteTagSource::User => (None, None),
         };
 
-        let promise = idxdb_add_note_tag(tag.tag.to_bytes(), source_note_id, source_account_id);
+        let promise = idxdb_add_note_tag(
+            tag.tag.to_bytes(),
+            source_note_id,
+            source_account_id,
+            tag.expires_after.map(|block_num| block_num.to_string()),
+            tag.keep_all,
+            tag.aux_filter.map(|aux| aux.as_int().to_string()),
+        );
         JsFuture::from(promise).await.unwrap();
 
         Ok(true)
@@ -95,6 +177,83 @@ impl WebStore {
         Ok(removed_tags)
     }
 
+    pub(super) async fn replace_note_tags(
+        &self,
+        removed: Vec<NoteTagRecord>,
+        added: Vec<NoteTagRecord>,
+    ) -> Result<(), StoreError> {
+        // Tags are stored encoded in base64, and missing values are passed as empty strings
+        let encode_tag = |tag: &NoteTagRecord| general_purpose::STANDARD.encode(tag.tag.to_bytes());
+        let source_note_id = |tag: &NoteTagRecord| match tag.source {
+            NoteTagSource::Note(note_id) => note_id.to_hex(),
+            _ => String::new(),
+        };
+        let source_account_id = |tag: &NoteTagRecord| match tag.source {
+            NoteTagSource::Account(account_id) => account_id.to_hex(),
+            _ => String::new(),
+        };
+
+        let promise = idxdb_replace_note_tags(
+            removed.iter().map(encode_tag).collect(),
+            removed.iter().map(source_note_id).collect(),
+            removed.iter().map(source_account_id).collect(),
+            added.iter().map(encode_tag).collect(),
+            added.iter().map(source_note_id).collect(),
+            added.iter().map(source_account_id).collect(),
+            added
+                .iter()
+                .map(|tag| {
+                    tag.expires_after.map(|block_num| block_num.to_string()).unwrap_or_default()
+                })
+                .collect(),
+            added.iter().map(|tag| u8::from(tag.keep_all)).collect(),
+            added
+                .iter()
+                .map(|tag| tag.aux_filter.map(|aux| aux.as_int().to_string()).unwrap_or_default())
+                .collect(),
+        );
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(super) async fn get_storage_slot_watches(
+        &self,
+    ) -> Result<Vec<StorageSlotWatch>, StoreError> {
+        let promise = idxdb_get_storage_slot_watches();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let watches_idxdb: Vec<StorageSlotWatchIdxdbObject> = from_value(js_value).unwrap();
+
+        watches_idxdb
+            .into_iter()
+            .map(|w| Ok(StorageSlotWatch::read_from_bytes(&w.watch)?))
+            .collect()
+    }
+
+    pub(super) async fn add_storage_slot_watch(
+        &self,
+        watch: StorageSlotWatch,
+    ) -> Result<bool, StoreError> {
+        if self.get_storage_slot_watches().await?.contains(&watch) {
+            return Ok(false);
+        }
+
+        let promise = idxdb_add_storage_slot_watch(watch.to_bytes());
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(true)
+    }
+
+    pub(super) async fn remove_storage_slot_watch(
+        &self,
+        watch: StorageSlotWatch,
+    ) -> Result<usize, StoreError> {
+        let promise = idxdb_remove_storage_slot_watch(watch.to_bytes());
+        let removed_watches = from_value(JsFuture::from(promise).await.unwrap()).unwrap();
+
+        Ok(removed_watches)
+    }
+
     pub(super) async fn apply_state_sync(
         &self,
         state_sync_update: StateSyncUpdate,
@@ -110,6 +269,7 @@ impl WebStore {
             transactions_to_discard: _transactions_to_discard, /* TODO: Add support for discarded
                                                                 * transactions in web store */
             tags_to_remove,
+            future_note_resolutions,
         } = state_sync_update;
 
         // Serialize data for updating state sync and block header
@@ -131,6 +291,7 @@ impl WebStore {
         // TODO: LOP INTO idxdb_apply_state_sync call
         // Update notes
         apply_note_updates_tx(&note_updates).await?;
+        apply_future_note_resolutions(&future_note_resolutions).await?;
 
         // Tags to remove
         let note_tags_to_remove_as_str: Vec<String> = tags_to_remove
@@ -175,4 +336,37 @@ impl WebStore {
 
         Ok(())
     }
+
+    pub(super) async fn reset_chain_state(&self, reset: ChainStateReset) -> Result<(), StoreError> {
+        let ChainStateReset {
+            note_updates,
+            transactions_to_discard: _transactions_to_discard, /* TODO: Add support for discarded
+                                                                * transactions in web store */
+            keep_accounts,
+            tags_to_add,
+            tags_to_remove,
+        } = reset;
+
+        // TODO: LOP INTO idxdb_reset_chain_state call
+        apply_note_updates_tx(&note_updates).await?;
+        for tag in tags_to_add {
+            self.add_note_tag(tag).await?;
+        }
+
+        let account_tag_ids: Vec<String> = tags_to_remove
+            .iter()
+            .filter_map(|tag_record| {
+                if let NoteTagSource::Account(account_id) = tag_record.source {
+                    Some(account_id.to_hex())
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let promise = idxdb_reset_chain_state(keep_accounts, account_tag_ids);
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
 }
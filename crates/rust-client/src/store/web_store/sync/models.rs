@@ -8,12 +8,36 @@ pub struct SyncHeightIdxdbObject {
     pub block_num: String,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct LastSyncSummaryIdxdbObject {
+    #[serde(deserialize_with = "base64_to_vec_u8_optional", default)]
+    pub last_sync_summary: Option<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PartialSyncProgressIdxdbObject {
+    #[serde(deserialize_with = "base64_to_vec_u8_optional", default)]
+    pub partial_sync_progress: Option<Vec<u8>>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct NoteTagIdxdbObject {
     #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
     pub tag: Vec<u8>,
     pub source_note_id: Option<String>,
     pub source_account_id: Option<String>,
+    #[serde(default)]
+    pub expires_after: Option<String>,
+    #[serde(default)]
+    pub keep_all: Option<bool>,
+    #[serde(default)]
+    pub aux_filter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StorageSlotWatchIdxdbObject {
+    #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
+    pub watch: Vec<u8>,
 }
 
 fn base64_to_vec_u8_required<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
@@ -25,3 +49,17 @@ where
         .decode(&base64_str)
         .map_err(|e| Error::custom(format!("Base64 decode error: {}", e)))
 }
+
+fn base64_to_vec_u8_optional<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let base64_str: Option<String> = Option::deserialize(deserializer)?;
+    match base64_str {
+        Some(str) => general_purpose::STANDARD
+            .decode(&str)
+            .map(Some)
+            .map_err(|e| Error::custom(format!("Base64 decode error: {}", e))),
+        None => Ok(None),
+    }
+}
@@ -13,6 +13,12 @@ extern "C" {
     #[wasm_bindgen(js_name = getTransactions)]
     pub fn idxdb_get_transactions(filter: String) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getProvenTransaction)]
+    pub fn idxdb_get_proven_transaction(transaction_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getSubmissionAttempts)]
+    pub fn idxdb_get_submission_attempts(transaction_id: String) -> js_sys::Promise;
+
     #[wasm_bindgen(js_name = insertTransactionScript)]
     pub fn idxdb_insert_transaction_script(
         script_hash: Vec<u8>,
@@ -30,5 +36,31 @@ extern "C" {
         script_hash: Option<Vec<u8>>,
         block_num: String,
         committed: Option<String>,
+        provenance: Option<Vec<u8>>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = discardLocalTransaction)]
+    pub fn idxdb_discard_local_transaction(
+        transaction_id: String,
+        account_id: String,
+        init_account_nonce: String,
+        removed_note_ids: Vec<String>,
+        discard_cause: Vec<u8>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = insertProvenTransaction)]
+    pub fn idxdb_insert_proven_transaction(
+        transaction_id: String,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
     ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = recordSubmissionAttempt)]
+    pub fn idxdb_record_submission_attempt(
+        transaction_id: String,
+        account_id: String,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = removeSubmissionAttempts)]
+    pub fn idxdb_remove_submission_attempts(transaction_id: String) -> js_sys::Promise;
 }
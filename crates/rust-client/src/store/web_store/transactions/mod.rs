@@ -1,24 +1,29 @@
-use alloc::{string::ToString, vec::Vec};
-
-use miden_objects::{
-    accounts::AccountId,
-    transaction::{OutputNotes, TransactionScript},
-    Digest,
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
 };
-use miden_tx::utils::Deserializable;
+
+use base64::{engine::general_purpose, Engine as _};
+use miden_objects::{accounts::AccountId, transaction::TransactionId};
+use miden_tx::utils::Serializable;
 use serde_wasm_bindgen::from_value;
 use wasm_bindgen_futures::*;
 
-use super::{notes::utils::apply_note_updates_tx, WebStore};
+use super::{
+    notes::utils::{apply_note_updates_tx, insert_future_note_links},
+    WebStore,
+};
 use crate::{
     store::{StoreError, TransactionFilter},
-    transactions::{TransactionRecord, TransactionStatus, TransactionStoreUpdate},
+    transactions::{
+        DiscardCause, LocalTransactionDiscard, TransactionRecord, TransactionStoreUpdate,
+    },
 };
 
 mod js_bindings;
 use js_bindings::*;
 
-mod models;
+pub(crate) mod models;
 use models::*;
 
 pub mod utils;
@@ -38,55 +43,7 @@ impl WebStore {
         let js_value = JsFuture::from(promise).await.unwrap();
         let transactions_idxdb: Vec<TransactionIdxdbObject> = from_value(js_value).unwrap();
 
-        let transaction_records: Result<Vec<TransactionRecord>, StoreError> = transactions_idxdb
-            .into_iter()
-            .map(|tx_idxdb| {
-                let native_account_id = AccountId::from_hex(&tx_idxdb.account_id).unwrap();
-                let block_num_as_u32: u32 = tx_idxdb.block_num.parse::<u32>().unwrap();
-                let commit_height_as_u32: Option<u32> =
-                    tx_idxdb.commit_height.map(|height| height.parse::<u32>().unwrap());
-
-                let id: Digest = tx_idxdb.id.try_into()?;
-                let init_account_state: Digest = tx_idxdb.init_account_state.try_into()?;
-
-                let final_account_state: Digest = tx_idxdb.final_account_state.try_into()?;
-
-                let input_note_nullifiers: Vec<Digest> =
-                    Vec::<Digest>::read_from_bytes(&tx_idxdb.input_notes)?;
-
-                let output_notes = OutputNotes::read_from_bytes(&tx_idxdb.output_notes)?;
-
-                let transaction_script: Option<TransactionScript> =
-                    if tx_idxdb.script_hash.is_some() {
-                        let tx_script = tx_idxdb
-                            .tx_script
-                            .map(|script| TransactionScript::read_from_bytes(&script))
-                            .transpose()?
-                            .expect("Transaction script should be included in the row");
-
-                        Some(tx_script)
-                    } else {
-                        None
-                    };
-
-                let transaction_status = commit_height_as_u32
-                    .map_or(TransactionStatus::Pending, TransactionStatus::Committed);
-
-                Ok(TransactionRecord {
-                    id: id.into(),
-                    account_id: native_account_id,
-                    init_account_state,
-                    final_account_state,
-                    input_note_nullifiers,
-                    output_notes,
-                    transaction_script,
-                    block_num: block_num_as_u32,
-                    transaction_status,
-                })
-            })
-            .collect();
-
-        transaction_records
+        transactions_idxdb.into_iter().map(parse_transaction_idxdb_object).collect()
     }
 
     pub async fn apply_transaction(
@@ -94,7 +51,8 @@ impl WebStore {
         tx_update: TransactionStoreUpdate,
     ) -> Result<(), StoreError> {
         // Transaction Data
-        insert_proven_transaction_data(tx_update.executed_transaction()).await?;
+        insert_proven_transaction_data(tx_update.executed_transaction(), tx_update.provenance())
+            .await?;
 
         // Account Data
         update_account(tx_update.updated_account()).await.unwrap();
@@ -106,6 +64,113 @@ impl WebStore {
             self.add_note_tag(*tag_record).await?;
         }
 
+        insert_future_note_links(tx_update.future_note_links()).await?;
+
+        Ok(())
+    }
+
+    pub async fn discard_local_transaction(
+        &self,
+        discard: LocalTransactionDiscard,
+    ) -> Result<(), StoreError> {
+        let LocalTransactionDiscard {
+            transaction_id,
+            account_id,
+            init_account_nonce,
+            note_updates,
+            removed_note_ids,
+            tags_to_add,
+            tags_to_remove,
+        } = discard;
+
+        apply_note_updates_tx(&note_updates).await?;
+        for tag in tags_to_add {
+            self.add_note_tag(tag).await?;
+        }
+        for tag in tags_to_remove {
+            self.remove_note_tag(tag).await?;
+        }
+
+        let promise = idxdb_discard_local_transaction(
+            transaction_id.inner().to_string(),
+            account_id.to_string(),
+            init_account_nonce.to_string(),
+            removed_note_ids.iter().map(|note_id| note_id.inner().to_string()).collect(),
+            DiscardCause::ManuallyDiscarded.to_bytes(),
+        );
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to discard transaction: {js_error:?}"))
+        })?;
+
+        Ok(())
+    }
+
+    pub async fn insert_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+        proven_transaction: Vec<u8>,
+        max_total_size: usize,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_insert_proven_transaction(
+            transaction_id.inner().to_string(),
+            proven_transaction,
+            max_total_size,
+        );
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<Vec<u8>>, StoreError> {
+        let promise = idxdb_get_proven_transaction(transaction_id.inner().to_string());
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let proven_transaction: Option<String> = from_value(js_value).unwrap();
+
+        proven_transaction
+            .map(|proven_transaction| {
+                general_purpose::STANDARD.decode(proven_transaction).map_err(|err| {
+                    StoreError::DatabaseError(format!("Invalid proven transaction: {err}"))
+                })
+            })
+            .transpose()
+    }
+
+    pub async fn record_submission_attempt(
+        &self,
+        transaction_id: TransactionId,
+        account_id: AccountId,
+    ) -> Result<u32, StoreError> {
+        let promise = idxdb_record_submission_attempt(
+            transaction_id.inner().to_string(),
+            account_id.to_string(),
+        );
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("Failed to record submission attempt: {js_error:?}"))
+        })?;
+
+        Ok(from_value(js_value).unwrap())
+    }
+
+    pub async fn get_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<u32, StoreError> {
+        let promise = idxdb_get_submission_attempts(transaction_id.inner().to_string());
+        let js_value = JsFuture::from(promise).await.unwrap();
+
+        Ok(from_value(js_value).unwrap())
+    }
+
+    pub async fn remove_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_remove_submission_attempts(transaction_id.inner().to_string());
+        JsFuture::from(promise).await.unwrap();
+
         Ok(())
     }
 }
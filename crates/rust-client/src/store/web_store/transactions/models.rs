@@ -19,6 +19,12 @@ pub struct TransactionIdxdbObject {
     pub tx_script: Option<Vec<u8>>,
     pub block_num: String,             // usually u32
     pub commit_height: Option<String>, // usually Option<u32>
+    #[serde(deserialize_with = "base64_to_vec_u8_optional", default)]
+    pub provenance: Option<Vec<u8>>,
+    #[serde(default)]
+    pub discarded: bool,
+    #[serde(deserialize_with = "base64_to_vec_u8_optional", default)]
+    pub discard_cause: Option<Vec<u8>>,
 }
 
 fn base64_to_vec_u8_required<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
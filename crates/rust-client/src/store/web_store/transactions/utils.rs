@@ -5,19 +5,22 @@ use alloc::{
 };
 
 use miden_objects::{
-    accounts::Account,
-    transaction::{ExecutedTransaction, ToInputNoteCommitments},
+    accounts::{Account, AccountId},
+    transaction::{ExecutedTransaction, OutputNotes, ToInputNoteCommitments, TransactionScript},
     Digest,
 };
-use miden_tx::utils::Serializable;
+use miden_tx::utils::{Deserializable, Serializable};
 use wasm_bindgen_futures::*;
 
-use super::js_bindings::*;
-use crate::store::{
-    web_store::accounts::utils::{
-        insert_account_asset_vault, insert_account_record, insert_account_storage,
+use super::{js_bindings::*, models::TransactionIdxdbObject};
+use crate::{
+    store::{
+        web_store::accounts::utils::{
+            insert_account_asset_vault, insert_account_record, insert_account_storage,
+        },
+        StoreError,
     },
-    StoreError,
+    transactions::{DiscardCause, TransactionProvenance, TransactionRecord, TransactionStatus},
 };
 
 // TYPES
@@ -34,14 +37,16 @@ pub struct SerializedTransactionData {
     pub tx_script: Option<Vec<u8>>,
     pub block_num: String,
     pub commit_height: Option<String>,
+    pub provenance: Option<Vec<u8>>,
 }
 
 // ================================================================================================
 
 pub async fn insert_proven_transaction_data(
     executed_transaction: &ExecutedTransaction,
+    provenance: Option<&TransactionProvenance>,
 ) -> Result<(), StoreError> {
-    let serialized_data = serialize_transaction_data(executed_transaction)?;
+    let serialized_data = serialize_transaction_data(executed_transaction, provenance)?;
 
     if let Some(hash) = serialized_data.script_hash.clone() {
         let promise = idxdb_insert_transaction_script(hash, serialized_data.tx_script);
@@ -58,6 +63,7 @@ pub async fn insert_proven_transaction_data(
         serialized_data.script_hash.clone(),
         serialized_data.block_num,
         serialized_data.commit_height,
+        serialized_data.provenance,
     );
     JsFuture::from(promise).await.unwrap();
 
@@ -66,6 +72,7 @@ pub async fn insert_proven_transaction_data(
 
 pub(super) fn serialize_transaction_data(
     executed_transaction: &ExecutedTransaction,
+    provenance: Option<&TransactionProvenance>,
 ) -> Result<SerializedTransactionData, StoreError> {
     let transaction_id: String = executed_transaction.id().inner().into();
 
@@ -105,6 +112,7 @@ pub(super) fn serialize_transaction_data(
         tx_script,
         block_num: executed_transaction.block_header().block_num().to_string(),
         commit_height: None,
+        provenance: provenance.map(|provenance| provenance.to_bytes()),
     })
 }
 
@@ -113,3 +121,64 @@ pub async fn update_account(new_account_state: &Account) -> Result<(), ()> {
     let _ = insert_account_asset_vault(new_account_state.vault()).await;
     insert_account_record(new_account_state, None).await
 }
+
+/// Parses a transaction read from IndexedDB into a [TransactionRecord].
+pub fn parse_transaction_idxdb_object(
+    tx_idxdb: TransactionIdxdbObject,
+) -> Result<TransactionRecord, StoreError> {
+    let native_account_id = AccountId::from_hex(&tx_idxdb.account_id).unwrap();
+    let block_num_as_u32: u32 = tx_idxdb.block_num.parse::<u32>().unwrap();
+    let commit_height_as_u32: Option<u32> =
+        tx_idxdb.commit_height.map(|height| height.parse::<u32>().unwrap());
+
+    let id: Digest = tx_idxdb.id.try_into()?;
+    let init_account_state: Digest = tx_idxdb.init_account_state.try_into()?;
+
+    let final_account_state: Digest = tx_idxdb.final_account_state.try_into()?;
+
+    let input_note_nullifiers: Vec<Digest> = Vec::<Digest>::read_from_bytes(&tx_idxdb.input_notes)?;
+
+    let output_notes = OutputNotes::read_from_bytes(&tx_idxdb.output_notes)?;
+
+    let transaction_script: Option<TransactionScript> = if tx_idxdb.script_hash.is_some() {
+        let tx_script = tx_idxdb
+            .tx_script
+            .map(|script| TransactionScript::read_from_bytes(&script))
+            .transpose()?
+            .expect("Transaction script should be included in the row");
+
+        Some(tx_script)
+    } else {
+        None
+    };
+
+    let transaction_status = if tx_idxdb.discarded {
+        TransactionStatus::Discarded
+    } else {
+        commit_height_as_u32.map_or(TransactionStatus::Pending, TransactionStatus::Committed)
+    };
+
+    let discard_cause = tx_idxdb
+        .discard_cause
+        .map(|cause| DiscardCause::read_from_bytes(&cause))
+        .transpose()?;
+
+    let provenance = tx_idxdb
+        .provenance
+        .map(|provenance| TransactionProvenance::read_from_bytes(&provenance))
+        .transpose()?;
+
+    Ok(TransactionRecord {
+        id: id.into(),
+        account_id: native_account_id,
+        init_account_state,
+        final_account_state,
+        input_note_nullifiers,
+        output_notes,
+        transaction_script,
+        block_num: block_num_as_u32,
+        transaction_status,
+        provenance,
+        discard_cause,
+    })
+}
@@ -1,13 +1,18 @@
-use alloc::vec::Vec;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 
 use crypto::merkle::{InOrderIndex, MmrDelta, MmrPeaks, PartialMmr};
 use miden_objects::{
+    accounts::AccountId,
     crypto::{self, merkle::MerklePath, rand::FeltRng},
+    notes::{Note, NoteTag},
     BlockHeader, Digest,
 };
 use tracing::warn;
 
-use super::NoteUpdates;
+use super::{NoteTagSource, NoteUpdates};
 use crate::{
     notes::NoteScreener,
     store::{ChainMmrNodeFilter, NoteFilter, StoreError},
@@ -41,6 +46,28 @@ impl<R: FeltRng> Client<R> {
         Ok(())
     }
 
+    /// Stores a checkpoint of the current [PartialMmr] if the sync height advanced at least
+    /// `mmr_checkpoint_interval` blocks past the last one.
+    pub(crate) async fn checkpoint_partial_mmr(&mut self) -> Result<(), ClientError> {
+        if self.mmr_checkpoint_interval == 0 {
+            return Ok(());
+        }
+
+        let current_block_num = self.store.get_sync_height().await?;
+        if let Some((checkpoint_block_num, _)) = self.store.load_mmr_checkpoint().await? {
+            if checkpoint_block_num <= current_block_num
+                && current_block_num - checkpoint_block_num < self.mmr_checkpoint_interval
+            {
+                return Ok(());
+            }
+        }
+
+        let partial_mmr = self.rebuild_partial_mmr(current_block_num).await?;
+        self.store.save_mmr_checkpoint(current_block_num, &partial_mmr).await?;
+
+        Ok(())
+    }
+
     /// Attempts to retrieve the genesis block from the store. If not found,
     /// it requests it from the node and store it.
     pub(crate) async fn ensure_genesis_in_place(&mut self) -> Result<(), ClientError> {
@@ -71,27 +98,45 @@ impl<R: FeltRng> Client<R> {
 
     /// Checks the relevance of the block by verifying if any of the input notes in the block are
     /// relevant to the client. If any of the notes are relevant, the function returns `true`.
+    ///
+    /// The notes are grouped by tag, and the notes of a tag that was only derived from tracked
+    /// accounts are screened against those accounts alone. Notes of any other tag are screened
+    /// against every tracked account.
     pub(crate) async fn check_block_relevance(
         &mut self,
         committed_notes: &NoteUpdates,
     ) -> Result<bool, ClientError> {
         // We'll only do the check for either incoming public notes or expected input notes as
         // output notes are not really candidates to be consumed here.
-
-        let note_screener = NoteScreener::new(self.store.clone());
-
-        // Find all relevant Input Notes using the note checker
+        let mut notes_by_tag: BTreeMap<NoteTag, Vec<Note>> = BTreeMap::new();
         for input_note in committed_notes
             .updated_input_notes()
             .iter()
-            .chain(committed_notes.new_input_notes().iter())
+            .chain(committed_notes.new_input_notes())
         {
-            if !note_screener
-                .check_relevance(&input_note.try_into().map_err(ClientError::NoteRecordError)?)
-                .await?
-                .is_empty()
-            {
-                return Ok(true);
+            let note = input_note.to_note()?;
+            notes_by_tag.entry(note.metadata().tag()).or_default().push(note);
+        }
+        if notes_by_tag.is_empty() {
+            return Ok(false);
+        }
+
+        let tag_sources = self.tag_sources().await?;
+        let tracked_accounts: BTreeSet<AccountId> =
+            self.store.get_account_ids().await?.into_iter().collect();
+        let note_screener = NoteScreener::new(self.store.clone());
+
+        for (tag, notes) in notes_by_tag {
+            let candidates = tag_sources
+                .get(&tag)
+                .and_then(|sources| account_sources(sources, &tracked_accounts))
+                .unwrap_or_else(|| tracked_accounts.clone());
+
+            for note in notes {
+                if !note_screener.check_relevance_for_accounts(&note, &candidates).await?.is_empty()
+                {
+                    return Ok(true);
+                }
             }
         }
 
@@ -104,12 +149,40 @@ impl<R: FeltRng> Client<R> {
     ///
     /// As part of the syncing process, we add the current block number so we don't need to
     /// track it here.
+    ///
+    /// If the store has a checkpoint of the MMR at the current sync height, it's used instead of
+    /// rebuilding the MMR from the tracked authentication nodes.
     pub(crate) async fn build_current_partial_mmr(
         &self,
         include_current_block: bool,
     ) -> Result<PartialMmr, ClientError> {
         let current_block_num = self.store.get_sync_height().await?;
 
+        let mut current_partial_mmr = match self.store.load_mmr_checkpoint().await? {
+            Some((checkpoint_block_num, partial_mmr))
+                if checkpoint_block_num == current_block_num =>
+            {
+                partial_mmr
+            },
+            _ => self.rebuild_partial_mmr(current_block_num).await?,
+        };
+
+        if include_current_block {
+            let (current_block, has_client_notes) =
+                self.store.get_block_header_by_num(current_block_num).await?;
+
+            current_partial_mmr.add(current_block.hash(), has_client_notes);
+        }
+
+        Ok(current_partial_mmr)
+    }
+
+    /// Builds the [PartialMmr] at `current_block_num` from the peaks stored for that block and
+    /// every tracked authentication node, without the block itself.
+    pub(crate) async fn rebuild_partial_mmr(
+        &self,
+        current_block_num: u32,
+    ) -> Result<PartialMmr, ClientError> {
         let tracked_nodes = self.store.get_chain_mmr_nodes(ChainMmrNodeFilter::All).await?;
         let current_peaks = self.store.get_chain_mmr_peaks_by_block_num(current_block_num).await?;
 
@@ -123,17 +196,7 @@ impl<R: FeltRng> Client<R> {
             false
         };
 
-        let mut current_partial_mmr =
-            PartialMmr::from_parts(current_peaks, tracked_nodes, track_latest);
-
-        if include_current_block {
-            let (current_block, has_client_notes) =
-                self.store.get_block_header_by_num(current_block_num).await?;
-
-            current_partial_mmr.add(current_block.hash(), has_client_notes);
-        }
-
-        Ok(current_partial_mmr)
+        Ok(PartialMmr::from_parts(current_peaks, tracked_nodes, track_latest))
     }
 
     /// Retrieves and stores a [BlockHeader] by number, and stores its authentication data as well.
@@ -241,3 +304,20 @@ pub(crate) fn apply_mmr_changes(
 
     Ok((partial_mmr.peaks(), new_authentication_nodes))
 }
+
+/// Returns the tracked accounts among the sources of a tag, or `None` if any of its sources is not
+/// a tracked account, in which case the tag's notes can be meant for any account.
+fn account_sources(
+    sources: &[NoteTagSource],
+    tracked_accounts: &BTreeSet<AccountId>,
+) -> Option<BTreeSet<AccountId>> {
+    sources
+        .iter()
+        .map(|source| match source {
+            NoteTagSource::Account(account_id) if tracked_accounts.contains(account_id) => {
+                Some(*account_id)
+            },
+            _ => None,
+        })
+        .collect()
+}
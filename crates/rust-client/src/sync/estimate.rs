@@ -0,0 +1,176 @@
+use alloc::collections::VecDeque;
+use core::{fmt, time::Duration};
+
+use miden_objects::crypto::rand::FeltRng;
+
+use crate::{Client, ClientError};
+
+/// Estimated size of a block header in a sync response, in bytes.
+const BLOCK_HEADER_SIZE: u64 = 320;
+
+/// Size of an MMR node in a sync response, in bytes.
+const MMR_NODE_SIZE: u64 = 32;
+
+/// Estimated size of a committed note in a sync response, in bytes. Most of it is the note's
+/// merkle path within the block, which has a depth of 16.
+const COMMITTED_NOTE_SIZE: u64 = 16 * 32 + 72;
+
+/// Estimated size of an account commitment update in a sync response, in bytes.
+const ACCOUNT_UPDATE_SIZE: u64 = 48;
+
+/// Number of recent sync iterations the sync rate is measured over.
+const SYNC_RATE_WINDOW: usize = 16;
+
+// SYNC ESTIMATE
+// ================================================================================================
+
+/// Estimate of the work needed to sync the client to the chain tip, as returned by
+/// [Client::estimate_sync_work].
+///
+/// The estimate is a heuristic upper bound. The node answers each sync request with the next
+/// block that has notes matching the client's tags, so `max_iterations` assumes every block of the
+/// gap has one. Each response is assumed to carry a block header, a committed note per tracked tag
+/// and a commitment update per tracked account, and the MMR delta of the whole gap adds about two
+/// nodes per block. Blocks with more matching notes than
+/// [Client::set_max_notes_per_sync_iteration] are synced in several requests, and the details of
+/// new public notes are fetched separately, so neither is accounted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncEstimate {
+    /// Block the client is currently synced to.
+    pub sync_height: u32,
+    /// Latest block known by the node.
+    pub chain_tip: u32,
+    /// Number of blocks between the sync height and the chain tip.
+    pub block_gap: u32,
+    /// Upper bound of the number of sync requests needed to reach the chain tip. A sync always
+    /// makes at least one request, even if the client is up to date.
+    pub max_iterations: u32,
+    /// Number of distinct note tags the client syncs.
+    pub tracked_tags: usize,
+    /// Number of accounts the client syncs.
+    pub tracked_accounts: usize,
+    /// Rough upper bound of the total size of the sync responses, in bytes.
+    pub max_response_bytes: u64,
+}
+
+impl SyncEstimate {
+    /// Returns whether the client is already synced to the chain tip.
+    pub fn is_synced(&self) -> bool {
+        self.block_gap == 0
+    }
+}
+
+// SYNC RATE
+// ================================================================================================
+
+/// Rate at which the client syncs blocks, as returned by [Client::sync_rate].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct BlocksPerSecond(f64);
+
+impl BlocksPerSecond {
+    /// Returns the number of blocks synced per second.
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl fmt::Display for BlocksPerSecond {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} blocks/s", self.0)
+    }
+}
+
+/// Blocks advanced and time taken by the most recent sync iterations.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SyncRateTracker {
+    samples: VecDeque<(u32, Duration)>,
+}
+
+impl SyncRateTracker {
+    /// Records a sync iteration that advanced the sync height by `blocks` in `elapsed`, dropping
+    /// the oldest iteration once [SYNC_RATE_WINDOW] are recorded.
+    pub(crate) fn record(&mut self, blocks: u32, elapsed: Duration) {
+        if self.samples.len() == SYNC_RATE_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((blocks, elapsed));
+    }
+
+    /// Returns the rate over the recorded iterations, or `None` if no time was measured.
+    pub(crate) fn rate(&self) -> Option<BlocksPerSecond> {
+        let blocks: u64 = self.samples.iter().map(|(blocks, _)| *blocks as u64).sum();
+        let elapsed: Duration = self.samples.iter().map(|(_, elapsed)| *elapsed).sum();
+        if elapsed.is_zero() {
+            return None;
+        }
+
+        Some(BlocksPerSecond(blocks as f64 / elapsed.as_secs_f64()))
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // SYNC ESTIMATE
+    // --------------------------------------------------------------------------------------------
+
+    /// Estimates the work needed to sync the client to the chain tip, so that applications can
+    /// warn users before a long catch-up sync. See [SyncEstimate] for how it's computed.
+    ///
+    /// Only the latest block header is requested from the node, without its MMR proof.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node can't be reached or the store can't be read.
+    pub async fn estimate_sync_work(&mut self) -> Result<SyncEstimate, ClientError> {
+        let (chain_tip_header, _) = self.rpc_api.get_block_header_by_number(None, false).await?;
+        let chain_tip = chain_tip_header.block_num();
+        let sync_height = self.get_sync_height().await?;
+        let tracked_tags = self.store.get_unique_note_tags().await?.len();
+        let tracked_accounts = self.store.get_account_ids().await?.len();
+
+        let block_gap = chain_tip.saturating_sub(sync_height);
+        let max_iterations = block_gap.max(1);
+        let response_size = BLOCK_HEADER_SIZE
+            + tracked_tags as u64 * COMMITTED_NOTE_SIZE
+            + tracked_accounts as u64 * ACCOUNT_UPDATE_SIZE;
+        let max_response_bytes =
+            max_iterations as u64 * response_size + 2 * block_gap as u64 * MMR_NODE_SIZE;
+
+        Ok(SyncEstimate {
+            sync_height,
+            chain_tip,
+            block_gap,
+            max_iterations,
+            tracked_tags,
+            tracked_accounts,
+            max_response_bytes,
+        })
+    }
+
+    /// Returns the rate at which the client synced blocks over its most recent sync iterations,
+    /// or `None` if no sync was timed yet.
+    ///
+    /// Iterations are timed with a monotonic clock under `std`. Otherwise, they are timed with the
+    /// client's [TimeSource](crate::time::TimeSource), which only measures whole seconds, so the
+    /// rate is unavailable until the timed iterations add up to a second.
+    pub fn sync_rate(&self) -> Option<BlocksPerSecond> {
+        self.sync_rate.rate()
+    }
+
+    /// Estimates the time left to sync the client to the chain tip, combining the block gap of
+    /// [Client::estimate_sync_work] with the [Client::sync_rate]. Returns `None` if the client is
+    /// behind the chain tip and no sync was timed yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node can't be reached or the store can't be read.
+    pub async fn estimated_time_to_tip(&mut self) -> Result<Option<Duration>, ClientError> {
+        let estimate = self.estimate_sync_work().await?;
+        if estimate.is_synced() {
+            return Ok(Some(Duration::ZERO));
+        }
+
+        Ok(self.sync_rate().filter(|rate| rate.value() > 0.0).and_then(|rate| {
+            Duration::try_from_secs_f64(estimate.block_gap as f64 / rate.value()).ok()
+        }))
+    }
+}
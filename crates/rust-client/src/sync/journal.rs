@@ -0,0 +1,367 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::cmp::max;
+
+use miden_objects::{
+    accounts::AccountId,
+    crypto::{hash::rpo::Rpo256, rand::FeltRng},
+    notes::NoteTag,
+    Digest, Felt,
+};
+use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::SyncSummary;
+use crate::{rpc::StateSyncInfo, Client};
+
+// SYNC JOURNAL
+// ================================================================================================
+
+/// Bounded log of the sync iterations performed by a client.
+///
+/// Each entry records a compact digest of the request sent to the node, of the node's response and
+/// of the changes applied to the store. Comparing the journals of two clients that track the same
+/// accounts with [diff_sync_journals] shows the first sync iteration in which they diverged.
+///
+/// Once the journal holds `capacity` entries, the oldest entry is dropped for every new one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncJournal {
+    capacity: usize,
+    /// Iteration number of the next entry.
+    next_iteration: u64,
+    entries: VecDeque<SyncJournalEntry>,
+}
+
+impl SyncJournal {
+    /// Returns a new, empty [SyncJournal] that retains up to `capacity` entries. Capacities lower
+    /// than 1 are treated as 1.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = max(capacity, 1);
+        Self {
+            capacity,
+            next_iteration: 0,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of entries retained by the journal.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the retained entries, from the oldest to the most recent.
+    pub fn entries(&self) -> impl Iterator<Item = &SyncJournalEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the entry of the specified iteration, if it's retained by the journal.
+    pub fn get(&self, iteration: u64) -> Option<&SyncJournalEntry> {
+        let index = iteration.checked_sub(self.first_iteration())?;
+        self.entries.get(usize::try_from(index).ok()?)
+    }
+
+    /// Returns the iteration number of the oldest retained entry.
+    fn first_iteration(&self) -> u64 {
+        self.next_iteration - self.entries.len() as u64
+    }
+
+    /// Appends a new entry, dropping the oldest one if the journal is full.
+    pub(crate) fn record(
+        &mut self,
+        request: SyncRequestDigest,
+        response: SyncResponseDigest,
+        mutations: SyncMutationCounts,
+    ) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(SyncJournalEntry {
+            iteration: self.next_iteration,
+            request,
+            response,
+            mutations,
+        });
+        self.next_iteration += 1;
+    }
+}
+
+impl Serializable for SyncJournal {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.capacity);
+        self.next_iteration.write_into(target);
+        target.write_usize(self.entries.len());
+        for entry in self.entries.iter() {
+            entry.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for SyncJournal {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let capacity = source.read_usize()?;
+        let next_iteration = u64::read_from(source)?;
+        let num_entries = source.read_usize()?;
+        if num_entries > capacity || num_entries as u64 > next_iteration {
+            return Err(DeserializationError::InvalidValue(
+                "sync journal has more entries than iterations or capacity".into(),
+            ));
+        }
+
+        let entries = source.read_many::<SyncJournalEntry>(num_entries)?.into();
+
+        Ok(Self { capacity, next_iteration, entries })
+    }
+}
+
+// SYNC JOURNAL ENTRY
+// ================================================================================================
+
+/// Record of a single sync iteration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncJournalEntry {
+    /// Number of the iteration, counting from the moment the journal was enabled.
+    pub iteration: u64,
+    pub request: SyncRequestDigest,
+    pub response: SyncResponseDigest,
+    pub mutations: SyncMutationCounts,
+}
+
+/// Parameters of a `SyncState` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncRequestDigest {
+    /// Block number from which the client requested the sync.
+    pub block_num: u32,
+    /// Hash of the IDs of the accounts tracked by the client.
+    pub account_ids_hash: Digest,
+    /// Hash of the note tags tracked by the client.
+    pub note_tags_hash: Digest,
+}
+
+impl SyncRequestDigest {
+    pub(crate) fn new(block_num: u32, account_ids: &[AccountId], note_tags: &[NoteTag]) -> Self {
+        let mut account_ids: Vec<Felt> = account_ids.iter().map(|id| Felt::from(*id)).collect();
+        account_ids.sort_by_key(|id| id.as_int());
+        let mut note_tags: Vec<Felt> =
+            note_tags.iter().map(|tag| Felt::from(u32::from(*tag))).collect();
+        note_tags.sort_by_key(|tag| tag.as_int());
+
+        Self {
+            block_num,
+            account_ids_hash: Rpo256::hash_elements(&account_ids),
+            note_tags_hash: Rpo256::hash_elements(&note_tags),
+        }
+    }
+}
+
+/// Summary of a `SyncState` response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncResponseDigest {
+    /// Number of the block returned by the node.
+    pub block_num: u32,
+    /// Commitment of the block header returned by the node.
+    pub block_header_commitment: Digest,
+    pub note_inclusion_count: u32,
+    pub account_update_count: u32,
+    pub nullifier_count: u32,
+}
+
+impl From<&StateSyncInfo> for SyncResponseDigest {
+    fn from(response: &StateSyncInfo) -> Self {
+        Self {
+            block_num: response.block_header.block_num(),
+            block_header_commitment: response.block_header.hash(),
+            note_inclusion_count: response.note_inclusions.len() as u32,
+            account_update_count: response.account_hash_updates.len() as u32,
+            nullifier_count: response.nullifiers.len() as u32,
+        }
+    }
+}
+
+/// Number of records changed in the store by a sync iteration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncMutationCounts {
+    pub received_notes: u32,
+    pub committed_notes: u32,
+    pub consumed_notes: u32,
+    pub updated_accounts: u32,
+    pub committed_transactions: u32,
+}
+
+impl From<&SyncSummary> for SyncMutationCounts {
+    fn from(summary: &SyncSummary) -> Self {
+        Self {
+            received_notes: summary.received_notes.len() as u32,
+            committed_notes: summary.committed_notes.len() as u32,
+            consumed_notes: summary.consumed_notes.len() as u32,
+            updated_accounts: summary.updated_accounts.len() as u32,
+            committed_transactions: summary.committed_transactions.len() as u32,
+        }
+    }
+}
+
+impl Serializable for SyncJournalEntry {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.iteration.write_into(target);
+
+        self.request.block_num.write_into(target);
+        self.request.account_ids_hash.write_into(target);
+        self.request.note_tags_hash.write_into(target);
+
+        self.response.block_num.write_into(target);
+        self.response.block_header_commitment.write_into(target);
+        self.response.note_inclusion_count.write_into(target);
+        self.response.account_update_count.write_into(target);
+        self.response.nullifier_count.write_into(target);
+
+        self.mutations.received_notes.write_into(target);
+        self.mutations.committed_notes.write_into(target);
+        self.mutations.consumed_notes.write_into(target);
+        self.mutations.updated_accounts.write_into(target);
+        self.mutations.committed_transactions.write_into(target);
+    }
+}
+
+impl Deserializable for SyncJournalEntry {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let iteration = u64::read_from(source)?;
+
+        let request = SyncRequestDigest {
+            block_num: u32::read_from(source)?,
+            account_ids_hash: Digest::read_from(source)?,
+            note_tags_hash: Digest::read_from(source)?,
+        };
+
+        let response = SyncResponseDigest {
+            block_num: u32::read_from(source)?,
+            block_header_commitment: Digest::read_from(source)?,
+            note_inclusion_count: u32::read_from(source)?,
+            account_update_count: u32::read_from(source)?,
+            nullifier_count: u32::read_from(source)?,
+        };
+
+        let mutations = SyncMutationCounts {
+            received_notes: u32::read_from(source)?,
+            committed_notes: u32::read_from(source)?,
+            consumed_notes: u32::read_from(source)?,
+            updated_accounts: u32::read_from(source)?,
+            committed_transactions: u32::read_from(source)?,
+        };
+
+        Ok(Self { iteration, request, response, mutations })
+    }
+}
+
+// JOURNAL COMPARISON
+// ================================================================================================
+
+/// First iteration in which two sync journals differ, along with the entry of each journal for
+/// that iteration. An entry is `None` if its journal doesn't have that iteration yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncJournalDivergence {
+    pub iteration: u64,
+    pub left: Option<SyncJournalEntry>,
+    pub right: Option<SyncJournalEntry>,
+}
+
+/// Compares two sync journals and returns the first iteration in which they differ, or `None` if
+/// they match.
+///
+/// Only the iterations retained by both journals can be compared, so iterations dropped from
+/// either journal are skipped. A journal with fewer iterations than the other diverges at its
+/// first missing iteration.
+pub fn diff_sync_journals(
+    left: &SyncJournal,
+    right: &SyncJournal,
+) -> Option<SyncJournalDivergence> {
+    let first_iteration = max(left.first_iteration(), right.first_iteration());
+    let last_iteration = max(left.next_iteration, right.next_iteration);
+
+    (first_iteration..last_iteration).find_map(|iteration| {
+        let left_entry = left.get(iteration);
+        let right_entry = right.get(iteration);
+
+        (left_entry != right_entry).then(|| SyncJournalDivergence {
+            iteration,
+            left: left_entry.cloned(),
+            right: right_entry.cloned(),
+        })
+    })
+}
+
+impl<R: FeltRng> Client<R> {
+    // SYNC JOURNAL
+    // --------------------------------------------------------------------------------------------
+
+    /// Enables the sync journal, which records up to `capacity` of the most recent sync
+    /// iterations. Enabling it again discards the recorded entries.
+    pub fn with_sync_journal(mut self, capacity: usize) -> Self {
+        self.sync_journal = Some(SyncJournal::new(capacity));
+        self
+    }
+
+    /// Returns a copy of the sync journal, or `None` if it's not enabled. The journal can be
+    /// serialized to be compared with the journal of another client.
+    pub fn export_sync_journal(&self) -> Option<SyncJournal> {
+        self.sync_journal.clone()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::Digest;
+    use miden_tx::utils::{Deserializable, Serializable};
+
+    use super::{
+        diff_sync_journals, SyncJournal, SyncMutationCounts, SyncRequestDigest, SyncResponseDigest,
+    };
+
+    fn record_iteration(journal: &mut SyncJournal, block_num: u32, nullifier_count: u32) {
+        journal.record(
+            SyncRequestDigest::new(block_num, &[], &[]),
+            SyncResponseDigest {
+                block_num: block_num + 1,
+                block_header_commitment: Digest::default(),
+                note_inclusion_count: 0,
+                account_update_count: 0,
+                nullifier_count,
+            },
+            SyncMutationCounts::default(),
+        );
+    }
+
+    #[test]
+    fn test_sync_journal_is_bounded() {
+        let mut journal = SyncJournal::new(3);
+        for block_num in 0..5 {
+            record_iteration(&mut journal, block_num, 0);
+        }
+
+        let iterations: Vec<u64> = journal.entries().map(|entry| entry.iteration).collect();
+        assert_eq!(iterations, vec![2, 3, 4]);
+        assert!(journal.get(1).is_none());
+        assert_eq!(journal.get(4).unwrap().request.block_num, 4);
+
+        let serialized = journal.to_bytes();
+        assert_eq!(SyncJournal::read_from_bytes(&serialized).unwrap(), journal);
+    }
+
+    #[test]
+    fn test_diff_sync_journals_skips_dropped_iterations() {
+        let mut left = SyncJournal::new(2);
+        let mut right = SyncJournal::new(5);
+        for block_num in 0..4 {
+            record_iteration(&mut left, block_num, 0);
+            // The iteration dropped from the left journal is not compared
+            record_iteration(&mut right, block_num, u32::from(block_num == 0));
+        }
+        assert_eq!(diff_sync_journals(&left, &right), None);
+
+        record_iteration(&mut left, 4, 0);
+        let divergence = diff_sync_journals(&left, &right).unwrap();
+        assert_eq!(divergence.iteration, 4);
+        assert!(divergence.left.is_some());
+        assert!(divergence.right.is_none());
+    }
+}
@@ -1,38 +1,72 @@
 //! Provides the client APIs for synchronizing the client's local state with the Miden
 //! rollup network. It ensures that the client maintains a valid, up-to-date view of the chain.
 
-use alloc::{collections::BTreeMap, vec::Vec};
-use core::cmp::max;
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::cmp::{max, min};
 
 use crypto::merkle::{InOrderIndex, MmrPeaks};
 use miden_objects::{
     accounts::{Account, AccountHeader, AccountId},
     crypto::{self, rand::FeltRng},
-    notes::{NoteId, NoteInclusionProof, NoteTag, Nullifier},
+    notes::{NoteId, NoteInclusionProof, NoteMetadata, NoteTag, Nullifier},
     transaction::TransactionId,
     BlockHeader, Digest,
 };
-use tracing::info;
+use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use tracing::{debug, info, warn};
 
 use crate::{
-    notes::NoteUpdates,
+    accounts::PrivateAccountMismatchPolicy,
+    notes::{ExternalNotesSummary, FutureNoteResolution, NoteUpdates},
     rpc::{
-        AccountDetails, CommittedNote, NoteDetails, NullifierUpdate, RpcError, TransactionUpdate,
+        traffic_since, AccountDetails, CommittedNote, NodeRpcClientEndpoint, NoteDetails,
+        NullifierUpdate, RpcError, RpcTraffic, TransactionUpdate,
     },
     store::{
-        input_note_states::CommittedNoteState, InputNoteRecord, NoteFilter, OutputNoteRecord,
-        StoreError, TransactionFilter,
+        input_note_states::{CommittedNoteState, ExpectedNoteState},
+        InputNoteRecord, InputNoteState, NoteFilter, OutputNoteRecord, StoreError,
+        TransactionFilter,
     },
+    time::Stopwatch,
+    transactions::{TransactionRecord, TransactionStatus},
     Client, ClientError,
 };
 
 mod block_headers;
 use block_headers::apply_mmr_changes;
 
+mod estimate;
+pub(crate) use estimate::SyncRateTracker;
+pub use estimate::{BlocksPerSecond, SyncEstimate};
+
+mod journal;
+pub use journal::{
+    diff_sync_journals, SyncJournal, SyncJournalDivergence, SyncJournalEntry, SyncMutationCounts,
+    SyncRequestDigest, SyncResponseDigest,
+};
+
+mod note_sync;
+pub use note_sync::NoteSyncSummary;
+
+mod reset;
+pub use reset::ChainStateReset;
+
+mod storage_watches;
+pub(crate) use storage_watches::StorageChangeCallback;
+pub use storage_watches::{StorageSlotChange, StorageSlotWatch};
+
+mod stream;
+
 mod tags;
-pub use tags::{NoteTagRecord, NoteTagSource};
+pub use tags::{NoteTagCorrection, NoteTagRecord, NoteTagSource, TagCollision};
 
 /// Contains stats about the sync operation.
+#[derive(Debug, Clone, PartialEq)]
 pub struct SyncSummary {
     /// Block number up to which the client has been synced.
     pub block_num: u32,
@@ -46,6 +80,28 @@ pub struct SyncSummary {
     pub updated_accounts: Vec<AccountId>,
     /// IDs of committed transactions
     pub committed_transactions: Vec<TransactionId>,
+    /// Number of committed notes screened for each tag. Tags shared by several sources, as
+    /// reported by [Client::get_tag_collisions], can explain large counts.
+    pub screened_notes_per_tag: BTreeMap<NoteTag, usize>,
+    /// Updates reported by the node that were skipped because they couldn't be applied, like
+    /// committed transactions whose account doesn't match the local record.
+    pub warnings: Vec<String>,
+    /// Changes of the storage values watched with
+    /// [Client::watch_storage_slot](crate::Client::watch_storage_slot).
+    pub storage_changes: Vec<StorageSlotChange>,
+    /// Expected notes, like swap paybacks, that were promoted or cancelled because the notes
+    /// whose consumption creates them were consumed.
+    pub future_note_resolutions: Vec<FutureNoteResolution>,
+    /// IDs of the notes imported or updated from the sources registered with
+    /// [Client::add_note_source].
+    pub imported_external_notes: Vec<NoteId>,
+    /// Expected notes that were committed with another tag than the one they were tracked with.
+    /// The stale tags stop being tracked.
+    pub tag_corrections: Vec<NoteTagCorrection>,
+    /// Bytes exchanged with the node by each RPC method during the sync, like the state sync
+    /// requests, the nullifier checks and the note detail fetches. Empty if the client's RPC
+    /// implementation doesn't measure its calls.
+    pub rpc_traffic: BTreeMap<NodeRpcClientEndpoint, RpcTraffic>,
 }
 
 impl SyncSummary {
@@ -64,9 +120,71 @@ impl SyncSummary {
             consumed_notes,
             updated_accounts,
             committed_transactions,
+            screened_notes_per_tag: BTreeMap::new(),
+            warnings: vec![],
+            storage_changes: vec![],
+            future_note_resolutions: vec![],
+            imported_external_notes: vec![],
+            tag_corrections: vec![],
+            rpc_traffic: BTreeMap::new(),
         }
     }
 
+    /// Returns the summary with the provided number of notes screened for each tag.
+    pub fn with_screened_notes_per_tag(
+        mut self,
+        screened_notes_per_tag: BTreeMap<NoteTag, usize>,
+    ) -> Self {
+        self.screened_notes_per_tag = screened_notes_per_tag;
+        self
+    }
+
+    /// Returns the summary with the provided warnings about skipped updates.
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Returns the summary with the provided changes of watched storage values.
+    pub fn with_storage_changes(mut self, storage_changes: Vec<StorageSlotChange>) -> Self {
+        self.storage_changes = storage_changes;
+        self
+    }
+
+    /// Returns the summary with the provided resolutions of expected future notes.
+    pub fn with_future_note_resolutions(
+        mut self,
+        future_note_resolutions: Vec<FutureNoteResolution>,
+    ) -> Self {
+        self.future_note_resolutions = future_note_resolutions;
+        self
+    }
+
+    /// Returns the summary with the provided corrections of the tags of committed notes.
+    pub fn with_tag_corrections(mut self, tag_corrections: Vec<NoteTagCorrection>) -> Self {
+        self.tag_corrections = tag_corrections;
+        self
+    }
+
+    /// Returns the summary with the provided bytes exchanged with the node by each RPC method.
+    pub fn with_rpc_traffic(
+        mut self,
+        rpc_traffic: BTreeMap<NodeRpcClientEndpoint, RpcTraffic>,
+    ) -> Self {
+        self.rpc_traffic = rpc_traffic;
+        self
+    }
+
+    /// Returns the bytes exchanged with the node by all the RPC methods during the sync.
+    pub fn total_rpc_traffic(&self) -> RpcTraffic {
+        let mut total = RpcTraffic::default();
+        for traffic in self.rpc_traffic.values() {
+            total.add(*traffic);
+        }
+
+        total
+    }
+
     pub fn new_empty(block_num: u32) -> Self {
         Self {
             block_num,
@@ -75,6 +193,13 @@ impl SyncSummary {
             consumed_notes: vec![],
             updated_accounts: vec![],
             committed_transactions: vec![],
+            screened_notes_per_tag: BTreeMap::new(),
+            warnings: vec![],
+            storage_changes: vec![],
+            future_note_resolutions: vec![],
+            imported_external_notes: vec![],
+            tag_corrections: vec![],
+            rpc_traffic: BTreeMap::new(),
         }
     }
 
@@ -83,6 +208,7 @@ impl SyncSummary {
             && self.committed_notes.is_empty()
             && self.consumed_notes.is_empty()
             && self.updated_accounts.is_empty()
+            && self.imported_external_notes.is_empty()
     }
 
     pub fn combine_with(&mut self, mut other: Self) {
@@ -91,15 +217,129 @@ impl SyncSummary {
         self.committed_notes.append(&mut other.committed_notes);
         self.consumed_notes.append(&mut other.consumed_notes);
         self.updated_accounts.append(&mut other.updated_accounts);
+        self.committed_transactions.append(&mut other.committed_transactions);
+        for (tag, screened_notes) in other.screened_notes_per_tag {
+            *self.screened_notes_per_tag.entry(tag).or_default() += screened_notes;
+        }
+        self.warnings.append(&mut other.warnings);
+        self.storage_changes.append(&mut other.storage_changes);
+        self.future_note_resolutions.append(&mut other.future_note_resolutions);
+        self.imported_external_notes.append(&mut other.imported_external_notes);
+        self.tag_corrections.append(&mut other.tag_corrections);
+        for (method, traffic) in other.rpc_traffic {
+            self.rpc_traffic.entry(method).or_default().add(traffic);
+        }
+    }
+}
+
+impl Serializable for SyncSummary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_num.write_into(target);
+        self.received_notes.write_into(target);
+        self.committed_notes.write_into(target);
+        self.consumed_notes.write_into(target);
+        self.updated_accounts.write_into(target);
+        self.committed_transactions.write_into(target);
+        target.write_usize(self.screened_notes_per_tag.len());
+        for (tag, screened_notes) in self.screened_notes_per_tag.iter() {
+            tag.write_into(target);
+            target.write_usize(*screened_notes);
+        }
+        self.warnings.write_into(target);
+        self.storage_changes.write_into(target);
+        self.future_note_resolutions.write_into(target);
+        self.imported_external_notes.write_into(target);
+        self.tag_corrections.write_into(target);
+        target.write_usize(self.rpc_traffic.len());
+        for (method, traffic) in self.rpc_traffic.iter() {
+            method.write_into(target);
+            traffic.write_into(target);
+        }
     }
 }
 
+impl Deserializable for SyncSummary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let block_num = u32::read_from(source)?;
+        let received_notes = Vec::<NoteId>::read_from(source)?;
+        let committed_notes = Vec::<NoteId>::read_from(source)?;
+        let consumed_notes = Vec::<NoteId>::read_from(source)?;
+        let updated_accounts = Vec::<AccountId>::read_from(source)?;
+        let committed_transactions = Vec::<TransactionId>::read_from(source)?;
+        let num_tags = source.read_usize()?;
+        let mut screened_notes_per_tag = BTreeMap::new();
+        for _ in 0..num_tags {
+            let tag = NoteTag::read_from(source)?;
+            screened_notes_per_tag.insert(tag, source.read_usize()?);
+        }
+        let warnings = Vec::<String>::read_from(source)?;
+        let storage_changes = Vec::<StorageSlotChange>::read_from(source)?;
+        let future_note_resolutions = Vec::<FutureNoteResolution>::read_from(source)?;
+        let imported_external_notes = Vec::<NoteId>::read_from(source)?;
+        let tag_corrections = Vec::<NoteTagCorrection>::read_from(source)?;
+        let num_methods = source.read_usize()?;
+        let mut rpc_traffic = BTreeMap::new();
+        for _ in 0..num_methods {
+            let method = NodeRpcClientEndpoint::read_from(source)?;
+            rpc_traffic.insert(method, RpcTraffic::read_from(source)?);
+        }
+
+        Ok(Self {
+            block_num,
+            received_notes,
+            committed_notes,
+            consumed_notes,
+            updated_accounts,
+            committed_transactions,
+            screened_notes_per_tag,
+            warnings,
+            storage_changes,
+            future_note_resolutions,
+            imported_external_notes,
+            tag_corrections,
+            rpc_traffic,
+        })
+    }
+}
+
+/// Contains the records that changed in a sync operation, as described by its [SyncSummary].
+///
+/// The records are read from the store when the changes are requested, so they reflect the
+/// current state of each note, account and transaction, which may have been modified after the
+/// sync.
+#[derive(Debug)]
+pub struct SyncChanges {
+    /// Block number up to which the client was synced.
+    pub block_num: u32,
+    /// New input notes received in the sync.
+    pub received_notes: Vec<InputNoteRecord>,
+    /// Tracked input notes that received inclusion proofs.
+    pub committed_input_notes: Vec<InputNoteRecord>,
+    /// Tracked output notes that received inclusion proofs.
+    pub committed_output_notes: Vec<OutputNoteRecord>,
+    /// Input notes that were consumed.
+    pub consumed_input_notes: Vec<InputNoteRecord>,
+    /// Output notes that were consumed.
+    pub consumed_output_notes: Vec<OutputNoteRecord>,
+    /// On-chain accounts that were updated.
+    pub updated_accounts: Vec<Account>,
+    /// Transactions that were committed.
+    pub committed_transactions: Vec<TransactionRecord>,
+}
+
 enum SyncStatus {
     SyncedToLastBlock(SyncSummary),
     SyncedToBlock(SyncSummary),
 }
 
 impl SyncStatus {
+    pub fn sync_summary(&self) -> &SyncSummary {
+        match self {
+            SyncStatus::SyncedToLastBlock(summary) => summary,
+            SyncStatus::SyncedToBlock(summary) => summary,
+        }
+    }
+
     pub fn into_sync_summary(self) -> SyncSummary {
         match self {
             SyncStatus::SyncedToLastBlock(summary) => summary,
@@ -130,6 +370,107 @@ pub struct StateSyncUpdate {
     pub block_has_relevant_notes: bool,
     /// Tag records that are no longer relevant
     pub tags_to_remove: Vec<NoteTagRecord>,
+    /// Resolved future note links, which are removed along with the expected notes of the
+    /// cancelled ones.
+    pub future_note_resolutions: Vec<FutureNoteResolution>,
+}
+
+/// Tracks the notes of a block that have already been applied to the store when the block's notes
+/// are processed across several sync iterations.
+///
+/// The progress is persisted after each batch of notes, so a sync that is interrupted, even by
+/// restarting the client, continues with the block's remaining notes. It's removed once the block
+/// is applied. Notes applied after the last persisted progress are processed again, which is safe
+/// because applying the same committed note twice does not modify its record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialSyncProgress {
+    /// Number of the block whose notes are being processed.
+    block_num: u32,
+    /// Number of notes (ordered by their index in the block) that were already applied.
+    processed_notes: usize,
+    /// Whether any of the already applied notes was relevant to the client.
+    block_has_relevant_notes: bool,
+    /// Tag records of the already applied notes that are no longer relevant. They are removed
+    /// together with the block's final update.
+    tags_to_remove: Vec<NoteTagRecord>,
+}
+
+impl PartialSyncProgress {
+    fn new(block_num: u32) -> Self {
+        Self {
+            block_num,
+            processed_notes: 0,
+            block_has_relevant_notes: false,
+            tags_to_remove: vec![],
+        }
+    }
+
+    /// Returns the number of the block whose notes are being processed.
+    pub fn block_num(&self) -> u32 {
+        self.block_num
+    }
+
+    /// Returns the number of notes of the block that were already applied.
+    pub fn processed_notes(&self) -> usize {
+        self.processed_notes
+    }
+}
+
+impl Serializable for PartialSyncProgress {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_num.write_into(target);
+        target.write_usize(self.processed_notes);
+        target.write_u8(self.block_has_relevant_notes.into());
+        self.tags_to_remove.write_into(target);
+    }
+}
+
+impl Deserializable for PartialSyncProgress {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            block_num: u32::read_from(source)?,
+            processed_notes: source.read_usize()?,
+            block_has_relevant_notes: source.read_u8()? != 0,
+            tags_to_remove: Vec::<NoteTagRecord>::read_from(source)?,
+        })
+    }
+}
+
+// NOTE DETAIL FETCH POLICY
+// ================================================================================================
+
+/// Determines which untracked public notes received during a sync have their details fetched from
+/// the node. Set with [Client::set_note_detail_fetch_policy].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NoteDetailFetchPolicy {
+    /// Fetches the details of every public note received with a tracked tag.
+    #[default]
+    FetchAllMatching,
+    /// Fetches the details of a public note only if its metadata shows it's relevant to the
+    /// client: its tag is tracked on behalf of an account or an expected note, or it was sent by
+    /// a tracked account. Notes received only through tags added by the user are skipped.
+    FetchRelevantOnly,
+}
+
+impl NoteDetailFetchPolicy {
+    /// Returns whether the details of an untracked public note with the provided metadata are
+    /// fetched, given the tag records of its tag and the accounts the client tracks tags for.
+    fn fetches_note(
+        &self,
+        metadata: &NoteMetadata,
+        tag_records: Option<&[NoteTagRecord]>,
+        tracked_accounts: &BTreeSet<AccountId>,
+    ) -> bool {
+        match self {
+            NoteDetailFetchPolicy::FetchAllMatching => true,
+            NoteDetailFetchPolicy::FetchRelevantOnly => {
+                tracked_accounts.contains(&metadata.sender())
+                    || tag_records.is_some_and(|records| {
+                        records.iter().any(|record| record.source != NoteTagSource::User)
+                    })
+            },
+        }
+    }
 }
 
 // CONSTANTS
@@ -138,6 +479,16 @@ pub struct StateSyncUpdate {
 /// The number of bits to shift identifiers for in use of filters.
 pub(crate) const FILTER_ID_SHIFT: u8 = 48;
 
+/// Default maximum number of committed notes processed in a single sync iteration.
+pub const DEFAULT_MAX_NOTES_PER_SYNC_ITERATION: usize = 1024;
+
+/// Default maximum number of note IDs sent to the node in a single `GetNotesById` request.
+pub const DEFAULT_MAX_NOTES_PER_REQUEST: usize = 100;
+
+/// Default number of blocks the sync height has to advance before the MMR checkpoint is
+/// refreshed.
+pub const DEFAULT_MMR_CHECKPOINT_INTERVAL: u32 = 1;
+
 impl<R: FeltRng> Client<R> {
     // SYNC STATE
     // --------------------------------------------------------------------------------------------
@@ -147,28 +498,350 @@ impl<R: FeltRng> Client<R> {
         self.store.get_sync_height().await.map_err(|err| err.into())
     }
 
+    /// Sets the maximum number of committed notes that are processed and applied to the store in a
+    /// single sync iteration. Values lower than 1 are treated as 1.
+    ///
+    /// When the node returns more notes than this for a block, the notes are applied in batches
+    /// and the sync keeps requesting the same block until all of them have been processed. The
+    /// block header and MMR data are applied only once, together with the last batch.
+    ///
+    /// Defaults to [DEFAULT_MAX_NOTES_PER_SYNC_ITERATION].
+    pub fn set_max_notes_per_sync_iteration(&mut self, max_notes: usize) {
+        self.max_notes_per_sync_iteration = max(max_notes, 1);
+    }
+
+    /// Sets the maximum number of note IDs sent to the node in a single `GetNotesById` request.
+    /// Values lower than 1 are treated as 1.
+    ///
+    /// Larger lists of note IDs, like the ones built when a sync discovers many new public notes,
+    /// are split into batches of this size so that the node doesn't reject the request.
+    ///
+    /// Defaults to [DEFAULT_MAX_NOTES_PER_REQUEST].
+    pub fn set_max_notes_per_request(&mut self, max_notes: usize) {
+        self.max_notes_per_request = max(max_notes, 1);
+    }
+
+    /// Sets how many blocks the sync height has to advance past the last checkpoint of the
+    /// chain's partial MMR before [Client::sync_state] takes a new one. A value of 0 disables
+    /// checkpoints.
+    ///
+    /// The checkpoint lets the client load its partial MMR from a single record instead of
+    /// rebuilding it from every tracked authentication node, but it's only used while it matches
+    /// the current sync height. With the default interval the checkpoint is refreshed at the end
+    /// of every sync that advances the chain, so a restarted client always finds an up-to-date
+    /// one. Larger intervals write the checkpoint less often, at the cost of rebuilding the MMR
+    /// whenever the last sync didn't land on a checkpoint.
+    ///
+    /// Defaults to [DEFAULT_MMR_CHECKPOINT_INTERVAL].
+    pub fn set_mmr_checkpoint_interval(&mut self, blocks: u32) {
+        self.mmr_checkpoint_interval = blocks;
+    }
+
+    /// Prepares the chain data the next sync needs, so that it isn't loaded when the sync starts.
+    ///
+    /// If the store has no checkpoint of the partial MMR at the current sync height, the MMR is
+    /// rebuilt from the tracked authentication nodes and stored as one. Applications can call
+    /// this in the background after starting up, as the first sync of a client with a long
+    /// history otherwise spends most of its time loading every node. Does nothing if checkpoints
+    /// are disabled with [Client::set_mmr_checkpoint_interval].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store can't be read or written.
+    pub async fn warmup(&mut self) -> Result<(), ClientError> {
+        if self.mmr_checkpoint_interval == 0 {
+            return Ok(());
+        }
+
+        let current_block_num = self.store.get_sync_height().await?;
+        if let Some((checkpoint_block_num, _)) = self.store.load_mmr_checkpoint().await? {
+            if checkpoint_block_num == current_block_num {
+                return Ok(());
+            }
+        }
+
+        let partial_mmr = self.rebuild_partial_mmr(current_block_num).await?;
+        self.store.save_mmr_checkpoint(current_block_num, &partial_mmr).await?;
+
+        Ok(())
+    }
+
+    /// Sets which untracked public notes received during a sync have their details fetched from
+    /// the node.
+    ///
+    /// With [NoteDetailFetchPolicy::FetchAllMatching], every public note received with a tracked
+    /// tag is fetched and stored, so nothing the tags match is missed. With
+    /// [NoteDetailFetchPolicy::FetchRelevantOnly], notes are screened by their metadata first and
+    /// only the ones that can concern the client's accounts are fetched. This saves bandwidth and
+    /// reveals fewer note IDs to the node, but notes matched only by tags added with
+    /// [Client::add_note_tag], like the tags of swap offers, are no longer stored. Skipped notes
+    /// can still be imported later by ID.
+    ///
+    /// Defaults to [NoteDetailFetchPolicy::FetchAllMatching].
+    pub fn set_note_detail_fetch_policy(&mut self, policy: NoteDetailFetchPolicy) {
+        self.note_detail_fetch_policy = policy;
+    }
+
     /// Syncs the client's state with the current state of the Miden network.
     /// Before doing so, it ensures the genesis block exists in the local store.
     ///
     /// Returns the block number the client has been synced to.
+    ///
+    /// Once the chain is synced, the note sources registered with [Client::add_note_source] are
+    /// polled for new notes. Sources that fail are reported in [SyncSummary::warnings] instead of
+    /// failing the sync.
+    ///
+    /// # Errors
+    ///
+    /// If the sync fails after some blocks were already applied to the store, the error is
+    /// wrapped in a [ClientError::SyncIncomplete] with the block the store advanced to. The
+    /// applied blocks are kept and the next sync resumes from that block.
     pub async fn sync_state(&mut self) -> Result<SyncSummary, ClientError> {
+        self.sync_state_with_limit(None, None).await
+    }
+
+    /// Syncs the client's state like [Client::sync_state], but stops after `max_iterations` sync
+    /// requests even if the chain tip wasn't reached. Values lower than 1 are treated as 1.
+    ///
+    /// The blocks synced up to that point are applied as in a full sync, so the next call resumes
+    /// from the returned block. This lets applications sync a long gap in chunks, using
+    /// [Client::estimate_sync_work] to report the remaining work between them.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [Client::sync_state].
+    pub async fn sync_state_up_to(
+        &mut self,
+        max_iterations: u32,
+    ) -> Result<SyncSummary, ClientError> {
+        self.sync_state_with_limit(Some(max(max_iterations, 1)), None).await
+    }
+
+    /// Syncs the client's state like [Client::sync_state], but only requests the updates of the
+    /// specified accounts, which saves bandwidth and work on the node when the client tracks many
+    /// accounts but only some of them are of interest.
+    ///
+    /// Only the commitments of the other accounts are left out of the sync request: every tracked
+    /// note tag and nullifier is still requested, so notes and the chain MMR are updated as in a
+    /// full sync, but account states are only reconciled for the specified accounts.
+    ///
+    /// The client's sync height advances for all its state, so the blocks synced this way aren't
+    /// requested again by later syncs. As the accounts of the request filter both the account
+    /// commitments and the transactions reported by the node, the updates of the other accounts
+    /// in those blocks are missed for good. Public accounts catch up when they're next updated on
+    /// chain, while private accounts are reported as diverged at that point.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [ClientError::StoreError] if any of the accounts isn't tracked by the client.
+    /// - Returns [ClientError::PendingTransactionsExcludedFromSync] if an account with pending
+    ///   transactions isn't included, as their commitment would be missed.
+    /// - Fails in the same cases as [Client::sync_state].
+    pub async fn sync_account_state(
+        &mut self,
+        account_ids: &[AccountId],
+    ) -> Result<SyncSummary, ClientError> {
+        for account_id in account_ids {
+            self.store.get_account_header(*account_id).await?;
+        }
+        let account_ids: BTreeSet<AccountId> = account_ids.iter().copied().collect();
+
+        let mut pending_transactions: BTreeMap<AccountId, Vec<TransactionId>> = BTreeMap::new();
+        for transaction in self.store.get_transactions(TransactionFilter::Uncomitted).await? {
+            if transaction.transaction_status == TransactionStatus::Pending
+                && !account_ids.contains(&transaction.account_id)
+            {
+                pending_transactions
+                    .entry(transaction.account_id)
+                    .or_default()
+                    .push(transaction.id);
+            }
+        }
+        if let Some((account_id, transaction_ids)) = pending_transactions.pop_first() {
+            return Err(ClientError::PendingTransactionsExcludedFromSync {
+                account_id,
+                transaction_ids,
+            });
+        }
+
+        self.sync_state_with_limit(None, Some(&account_ids)).await
+    }
+
+    /// Syncs the client's state as described in [Client::sync_state_up_to], restricted to the
+    /// accounts in `account_filter` if it's set.
+    async fn sync_state_with_limit(
+        &mut self,
+        max_iterations: Option<u32>,
+        account_filter: Option<&BTreeSet<AccountId>>,
+    ) -> Result<SyncSummary, ClientError> {
         self.ensure_genesis_in_place().await?;
+        let initial_block_num = self.get_sync_height().await?;
+
+        match self.sync_to_chain_tip(max_iterations, account_filter).await {
+            Ok(summary) => Ok(summary),
+            Err(err) => match self.get_sync_height().await {
+                Ok(synced_to) if synced_to > initial_block_num => {
+                    Err(ClientError::SyncIncomplete { synced_to, source: Box::new(err) })
+                },
+                _ => Err(err),
+            },
+        }
+    }
+
+    /// Syncs the client's state until the chain tip is reached, or until `max_iterations` sync
+    /// requests were made if it's set, and finishes the sync by updating the client's MMR data
+    /// and the issuance of its faucets.
+    async fn sync_to_chain_tip(
+        &mut self,
+        max_iterations: Option<u32>,
+        account_filter: Option<&BTreeSet<AccountId>>,
+    ) -> Result<SyncSummary, ClientError> {
         let mut total_sync_summary = SyncSummary::new_empty(0);
+        let mut iterations = 0;
+        let mut previous_block_num = self.get_sync_height().await?;
         loop {
-            let response = self.sync_state_once().await?;
-            let is_last_block = matches!(response, SyncStatus::SyncedToLastBlock(_));
-            total_sync_summary.combine_with(response.into_sync_summary());
+            let (summary, is_last_block) =
+                self.sync_iteration(previous_block_num, account_filter).await?;
+            previous_block_num = summary.block_num;
+            total_sync_summary.combine_with(summary);
+            iterations += 1;
 
-            if is_last_block {
+            if is_last_block || max_iterations.is_some_and(|max| iterations >= max) {
                 break;
             }
         }
-        self.update_mmr_data().await?;
+        self.finish_sync(&mut total_sync_summary).await?;
 
         Ok(total_sync_summary)
     }
 
-    async fn sync_state_once(&mut self) -> Result<SyncStatus, ClientError> {
+    /// Runs a single sync request from `previous_block_num`, the client's sync height, and records
+    /// the sync rate. Returns the summary of the applied update and whether the chain tip was
+    /// reached.
+    async fn sync_iteration(
+        &mut self,
+        previous_block_num: u32,
+        account_filter: Option<&BTreeSet<AccountId>>,
+    ) -> Result<(SyncSummary, bool), ClientError> {
+        let stopwatch = Stopwatch::start_with_time_source(self.time_source.clone());
+        let initial_traffic = self.rpc_api.traffic();
+        let response = self.sync_state_once(account_filter).await?;
+        let is_last_block = matches!(response, SyncStatus::SyncedToLastBlock(_));
+        let summary = response
+            .into_sync_summary()
+            .with_rpc_traffic(traffic_since(&self.rpc_api.traffic(), &initial_traffic));
+        if let Some(elapsed) = stopwatch.elapsed() {
+            self.sync_rate
+                .record(summary.block_num.saturating_sub(previous_block_num), elapsed);
+        }
+
+        Ok((summary, is_last_block))
+    }
+
+    /// Finishes a sync by updating the client's MMR data and the issuance of its faucets, and
+    /// polling the external note sources. The notes imported from them, and the RPC traffic of
+    /// these steps, are added to `total_sync_summary`, which is then persisted as the summary of
+    /// the last sync.
+    async fn finish_sync(
+        &mut self,
+        total_sync_summary: &mut SyncSummary,
+    ) -> Result<ExternalNotesSummary, ClientError> {
+        let initial_traffic = self.rpc_api.traffic();
+        self.update_mmr_data().await?;
+        self.checkpoint_partial_mmr().await?;
+        self.check_faucets_issuance().await?;
+        self.remove_expired_note_tags(total_sync_summary.block_num).await?;
+
+        let external_notes = self.fetch_external_notes().await?;
+        total_sync_summary.imported_external_notes = external_notes.imported_notes.clone();
+        total_sync_summary.warnings.extend(external_notes.warnings.iter().cloned());
+        for (method, traffic) in traffic_since(&self.rpc_api.traffic(), &initial_traffic) {
+            total_sync_summary.rpc_traffic.entry(method).or_default().add(traffic);
+        }
+
+        self.store.set_last_sync_summary(total_sync_summary.clone()).await?;
+
+        Ok(external_notes)
+    }
+
+    /// Returns the records that changed in the most recent call to [Client::sync_state].
+    ///
+    /// The summary of the last sync is persisted in the store, so the changes are available until
+    /// the next sync, even across client instances. If the client was never synced, the returned
+    /// changes are empty.
+    pub async fn get_last_sync_changes(&self) -> Result<SyncChanges, ClientError> {
+        let summary = match self.store.get_last_sync_summary().await? {
+            Some(summary) => summary,
+            None => SyncSummary::new_empty(self.store.get_sync_height().await?),
+        };
+
+        let received_notes = self.get_input_notes_by_ids(summary.received_notes).await?;
+        let committed_input_notes =
+            self.get_input_notes_by_ids(summary.committed_notes.clone()).await?;
+        let committed_output_notes = self.get_output_notes_by_ids(summary.committed_notes).await?;
+        let consumed_input_notes =
+            self.get_input_notes_by_ids(summary.consumed_notes.clone()).await?;
+        let consumed_output_notes = self.get_output_notes_by_ids(summary.consumed_notes).await?;
+
+        let mut updated_accounts = Vec::with_capacity(summary.updated_accounts.len());
+        for account_id in summary.updated_accounts {
+            let (account, _) = self.store.get_account(account_id).await?;
+            updated_accounts.push(account);
+        }
+
+        let committed_transactions = if summary.committed_transactions.is_empty() {
+            vec![]
+        } else {
+            self.store
+                .get_transactions(TransactionFilter::All)
+                .await?
+                .into_iter()
+                .filter(|tx| summary.committed_transactions.contains(&tx.id))
+                .collect()
+        };
+
+        Ok(SyncChanges {
+            block_num: summary.block_num,
+            received_notes,
+            committed_input_notes,
+            committed_output_notes,
+            consumed_input_notes,
+            consumed_output_notes,
+            updated_accounts,
+            committed_transactions,
+        })
+    }
+
+    /// Returns the input notes with the specified IDs. IDs that don't match any input note are
+    /// ignored.
+    async fn get_input_notes_by_ids(
+        &self,
+        note_ids: Vec<NoteId>,
+    ) -> Result<Vec<InputNoteRecord>, ClientError> {
+        if note_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Ok(self.store.get_input_notes(NoteFilter::List(note_ids)).await?)
+    }
+
+    /// Returns the output notes with the specified IDs. IDs that don't match any output note are
+    /// ignored.
+    async fn get_output_notes_by_ids(
+        &self,
+        note_ids: Vec<NoteId>,
+    ) -> Result<Vec<OutputNoteRecord>, ClientError> {
+        if note_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Ok(self.store.get_output_notes(NoteFilter::List(note_ids)).await?)
+    }
+
+    async fn sync_state_once(
+        &mut self,
+        account_filter: Option<&BTreeSet<AccountId>>,
+    ) -> Result<SyncStatus, ClientError> {
         let current_block_num = self.store.get_sync_height().await?;
 
         let accounts: Vec<AccountHeader> = self
@@ -177,15 +850,18 @@ impl<R: FeltRng> Client<R> {
             .await?
             .into_iter()
             .map(|(acc_header, _)| acc_header)
+            .filter(|acc_header| account_filter.map_or(true, |ids| ids.contains(&acc_header.id())))
             .collect();
 
+        // All tags are requested even when accounts are filtered out, as the blocks synced now
+        // aren't requested again
         let note_tags: Vec<NoteTag> = self.get_unique_note_tags().await?.into_iter().collect();
 
         // To receive information about added nullifiers, we reduce them to the higher 16 bits
         // Note that besides filtering by nullifier prefixes, the node also filters by block number
         // (it only returns nullifiers from current_block_num until
         // response.block_header.block_num())
-        let nullifiers_tags: Vec<u16> = self
+        let mut nullifiers_tags: Vec<u16> = self
             .store
             .get_unspent_input_note_nullifiers()
             .await?
@@ -193,6 +869,12 @@ impl<R: FeltRng> Client<R> {
             .map(get_nullifier_prefix)
             .collect();
 
+        // The source notes of future note links are usually output notes only, whose nullifiers
+        // are needed to resolve the links
+        nullifiers_tags.extend(
+            self.get_future_note_source_nullifiers().await?.iter().map(get_nullifier_prefix),
+        );
+
         // Send request
         let account_ids: Vec<AccountId> = accounts.iter().map(|acc| acc.id()).collect();
         let response = self
@@ -200,25 +882,76 @@ impl<R: FeltRng> Client<R> {
             .sync_state(current_block_num, &account_ids, &note_tags, &nullifiers_tags)
             .await?;
 
+        // A node whose chain is behind the local sync height, like one that was reset, can't
+        // provide the data to continue from it
+        if response.chain_tip < current_block_num {
+            return Err(ClientError::ChainBehindLocalState {
+                local: current_block_num,
+                node: response.chain_tip,
+            });
+        }
+
+        let journal_digests = self.sync_journal.is_some().then(|| {
+            (
+                SyncRequestDigest::new(current_block_num, &account_ids, &note_tags),
+                SyncResponseDigest::from(&response),
+            )
+        });
+
         // We don't need to continue if the chain has not advanced, there are no new changes
         if response.block_header.block_num() == current_block_num {
-            return Ok(SyncStatus::SyncedToLastBlock(SyncSummary::new_empty(current_block_num)));
+            let status = SyncStatus::SyncedToLastBlock(SyncSummary::new_empty(current_block_num));
+            return Ok(self.record_sync_iteration(journal_digests, status));
         }
 
-        let (committed_note_updates, tags_to_remove) = self
-            .committed_note_updates(response.note_inclusions, &response.block_header)
-            .await?;
+        // Skip the notes of this block that were already applied in previous iterations
+        let mut progress = match self.store.get_partial_sync_progress().await? {
+            Some(progress) if progress.block_num == response.block_header.block_num() => progress,
+            _ => PartialSyncProgress::new(response.block_header.block_num()),
+        };
 
-        let incoming_block_has_relevant_notes =
-            self.check_block_relevance(&committed_note_updates).await?;
+        let mut note_inclusions = response.note_inclusions;
+        note_inclusions.sort_by_key(|note| note.note_index());
+        let mut pending_notes =
+            note_inclusions.split_off(min(progress.processed_notes, note_inclusions.len()));
+
+        if pending_notes.len() > self.max_notes_per_sync_iteration {
+            pending_notes.truncate(self.max_notes_per_sync_iteration);
+
+            let status = self
+                .apply_note_batch(
+                    pending_notes,
+                    &response.block_header,
+                    current_block_num,
+                    progress,
+                )
+                .await?;
+            return Ok(self.record_sync_iteration(journal_digests, status));
+        }
 
-        let transactions_to_commit = self.get_transactions_to_commit(response.transactions).await?;
+        let included_note_ids: Vec<NoteId> =
+            pending_notes.iter().map(|note| *note.note_id()).collect();
+        let (committed_note_updates, mut tags_to_remove, screened_notes_per_tag, tag_corrections) =
+            self.committed_note_updates(pending_notes, &response.block_header).await?;
+        tags_to_remove.append(&mut progress.tags_to_remove);
+
+        let incoming_block_has_relevant_notes = progress.block_has_relevant_notes
+            || self.check_block_relevance(&committed_note_updates).await?;
+
+        let (transactions_to_commit, warnings) =
+            self.get_transactions_to_commit(response.transactions, &account_ids).await?;
 
         let (consumed_note_updates, transactions_to_discard) =
             self.consumed_note_updates(response.nullifiers, &transactions_to_commit).await?;
 
         let note_updates = committed_note_updates.combine_with(consumed_note_updates);
 
+        let (future_note_resolutions, promoted_notes, mut cancelled_note_tags) =
+            self.future_note_resolutions(&note_updates, &transactions_to_discard).await?;
+        let note_updates =
+            note_updates.combine_with(NoteUpdates::new(vec![], vec![], promoted_notes, vec![]));
+        tags_to_remove.append(&mut cancelled_note_tags);
+
         let (onchain_accounts, offchain_accounts): (Vec<_>, Vec<_>) =
             accounts.into_iter().partition(|account_header| account_header.id().is_public());
 
@@ -229,6 +962,10 @@ impl<R: FeltRng> Client<R> {
         self.validate_local_account_hashes(&response.account_hash_updates, &offchain_accounts)
             .await?;
 
+        let storage_changes = self
+            .storage_slot_changes(&updated_onchain_accounts, response.block_header.block_num())
+            .await?;
+
         // Build PartialMmr with current data and apply updates
         let (new_peaks, new_authentication_nodes) = {
             let current_partial_mmr = self.build_current_partial_mmr(false).await?;
@@ -252,7 +989,12 @@ impl<R: FeltRng> Client<R> {
             note_updates.consumed_note_ids().into_iter().collect(),
             updated_onchain_accounts.iter().map(|acc| acc.id()).collect(),
             transactions_to_commit.iter().map(|tx| tx.transaction_id).collect(),
-        );
+        )
+        .with_screened_notes_per_tag(screened_notes_per_tag)
+        .with_warnings(warnings)
+        .with_storage_changes(storage_changes)
+        .with_future_note_resolutions(future_note_resolutions.clone())
+        .with_tag_corrections(tag_corrections);
 
         let state_sync_update = StateSyncUpdate {
             block_header: response.block_header,
@@ -264,6 +1006,7 @@ impl<R: FeltRng> Client<R> {
             block_has_relevant_notes: incoming_block_has_relevant_notes,
             transactions_to_discard,
             tags_to_remove,
+            future_note_resolutions,
         };
 
         // Apply received and computed updates to the store
@@ -271,24 +1014,102 @@ impl<R: FeltRng> Client<R> {
             .apply_state_sync(state_sync_update)
             .await
             .map_err(ClientError::StoreError)?;
+        self.notify_storage_changes(&sync_summary.storage_changes);
+
+        self.update_committed_pending_notes(&included_note_ids, response.block_header.block_num())
+            .await?;
+        self.resolve_pending_auth_keys().await?;
 
-        if response.chain_tip == response.block_header.block_num() {
-            Ok(SyncStatus::SyncedToLastBlock(sync_summary))
+        let status = if response.chain_tip == response.block_header.block_num() {
+            SyncStatus::SyncedToLastBlock(sync_summary)
         } else {
-            Ok(SyncStatus::SyncedToBlock(sync_summary))
-        }
+            SyncStatus::SyncedToBlock(sync_summary)
+        };
+
+        Ok(self.record_sync_iteration(journal_digests, status))
     }
 
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns the [NoteUpdates] containing new public note and committed input/output notes and a
-    /// list or note tag records to be removed from the store.
+    /// Appends the sync iteration to the sync journal, if it's enabled, and returns its status.
+    fn record_sync_iteration(
+        &mut self,
+        journal_digests: Option<(SyncRequestDigest, SyncResponseDigest)>,
+        status: SyncStatus,
+    ) -> SyncStatus {
+        if let (Some(journal), Some((request, response))) =
+            (self.sync_journal.as_mut(), journal_digests)
+        {
+            journal.record(request, response, status.sync_summary().into());
+        }
+
+        status
+    }
+
+    /// Processes a batch of the committed notes of a block and applies the resulting note updates
+    /// to the store, without applying the block itself. The sync height is not advanced, so the
+    /// next iteration requests the same block and continues with the remaining notes.
+    async fn apply_note_batch(
+        &mut self,
+        notes: Vec<CommittedNote>,
+        block_header: &BlockHeader,
+        current_block_num: u32,
+        mut progress: PartialSyncProgress,
+    ) -> Result<SyncStatus, ClientError> {
+        let batch_size = notes.len();
+        let included_note_ids: Vec<NoteId> = notes.iter().map(|note| *note.note_id()).collect();
+        let (note_updates, mut tags_to_remove, screened_notes_per_tag, tag_corrections) =
+            self.committed_note_updates(notes, block_header).await?;
+
+        if self.check_block_relevance(&note_updates).await? {
+            progress.block_has_relevant_notes = true;
+        }
+
+        let sync_summary = SyncSummary::new(
+            current_block_num,
+            note_updates.new_input_notes().iter().map(|n| n.id()).collect(),
+            note_updates.committed_note_ids().into_iter().collect(),
+            vec![],
+            vec![],
+            vec![],
+        )
+        .with_screened_notes_per_tag(screened_notes_per_tag)
+        .with_tag_corrections(tag_corrections);
+
+        progress.processed_notes += batch_size;
+        progress.tags_to_remove.append(&mut tags_to_remove);
+        let (processed_notes, block_num) = (progress.processed_notes, progress.block_num);
+        self.store.apply_note_batch(note_updates, progress).await?;
+        self.update_committed_pending_notes(&included_note_ids, block_header.block_num())
+            .await?;
+        info!(
+            "Applied {processed_notes} notes of block {block_num}, continuing with the remaining \
+             notes."
+        );
+
+        Ok(SyncStatus::SyncedToBlock(sync_summary))
+    }
+
+    /// Returns the [NoteUpdates] containing new public note and committed input/output notes, a
+    /// list or note tag records to be removed from the store, the number of committed notes
+    /// screened for each tag and the corrections of the tags of expected notes committed with
+    /// another tag.
+    ///
+    /// The committed notes are grouped by tag once and processed one group at a time.
     async fn committed_note_updates(
         &mut self,
         committed_notes: Vec<CommittedNote>,
         block_header: &BlockHeader,
-    ) -> Result<(NoteUpdates, Vec<NoteTagRecord>), ClientError> {
+    ) -> Result<
+        (
+            NoteUpdates,
+            Vec<NoteTagRecord>,
+            BTreeMap<NoteTag, usize>,
+            Vec<NoteTagCorrection>,
+        ),
+        ClientError,
+    > {
         // We'll only pick committed notes that we are tracking as input/output notes. Since the
         // sync response contains notes matching either the provided accounts or the provided tag
         // we might get many notes when we only care about a few of those.
@@ -311,12 +1132,32 @@ impl<R: FeltRng> Client<R> {
             .map(|n| (n.id(), n))
             .collect();
 
+        let mut tag_records: BTreeMap<NoteTag, Vec<NoteTagRecord>> = BTreeMap::new();
+        let mut tracked_accounts = BTreeSet::new();
+        for tag_record in self.store.get_note_tags().await? {
+            if let NoteTagSource::Account(account_id) = tag_record.source {
+                tracked_accounts.insert(account_id);
+            }
+            tag_records.entry(tag_record.tag).or_default().push(tag_record);
+        }
+
         let mut new_public_notes = vec![];
         let mut committed_tracked_input_notes = vec![];
         let mut committed_tracked_output_notes = vec![];
         let mut removed_tags = vec![];
+        let mut tag_corrections = vec![];
 
+        let mut notes_by_tag: BTreeMap<NoteTag, Vec<CommittedNote>> = BTreeMap::new();
         for committed_note in committed_notes {
+            notes_by_tag
+                .entry(committed_note.metadata().tag())
+                .or_default()
+                .push(committed_note);
+        }
+        let screened_notes_per_tag: BTreeMap<NoteTag, usize> =
+            notes_by_tag.iter().map(|(tag, notes)| (*tag, notes.len())).collect();
+
+        for committed_note in notes_by_tag.into_values().flatten() {
             let inclusion_proof = NoteInclusionProof::new(
                 block_header.block_num(),
                 committed_note.note_index(),
@@ -325,12 +1166,46 @@ impl<R: FeltRng> Client<R> {
 
             if let Some(mut note_record) = committed_input_notes.remove(committed_note.note_id()) {
                 // The note belongs to our locally tracked set of input notes
+                let expected_tag = NoteTagRecord::for_expected_note(&note_record);
 
                 let inclusion_proof_received = note_record
                     .inclusion_proof_received(inclusion_proof.clone(), committed_note.metadata())?;
-                let block_header_received = note_record.block_header_received(*block_header)?;
+                let block_header_received =
+                    note_record.chain_block_header_received(*block_header)?;
+
+                if note_record.is_rejected() {
+                    warn!(
+                        "Expected note {} was rejected: {}",
+                        note_record.id(),
+                        note_record.state()
+                    );
 
-                removed_tags.push((&note_record).try_into()?);
+                    // The note was expected with a tag that may differ from the committed one
+                    removed_tags.extend(expected_tag);
+                    removed_tags.push(NoteTagRecord::with_note_source(
+                        committed_note.metadata().tag(),
+                        note_record.id(),
+                    ));
+                } else {
+                    let committed_tag = committed_note.metadata().tag();
+                    if let Some(expected_tag) =
+                        expected_tag.filter(|expected_tag| expected_tag.tag != committed_tag)
+                    {
+                        info!(
+                            "Expected note {} was committed with tag {committed_tag} instead of {}",
+                            note_record.id(),
+                            expected_tag.tag
+                        );
+
+                        removed_tags.push(expected_tag);
+                        tag_corrections.push(NoteTagCorrection {
+                            note_id: note_record.id(),
+                            previous_tag: expected_tag.tag,
+                            corrected_tag: committed_tag,
+                        });
+                    }
+                    removed_tags.push((&note_record).try_into()?);
+                }
 
                 if inclusion_proof_received || block_header_received {
                     committed_tracked_input_notes.push(note_record);
@@ -340,7 +1215,10 @@ impl<R: FeltRng> Client<R> {
             if let Some(mut note_record) = committed_output_notes.remove(committed_note.note_id()) {
                 // The note belongs to our locally tracked set of output notes
 
-                if note_record.inclusion_proof_received(inclusion_proof.clone())? {
+                if note_record.inclusion_proof_received(
+                    inclusion_proof.clone(),
+                    block_header.timestamp().into(),
+                )? {
                     committed_tracked_output_notes.push(note_record);
                 }
             }
@@ -349,7 +1227,25 @@ impl<R: FeltRng> Client<R> {
                 && !committed_output_notes.contains_key(committed_note.note_id())
             {
                 // The note is public and we are not tracking it, push to the list of IDs to query
-                new_public_notes.push(*committed_note.note_id());
+                // unless no subscription to its tag keeps it or the fetch policy skips it
+                let note_tag_records =
+                    tag_records.get(&committed_note.metadata().tag()).map(Vec::as_slice);
+                let kept = note_tag_records.map_or(true, |records| {
+                    records.iter().any(|record| record.keeps_note(committed_note.metadata()))
+                });
+                let fetched = self.note_detail_fetch_policy.fetches_note(
+                    committed_note.metadata(),
+                    note_tag_records,
+                    &tracked_accounts,
+                );
+                if kept && fetched {
+                    new_public_notes.push(*committed_note.note_id());
+                } else if kept {
+                    debug!(
+                        "Skipping the details of note {}, which isn't relevant to the client.",
+                        committed_note.note_id()
+                    );
+                }
             }
         }
 
@@ -365,6 +1261,8 @@ impl<R: FeltRng> Client<R> {
                 committed_tracked_output_notes,
             ),
             removed_tags,
+            screened_notes_per_tag,
+            tag_corrections,
         ))
     }
 
@@ -471,6 +1369,121 @@ impl<R: FeltRng> Client<R> {
         ))
     }
 
+    /// Returns the nullifiers of the unconsumed source notes of the future note links.
+    async fn get_future_note_source_nullifiers(&self) -> Result<Vec<Nullifier>, ClientError> {
+        let source_note_ids: Vec<NoteId> = self
+            .store
+            .get_future_note_links()
+            .await?
+            .iter()
+            .map(|link| link.source_note_id())
+            .collect();
+
+        Ok(self
+            .get_output_notes_by_ids(source_note_ids)
+            .await?
+            .iter()
+            .filter(|note| !note.is_consumed())
+            .filter_map(|note| note.nullifier())
+            .collect())
+    }
+
+    /// Resolves the future note links whose source notes were consumed, as found in the provided
+    /// note updates. A link is cancelled if a local transaction of the account that created the
+    /// source note consumed it without creating the future note, and promoted otherwise.
+    ///
+    /// Returns the resolutions, the promoted expected notes, which are now expected after the
+    /// block before the consumption, and the tags of the cancelled expected notes. Expected notes
+    /// that were committed in the same sync are left as they are.
+    async fn future_note_resolutions(
+        &self,
+        note_updates: &NoteUpdates,
+        transactions_to_discard: &[TransactionId],
+    ) -> Result<(Vec<FutureNoteResolution>, Vec<InputNoteRecord>, Vec<NoteTagRecord>), ClientError>
+    {
+        let links = self.store.get_future_note_links().await?;
+        if links.is_empty() {
+            return Ok((vec![], vec![], vec![]));
+        }
+
+        let mut resolutions = vec![];
+        let mut promoted_notes = vec![];
+        let mut cancelled_note_tags = vec![];
+        for output_note in note_updates.updated_output_notes() {
+            let Some(link) = links.iter().find(|link| link.source_note_id() == output_note.id())
+            else {
+                continue;
+            };
+            let (Some(block_num), Some(nullifier)) =
+                (output_note.consumed_at_block(), output_note.nullifier())
+            else {
+                continue;
+            };
+
+            let consumed_by_creator = self
+                .store
+                .get_note_transactions(output_note.id(), Some(nullifier))
+                .await?
+                .iter()
+                .any(|transaction| {
+                    transaction.account_id == link.creator_account_id()
+                        && transaction.input_note_nullifiers.contains(&nullifier.inner())
+                        && !transaction
+                            .output_notes
+                            .iter()
+                            .any(|note| note.id() == link.future_note_id())
+                        && transaction.transaction_status != TransactionStatus::Discarded
+                        && !transactions_to_discard.contains(&transaction.id)
+                });
+
+            let future_note = self
+                .store
+                .get_input_notes(NoteFilter::List(vec![link.future_note_id()]))
+                .await?
+                .pop()
+                .filter(|note| {
+                    !note_updates
+                        .updated_input_notes()
+                        .iter()
+                        .chain(note_updates.new_input_notes())
+                        .any(|updated_note| updated_note.id() == note.id())
+                });
+            let expected_state = match future_note.as_ref().map(|note| note.state()) {
+                Some(InputNoteState::Expected(state)) => Some(state.clone()),
+                _ => None,
+            };
+
+            if consumed_by_creator {
+                if let Some(tag) = future_note.as_ref().and_then(NoteTagRecord::for_expected_note) {
+                    cancelled_note_tags.push(tag);
+                }
+                resolutions.push(FutureNoteResolution::Cancelled {
+                    source_note_id: link.source_note_id(),
+                    future_note_id: link.future_note_id(),
+                });
+            } else {
+                if let (Some(note), Some(state)) = (future_note, expected_state) {
+                    let state = ExpectedNoteState {
+                        after_block_num: block_num.saturating_sub(1),
+                        ..state
+                    };
+                    promoted_notes.push(InputNoteRecord::new(
+                        note.details().clone(),
+                        note.created_at(),
+                        state.into(),
+                    ));
+                }
+                resolutions.push(FutureNoteResolution::Promoted {
+                    source_note_id: link.source_note_id(),
+                    future_note_id: link.future_note_id(),
+                    block_num,
+                });
+            }
+        }
+
+        Ok((resolutions, promoted_notes, cancelled_note_tags))
+    }
+
     /// Queries the node for all received notes that are not being locally tracked in the client
     ///
     /// The client can receive metadata for private notes that it's not tracking. In this case,
@@ -485,7 +1498,7 @@ impl<R: FeltRng> Client<R> {
         }
         info!("Getting note details for notes that are not being tracked.");
 
-        let notes_data = self.rpc_api.get_notes_by_id(query_notes).await?;
+        let notes_data = self.get_notes_by_id_in_batches(query_notes).await?;
         let mut return_notes = Vec::with_capacity(query_notes.len());
         for note_data in notes_data {
             match note_data {
@@ -520,26 +1533,73 @@ impl<R: FeltRng> Client<R> {
         Ok(return_notes)
     }
 
+    /// Fetches the details of the specified notes from the node, splitting the IDs into batches
+    /// of at most `max_notes_per_request` IDs. The batches are requested one after the other, as
+    /// the RPC client can't be shared between concurrent requests.
+    pub(crate) async fn get_notes_by_id_in_batches(
+        &mut self,
+        note_ids: &[NoteId],
+    ) -> Result<Vec<NoteDetails>, RpcError> {
+        let mut notes_data = Vec::with_capacity(note_ids.len());
+        for batch in note_ids.chunks(self.max_notes_per_request) {
+            notes_data.extend(self.rpc_api.get_notes_by_id(batch).await?);
+        }
+
+        Ok(notes_data)
+    }
+
     /// Extracts information about transactions for uncommitted transactions that the client is
-    /// tracking from the received [SyncStateResponse]
+    /// tracking from the received [SyncStateResponse].
+    ///
+    /// Updates are matched with the local transaction records by ID. Updates for transactions
+    /// without a local record are ignored, since the node reports the transactions of tracked
+    /// accounts executed by other clients too. Updates whose account doesn't match the local
+    /// record, or whose account is not among the `tracked_account_ids`, are skipped and a
+    /// warning is returned for each of them, so that they don't fail the sync.
     async fn get_transactions_to_commit(
         &self,
-        mut transactions: Vec<TransactionUpdate>,
-    ) -> Result<Vec<TransactionUpdate>, ClientError> {
+        transactions: Vec<TransactionUpdate>,
+        tracked_account_ids: &[AccountId],
+    ) -> Result<(Vec<TransactionUpdate>, Vec<String>), ClientError> {
         // Get current uncommitted transactions
-        let uncommitted_transaction_ids = self
+        let uncommitted_transactions: BTreeMap<TransactionId, AccountId> = self
             .store
             .get_transactions(TransactionFilter::Uncomitted)
             .await?
             .into_iter()
-            .map(|tx| tx.id)
-            .collect::<Vec<_>>();
+            .map(|tx| (tx.id, tx.account_id))
+            .collect();
 
-        transactions.retain(|transaction_update| {
-            uncommitted_transaction_ids.contains(&transaction_update.transaction_id)
-        });
+        let mut transactions_to_commit = vec![];
+        let mut warnings = vec![];
+        for transaction_update in transactions {
+            let transaction_id = transaction_update.transaction_id;
+            let Some(account_id) = uncommitted_transactions.get(&transaction_id) else {
+                debug!("Ignoring update for transaction {transaction_id}, which is not tracked");
+                continue;
+            };
+
+            let warning = if *account_id != transaction_update.account_id {
+                format!(
+                    "Skipped commit of transaction {transaction_id}: the node reported it for \
+                     account {}, but it was executed against account {account_id}",
+                    transaction_update.account_id
+                )
+            } else if !tracked_account_ids.contains(account_id) {
+                format!(
+                    "Skipped commit of transaction {transaction_id}: its account {account_id} is \
+                     no longer tracked"
+                )
+            } else {
+                transactions_to_commit.push(transaction_update);
+                continue;
+            };
 
-        Ok(transactions)
+            debug!("{warning}");
+            warnings.push(warning);
+        }
+
+        Ok((transactions_to_commit, warnings))
     }
 
     async fn get_updated_onchain_accounts(
@@ -558,9 +1618,17 @@ impl<R: FeltRng> Client<R> {
                 info!("Public account hash difference detected for account with ID: {}. Fetching node for updates...", tracked_account.id());
                 let account_details = self.rpc_api.get_account_update(tracked_account.id()).await?;
                 if let AccountDetails::Public(account, _) = account_details {
-                    // We should only do the update if it's newer, otherwise we ignore it
+                    // We should only do the update if it's newer, otherwise we ignore it unless
+                    // the remote state is unknown, in which case the local state diverged
                     if account.nonce().as_int() > tracked_account.nonce().as_int() {
                         accounts_to_update.push(account);
+                    } else if self
+                        .store
+                        .get_account_header_by_hash(*remote_account_hash)
+                        .await?
+                        .is_none()
+                    {
+                        self.lock_account(*remote_account_id, *remote_account_hash).await?;
                     }
                 } else {
                     return Err(RpcError::AccountUpdateForPrivateAccountReceived(
@@ -573,7 +1641,8 @@ impl<R: FeltRng> Client<R> {
         Ok(accounts_to_update)
     }
 
-    /// Validates account hash updates and returns an error if there is a mismatch.
+    /// Validates account hash updates and applies the client's [PrivateAccountMismatchPolicy] to
+    /// the accounts whose hash doesn't match any known state.
     async fn validate_local_account_hashes(
         &mut self,
         account_updates: &[(AccountId, Digest)],
@@ -586,13 +1655,30 @@ impl<R: FeltRng> Client<R> {
                 .find(|acc| *remote_account_id == acc.id() && *remote_account_hash != acc.hash());
 
             // OffChain accounts should always have the latest known state. If we receive a stale
-            // update we ignore it.
+            // update we ignore it, while an unknown state means the account diverged and it gets
+            // locked until it's recovered.
             if mismatched_accounts.is_some() {
                 let account_by_hash =
                     self.store.get_account_header_by_hash(*remote_account_hash).await?;
 
                 if account_by_hash.is_none() {
-                    return Err(StoreError::AccountHashMismatch(*remote_account_id).into());
+                    match self.private_account_mismatch_policy {
+                        PrivateAccountMismatchPolicy::Lock => {
+                            self.lock_account(*remote_account_id, *remote_account_hash).await?;
+                        },
+                        PrivateAccountMismatchPolicy::WarnKeepLocal => {
+                            warn!(
+                                "Keeping the local state of account {} although the node reported an unknown commitment: {}",
+                                remote_account_id, remote_account_hash
+                            );
+                        },
+                        PrivateAccountMismatchPolicy::Error => {
+                            return Err(ClientError::PrivateAccountMismatch {
+                                account_id: *remote_account_id,
+                                remote_hash: *remote_account_hash,
+                            });
+                        },
+                    }
                 }
             }
         }
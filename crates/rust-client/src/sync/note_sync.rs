@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    crypto::rand::FeltRng,
+    notes::{NoteId, NoteInclusionProof, NoteTag},
+};
+use tracing::info;
+
+use super::NoteTagRecord;
+use crate::{
+    store::{input_note_states::ExpectedNoteState, InputNoteRecord, InputNoteState, NoteFilter},
+    Client, ClientError,
+};
+
+// NOTE SYNC SUMMARY
+// ================================================================================================
+
+/// Contains stats about the notes synced by [Client::sync_notes].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoteSyncSummary {
+    /// Block the notes were synced up to, which is the chain tip unless no note needed to be
+    /// requested, in which case it's the client's sync height.
+    pub block_num: u32,
+    /// Notes that received an inclusion proof in a block up to the client's sync height. The
+    /// block was authenticated and stored, so the notes are committed.
+    pub committed_notes: Vec<NoteId>,
+    /// Notes that received an inclusion proof in a block after the client's sync height. The next
+    /// [Client::sync_state] authenticates the block and commits them.
+    pub unverified_notes: Vec<NoteId>,
+    /// Requested notes that weren't looked up because they aren't expected or their tag is
+    /// unknown.
+    pub skipped_notes: Vec<NoteId>,
+}
+
+impl NoteSyncSummary {
+    /// Returns the IDs of the notes that received an inclusion proof.
+    pub fn notes_with_inclusion_proofs(&self) -> impl Iterator<Item = &NoteId> {
+        self.committed_notes.iter().chain(self.unverified_notes.iter())
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // NOTE SYNC
+    // --------------------------------------------------------------------------------------------
+
+    /// Looks up the specified expected notes on the node and stores the inclusion proofs of the
+    /// ones that were committed, without syncing accounts, transactions or nullifiers.
+    ///
+    /// The notes are requested by their tags, starting after the earliest block they're expected
+    /// after, until the chain tip. Notes committed up to the client's sync height are verified
+    /// against their block, which is authenticated and stored if it isn't already. Notes committed
+    /// after it are left unverified until the next [Client::sync_state], as the client's partial
+    /// MMR can't authenticate blocks past its sync height. Either way, their tags stop being
+    /// tracked.
+    ///
+    /// Notes that aren't expected, like already committed or consumed ones, and expected notes
+    /// without a tag are skipped and reported in [NoteSyncSummary::skipped_notes].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the notes isn't tracked by the client, if the node can't be
+    /// reached or if the store can't be updated.
+    pub async fn sync_notes(
+        &mut self,
+        note_ids: &[NoteId],
+    ) -> Result<NoteSyncSummary, ClientError> {
+        let sync_height = self.store.get_sync_height().await?;
+        let mut summary = NoteSyncSummary {
+            block_num: sync_height,
+            ..Default::default()
+        };
+        if note_ids.is_empty() {
+            return Ok(summary);
+        }
+
+        let mut pending_notes: Vec<(InputNoteRecord, NoteTag)> = vec![];
+        let mut request_block_num = u32::MAX;
+        for note in self.store.get_input_notes(NoteFilter::List(note_ids.to_vec())).await? {
+            match note.state() {
+                InputNoteState::Expected(ExpectedNoteState {
+                    tag: Some(tag),
+                    after_block_num,
+                    ..
+                }) => {
+                    request_block_num = request_block_num.min(*after_block_num);
+                    let tag = *tag;
+                    pending_notes.push((note, tag));
+                },
+                _ => summary.skipped_notes.push(note.id()),
+            }
+        }
+        if pending_notes.is_empty() {
+            return Ok(summary);
+        }
+
+        let mut tags: Vec<NoteTag> = vec![];
+        for (_, tag) in pending_notes.iter() {
+            if !tags.contains(tag) {
+                tags.push(*tag);
+            }
+        }
+
+        let mut found_notes = vec![];
+        loop {
+            let response = self.rpc_api.sync_notes(request_block_num, &tags).await?;
+            let block_num = response.block_header.block_num();
+
+            for committed_note in response.notes.iter() {
+                let Some(position) = pending_notes
+                    .iter()
+                    .position(|(note, _)| note.id() == *committed_note.note_id())
+                else {
+                    continue;
+                };
+
+                let (mut note, tag) = pending_notes.swap_remove(position);
+                let inclusion_proof = NoteInclusionProof::new(
+                    block_num,
+                    committed_note.note_index(),
+                    committed_note.merkle_path().clone(),
+                )?;
+                note.inclusion_proof_received(inclusion_proof, committed_note.metadata())?;
+                found_notes.push((note, tag));
+            }
+
+            summary.block_num = block_num;
+            if pending_notes.is_empty()
+                || block_num >= response.chain_tip
+                || block_num <= request_block_num
+            {
+                break;
+            }
+            request_block_num = block_num;
+        }
+
+        let mut current_partial_mmr = None;
+        let mut updated_notes = vec![];
+        for (mut note, tag) in found_notes {
+            let block_num = note
+                .inclusion_proof()
+                .expect("Notes with an inclusion proof received should have it")
+                .location()
+                .block_num();
+
+            if block_num <= sync_height {
+                if current_partial_mmr.is_none() {
+                    current_partial_mmr = Some(self.build_current_partial_mmr(true).await?);
+                }
+                let partial_mmr =
+                    current_partial_mmr.as_mut().expect("The partial MMR was just built");
+                let block_header =
+                    self.get_and_store_authenticated_block(block_num, partial_mmr).await?;
+                note.chain_block_header_received(block_header)?;
+            }
+
+            match note.state() {
+                InputNoteState::Committed(_) => summary.committed_notes.push(note.id()),
+                InputNoteState::Unverified(_) => summary.unverified_notes.push(note.id()),
+                _ => {},
+            }
+
+            self.store
+                .remove_note_tag(NoteTagRecord::with_note_source(tag, note.id()))
+                .await?;
+            updated_notes.push(note);
+        }
+
+        self.store.upsert_input_notes(&updated_notes).await?;
+
+        info!(
+            "Synced notes up to block {}: {} committed, {} unverified, {} not found",
+            summary.block_num,
+            summary.committed_notes.len(),
+            summary.unverified_notes.len(),
+            pending_notes.len()
+        );
+
+        Ok(summary)
+    }
+}
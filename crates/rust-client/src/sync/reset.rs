@@ -0,0 +1,157 @@
+use alloc::vec::Vec;
+
+use miden_objects::{crypto::rand::FeltRng, transaction::TransactionId};
+use tracing::info;
+
+use super::{NoteTagRecord, NoteTagSource};
+use crate::{
+    notes::NoteUpdates,
+    store::{
+        input_note_states::ExpectedNoteState, InputNoteRecord, InputNoteState, NoteFilter,
+        OutputNoteRecord, OutputNoteState, TransactionFilter,
+    },
+    transactions::TransactionStatus,
+    Client, ClientError,
+};
+
+// CHAIN STATE RESET
+// ================================================================================================
+
+/// Changes applied to the store by [Client::reset_chain_state], besides wiping the chain data and
+/// the sync height.
+pub struct ChainStateReset {
+    /// Notes reverted to their expected states.
+    pub note_updates: NoteUpdates,
+    /// Pending and committed transactions, which aren't part of the new chain.
+    pub transactions_to_discard: Vec<TransactionId>,
+    /// Whether the accounts and their keys are kept. Otherwise, they are removed along with their
+    /// transactions.
+    pub keep_accounts: bool,
+    /// Tags of the notes reverted to their expected states, which were removed when the notes
+    /// were committed.
+    pub tags_to_add: Vec<NoteTagRecord>,
+    /// Tags of the removed accounts.
+    pub tags_to_remove: Vec<NoteTagRecord>,
+}
+
+impl<R: FeltRng> Client<R> {
+    /// Wipes the data the client derived from the chain so that it can sync from the genesis of a
+    /// new chain, like the one of a node that was reset. This is the way out of a
+    /// [ClientError::ChainBehindLocalState].
+    ///
+    /// The block headers, the chain MMR, the sync height and the summary of the last sync are
+    /// removed. Every tracked note goes back to its expected state, dropping its inclusion proof
+    /// and the transaction that consumed it, and its tag is tracked again. The pending and
+    /// committed transactions are discarded. Account locks are cleared, as they refer to
+    /// commitments reported by the previous chain.
+    ///
+    /// If `keep_accounts` is `true`, the accounts keep their current states and keys. Otherwise,
+    /// they are removed along with their keys, transactions, tags and storage slot watches, and the
+    /// callbacks registered with [Client::watch_account_storage] are dropped.
+    pub async fn reset_chain_state(&mut self, keep_accounts: bool) -> Result<(), ClientError> {
+        let updated_input_notes: Vec<InputNoteRecord> = self
+            .store
+            .get_input_notes(NoteFilter::All)
+            .await?
+            .into_iter()
+            .filter_map(expected_input_note)
+            .collect();
+
+        let updated_output_notes: Vec<OutputNoteRecord> = self
+            .store
+            .get_output_notes(NoteFilter::All)
+            .await?
+            .into_iter()
+            .filter_map(expected_output_note)
+            .collect();
+
+        let transactions_to_discard = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|transaction| transaction.transaction_status != TransactionStatus::Discarded)
+            .map(|transaction| transaction.id)
+            .collect();
+
+        let tracked_tags = self.store.get_note_tags().await?;
+        let tags_to_add = updated_input_notes
+            .iter()
+            .filter_map(NoteTagRecord::for_expected_note)
+            .filter(|tag| !tracked_tags.contains(tag))
+            .collect();
+        let tags_to_remove = if keep_accounts {
+            vec![]
+        } else {
+            tracked_tags
+                .into_iter()
+                .filter(|tag| matches!(tag.source, NoteTagSource::Account(_)))
+                .collect()
+        };
+
+        info!(
+            "Resetting chain state: {} input notes and {} output notes reverted to expected",
+            updated_input_notes.len(),
+            updated_output_notes.len()
+        );
+
+        self.store
+            .reset_chain_state(ChainStateReset {
+                note_updates: NoteUpdates::new(
+                    vec![],
+                    vec![],
+                    updated_input_notes,
+                    updated_output_notes,
+                ),
+                transactions_to_discard,
+                keep_accounts,
+                tags_to_add,
+                tags_to_remove,
+            })
+            .await?;
+
+        if !keep_accounts {
+            self.storage_change_callbacks.clear();
+        }
+
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the note reverted to its expected state, or `None` if it's already expected to be
+/// committed from the genesis block.
+fn expected_input_note(note: InputNoteRecord) -> Option<InputNoteRecord> {
+    let metadata = note.metadata().copied();
+    let tag = match note.state() {
+        InputNoteState::Expected(ExpectedNoteState { after_block_num: 0, .. }) => return None,
+        InputNoteState::Expected(state) => state.tag,
+        _ => metadata.map(|metadata| metadata.tag()),
+    };
+    let state = ExpectedNoteState { metadata, after_block_num: 0, tag };
+
+    Some(InputNoteRecord::new(note.details().clone(), note.created_at(), state.into()))
+}
+
+/// Returns the note reverted to its expected state, or `None` if it's already expected to be
+/// committed from the genesis block.
+fn expected_output_note(note: OutputNoteRecord) -> Option<OutputNoteRecord> {
+    let state = match note.recipient() {
+        Some(recipient) => OutputNoteState::ExpectedFull { recipient: recipient.clone() },
+        None => OutputNoteState::ExpectedPartial,
+    };
+    if note.state() == &state && note.expected_height() == 0 {
+        return None;
+    }
+
+    Some(OutputNoteRecord::new(
+        note.recipient_digest(),
+        note.assets().clone(),
+        *note.metadata(),
+        state,
+        0,
+        note.created_at(),
+    ))
+}
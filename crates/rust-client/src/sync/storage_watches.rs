@@ -0,0 +1,263 @@
+use alloc::{boxed::Box, collections::BTreeMap, string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::{Account, AccountId, StorageSlot},
+    crypto::rand::FeltRng,
+    Word,
+};
+use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use tracing::{info, warn};
+
+use crate::{errors::ClientError, Client};
+
+impl<R: FeltRng> Client<R> {
+    /// Returns the storage slots watched by the client.
+    pub async fn get_storage_slot_watches(&self) -> Result<Vec<StorageSlotWatch>, ClientError> {
+        self.store.get_storage_slot_watches().await.map_err(|err| err.into())
+    }
+
+    /// Starts watching a storage slot of a tracked public account. For map slots, `key` selects
+    /// the entry of the map that is watched, and must be `None` for value slots.
+    ///
+    /// Whenever a sync updates the account and the watched value changes, a
+    /// [StorageSlotChange] is reported in the
+    /// [SyncSummary::storage_changes](crate::sync::SyncSummary::storage_changes) of the sync.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::InvalidStorageSlotWatch] if the account is private, if it doesn't
+    /// have a slot with the provided index, or if `key` doesn't match the type of the slot, and a
+    /// [ClientError::StoreError] if the account is not tracked by the client.
+    pub async fn watch_storage_slot(
+        &mut self,
+        account_id: AccountId,
+        slot_index: u8,
+        key: Option<Word>,
+    ) -> Result<(), ClientError> {
+        let watch = StorageSlotWatch { account_id, slot_index, key };
+        let invalid = |reason: &str| ClientError::InvalidStorageSlotWatch {
+            watch,
+            reason: reason.to_string(),
+        };
+
+        if !account_id.is_public() {
+            return Err(invalid("only public accounts are updated by the sync"));
+        }
+
+        let (account, _) = self.store.get_account(account_id).await?;
+        match (account.storage().slots().get(slot_index as usize), key) {
+            (None, _) => return Err(invalid("the account doesn't have such a slot")),
+            (Some(StorageSlot::Value(_)), Some(_)) => {
+                return Err(invalid("keys can only be watched in map slots"))
+            },
+            (Some(StorageSlot::Map(_)), None) => {
+                return Err(invalid("a key must be provided to watch a map slot"))
+            },
+            _ => {},
+        }
+
+        if !self.store.add_storage_slot_watch(watch).await? {
+            warn!("Storage slot {slot_index} of account {account_id} is already being watched");
+        }
+
+        Ok(())
+    }
+
+    /// Watches a value slot of a tracked public account like [Client::watch_storage_slot], and
+    /// registers a callback that is invoked with each [StorageSlotChange] of the slot, carrying
+    /// its old and new values, once the sync that received it is applied to the store.
+    ///
+    /// Several callbacks can be registered for the same slot. Unlike the watch, callbacks are not
+    /// persisted, so they have to be registered again when the client is rebuilt, and they are
+    /// dropped when the slot is unwatched.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [Client::watch_storage_slot], which include the slot being a map
+    /// slot.
+    pub async fn watch_account_storage(
+        &mut self,
+        account_id: AccountId,
+        slot_index: u8,
+        callback: impl FnMut(&StorageSlotChange) + Send + 'static,
+    ) -> Result<(), ClientError> {
+        self.watch_storage_slot(account_id, slot_index, None).await?;
+        self.storage_change_callbacks
+            .entry((account_id, slot_index))
+            .or_default()
+            .push(Box::new(callback));
+
+        Ok(())
+    }
+
+    /// Stops watching a storage slot, dropping the callbacks registered for it.
+    pub async fn unwatch_storage_slot(
+        &mut self,
+        account_id: AccountId,
+        slot_index: u8,
+        key: Option<Word>,
+    ) -> Result<(), ClientError> {
+        let watch = StorageSlotWatch { account_id, slot_index, key };
+        if key.is_none() {
+            self.storage_change_callbacks.remove(&(account_id, slot_index));
+        }
+        if self.store.remove_storage_slot_watch(watch).await? == 0 {
+            warn!("Storage slot {slot_index} of account {account_id} wasn't being watched");
+        }
+
+        Ok(())
+    }
+
+    /// Compares the watched storage slots of the tracked public accounts between their local
+    /// state and the updated state received in the sync, and returns the changes.
+    ///
+    /// Updated public accounts are received with their whole storage, so the values of watched
+    /// map keys are read from the new state without fetching them separately.
+    pub(super) async fn storage_slot_changes(
+        &self,
+        updated_accounts: &[Account],
+        block_num: u32,
+    ) -> Result<Vec<StorageSlotChange>, ClientError> {
+        let mut watches: BTreeMap<AccountId, Vec<StorageSlotWatch>> = BTreeMap::new();
+        for watch in self.store.get_storage_slot_watches().await? {
+            watches.entry(watch.account_id).or_default().push(watch);
+        }
+
+        let mut changes = vec![];
+        for new_state in updated_accounts {
+            let Some(account_watches) = watches.get(&new_state.id()) else {
+                continue;
+            };
+            let (old_state, _) = self.store.get_account(new_state.id()).await?;
+
+            for watch in account_watches {
+                let old = watch.read_value(&old_state)?;
+                let new = watch.read_value(new_state)?;
+                if old != new {
+                    changes.push(StorageSlotChange {
+                        account_id: watch.account_id,
+                        slot_index: watch.slot_index,
+                        key: watch.key,
+                        old,
+                        new,
+                        block_num,
+                    });
+                }
+            }
+        }
+
+        if !changes.is_empty() {
+            info!("{} watched storage values changed in block {block_num}", changes.len());
+        }
+
+        Ok(changes)
+    }
+
+    /// Invokes the callbacks registered with [Client::watch_account_storage] for the value slots
+    /// that changed.
+    pub(super) fn notify_storage_changes(&mut self, changes: &[StorageSlotChange]) {
+        for change in changes.iter().filter(|change| change.key.is_none()) {
+            let callbacks =
+                self.storage_change_callbacks.get_mut(&(change.account_id, change.slot_index));
+            for callback in callbacks.into_iter().flatten() {
+                callback(change);
+            }
+        }
+    }
+}
+
+/// Callback registered with [Client::watch_account_storage].
+pub(crate) type StorageChangeCallback = Box<dyn FnMut(&StorageSlotChange) + Send>;
+
+// STORAGE SLOT WATCH
+// ================================================================================================
+
+/// Storage slot of a public account watched by the client, as added with
+/// [Client::watch_storage_slot].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageSlotWatch {
+    pub account_id: AccountId,
+    pub slot_index: u8,
+    /// Watched key, for map slots.
+    pub key: Option<Word>,
+}
+
+impl StorageSlotWatch {
+    /// Returns the watched value in the provided state of the account.
+    fn read_value(&self, account: &Account) -> Result<Word, ClientError> {
+        let storage = account.storage();
+        let value = match self.key {
+            Some(key) => storage.get_map_item(self.slot_index, key)?,
+            None => storage.get_item(self.slot_index)?.into(),
+        };
+
+        Ok(value)
+    }
+}
+
+impl Serializable for StorageSlotWatch {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        target.write_u8(self.slot_index);
+        self.key.write_into(target);
+    }
+}
+
+impl Deserializable for StorageSlotWatch {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let slot_index = source.read_u8()?;
+        let key = Option::<Word>::read_from(source)?;
+
+        Ok(Self { account_id, slot_index, key })
+    }
+}
+
+// STORAGE SLOT CHANGE
+// ================================================================================================
+
+/// Change of a watched storage value detected by a sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageSlotChange {
+    pub account_id: AccountId,
+    pub slot_index: u8,
+    /// Watched key, for map slots.
+    pub key: Option<Word>,
+    /// Value before the sync.
+    pub old: Word,
+    /// Value after the sync.
+    pub new: Word,
+    /// Block the sync updated the account to.
+    pub block_num: u32,
+}
+
+impl Serializable for StorageSlotChange {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        target.write_u8(self.slot_index);
+        self.key.write_into(target);
+        self.old.write_into(target);
+        self.new.write_into(target);
+        self.block_num.write_into(target);
+    }
+}
+
+impl Deserializable for StorageSlotChange {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let slot_index = source.read_u8()?;
+        let key = Option::<Word>::read_from(source)?;
+        let old = Word::read_from(source)?;
+        let new = Word::read_from(source)?;
+        let block_num = u32::read_from(source)?;
+
+        Ok(Self {
+            account_id,
+            slot_index,
+            key,
+            old,
+            new,
+            block_num,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use futures::{stream, Stream};
+use miden_objects::crypto::rand::FeltRng;
+
+use super::SyncSummary;
+use crate::{Client, ClientError};
+
+// SYNC STREAM
+// ================================================================================================
+
+/// State of a sync driven by [Client::sync_state_stream].
+struct SyncStreamState<'a, R: FeltRng> {
+    client: &'a mut Client<R>,
+    /// Block the client was synced to by the previous iteration, or `None` before the first one.
+    previous_block_num: Option<u32>,
+    /// Summary of all the iterations so far, persisted as the summary of the last sync once the
+    /// chain tip is reached.
+    total_summary: SyncSummary,
+}
+
+impl<R: FeltRng> SyncStreamState<'_, R> {
+    /// Runs the next sync iteration, finishing the sync if it reaches the chain tip. Returns the
+    /// summary of the iteration and whether the chain tip was reached.
+    async fn next_summary(&mut self) -> Result<(SyncSummary, bool), ClientError> {
+        let previous_block_num = match self.previous_block_num {
+            Some(block_num) => block_num,
+            None => {
+                self.client.ensure_genesis_in_place().await?;
+                self.client.get_sync_height().await?
+            },
+        };
+
+        let (mut summary, is_last_block) =
+            self.client.sync_iteration(previous_block_num, None).await?;
+        self.previous_block_num = Some(summary.block_num);
+        self.total_summary.combine_with(summary.clone());
+
+        if is_last_block {
+            let external_notes = self.client.finish_sync(&mut self.total_summary).await?;
+            summary.imported_external_notes = external_notes.imported_notes;
+            summary.warnings.extend(external_notes.warnings);
+        }
+
+        Ok((summary, is_last_block))
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    /// Syncs the client's state like [Client::sync_state], yielding the summary of every sync
+    /// request as soon as its update is applied, instead of a single summary once the chain tip
+    /// is reached. The block number of each summary can be compared with the chain tip reported
+    /// by [Client::estimate_sync_work] to show the progress of a long sync.
+    ///
+    /// Every update is stored before its summary is yielded, so dropping the stream before it
+    /// ends leaves the client synced to the block of the last yielded summary, and the next sync
+    /// resumes from it. The sync is only finished, as in [Client::sync_state], when the chain tip
+    /// is reached, so the last summary also includes the notes imported from the external note
+    /// sources.
+    ///
+    /// The stream ends after the summary of the chain tip or after the first error, which is
+    /// yielded as is instead of being wrapped in a [ClientError::SyncIncomplete].
+    pub fn sync_state_stream(
+        &mut self,
+    ) -> impl Stream<Item = Result<SyncSummary, ClientError>> + '_ {
+        let state = SyncStreamState {
+            client: self,
+            previous_block_num: None,
+            total_summary: SyncSummary::new_empty(0),
+        };
+
+        stream::unfold(Some(state), |state| async move {
+            let mut state = state?;
+            match state.next_summary().await {
+                Ok((summary, false)) => Some((Ok(summary), Some(state))),
+                Ok((summary, true)) => Some((Ok(summary), None)),
+                Err(err) => Some((Err(err), None)),
+            }
+        })
+    }
+}
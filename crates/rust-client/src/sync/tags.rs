@@ -1,17 +1,24 @@
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
 
 use miden_objects::{
     accounts::{Account, AccountId},
     crypto::rand::FeltRng,
-    notes::{NoteExecutionMode, NoteId, NoteTag},
-    NoteError,
+    notes::{NoteExecutionMode, NoteId, NoteMetadata, NoteTag},
+    Felt, NoteError,
 };
 use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
-use tracing::warn;
+use tracing::{info, warn};
 
 use crate::{
     errors::ClientError,
-    store::{InputNoteRecord, NoteRecordError},
+    store::{
+        input_note_states::ExpectedNoteState, InputNoteRecord, InputNoteState, NoteFilter,
+        NoteRecordError,
+    },
     Client,
 };
 
@@ -37,7 +44,7 @@ impl<R: FeltRng> Client<R> {
     pub async fn add_note_tag(&mut self, tag: NoteTag) -> Result<(), ClientError> {
         match self
             .store
-            .add_note_tag(NoteTagRecord { tag, source: NoteTagSource::User })
+            .add_note_tag(NoteTagRecord::with_user_source(tag))
             .await
             .map_err(|err| err.into())
         {
@@ -50,26 +57,204 @@ impl<R: FeltRng> Client<R> {
         }
     }
 
+    /// Subscribes the client to a note tag, choosing which of the untracked public notes received
+    /// with the tag are stored:
+    ///
+    /// - With `keep_all`, every note is stored, as with [Client::add_note_tag].
+    /// - Otherwise, only the notes whose metadata `aux` matches `aux_filter` are stored. Without a
+    ///   filter, the tag is only used to receive updates of the notes the client already tracks.
+    ///
+    /// Subscribing to a tag that is already tracked by the user replaces its previous
+    /// subscription. Notes with the tag that are tracked on behalf of an account or an expected
+    /// note are stored regardless of the subscription.
+    pub async fn add_note_tag_subscription(
+        &mut self,
+        tag: NoteTag,
+        keep_all: bool,
+        aux_filter: Option<Felt>,
+    ) -> Result<(), ClientError> {
+        let tag_record =
+            NoteTagRecord::with_user_source(tag).with_subscription(keep_all, aux_filter);
+        self.store.replace_note_tags(vec![tag_record], vec![tag_record]).await?;
+
+        Ok(())
+    }
+
     /// Removes a note tag for the client to track.
     pub async fn remove_note_tag(&mut self, tag: NoteTag) -> Result<(), ClientError> {
-        if self
-            .store
-            .remove_note_tag(NoteTagRecord { tag, source: NoteTagSource::User })
-            .await?
-            == 0
-        {
+        if self.store.remove_note_tag(NoteTagRecord::with_user_source(tag)).await? == 0 {
             warn!("Tag {} wasn't being tracked", tag);
         }
 
         Ok(())
     }
+
+    /// Replaces the tag with which the expected note with the specified ID is tracked by
+    /// `new_tag`, so that a note imported with the wrong tag can be found by the next syncs.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the client doesn't track an input note with the specified ID.
+    /// - Returns [ClientError::NoteNotExpected] if the note is no longer expected, like when it was
+    ///   already committed.
+    /// - Returns [ClientError::NoteRecordError] if the metadata of the note is known and its tag
+    ///   differs from `new_tag`.
+    pub async fn update_note_tag(
+        &mut self,
+        note_id: NoteId,
+        new_tag: NoteTag,
+    ) -> Result<(), ClientError> {
+        let note = self.get_input_note(note_id).await?;
+        let InputNoteState::Expected(state) = note.state() else {
+            return Err(ClientError::NoteNotExpected(note_id));
+        };
+
+        if let Some(metadata) = state.metadata.filter(|metadata| metadata.tag() != new_tag) {
+            return Err(NoteRecordError::StateTransitionError(format!(
+                "The metadata of note {note_id} has tag {}, which can't be replaced by {new_tag}",
+                metadata.tag()
+            ))
+            .into());
+        }
+
+        let state = ExpectedNoteState { tag: Some(new_tag), ..state.clone() };
+        let old_tag_record = NoteTagRecord::for_expected_note(&note);
+        let note = InputNoteRecord::new(note.details().clone(), note.created_at(), state.into());
+        let new_tag_record = NoteTagRecord::for_expected_note(&note)
+            .expect("An expected note with a tag should have a tag record");
+
+        let removed: Vec<NoteTagRecord> = old_tag_record.into_iter().collect();
+        self.store.replace_note_tags(removed.clone(), vec![new_tag_record]).await?;
+
+        if let Err(err) = self.store.upsert_input_notes(&[note]).await {
+            // Keep tracking the note with its previous tag if the note couldn't be updated
+            self.store.replace_note_tags(vec![new_tag_record], removed).await?;
+            return Err(err.into());
+        }
+
+        info!("Note {note_id} is now tracked with tag {new_tag}");
+
+        Ok(())
+    }
+
+    /// Returns the tracked tags that are shared by more than one source, such as two accounts
+    /// whose IDs have the same prefix, along with their sources.
+    ///
+    /// Notes with a colliding tag are retrieved on behalf of every source of the tag, so the
+    /// report helps explain why a sync screens notes that are not meant for an account.
+    pub async fn get_tag_collisions(&self) -> Result<Vec<TagCollision>, ClientError> {
+        Ok(self
+            .tag_sources()
+            .await?
+            .into_iter()
+            .filter(|(_, sources)| sources.len() > 1)
+            .map(|(tag, sources)| TagCollision { tag, sources })
+            .collect())
+    }
+
+    /// Returns the distinct sources of each tracked tag.
+    pub(super) async fn tag_sources(
+        &self,
+    ) -> Result<BTreeMap<NoteTag, Vec<NoteTagSource>>, ClientError> {
+        let mut tag_sources: BTreeMap<NoteTag, Vec<NoteTagSource>> = BTreeMap::new();
+        for tag_record in self.store.get_note_tags().await? {
+            let sources = tag_sources.entry(tag_record.tag).or_default();
+            if !sources.contains(&tag_record.source) {
+                sources.push(tag_record.source);
+            }
+        }
+
+        Ok(tag_sources)
+    }
+
+    /// Stops tracking the tags of expected notes that expired at the specified block and whose
+    /// note is consumed, invalid, rejected or no longer tracked. Returns how many tags were
+    /// removed.
+    pub(super) async fn remove_expired_note_tags(
+        &mut self,
+        block_num: u32,
+    ) -> Result<usize, ClientError> {
+        let expired_candidates: Vec<NoteTagRecord> = self
+            .store
+            .get_note_tags()
+            .await?
+            .into_iter()
+            .filter(|tag_record| {
+                tag_record.expires_after.is_some_and(|expires_after| expires_after <= block_num)
+            })
+            .collect();
+        if expired_candidates.is_empty() {
+            return Ok(0);
+        }
+
+        let note_ids = expired_candidates
+            .iter()
+            .filter_map(|tag_record| match tag_record.source {
+                NoteTagSource::Note(note_id) => Some(note_id),
+                _ => None,
+            })
+            .collect();
+        let notes = self.store.get_input_notes(NoteFilter::List(note_ids)).await?;
+
+        let mut removed = 0;
+        for tag_record in expired_candidates {
+            let note = match tag_record.source {
+                NoteTagSource::Note(note_id) => notes.iter().find(|note| note.id() == note_id),
+                _ => None,
+            };
+
+            if tag_record.is_expired(block_num, note) {
+                removed += self.store.remove_note_tag(tag_record).await?;
+            }
+        }
+
+        if removed > 0 {
+            info!("Removed {removed} expired note tags.");
+        }
+
+        Ok(removed)
+    }
+}
+
+/// Tag tracked on behalf of more than one source, as reported by [Client::get_tag_collisions].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagCollision {
+    pub tag: NoteTag,
+    /// Distinct sources of the tag, in the order their records were read from the store.
+    pub sources: Vec<NoteTagSource>,
+}
+
+/// Replacement of the tag an expected note was tracked with by the tag the note actually has.
+///
+/// Corrections are reported by [Client::import_note_with_outcome], when the tag of an imported
+/// note file doesn't match the one derived from the note's details, and in the
+/// [SyncSummary](super::SyncSummary), when a note is committed with another tag than the tracked
+/// one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteTagCorrection {
+    pub note_id: NoteId,
+    /// Tag the note was tracked with, or provided with when it was imported.
+    pub previous_tag: NoteTag,
+    /// Tag derived from the note's details or found in its committed metadata.
+    pub corrected_tag: NoteTag,
 }
 
 /// Represents a note tag of which the Store can keep track and retrieve.
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///
+/// Records are identified by their tag and source, so two records that only differ in their
+/// expiry or subscription are considered equal.
+#[derive(Debug, Clone, Copy)]
 pub struct NoteTagRecord {
     pub tag: NoteTag,
     pub source: NoteTagSource,
+    /// Block after which the tag can stop being tracked once its source note is consumed or
+    /// invalid. Only set for tags of expected notes.
+    pub expires_after: Option<u32>,
+    /// Whether every untracked public note received with the tag is stored.
+    pub keep_all: bool,
+    /// Value of the metadata `aux` of the untracked public notes that are stored when `keep_all`
+    /// is not set.
+    pub aux_filter: Option<Felt>,
 }
 
 /// Represents the source of the tag. This is used to differentiate between tags that are added by
@@ -89,6 +274,9 @@ impl NoteTagRecord {
         Self {
             tag,
             source: NoteTagSource::Note(note_id),
+            expires_after: None,
+            keep_all: true,
+            aux_filter: None,
         }
     }
 
@@ -96,10 +284,78 @@ impl NoteTagRecord {
         Self {
             tag,
             source: NoteTagSource::Account(account_id),
+            expires_after: None,
+            keep_all: true,
+            aux_filter: None,
+        }
+    }
+
+    pub fn with_user_source(tag: NoteTag) -> Self {
+        Self {
+            tag,
+            source: NoteTagSource::User,
+            expires_after: None,
+            keep_all: true,
+            aux_filter: None,
         }
     }
+
+    /// Returns the record with its expiry set to the specified block.
+    pub fn with_expiry(mut self, block_num: u32) -> Self {
+        self.expires_after = Some(block_num);
+        self
+    }
+
+    /// Returns the record with the specified subscription, as described in
+    /// [Client::add_note_tag_subscription].
+    pub fn with_subscription(mut self, keep_all: bool, aux_filter: Option<Felt>) -> Self {
+        self.keep_all = keep_all;
+        self.aux_filter = aux_filter;
+        self
+    }
+
+    /// Returns whether an untracked public note with the provided metadata is stored when it's
+    /// received with the tag.
+    pub(crate) fn keeps_note(&self, metadata: &NoteMetadata) -> bool {
+        self.keep_all || self.aux_filter.is_some_and(|aux| metadata.aux() == aux)
+    }
+
+    /// Returns the record tracking the tag of an expected note, which expires after the block the
+    /// note is expected to be committed after. Returns `None` if the note isn't expected or its
+    /// tag is unknown.
+    pub(crate) fn for_expected_note(note: &InputNoteRecord) -> Option<Self> {
+        match note.state() {
+            InputNoteState::Expected(ExpectedNoteState {
+                tag: Some(tag), after_block_num, ..
+            }) => Some(Self::with_note_source(*tag, note.id()).with_expiry(*after_block_num)),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the tag can stop being tracked at the specified block, given the state of
+    /// its source note. Notes that are no longer tracked as input notes are passed as `None`.
+    pub(crate) fn is_expired(&self, block_num: u32, note: Option<&InputNoteRecord>) -> bool {
+        let Some(expires_after) = self.expires_after else {
+            return false;
+        };
+
+        expires_after <= block_num
+            && note.map_or(true, |note| {
+                note.is_consumed()
+                    || note.is_rejected()
+                    || matches!(note.state(), InputNoteState::Invalid(_))
+            })
+    }
+}
+
+impl PartialEq for NoteTagRecord {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag && self.source == other.source
+    }
 }
 
+impl Eq for NoteTagRecord {}
+
 impl Serializable for NoteTagSource {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         match self {
@@ -127,6 +383,46 @@ impl Deserializable for NoteTagSource {
     }
 }
 
+impl Serializable for NoteTagRecord {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tag.write_into(target);
+        self.source.write_into(target);
+        self.expires_after.write_into(target);
+        target.write_u8(self.keep_all.into());
+        self.aux_filter.write_into(target);
+    }
+}
+
+impl Deserializable for NoteTagRecord {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            tag: NoteTag::read_from(source)?,
+            source: NoteTagSource::read_from(source)?,
+            expires_after: Option::<u32>::read_from(source)?,
+            keep_all: source.read_u8()? != 0,
+            aux_filter: Option::<Felt>::read_from(source)?,
+        })
+    }
+}
+
+impl Serializable for NoteTagCorrection {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_id.write_into(target);
+        self.previous_tag.write_into(target);
+        self.corrected_tag.write_into(target);
+    }
+}
+
+impl Deserializable for NoteTagCorrection {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            note_id: NoteId::read_from(source)?,
+            previous_tag: NoteTag::read_from(source)?,
+            corrected_tag: NoteTag::read_from(source)?,
+        })
+    }
+}
+
 impl PartialEq<NoteTag> for NoteTagRecord {
     fn eq(&self, other: &NoteTag) -> bool {
         self.tag == *other
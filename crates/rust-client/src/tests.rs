@@ -1,30 +1,100 @@
-use alloc::vec::Vec;
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    sync::Arc,
+    vec::Vec,
+};
+use core::time::Duration;
 
+use futures::{future::join_all, StreamExt};
 // TESTS
 // ================================================================================================
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     accounts::{
         account_id::testing::{
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
         },
-        Account, AccountCode, AccountHeader, AccountId, AccountStorageMode, AuthSecretKey,
+        Account, AccountCode, AccountData, AccountHeader, AccountId, AccountStorage,
+        AccountStorageMode, AuthSecretKey, StorageSlot,
+    },
+    assets::{Asset, FungibleAsset, TokenSymbol},
+    crypto::{
+        dsa::rpo_falcon512::SecretKey,
+        merkle::{InOrderIndex, MerklePath},
+        rand::RpoRandomCoin,
     },
-    assets::{FungibleAsset, TokenSymbol},
-    crypto::dsa::rpo_falcon512::SecretKey,
-    notes::{NoteFile, NoteTag},
-    Felt, FieldElement, Word,
+    notes::{
+        Note, NoteAssets, NoteExecutionMode, NoteFile, NoteId, NoteRecipient, NoteTag, PartialNote,
+    },
+    testing::notes::NoteBuilder,
+    transaction::OutputNote,
+    Digest, Felt, FieldElement, Word,
+};
+#[cfg(feature = "local-prover")]
+use miden_objects::{
+    notes::NoteType,
+    transaction::{ProvenTransaction, TransactionScript, TransactionWitness},
+    MIN_PROOF_SECURITY_LEVEL,
 };
 use miden_tx::utils::{Deserializable, Serializable};
+#[cfg(feature = "local-prover")]
+use miden_tx::TransactionVerifier;
 
 use crate::{
-    accounts::AccountTemplate,
-    mock::create_test_client,
-    rpc::NodeRpcClient,
-    store::{InputNoteRecord, NoteFilter, Store},
-    transactions::TransactionRequest,
+    accounts::{
+        AccountIntegrityIssue, AccountLockStatus, AccountReport, AccountTemplate,
+        AccountTypeReport, AuthSchemeReport, PrivateAccountMismatchPolicy, RemoveOptions,
+        UnlockStrategy, UnlockStrategyKind, WatchOnlyAccountFile,
+    },
+    config::ClientProfile,
+    maintenance::{MaintenanceScheduler, MaintenanceTask},
+    mock::{
+        create_test_client, create_test_client_with_rpc, create_test_client_with_store,
+        create_test_store_path, MockRpcApi,
+    },
+    notes::{
+        create_p2id_note,
+        memo::{read_memo, MAX_MEMO_LEN},
+        FutureNoteLink, FutureNoteResolution, ImportOutcome, InMemoryNoteSource, NoteImportOutcome,
+        NoteStorageStats, NoteTagPolicy, PendingNoteRecord,
+    },
+    recovery::DerivedCategory,
+    rpc::{
+        NodeRpcClient, NodeRpcClientEndpoint, RpcError, RpcLatencyTracker, RpcTraffic,
+        RpcTrafficCounter, TransactionUpdate, LATENCY_SAMPLES_PER_METHOD,
+    },
+    store::{
+        input_note_states::{CommittedNoteState, ExpectedNoteState, RejectedNoteState},
+        memory_store::MemoryStore,
+        sqlite_store::{config::SqliteStoreConfig, SqliteStore},
+        ChainMmrNodeFilter, InputNoteRecord, InputNoteState, NoteFilter, NoteStateCode,
+        OutputNoteRecord, OutputNoteState, Store, StoreError, TransactionFilter,
+    },
+    sync::{
+        diff_sync_journals, NoteDetailFetchPolicy, NoteTagCorrection, NoteTagRecord, NoteTagSource,
+        SyncJournal, SyncRateTracker, SyncRequestDigest, SyncSummary,
+    },
+    time::TimeSource,
+    transactions::{
+        script_arg_key, ConsumabilityVerdict, DiscardCause, ForeignAccountInputs,
+        PaymentTransactionData, ScriptArg, SigningRequest, StatelessTransactionInputs,
+        SwapTransactionData, TransactionId, TransactionRequest, TransactionRequestError,
+        TransactionResult, TransactionStatus, TransactionStoreUpdate,
+    },
+    ClientError, IdPrefixFetchError,
+};
+#[cfg(feature = "local-prover")]
+use crate::{
+    accounts::{AuthKeyRotation, ISSUANCE_WARNING_THRESHOLD_PERCENT},
+    sync::StorageSlotChange,
+    transactions::{
+        prepare_word, LocalTransactionProver, OutputNoteSummary, ProverPool, ProverPoolConfig,
+        TransactionProvenance, TransactionProver, CLIENT_VERSION,
+    },
 };
 
 #[tokio::test]
@@ -184,6 +254,50 @@ async fn insert_same_account_twice_fails() {
         .is_err());
 }
 
+#[tokio::test]
+async fn test_concurrent_account_creation_shares_store() {
+    let store_config: SqliteStoreConfig =
+        create_test_store_path().to_string_lossy().to_string().try_into().unwrap();
+    let store = Arc::new(SqliteStore::new(&store_config).await.unwrap());
+    let rpc_api = MockRpcApi::new();
+
+    let mut clients: Vec<_> = (0..4)
+        .map(|_| create_test_client_with_store(rpc_api.clone(), store.clone()))
+        .collect();
+
+    let created = join_all(clients.iter_mut().map(|client| {
+        client.new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+    }))
+    .await;
+    let created_ids: BTreeSet<AccountId> =
+        created.into_iter().map(|result| result.unwrap().0.id()).collect();
+
+    let client = &mut clients[0];
+    let tracked_ids: BTreeSet<AccountId> = client
+        .get_account_headers()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(header, _)| header.id())
+        .collect();
+    assert_eq!(created_ids.len(), 4);
+    assert_eq!(tracked_ids, created_ids);
+
+    // Every stored key has its account and no reservation is left behind
+    assert!(client.find_orphaned_keys().await.unwrap().is_empty());
+    assert!(client.cleanup_orphaned_keys().await.unwrap().is_empty());
+    for account_id in created_ids {
+        client.get_account_auth(account_id).await.unwrap();
+        assert!(matches!(
+            store.reserve_account_id(account_id).await,
+            Err(StoreError::AccountIdUnavailable(_))
+        ));
+    }
+}
+
 #[tokio::test]
 async fn test_account_code() {
     // generate test client with a random store name
@@ -243,11 +357,133 @@ async fn test_get_account_by_id() {
     assert!(client.get_account_header_by_id(invalid_id).await.is_err());
 }
 
+#[tokio::test]
+async fn test_archive_and_restore_consumed_note() {
+    let (mut client, rpc_api) = create_test_client().await;
+
+    let mut consumed_note: InputNoteRecord = rpc_api.get_note_at(0).note().clone().into();
+    consumed_note.consumed_externally(consumed_note.nullifier(), 1).unwrap();
+    let expected_note: InputNoteRecord = rpc_api.get_note_at(1).note().clone().into();
+    Store::upsert_input_notes(client.store.as_ref(), &[consumed_note.clone(), expected_note])
+        .await
+        .unwrap();
+
+    // read the note back so the record includes the creation timestamp set by the store
+    let consumed_note = client.get_input_note(consumed_note.id()).await.unwrap();
+
+    // only the consumed note is archived
+    let archived = client.archive_notes(NoteFilter::All).await.unwrap();
+    assert_eq!(archived, vec![consumed_note.id()]);
+
+    let notes = client.get_input_notes(NoteFilter::All).await.unwrap();
+    assert_eq!(notes.len(), 1);
+    assert!(notes.iter().all(|note| note.id() != consumed_note.id()));
+    assert_eq!(
+        client.search_archived_notes(consumed_note.id()).await.unwrap(),
+        Some(consumed_note.clone())
+    );
+    assert_eq!(
+        client.get_note_storage_stats().await.unwrap(),
+        NoteStorageStats {
+            active_input_notes: 1,
+            archived_input_notes: 1
+        }
+    );
+
+    let restored_note = client.restore_archived_note(consumed_note.id()).await.unwrap();
+    assert_eq!(restored_note, consumed_note);
+    assert_eq!(client.get_input_note(consumed_note.id()).await.unwrap(), consumed_note);
+    assert_eq!(client.search_archived_notes(consumed_note.id()).await.unwrap(), None);
+    assert_eq!(client.get_input_notes(NoteFilter::All).await.unwrap().len(), 2);
+
+    // restoring a note that is not archived fails
+    assert!(client.restore_archived_note(consumed_note.id()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_sync_rejects_notes_not_matching_the_chain() {
+    let mut rpc_api = MockRpcApi::new();
+    let mismatching_note = rpc_api.get_note_at(0).note().clone();
+    let unverifiable_note = rpc_api.get_note_at(1).note().clone();
+
+    // the node reports each note with the metadata of the other one
+    rpc_api
+        .doctored_note_metadata
+        .insert(mismatching_note.id(), *unverifiable_note.metadata());
+    rpc_api
+        .doctored_note_metadata
+        .insert(unverifiable_note.id(), *mismatching_note.metadata());
+    let mut client = create_test_client_with_rpc(rpc_api).await;
+
+    // the first note is expected with its metadata, the second one only with its tag
+    let unverifiable_tag = unverifiable_note.metadata().tag();
+    let expected_notes = [
+        mismatching_note.clone().into(),
+        InputNoteRecord::new(
+            unverifiable_note.clone().into(),
+            None,
+            ExpectedNoteState {
+                metadata: None,
+                after_block_num: 0,
+                tag: Some(unverifiable_tag),
+            }
+            .into(),
+        ),
+    ];
+    Store::upsert_input_notes(client.store.as_ref(), &expected_notes).await.unwrap();
+    for note in expected_notes.iter() {
+        let tag_record = NoteTagRecord::for_expected_note(note).unwrap();
+        client.store.add_note_tag(tag_record).await.unwrap();
+    }
+
+    client.sync_state().await.unwrap();
+
+    let rejected_notes = client.get_input_notes(NoteFilter::Rejected).await.unwrap();
+    assert_eq!(rejected_notes.len(), 2);
+    assert!(client.get_input_notes(NoteFilter::Expected).await.unwrap().is_empty());
+    assert!(client.get_input_notes(NoteFilter::Committed).await.unwrap().is_empty());
+
+    let mismatching_record = client.get_input_note(mismatching_note.id()).await.unwrap();
+    assert!(matches!(
+        mismatching_record.state(),
+        InputNoteState::Rejected(RejectedNoteState { reason, .. }) if reason.contains("metadata")
+    ));
+    let unverifiable_record = client.get_input_note(unverifiable_note.id()).await.unwrap();
+    assert!(matches!(
+        unverifiable_record.state(),
+        InputNoteState::Rejected(RejectedNoteState { reason, .. }) if reason.contains("commitment")
+    ));
+
+    // rejected notes are counted apart and their tags are no longer tracked
+    let counts = client.count_notes_by_state().await.unwrap();
+    assert_eq!(counts.rejected, 2);
+    assert_eq!(counts.expected, 0);
+    assert!(!client
+        .store
+        .get_note_tags()
+        .await
+        .unwrap()
+        .iter()
+        .any(|tag| matches!(tag.source, NoteTagSource::Note(_))));
+}
+
 #[tokio::test]
 async fn test_sync_state() {
     // generate test client with a random store name
-    let (mut client, rpc_api) = create_test_client().await;
+    let (client, rpc_api) = create_test_client().await;
+    check_sync_state(client, rpc_api).await;
+}
+
+#[tokio::test]
+async fn test_sync_state_in_memory() {
+    let rpc_api = MockRpcApi::new();
+    let client = create_test_client_with_store(rpc_api.clone(), Arc::new(MemoryStore::new()));
+    check_sync_state(client, rpc_api).await;
+}
 
+/// Syncs a client that expects the first note of the mock chain, which is committed and then
+/// consumed, and checks the notes and sync height stored by the client's store.
+async fn check_sync_state(mut client: crate::mock::MockClient, rpc_api: MockRpcApi) {
     // Import first mockchain note as expected
     let expected_note = rpc_api.get_note_at(1).note().clone();
     Store::upsert_input_notes(client.store.as_ref(), &[expected_note.clone().into()])
@@ -281,6 +517,49 @@ async fn test_sync_state() {
     );
 }
 
+#[tokio::test]
+async fn test_get_last_sync_changes() {
+    // generate test client with a random store name
+    let (mut client, rpc_api) = create_test_client().await;
+
+    // before the first sync there are no changes
+    let changes = client.get_last_sync_changes().await.unwrap();
+    assert_eq!(changes.block_num, 0);
+    assert!(changes.committed_input_notes.is_empty());
+
+    let expected_note = rpc_api.get_note_at(1).note().clone();
+    Store::upsert_input_notes(client.store.as_ref(), &[expected_note.clone().into()])
+        .await
+        .unwrap();
+
+    let sync_details = client.sync_state().await.unwrap();
+
+    // the changes contain the records of the notes referenced by the summary
+    let changes = client.get_last_sync_changes().await.unwrap();
+    assert_eq!(changes.block_num, sync_details.block_num);
+    assert_eq!(
+        changes.committed_input_notes.iter().map(|note| note.id()).collect::<Vec<_>>(),
+        sync_details.committed_notes
+    );
+    assert_eq!(
+        changes.consumed_input_notes.iter().map(|note| note.id()).collect::<Vec<_>>(),
+        sync_details.consumed_notes
+    );
+    assert!(changes.consumed_input_notes.iter().all(|note| note.is_consumed()));
+
+    // the summary is persisted, so it's returned again until the next sync
+    assert_eq!(
+        Store::get_last_sync_summary(client.store.as_ref()).await.unwrap(),
+        Some(sync_details)
+    );
+
+    // a sync without new blocks replaces the changes with an empty set
+    client.sync_state().await.unwrap();
+    let changes = client.get_last_sync_changes().await.unwrap();
+    assert!(changes.consumed_input_notes.is_empty());
+    assert!(changes.committed_input_notes.is_empty());
+}
+
 #[tokio::test]
 async fn test_sync_state_mmr() {
     // generate test client with a random store name
@@ -339,171 +618,4756 @@ async fn test_sync_state_mmr() {
 }
 
 #[tokio::test]
-async fn test_tags() {
-    // generate test client with a random store name
-    let (mut client, _rpc_api) = create_test_client().await;
-
-    // Assert that the store gets created with the tag 0 (used for notes consumable by any account)
-    assert!(client.get_note_tags().await.unwrap().is_empty());
-
-    // add a tag
-    let tag_1: NoteTag = 1.into();
-    let tag_2: NoteTag = 2.into();
-    client.add_note_tag(tag_1).await.unwrap();
-    client.add_note_tag(tag_2).await.unwrap();
+async fn test_estimate_sync_work_and_capped_sync() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: true,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
 
-    // verify that the tag is being tracked
-    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_1, tag_2]);
+    let estimate = client.estimate_sync_work().await.unwrap();
+    assert_eq!(estimate.sync_height, 0);
+    assert_eq!(estimate.chain_tip, 5);
+    assert_eq!(estimate.block_gap, 5);
+    assert_eq!(estimate.max_iterations, 5);
+    assert_eq!(estimate.tracked_tags, 1);
+    assert_eq!(estimate.tracked_accounts, 1);
+    assert!(!estimate.is_synced());
 
-    // attempt to add the same tag again
-    client.add_note_tag(tag_1).await.unwrap();
+    // the mock node answers with the blocks that have notes, 1 and 4, before the chain tip
+    let summary = client.sync_state_up_to(1).await.unwrap();
+    assert_eq!(summary.block_num, 1);
+    assert_eq!(client.get_sync_height().await.unwrap(), 1);
 
-    // verify that the tag is still being tracked only once
-    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_1, tag_2]);
+    let partial_estimate = client.estimate_sync_work().await.unwrap();
+    assert_eq!(partial_estimate.sync_height, 1);
+    assert_eq!(partial_estimate.block_gap, 4);
+    assert_eq!(partial_estimate.max_iterations, 4);
+    assert!(partial_estimate.max_response_bytes < estimate.max_response_bytes);
 
-    // Try removing non-existent tag
-    let tag_4: NoteTag = 4.into();
-    client.remove_note_tag(tag_4).await.unwrap();
+    // a sync always makes at least one request
+    let summary = client.sync_state_up_to(0).await.unwrap();
+    assert_eq!(summary.block_num, 4);
 
-    // verify that the tracked tags are unchanged
-    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_1, tag_2]);
+    let summary = client.sync_state_up_to(10).await.unwrap();
+    assert_eq!(summary.block_num, 5);
+    let synced_estimate = client.estimate_sync_work().await.unwrap();
+    assert!(synced_estimate.is_synced());
+    assert_eq!(synced_estimate.max_iterations, 1);
+    assert!(synced_estimate.max_response_bytes < partial_estimate.max_response_bytes);
 
-    // remove second tag
-    client.remove_note_tag(tag_1).await.unwrap();
+    // new blocks open the gap again
+    for _ in 0..3 {
+        rpc_api.add_block_with_notes(vec![]);
+    }
+    *client.rpc_api() = Box::new(rpc_api);
 
-    // verify that tag_1 is not tracked anymore
-    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_2]);
+    let estimate = client.estimate_sync_work().await.unwrap();
+    assert_eq!(estimate.sync_height, 5);
+    assert_eq!(estimate.chain_tip, 8);
+    assert_eq!(estimate.block_gap, 3);
+    assert_eq!(client.sync_state_up_to(1).await.unwrap().block_num, 8);
 }
 
 #[tokio::test]
-async fn test_mint_transaction() {
-    // generate test client with a random store name
-    let (mut client, _rpc_api) = create_test_client().await;
-
-    // Faucet account generation
-    let (faucet, _seed) = client
-        .new_account(AccountTemplate::FungibleFaucet {
-            token_symbol: "TST".try_into().unwrap(),
-            decimals: 3,
-            max_supply: 10000,
+async fn test_sync_state_stream() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
             storage_mode: AccountStorageMode::Private,
         })
         .await
         .unwrap();
 
-    client.sync_state().await.unwrap();
+    // Mint notes to the account in two new blocks, followed by an empty one
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    for _ in 0..2 {
+        let note = create_p2id_note(
+            faucet_id,
+            account.id(),
+            vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap();
+        rpc_api.add_block_with_notes(vec![note]);
+    }
+    rpc_api.add_block_with_notes(vec![]);
+    *client.rpc_api() = Box::new(rpc_api);
 
-    // Test submitting a mint transaction
-    let transaction_request = TransactionRequest::mint_fungible_asset(
-        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
-        AccountId::from_hex("0x168187d729b31a84").unwrap(),
-        miden_objects::notes::NoteType::Private,
-        client.rng(),
-    )
-    .unwrap();
+    // The mock node answers with every block that has notes before the chain tip
+    let summaries: Vec<_> = client.sync_state_stream().collect().await;
+    let block_nums: Vec<u32> =
+        summaries.into_iter().map(|summary| summary.unwrap().block_num).collect();
+    assert_eq!(block_nums, vec![1, 4, 6, 7, 8]);
+    assert_eq!(client.get_sync_height().await.unwrap(), 8);
+    assert_eq!(client.get_last_sync_changes().await.unwrap().block_num, 8);
 
-    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    // A synced client yields the chain tip once
+    let summaries: Vec<_> = client.sync_state_stream().collect().await;
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].as_ref().unwrap().block_num, 8);
+}
 
-    assert!(transaction.executed_transaction().account_delta().nonce().is_some());
+#[tokio::test]
+async fn test_dropped_sync_stream_keeps_applied_blocks() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let mut stream = Box::pin(client.sync_state_stream());
+    assert_eq!(stream.next().await.unwrap().unwrap().block_num, 1);
+    drop(stream);
+
+    assert_eq!(client.get_sync_height().await.unwrap(), 1);
+    assert_eq!(client.sync_state().await.unwrap().block_num, 5);
 }
 
 #[tokio::test]
-async fn test_get_output_notes() {
-    // generate test client with a random store name
+async fn test_sync_rate_and_time_to_tip() {
     let (mut client, _rpc_api) = create_test_client().await;
+    assert!(client.sync_rate().is_none());
+    assert_eq!(client.estimated_time_to_tip().await.unwrap(), None);
+
+    client.sync_state_up_to(1).await.unwrap();
+    assert!(client.sync_rate().unwrap().value() > 0.0);
+    assert!(client.estimated_time_to_tip().await.unwrap().unwrap() > Duration::ZERO);
+
     client.sync_state().await.unwrap();
+    assert_eq!(client.estimated_time_to_tip().await.unwrap(), Some(Duration::ZERO));
 
-    // Faucet account generation
-    let (faucet, _seed) = client
-        .new_account(AccountTemplate::FungibleFaucet {
-            token_symbol: "TST".try_into().unwrap(),
-            decimals: 3,
-            max_supply: 10000,
+    // the rate is measured over the most recent iterations only
+    let mut tracker = SyncRateTracker::default();
+    assert_eq!(tracker.rate(), None);
+    tracker.record(10, Duration::from_secs(2));
+    assert_eq!(tracker.rate().unwrap().value(), 5.0);
+    for _ in 0..16 {
+        tracker.record(1, Duration::from_secs(1));
+    }
+    assert_eq!(tracker.rate().unwrap().value(), 1.0);
+}
+
+#[tokio::test]
+async fn test_sync_state_mmr_checkpoint() {
+    let (mut client, rpc_api) = create_test_client().await;
+    client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
             storage_mode: AccountStorageMode::Private,
         })
         .await
         .unwrap();
 
-    // Test submitting a mint transaction
-    let transaction_request = TransactionRequest::mint_fungible_asset(
-        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
-        AccountId::from_hex("0x0123456789abcdef").unwrap(),
-        miden_objects::notes::NoteType::Private,
-        client.rng(),
-    )
-    .unwrap();
+    let notes = rpc_api.notes.values().map(|n| n.note().clone().into()).collect::<Vec<_>>();
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
 
-    //Before executing transaction, there are no output notes
-    assert!(client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
 
-    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
-    client.submit_transaction(transaction).await.unwrap();
+    // The sync leaves a checkpoint at the sync height matching the MMR rebuilt from the nodes
+    let (checkpoint_block_num, checkpoint_mmr) =
+        client.store.load_mmr_checkpoint().await.unwrap().unwrap();
+    assert_eq!(checkpoint_block_num, sync_height);
+    let rebuilt_mmr = client.rebuild_partial_mmr(sync_height).await.unwrap();
+    assert_eq!(checkpoint_mmr, rebuilt_mmr);
+    assert_eq!(client.build_current_partial_mmr(false).await.unwrap(), rebuilt_mmr);
 
-    // Check that there was an output note but it wasn't consumed
-    assert!(client.get_output_notes(NoteFilter::Consumed).await.unwrap().is_empty());
-    assert!(!client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
+    // Inserting chain data outside of a sync discards the checkpoint
+    let nodes: Vec<_> = client
+        .store
+        .get_chain_mmr_nodes(ChainMmrNodeFilter::All)
+        .await
+        .unwrap()
+        .into_iter()
+        .collect();
+    client.store.insert_chain_mmr_nodes(&nodes[..1]).await.unwrap();
+    assert!(client.store.load_mmr_checkpoint().await.unwrap().is_none());
+    assert_eq!(client.build_current_partial_mmr(false).await.unwrap(), rebuilt_mmr);
+
+    // The next sync takes a new checkpoint, even though the chain didn't advance
+    client.sync_state().await.unwrap();
+    let (checkpoint_block_num, checkpoint_mmr) =
+        client.store.load_mmr_checkpoint().await.unwrap().unwrap();
+    assert_eq!(checkpoint_block_num, sync_height);
+    assert_eq!(checkpoint_mmr, rebuilt_mmr);
 }
 
 #[tokio::test]
-async fn test_import_note_validation() {
-    // generate test client
+async fn test_warmup_restores_mmr_checkpoint() {
     let (mut client, rpc_api) = create_test_client().await;
-
-    // generate test data
-    let committed_note: InputNoteRecord = rpc_api.get_note_at(0).into();
-    let expected_note: InputNoteRecord = rpc_api.get_note_at(1).note().clone().into();
-
     client
-        .import_note(NoteFile::NoteDetails {
-            details: committed_note.clone().into(),
-            after_block_num: 0,
-            tag: None,
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
         })
         .await
         .unwrap();
-    assert!(client.import_note(NoteFile::NoteId(expected_note.id())).await.is_err());
-    client
-        .import_note(NoteFile::NoteDetails {
-            details: expected_note.clone().into(),
-            after_block_num: 0,
-            tag: None,
-        })
+
+    let notes = rpc_api.notes.values().map(|n| n.note().clone().into()).collect::<Vec<_>>();
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
+
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
+    let eager_mmr = client.rebuild_partial_mmr(sync_height).await.unwrap();
+
+    // Discard the checkpoint so that the next sync would have to rebuild the MMR
+    let nodes: Vec<_> = client
+        .store
+        .get_chain_mmr_nodes(ChainMmrNodeFilter::All)
         .await
-        .unwrap();
+        .unwrap()
+        .into_iter()
+        .collect();
+    client.store.insert_chain_mmr_nodes(&nodes[..1]).await.unwrap();
+    assert!(client.store.load_mmr_checkpoint().await.unwrap().is_none());
 
-    assert!(expected_note.inclusion_proof().is_none());
-    assert!(committed_note.inclusion_proof().is_some());
+    client.warmup().await.unwrap();
+    let (checkpoint_block_num, checkpoint_mmr) =
+        client.store.load_mmr_checkpoint().await.unwrap().unwrap();
+    assert_eq!(checkpoint_block_num, sync_height);
+    assert_eq!(checkpoint_mmr, eager_mmr);
+
+    // Syncing after the warmup ends up in the same state as the eager path
+    client.sync_state().await.unwrap();
+    assert_eq!(client.get_sync_height().await.unwrap(), sync_height);
+    assert_eq!(client.build_current_partial_mmr(false).await.unwrap(), eager_mmr);
+
+    // Checkpoints disabled make the warmup a no-op
+    client.store.insert_chain_mmr_nodes(&nodes[..1]).await.unwrap();
+    client.set_mmr_checkpoint_interval(0);
+    client.warmup().await.unwrap();
+    assert!(client.store.load_mmr_checkpoint().await.unwrap().is_none());
 }
 
 #[tokio::test]
-async fn test_transaction_request_expiration() {
-    let (mut client, _) = create_test_client().await;
-    client.sync_state().await.unwrap();
+async fn test_sync_notes() {
+    let store_config: SqliteStoreConfig =
+        create_test_store_path().to_string_lossy().to_string().try_into().unwrap();
+    let store = Arc::new(SqliteStore::new(&store_config).await.unwrap());
+    let mut rpc_api = MockRpcApi::new();
 
-    let current_height = client.get_sync_height().await.unwrap();
-    let (faucet, _seed) = client
-        .new_account(AccountTemplate::FungibleFaucet {
-            token_symbol: "TST".try_into().unwrap(),
-            decimals: 3,
-            max_supply: 10000,
+    let mut client = create_test_client_with_store(rpc_api.clone(), store.clone());
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
             storage_mode: AccountStorageMode::Private,
         })
         .await
         .unwrap();
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
 
-    let transaction_request = TransactionRequest::mint_fungible_asset(
-        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
-        AccountId::from_hex("0x168187d729b31a84").unwrap(),
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let note = create_p2id_note(
+        sender_id,
+        account.id(),
+        vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
         miden_objects::notes::NoteType::Private,
+        Felt::ZERO,
         client.rng(),
     )
-    .unwrap()
-    .with_expiration_delta(5)
     .unwrap();
+    client
+        .import_note(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: sync_height,
+            tag: Some(note.metadata().tag()),
+        })
+        .await
+        .unwrap();
 
-    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    // The note is committed in a block after the client's sync height
+    rpc_api.add_block_with_notes(vec![note.clone()]);
+    let mut client = create_test_client_with_store(rpc_api, store);
+    let summary = client.sync_notes(&[note.id()]).await.unwrap();
+    assert_eq!(summary.block_num, sync_height + 1);
+    assert_eq!(summary.unverified_notes, vec![note.id()]);
+    assert!(summary.committed_notes.is_empty());
+    assert!(summary.skipped_notes.is_empty());
 
-    let (_, tx_outputs, ..) = transaction.executed_transaction().clone().into_parts();
+    let record = client.get_input_note(note.id()).await.unwrap();
+    assert!(matches!(record.state(), InputNoteState::Unverified(_)));
+    assert_eq!(record.inclusion_proof().unwrap().location().block_num(), sync_height + 1);
+    assert!(!client
+        .get_note_tags()
+        .await
+        .unwrap()
+        .iter()
+        .any(|tag| tag.source == NoteTagSource::Note(note.id())));
 
-    assert_eq!(tx_outputs.expiration_block_num, current_height + 5);
+    // Nothing but the note was synced
+    assert_eq!(client.get_sync_height().await.unwrap(), sync_height);
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+
+    // Notes that are no longer expected are skipped
+    let summary = client.sync_notes(&[note.id()]).await.unwrap();
+    assert_eq!(summary.block_num, sync_height);
+    assert_eq!(summary.skipped_notes, vec![note.id()]);
+    assert_eq!(summary.notes_with_inclusion_proofs().count(), 0);
+}
+
+#[tokio::test]
+async fn test_find_public_notes_in_range() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let mut p2id_note = |target_id, note_type| {
+        create_p2id_note(
+            sender_id,
+            target_id,
+            vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+            note_type,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap()
+    };
+    let first_note = p2id_note(account.id(), miden_objects::notes::NoteType::Public);
+    let private_note = p2id_note(account.id(), miden_objects::notes::NoteType::Private);
+    let other_account_note = p2id_note(other_account_id, miden_objects::notes::NoteType::Public);
+    let last_note = p2id_note(account.id(), miden_objects::notes::NoteType::Public);
+
+    let first_block = rpc_api.blocks.len() as u32;
+    rpc_api.add_block_with_notes(vec![first_note.clone()]);
+    rpc_api.add_block_with_notes(vec![private_note.clone(), other_account_note.clone()]);
+    rpc_api.add_block_with_notes(vec![last_note.clone()]);
+    rpc_api.public_note_details.extend([
+        first_note.id(),
+        private_note.id(),
+        other_account_note.id(),
+        last_note.id(),
+    ]);
+    *client.rpc_api() = Box::new(rpc_api);
+
+    let tags = [first_note.metadata().tag(), other_account_note.metadata().tag()];
+
+    // Private notes and notes other accounts can consume are left out
+    let notes = client
+        .find_public_notes_in_range(&tags, first_block, first_block + 1)
+        .await
+        .unwrap();
+    assert_eq!(notes.iter().map(InputNoteRecord::id).collect::<Vec<_>>(), vec![first_note.id()]);
+    assert!(matches!(notes[0].state(), InputNoteState::Unverified(_)));
+    assert_eq!(notes[0].inclusion_proof().unwrap().location().block_num(), first_block);
+
+    // Blocks outside of the range aren't searched
+    let notes = client
+        .find_public_notes_in_range(&tags, first_block + 1, first_block + 2)
+        .await
+        .unwrap();
+    assert_eq!(notes.iter().map(InputNoteRecord::id).collect::<Vec<_>>(), vec![last_note.id()]);
+
+    // Only notes with the provided tags are returned
+    let unrelated_tag = NoteTag::for_public_use_case(7, 0, NoteExecutionMode::Local).unwrap();
+    assert!(client
+        .find_public_notes_in_range(&[unrelated_tag], first_block, first_block + 2)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // The found notes aren't stored
+    assert!(client.get_input_notes(NoteFilter::All).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_fetch_external_notes() {
+    let (client, _rpc_api) = create_test_client().await;
+    let mut client = client.with_time_source(Arc::new(FixedTimeSource(1_000)));
+
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let mut private_note = || {
+        create_p2id_note(
+            sender_id,
+            sender_id,
+            vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap()
+    };
+    let first_note = private_note();
+    let second_note = private_note();
+    let note_file = |note: &Note| NoteFile::NoteDetails {
+        details: note.clone().into(),
+        after_block_num: 100,
+        tag: Some(note.metadata().tag()),
+    };
+
+    // The relay returns the same note twice
+    let relay = Arc::new(InMemoryNoteSource::new());
+    relay.publish(note_file(&first_note), 1_000);
+    relay.publish(note_file(&first_note), 1_000);
+    client.add_note_source(relay.clone());
+
+    let summary = client.fetch_external_notes().await.unwrap();
+    assert_eq!(summary.imported_notes, vec![first_note.id()]);
+    assert!(summary.warnings.is_empty());
+    assert!(matches!(
+        client.get_input_note(first_note.id()).await.unwrap().state(),
+        InputNoteState::Expected(_)
+    ));
+
+    // Notes returned again by a later poll are already tracked, so they aren't reported
+    let summary = client.fetch_external_notes().await.unwrap();
+    assert!(summary.imported_notes.is_empty());
+    assert_eq!(client.get_input_notes(NoteFilter::All).await.unwrap().len(), 1);
+
+    // A failing source doesn't fail the sync, which imports the notes of the other sources
+    let failing_relay = Arc::new(InMemoryNoteSource::new());
+    failing_relay.set_unavailable(true);
+    client.add_note_source(failing_relay);
+    relay.publish(note_file(&second_note), 1_000);
+
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.imported_external_notes, vec![second_note.id()]);
+    assert_eq!(summary.warnings.len(), 1);
+    assert_eq!(client.get_input_notes(NoteFilter::All).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_sync_against_reset_node() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let notes = rpc_api.notes.values().map(|n| n.note().clone().into()).collect::<Vec<_>>();
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
+
+    // Sync past the tip of a freshly started node
+    rpc_api.add_block_with_notes(vec![]);
+    rpc_api.add_block_with_notes(vec![]);
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    client.sync_state().await.unwrap();
+    assert_eq!(client.get_sync_height().await.unwrap(), 7);
+
+    // The node is wiped, so its chain is now behind the client's
+    let reset_rpc_api = MockRpcApi::new();
+    let reset_chain_tip = reset_rpc_api.blocks.last().unwrap().header().block_num();
+    *client.rpc_api() = Box::new(reset_rpc_api.clone());
+
+    assert!(matches!(
+        client.sync_state().await,
+        Err(ClientError::ChainBehindLocalState { local: 7, node }) if node == reset_chain_tip
+    ));
+    assert_eq!(client.get_sync_height().await.unwrap(), 7);
+
+    // Resetting the chain state keeps the account and reverts the notes to expected
+    client.reset_chain_state(true).await.unwrap();
+    assert_eq!(client.get_sync_height().await.unwrap(), 0);
+    assert!(client
+        .store
+        .get_chain_mmr_nodes(ChainMmrNodeFilter::All)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(client.store.get_last_sync_summary().await.unwrap().is_none());
+    assert_eq!(client.get_account_headers().await.unwrap().len(), 1);
+    assert_eq!(client.get_input_notes(NoteFilter::Expected).await.unwrap().len(), notes.len());
+
+    // The client syncs cleanly against the new chain
+    let sync_details = client.sync_state().await.unwrap();
+    assert_eq!(sync_details.block_num, reset_chain_tip);
+    assert!(client.get_input_notes(NoteFilter::Expected).await.unwrap().is_empty());
+    let partial_mmr = client.build_current_partial_mmr(true).await.unwrap();
+    assert_eq!(partial_mmr.forest(), reset_chain_tip as usize + 1);
+    assert_eq!(partial_mmr.peaks(), reset_rpc_api.get_mmr().peaks());
+
+    // Without keeping the accounts, they are removed as well
+    client.reset_chain_state(false).await.unwrap();
+    assert!(client.get_account_headers().await.unwrap().is_empty());
+    assert_eq!(client.get_sync_height().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_sync_state_with_notes_over_iteration_limit() {
+    // generate test client with a random store name
+    let (mut client, mut rpc_api) = create_test_client().await;
+
+    let notes = derive_notes(rpc_api.get_note_at(0).note(), 3000);
+
+    let expected_notes: Vec<InputNoteRecord> = notes.iter().cloned().map(Into::into).collect();
+    Store::upsert_input_notes(client.store.as_ref(), &expected_notes).await.unwrap();
+
+    rpc_api.add_block_with_notes(notes);
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    let chain_tip = rpc_api.blocks.last().unwrap().header().block_num();
+
+    let sync_details = client.sync_state().await.unwrap();
+
+    // all notes were committed even though they were processed in several iterations
+    assert_eq!(sync_details.block_num, chain_tip);
+    assert_eq!(sync_details.committed_notes.len(), 3000);
+    assert_eq!(client.get_input_notes(NoteFilter::Committed).await.unwrap().len(), 3000);
+    assert_eq!(client.get_input_notes(NoteFilter::Expected).await.unwrap().len(), 0);
+
+    // the block was applied once and the sync height advanced to the chain tip
+    assert_eq!(client.get_sync_height().await.unwrap(), chain_tip);
+    assert_eq!(
+        client.get_block_headers(&[chain_tip]).await.unwrap()[0].0.hash(),
+        rpc_api.blocks.last().unwrap().hash()
+    );
+    assert!(client.store.get_partial_sync_progress().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_partial_sync_progress_survives_restart() {
+    let store_config: SqliteStoreConfig =
+        create_test_store_path().to_string_lossy().to_string().try_into().unwrap();
+    let mut rpc_api = MockRpcApi::new();
+    let mut client = create_test_client_with_store(
+        rpc_api.clone(),
+        Arc::new(SqliteStore::new(&store_config).await.unwrap()),
+    );
+    client.sync_state().await.unwrap();
+    client.set_max_notes_per_sync_iteration(8);
+
+    let notes = derive_notes(rpc_api.get_note_at(0).note(), 20);
+    let expected_notes: Vec<InputNoteRecord> = notes.iter().cloned().map(Into::into).collect();
+    Store::upsert_input_notes(client.store.as_ref(), &expected_notes).await.unwrap();
+
+    rpc_api.add_block_with_notes(notes);
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    let chain_tip = rpc_api.blocks.last().unwrap().header().block_num();
+
+    // the first batch of notes is applied before the client is dropped
+    client.sync_state_up_to(1).await.unwrap();
+    drop(client);
+
+    let store = SqliteStore::new(&store_config).await.unwrap();
+    let progress = store.get_partial_sync_progress().await.unwrap().unwrap();
+    assert_eq!(progress.block_num(), chain_tip);
+    assert_eq!(progress.processed_notes(), 8);
+
+    // a new client continues with the remaining notes and clears the progress
+    let mut client = create_test_client_with_store(rpc_api, Arc::new(store));
+    client.set_max_notes_per_sync_iteration(8);
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.block_num, chain_tip);
+    assert_eq!(summary.committed_notes.len(), 12);
+    assert_eq!(client.get_input_notes(NoteFilter::Committed).await.unwrap().len(), 20);
+    assert!(client.store.get_partial_sync_progress().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_get_notes_by_id_in_batches() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+
+    let notes = derive_notes(rpc_api.get_note_at(0).note(), 25);
+    let note_ids: Vec<_> = notes.iter().map(Note::id).collect();
+
+    rpc_api.add_block_with_notes(notes);
+    rpc_api.max_notes_per_request = Some(10);
+    *client.rpc_api() = Box::new(rpc_api);
+
+    // the node rejects requests with more IDs than its limit
+    client.set_max_notes_per_request(note_ids.len());
+    assert!(client.get_notes_by_id_in_batches(&note_ids).await.is_err());
+
+    client.set_max_notes_per_request(10);
+    let notes_data = client.get_notes_by_id_in_batches(&note_ids).await.unwrap();
+    let mut fetched_ids: Vec<_> = notes_data.iter().map(|note| note.id()).collect();
+    let mut expected_ids = note_ids.clone();
+    fetched_ids.sort();
+    expected_ids.sort();
+    assert_eq!(fetched_ids, expected_ids);
+}
+
+#[tokio::test]
+async fn test_tags() {
+    // generate test client with a random store name
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    // Assert that the store gets created with the tag 0 (used for notes consumable by any account)
+    assert!(client.get_note_tags().await.unwrap().is_empty());
+
+    // add a tag
+    let tag_1: NoteTag = 1.into();
+    let tag_2: NoteTag = 2.into();
+    client.add_note_tag(tag_1).await.unwrap();
+    client.add_note_tag(tag_2).await.unwrap();
+
+    // verify that the tag is being tracked
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_1, tag_2]);
+
+    // attempt to add the same tag again
+    client.add_note_tag(tag_1).await.unwrap();
+
+    // verify that the tag is still being tracked only once
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_1, tag_2]);
+
+    // Try removing non-existent tag
+    let tag_4: NoteTag = 4.into();
+    client.remove_note_tag(tag_4).await.unwrap();
+
+    // verify that the tracked tags are unchanged
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_1, tag_2]);
+
+    // remove second tag
+    client.remove_note_tag(tag_1).await.unwrap();
+
+    // verify that tag_1 is not tracked anymore
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_2]);
+}
+
+#[tokio::test]
+async fn test_tag_subscription_aux_filter() {
+    const APP_ID: Felt = Felt::new(42);
+    const OTHER_APP_ID: Felt = Felt::new(7);
+
+    let tag = NoteTag::for_public_use_case(7, 0, NoteExecutionMode::Local).unwrap();
+    let [app_note, other_note] = [(1, APP_ID), (2, OTHER_APP_ID)].map(|(seed, aux)| {
+        NoteBuilder::new(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+            RpoRandomCoin::new([Felt::new(seed); 4]),
+        )
+        .tag(tag.into())
+        .aux(aux)
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap()
+    });
+
+    let mut rpc_api = MockRpcApi::new();
+    rpc_api.add_block_with_notes(vec![app_note.clone(), other_note.clone()]);
+    rpc_api.public_note_details.extend([app_note.id(), other_note.id()]);
+
+    // only the notes of the application are stored under a filtered subscription
+    let mut client = create_test_client_with_rpc(rpc_api.clone()).await;
+    client.add_note_tag_subscription(tag, false, Some(APP_ID)).await.unwrap();
+    client.sync_state().await.unwrap();
+
+    let stored_ids: Vec<NoteId> = client
+        .get_input_notes(NoteFilter::All)
+        .await
+        .unwrap()
+        .iter()
+        .map(|n| n.id())
+        .collect();
+    assert_eq!(stored_ids, vec![app_note.id()]);
+    let app_notes = client.get_input_notes(NoteFilter::Aux(APP_ID)).await.unwrap();
+    assert_eq!(app_notes.len(), 1);
+    assert_eq!(app_notes[0].id(), app_note.id());
+
+    // both notes are stored when every note of the tag is kept
+    let mut client = create_test_client_with_rpc(rpc_api).await;
+    client.add_note_tag_subscription(tag, false, Some(APP_ID)).await.unwrap();
+    client.add_note_tag_subscription(tag, true, None).await.unwrap();
+    assert_eq!(client.get_note_tags().await.unwrap().len(), 1);
+    client.sync_state().await.unwrap();
+
+    assert_eq!(client.get_input_notes(NoteFilter::All).await.unwrap().len(), 2);
+    for (note, aux) in [(&app_note, APP_ID), (&other_note, OTHER_APP_ID)] {
+        let notes = client.get_input_notes(NoteFilter::Aux(aux)).await.unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].id(), note.id());
+    }
+    assert!(client.get_input_notes(NoteFilter::Aux(Felt::new(1))).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_note_detail_fetch_policy() {
+    let mut client = create_test_client_with_rpc(MockRpcApi::new()).await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: true,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let user_tag = NoteTag::for_public_use_case(7, 0, NoteExecutionMode::Local).unwrap();
+    let account_tag = NoteTag::from_account_id(wallet.id(), NoteExecutionMode::Local).unwrap();
+    let other_sender =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    // notes sent by the wallet, sent to the wallet, and matched only by the user tag
+    let [sent_note, received_note, user_tag_note] = [
+        (1, wallet.id(), user_tag),
+        (2, other_sender, account_tag),
+        (3, other_sender, user_tag),
+    ]
+    .map(|(seed, sender, tag)| {
+        NoteBuilder::new(sender, RpoRandomCoin::new([Felt::new(seed); 4]))
+            .tag(tag.into())
+            .build(&TransactionKernel::testing_assembler())
+            .unwrap()
+    });
+
+    let mut rpc_api = MockRpcApi::new();
+    rpc_api.add_block_with_notes(vec![
+        sent_note.clone(),
+        received_note.clone(),
+        user_tag_note.clone(),
+    ]);
+    rpc_api
+        .public_note_details
+        .extend([sent_note.id(), received_note.id(), user_tag_note.id()]);
+
+    let sorted = |mut ids: Vec<NoteId>| {
+        ids.sort();
+        ids
+    };
+    let stored_ids =
+        |notes: Vec<InputNoteRecord>| sorted(notes.iter().map(|note| note.id()).collect());
+
+    // only the notes relevant to the wallet are fetched
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    client.add_note_tag(user_tag).await.unwrap();
+    client.set_note_detail_fetch_policy(NoteDetailFetchPolicy::FetchRelevantOnly);
+    let summary = client.sync_state().await.unwrap();
+
+    assert_eq!(sorted(summary.received_notes), sorted(vec![sent_note.id(), received_note.id()]));
+    assert_eq!(
+        stored_ids(client.get_input_notes(NoteFilter::All).await.unwrap()),
+        sorted(vec![sent_note.id(), received_note.id()])
+    );
+
+    // every note matching a tracked tag is fetched by default
+    let mut client = create_test_client_with_rpc(rpc_api).await;
+    client.add_note_tag(user_tag).await.unwrap();
+    client.sync_state().await.unwrap();
+
+    assert_eq!(
+        stored_ids(client.get_input_notes(NoteFilter::All).await.unwrap()),
+        sorted(vec![sent_note.id(), received_note.id(), user_tag_note.id()])
+    );
+}
+
+#[tokio::test]
+async fn test_mint_transaction() {
+    // generate test client with a random store name
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    // Faucet account generation
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    client.sync_state().await.unwrap();
+
+    // Test submitting a mint transaction
+    let transaction_request = mint_request(&mut client, faucet.id(), 5);
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+
+    assert!(transaction.executed_transaction().account_delta().nonce().is_some());
+}
+
+#[tokio::test]
+async fn test_supplied_foreign_account_inputs() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
+
+    let foreign_account = Account::mock(
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        Felt::new(2),
+        TransactionKernel::testing_assembler(),
+    );
+    let foreign_inputs = ForeignAccountInputs::new(
+        AccountHeader::from(&foreign_account),
+        foreign_account.storage().get_header(),
+        foreign_account.code().clone(),
+        MerklePath::new(vec![]),
+    );
+
+    // Inputs must be supplied for a single block
+    let transaction_request = mint_request(&mut client, faucet.id(), 5)
+        .with_foreign_account_inputs(vec![foreign_inputs.clone()], sync_height)
+        .unwrap();
+    assert!(matches!(
+        transaction_request
+            .clone()
+            .with_foreign_account_inputs(vec![foreign_inputs.clone()], sync_height + 1),
+        Err(TransactionRequestError::ForeignAccountInputsBlockMismatch(supplied, requested))
+            if supplied == sync_height && requested == sync_height + 1
+    ));
+    assert_eq!(
+        TransactionRequest::read_from_bytes(&transaction_request.to_bytes()).unwrap(),
+        transaction_request
+    );
+
+    // The code of the account must match its header
+    let faucet_code_inputs = ForeignAccountInputs::new(
+        AccountHeader::from(&foreign_account),
+        foreign_account.storage().get_header(),
+        faucet.code().clone(),
+        MerklePath::new(vec![]),
+    );
+    assert!(matches!(
+        mint_request(&mut client, faucet.id(), 5)
+            .with_foreign_account_inputs(vec![faucet_code_inputs], sync_height),
+        Err(TransactionRequestError::InvalidForeignAccountInputs(account_id))
+            if account_id == foreign_account.id()
+    ));
+
+    // Every foreign account needs supplied inputs once some are supplied
+    let other_account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let transaction_request = mint_request(&mut client, faucet.id(), 5)
+        .with_public_foreign_accounts([other_account_id])
+        .unwrap()
+        .with_foreign_account_inputs(vec![foreign_inputs.clone()], sync_height)
+        .unwrap();
+    assert!(matches!(
+        client.new_transaction(faucet.id(), transaction_request).await,
+        Err(ClientError::TransactionRequestError(
+            TransactionRequestError::MissingForeignAccountInputs(account_id)
+        )) if account_id == other_account_id
+    ));
+
+    // The block the inputs were retrieved at must be tracked by the client
+    let transaction_request = mint_request(&mut client, faucet.id(), 5)
+        .with_foreign_account_inputs(vec![foreign_inputs], sync_height + 10)
+        .unwrap();
+    assert!(matches!(
+        client.new_transaction(faucet.id(), transaction_request).await,
+        Err(ClientError::StoreError(StoreError::BlockHeaderNotFound(block_num)))
+            if block_num == sync_height + 10
+    ));
+}
+
+#[tokio::test]
+async fn test_foreign_account_block_unavailable() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    client.sync_state().await.unwrap();
+
+    // The foreign account proofs are reported at a block whose header can't be retrieved
+    let proofs_block_num = 3;
+    assert!(client.get_block_headers(&[proofs_block_num]).await.unwrap().is_empty());
+    rpc_api.account_proofs_block_num = Some(proofs_block_num);
+    rpc_api.fail_block_header_requests.insert(proofs_block_num);
+    *client.rpc_api() = Box::new(rpc_api.clone());
+
+    let unauthenticated_note = rpc_api.get_note_at(0).note().clone();
+    assert!(client.get_input_note(unauthenticated_note.id()).await.is_err());
+    let foreign_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let transaction_request = mint_request(&mut client, faucet.id(), 5)
+        .with_unauthenticated_input_notes([(unauthenticated_note.clone(), None)])
+        .with_public_foreign_accounts([foreign_account_id])
+        .unwrap();
+
+    assert!(matches!(
+        client.new_transaction(faucet.id(), transaction_request.clone()).await,
+        Err(ClientError::ForeignAccountBlockUnavailable { block_num }) if block_num == proofs_block_num
+    ));
+
+    // Nothing was persisted for the rejected transaction
+    assert!(client.get_block_headers(&[proofs_block_num]).await.unwrap().is_empty());
+    assert!(client.get_input_note(unauthenticated_note.id()).await.is_err());
+
+    // Once the header can be retrieved, it's authenticated and tracked before the execution
+    rpc_api.fail_block_header_requests.clear();
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    let result = client.new_transaction(faucet.id(), transaction_request).await;
+    assert!(!matches!(result, Err(ClientError::ForeignAccountBlockUnavailable { .. })));
+
+    let block_headers = client.get_block_headers(&[proofs_block_num]).await.unwrap();
+    assert_eq!(block_headers[0].0.block_num(), proofs_block_num);
+}
+
+#[tokio::test]
+async fn test_fpi_block_mismatch() {
+    let (mut client, rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    client.sync_state().await.unwrap();
+
+    // Track a note committed at the block of the first mock note
+    let faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap();
+    let inclusion_proof = rpc_api.get_note_at(0).proof().unwrap().clone();
+    let note_block_num = inclusion_proof.location().block_num();
+    let note = create_p2id_note(
+        faucet_id,
+        wallet.id(),
+        vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+        miden_objects::notes::NoteType::Private,
+        Felt::ZERO,
+        client.rng(),
+    )
+    .unwrap();
+    let state = CommittedNoteState {
+        metadata: *note.metadata(),
+        inclusion_proof,
+        block_note_root: Default::default(),
+    };
+    Store::upsert_input_notes(
+        client.store.as_ref(),
+        &[InputNoteRecord::new(note.clone().into(), None, state.into())],
+    )
+    .await
+    .unwrap();
+
+    let foreign_account = Account::mock(
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        Felt::new(2),
+        TransactionKernel::testing_assembler(),
+    );
+    let foreign_inputs = ForeignAccountInputs::new(
+        AccountHeader::from(&foreign_account),
+        foreign_account.storage().get_header(),
+        foreign_account.code().clone(),
+        MerklePath::new(vec![]),
+    );
+
+    // The foreign data is at a block before the note was committed
+    let transaction_request = TransactionRequest::consume_notes(vec![note.id()])
+        .with_foreign_account_inputs(vec![foreign_inputs], note_block_num - 1)
+        .unwrap();
+    assert!(matches!(
+        client.new_transaction(wallet.id(), transaction_request).await,
+        Err(ClientError::FpiBlockMismatch { fpi_block_num, note_id, note_block_num: block_num })
+            if fpi_block_num == note_block_num - 1
+                && note_id == note.id()
+                && block_num == note_block_num
+    ));
+}
+
+#[tokio::test]
+async fn test_execute_transaction_stateless() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    client.sync_state().await.unwrap();
+
+    // Export the faucet's inputs and execute the mint on a client that doesn't track it
+    let inputs = client.export_stateless_inputs(faucet.id(), &[]).await.unwrap();
+    let inputs = StatelessTransactionInputs::read_from_bytes(&inputs.to_bytes()).unwrap();
+    assert_eq!(inputs.account().id(), faucet.id());
+
+    let (mut stateless_client, _rpc_api) = create_test_client().await;
+    let transaction_request = mint_request(&mut stateless_client, faucet.id(), 5);
+
+    let transaction = stateless_client
+        .execute_transaction_stateless(inputs.clone(), transaction_request)
+        .await
+        .unwrap();
+    assert!(transaction.executed_transaction().account_delta().nonce().is_some());
+    assert_eq!(transaction.executed_transaction().account_id(), faucet.id());
+
+    // Nothing was written to either store
+    assert!(stateless_client.get_account_headers().await.unwrap().is_empty());
+    assert!(stateless_client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .is_empty());
+    let (stored_faucet, _seed) = client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(stored_faucet.nonce(), faucet.nonce());
+
+    // Notes that are not part of the inputs can't be consumed
+    let missing_note_id = NoteId::new(Default::default(), Default::default());
+    let transaction_request = TransactionRequest::consume_notes(vec![missing_note_id]);
+    assert!(stateless_client
+        .execute_transaction_stateless(inputs, transaction_request)
+        .await
+        .is_err());
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_get_output_notes() {
+    // generate test client with a random store name
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    // Faucet account generation
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    // Test submitting a mint transaction
+    let transaction_request = mint_request_to(
+        &mut client,
+        faucet.id(),
+        5u64,
+        AccountId::from_hex("0x0123456789abcdef").unwrap(),
+    );
+
+    //Before executing transaction, there are no output notes
+    assert!(client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    client.submit_transaction(transaction).await.unwrap();
+
+    // Check that there was an output note but it wasn't consumed
+    assert!(client.get_output_notes(NoteFilter::Consumed).await.unwrap().is_empty());
+    assert!(!client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_output_note_record_serialization() {
+    let (_client, rpc_api) = create_test_client().await;
+    let input_note = rpc_api.get_note_at(0);
+    let note = input_note.note().clone();
+    let inclusion_proof = input_note.proof().unwrap().clone();
+    let committed_block = inclusion_proof.location().block_num();
+
+    let mut record =
+        OutputNoteRecord::from_full_note(note.clone(), 0).with_created_at(Some(1_700_000_000));
+    assert_eq!(OutputNoteRecord::read_from_bytes(&record.to_bytes()).unwrap(), record);
+
+    assert!(record.inclusion_proof_received(inclusion_proof.clone(), 1_700_000_100).unwrap());
+    assert_eq!(record.committed_at_block(), Some(committed_block));
+    assert_eq!(record.committed_timestamp(), Some(1_700_000_100));
+    assert_eq!(OutputNoteRecord::read_from_bytes(&record.to_bytes()).unwrap(), record);
+
+    assert!(record.nullifier_received(note.nullifier(), committed_block + 1).unwrap());
+    assert_eq!(record.consumed_at_block(), Some(committed_block + 1));
+    assert_eq!(record.committed_at_block(), Some(committed_block));
+    assert_eq!(record.committed_timestamp(), Some(1_700_000_100));
+    assert_eq!(record.created_at(), Some(1_700_000_000));
+    assert_eq!(OutputNoteRecord::read_from_bytes(&record.to_bytes()).unwrap(), record);
+
+    // partial notes are committed without ever knowing their recipient
+    let partial_note =
+        PartialNote::new(*note.metadata(), note.recipient().digest(), note.assets().clone());
+    let mut record = OutputNoteRecord::from_partial_note(partial_note, 0);
+    assert!(record.inclusion_proof_received(inclusion_proof, 1_700_000_100).unwrap());
+    assert!(matches!(record.state(), OutputNoteState::CommittedPartial { .. }));
+    assert_eq!(record.created_at(), None);
+    assert_eq!(OutputNoteRecord::read_from_bytes(&record.to_bytes()).unwrap(), record);
+}
+
+#[tokio::test]
+async fn test_output_note_commit_and_consumption_blocks() {
+    let (client, mut rpc_api) = create_test_client().await;
+    let mut client = client.with_time_source(Arc::new(FixedTimeSource(1_700_000_000)));
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let note = match transaction.created_notes().get_note(0) {
+        OutputNote::Full(note) => note.clone(),
+        _ => panic!("the minted note should have its full details"),
+    };
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    let record = client.get_output_note(note.id()).await.unwrap();
+    assert_eq!(record.created_at(), Some(1_700_000_000));
+    assert_eq!(record.expected_height(), sync_height);
+    assert_eq!(record.committed_at_block(), None);
+    assert_eq!(record.committed_timestamp(), None);
+    assert_eq!(record.consumed_at_block(), None);
+
+    // the note is committed in the next block
+    rpc_api.add_block_with_notes(vec![note.clone()]);
+    let commit_block = rpc_api.blocks.last().unwrap().header();
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    client.sync_state().await.unwrap();
+
+    let record = client.get_output_note(note.id()).await.unwrap();
+    assert!(record.is_committed());
+    assert_eq!(record.created_at(), Some(1_700_000_000));
+    assert_eq!(record.committed_at_block(), Some(commit_block.block_num()));
+    assert_eq!(record.committed_timestamp(), Some(commit_block.timestamp().into()));
+    assert_eq!(record.consumed_at_block(), None);
+
+    // and nullified in the one after it, keeping the block in which it was committed
+    rpc_api.add_block_with_nullifiers(vec![note.nullifier()]);
+    let nullifier_block_num = rpc_api.blocks.last().unwrap().header().block_num();
+    *client.rpc_api() = Box::new(rpc_api);
+    client.sync_state().await.unwrap();
+
+    let record = client.get_output_note(note.id()).await.unwrap();
+    assert!(record.is_consumed());
+    assert_eq!(record.created_at(), Some(1_700_000_000));
+    assert_eq!(record.committed_at_block(), Some(commit_block.block_num()));
+    assert_eq!(record.committed_timestamp(), Some(commit_block.timestamp().into()));
+    assert_eq!(record.consumed_at_block(), Some(nullifier_block_num));
+}
+
+#[tokio::test]
+async fn test_update_note_tag() {
+    let (mut client, rpc_api) = create_test_client().await;
+    let note = rpc_api.get_note_at(1).note().clone();
+    let note_tags = |tags: Vec<NoteTagRecord>| -> Vec<NoteTag> {
+        tags.into_iter()
+            .filter(|tag| tag.source == NoteTagSource::Note(note.id()))
+            .map(|tag| tag.tag)
+            .collect()
+    };
+
+    client
+        .import_note(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: 0,
+            tag: Some(NoteTag::from(7)),
+        })
+        .await
+        .unwrap();
+    assert_eq!(note_tags(client.get_note_tags().await.unwrap()), vec![NoteTag::from(7)]);
+
+    client.update_note_tag(note.id(), note.metadata().tag()).await.unwrap();
+    assert_eq!(note_tags(client.get_note_tags().await.unwrap()), vec![note.metadata().tag()]);
+    let record = client.get_input_note(note.id()).await.unwrap();
+    let InputNoteState::Expected(state) = record.state() else {
+        panic!("the note should still be expected");
+    };
+    assert_eq!(state.tag, Some(note.metadata().tag()));
+
+    // once the note is committed, its tag is no longer tracked and can't be updated
+    client.sync_state().await.unwrap();
+    let record = client.get_input_note(note.id()).await.unwrap();
+    assert!(!matches!(record.state(), InputNoteState::Expected(_)));
+    assert!(note_tags(client.get_note_tags().await.unwrap()).is_empty());
+    assert!(matches!(
+        client.update_note_tag(note.id(), NoteTag::from(7)).await,
+        Err(ClientError::NoteNotExpected(note_id)) if note_id == note.id()
+    ));
+
+    let untracked_note = rpc_api.get_note_at(0).note().clone();
+    assert!(client.update_note_tag(untracked_note.id(), NoteTag::from(7)).await.is_err());
+}
+
+/// Returns a P2ID note to a mock account, which is tagged for its target account.
+fn p2id_note_for_tag_checks(client: &mut crate::mock::MockClient) -> Note {
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    create_p2id_note(
+        sender_id,
+        sender_id,
+        vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+        miden_objects::notes::NoteType::Private,
+        Felt::ZERO,
+        client.rng(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_import_note_with_wrong_tag_is_corrected() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let note = p2id_note_for_tag_checks(&mut client);
+    let wrong_tag = NoteTag::from(7);
+
+    let outcome = client
+        .import_note_with_outcome(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: 0,
+            tag: Some(wrong_tag),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome,
+        NoteImportOutcome {
+            note_id: note.id(),
+            tag_correction: Some(NoteTagCorrection {
+                note_id: note.id(),
+                previous_tag: wrong_tag,
+                corrected_tag: note.metadata().tag(),
+            }),
+        }
+    );
+
+    let record = client.get_input_note(note.id()).await.unwrap();
+    let InputNoteState::Expected(state) = record.state() else {
+        panic!("the note should be expected");
+    };
+    assert_eq!(state.tag, Some(note.metadata().tag()));
+    let note_tags: Vec<NoteTag> = client
+        .get_note_tags()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|tag| tag.source == NoteTagSource::Note(note.id()))
+        .map(|tag| tag.tag)
+        .collect();
+    assert_eq!(note_tags, vec![note.metadata().tag()]);
+
+    // Importing the note with its actual tag doesn't report a correction
+    let outcome = client
+        .import_note_with_outcome(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: 0,
+            tag: Some(note.metadata().tag()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(outcome.tag_correction, None);
+}
+
+#[tokio::test]
+async fn test_import_note_with_wrong_tag_strict_policy() {
+    let (client, _rpc_api) = create_test_client().await;
+    let mut client = client.with_note_tag_policy(NoteTagPolicy::Strict);
+    let note = p2id_note_for_tag_checks(&mut client);
+    let initial_tags = client.get_note_tags().await.unwrap();
+
+    let result = client
+        .import_note(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: 0,
+            tag: Some(NoteTag::from(7)),
+        })
+        .await;
+    assert!(matches!(
+        result,
+        Err(ClientError::NoteTagMismatch { provided, derived })
+            if provided == NoteTag::from(7) && derived == note.metadata().tag()
+    ));
+    assert!(client.get_input_note(note.id()).await.is_err());
+    assert_eq!(client.get_note_tags().await.unwrap(), initial_tags);
+
+    // The note is imported if its tag matches
+    client
+        .import_note(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: 0,
+            tag: Some(note.metadata().tag()),
+        })
+        .await
+        .unwrap();
+    assert!(client.get_input_note(note.id()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_sync_corrects_tag_of_note_committed_with_another_tag() {
+    let (mut client, rpc_api) = create_test_client().await;
+    // The tag of the mock note can't be derived from its details, so it's imported as provided
+    let note = rpc_api.get_note_at(1).note().clone();
+    let wrong_tag = NoteTag::from(7);
+    assert_ne!(note.metadata().tag(), wrong_tag);
+
+    let outcome = client
+        .import_note_with_outcome(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: 0,
+            tag: Some(wrong_tag),
+        })
+        .await
+        .unwrap();
+    assert_eq!(outcome.tag_correction, None);
+
+    let summary = client.sync_state().await.unwrap();
+    assert!(summary.committed_notes.contains(&note.id()));
+    assert_eq!(
+        summary.tag_corrections,
+        vec![NoteTagCorrection {
+            note_id: note.id(),
+            previous_tag: wrong_tag,
+            corrected_tag: note.metadata().tag(),
+        }]
+    );
+    assert!(!client
+        .get_note_tags()
+        .await
+        .unwrap()
+        .iter()
+        .any(|tag| tag.source == NoteTagSource::Note(note.id())));
+}
+
+#[tokio::test]
+async fn test_future_note_resolutions() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    client.sync_state().await.unwrap();
+
+    let build_note = |seed: u64| {
+        NoteBuilder::new(wallet.id(), RpoRandomCoin::new([Felt::new(seed); 4]))
+            .build(&TransactionKernel::testing_assembler())
+            .unwrap()
+    };
+    let recalled_note = build_note(1);
+    let taken_note = build_note(2);
+    let recalled_payback = build_note(3);
+    let taken_payback = build_note(4);
+    let tag = NoteTag::from(7);
+    let expected_payback = |note: &Note| {
+        InputNoteRecord::new(
+            note.clone().into(),
+            None,
+            ExpectedNoteState {
+                metadata: None,
+                after_block_num: 0,
+                tag: Some(tag),
+            }
+            .into(),
+        )
+    };
+
+    // the notes are linked to their paybacks as the swap notes of the wallet would be
+    let transaction = execute_mint_transaction(&mut client).await;
+    let (mut faucet, _seed) = client
+        .get_account(transaction.executed_transaction().account_id())
+        .await
+        .unwrap();
+    faucet.apply_delta(transaction.account_delta()).unwrap();
+    let created_input_notes =
+        vec![expected_payback(&recalled_payback), expected_payback(&taken_payback)];
+    let new_tags = created_input_notes
+        .iter()
+        .filter_map(NoteTagRecord::for_expected_note)
+        .collect();
+    let store_update = TransactionStoreUpdate::new(
+        transaction.executed_transaction().clone(),
+        faucet,
+        created_input_notes,
+        vec![
+            OutputNoteRecord::from_full_note(recalled_note.clone(), 0),
+            OutputNoteRecord::from_full_note(taken_note.clone(), 0),
+        ],
+        vec![],
+        new_tags,
+    )
+    .with_future_note_links(vec![
+        FutureNoteLink::new(recalled_note.id(), recalled_payback.id(), wallet.id()),
+        FutureNoteLink::new(taken_note.id(), taken_payback.id(), wallet.id()),
+    ]);
+    client.store.apply_transaction(store_update).await.unwrap();
+    assert_eq!(client.store.get_future_note_links().await.unwrap().len(), 2);
+
+    // the wallet consumes the first note itself, while the second one is consumed by another
+    // account in the same block
+    let transaction_request =
+        TransactionRequest::new().with_unauthenticated_input_notes([(recalled_note.clone(), None)]);
+    let transaction = client.new_transaction(wallet.id(), transaction_request).await.unwrap();
+    let transaction_id = transaction.executed_transaction().id();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    rpc_api.add_block_with_nullifiers(vec![recalled_note.nullifier(), taken_note.nullifier()]);
+    let block_num = rpc_api.blocks.last().unwrap().header().block_num();
+    rpc_api.committed_transactions = vec![TransactionUpdate {
+        transaction_id,
+        block_num,
+        account_id: wallet.id(),
+    }];
+    *client.rpc_api() = Box::new(rpc_api);
+
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.future_note_resolutions.len(), 2);
+    assert!(summary.future_note_resolutions.contains(&FutureNoteResolution::Cancelled {
+        source_note_id: recalled_note.id(),
+        future_note_id: recalled_payback.id(),
+    }));
+    assert!(summary.future_note_resolutions.contains(&FutureNoteResolution::Promoted {
+        source_note_id: taken_note.id(),
+        future_note_id: taken_payback.id(),
+        block_num,
+    }));
+    assert!(client.store.get_future_note_links().await.unwrap().is_empty());
+
+    // the recalled payback will never be created, so it stops being tracked along with its tag
+    assert!(client.get_input_note(recalled_payback.id()).await.is_err());
+    let tags = client.get_note_tags().await.unwrap();
+    assert!(!tags.contains(&NoteTagRecord::with_note_source(tag, recalled_payback.id())));
+    assert!(tags.contains(&NoteTagRecord::with_note_source(tag, taken_payback.id())));
+
+    // the other one was created along with the consumption of its swap note
+    let promoted_payback = client.get_input_note(taken_payback.id()).await.unwrap();
+    match promoted_payback.state() {
+        InputNoteState::Expected(state) => assert_eq!(state.after_block_num, block_num - 1),
+        state => panic!("the payback should still be expected, got {state}"),
+    }
+}
+
+#[tokio::test]
+async fn test_import_note_validation() {
+    // generate test client
+    let (mut client, rpc_api) = create_test_client().await;
+
+    // generate test data
+    let committed_note: InputNoteRecord = rpc_api.get_note_at(0).into();
+    let expected_note: InputNoteRecord = rpc_api.get_note_at(1).note().clone().into();
+
+    client
+        .import_note(NoteFile::NoteDetails {
+            details: committed_note.clone().into(),
+            after_block_num: 0,
+            tag: None,
+        })
+        .await
+        .unwrap();
+    assert!(client.import_note(NoteFile::NoteId(expected_note.id())).await.is_err());
+    client
+        .import_note(NoteFile::NoteDetails {
+            details: expected_note.clone().into(),
+            after_block_num: 0,
+            tag: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(expected_note.inclusion_proof().is_none());
+    assert!(committed_note.inclusion_proof().is_some());
+}
+
+#[tokio::test]
+async fn test_transaction_request_expiration() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let current_height = client.get_sync_height().await.unwrap();
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    let transaction_request =
+        mint_request(&mut client, faucet.id(), 5).with_expiration_delta(5).unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+
+    let (_, tx_outputs, ..) = transaction.executed_transaction().clone().into_parts();
+
+    assert_eq!(tx_outputs.expiration_block_num, current_height + 5);
+}
+
+#[tokio::test]
+async fn test_min_note_value_rejects_dust_notes() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    client.sync_state().await.unwrap();
+
+    let asset = FungibleAsset::mock(20).unwrap_fungible();
+    let note = NoteBuilder::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+        RpoRandomCoin::new([Felt::new(7); 4]),
+    )
+    .add_assets([Asset::Fungible(asset)])
+    .build(&TransactionKernel::testing_assembler())
+    .unwrap();
+
+    let transaction_request = TransactionRequest::new()
+        .with_unauthenticated_input_notes([(note.clone(), None)])
+        .with_min_note_value(asset.faucet_id(), asset.amount() + 1);
+    assert_eq!(
+        TransactionRequest::read_from_bytes(&transaction_request.to_bytes()).unwrap(),
+        transaction_request
+    );
+
+    match client.new_transaction(wallet.id(), transaction_request).await {
+        Err(ClientError::TransactionRequestError(
+            TransactionRequestError::NoteValueBelowMinimum {
+                note_id,
+                faucet_id,
+                amount,
+                min_amount,
+            },
+        )) => {
+            assert_eq!(note_id, note.id());
+            assert_eq!(faucet_id, asset.faucet_id());
+            assert_eq!(amount, asset.amount());
+            assert_eq!(min_amount, asset.amount() + 1);
+        },
+        result => panic!("expected the note to be rejected, got {:?}", result.map(|_| ())),
+    }
+
+    // The note was rejected before anything was written to the store
+    assert!(client.get_input_note(note.id()).await.is_err());
+
+    // Notes holding the minimum amount pass the check
+    let transaction_request = TransactionRequest::new()
+        .with_unauthenticated_input_notes([(note.clone(), None)])
+        .with_min_note_value(asset.faucet_id(), asset.amount());
+    let result = client.new_transaction(wallet.id(), transaction_request).await;
+    assert!(!matches!(
+        result,
+        Err(ClientError::TransactionRequestError(
+            TransactionRequestError::NoteValueBelowMinimum { .. }
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn test_custom_script_named_args() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let first: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let second: Word = [Felt::new(10), Felt::new(20), Felt::new(30), Felt::new(40)];
+    let script = |expected_sum: u64| {
+        format!(
+            "
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+                exec.arg_first exec.arg_second
+                # => [SECOND, FIRST]
+
+                add add add add add add add
+                push.{expected_sum} assert_eq
+
+                call.auth_tx::auth_tx_rpo_falcon512
+            end
+            "
+        )
+    };
+    let args = BTreeMap::from([
+        ("first".to_string(), ScriptArg::Word(first)),
+        ("second".to_string(), ScriptArg::Word(second)),
+    ]);
+
+    let transaction_request = TransactionRequest::new()
+        .with_custom_script_args(&script(110), args.clone())
+        .unwrap();
+    assert_eq!(
+        transaction_request.advice_map().get(&script_arg_key("second")),
+        Some(second.as_slice())
+    );
+    client.new_transaction(wallet.id(), transaction_request).await.unwrap();
+
+    // The script fails if the arguments don't hold the expected values
+    let transaction_request = TransactionRequest::new()
+        .with_custom_script_args(&script(111), args.clone())
+        .unwrap();
+    assert!(client.new_transaction(wallet.id(), transaction_request).await.is_err());
+
+    // Misspelled and missing arguments are rejected before the script is compiled
+    let mut misspelled_args = args.clone();
+    let value = misspelled_args.remove("second").unwrap();
+    misspelled_args.insert("secnod".to_string(), value);
+    assert!(matches!(
+        TransactionRequest::new().with_custom_script_args(&script(110), misspelled_args),
+        Err(TransactionRequestError::MissingScriptArg(name)) if name == "second"
+    ));
+
+    let mut extra_args = args.clone();
+    extra_args.insert("third".to_string(), ScriptArg::Felt(Felt::new(5)));
+    assert!(matches!(
+        TransactionRequest::new().with_custom_script_args(&script(110), extra_args),
+        Err(TransactionRequestError::UnusedScriptArg(name)) if name == "third"
+    ));
+}
+
+#[tokio::test]
+async fn test_validate_request_verbose() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    // The new account has no assets, so both payments are short
+    let assets = vec![
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap(), 100)
+            .unwrap()
+            .into(),
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().unwrap(), 50)
+            .unwrap()
+            .into(),
+    ];
+    let transaction_request = TransactionRequest::pay_to_id(
+        PaymentTransactionData::new(
+            assets,
+            account.id(),
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.try_into().unwrap(),
+        ),
+        None,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let fast_error = client.validate_request(account.id(), &transaction_request).await.unwrap_err();
+    let failures = client
+        .validate_request_verbose(account.id(), &transaction_request)
+        .await
+        .unwrap_err();
+
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].to_string(), fast_error.to_string());
+    assert!(failures.iter().all(|failure| matches!(
+        failure,
+        ClientError::AssetError(miden_objects::AssetError::AssetAmountNotSufficient(0, _))
+    )));
+}
+
+#[tokio::test]
+async fn test_validate_request_aggregates_payments() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap();
+    let payments = [
+        (ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, 100),
+        (ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, 50),
+    ]
+    .into_iter()
+    .map(|(target_id, amount)| {
+        PaymentTransactionData::new(
+            vec![FungibleAsset::new(faucet_id, amount).unwrap().into()],
+            account.id(),
+            target_id.try_into().unwrap(),
+        )
+    })
+    .collect();
+    let transaction_request = TransactionRequest::pay_to_ids(
+        payments,
+        None,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    assert_eq!(transaction_request.expected_output_notes().count(), 2);
+
+    // The amounts sent to both targets are checked against the balance as a whole
+    let failures = client
+        .validate_request_verbose(account.id(), &transaction_request)
+        .await
+        .unwrap_err();
+    assert_eq!(failures.len(), 1);
+    assert!(matches!(
+        failures[0],
+        ClientError::AssetError(miden_objects::AssetError::AssetAmountNotSufficient(0, 150))
+    ));
+}
+
+#[tokio::test]
+async fn test_pay_to_id_with_memo() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let sender = ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN.try_into().unwrap();
+    let target = ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.try_into().unwrap();
+    let asset: Asset =
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap(), 100)
+            .unwrap()
+            .into();
+
+    let memo = b"invoice #42".to_vec();
+    let payment_data = PaymentTransactionData::new(vec![asset], sender, target)
+        .with_memo(memo.clone())
+        .unwrap();
+    let transaction_request = TransactionRequest::pay_to_id(
+        payment_data.clone(),
+        None,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let note = transaction_request.expected_output_notes().next().unwrap();
+    assert_eq!(read_memo(note.recipient()), Some(memo));
+    assert_eq!(note.inputs().values()[0], target.into());
+
+    // memos can't be combined with a recall height
+    assert!(TransactionRequest::pay_to_id(
+        payment_data,
+        Some(100),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .is_err());
+
+    // the memo must fit in the note inputs
+    let error = PaymentTransactionData::new(vec![asset], sender, target)
+        .with_memo(vec![0; MAX_MEMO_LEN + 1])
+        .unwrap_err();
+    assert!(matches!(error, TransactionRequestError::MemoTooLarge(len) if len == MAX_MEMO_LEN + 1));
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_faucet_issuance_tracks_local_mints() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    for amount in [5u64, 7] {
+        let transaction_request = mint_request(&mut client, faucet.id(), amount);
+        let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+        client.submit_transaction(transaction).await.unwrap();
+    }
+
+    let issuance = client.get_faucet_issuance(faucet.id()).await.unwrap();
+    assert_eq!(issuance.minted_locally, 12);
+    assert_eq!(issuance.mint_transactions, 2);
+    assert_eq!(issuance.storage_issuance, 12);
+    assert_eq!(issuance.max_supply, 10000);
+    assert_eq!(issuance.remaining(), 9988);
+    assert!(!issuance.has_diverged());
+    assert!(!issuance.is_near_max_supply(ISSUANCE_WARNING_THRESHOLD_PERCENT));
+
+    // only fungible faucets have an issuance
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    assert!(client.get_faucet_issuance(wallet.id()).await.is_err());
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_faucet_issuance_divergence_after_sync() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Public).await;
+
+    let transaction_request = mint_request(&mut client, faucet.id(), 5);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    client.submit_transaction(transaction).await.unwrap();
+
+    // another instance of the faucet mints tokens and the update is reported by the node
+    let transaction_request = mint_request(&mut client, faucet.id(), 100);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let (mut remote_faucet, _seed) = client.get_account(faucet.id()).await.unwrap();
+    remote_faucet.apply_delta(transaction.account_delta()).unwrap();
+
+    rpc_api.add_block_with_account_update(remote_faucet);
+    *client.rpc_api() = Box::new(rpc_api);
+    client.sync_state().await.unwrap();
+
+    let issuance = client.get_faucet_issuance(faucet.id()).await.unwrap();
+    assert_eq!(issuance.minted_locally, 5);
+    assert_eq!(issuance.mint_transactions, 1);
+    assert_eq!(issuance.storage_issuance, 105);
+    assert!(issuance.has_diverged());
+}
+
+#[tokio::test]
+async fn test_sync_skips_faucets_with_unreadable_issuance() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    // A fungible faucet without the metadata slot of basic fungible faucets
+    let mock_faucet = Account::mock(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN,
+        Felt::new(2),
+        TransactionKernel::testing_assembler(),
+    );
+    let faucet = Account::from_parts(
+        mock_faucet.id(),
+        mock_faucet.vault().clone(),
+        AccountStorage::new(vec![StorageSlot::Value(Word::default())]).unwrap(),
+        mock_faucet.code().clone(),
+        mock_faucet.nonce(),
+    );
+    client
+        .insert_account(&faucet, None, &AuthSecretKey::RpoFalcon512(SecretKey::new()))
+        .await
+        .unwrap();
+
+    assert!(client.get_faucet_issuance(faucet.id()).await.is_err());
+    client.sync_state().await.unwrap();
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_watched_storage_slot_changes_are_reported_on_sync() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Public).await;
+
+    // the issuance of the faucet is kept in its first slot, which holds a value and not a map
+    assert!(matches!(
+        client.watch_storage_slot(faucet.id(), 0, Some(Word::default())).await,
+        Err(ClientError::InvalidStorageSlotWatch { .. })
+    ));
+    assert!(matches!(
+        client.watch_storage_slot(faucet.id(), 200, None).await,
+        Err(ClientError::InvalidStorageSlotWatch { .. })
+    ));
+    client.watch_storage_slot(faucet.id(), 0, None).await.unwrap();
+    client.watch_storage_slot(faucet.id(), 0, None).await.unwrap();
+    assert_eq!(client.get_storage_slot_watches().await.unwrap().len(), 1);
+
+    // another instance of the faucet mints tokens and the update is reported by the node
+    let transaction_request = mint_request(&mut client, faucet.id(), 100);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let mut remote_faucet = faucet.clone();
+    remote_faucet.apply_delta(transaction.account_delta()).unwrap();
+
+    rpc_api.add_block_with_account_update(remote_faucet.clone());
+    *client.rpc_api() = Box::new(rpc_api);
+    let summary = client.sync_state().await.unwrap();
+
+    let expected_change = StorageSlotChange {
+        account_id: faucet.id(),
+        slot_index: 0,
+        key: None,
+        old: faucet.storage().get_item(0).unwrap().into(),
+        new: remote_faucet.storage().get_item(0).unwrap().into(),
+        block_num: summary.block_num,
+    };
+    assert_ne!(expected_change.old, expected_change.new);
+    assert_eq!(summary.storage_changes, vec![expected_change]);
+
+    client.unwatch_storage_slot(faucet.id(), 0, None).await.unwrap();
+    assert!(client.get_storage_slot_watches().await.unwrap().is_empty());
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_watch_account_storage_invokes_callbacks() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Public).await;
+
+    let received_changes = Arc::new(std::sync::Mutex::new(vec![]));
+    let callback_changes = received_changes.clone();
+    client
+        .watch_account_storage(faucet.id(), 0, move |change: &StorageSlotChange| {
+            callback_changes.lock().unwrap().push((change.old, change.new));
+        })
+        .await
+        .unwrap();
+    assert!(matches!(
+        client.watch_account_storage(faucet.id(), 200, |_: &StorageSlotChange| {}).await,
+        Err(ClientError::InvalidStorageSlotWatch { .. })
+    ));
+
+    // another instance of the faucet mints tokens and the update is reported by the node
+    let transaction_request = mint_request(&mut client, faucet.id(), 100);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let mut remote_faucet = faucet.clone();
+    remote_faucet.apply_delta(transaction.account_delta()).unwrap();
+
+    rpc_api.add_block_with_account_update(remote_faucet.clone());
+    *client.rpc_api() = Box::new(rpc_api);
+    client.sync_state().await.unwrap();
+
+    let old: Word = faucet.storage().get_item(0).unwrap().into();
+    let new: Word = remote_faucet.storage().get_item(0).unwrap().into();
+    assert_eq!(*received_changes.lock().unwrap(), vec![(old, new)]);
+
+    // unwatching the slot drops its callbacks
+    client.unwatch_storage_slot(faucet.id(), 0, None).await.unwrap();
+    assert!(client.storage_change_callbacks.is_empty());
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_faucet_issuance_warning_threshold() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    let transaction_request = mint_request(&mut client, faucet.id(), 9200);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    client.submit_transaction(transaction).await.unwrap();
+
+    let issuance = client.get_faucet_issuance(faucet.id()).await.unwrap();
+    assert_eq!(issuance.remaining(), 800);
+    assert!(issuance.is_near_max_supply(ISSUANCE_WARNING_THRESHOLD_PERCENT));
+    assert!(!issuance.is_near_max_supply(95));
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_import_public_account_checks_node_commitment() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Public).await;
+
+    let transaction_request = mint_request(&mut client, faucet.id(), 5);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    client.submit_transaction(transaction).await.unwrap();
+    let (stale_faucet, _seed) = client.get_account(faucet.id()).await.unwrap();
+    let auth = client.get_account_auth(faucet.id()).await.unwrap();
+
+    // the faucet keeps being used, so the node's state moves past the exported one
+    let transaction_request = mint_request(&mut client, faucet.id(), 10);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let mut current_faucet = stale_faucet.clone();
+    current_faucet.apply_delta(transaction.account_delta()).unwrap();
+    rpc_api.add_block_with_account_update(current_faucet.clone());
+
+    let mut importing_client = create_test_client_with_rpc(rpc_api.clone()).await;
+    let stale_data = AccountData::new(stale_faucet.clone(), None, auth.clone());
+    assert!(matches!(
+        importing_client.import_account(stale_data, false).await,
+        Err(ClientError::ImportedAccountStateMismatch { account_id, node_hash, .. })
+            if account_id == faucet.id() && node_hash == current_faucet.hash()
+    ));
+    assert!(importing_client.get_account_headers().await.unwrap().is_empty());
+
+    let current_data = AccountData::new(current_faucet.clone(), None, auth.clone());
+    importing_client.import_account(current_data, false).await.unwrap();
+    let (imported_faucet, _seed) = importing_client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(imported_faucet.hash(), current_faucet.hash());
+
+    // forcing the import tracks the stale state anyway
+    let mut forcing_client = create_test_client_with_rpc(rpc_api).await;
+    let stale_data = AccountData::new(stale_faucet.clone(), None, auth);
+    forcing_client.import_account(stale_data, true).await.unwrap();
+    let (imported_faucet, _seed) = forcing_client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(imported_faucet.hash(), stale_faucet.hash());
+}
+
+#[tokio::test]
+async fn test_diverged_private_account_is_locked_until_unlocked() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    // another instance of the faucet updates it and the node reports the new commitment
+    let transaction_request = mint_request(&mut client, faucet.id(), 5);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let mut remote_faucet = faucet.clone();
+    remote_faucet.apply_delta(transaction.account_delta()).unwrap();
+
+    rpc_api.add_block_with_account_update(remote_faucet.clone());
+    *client.rpc_api() = Box::new(rpc_api);
+    client.sync_state().await.unwrap();
+
+    assert_eq!(
+        client.get_account_lock_status(faucet.id()).await.unwrap(),
+        AccountLockStatus::Locked { remote_hash: remote_faucet.hash() }
+    );
+
+    let transaction_request = mint_request(&mut client, faucet.id(), 5);
+    assert!(matches!(
+        client.new_transaction(faucet.id(), transaction_request).await,
+        Err(ClientError::AccountLocked(_))
+    ));
+
+    let diagnosis = client.diagnose_locked_account(faucet.id()).await.unwrap();
+    assert_eq!(diagnosis.local_hash, faucet.hash());
+    assert_eq!(diagnosis.remote_hash, remote_faucet.hash());
+    assert!(diagnosis.pending_transactions.is_empty());
+    assert!(!diagnosis.explained_by_pending_transactions);
+
+    // importing a state that doesn't match the node's commitment is rejected
+    let auth = client.get_account_auth(faucet.id()).await.unwrap();
+    let stale_state = AccountData::new(faucet.clone(), Some(seed), auth.clone());
+    assert!(matches!(
+        client
+            .unlock_account(faucet.id(), UnlockStrategy::ImportState(stale_state))
+            .await,
+        Err(ClientError::AccountUnlockError(_))
+    ));
+
+    let remote_state = AccountData::new(remote_faucet.clone(), None, auth);
+    client
+        .unlock_account(faucet.id(), UnlockStrategy::ImportState(remote_state))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        client.get_account_lock_status(faucet.id()).await.unwrap(),
+        AccountLockStatus::Unlocked
+    );
+    let (local_faucet, _seed) = client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(local_faucet.hash(), remote_faucet.hash());
+
+    let unlock_records = client.get_account_unlock_records(faucet.id()).await.unwrap();
+    assert_eq!(unlock_records.len(), 1);
+    assert_eq!(unlock_records[0].strategy, UnlockStrategyKind::ImportState);
+    assert_eq!(unlock_records[0].local_hash, faucet.hash());
+    assert_eq!(unlock_records[0].remote_hash, remote_faucet.hash());
+
+    assert!(matches!(
+        client.unlock_account(faucet.id(), UnlockStrategy::ForceKeepLocal).await,
+        Err(ClientError::AccountNotLocked(_))
+    ));
+}
+
+/// Time source that always returns the same timestamp.
+struct FixedTimeSource(u64);
+
+impl TimeSource for FixedTimeSource {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+#[tokio::test]
+async fn test_client_time_source() {
+    let (client, _rpc_api) = create_test_client().await;
+
+    // the system clock is used by default under `std`
+    assert!(client.current_timestamp().is_some_and(|timestamp| timestamp > 0));
+
+    let client = client.with_time_source(Arc::new(FixedTimeSource(1_700_000_000)));
+    assert_eq!(client.current_timestamp(), Some(1_700_000_000));
+}
+
+#[tokio::test]
+async fn test_verify_account_integrity() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let transaction = execute_mint_transaction(&mut client).await;
+    let faucet_id = transaction.executed_transaction().account_id();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    let report = client.verify_account_integrity(faucet_id).await.unwrap();
+    assert!(report.is_intact());
+    assert!(report.current_state_matches());
+    assert_eq!(report.states_checked, 2);
+    assert_eq!(report.verified_transitions, 1);
+    assert_eq!(report.unverified_transitions, 0);
+
+    // the state stored after a transaction is not the one the transaction produced
+    let (mut client, _rpc_api) = create_test_client().await;
+    let transaction = execute_mint_transaction(&mut client).await;
+    let faucet_id = transaction.executed_transaction().account_id();
+    let (mut stored_faucet, _seed) = client.get_account(faucet_id).await.unwrap();
+    let other_transaction = client
+        .new_transaction(faucet_id, mint_request(&mut client, faucet_id, 100))
+        .await
+        .unwrap();
+    stored_faucet.apply_delta(other_transaction.account_delta()).unwrap();
+
+    let store_update = TransactionStoreUpdate::new(
+        transaction.executed_transaction().clone(),
+        stored_faucet.clone(),
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    );
+    client.store.apply_transaction(store_update).await.unwrap();
+
+    let report = client.verify_account_integrity(faucet_id).await.unwrap();
+    assert!(!report.is_intact());
+    assert!(report.current_state_matches());
+    assert_eq!(
+        report.first_divergent_transaction(),
+        Some(transaction.executed_transaction().id())
+    );
+    assert_eq!(
+        report.issues,
+        vec![AccountIntegrityIssue::DivergentTransaction {
+            transaction_id: transaction.executed_transaction().id(),
+            expected_hash: transaction.executed_transaction().final_account().hash(),
+            stored_hash: stored_faucet.hash(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_external_signing_requests() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    client.sync_state().await.unwrap();
+
+    let transaction_request = mint_request(&mut client, faucet.id(), 5);
+
+    let signing_requests =
+        client.signing_requests(faucet.id(), &transaction_request).await.unwrap();
+    assert_eq!(signing_requests.len(), 1);
+    let signing_request = &signing_requests[0];
+
+    let serialized = signing_request.to_bytes();
+    assert_eq!(&SigningRequest::read_from_bytes(&serialized).unwrap(), signing_request);
+
+    let AuthSecretKey::RpoFalcon512(secret_key) =
+        client.get_account_auth(faucet.id()).await.unwrap();
+    assert_eq!(signing_request.pub_key, Word::from(secret_key.public_key()));
+
+    // the signature is produced away from the client and provided with the request
+    let other_key = AuthSecretKey::RpoFalcon512(SecretKey::new());
+    assert!(signing_request.sign(&other_key, client.rng()).is_err());
+    let signature = signing_request
+        .sign(&AuthSecretKey::RpoFalcon512(secret_key), client.rng())
+        .unwrap();
+    let signed_request = transaction_request.with_signature(signing_request, signature.clone());
+
+    let transaction = client.new_transaction(faucet.id(), signed_request).await.unwrap();
+    assert_eq!(
+        transaction
+            .executed_transaction()
+            .advice_witness()
+            .mapped_values(&signing_request.signature_key()),
+        Some(signature.as_slice())
+    );
+}
+
+#[tokio::test]
+async fn test_swap_payback_tag_is_tracked() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    let swap_data = SwapTransactionData::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        FungibleAsset::new(faucet.id(), 5).unwrap().into(),
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().unwrap(), 10)
+            .unwrap()
+            .into(),
+    );
+    let swap_tag = swap_data.swap_tag(miden_objects::notes::NoteType::Private).unwrap();
+    let swap_request = TransactionRequest::swap(
+        swap_data.with_payback_tag(swap_tag),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    let future_notes: Vec<_> = swap_request.expected_future_notes().cloned().collect();
+    let payback_note_id = future_notes[0].0.id();
+
+    // executing the swap needs a funded wallet, so its expected payback note is attached to a mint
+    // transaction instead
+    let transaction_request =
+        mint_request(&mut client, faucet.id(), 5).with_expected_future_notes(future_notes);
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    let payback_tags: Vec<NoteTagRecord> = client
+        .get_note_tags()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|tag| tag.source == NoteTagSource::Note(payback_note_id))
+        .collect();
+    assert_eq!(payback_tags.len(), 1);
+    assert_eq!(payback_tags[0].tag, swap_tag);
+}
+
+/// Derives `count` distinct notes from `base_note` by changing their serial numbers.
+fn derive_notes(base_note: &Note, count: u64) -> Vec<Note> {
+    (0..count)
+        .map(|i| {
+            let recipient = NoteRecipient::new(
+                [Felt::new(i), Felt::ONE, Felt::ZERO, Felt::ZERO],
+                base_note.script().clone(),
+                base_note.inputs().clone(),
+            );
+            Note::new(base_note.assets().clone(), *base_note.metadata(), recipient)
+        })
+        .collect()
+}
+
+/// Creates a new fungible faucet with the given storage mode.
+async fn create_test_faucet(
+    client: &mut crate::mock::MockClient,
+    storage_mode: AccountStorageMode,
+) -> (Account, Word) {
+    client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode,
+        })
+        .await
+        .unwrap()
+}
+
+/// Builds a request that mints `amount` tokens of `faucet_id` into a private note for a fixed
+/// mock account.
+fn mint_request(
+    client: &mut crate::mock::MockClient,
+    faucet_id: AccountId,
+    amount: u64,
+) -> TransactionRequest {
+    mint_request_to(client, faucet_id, amount, AccountId::from_hex("0x168187d729b31a84").unwrap())
+}
+
+/// Builds a request that mints `amount` tokens of `faucet_id` into a private note for `target`.
+fn mint_request_to(
+    client: &mut crate::mock::MockClient,
+    faucet_id: AccountId,
+    amount: u64,
+    target: AccountId,
+) -> TransactionRequest {
+    TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet_id, amount).unwrap(),
+        target,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap()
+}
+
+/// Creates a new private fungible faucet and executes a mint transaction against it, without
+/// submitting it.
+async fn execute_mint_transaction(client: &mut crate::mock::MockClient) -> TransactionResult {
+    let (faucet, _seed) = create_test_faucet(client, AccountStorageMode::Private).await;
+
+    let transaction_request = mint_request(client, faucet.id(), 5);
+
+    client.new_transaction(faucet.id(), transaction_request).await.unwrap()
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_prover_pool_proofs_verify() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let mut transactions = vec![];
+    for _ in 0..2 {
+        transactions.push(execute_mint_transaction(&mut client).await);
+    }
+
+    let prover_pool = ProverPool::new(ProverPoolConfig { parallelism: 2, ..Default::default() });
+    assert_eq!(prover_pool.parallelism(), 2);
+
+    let proofs =
+        join_all(transactions.iter().map(|transaction| {
+            prover_pool.prove(transaction.executed_transaction().clone().into())
+        }))
+        .await;
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    for (transaction, proof) in transactions.iter().zip(proofs) {
+        let proven_transaction = proof.unwrap();
+        assert_eq!(proven_transaction.id(), transaction.executed_transaction().id());
+        verifier.verify(proven_transaction).unwrap();
+    }
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_submit_transactions() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let mut transactions = vec![];
+    for _ in 0..2 {
+        transactions.push(execute_mint_transaction(&mut client).await);
+    }
+    let transaction_ids: Vec<_> = transactions
+        .iter()
+        .map(|transaction| transaction.executed_transaction().id())
+        .collect();
+
+    client.submit_transactions(transactions).await.unwrap();
+
+    let stored_ids: Vec<_> = client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|transaction| transaction.id)
+        .collect();
+    assert!(transaction_ids.iter().all(|id| stored_ids.contains(id)));
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_submitted_transaction_provenance() {
+    let (client, _rpc_api) = create_test_client().await;
+    let mut client = client.with_prover_description("local");
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    client.submit_transaction(transaction).await.unwrap();
+
+    let transaction = client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|transaction| transaction.id == transaction_id)
+        .unwrap();
+
+    let provenance = transaction.provenance().unwrap();
+    assert_eq!(provenance.rpc_endpoint, "mock");
+    assert_eq!(provenance.client_version, CLIENT_VERSION);
+    assert_eq!(provenance.prover.as_deref(), Some("local"));
+    assert!(provenance.submitted_on_first_attempt());
+
+    let serialized = provenance.to_bytes();
+    assert_eq!(&TransactionProvenance::read_from_bytes(&serialized).unwrap(), provenance);
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_client_with_prover() {
+    let (client, _rpc_api) = create_test_client().await;
+    let prover: Arc<dyn TransactionProver> =
+        Arc::new(ProverPool::new(ProverPoolConfig { parallelism: 2, ..Default::default() }));
+    let mut client = client.with_prover_description("local").with_prover(prover.clone());
+    assert!(Arc::ptr_eq(&client.prover(), &prover));
+    // The description of the replaced prover is dropped
+    assert_eq!(client.prover_description(), None);
+
+    client = client.with_prover_description("pool");
+    assert_eq!(client.prover_description(), Some("pool"));
+    client.sync_state().await.unwrap();
+
+    // Submitted transactions are proven with the configured prover
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    client.submit_transaction(transaction).await.unwrap();
+
+    let transaction = client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|transaction| transaction.id == transaction_id)
+        .unwrap();
+    assert_eq!(transaction.provenance().unwrap().prover.as_deref(), Some("pool"));
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_retried_submission_resubmits_lost_transaction() {
+    let mut rpc_api = MockRpcApi::new();
+    rpc_api.failing_submissions = 1;
+    let mut client = create_test_client_with_rpc(rpc_api).await;
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    client.submit_transaction(transaction.clone()).await.unwrap_err();
+    assert_eq!(client.get_submission_attempts(transaction_id).await.unwrap(), 1);
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+
+    // The node doesn't have the transaction, so the retry submits it again
+    client.submit_transaction(transaction).await.unwrap();
+    let transaction = client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|transaction| transaction.id == transaction_id)
+        .unwrap();
+    assert_eq!(transaction.provenance().unwrap().submission_attempts, 2);
+    assert_eq!(client.get_submission_attempts(transaction_id).await.unwrap(), 0);
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_retried_submission_detects_transaction_on_node() {
+    let store_config: SqliteStoreConfig =
+        create_test_store_path().to_string_lossy().to_string().try_into().unwrap();
+    let store = Arc::new(SqliteStore::new(&store_config).await.unwrap());
+    let mut rpc_api = MockRpcApi::new();
+    rpc_api.failing_submissions = 1;
+
+    let mut client = create_test_client_with_store(rpc_api.clone(), store.clone());
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    client.submit_transaction(transaction.clone()).await.unwrap_err();
+
+    // The submission timed out after reaching the node, which now has the resulting state
+    let account_id = transaction.executed_transaction().account_id();
+    let (mut account, _) = client.get_account(account_id).await.unwrap();
+    account.apply_delta(transaction.account_delta()).unwrap();
+    rpc_api.updated_accounts.insert(account_id, account);
+
+    // A resubmission would fail, so the retry only succeeds if it isn't submitted again
+    let mut client = create_test_client_with_store(rpc_api, store);
+    client.submit_transaction(transaction).await.unwrap();
+
+    let transaction = client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|transaction| transaction.id == transaction_id)
+        .unwrap();
+    assert_eq!(transaction.provenance().unwrap().submission_attempts, 1);
+    assert_eq!(client.get_submission_attempts(transaction_id).await.unwrap(), 0);
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_retried_submission_fails_if_node_state_is_unknown() {
+    let store_config: SqliteStoreConfig =
+        create_test_store_path().to_string_lossy().to_string().try_into().unwrap();
+    let store = Arc::new(SqliteStore::new(&store_config).await.unwrap());
+    let mut rpc_api = MockRpcApi::new();
+    rpc_api.failing_submissions = 1;
+
+    let mut client = create_test_client_with_store(rpc_api.clone(), store.clone());
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    client.submit_transaction(transaction.clone()).await.unwrap_err();
+
+    // The node fails to report the state of the account, so the transaction may have reached it
+    // and isn't submitted again
+    rpc_api.fail_account_details_requests = true;
+    let mut client = create_test_client_with_store(rpc_api, store);
+    assert!(matches!(
+        client.submit_transaction(transaction).await,
+        Err(ClientError::RpcError(RpcError::RequestError(..)))
+    ));
+    assert_eq!(client.get_submission_attempts(transaction_id).await.unwrap(), 1);
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_remove_account_removes_submission_attempts() {
+    let mut rpc_api = MockRpcApi::new();
+    rpc_api.failing_submissions = 1;
+    let mut client = create_test_client_with_rpc(rpc_api).await;
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    let account_id = transaction.executed_transaction().account_id();
+    client.submit_transaction(transaction).await.unwrap_err();
+    assert_eq!(client.get_submission_attempts(transaction_id).await.unwrap(), 1);
+
+    client.remove_account(account_id, RemoveOptions::default()).await.unwrap();
+    assert_eq!(client.get_submission_attempts(transaction_id).await.unwrap(), 0);
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_pending_transaction_output_note_summaries() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    let created_note = match transaction.created_notes().get_note(0) {
+        OutputNote::Full(note) => note.clone(),
+        _ => panic!("the minted note should have its full details"),
+    };
+    client.submit_transaction(transaction).await.unwrap();
+
+    let transaction = client
+        .get_transactions(TransactionFilter::Uncomitted)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|transaction| transaction.id == transaction_id)
+        .unwrap();
+
+    assert_eq!(
+        transaction.output_note_summaries(),
+        vec![OutputNoteSummary {
+            id: created_note.id(),
+            note_type: NoteType::Private,
+            assets: Some(created_note.assets().clone()),
+            recipient: Some(created_note.recipient().clone()),
+        }]
+    );
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "benchmark, proves several transactions"]
+async fn bench_prover_pool_against_sequential_proving() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let mut witnesses: Vec<TransactionWitness> = vec![];
+    for _ in 0..4 {
+        let transaction = execute_mint_transaction(&mut client).await;
+        witnesses.push(transaction.executed_transaction().clone().into());
+    }
+
+    let sequential_prover = LocalTransactionProver::default();
+    let start = std::time::Instant::now();
+    for witness in witnesses.iter() {
+        sequential_prover.prove(witness.clone()).await.unwrap();
+    }
+    let sequential_time = start.elapsed();
+
+    let prover_pool = ProverPool::new(ProverPoolConfig { parallelism: 4, ..Default::default() });
+    let start = std::time::Instant::now();
+    let proofs = join_all(witnesses.into_iter().map(|witness| prover_pool.prove(witness))).await;
+    let pool_time = start.elapsed();
+
+    assert!(proofs.into_iter().all(|proof| proof.is_ok()));
+    assert!(
+        pool_time < sequential_time,
+        "pool took {pool_time:?} while sequential proving took {sequential_time:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_watch_only_account_export() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (mut watching_client, _rpc_api) = create_test_client().await;
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    assert!(!client.is_watch_only_account(faucet.id()).await.unwrap());
+
+    let exported = client.export_account_watch_only(faucet.id()).await.unwrap();
+    let account_file = WatchOnlyAccountFile::read_from_bytes(&exported).unwrap();
+    assert_eq!(account_file.account, faucet);
+
+    watching_client.import_watch_only_account(account_file).await.unwrap();
+    assert!(watching_client.is_watch_only_account(faucet.id()).await.unwrap());
+
+    let (imported_faucet, _seed) = watching_client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(imported_faucet.hash(), faucet.hash());
+
+    let transaction_request = mint_request(&mut watching_client, faucet.id(), 5);
+    assert!(matches!(
+        watching_client.new_transaction(faucet.id(), transaction_request).await,
+        Err(ClientError::AccountIsWatchOnly(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_can_sign_for() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (mut other_client, _rpc_api) = create_test_client().await;
+
+    let (wallet, wallet_seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: true,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    assert!(client.can_sign_for(wallet.id()).await.unwrap());
+    assert!(client.can_sign_for(faucet.id()).await.unwrap());
+
+    // watch-only accounts have no key
+    let exported = client.export_account_watch_only(faucet.id()).await.unwrap();
+    other_client
+        .import_watch_only_account(WatchOnlyAccountFile::read_from_bytes(&exported).unwrap())
+        .await
+        .unwrap();
+    assert!(!other_client.can_sign_for(faucet.id()).await.unwrap());
+
+    // a key that doesn't match the account's public key can't sign for it
+    other_client
+        .insert_account(&wallet, Some(wallet_seed), &AuthSecretKey::RpoFalcon512(SecretKey::new()))
+        .await
+        .unwrap();
+    assert!(!other_client.can_sign_for(wallet.id()).await.unwrap());
+
+    assert!(matches!(
+        client.can_sign_for(AccountId::from_hex("0x168187d729b31a84").unwrap()).await,
+        Err(ClientError::StoreError(StoreError::AccountDataNotFound(_)))
+    ));
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_rotate_auth_key() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::RotatableWallet {
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let AuthSecretKey::RpoFalcon512(old_key) = client.get_account_auth(wallet.id()).await.unwrap();
+
+    // A note can't replace the key, even though the procedure is part of the account's interface
+    let base_note = rpc_api.get_note_at(0).note().clone();
+    let note_script = client
+        .compile_note_script(&format!(
+            "
+            begin
+                push.{new_pub_key}
+                call.{set_auth_key_root}
+                dropw
+            end
+            ",
+            new_pub_key = prepare_word(&Word::from(SecretKey::new().public_key())),
+            set_auth_key_root = AuthKeyRotation::set_auth_key_root(),
+        ))
+        .unwrap();
+    let recipient = NoteRecipient::new(
+        [Felt::new(7), Felt::ONE, Felt::ZERO, Felt::ZERO],
+        note_script,
+        base_note.inputs().clone(),
+    );
+    let takeover_note =
+        Note::new(NoteAssets::new(vec![]).unwrap(), *base_note.metadata(), recipient);
+    let transaction_request =
+        TransactionRequest::new().with_unauthenticated_input_notes([(takeover_note, None)]);
+    assert!(client.new_transaction(wallet.id(), transaction_request).await.is_err());
+
+    let new_key = SecretKey::new();
+    client
+        .rotate_auth_key(wallet.id(), AuthSecretKey::RpoFalcon512(new_key.clone()))
+        .await
+        .unwrap();
+
+    let (rotated_wallet, _seed) = client.store.get_account(wallet.id()).await.unwrap();
+    assert_eq!(rotated_wallet.nonce(), wallet.nonce() + Felt::ONE);
+    assert_eq!(
+        Word::from(rotated_wallet.storage().get_item(0).unwrap()),
+        Word::from(new_key.public_key())
+    );
+    assert!(client.can_sign_for(wallet.id()).await.unwrap());
+
+    // Both keys are kept until the rotation is committed
+    let pending_auths = client.store.get_pending_account_auths().await.unwrap();
+    assert_eq!(pending_auths.len(), 1);
+    let (rotation_id, pending_account_id) = pending_auths[0];
+    assert_eq!(pending_account_id, wallet.id());
+    let AuthSecretKey::RpoFalcon512(current_key) =
+        client.get_account_auth(wallet.id()).await.unwrap();
+    assert_eq!(Word::from(current_key.public_key()), Word::from(old_key.public_key()));
+    for pub_key in [old_key.public_key(), new_key.public_key()] {
+        assert!(client.store.get_account_auth_by_pub_key(Word::from(pub_key)).await.is_ok());
+    }
+
+    // The following transactions are signed with the new key
+    let script = TransactionScript::compile(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+        vec![],
+        TransactionKernel::assembler(),
+    )
+    .unwrap();
+    let transaction_request = TransactionRequest::new().with_custom_script(script).unwrap();
+    let transaction = client.new_transaction(wallet.id(), transaction_request).await.unwrap();
+    client.submit_transaction(transaction).await.unwrap();
+
+    let (wallet_after_transaction, _seed) = client.store.get_account(wallet.id()).await.unwrap();
+    assert_eq!(wallet_after_transaction.nonce(), rotated_wallet.nonce() + Felt::ONE);
+
+    // The new key replaces the old one in the sync that commits the rotation
+    rpc_api.add_block_with_notes(vec![]);
+    let block_num = rpc_api.blocks.last().unwrap().header().block_num();
+    rpc_api.committed_transactions = vec![TransactionUpdate {
+        transaction_id: rotation_id,
+        block_num,
+        account_id: wallet.id(),
+    }];
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    client.sync_state().await.unwrap();
+
+    assert!(client.store.get_pending_account_auths().await.unwrap().is_empty());
+    let AuthSecretKey::RpoFalcon512(current_key) =
+        client.get_account_auth(wallet.id()).await.unwrap();
+    assert_eq!(Word::from(current_key.public_key()), Word::from(new_key.public_key()));
+    assert!(client
+        .store
+        .get_account_auth_by_pub_key(Word::from(old_key.public_key()))
+        .await
+        .is_err());
+
+    // If the rotation is discarded, its key is dropped and the old one is kept
+    let (discarded_wallet, _seed) = client
+        .new_account(AccountTemplate::RotatableWallet {
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let AuthSecretKey::RpoFalcon512(discarded_wallet_key) =
+        client.get_account_auth(discarded_wallet.id()).await.unwrap();
+    let discarded_key = SecretKey::new();
+    client
+        .rotate_auth_key(discarded_wallet.id(), AuthSecretKey::RpoFalcon512(discarded_key.clone()))
+        .await
+        .unwrap();
+    let (discarded_rotation_id, _) = client.store.get_pending_account_auths().await.unwrap()[0];
+    client.discard_local_transaction(discarded_rotation_id).await.unwrap();
+
+    assert!(client.store.get_pending_account_auths().await.unwrap().is_empty());
+    let AuthSecretKey::RpoFalcon512(current_key) =
+        client.get_account_auth(discarded_wallet.id()).await.unwrap();
+    assert_eq!(
+        Word::from(current_key.public_key()),
+        Word::from(discarded_wallet_key.public_key())
+    );
+    assert!(client
+        .store
+        .get_account_auth_by_pub_key(Word::from(discarded_key.public_key()))
+        .await
+        .is_err());
+
+    // The key of wallets without the rotation component can't be rotated, even if their code is
+    // mutable
+    let (mutable_wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: true,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    assert!(matches!(
+        client
+            .rotate_auth_key(mutable_wallet.id(), AuthSecretKey::RpoFalcon512(SecretKey::new()))
+            .await,
+        Err(ClientError::AuthKeyRotationUnsupported(account_id)) if account_id == mutable_wallet.id()
+    ));
+
+    // The key of accounts with immutable code can't be rotated
+    let (immutable_wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    assert!(matches!(
+        client
+            .rotate_auth_key(immutable_wallet.id(), AuthSecretKey::RpoFalcon512(SecretKey::new()))
+            .await,
+        Err(ClientError::AuthKeyRotationUnsupported(account_id)) if account_id == immutable_wallet.id()
+    ));
+}
+
+#[tokio::test]
+async fn test_find_note_by_prefix() {
+    let (client, rpc_api) = create_test_client().await;
+
+    let notes = rpc_api.notes.values().map(|n| n.note().clone().into()).collect::<Vec<_>>();
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
+    assert!(notes.len() > 1);
+
+    // Prefixes are matched regardless of case and of the leading `0x`
+    let note_id = notes[0].id();
+    let unprefixed_id = note_id.to_hex().trim_start_matches("0x").to_uppercase();
+    let note_match = client.find_note_by_prefix(&unprefixed_id[..16]).await.unwrap();
+    assert_eq!(note_match.id, note_id);
+    assert_eq!(note_match.input_note.unwrap().id(), note_id);
+    assert!(note_match.output_note.is_none());
+
+    let note_match = client.find_note_by_prefix(&format!("0X{unprefixed_id}")).await.unwrap();
+    assert_eq!(note_match.id, note_id);
+
+    // An ambiguous prefix returns the IDs of all the matching notes
+    let mut expected_candidates: Vec<_> = notes.iter().map(|note| note.id().to_hex()).collect();
+    expected_candidates.sort();
+    match client.find_note_by_prefix("0x").await {
+        Err(IdPrefixFetchError::MultipleMatches(_, candidates)) => {
+            assert_eq!(candidates, expected_candidates)
+        },
+        result => panic!("expected multiple matches, got {result:?}"),
+    }
+
+    assert!(matches!(
+        client.find_note_by_prefix("0xnothex").await,
+        Err(IdPrefixFetchError::NoMatch(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_find_account_by_prefix() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let mut account_ids = Vec::new();
+    for _ in 0..2 {
+        let (account, _seed) = client
+            .new_account(AccountTemplate::BasicWallet {
+                mutable_code: false,
+                storage_mode: AccountStorageMode::Private,
+            })
+            .await
+            .unwrap();
+        account_ids.push(account.id().to_hex());
+    }
+    account_ids.sort();
+
+    // The longest common prefix of both IDs is ambiguous
+    let common_prefix_len = account_ids[0]
+        .chars()
+        .zip(account_ids[1].chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let common_prefix = &account_ids[0][..common_prefix_len];
+    match client.find_account_by_prefix(common_prefix).await {
+        Err(IdPrefixFetchError::MultipleMatches(_, candidates)) => {
+            assert_eq!(candidates, account_ids)
+        },
+        result => panic!("expected multiple matches, got {result:?}"),
+    }
+
+    // One more digit is enough to tell the accounts apart
+    let unique_prefix = account_ids[1][..common_prefix_len + 1].trim_start_matches("0x");
+    let account = client.find_account_by_prefix(&unique_prefix.to_uppercase()).await.unwrap();
+    assert_eq!(account.id().to_hex(), account_ids[1]);
+
+    let account = client.find_account_by_prefix(&account_ids[0]).await.unwrap();
+    assert_eq!(account.id().to_hex(), account_ids[0]);
+
+    let unused_digit = "0123456789abcdef"
+        .chars()
+        .find(|digit| account_ids.iter().all(|id| !id[2..].starts_with(*digit)))
+        .unwrap();
+    assert!(matches!(
+        client.find_account_by_prefix(&format!("0x{unused_digit}")).await,
+        Err(IdPrefixFetchError::NoMatch(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_shutdown_flushes_store() {
+    let store_path = create_test_store_path();
+    let store_config: SqliteStoreConfig =
+        store_path.to_string_lossy().to_string().try_into().unwrap();
+    let store = Arc::new(SqliteStore::new(&store_config).await.unwrap());
+    let mut client = create_test_client_with_store(MockRpcApi::new(), store.clone());
+    client.sync_state().await.unwrap();
+    let faucet_id = execute_mint_transaction(&mut client).await.executed_transaction().account_id();
+    let sync_height = client.get_sync_height().await.unwrap();
+
+    client.shutdown().await.unwrap();
+
+    // The writes were moved from the WAL to the database while the store is still open
+    let mut wal_path = store_path.clone().into_os_string();
+    wal_path.push("-wal");
+    assert_eq!(std::fs::metadata(&wal_path).unwrap().len(), 0);
+
+    let reopened_store = SqliteStore::new(&store_config).await.unwrap();
+    assert_eq!(reopened_store.get_sync_height().await.unwrap(), sync_height);
+    assert!(reopened_store.get_account_header(faucet_id).await.is_ok());
+    drop(store);
+}
+
+#[tokio::test]
+async fn test_sync_state_reports_partial_progress() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+
+    // The first sync iteration reaches block 1, and the next one fails
+    rpc_api.fail_sync_from_block = Some(1);
+    *client.rpc_api() = Box::new(rpc_api.clone());
+
+    match client.sync_state().await {
+        Err(ClientError::SyncIncomplete { synced_to, source }) => {
+            assert_eq!(synced_to, 1);
+            assert!(matches!(*source, ClientError::RpcError(RpcError::ConnectionError(_))));
+        },
+        result => panic!("Expected an incomplete sync, got {result:?}"),
+    }
+    assert_eq!(client.get_sync_height().await.unwrap(), 1);
+
+    // A sync that fails without advancing the store returns the original error
+    assert!(matches!(
+        client.sync_state().await,
+        Err(ClientError::RpcError(RpcError::ConnectionError(_)))
+    ));
+
+    // The next sync resumes from the block the store advanced to
+    rpc_api.fail_sync_from_block = None;
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.block_num, rpc_api.blocks.last().unwrap().header().block_num());
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_get_note_transactions() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    let note_id = transaction.created_notes().get_note(0).id();
+    client.submit_transaction(transaction).await.unwrap();
+
+    let provenance = client.get_note_transactions(note_id).await.unwrap();
+    assert_eq!(provenance.note_id, note_id);
+    assert_eq!(provenance.creator_transaction.unwrap().id, transaction_id);
+    assert!(provenance.consumption.is_none());
+    assert!(provenance.inclusion_block_num.is_none());
+
+    let unknown_note_id = NoteId::new(Default::default(), Default::default());
+    assert!(matches!(
+        client.get_note_transactions(unknown_note_id).await,
+        Err(ClientError::StoreError(StoreError::NoteNotFound(id))) if id == unknown_note_id
+    ));
+}
+
+#[tokio::test]
+async fn test_run_maintenance() {
+    let (mut client, rpc_api) = create_test_client().await;
+
+    let mut consumed_note: InputNoteRecord = rpc_api.get_note_at(0).note().clone().into();
+    consumed_note.consumed_externally(consumed_note.nullifier(), 1).unwrap();
+    let expected_note: InputNoteRecord = rpc_api.get_note_at(1).note().clone().into();
+    Store::upsert_input_notes(
+        client.store.as_ref(),
+        &[consumed_note.clone(), expected_note.clone()],
+    )
+    .await
+    .unwrap();
+
+    // The pending note was already imported, so it's resolved
+    let pending_note = PendingNoteRecord::new(consumed_note.id(), Some(NoteTag::from(42)));
+    client.store.upsert_pending_note(pending_note).await.unwrap();
+
+    let untracked_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let expected_tag = NoteTagRecord::with_note_source(NoteTag::from(1), expected_note.id());
+    for tag_record in [
+        pending_note.tag_record().unwrap(),
+        expected_tag,
+        NoteTagRecord::with_note_source(NoteTag::from(2), consumed_note.id()),
+        NoteTagRecord::with_account_source(NoteTag::from(3), untracked_account_id),
+    ] {
+        client.store.add_note_tag(tag_record).await.unwrap();
+    }
+    client.add_note_tag(NoteTag::from(4)).await.unwrap();
+
+    // Tasks are run in their own order, regardless of the order in which they are provided
+    let report = client
+        .run_maintenance(&[
+            MaintenanceTask::RemoveStaleTags,
+            MaintenanceTask::ArchiveConsumedNotes,
+            MaintenanceTask::ClearResolvedPendingNotes,
+        ])
+        .await;
+    assert_eq!(
+        report.tasks.iter().map(|task| task.task).collect::<Vec<_>>(),
+        MaintenanceTask::ALL.to_vec()
+    );
+    assert_eq!(report.failed_tasks().count(), 0);
+    assert!(report.tasks.iter().all(|task| task.duration.is_some()));
+
+    let items_affected = |task| *report.task(task).unwrap().result.as_ref().unwrap();
+    assert_eq!(items_affected(MaintenanceTask::ClearResolvedPendingNotes), 1);
+    assert_eq!(items_affected(MaintenanceTask::ArchiveConsumedNotes), 1);
+    assert_eq!(items_affected(MaintenanceTask::RemoveStaleTags), 2);
+    assert_eq!(report.items_affected(), 4);
+
+    assert!(client.get_pending_notes().await.unwrap().is_empty());
+    assert_eq!(
+        client
+            .get_input_notes(NoteFilter::All)
+            .await
+            .unwrap()
+            .iter()
+            .map(|note| note.id())
+            .collect::<Vec<_>>(),
+        vec![expected_note.id()]
+    );
+    assert!(client.search_archived_notes(consumed_note.id()).await.unwrap().is_some());
+
+    let remaining_tags = client.store.get_note_tags().await.unwrap();
+    assert_eq!(remaining_tags.len(), 2);
+    assert!(remaining_tags.contains(&expected_tag));
+    assert!(remaining_tags.iter().any(|tag| tag.source == NoteTagSource::User));
+
+    // The scheduler runs right away, and not again until the interval elapses
+    let mut scheduler =
+        MaintenanceScheduler::new(MaintenanceTask::ALL.to_vec(), Duration::from_secs(3600));
+    let report = scheduler.tick(&mut client).await.unwrap().unwrap();
+    assert_eq!(report.items_affected(), 0);
+    assert!(!scheduler.is_due());
+    assert!(scheduler.tick(&mut client).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_rebuild_derived_state() {
+    let (mut client, rpc_api) = create_test_client().await;
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    // Track the notes of the mock chain so that the partial MMR authenticates their blocks
+    let notes = rpc_api.notes.values().map(|n| n.note().clone().into()).collect::<Vec<_>>();
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
+
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let [pending_note, expected_note] = [10, 20].map(|amount| {
+        create_p2id_note(
+            sender_id,
+            account.id(),
+            vec![FungibleAsset::new(faucet_id, amount).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap()
+    });
+    client.import_note_id(pending_note.id(), Some(NoteTag::from(7))).await.unwrap();
+    client
+        .import_note(NoteFile::NoteDetails {
+            details: expected_note.clone().into(),
+            after_block_num: sync_height + 10,
+            tag: Some(expected_note.metadata().tag()),
+        })
+        .await
+        .unwrap();
+    client.add_note_tag(NoteTag::from(4)).await.unwrap();
+
+    // Rebuilding a consistent store doesn't change it
+    let reference_state = derived_state(&client).await;
+    let report = client.rebuild_derived_state(&DerivedCategory::ALL).await;
+    assert_eq!(
+        report.categories.iter().map(|category| category.category).collect::<Vec<_>>(),
+        DerivedCategory::ALL.to_vec()
+    );
+    assert_eq!(report.failed_categories().count(), 0);
+    assert_eq!(report.records_repaired(), 0);
+    assert_eq!(derived_state(&client).await, reference_state);
+
+    let untracked_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let tag_records = client.store.get_note_tags().await.unwrap();
+    let account_tag = *tag_records
+        .iter()
+        .find(|tag| tag.source == NoteTagSource::Account(account.id()))
+        .unwrap();
+    let note_tags: Vec<NoteTagRecord> = tag_records
+        .iter()
+        .filter(|tag| matches!(tag.source, NoteTagSource::Note(_)))
+        .copied()
+        .collect();
+    assert_eq!(note_tags.len(), 2);
+
+    // Account tags: the account's tag is missing and an untracked account's tag is left behind
+    client
+        .store
+        .replace_note_tags(
+            vec![account_tag],
+            vec![NoteTagRecord::with_account_source(NoteTag::from(3), untracked_account_id)],
+        )
+        .await
+        .unwrap();
+
+    // Note tags: the expected note's tag lost its expiry and an unknown note's tag is left behind
+    let unknown_note_id = NoteId::new(Default::default(), Default::default());
+    client
+        .store
+        .replace_note_tags(
+            note_tags.clone(),
+            vec![
+                NoteTagRecord::with_note_source(expected_note.metadata().tag(), expected_note.id()),
+                NoteTagRecord::with_note_source(NoteTag::from(5), unknown_note_id),
+            ],
+        )
+        .await
+        .unwrap();
+
+    // Chain MMR: the leaves of the blocks whose headers are stored hold a wrong value
+    let mmr_nodes = client.store.get_chain_mmr_nodes(ChainMmrNodeFilter::All).await.unwrap();
+    let stored_block_nums: Vec<u32> = client
+        .get_block_headers(
+            &mmr_nodes
+                .keys()
+                .filter(|index| index.is_leaf())
+                .map(|index| ((u64::from(*index) - 1) / 2) as u32)
+                .collect::<Vec<_>>(),
+        )
+        .await
+        .unwrap()
+        .iter()
+        .map(|(header, _)| header.block_num())
+        .collect();
+    assert!(!stored_block_nums.is_empty());
+    let corrupted_nodes: Vec<_> = stored_block_nums
+        .iter()
+        .map(|block_num| (InOrderIndex::from_leaf_pos(*block_num as usize), Digest::default()))
+        .collect();
+    client.store.replace_chain_mmr_nodes(&corrupted_nodes).await.unwrap();
+    assert_ne!(derived_state(&client).await, reference_state);
+
+    // Each category is restored independently of the others
+    for category in DerivedCategory::ALL {
+        let report = client.rebuild_derived_state(&[category]).await;
+        assert!(*report.category(category).unwrap().result.as_ref().unwrap() > 0);
+    }
+    assert_eq!(derived_state(&client).await, reference_state);
+    assert!(client
+        .store
+        .get_note_tags()
+        .await
+        .unwrap()
+        .iter()
+        .any(|tag| tag.source == NoteTagSource::User));
+}
+
+/// Returns the tag records, with their expiry, and the chain MMR nodes stored by the client.
+async fn derived_state(
+    client: &crate::mock::MockClient,
+) -> (Vec<(Vec<u8>, Vec<u8>, Option<u32>)>, BTreeMap<InOrderIndex, Digest>) {
+    let mut tags: Vec<_> = client
+        .store
+        .get_note_tags()
+        .await
+        .unwrap()
+        .iter()
+        .map(|tag| (tag.tag.to_bytes(), tag.source.to_bytes(), tag.expires_after))
+        .collect();
+    tags.sort();
+
+    (tags, client.store.get_chain_mmr_nodes(ChainMmrNodeFilter::All).await.unwrap())
+}
+
+#[tokio::test]
+async fn test_can_consume_checks_note_state() {
+    let (mut client, rpc_api) = create_test_client().await;
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let mut consumed_note: InputNoteRecord = rpc_api.get_note_at(0).note().clone().into();
+    consumed_note.consumed_externally(consumed_note.nullifier(), 1).unwrap();
+    Store::upsert_input_notes(client.store.as_ref(), &[consumed_note.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        client.can_consume(consumed_note.id(), account.id()).await.unwrap(),
+        ConsumabilityVerdict::NotConsumable("the note is consumed".to_string())
+    );
+
+    let unknown_note_id = NoteId::new(Default::default(), Default::default());
+    assert!(client.can_consume(unknown_note_id, account.id()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_can_consume_executes_custom_scripts() {
+    let (mut client, rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+    let (account, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    // Notes without assets whose custom scripts don't check the consuming account, so they're
+    // only recognized by executing them
+    let base_note = rpc_api.get_note_at(0).note().clone();
+    let custom_note = |script: &str| -> InputNoteRecord {
+        let recipient = NoteRecipient::new(
+            [Felt::new(7), Felt::ONE, Felt::ZERO, Felt::ZERO],
+            client.compile_note_script(script).unwrap(),
+            base_note.inputs().clone(),
+        );
+        Note::new(NoteAssets::new(vec![]).unwrap(), *base_note.metadata(), recipient).into()
+    };
+    let anyone_can_consume = custom_note("begin push.1 drop end");
+    let no_one_can_consume = custom_note("begin push.0 assert end");
+    Store::upsert_input_notes(
+        client.store.as_ref(),
+        &[anyone_can_consume.clone(), no_one_can_consume.clone()],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        client.can_consume(anyone_can_consume.id(), account.id()).await.unwrap(),
+        ConsumabilityVerdict::Consumable
+    );
+    assert!(matches!(
+        client.can_consume(no_one_can_consume.id(), account.id()).await.unwrap(),
+        ConsumabilityVerdict::NotConsumable(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_get_note_consumer() {
+    let (mut client, rpc_api) = create_test_client().await;
+
+    let note: InputNoteRecord = rpc_api.get_note_at(0).note().clone().into();
+    Store::upsert_input_notes(client.store.as_ref(), &[note.clone()]).await.unwrap();
+
+    // The note wasn't consumed by a local transaction
+    assert_eq!(client.get_note_consumer(note.id()).await.unwrap(), None);
+
+    let unknown_note_id = NoteId::new(Default::default(), Default::default());
+    assert!(client.get_note_consumer(unknown_note_id).await.is_err());
+
+    // A note minted for a tracked wallet is consumed by a local transaction
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    client.sync_state().await.unwrap();
+
+    let transaction_request = mint_request_to(&mut client, faucet.id(), 5, wallet.id());
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let minted_note = match transaction.created_notes().get_note(0) {
+        OutputNote::Full(note) => note.clone(),
+        _ => panic!("the minted note should be a full note"),
+    };
+    client.testing_apply_transaction(transaction).await.unwrap();
+    assert_eq!(client.get_note_consumer(minted_note.id()).await.unwrap(), None);
+
+    let transaction_request =
+        TransactionRequest::new().with_unauthenticated_input_notes([(minted_note.clone(), None)]);
+    let transaction = client.new_transaction(wallet.id(), transaction_request).await.unwrap();
+    let consume_id = transaction.executed_transaction().id();
+    client.testing_apply_transaction(transaction).await.unwrap();
+    assert_eq!(client.get_note_consumer(minted_note.id()).await.unwrap(), Some(consume_id));
+}
+
+#[tokio::test]
+async fn test_sync_journal_divergence() {
+    let (client, rpc_api) = create_test_client().await;
+    let mut client = client.with_sync_journal(16);
+    client.sync_state().await.unwrap();
+
+    // The second node doesn't report the note of block 4, so the second client syncs from block 1
+    // straight to the chain tip
+    let mut diverging_rpc_api = rpc_api.clone();
+    diverging_rpc_api
+        .notes
+        .retain(|_, note| note.location().map_or(true, |location| location.block_num() != 4));
+    let mut diverging_client =
+        create_test_client_with_rpc(diverging_rpc_api).await.with_sync_journal(16);
+    diverging_client.sync_state().await.unwrap();
+
+    let mut matching_client = create_test_client_with_rpc(rpc_api).await.with_sync_journal(16);
+    matching_client.sync_state().await.unwrap();
+
+    let journal = client.export_sync_journal().unwrap();
+    let journal = SyncJournal::read_from_bytes(&journal.to_bytes()).unwrap();
+    assert_eq!(
+        diff_sync_journals(&journal, &matching_client.export_sync_journal().unwrap()),
+        None
+    );
+
+    let divergence =
+        diff_sync_journals(&journal, &diverging_client.export_sync_journal().unwrap()).unwrap();
+    assert_eq!(divergence.iteration, 1);
+    assert_eq!(divergence.left.unwrap().response.block_num, 4);
+    assert_eq!(divergence.right.unwrap().response.block_num, 5);
+}
+
+#[tokio::test]
+async fn test_consumable_notes_exclude_processing_notes() {
+    let (mut client, rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap();
+    let inclusion_proof = rpc_api.get_note_at(0).proof().unwrap().clone();
+
+    let mut notes = vec![];
+    for amount in [10, 20] {
+        let note = create_p2id_note(
+            faucet_id,
+            wallet.id(),
+            vec![FungibleAsset::new(faucet_id, amount).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap();
+        let state = CommittedNoteState {
+            metadata: *note.metadata(),
+            inclusion_proof: inclusion_proof.clone(),
+            block_note_root: Default::default(),
+        };
+        notes.push(InputNoteRecord::new(note.into(), None, state.into()));
+    }
+
+    // the second note is already being consumed by a local transaction
+    let processing_note_id = notes[1].id();
+    notes[1]
+        .consumed_locally(wallet.id(), TransactionId::from(Digest::default()), None)
+        .unwrap();
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
+
+    let consumable_notes = client.get_consumable_notes(Some(wallet.id()), false).await.unwrap();
+    assert_eq!(consumable_notes.len(), 1);
+    assert_ne!(consumable_notes[0].0.id(), processing_note_id);
+
+    let consumable_notes = client.get_consumable_notes(Some(wallet.id()), true).await.unwrap();
+    assert_eq!(consumable_notes.len(), 2);
+    assert!(consumable_notes.iter().any(|(note, _)| note.id() == processing_note_id));
+}
+
+#[tokio::test]
+async fn test_select_notes_for_amount() {
+    let (mut client, rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap();
+    let other_faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().unwrap();
+    let other_account_id: AccountId =
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.try_into().unwrap();
+    let inclusion_proof = rpc_api.get_note_at(0).proof().unwrap().clone();
+
+    let mut notes = vec![];
+    for (asset_faucet_id, target_id, amount) in [
+        (faucet_id, wallet.id(), 10),
+        (faucet_id, wallet.id(), 40),
+        (faucet_id, wallet.id(), 25),
+        (faucet_id, other_account_id, 100),
+        (other_faucet_id, wallet.id(), 1000),
+    ] {
+        let note = create_p2id_note(
+            faucet_id,
+            target_id,
+            vec![FungibleAsset::new(asset_faucet_id, amount).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap();
+        let state = CommittedNoteState {
+            metadata: *note.metadata(),
+            inclusion_proof: inclusion_proof.clone(),
+            block_note_root: Default::default(),
+        };
+        notes.push(InputNoteRecord::new(note.into(), None, state.into()));
+    }
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
+
+    let amounts = |notes: Vec<InputNoteRecord>| -> Vec<u64> {
+        notes
+            .iter()
+            .map(|note| note.assets().iter().next().unwrap().unwrap_fungible().amount())
+            .collect()
+    };
+
+    // The store doesn't screen the notes, so the note targeting the other account is selected
+    let store_notes = client.store.get_consumable_notes_for_faucet(faucet_id, 120).await.unwrap();
+    assert_eq!(amounts(store_notes), vec![100, 40]);
+    let store_notes = client.store.get_consumable_notes_for_faucet(faucet_id, 1000).await.unwrap();
+    assert_eq!(amounts(store_notes), vec![100, 40, 25, 10]);
+    assert!(client
+        .store
+        .get_consumable_notes_for_faucet(faucet_id, 0)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let asset = FungibleAsset::new(faucet_id, 50).unwrap();
+    let selected_notes = client.select_notes_for_amount(wallet.id(), asset).await.unwrap();
+    assert_eq!(amounts(selected_notes), vec![40, 25]);
+
+    let asset = FungibleAsset::new(faucet_id, 500).unwrap();
+    let selected_notes = client.select_notes_for_amount(wallet.id(), asset).await.unwrap();
+    assert_eq!(amounts(selected_notes), vec![40, 25, 10]);
+}
+
+#[tokio::test]
+async fn test_get_input_notes_page() {
+    let (mut client, rpc_api) = create_test_client().await;
+
+    let faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap();
+    let target_id: AccountId =
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.try_into().unwrap();
+    let inclusion_proof = rpc_api.get_note_at(0).proof().unwrap().clone();
+
+    // Two committed notes and three expected ones
+    let mut notes: Vec<InputNoteRecord> = vec![];
+    for index in 0..5 {
+        let note = create_p2id_note(
+            faucet_id,
+            target_id,
+            vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap();
+        if index < 2 {
+            let state = CommittedNoteState {
+                metadata: *note.metadata(),
+                inclusion_proof: inclusion_proof.clone(),
+                block_note_root: Default::default(),
+            };
+            notes.push(InputNoteRecord::new(note.into(), None, state.into()));
+        } else {
+            notes.push(note.into());
+        }
+    }
+    Store::upsert_input_notes(client.store.as_ref(), &notes).await.unwrap();
+
+    let mut sorted_ids: Vec<NoteId> = notes.iter().map(InputNoteRecord::id).collect();
+    sorted_ids.sort_by_key(|note_id| note_id.to_hex());
+
+    // Walking through the pages returns every note once, ordered by ID
+    let mut paged_ids = vec![];
+    let mut offset = 0;
+    loop {
+        let (page, total) = client.get_input_notes_page(NoteFilter::All, offset, 2).await.unwrap();
+        assert_eq!(total, 5);
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 2);
+        paged_ids.extend(page.iter().map(InputNoteRecord::id));
+        offset += page.len();
+    }
+    assert_eq!(paged_ids, sorted_ids);
+
+    // The total respects the filter
+    let (page, total) = client.get_input_notes_page(NoteFilter::Expected, 0, 2).await.unwrap();
+    assert_eq!(total, 3);
+    assert_eq!(page.len(), 2);
+    assert!(page.iter().all(|note| matches!(note.state(), InputNoteState::Expected(_))));
+
+    let (page, total) = client.get_input_notes_page(NoteFilter::Committed, 1, 10).await.unwrap();
+    assert_eq!(total, 2);
+    assert_eq!(page.len(), 1);
+
+    let (page, total) = client
+        .get_input_notes_page(NoteFilter::List(vec![notes[4].id(), notes[0].id()]), 0, 1)
+        .await
+        .unwrap();
+    assert_eq!(total, 2);
+    assert_eq!(page.len(), 1);
+
+    // Offsets past the last note return an empty page
+    let (page, total) = client.get_input_notes_page(NoteFilter::All, 10, 10).await.unwrap();
+    assert!(page.is_empty());
+    assert_eq!(total, 5);
+
+    let missing_note_id = rpc_api.get_note_at(0).id();
+    assert!(matches!(
+        client.get_input_notes_page(NoteFilter::Unique(missing_note_id), 0, 10).await,
+        Err(ClientError::StoreError(StoreError::NoteNotFound(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_expected_note_import_limit() {
+    let (client, _rpc_api) = create_test_client().await;
+    let mut client = client.with_max_expected_notes(2);
+
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let initial_tag_count = client.store.get_note_tags().await.unwrap().len();
+
+    let mut notes = vec![];
+    for _ in 0..5 {
+        let note = create_p2id_note(
+            sender_id,
+            sender_id,
+            vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap();
+        notes.push(note);
+    }
+
+    // The notes are expected after the current sync height, so they aren't checked against the
+    // chain
+    let note_file = |note: &Note| NoteFile::NoteDetails {
+        details: note.clone().into(),
+        after_block_num: 100,
+        tag: Some(note.metadata().tag()),
+    };
+
+    client.import_note(note_file(&notes[0])).await.unwrap();
+    client.import_note(note_file(&notes[1])).await.unwrap();
+
+    // Importing a tracked note again is accepted and doesn't change the store
+    client.import_note(note_file(&notes[0])).await.unwrap();
+
+    for note in notes[2..].iter() {
+        assert!(matches!(
+            client.import_note(note_file(note)).await,
+            Err(ClientError::ExpectedNoteLimitReached(2))
+        ));
+    }
+
+    let counts = client.count_notes_by_state().await.unwrap();
+    assert_eq!(counts.expected, 2);
+    assert_eq!(counts.total(), 2);
+    assert_eq!(client.get_input_notes(NoteFilter::All).await.unwrap().len(), 2);
+    assert_eq!(client.store.get_note_tags().await.unwrap().len(), initial_tag_count + 2);
+}
+
+#[tokio::test]
+async fn test_import_note_id_before_commitment() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let note = create_p2id_note(
+        sender_id,
+        sender_id,
+        vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+        miden_objects::notes::NoteType::Private,
+        Felt::ZERO,
+        client.rng(),
+    )
+    .unwrap();
+    let tag = note.metadata().tag();
+    let initial_tag_count = client.store.get_note_tags().await.unwrap().len();
+
+    // The node doesn't know the note yet, so it's tracked as a pending note along with its tag
+    let outcome = client.import_note_id(note.id(), Some(tag)).await.unwrap();
+    assert_eq!(outcome, ImportOutcome::PendingCommitment(note.id()));
+    assert_eq!(client.store.get_note_tags().await.unwrap().len(), initial_tag_count + 1);
+
+    let pending_notes = client.get_pending_notes().await.unwrap();
+    assert_eq!(pending_notes.len(), 1);
+    assert!(!pending_notes[0].is_committed());
+
+    rpc_api.add_block_with_notes(vec![note.clone()]);
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    let chain_tip = rpc_api.blocks.last().unwrap().header().block_num();
+    client.sync_state().await.unwrap();
+
+    // The note is private, so the sync only learns the block in which it was committed
+    let pending_notes = client.get_pending_notes().await.unwrap();
+    assert_eq!(pending_notes.len(), 1);
+    assert_eq!(pending_notes[0].inclusion_block_num(), Some(chain_tip));
+    assert_eq!(client.store.get_note_tags().await.unwrap().len(), initial_tag_count);
+
+    // Importing the note's details replaces the pending note
+    client
+        .import_note(NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: 0,
+            tag: Some(tag),
+        })
+        .await
+        .unwrap();
+    assert!(client.get_pending_notes().await.unwrap().is_empty());
+    assert!(client.get_input_note(note.id()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_expired_note_tags_removed_on_sync() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let sender_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let mut notes = Vec::new();
+    for amount in [10, 20] {
+        notes.push(
+            create_p2id_note(
+                sender_id,
+                sender_id,
+                vec![FungibleAsset::new(faucet_id, amount).unwrap().into()],
+                miden_objects::notes::NoteType::Private,
+                Felt::ZERO,
+                client.rng(),
+            )
+            .unwrap(),
+        );
+    }
+
+    for note in notes.iter() {
+        client
+            .import_note(NoteFile::NoteDetails {
+                details: note.clone().into(),
+                after_block_num: 2,
+                tag: Some(note.metadata().tag()),
+            })
+            .await
+            .unwrap();
+    }
+
+    // Tags of expected notes expire after the block the note is expected after
+    let expected_tags: Vec<NoteTagRecord> = client
+        .store
+        .get_note_tags()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|tag| matches!(tag.source, NoteTagSource::Note(_)))
+        .collect();
+    assert_eq!(expected_tags.len(), 2);
+    assert!(expected_tags.iter().all(|tag| tag.expires_after == Some(2)));
+
+    let mut consumed_note = client.get_input_note(notes[0].id()).await.unwrap();
+    consumed_note.consumed_externally(consumed_note.nullifier(), 1).unwrap();
+    Store::upsert_input_notes(client.store.as_ref(), &[consumed_note])
+        .await
+        .unwrap();
+
+    // Only the tag of the consumed note is removed, the other note may still be committed
+    client.sync_state().await.unwrap();
+    let remaining_sources: Vec<NoteTagSource> = client
+        .store
+        .get_note_tags()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter_map(|tag| match tag.source {
+            NoteTagSource::Note(_) => Some(tag.source),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(remaining_sources, vec![NoteTagSource::Note(notes[1].id())]);
+}
+
+#[tokio::test]
+async fn test_check_connection_and_rpc_stats() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    let chain_tip = rpc_api.blocks.last().unwrap().header().block_num();
+
+    let delay = Duration::from_millis(20);
+    rpc_api
+        .response_delays
+        .insert(NodeRpcClientEndpoint::GetBlockHeaderByNumber, delay);
+    *client.rpc_api() = Box::new(rpc_api);
+
+    // The client never synced, so there's no genesis block to compare against
+    let health = client.check_connection().await.unwrap();
+    assert_eq!(health.endpoint.as_deref(), Some("mock"));
+    assert_eq!(health.chain_tip, chain_tip);
+    assert!(health.latency.unwrap() >= delay);
+    assert_eq!(health.genesis_matches, None);
+
+    client.sync_state().await.unwrap();
+    let health = client.check_connection().await.unwrap();
+    assert_eq!(health.genesis_matches, Some(true));
+
+    let stats = client.rpc_stats();
+    let header_stats = stats
+        .iter()
+        .find(|stats| stats.method == NodeRpcClientEndpoint::GetBlockHeaderByNumber)
+        .unwrap();
+    assert!(header_stats.samples >= 3);
+    assert_eq!((header_stats.min, header_stats.max, header_stats.mean), (delay, delay, delay));
+    let sync_stats = stats
+        .iter()
+        .find(|stats| stats.method == NodeRpcClientEndpoint::SyncState)
+        .unwrap();
+    assert_eq!(sync_stats.max, Duration::ZERO);
+
+    // Only the most recent calls are kept
+    let mut tracker = RpcLatencyTracker::default();
+    for millis in 0..=LATENCY_SAMPLES_PER_METHOD as u64 {
+        tracker.record(NodeRpcClientEndpoint::SyncNotes, Duration::from_millis(millis));
+    }
+    let stats = tracker.stats();
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].samples, LATENCY_SAMPLES_PER_METHOD);
+    assert_eq!(stats[0].min, Duration::from_millis(1));
+    assert_eq!(stats[0].last, Duration::from_millis(LATENCY_SAMPLES_PER_METHOD as u64));
+}
+
+#[tokio::test]
+async fn test_sync_account_state() {
+    let (client, mut rpc_api) = create_test_client().await;
+    let mut client = client.with_sync_journal(16);
+    client.sync_state().await.unwrap();
+
+    let mut wallets = vec![];
+    for _ in 0..2 {
+        let (wallet, _seed) = client
+            .new_account(AccountTemplate::BasicWallet {
+                mutable_code: false,
+                storage_mode: AccountStorageMode::Public,
+            })
+            .await
+            .unwrap();
+        wallets.push(wallet);
+    }
+
+    // Both wallets are updated on the node
+    for wallet in wallets.iter() {
+        let remote_wallet = Account::from_parts(
+            wallet.id(),
+            wallet.vault().clone(),
+            wallet.storage().clone(),
+            wallet.code().clone(),
+            wallet.nonce() + Felt::ONE,
+        );
+        rpc_api.add_block_with_account_update(remote_wallet);
+    }
+    *client.rpc_api() = Box::new(rpc_api);
+
+    let user_tag = NoteTag::for_public_use_case(7, 0, NoteExecutionMode::Local).unwrap();
+    client.add_note_tag(user_tag).await.unwrap();
+    let note_tags: Vec<NoteTag> =
+        client.get_unique_note_tags().await.unwrap().into_iter().collect();
+    let sync_height = client.get_sync_height().await.unwrap();
+
+    // Only the requested wallet is reconciled
+    let summary = client.sync_account_state(&[wallets[0].id()]).await.unwrap();
+    assert_eq!(summary.updated_accounts, [wallets[0].id()]);
+    let (first_wallet, _) = client.get_account(wallets[0].id()).await.unwrap();
+    assert_eq!(first_wallet.nonce(), wallets[0].nonce() + Felt::ONE);
+    let (second_wallet, _) = client.get_account(wallets[1].id()).await.unwrap();
+    assert_eq!(second_wallet.nonce(), wallets[1].nonce());
+
+    // The commitment of the other wallet is left out of the request, but not the tags of any
+    // account or those added by the user
+    let journal = client.export_sync_journal().unwrap();
+    let request = journal
+        .entries()
+        .filter(|entry| entry.request.block_num == sync_height)
+        .last()
+        .unwrap();
+    assert_eq!(
+        request.request,
+        SyncRequestDigest::new(sync_height, &[wallets[0].id()], &note_tags)
+    );
+    assert!(note_tags
+        .contains(&NoteTag::from_account_id(wallets[1].id(), NoteExecutionMode::Local).unwrap()));
+
+    // The accounts must be tracked
+    let untracked_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    assert!(matches!(
+        client.sync_account_state(&[untracked_account_id]).await,
+        Err(ClientError::StoreError(StoreError::AccountDataNotFound(account_id)))
+            if account_id == untracked_account_id
+    ));
+}
+
+#[tokio::test]
+async fn test_sync_account_state_misses_excluded_account_updates() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let mut wallets = vec![];
+    for _ in 0..2 {
+        let (wallet, _seed) = client
+            .new_account(AccountTemplate::BasicWallet {
+                mutable_code: false,
+                storage_mode: AccountStorageMode::Public,
+            })
+            .await
+            .unwrap();
+        wallets.push(wallet);
+    }
+    let (synced_wallet, excluded_wallet) = (&wallets[0], &wallets[1]);
+    let updated_wallet = |wallet: &Account, nonce_increment: u64| {
+        Account::from_parts(
+            wallet.id(),
+            wallet.vault().clone(),
+            wallet.storage().clone(),
+            wallet.code().clone(),
+            wallet.nonce() + Felt::new(nonce_increment),
+        )
+    };
+
+    // The excluded wallet is updated on chain in the blocks covered by the selective sync
+    rpc_api.add_block_with_account_update(updated_wallet(excluded_wallet, 1));
+    *client.rpc_api() = Box::new(rpc_api.clone());
+    let summary = client.sync_account_state(&[synced_wallet.id()]).await.unwrap();
+    assert!(summary.updated_accounts.is_empty());
+
+    // Those blocks aren't requested again, so a full sync doesn't see the update either
+    let summary = client.sync_state().await.unwrap();
+    assert!(summary.updated_accounts.is_empty());
+    let (local_wallet, _) = client.get_account(excluded_wallet.id()).await.unwrap();
+    assert_eq!(local_wallet.nonce(), excluded_wallet.nonce());
+
+    // The wallet catches up once it's updated on chain again
+    let remote_wallet = updated_wallet(excluded_wallet, 2);
+    rpc_api.add_block_with_account_update(remote_wallet.clone());
+    *client.rpc_api() = Box::new(rpc_api);
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.updated_accounts, [excluded_wallet.id()]);
+    let (local_wallet, _) = client.get_account(excluded_wallet.id()).await.unwrap();
+    assert_eq!(local_wallet.hash(), remote_wallet.hash());
+}
+
+#[tokio::test]
+async fn test_sync_summary_reports_rpc_traffic() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let summary = client.sync_state().await.unwrap();
+    let sync_traffic = summary.rpc_traffic[&NodeRpcClientEndpoint::SyncState];
+    assert!(sync_traffic.request_bytes > 0);
+    assert!(sync_traffic.response_bytes > 0);
+    assert_eq!(summary.total_rpc_traffic(), sync_traffic);
+    assert_eq!(client.rpc_traffic(), summary.rpc_traffic);
+
+    // The traffic is persisted along with the rest of the summary
+    let decoded = SyncSummary::read_from_bytes(&summary.to_bytes()).unwrap();
+    assert_eq!(decoded.rpc_traffic, summary.rpc_traffic);
+
+    // Each sync only reports its own traffic, while the client's counters keep growing
+    let summary = client.sync_state().await.unwrap();
+    let client_traffic = client.rpc_traffic()[&NodeRpcClientEndpoint::SyncState];
+    assert_eq!(
+        client_traffic.total_bytes(),
+        sync_traffic.total_bytes()
+            + summary.rpc_traffic[&NodeRpcClientEndpoint::SyncState].total_bytes()
+    );
+
+    let mut counter = RpcTrafficCounter::default();
+    counter.record(NodeRpcClientEndpoint::GetNotesById, 10, 100);
+    counter.record(NodeRpcClientEndpoint::GetNotesById, 5, 50);
+    assert_eq!(
+        counter.traffic()[&NodeRpcClientEndpoint::GetNotesById],
+        RpcTraffic { request_bytes: 15, response_bytes: 150 }
+    );
+}
+
+#[tokio::test]
+async fn test_sync_with_colliding_account_tags() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    // Both accounts share the high bits from which their note tags are derived
+    let first_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let second_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN + 1).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+
+    let mut notes = vec![];
+    for target_id in [first_account_id, second_account_id] {
+        let note = create_p2id_note(
+            faucet_id,
+            target_id,
+            vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+            miden_objects::notes::NoteType::Private,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .unwrap();
+        notes.push(note);
+    }
+    let tag = notes[0].metadata().tag();
+    assert_eq!(notes[1].metadata().tag(), tag);
+
+    for account_id in [first_account_id, second_account_id] {
+        client
+            .store
+            .add_note_tag(NoteTagRecord::with_account_source(tag, account_id))
+            .await
+            .unwrap();
+    }
+
+    let collisions = client.get_tag_collisions().await.unwrap();
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].tag, tag);
+    assert_eq!(
+        collisions[0].sources,
+        vec![
+            NoteTagSource::Account(first_account_id),
+            NoteTagSource::Account(second_account_id)
+        ]
+    );
+
+    let expected_notes: Vec<InputNoteRecord> = notes.iter().cloned().map(Into::into).collect();
+    Store::upsert_input_notes(client.store.as_ref(), &expected_notes).await.unwrap();
+    rpc_api.add_block_with_notes(notes.clone());
+    *client.rpc_api() = Box::new(rpc_api);
+
+    let sync_summary = client.sync_state().await.unwrap();
+
+    // Each note was screened once, within the group of its tag
+    assert_eq!(sync_summary.screened_notes_per_tag, BTreeMap::from([(tag, 2)]));
+    for note in notes.iter() {
+        assert!(sync_summary.committed_notes.contains(&note.id()));
+        assert!(client.get_input_note(note.id()).await.unwrap().is_committed());
+    }
+}
+
+#[tokio::test]
+async fn test_get_known_faucets_for_account() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        Felt::new(2),
+        TransactionKernel::testing_assembler(),
+    );
+    client
+        .insert_account(
+            &account,
+            Some(Word::default()),
+            &AuthSecretKey::RpoFalcon512(SecretKey::new()),
+        )
+        .await
+        .unwrap();
+
+    let vault_faucets: BTreeSet<AccountId> =
+        account.vault().assets().map(|asset| asset.faucet_id()).collect();
+    assert!(!vault_faucets.is_empty());
+    assert_eq!(
+        client.get_known_faucets_for_account(account.id()).await.unwrap(),
+        vault_faucets.iter().copied().collect::<Vec<_>>()
+    );
+
+    // a committed note the account can consume adds the faucet of its assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    assert!(!vault_faucets.contains(&faucet_id));
+    let note = create_p2id_note(
+        faucet_id,
+        account.id(),
+        vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+        miden_objects::notes::NoteType::Private,
+        Felt::ZERO,
+        client.rng(),
+    )
+    .unwrap();
+    client
+        .store
+        .add_note_tag(NoteTagRecord::with_account_source(note.metadata().tag(), account.id()))
+        .await
+        .unwrap();
+    Store::upsert_input_notes(client.store.as_ref(), &[note.clone().into()])
+        .await
+        .unwrap();
+    rpc_api.add_block_with_notes(vec![note]);
+    *client.rpc_api() = Box::new(rpc_api);
+    client.sync_state().await.unwrap();
+
+    // the note is not consumed yet, so the store only knows about the faucets of the vault
+    assert_eq!(
+        client.store.get_known_faucets_for_account(account.id()).await.unwrap(),
+        vault_faucets.iter().copied().collect::<Vec<_>>()
+    );
+    let mut expected_faucets = vault_faucets;
+    expected_faucets.insert(faucet_id);
+    assert_eq!(
+        client.get_known_faucets_for_account(account.id()).await.unwrap(),
+        expected_faucets.into_iter().collect::<Vec<_>>()
+    );
+
+    let unknown_account =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    assert!(client.get_known_faucets_for_account(unknown_account).await.is_err());
+}
+
+#[tokio::test]
+async fn test_private_account_mismatch_policy() {
+    for policy in [
+        PrivateAccountMismatchPolicy::Lock,
+        PrivateAccountMismatchPolicy::WarnKeepLocal,
+        PrivateAccountMismatchPolicy::Error,
+    ] {
+        let (client, mut rpc_api) = create_test_client().await;
+        let mut client = client.with_private_account_mismatch_policy(policy);
+        client.sync_state().await.unwrap();
+
+        let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+        // another instance of the faucet updates it and the node reports the new commitment
+        let transaction_request = mint_request(&mut client, faucet.id(), 5);
+        let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+        let mut remote_faucet = faucet.clone();
+        remote_faucet.apply_delta(transaction.account_delta()).unwrap();
+
+        rpc_api.add_block_with_account_update(remote_faucet.clone());
+        *client.rpc_api() = Box::new(rpc_api);
+        let sync_result = client.sync_state().await;
+
+        let lock_status = client.get_account_lock_status(faucet.id()).await.unwrap();
+        match policy {
+            PrivateAccountMismatchPolicy::Lock => {
+                sync_result.unwrap();
+                assert_eq!(
+                    lock_status,
+                    AccountLockStatus::Locked { remote_hash: remote_faucet.hash() }
+                );
+            },
+            PrivateAccountMismatchPolicy::WarnKeepLocal => {
+                sync_result.unwrap();
+                assert_eq!(lock_status, AccountLockStatus::Unlocked);
+            },
+            PrivateAccountMismatchPolicy::Error => {
+                assert!(matches!(
+                    sync_result,
+                    Err(ClientError::PrivateAccountMismatch { account_id, remote_hash })
+                        if account_id == faucet.id() && remote_hash == remote_faucet.hash()
+                ));
+                assert_eq!(lock_status, AccountLockStatus::Unlocked);
+            },
+        }
+
+        // the local state is kept regardless of the policy
+        let (local_faucet, _seed) = client.get_account(faucet.id()).await.unwrap();
+        assert_eq!(local_faucet.hash(), faucet.hash());
+    }
+}
+
+#[tokio::test]
+async fn test_read_snapshot_is_isolated_from_sync() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        Felt::new(2),
+        TransactionKernel::testing_assembler(),
+    );
+    client
+        .insert_account(
+            &account,
+            Some(Word::default()),
+            &AuthSecretKey::RpoFalcon512(SecretKey::new()),
+        )
+        .await
+        .unwrap();
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let note = create_p2id_note(
+        faucet_id,
+        account.id(),
+        vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+        miden_objects::notes::NoteType::Private,
+        Felt::ZERO,
+        client.rng(),
+    )
+    .unwrap();
+    client
+        .store
+        .add_note_tag(NoteTagRecord::with_account_source(note.metadata().tag(), account.id()))
+        .await
+        .unwrap();
+    Store::upsert_input_notes(client.store.as_ref(), &[note.clone().into()])
+        .await
+        .unwrap();
+
+    let snapshot = client.read_snapshot().await.unwrap();
+    assert_eq!(snapshot.get_account_headers().await.unwrap().len(), 1);
+
+    // the note gets committed while the snapshot is open
+    rpc_api.add_block_with_notes(vec![note.clone()]);
+    *client.rpc_api() = Box::new(rpc_api);
+    client.sync_state().await.unwrap();
+
+    let snapshot_note =
+        snapshot.get_input_notes(NoteFilter::Unique(note.id())).await.unwrap().remove(0);
+    assert!(matches!(snapshot_note.state(), InputNoteState::Expected(_)));
+    assert!(snapshot.get_input_notes(NoteFilter::Committed).await.unwrap().is_empty());
+    assert!(snapshot.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+
+    // a fresh read sees the new state
+    assert!(client.get_input_note(note.id()).await.unwrap().is_committed());
+    let fresh_snapshot = client.read_snapshot().await.unwrap();
+    assert_eq!(fresh_snapshot.get_input_notes(NoteFilter::Committed).await.unwrap().len(), 1);
+
+    drop(snapshot);
+    drop(fresh_snapshot);
+    client.sync_state().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_read_snapshot_expires() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let snapshot = client.read_snapshot_with_timeout(Duration::ZERO).await.unwrap();
+    assert!(matches!(
+        snapshot.get_input_notes(NoteFilter::All).await,
+        Err(StoreError::SnapshotExpired(timeout)) if timeout == Duration::ZERO
+    ));
+
+    // the expired snapshot doesn't prevent the store from being written to
+    client.add_note_tag(NoteTag::from(1)).await.unwrap();
+    assert!(matches!(
+        snapshot.get_transactions(TransactionFilter::All).await,
+        Err(StoreError::SnapshotExpired(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_retry_discarded_transaction_checks_status() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    // pending transactions can't be retried
+    assert!(client.get_discarded_transactions().await.unwrap().is_empty());
+    assert!(matches!(
+        client.retry_discarded_transaction(transaction_id).await,
+        Err(ClientError::TransactionNotDiscarded(id)) if id == transaction_id
+    ));
+
+    let unknown_transaction_id = TransactionId::from(Digest::default());
+    assert!(matches!(
+        client.retry_discarded_transaction(unknown_transaction_id).await,
+        Err(ClientError::TransactionNotFound(id)) if id == unknown_transaction_id
+    ));
+}
+
+#[tokio::test]
+async fn test_discard_local_transaction() {
+    let (client, _rpc_api) = create_test_client().await;
+    check_discard_local_transaction(client).await;
+}
+
+#[tokio::test]
+async fn test_discard_local_transaction_in_memory() {
+    let client = create_test_client_with_store(MockRpcApi::new(), Arc::new(MemoryStore::new()));
+    check_discard_local_transaction(client).await;
+}
+
+/// Discards a mint and the consumption of the minted note, checking that the accounts, notes,
+/// tags and transactions stored by the client's store are reverted.
+async fn check_discard_local_transaction(mut client: crate::mock::MockClient) {
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    client.sync_state().await.unwrap();
+    let (faucet_before, _) = client.get_account(faucet.id()).await.unwrap();
+    let (wallet_before, _) = client.get_account(wallet.id()).await.unwrap();
+
+    // the faucet mints a note for the wallet, which consumes it before it's committed
+    let transaction_request = mint_request_to(&mut client, faucet.id(), 5, wallet.id());
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let mint_id = transaction.executed_transaction().id();
+    let minted_note = match transaction.created_notes().get_note(0) {
+        OutputNote::Full(note) => note.clone(),
+        _ => panic!("the minted note should be a full note"),
+    };
+    client.testing_apply_transaction(transaction).await.unwrap();
+    let expected_tags = client.get_note_tags().await.unwrap();
+    assert!(matches!(
+        client.get_input_note(minted_note.id()).await.unwrap().state(),
+        InputNoteState::Expected(_)
+    ));
+
+    let transaction_request =
+        TransactionRequest::new().with_unauthenticated_input_notes([(minted_note.clone(), None)]);
+    let transaction = client.new_transaction(wallet.id(), transaction_request).await.unwrap();
+    let consume_id = transaction.executed_transaction().id();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    // the mint can't be discarded while the consumption of its note is pending
+    assert!(matches!(
+        client.discard_local_transaction(mint_id).await,
+        Err(ClientError::LocalTransactionNotDiscardable { transaction_id, .. })
+            if transaction_id == mint_id
+    ));
+
+    client.discard_local_transaction(consume_id).await.unwrap();
+    let (wallet_after, _) = client.get_account(wallet.id()).await.unwrap();
+    assert_eq!(wallet_after.to_bytes(), wallet_before.to_bytes());
+    let reverted_note = client.get_input_note(minted_note.id()).await.unwrap();
+    assert!(matches!(reverted_note.state(), InputNoteState::Expected(_)));
+    assert_eq!(client.get_note_tags().await.unwrap(), expected_tags);
+
+    client.discard_local_transaction(mint_id).await.unwrap();
+    let (faucet_after, _) = client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(faucet_after.to_bytes(), faucet_before.to_bytes());
+    assert!(client.get_input_note(minted_note.id()).await.is_err());
+    assert!(client
+        .get_output_notes(NoteFilter::List(vec![minted_note.id()]))
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(!client
+        .get_note_tags()
+        .await
+        .unwrap()
+        .iter()
+        .any(|tag| tag.source == NoteTagSource::Note(minted_note.id())));
+
+    let transactions = client.get_transactions(TransactionFilter::All).await.unwrap();
+    assert_eq!(transactions.len(), 2);
+    for transaction in transactions {
+        assert_eq!(transaction.transaction_status, TransactionStatus::Discarded);
+        assert_eq!(transaction.discard_cause(), Some(DiscardCause::ManuallyDiscarded));
+    }
+
+    // discarded transactions aren't pending anymore
+    assert!(matches!(
+        client.discard_local_transaction(mint_id).await,
+        Err(ClientError::LocalTransactionNotDiscardable { transaction_id, .. })
+            if transaction_id == mint_id
+    ));
+}
+
+#[tokio::test]
+async fn test_discard_local_transaction_refuses_chained_transactions() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    client.sync_state().await.unwrap();
+
+    let mut transaction_ids = vec![];
+    for _ in 0..2 {
+        let transaction_request = mint_request(&mut client, faucet.id(), 5);
+        let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+        transaction_ids.push(transaction.executed_transaction().id());
+        client.testing_apply_transaction(transaction).await.unwrap();
+    }
+    let (first_id, second_id) = (transaction_ids[0], transaction_ids[1]);
+
+    // the second mint builds on the faucet state of the first one, so it has to go first
+    assert!(matches!(
+        client.discard_local_transaction(first_id).await,
+        Err(ClientError::LocalTransactionNotDiscardable { transaction_id, .. })
+            if transaction_id == first_id
+    ));
+    client.discard_local_transaction(second_id).await.unwrap();
+    client.discard_local_transaction(first_id).await.unwrap();
+
+    let unknown_transaction_id = TransactionId::from(Digest::default());
+    assert!(matches!(
+        client.discard_local_transaction(unknown_transaction_id).await,
+        Err(ClientError::TransactionNotFound(id)) if id == unknown_transaction_id
+    ));
+}
+
+#[tokio::test]
+async fn test_remove_only_account_keeps_sync_working() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    client.sync_state().await.unwrap();
+    let user_tag = NoteTag::from(3);
+    client.add_note_tag(user_tag).await.unwrap();
+
+    client.remove_account(wallet.id(), RemoveOptions::default()).await.unwrap();
+    assert!(client.get_account(wallet.id()).await.is_err());
+    assert!(client.get_account_headers().await.unwrap().is_empty());
+    assert!(client.get_account_auth(wallet.id()).await.is_err());
+    assert!(client.find_orphaned_keys().await.unwrap().is_empty());
+    let tags = client.get_note_tags().await.unwrap();
+    assert!(!tags.iter().any(|tag| tag.source == NoteTagSource::Account(wallet.id())));
+    assert!(tags.contains(&NoteTagRecord::with_user_source(user_tag)));
+
+    // the client keeps syncing its remaining tags without any account
+    let sync_height = client.get_sync_height().await.unwrap();
+    let summary = client.sync_state().await.unwrap();
+    assert!(summary.block_num >= sync_height);
+    assert!(summary.updated_accounts.is_empty());
+
+    assert!(client.remove_account(wallet.id(), RemoveOptions::default()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_remove_account_with_pending_transactions() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    let (other_faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "OTR".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    client.sync_state().await.unwrap();
+
+    let mut mint_ids = vec![];
+    let mut minted_note_ids = vec![];
+    for faucet_id in [faucet.id(), other_faucet.id()] {
+        let transaction_request = mint_request(&mut client, faucet_id, 5);
+        let transaction = client.new_transaction(faucet_id, transaction_request).await.unwrap();
+        mint_ids.push(transaction.executed_transaction().id());
+        minted_note_ids.push(transaction.created_notes().get_note(0).id());
+        client.testing_apply_transaction(transaction).await.unwrap();
+    }
+
+    assert!(matches!(
+        client.remove_account(faucet.id(), RemoveOptions::default()).await,
+        Err(ClientError::AccountHasPendingTransactions { account_id, transaction_ids })
+            if account_id == faucet.id() && transaction_ids == vec![mint_ids[0]]
+    ));
+
+    // the history of the first faucet is removed with it
+    client
+        .remove_account(faucet.id(), RemoveOptions::default().with_force(true))
+        .await
+        .unwrap();
+    let transactions = client.get_transactions(TransactionFilter::All).await.unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].id, mint_ids[1]);
+    let output_notes = client.get_output_notes(NoteFilter::All).await.unwrap();
+    assert_eq!(output_notes.len(), 1);
+    assert_eq!(output_notes[0].id(), minted_note_ids[1]);
+
+    // while the pending mint of the second one is kept as discarded
+    client
+        .remove_account(
+            other_faucet.id(),
+            RemoveOptions::default().with_force(true).with_keep_history(true),
+        )
+        .await
+        .unwrap();
+    let transactions = client.get_transactions(TransactionFilter::All).await.unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].transaction_status, TransactionStatus::Discarded);
+    assert_eq!(transactions[0].discard_cause(), Some(DiscardCause::AccountRemoved));
+    assert_eq!(client.get_output_notes(NoteFilter::All).await.unwrap().len(), 1);
+    assert!(client.get_account_headers().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_inspect_account() {
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    let report = client.inspect_account(faucet.id()).await.unwrap();
+    assert_eq!(report.id, faucet.id().to_hex());
+    assert_eq!(report.account_type, AccountTypeReport::FungibleFaucet);
+    assert_eq!(report.storage_mode, "private");
+    assert_eq!(report.nonce, 0);
+    assert!(report.is_new);
+    assert!(report.vault_assets.is_empty());
+    assert_eq!(report.storage_slots.len(), faucet.storage().slots().len());
+    assert_eq!(report.code_commitment, faucet.code().commitment().to_hex());
+
+    let AuthSecretKey::RpoFalcon512(secret_key) =
+        client.get_account_auth(faucet.id()).await.unwrap();
+    assert_eq!(
+        report.auth_scheme,
+        Some(AuthSchemeReport::RpoFalcon512 {
+            public_key: Digest::from(Word::from(secret_key.public_key())).to_hex()
+        })
+    );
+
+    // the report round-trips through JSON
+    let json = serde_json::to_string(&report).unwrap();
+    assert_eq!(serde_json::from_str::<AccountReport>(&json).unwrap(), report);
+
+    let unknown_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    assert!(matches!(
+        client.inspect_account(unknown_account_id).await,
+        Err(ClientError::StoreError(StoreError::AccountDataNotFound(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_sync_skips_mismatched_transaction_updates() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let mismatched_transaction = execute_mint_transaction(&mut client).await;
+    let mismatched_transaction_id = mismatched_transaction.executed_transaction().id();
+    client.testing_apply_transaction(mismatched_transaction).await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    let account_id = transaction.executed_transaction().account_id();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    // the node reports the first transaction for an account that didn't execute it, along with a
+    // transaction the client doesn't know about
+    rpc_api.add_block_with_notes(vec![]);
+    let block_num = rpc_api.blocks.last().unwrap().header().block_num();
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    rpc_api.committed_transactions = vec![
+        TransactionUpdate {
+            transaction_id: mismatched_transaction_id,
+            block_num,
+            account_id: other_account_id,
+        },
+        TransactionUpdate { transaction_id, block_num, account_id },
+        TransactionUpdate {
+            transaction_id: TransactionId::from(Digest::default()),
+            block_num,
+            account_id: other_account_id,
+        },
+    ];
+    *client.rpc_api() = Box::new(rpc_api);
+
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.block_num, block_num);
+    assert_eq!(summary.committed_transactions, vec![transaction_id]);
+    assert_eq!(summary.warnings.len(), 1);
+    assert!(summary.warnings[0].contains(&mismatched_transaction_id.to_string()));
+
+    let transactions = client.get_transactions(TransactionFilter::All).await.unwrap();
+    let status_of = |id: TransactionId| {
+        transactions.iter().find(|tx| tx.id == id).unwrap().transaction_status.clone()
+    };
+    assert_eq!(status_of(transaction_id), TransactionStatus::Committed(block_num));
+    assert_eq!(status_of(mismatched_transaction_id), TransactionStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_account_net_flow() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+
+    // the faucet mints 5 tokens for the wallet, which consumes them and sends 2 to another account
+    let transaction_request = mint_request_to(&mut client, faucet.id(), 5, wallet.id());
+    let mint = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let minted_note = match mint.created_notes().get_note(0) {
+        OutputNote::Full(note) => note.clone(),
+        _ => panic!("the minted note should be a full note"),
+    };
+    let mut transactions = vec![mint.executed_transaction().clone()];
+    client.testing_apply_transaction(mint).await.unwrap();
+
+    let transaction_request =
+        TransactionRequest::new().with_unauthenticated_input_notes([(minted_note, None)]);
+    let consume = client.new_transaction(wallet.id(), transaction_request).await.unwrap();
+    transactions.push(consume.executed_transaction().clone());
+    client.testing_apply_transaction(consume).await.unwrap();
+
+    let transaction_request = TransactionRequest::pay_to_id(
+        PaymentTransactionData::new(
+            vec![FungibleAsset::new(faucet.id(), 2).unwrap().into()],
+            wallet.id(),
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.try_into().unwrap(),
+        ),
+        None,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    let send = client.new_transaction(wallet.id(), transaction_request).await.unwrap();
+    transactions.push(send.executed_transaction().clone());
+    client.testing_apply_transaction(send).await.unwrap();
+
+    // only committed transactions count towards the flow
+    assert!(client.get_account_net_flow(wallet.id()).await.unwrap().is_empty());
+
+    rpc_api.add_block_with_notes(vec![]);
+    let block_num = rpc_api.blocks.last().unwrap().header().block_num();
+    rpc_api.committed_transactions = transactions
+        .iter()
+        .map(|transaction| TransactionUpdate {
+            transaction_id: transaction.id(),
+            block_num,
+            account_id: transaction.account_id(),
+        })
+        .collect();
+    *client.rpc_api() = Box::new(rpc_api);
+    client.sync_state().await.unwrap();
+
+    assert_eq!(
+        client.get_account_net_flow(wallet.id()).await.unwrap(),
+        BTreeMap::from([(faucet.id(), 3)])
+    );
+    assert_eq!(
+        client.get_account_net_flow(faucet.id()).await.unwrap(),
+        BTreeMap::from([(faucet.id(), -5)])
+    );
+}
+
+#[tokio::test]
+async fn test_client_profiles() {
+    let (client, _rpc_api) = create_test_client().await;
+    assert_eq!(client.profile_name(), None);
+
+    let client = client.with_profile(ClientProfile::Wallet);
+    assert_eq!(client.profile_name(), Some("wallet"));
+    assert_eq!(client.max_notes_per_sync_iteration, 256);
+    assert_eq!(client.mmr_checkpoint_interval, 1);
+    assert_eq!(client.max_expected_notes, Some(1000));
+    assert_eq!(client.private_account_mismatch_policy, PrivateAccountMismatchPolicy::Lock);
+
+    let client = client.with_profile(ClientProfile::Server);
+    assert_eq!(client.profile_name(), Some("server"));
+    assert_eq!(client.max_notes_per_sync_iteration, 4096);
+    assert_eq!(client.max_notes_per_request, 250);
+    assert_eq!(client.mmr_checkpoint_interval, 16);
+    assert_eq!(client.max_expected_notes, None);
+    assert_eq!(client.export_sync_journal().unwrap().capacity(), 64);
+
+    let mut client = client.with_profile(ClientProfile::Testing);
+    assert_eq!(client.profile_name(), Some("testing"));
+    assert_eq!(client.max_notes_per_sync_iteration, 16);
+    assert_eq!(client.mmr_checkpoint_interval, 1);
+    assert_eq!(client.private_account_mismatch_policy, PrivateAccountMismatchPolicy::Error);
+    assert_eq!(client.export_sync_journal().unwrap().capacity(), 16);
+
+    // Settings changed after selecting a profile override its values
+    client.set_max_notes_per_sync_iteration(8);
+    client.set_mmr_checkpoint_interval(0);
+    let client = client
+        .with_private_account_mismatch_policy(PrivateAccountMismatchPolicy::WarnKeepLocal)
+        .with_max_expected_notes(5);
+    assert_eq!(client.profile_name(), Some("testing"));
+    assert_eq!(client.max_notes_per_sync_iteration, 8);
+    assert_eq!(client.mmr_checkpoint_interval, 0);
+    assert_eq!(client.max_expected_notes, Some(5));
+    assert_eq!(
+        client.private_account_mismatch_policy,
+        PrivateAccountMismatchPolicy::WarnKeepLocal
+    );
+}
+
+#[tokio::test]
+async fn test_notes_created_by_transaction() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    client.sync_state().await.unwrap();
+
+    let transaction_request = mint_request_to(&mut client, faucet.id(), 5, wallet.id());
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let transaction_id = transaction.executed_transaction().id();
+    let minted_note_id = transaction.created_notes().get_note(0).id();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    // The minted note is tracked as an input note because it targets the wallet
+    let created_notes =
+        client.get_input_notes_created_by_transaction(transaction_id).await.unwrap();
+    assert_eq!(created_notes.len(), 1);
+    assert_eq!(created_notes[0].id(), minted_note_id);
+
+    let created_output_notes = client
+        .get_output_notes(NoteFilter::CreatedByTransaction(transaction_id))
+        .await
+        .unwrap();
+    assert_eq!(created_output_notes.len(), 1);
+    assert_eq!(created_output_notes[0].id(), minted_note_id);
+
+    // Unknown transactions didn't create any notes
+    let unknown_transaction_id = TransactionId::from(Digest::default());
+    assert!(client
+        .get_input_notes_created_by_transaction(unknown_transaction_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[cfg(feature = "local-prover")]
+#[tokio::test]
+async fn test_retained_proven_transaction_verifies() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.set_retain_proven_transactions(true);
+    client.sync_state().await.unwrap();
+
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    let account_id = transaction.executed_transaction().account_id();
+    client.submit_transaction(transaction).await.unwrap();
+
+    // The exported bytes are enough to verify the transaction on their own
+    let proof_bytes = client.get_proven_transaction(transaction_id).await.unwrap().unwrap();
+    let proven_transaction = ProvenTransaction::read_from_bytes(&proof_bytes).unwrap();
+    assert_eq!(proven_transaction.id(), transaction_id);
+    assert_eq!(proven_transaction.account_id(), account_id);
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .verify(proven_transaction)
+        .unwrap();
+
+    // Proofs are not retained unless enabled
+    client.set_retain_proven_transactions(false);
+    let transaction = execute_mint_transaction(&mut client).await;
+    let transaction_id = transaction.executed_transaction().id();
+    client.submit_transaction(transaction).await.unwrap();
+    assert!(client.get_proven_transaction(transaction_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_retained_proofs_eviction() {
+    let (client, _rpc_api) = create_test_client().await;
+    let transaction_ids: Vec<TransactionId> = (0..4u64)
+        .map(|i| {
+            TransactionId::from(Digest::from([Felt::new(i), Felt::ZERO, Felt::ZERO, Felt::ZERO]))
+        })
+        .collect();
+
+    for transaction_id in &transaction_ids[..3] {
+        client
+            .store
+            .insert_proven_transaction(*transaction_id, vec![1; 10], 30)
+            .await
+            .unwrap();
+    }
+    for transaction_id in &transaction_ids[..3] {
+        assert!(client.get_proven_transaction(*transaction_id).await.unwrap().is_some());
+    }
+
+    // Retrieving the first proof makes the second one the least recently used
+    client.get_proven_transaction(transaction_ids[0]).await.unwrap();
+    client
+        .store
+        .insert_proven_transaction(transaction_ids[3], vec![2; 10], 30)
+        .await
+        .unwrap();
+
+    let retained = |index: usize| client.get_proven_transaction(transaction_ids[index]);
+    assert_eq!(retained(0).await.unwrap(), Some(vec![1; 10]));
+    assert_eq!(retained(1).await.unwrap(), None);
+    assert_eq!(retained(3).await.unwrap(), Some(vec![2; 10]));
+
+    // Proofs larger than the limit are not retained
+    client
+        .store
+        .insert_proven_transaction(transaction_ids[1], vec![3; 40], 30)
+        .await
+        .unwrap();
+    assert_eq!(retained(1).await.unwrap(), None);
+}
+
+/// Returns the code an input note state is expected to have. The match has no wildcard so that
+/// adding a note state forces its code to be reviewed.
+fn expected_input_note_state_code(state: &InputNoteState) -> &'static str {
+    match state {
+        InputNoteState::Expected(_) => "expected",
+        InputNoteState::Unverified(_) => "unverified",
+        InputNoteState::Committed(_) => "committed",
+        InputNoteState::Invalid(_) => "invalid",
+        InputNoteState::ProcessingAuthenticated(_)
+        | InputNoteState::ProcessingUnauthenticated(_) => "processing",
+        InputNoteState::ConsumedAuthenticatedLocal(_)
+        | InputNoteState::ConsumedUnauthenticatedLocal(_) => "consumed_local",
+        InputNoteState::ConsumedExternal(_) => "consumed_external",
+        InputNoteState::Rejected(_) => "rejected",
+    }
+}
+
+/// Returns the code an output note state is expected to have, see
+/// [expected_input_note_state_code].
+fn expected_output_note_state_code(state: &OutputNoteState) -> &'static str {
+    match state {
+        OutputNoteState::ExpectedPartial | OutputNoteState::ExpectedFull { .. } => "expected",
+        OutputNoteState::CommittedPartial { .. } | OutputNoteState::CommittedFull { .. } => {
+            "committed"
+        },
+        OutputNoteState::Consumed { .. } => "consumed",
+    }
+}
+
+#[test]
+fn test_note_state_code_snapshot() {
+    // codes are matched on by other tools, so they must never change
+    let snapshot: Vec<String> = NoteStateCode::ALL
+        .iter()
+        .map(|code| format!("{} => {}", code.as_str(), code.human_label()))
+        .collect();
+    assert_eq!(
+        snapshot,
+        [
+            "expected => Expected",
+            "unverified => Unverified",
+            "committed => Committed",
+            "processing => Processing",
+            "consumed_local => Consumed (local)",
+            "consumed_external => Consumed (external)",
+            "consumed => Consumed",
+            "invalid => Invalid",
+            "rejected => Rejected",
+        ]
+    );
+
+    // ALL lists every code exactly once
+    for (index, code) in NoteStateCode::ALL.iter().enumerate() {
+        let expected_index = match code {
+            NoteStateCode::Expected => 0,
+            NoteStateCode::Unverified => 1,
+            NoteStateCode::Committed => 2,
+            NoteStateCode::Processing => 3,
+            NoteStateCode::ConsumedLocal => 4,
+            NoteStateCode::ConsumedExternal => 5,
+            NoteStateCode::Consumed => 6,
+            NoteStateCode::Invalid => 7,
+            NoteStateCode::Rejected => 8,
+        };
+        assert_eq!(index, expected_index);
+        assert_eq!(code.to_string(), code.as_str());
+    }
+}
+
+#[tokio::test]
+async fn test_note_state_codes() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let (faucet, _seed) = create_test_faucet(&mut client, AccountStorageMode::Private).await;
+    client.sync_state().await.unwrap();
+
+    let transaction_request = mint_request_to(&mut client, faucet.id(), 5, wallet.id());
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    client.testing_apply_transaction(transaction).await.unwrap();
+
+    let input_notes = client.get_input_notes(NoteFilter::All).await.unwrap();
+    assert!(!input_notes.is_empty());
+    for note in input_notes {
+        assert_eq!(note.state().code().as_str(), expected_input_note_state_code(note.state()));
+    }
+    let output_notes = client.get_output_notes(NoteFilter::All).await.unwrap();
+    assert!(!output_notes.is_empty());
+    for note in output_notes {
+        assert_eq!(note.state().code().as_str(), expected_output_note_state_code(note.state()));
+    }
+
+    // the detailed state is still available through its Display output
+    let expected_note = &client.get_input_notes(NoteFilter::Expected).await.unwrap()[0];
+    assert_eq!(expected_note.state().code(), NoteStateCode::Expected);
+    assert!(expected_note.state().to_string().starts_with("Expected (after block"));
+    assert_eq!(
+        OutputNoteState::ExpectedPartial.code().human_label(),
+        NoteStateCode::Expected.human_label()
+    );
 }
@@ -0,0 +1,100 @@
+//! Provides the [TimeSource] abstraction used by the client to timestamp the records it creates.
+//!
+//! `no_std` targets don't have access to a system clock, so hosts like embedded devices or the
+//! browser can supply their own wall-clock time with [Client::with_time_source](crate::Client).
+
+// TIME SOURCE
+// ================================================================================================
+
+/// Source of wall-clock time for the client.
+pub trait TimeSource: Send + Sync {
+    /// Returns the current time as the number of seconds elapsed since the Unix epoch.
+    fn now(&self) -> u64;
+}
+
+/// [TimeSource] backed by the system clock. Used by default when the `std` feature is enabled.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemTimeSource;
+
+#[cfg(feature = "std")]
+impl TimeSource for SystemTimeSource {
+    fn now(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the [TimeSource] used by the client when none is provided: the system clock under
+/// `std` and no time source otherwise.
+pub(crate) fn default_time_source() -> Option<alloc::sync::Arc<dyn TimeSource>> {
+    #[cfg(feature = "std")]
+    {
+        Some(alloc::sync::Arc::new(SystemTimeSource))
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        None
+    }
+}
+
+// STOPWATCH
+// ================================================================================================
+
+/// Measures elapsed time with a monotonic clock under `std`. Without `std`, it can fall back to a
+/// [TimeSource], which only measures whole seconds.
+pub(crate) struct Stopwatch {
+    #[cfg(feature = "std")]
+    started: std::time::Instant,
+    #[cfg(not(feature = "std"))]
+    started: Option<(alloc::sync::Arc<dyn TimeSource>, u64)>,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            started: std::time::Instant::now(),
+            #[cfg(not(feature = "std"))]
+            started: None,
+        }
+    }
+
+    /// Starts a stopwatch that uses `time_source` when there's no monotonic clock. Under `std`,
+    /// the time source is ignored.
+    pub(crate) fn start_with_time_source(
+        time_source: Option<alloc::sync::Arc<dyn TimeSource>>,
+    ) -> Self {
+        #[cfg(feature = "std")]
+        {
+            let _ = time_source;
+            Self::start()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Self {
+                started: time_source.map(|time_source| {
+                    let now = time_source.now();
+                    (time_source, now)
+                }),
+            }
+        }
+    }
+
+    /// Returns the time elapsed since the stopwatch was started, or `None` if it has no clock to
+    /// measure it with.
+    pub(crate) fn elapsed(&self) -> Option<core::time::Duration> {
+        #[cfg(feature = "std")]
+        {
+            Some(self.started.elapsed())
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.started.as_ref().map(|(time_source, started)| {
+                core::time::Duration::from_secs(time_source.now().saturating_sub(*started))
+            })
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use miden_objects::{accounts::AccountId, crypto::rand::FeltRng, notes::NoteId};
+use miden_tx::TransactionExecutorError;
+
+use super::TransactionRequest;
+use crate::{
+    notes::{NoteRelevance, NoteScreener},
+    Client, ClientError,
+};
+
+// CONSUMABILITY VERDICT
+// ================================================================================================
+
+/// Result of checking whether an account can consume a note with [Client::can_consume].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsumabilityVerdict {
+    /// The account can consume the note at the current block.
+    Consumable,
+    /// The account can't consume the note. Contains the reason.
+    NotConsumable(String),
+    /// The account can consume the note once the chain reaches the contained block.
+    ConsumableAfter(u32),
+}
+
+impl ConsumabilityVerdict {
+    /// Returns whether the account can consume the note at the current block.
+    pub fn is_consumable(&self) -> bool {
+        matches!(self, ConsumabilityVerdict::Consumable)
+    }
+}
+
+impl fmt::Display for ConsumabilityVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsumabilityVerdict::Consumable => write!(f, "Consumable"),
+            ConsumabilityVerdict::NotConsumable(reason) => write!(f, "Not consumable: {reason}"),
+            ConsumabilityVerdict::ConsumableAfter(block_num) => {
+                write!(f, "Consumable after block {block_num}")
+            },
+        }
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // CONSUMPTION CHECK
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks whether the specified account can consume the input note with the specified ID.
+    ///
+    /// Unlike [Client::get_consumable_notes], which only recognizes the standard note scripts,
+    /// the check executes a transaction that consumes just the note against the account's
+    /// current state, so it also applies to custom scripts that any account can consume. The
+    /// transaction is neither proven nor stored. Notes with a known script that are only
+    /// consumable after a certain block, like the recall of a P2IDR note, are reported as
+    /// [ConsumabilityVerdict::ConsumableAfter] without executing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the note or the account are not tracked by the client, if the
+    /// account can't execute transactions, or if the transaction couldn't be executed for
+    /// reasons other than the note's script failing.
+    pub async fn can_consume(
+        &mut self,
+        note_id: NoteId,
+        account_id: AccountId,
+    ) -> Result<ConsumabilityVerdict, ClientError> {
+        let note_record = self.get_input_note(note_id).await?;
+        if note_record.is_consumed() {
+            return Ok(ConsumabilityVerdict::NotConsumable("the note is consumed".to_string()));
+        }
+        if note_record.is_processing() {
+            return Ok(ConsumabilityVerdict::NotConsumable(
+                "the note is being consumed by another transaction".to_string(),
+            ));
+        }
+        let Ok(note) = note_record.to_note() else {
+            return Ok(ConsumabilityVerdict::NotConsumable(
+                "the note's metadata is unknown".to_string(),
+            ));
+        };
+
+        let block_num = self.get_sync_height().await?;
+        let relevances = NoteScreener::new(self.store.clone()).check_relevance(&note).await?;
+        let consumable_after = relevances.iter().find_map(|(id, relevance)| match relevance {
+            NoteRelevance::After(after_block) if *id == account_id && *after_block > block_num => {
+                Some(*after_block)
+            },
+            _ => None,
+        });
+        if let Some(after_block) = consumable_after {
+            return Ok(ConsumabilityVerdict::ConsumableAfter(after_block));
+        }
+
+        let transaction_request = TransactionRequest::consume_notes(vec![note_id]);
+        self.validate_request(account_id, &transaction_request).await?;
+
+        let tx_script = transaction_request
+            .build_transaction_script(self.get_account_capabilities(account_id).await?)?;
+        let note_ids: Vec<NoteId> = transaction_request.get_input_note_ids();
+        let tx_args = transaction_request.into_transaction_args(tx_script);
+
+        match self
+            .tx_executor
+            .execute_transaction(account_id, block_num, &note_ids, tx_args)
+            .await
+        {
+            Ok(_) => Ok(ConsumabilityVerdict::Consumable),
+            Err(err @ TransactionExecutorError::ExecuteTransactionProgramFailed(_)) => {
+                Ok(ConsumabilityVerdict::NotConsumable(err.to_string()))
+            },
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    accounts::AccountId,
+    crypto::rand::FeltRng,
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
+};
+use tracing::info;
+
+use super::{TransactionRecord, TransactionStatus};
+use crate::{
+    notes::NoteUpdates,
+    store::{InputNoteRecord, NoteFilter, TransactionFilter},
+    sync::{get_nullifier_prefix, NoteTagRecord, NoteTagSource},
+    Client, ClientError,
+};
+
+// LOCAL TRANSACTION DISCARD
+// ================================================================================================
+
+/// Changes applied to the store by [Client::discard_local_transaction], which are applied
+/// atomically along with marking the transaction as discarded.
+pub struct LocalTransactionDiscard {
+    /// ID of the discarded transaction.
+    pub transaction_id: TransactionId,
+    /// Account that executed the transaction.
+    pub account_id: AccountId,
+    /// Nonce of the account state the transaction was executed against. The states of the
+    /// account with higher nonces are removed.
+    pub init_account_nonce: u64,
+    /// Input notes consumed by the transaction, reverted to their states before it.
+    pub note_updates: NoteUpdates,
+    /// Notes created by the transaction, whose input and output note records are removed.
+    pub removed_note_ids: Vec<NoteId>,
+    /// Tags of the input notes reverted to being expected, which were removed when the notes were
+    /// committed.
+    pub tags_to_add: Vec<NoteTagRecord>,
+    /// Tags of the removed input notes.
+    pub tags_to_remove: Vec<NoteTagRecord>,
+}
+
+impl<R: FeltRng> Client<R> {
+    // LOCAL TRANSACTION DISCARD
+    // --------------------------------------------------------------------------------------------
+
+    /// Discards a pending transaction that was applied to the store but never reached the node,
+    /// like one applied before a crash or without being submitted.
+    ///
+    /// The account goes back to the state the transaction was executed against, the input notes
+    /// it consumed go back to their previous states, and the notes it created are removed along
+    /// with their tags. The transaction is kept, marked as discarded with the
+    /// [DiscardCause::ManuallyDiscarded](super::DiscardCause::ManuallyDiscarded) cause. All of the
+    /// changes are applied in a single store update.
+    ///
+    /// If the transaction consumed notes, the node is asked whether their nullifiers were
+    /// already committed, which means the transaction, or another one consuming the same notes,
+    /// reached the chain. Transactions without input notes are discarded without contacting the
+    /// node.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [ClientError::TransactionNotFound] if the client doesn't track the transaction.
+    /// - Returns [ClientError::LocalTransactionNotDiscardable] if the transaction isn't pending, if
+    ///   later local transactions build on its account state or consume its notes, or if the node
+    ///   already nullified its input notes.
+    /// - Returns [ClientError::RpcError] if the node can't be reached to check the input notes.
+    pub async fn discard_local_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Result<(), ClientError> {
+        let transaction = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .find(|tx| tx.id == transaction_id)
+            .ok_or(ClientError::TransactionNotFound(transaction_id))?;
+
+        let not_discardable =
+            |reason: String| ClientError::LocalTransactionNotDiscardable { transaction_id, reason };
+
+        if transaction.transaction_status != TransactionStatus::Pending {
+            return Err(not_discardable(format!(
+                "its status is {}",
+                transaction.transaction_status
+            )));
+        }
+
+        let (current_account, _) = self.store.get_account_header(transaction.account_id).await?;
+        if current_account.hash() != transaction.final_account_state {
+            return Err(not_discardable(format!(
+                "later local transactions of account {} build on it, and have to be discarded \
+                 first, starting with the latest one",
+                transaction.account_id
+            )));
+        }
+        let init_account = self
+            .store
+            .get_account_header_by_hash(transaction.init_account_state)
+            .await?
+            .ok_or_else(|| {
+                not_discardable(
+                    "the account state it was executed against is not stored".to_string(),
+                )
+            })?;
+
+        let created_note_ids: Vec<NoteId> =
+            transaction.output_notes.iter().map(|note| note.id()).collect();
+        let created_input_notes = if created_note_ids.is_empty() {
+            vec![]
+        } else {
+            self.store.get_input_notes(NoteFilter::List(created_note_ids.clone())).await?
+        };
+        if let Some(note) =
+            created_input_notes.iter().find(|note| note.consumer_transaction_id().is_some())
+        {
+            return Err(not_discardable(format!(
+                "its created note {} is consumed by a later local transaction",
+                note.id()
+            )));
+        }
+
+        if let Some(nullifier) = self.find_nullified_input_note(&transaction).await? {
+            return Err(not_discardable(format!(
+                "the node already nullified its input note with nullifier {nullifier}"
+            )));
+        }
+
+        let reverted_input_notes: Vec<InputNoteRecord> = if transaction
+            .input_note_nullifiers
+            .is_empty()
+        {
+            vec![]
+        } else {
+            let nullifiers =
+                transaction.input_note_nullifiers.iter().copied().map(Nullifier::from).collect();
+            self.store
+                .get_input_notes(NoteFilter::Nullifiers(nullifiers))
+                .await?
+                .into_iter()
+                .filter_map(|mut note| note.transaction_discarded(transaction_id).then_some(note))
+                .collect()
+        };
+
+        let tracked_tags = self.store.get_note_tags().await?;
+        let tags_to_add = reverted_input_notes
+            .iter()
+            .filter_map(NoteTagRecord::for_expected_note)
+            .filter(|tag| !tracked_tags.contains(tag))
+            .collect();
+        let tags_to_remove = tracked_tags
+            .into_iter()
+            .filter(|tag| match tag.source {
+                NoteTagSource::Note(note_id) => {
+                    created_input_notes.iter().any(|note| note.id() == note_id)
+                },
+                _ => false,
+            })
+            .collect();
+
+        info!(
+            "Discarding transaction {transaction_id}: {} input notes reverted and {} created notes \
+             removed",
+            reverted_input_notes.len(),
+            created_note_ids.len()
+        );
+
+        self.store
+            .discard_local_transaction(LocalTransactionDiscard {
+                transaction_id,
+                account_id: transaction.account_id,
+                init_account_nonce: init_account.nonce().as_int(),
+                note_updates: NoteUpdates::new(vec![], vec![], reverted_input_notes, vec![]),
+                removed_note_ids: created_note_ids,
+                tags_to_add,
+                tags_to_remove,
+            })
+            .await?;
+
+        // Drop the key set by the transaction if it was a key rotation
+        self.resolve_pending_auth_keys().await
+    }
+
+    /// Asks the node whether any of the input notes of the transaction was already nullified, and
+    /// returns the nullifier of the first one that was.
+    async fn find_nullified_input_note(
+        &mut self,
+        transaction: &TransactionRecord,
+    ) -> Result<Option<Nullifier>, ClientError> {
+        if transaction.input_note_nullifiers.is_empty() {
+            return Ok(None);
+        }
+
+        let nullifiers: Vec<Nullifier> =
+            transaction.input_note_nullifiers.iter().copied().map(Nullifier::from).collect();
+        let prefixes: Vec<u16> = nullifiers.iter().map(get_nullifier_prefix).collect();
+        let nullified = self.rpc_api.check_nullifiers_by_prefix(&prefixes).await?;
+
+        Ok(nullifiers
+            .into_iter()
+            .find(|nullifier| nullified.iter().any(|(nullified, _)| nullified == nullifier)))
+    }
+}
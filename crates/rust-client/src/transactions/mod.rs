@@ -4,51 +4,92 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
+    sync::Arc,
     vec::Vec,
 };
 use core::fmt::{self};
 
+use futures::future::join_all;
 pub use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     accounts::{
         Account, AccountCode, AccountDelta, AccountHeader, AccountId, AccountStorageHeader,
-        AccountType,
+        AccountType, AuthSecretKey,
     },
     assets::{Asset, NonFungibleAsset},
     crypto::merkle::MerklePath,
-    notes::{Note, NoteDetails, NoteId, NoteTag},
+    notes::{Note, NoteAssets, NoteDetails, NoteId, NoteRecipient, NoteTag, NoteType, Nullifier},
     transaction::{InputNotes, TransactionArgs},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     vm::AdviceInputs,
     AssetError, Digest, Felt, Word, ZERO,
 };
-pub use miden_tx::{LocalTransactionProver, ProvingOptions, TransactionProver};
+#[cfg(feature = "local-prover")]
+pub use miden_tx::LocalTransactionProver;
+pub use miden_tx::{ProvingOptions, TransactionProver};
+#[cfg(feature = "remote-prover")]
+pub use miden_tx_prover::RemoteTransactionProver;
 use script_builder::{AccountCapabilities, AccountInterface};
-use tracing::info;
+use tracing::{info, warn};
 
 use super::{Client, FeltRng};
 use crate::{
-    notes::{NoteScreener, NoteUpdates},
+    notes::{FutureNoteLink, NoteScreener, NoteUpdates},
+    rpc::{RpcError, SubmissionInfo},
     store::{
-        input_note_states::ExpectedNoteState, InputNoteRecord, InputNoteState, NoteFilter,
-        OutputNoteRecord, TransactionFilter,
+        input_note_states::ExpectedNoteState, InputNoteRecord, NoteFilter, OutputNoteRecord,
+        StoreError, TransactionFilter,
     },
     sync::NoteTagRecord,
-    ClientError,
+    ClientError, OutputNoteMismatch,
 };
 
+mod consumption_check;
+pub use consumption_check::ConsumabilityVerdict;
+
+mod discard;
+pub use discard::LocalTransactionDiscard;
+
 mod request;
 pub use request::{
-    NoteArgs, PaymentTransactionData, SwapTransactionData, TransactionRequest,
-    TransactionRequestError, TransactionScriptTemplate,
+    ForeignAccountInputs, InputNoteSpec, NoteArgs, PaymentTransactionData, SwapTransactionData,
+    TransactionRequest, TransactionRequestError, TransactionScriptTemplate,
+    DEFAULT_MAX_ADVICE_DATA_SIZE,
 };
 
+mod proving_backend;
+pub use proving_backend::{Acceleration, ProvingBackend, ProvingBackendInfo};
+
+#[cfg(all(feature = "std", feature = "local-prover"))]
+mod prover_pool;
+#[cfg(all(feature = "std", feature = "local-prover"))]
+pub use prover_pool::{ProverPool, ProverPoolConfig};
+
+mod script_args;
+pub use script_args::{script_arg_key, ScriptArg};
 mod script_builder;
+mod signing;
+pub use signing::SigningRequest;
+mod stateless;
+pub use stateless::StatelessTransactionInputs;
+mod view_call;
 pub use miden_objects::transaction::{
     ExecutedTransaction, InputNote, OutputNote, OutputNotes, ProvenTransaction, TransactionId,
     TransactionScript,
 };
 pub use miden_tx::{DataStoreError, TransactionExecutorError};
 pub use script_builder::TransactionScriptBuilderError;
+pub use view_call::{
+    ViewCallError, ViewProcedure, MAX_FOREIGN_VIEW_CALL_INPUTS, MAX_VIEW_CALL_INPUTS,
+};
+
+/// Number of times the header of the block foreign account data was retrieved at is requested
+/// before the transaction is rejected.
+const FOREIGN_ACCOUNT_BLOCK_ATTEMPTS: usize = 3;
+
+/// Default maximum size, in bytes, of the proven transactions retained by the client. It holds a
+/// few hundred proofs.
+pub const DEFAULT_MAX_RETAINED_PROOFS_SIZE: usize = 16 * 1024 * 1024;
 
 // TRANSACTION RESULT
 // --------------------------------------------------------------------------------------------
@@ -162,6 +203,8 @@ pub struct TransactionRecord {
     pub transaction_script: Option<TransactionScript>,
     pub block_num: u32,
     pub transaction_status: TransactionStatus,
+    pub(crate) provenance: Option<TransactionProvenance>,
+    pub(crate) discard_cause: Option<DiscardCause>,
 }
 
 impl TransactionRecord {
@@ -187,8 +230,126 @@ impl TransactionRecord {
             transaction_script,
             block_num,
             transaction_status,
+            provenance: None,
+            discard_cause: None,
         }
     }
+
+    /// Returns where and how the transaction was submitted to the network. Transactions that were
+    /// not submitted through the client, or that were stored by older versions of it, don't have
+    /// a provenance.
+    pub fn provenance(&self) -> Option<&TransactionProvenance> {
+        self.provenance.as_ref()
+    }
+
+    /// Returns why the transaction was discarded. Transactions discarded by older versions of the
+    /// client don't have a cause.
+    pub fn discard_cause(&self) -> Option<DiscardCause> {
+        self.discard_cause
+    }
+
+    /// Returns a summary of each note created by the transaction, in the order they were created.
+    ///
+    /// The summaries are available as soon as the transaction is stored, so they can be used to
+    /// show the notes of a pending transaction before they are committed.
+    pub fn output_note_summaries(&self) -> Vec<OutputNoteSummary> {
+        self.output_notes.iter().map(OutputNoteSummary::from).collect()
+    }
+}
+
+// OUTPUT NOTE SUMMARY
+// --------------------------------------------------------------------------------------------
+
+/// Displayable summary of a note created by a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputNoteSummary {
+    /// ID of the note.
+    pub id: NoteId,
+    /// Type of the note.
+    pub note_type: NoteType,
+    /// Assets of the note. Only known if the transaction kept more than the note's header.
+    pub assets: Option<NoteAssets>,
+    /// Recipient of the note. Only known if the transaction kept the note's full details.
+    pub recipient: Option<NoteRecipient>,
+}
+
+impl From<&OutputNote> for OutputNoteSummary {
+    fn from(note: &OutputNote) -> Self {
+        let (assets, recipient) = match note {
+            OutputNote::Full(note) => (Some(note.assets().clone()), Some(note.recipient().clone())),
+            OutputNote::Partial(note) => (Some(note.assets().clone()), None),
+            OutputNote::Header(_) => (None, None),
+        };
+
+        Self {
+            id: note.id(),
+            note_type: note.metadata().note_type(),
+            assets,
+            recipient,
+        }
+    }
+}
+
+// TRANSACTION PROVENANCE
+// --------------------------------------------------------------------------------------------
+
+/// Version of the client crate, recorded in the provenance of submitted transactions.
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Records how a transaction reached the network, to help diagnose transactions that never got
+/// committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionProvenance {
+    /// Endpoint of the node that accepted the transaction.
+    pub rpc_endpoint: String,
+    /// Version of the client that built and submitted the transaction.
+    pub client_version: String,
+    /// Description of the prover used to prove the transaction, if it was known to the client.
+    pub prover: Option<String>,
+    /// Number of attempts it took to submit the transaction, starting at 1.
+    pub submission_attempts: u32,
+}
+
+impl TransactionProvenance {
+    /// Returns the provenance of a transaction submitted by this version of the client.
+    pub fn new(submission: SubmissionInfo, prover: Option<String>) -> Self {
+        Self {
+            rpc_endpoint: submission.endpoint,
+            client_version: CLIENT_VERSION.to_string(),
+            prover,
+            submission_attempts: submission.attempts,
+        }
+    }
+
+    /// Returns whether the transaction was accepted on the first submission attempt.
+    pub fn submitted_on_first_attempt(&self) -> bool {
+        self.submission_attempts == 1
+    }
+}
+
+impl Serializable for TransactionProvenance {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.rpc_endpoint.write_into(target);
+        self.client_version.write_into(target);
+        self.prover.write_into(target);
+        target.write_u32(self.submission_attempts);
+    }
+}
+
+impl Deserializable for TransactionProvenance {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let rpc_endpoint = String::read_from(source)?;
+        let client_version = String::read_from(source)?;
+        let prover = Option::<String>::read_from(source)?;
+        let submission_attempts = source.read_u32()?;
+
+        Ok(Self {
+            rpc_endpoint,
+            client_version,
+            prover,
+            submission_attempts,
+        })
+    }
 }
 
 /// Represents the status of a transaction
@@ -214,6 +375,60 @@ impl fmt::Display for TransactionStatus {
     }
 }
 
+/// Reason a transaction was discarded, as returned by [TransactionRecord::discard_cause].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardCause {
+    /// One of the input notes of the transaction was consumed by another transaction.
+    InputNoteConsumedExternally,
+    /// The chain data of the client was reset with
+    /// [Client::reset_chain_state](crate::Client::reset_chain_state).
+    ChainReset,
+    /// The transaction was discarded with [Client::discard_local_transaction].
+    ManuallyDiscarded,
+    /// The transaction was pending when its account was removed with
+    /// [Client::remove_account](crate::Client::remove_account).
+    AccountRemoved,
+}
+
+impl fmt::Display for DiscardCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiscardCause::InputNoteConsumedExternally => {
+                write!(f, "Input note consumed by another transaction")
+            },
+            DiscardCause::ChainReset => write!(f, "Chain reset"),
+            DiscardCause::ManuallyDiscarded => write!(f, "Manually discarded"),
+            DiscardCause::AccountRemoved => write!(f, "Account removed"),
+        }
+    }
+}
+
+impl Serializable for DiscardCause {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let discriminant = match self {
+            DiscardCause::InputNoteConsumedExternally => 0,
+            DiscardCause::ChainReset => 1,
+            DiscardCause::ManuallyDiscarded => 2,
+            DiscardCause::AccountRemoved => 3,
+        };
+        target.write_u8(discriminant);
+    }
+}
+
+impl Deserializable for DiscardCause {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(DiscardCause::InputNoteConsumedExternally),
+            1 => Ok(DiscardCause::ChainReset),
+            2 => Ok(DiscardCause::ManuallyDiscarded),
+            3 => Ok(DiscardCause::AccountRemoved),
+            discriminant => Err(DeserializationError::InvalidValue(format!(
+                "Invalid discard cause discriminant: {discriminant}"
+            ))),
+        }
+    }
+}
+
 // TRANSACTION STORE UPDATE
 // --------------------------------------------------------------------------------------------
 
@@ -228,6 +443,10 @@ pub struct TransactionStoreUpdate {
     note_updates: NoteUpdates,
     /// New note tags to be tracked
     new_tags: Vec<NoteTagRecord>,
+    /// Provenance of the transaction, if it was submitted to the network
+    provenance: Option<TransactionProvenance>,
+    /// Links between the created notes and the expected notes their consumption creates
+    future_note_links: Vec<FutureNoteLink>,
 }
 
 impl TransactionStoreUpdate {
@@ -250,9 +469,23 @@ impl TransactionStoreUpdate {
                 vec![],
             ),
             new_tags,
+            provenance: None,
+            future_note_links: vec![],
         }
     }
 
+    /// Sets the provenance recorded for the transaction.
+    pub fn with_provenance(mut self, provenance: Option<TransactionProvenance>) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    /// Sets the links between the created notes and the expected notes their consumption creates.
+    pub fn with_future_note_links(mut self, future_note_links: Vec<FutureNoteLink>) -> Self {
+        self.future_note_links = future_note_links;
+        self
+    }
+
     /// Returns the executed transaction.
     pub fn executed_transaction(&self) -> &ExecutedTransaction {
         &self.executed_transaction
@@ -268,10 +501,21 @@ impl TransactionStoreUpdate {
         &self.note_updates
     }
 
+    /// Returns the links between the created notes and the expected notes their consumption
+    /// creates.
+    pub fn future_note_links(&self) -> &[FutureNoteLink] {
+        &self.future_note_links
+    }
+
     /// Returns the new tags that were created as part of the transaction.
     pub fn new_tags(&self) -> &[NoteTagRecord] {
         &self.new_tags
     }
+
+    /// Returns the provenance recorded for the transaction, if any.
+    pub fn provenance(&self) -> Option<&TransactionProvenance> {
+        self.provenance.as_ref()
+    }
 }
 
 impl<R: FeltRng> Client<R> {
@@ -286,9 +530,120 @@ impl<R: FeltRng> Client<R> {
         self.store.get_transactions(filter).await.map_err(|err| err.into())
     }
 
+    /// Retrieves the tracked transactions that were discarded, which can be executed again with
+    /// [Client::retry_discarded_transaction].
+    pub async fn get_discarded_transactions(&self) -> Result<Vec<TransactionRecord>, ClientError> {
+        Ok(self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|tx| tx.transaction_status == TransactionStatus::Discarded)
+            .collect())
+    }
+
+    /// Returns the net flow of fungible assets of the account over its committed transactions,
+    /// keyed by faucet ID. Assets of consumed notes count as incoming and are positive, while
+    /// assets of created notes count as outgoing and are negative.
+    ///
+    /// Pending and discarded transactions are skipped. Incoming assets are taken from the input
+    /// notes tracked by the store, so notes moved to the archive with
+    /// [Client::archive_notes](crate::Client::archive_notes) don't count towards the flow.
+    pub async fn get_account_net_flow(
+        &self,
+        account_id: AccountId,
+    ) -> Result<BTreeMap<AccountId, i64>, ClientError> {
+        let transactions: Vec<TransactionRecord> = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|tx| {
+                tx.account_id == account_id
+                    && matches!(tx.transaction_status, TransactionStatus::Committed(_))
+            })
+            .collect();
+
+        let nullifiers: Vec<Nullifier> = transactions
+            .iter()
+            .flat_map(|tx| tx.input_note_nullifiers.iter().copied().map(Nullifier::from))
+            .collect();
+        let consumed_notes = if nullifiers.is_empty() {
+            vec![]
+        } else {
+            self.store.get_input_notes(NoteFilter::Nullifiers(nullifiers)).await?
+        };
+
+        let (incoming, _) =
+            collect_assets(consumed_notes.iter().flat_map(|note| note.assets().iter()));
+        let (outgoing, _) = collect_assets(
+            transactions
+                .iter()
+                .flat_map(|tx| tx.output_notes.iter())
+                .filter_map(|note| note.assets())
+                .flat_map(|assets| assets.iter()),
+        );
+
+        // Each fungible amount fits in an i64, but the totals of several notes might not, so they
+        // saturate like the totals themselves
+        let mut net_flow: BTreeMap<AccountId, i64> = incoming
+            .into_iter()
+            .map(|(faucet_id, amount)| (faucet_id, i64::try_from(amount).unwrap_or(i64::MAX)))
+            .collect();
+        for (faucet_id, amount) in outgoing {
+            let flow = net_flow.entry(faucet_id).or_default();
+            *flow = flow.saturating_sub(i64::try_from(amount).unwrap_or(i64::MAX));
+        }
+
+        Ok(net_flow)
+    }
+
     // TRANSACTION
     // --------------------------------------------------------------------------------------------
 
+    /// Sets the maximum size, in bytes, of the serialized advice map and merkle store of the
+    /// transaction requests executed by the client. Requests can override it with
+    /// [TransactionRequest::with_max_advice_data_size].
+    ///
+    /// Defaults to [DEFAULT_MAX_ADVICE_DATA_SIZE].
+    pub fn set_max_advice_data_size(&mut self, max_size: usize) {
+        self.max_advice_data_size = max_size;
+    }
+
+    /// Sets whether the client retains the [ProvenTransaction] of the transactions it submits,
+    /// so that they can be retrieved with [Client::get_proven_transaction] and verified by third
+    /// parties. The proofs are retained up to [Client::set_max_retained_proofs_size] bytes,
+    /// evicting the least recently used ones.
+    ///
+    /// Disabled by default.
+    pub fn set_retain_proven_transactions(&mut self, retain: bool) {
+        self.retain_proven_transactions = retain;
+    }
+
+    /// Sets the maximum size, in bytes, of the proven transactions retained by the client.
+    ///
+    /// Defaults to [DEFAULT_MAX_RETAINED_PROOFS_SIZE].
+    pub fn set_max_retained_proofs_size(&mut self, max_size: usize) {
+        self.max_retained_proofs_size = max_size;
+    }
+
+    /// Retrieves the serialized [ProvenTransaction] of the submitted transaction with the
+    /// specified ID, or `None` if it wasn't retained or was evicted.
+    ///
+    /// Together with a [TransactionVerifier](miden_tx::TransactionVerifier), the bytes are enough
+    /// to verify the transaction's proof independently of the client: the proven transaction
+    /// holds the account ID, its initial and final commitments, the input note nullifiers, the
+    /// output note commitments and the reference block.
+    pub async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<Vec<u8>>, ClientError> {
+        self.store
+            .get_proven_transaction(transaction_id)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Creates and executes a transaction specified by the request against the specified account,
     /// but does not change the local database.
     ///
@@ -301,12 +656,17 @@ impl<R: FeltRng> Client<R> {
     /// - Returns [ClientError::MissingOutputNotes] if the [TransactionRequest] ouput notes are not
     ///   a subset of executor's output notes.
     /// - Returns a [ClientError::TransactionExecutorError] if the execution fails.
-    /// - Returns a [ClientError::TransactionRequestError] if the request is invalid.
+    /// - Returns a [ClientError::TransactionRequestError] if the request is invalid, or if its
+    ///   advice data is larger than the limit set with [Client::set_max_advice_data_size].
+    /// - Returns [ClientError::FpiBlockMismatch] if the request uses foreign account data and one
+    ///   of its authenticated input notes was committed after the block that data is at.
     pub async fn new_transaction(
         &mut self,
         account_id: AccountId,
         transaction_request: TransactionRequest,
     ) -> Result<TransactionResult, ClientError> {
+        transaction_request.check_advice_data_size(self.max_advice_data_size)?;
+
         // Validates the transaction request before executing
         self.validate_request(account_id, &transaction_request).await?;
 
@@ -321,7 +681,7 @@ impl<R: FeltRng> Client<R> {
             .get_input_notes(NoteFilter::List(authenticated_input_note_ids))
             .await?;
 
-        for authenticated_note_record in authenticated_note_records {
+        for authenticated_note_record in authenticated_note_records.iter() {
             if !authenticated_note_record.is_authenticated() {
                 return Err(ClientError::TransactionRequestError(
                     TransactionRequestError::InputNoteNotAuthenticated,
@@ -329,6 +689,46 @@ impl<R: FeltRng> Client<R> {
             }
         }
 
+        let input_note_assets = authenticated_note_records
+            .iter()
+            .map(|record| (record.id(), record.assets()))
+            .chain(
+                transaction_request
+                    .unauthenticated_input_notes()
+                    .iter()
+                    .map(|note| (note.id(), note.assets())),
+            );
+        transaction_request.check_min_note_values(input_note_assets)?;
+
+        // Inject foreign account data. This is done before anything is written to the store, so
+        // a failure to retrieve the foreign data doesn't leave the request half applied.
+        let (foreign_data_advice_inputs, foreign_account_codes, fpi_block_num) =
+            match transaction_request.foreign_account_inputs() {
+                Some((block_num, supplied_inputs)) => {
+                    check_fpi_block_consistency(block_num, &authenticated_note_records)?;
+                    self.validate_supplied_foreign_account_inputs(
+                        transaction_request.foreign_accounts(),
+                        supplied_inputs,
+                        block_num,
+                    )
+                    .await?;
+                    let (advice_inputs, account_codes) =
+                        build_foreign_account_advice_inputs(supplied_inputs.values())?;
+
+                    (advice_inputs, account_codes, Some(block_num))
+                },
+                None => {
+                    let (advice_inputs, account_codes, block_num) = self
+                        .get_foreign_account_inputs(transaction_request.foreign_accounts())
+                        .await?;
+                    if let Some(block_num) = block_num {
+                        check_fpi_block_consistency(block_num, &authenticated_note_records)?;
+                    }
+
+                    (advice_inputs, account_codes, block_num)
+                },
+            };
+
         // If tx request contains unauthenticated_input_notes we should insert them
         let unauthenticated_input_notes = transaction_request
             .unauthenticated_input_notes()
@@ -350,10 +750,6 @@ impl<R: FeltRng> Client<R> {
         let tx_script = transaction_request
             .build_transaction_script(self.get_account_capabilities(account_id).await?)?;
 
-        // Inject foreign account data
-        let (foreign_data_advice_inputs, foreign_account_codes, fpi_block_num) =
-            self.get_foreign_account_inputs(transaction_request.foreign_accounts()).await?;
-
         let tx_args = transaction_request
             .into_transaction_args(tx_script)
             .with_advice_inputs(foreign_data_advice_inputs);
@@ -374,67 +770,276 @@ impl<R: FeltRng> Client<R> {
             .execute_transaction(account_id, block_num, &note_ids, tx_args)
             .await?;
 
-        // Check that the expected output notes matches the transaction outcome.
-        // We compare authentication hashes where possible since that involves note IDs + metadata
-        // (as opposed to just note ID which remains the same regardless of metadata)
-        // We also do the check for partial output notes
+        // Check that the expected output notes matches the transaction outcome
+        let mismatches =
+            find_output_note_mismatches(&output_notes, executed_transaction.output_notes());
+        if !mismatches.is_empty() {
+            return Err(ClientError::MissingOutputNotes(mismatches));
+        }
+
+        let screener = NoteScreener::new(self.store.clone());
+
+        TransactionResult::new(executed_transaction, screener, future_notes).await
+    }
+
+    /// Executes again the intent of a discarded transaction against the current state of its
+    /// account, so that it can be submitted with [Client::submit_transaction].
+    ///
+    /// The new [TransactionRequest] consumes the same input notes and runs the same transaction
+    /// script as the discarded transaction, and expects it to create the same output notes
+    /// whose details are known. Note arguments are not stored with the transaction, so notes
+    /// that were consumed with arguments are consumed without them.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [ClientError::TransactionNotFound] if the client doesn't track the transaction.
+    /// - Returns [ClientError::TransactionNotDiscarded] if the transaction is pending or committed.
+    /// - Returns [ClientError::DiscardedTransactionNotRetriable] if any of the input notes of the
+    ///   transaction is no longer tracked, was consumed, or is being consumed by another
+    ///   transaction.
+    /// - Returns any of the errors of [Client::new_transaction] if the execution fails.
+    pub async fn retry_discarded_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Result<TransactionResult, ClientError> {
+        let transaction = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .find(|tx| tx.id == transaction_id)
+            .ok_or(ClientError::TransactionNotFound(transaction_id))?;
+
+        if transaction.transaction_status != TransactionStatus::Discarded {
+            return Err(ClientError::TransactionNotDiscarded(transaction_id));
+        }
+
+        let not_retriable = |reason: String| ClientError::DiscardedTransactionNotRetriable {
+            transaction_id,
+            reason,
+        };
+
+        let nullifiers: Vec<Nullifier> =
+            transaction.input_note_nullifiers.iter().copied().map(Nullifier::from).collect();
+        let input_note_records = if nullifiers.is_empty() {
+            vec![]
+        } else {
+            self.store.get_input_notes(NoteFilter::Nullifiers(nullifiers.clone())).await?
+        };
 
-        let tx_note_auth_hashes: BTreeSet<Digest> =
-            notes_from_output(executed_transaction.output_notes())
-                .map(|note| note.hash())
-                .collect();
+        let mut input_notes = Vec::with_capacity(nullifiers.len());
+        for nullifier in nullifiers {
+            let note_record = input_note_records
+                .iter()
+                .find(|note| note.nullifier() == nullifier)
+                .ok_or_else(|| {
+                    not_retriable(format!(
+                        "the input note with nullifier {nullifier} is not tracked"
+                    ))
+                })?;
+
+            if note_record.is_consumed() {
+                return Err(not_retriable(format!(
+                    "the input note {} was already consumed",
+                    note_record.id()
+                )));
+            }
+            if note_record.is_processing()
+                && note_record.consumer_transaction_id() != Some(&transaction_id)
+            {
+                return Err(not_retriable(format!(
+                    "the input note {} is being consumed by another transaction",
+                    note_record.id()
+                )));
+            }
 
-        let missing_note_ids: Vec<NoteId> = output_notes
+            let note_spec = if note_record.is_authenticated() {
+                InputNoteSpec::Authenticated(note_record.id())
+            } else {
+                InputNoteSpec::Unauthenticated(note_record.to_note()?)
+            };
+            input_notes.push((note_spec, None));
+        }
+
+        let expected_output_notes: Vec<Note> = transaction
+            .output_notes
             .iter()
-            .filter_map(|n| (!tx_note_auth_hashes.contains(&n.hash())).then_some(n.id()))
+            .filter_map(|note| match note {
+                OutputNote::Full(note) => Some(note.clone()),
+                _ => None,
+            })
             .collect();
 
-        if !missing_note_ids.is_empty() {
-            return Err(ClientError::MissingOutputNotes(missing_note_ids));
+        let mut transaction_request = TransactionRequest::new()
+            .with_input_notes_mixed(input_notes)
+            .with_expected_output_notes(expected_output_notes);
+        if let Some(script) = transaction.transaction_script {
+            transaction_request = transaction_request.with_custom_script(script)?;
         }
 
-        let screener = NoteScreener::new(self.store.clone());
-
-        TransactionResult::new(executed_transaction, screener, future_notes).await
+        self.new_transaction(transaction.account_id, transaction_request).await
     }
 
-    /// Proves the specified transaction, submits it to the network, and saves the transaction into
-    /// the local database for tracking.
+    /// Proves the specified transaction with the client's prover, which can be replaced with
+    /// [Client::with_prover], submits it to the network, and saves the transaction into the local
+    /// database for tracking.
     pub async fn submit_transaction(
         &mut self,
         tx_result: TransactionResult,
     ) -> Result<(), ClientError> {
-        let proven_transaction = self.prove_transaction(&tx_result).await?;
-        self.submit_proven_transaction(proven_transaction).await?;
-        self.apply_transaction(tx_result).await
+        let proven_transaction = prove_transaction(self.tx_prover.as_ref(), &tx_result).await?;
+        let submission = self.submit_proven_transaction(proven_transaction).await?;
+
+        let provenance = TransactionProvenance::new(submission, self.tx_prover_description.clone());
+        self.apply_transaction(tx_result, Some(provenance)).await
     }
 
-    async fn prove_transaction(
+    /// Proves the specified transaction with the provided prover instead of the client's one,
+    /// submits it to the network, and saves the transaction into the local database for tracking.
+    ///
+    /// The prover is not described in the transaction's [TransactionProvenance].
+    pub async fn submit_transaction_with_prover(
         &mut self,
-        tx_result: &TransactionResult,
-    ) -> Result<ProvenTransaction, ClientError> {
-        info!("Proving transaction...");
+        tx_result: TransactionResult,
+        tx_prover: Arc<dyn TransactionProver>,
+    ) -> Result<(), ClientError> {
+        let proven_transaction = prove_transaction(tx_prover.as_ref(), &tx_result).await?;
+        let submission = self.submit_proven_transaction(proven_transaction).await?;
 
-        let proven_transaction =
-            self.tx_prover.prove(tx_result.executed_transaction().clone().into()).await?;
+        let provenance = TransactionProvenance::new(submission, None);
+        self.apply_transaction(tx_result, Some(provenance)).await
+    }
 
-        info!("Transaction proven.");
+    /// Proves the specified transactions, submits them to the network, and saves them into the
+    /// local database for tracking.
+    ///
+    /// The proofs are awaited concurrently, so provers that support concurrent proving like
+    /// [ProverPool] prove the transactions in parallel. Once every transaction is proven, they
+    /// are submitted and applied in the provided order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the transactions can't be proven, in which case none of them is
+    /// submitted. If a submission fails, the transactions that come after it are not submitted.
+    pub async fn submit_transactions(
+        &mut self,
+        tx_results: Vec<TransactionResult>,
+    ) -> Result<(), ClientError> {
+        let tx_prover = self.tx_prover.clone();
+        let proven_transactions = join_all(
+            tx_results
+                .iter()
+                .map(|tx_result| prove_transaction(tx_prover.as_ref(), tx_result)),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(proven_transaction)
+        for (tx_result, proven_transaction) in tx_results.into_iter().zip(proven_transactions) {
+            let submission = self.submit_proven_transaction(proven_transaction).await?;
+
+            let provenance =
+                TransactionProvenance::new(submission, self.tx_prover_description.clone());
+            self.apply_transaction(tx_result, Some(provenance)).await?;
+        }
+
+        Ok(())
     }
 
+    /// Submits the proven transaction to the network, recording the attempt in the store first.
+    ///
+    /// The ID of the transaction is used as its idempotency key: if a previous attempt to submit
+    /// it was recorded, like one that timed out, the node is asked whether it already has the
+    /// transaction and the submission is skipped if it does.
     async fn submit_proven_transaction(
         &mut self,
         proven_transaction: ProvenTransaction,
-    ) -> Result<(), ClientError> {
-        info!("Submitting transaction to the network...");
-        self.rpc_api.submit_proven_transaction(proven_transaction).await?;
-        info!("Transaction submitted.");
+    ) -> Result<SubmissionInfo, ClientError> {
+        let transaction_id = proven_transaction.id();
+        let retained_proof = self.retain_proven_transactions.then(|| proven_transaction.to_bytes());
+
+        let prior_attempts = self.store.get_submission_attempts(transaction_id).await?;
+        let submission = if prior_attempts > 0
+            && self.transaction_reached_node(&proven_transaction).await?
+        {
+            info!(
+                "Transaction {transaction_id} already reached the node after {prior_attempts} \
+                 attempt(s), skipping its submission."
+            );
+            SubmissionInfo {
+                endpoint: self.rpc_api.endpoint().unwrap_or_default(),
+                attempts: prior_attempts,
+            }
+        } else {
+            self.store
+                .record_submission_attempt(transaction_id, proven_transaction.account_id())
+                .await?;
+
+            info!("Submitting transaction to the network...");
+            let mut submission = self.rpc_api.submit_proven_transaction(proven_transaction).await?;
+            submission.attempts += prior_attempts;
+            info!(
+                "Transaction submitted to {} after {} attempt(s).",
+                submission.endpoint, submission.attempts
+            );
+            submission
+        };
 
-        Ok(())
+        // The transaction is already in the network, so failing to retain its proof must not
+        // prevent it from being tracked
+        if let Some(proof) = retained_proof {
+            if let Err(err) = self
+                .store
+                .insert_proven_transaction(transaction_id, proof, self.max_retained_proofs_size)
+                .await
+            {
+                warn!("Failed to retain the proof of transaction {transaction_id}: {err}");
+            }
+        }
+
+        Ok(submission)
+    }
+
+    /// Returns whether the node already has the transaction, which is the case if the latest
+    /// state of its account known by the node is the one the transaction produces.
+    ///
+    /// Transactions that are accepted but not yet included in a block can't be told apart from
+    /// lost ones, so they're reported as not reaching the node. Any error other than the node not
+    /// knowing the account is returned, as the transaction may have reached it.
+    async fn transaction_reached_node(
+        &mut self,
+        proven_transaction: &ProvenTransaction,
+    ) -> Result<bool, ClientError> {
+        let account_id = proven_transaction.account_id();
+        match self.rpc_api.get_account_update(account_id).await {
+            Ok(account_details) => {
+                Ok(account_details.hash() == proven_transaction.account_update().final_state_hash())
+            },
+            // The node doesn't know about accounts created by a transaction until it's committed
+            Err(RpcError::NotFound(..)) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
     }
 
-    async fn apply_transaction(&self, tx_result: TransactionResult) -> Result<(), ClientError> {
+    /// Returns the number of attempts made to submit the transaction with the specified ID that
+    /// aren't confirmed yet, or 0 if there are none.
+    ///
+    /// Attempts are confirmed once the submission succeeds and the transaction is applied to the
+    /// store. A failed [Client::submit_transaction] can be retried with the same transaction
+    /// result: a recorded attempt makes the retry check whether the node already has the
+    /// transaction before submitting it again.
+    pub async fn get_submission_attempts(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<u32, ClientError> {
+        Ok(self.store.get_submission_attempts(transaction_id).await?)
+    }
+
+    async fn apply_transaction(
+        &self,
+        tx_result: TransactionResult,
+        provenance: Option<TransactionProvenance>,
+    ) -> Result<(), ClientError> {
         let transaction_id = tx_result.executed_transaction().id();
         let sync_height = self.get_sync_height().await?;
 
@@ -452,24 +1057,26 @@ impl<R: FeltRng> Client<R> {
         let created_input_notes = tx_result.relevant_notes().to_vec();
         let new_tags = created_input_notes
             .iter()
-            .filter_map(|note| {
-                if let InputNoteState::Expected(ExpectedNoteState { tag: Some(tag), .. }) =
-                    note.state()
-                {
-                    Some(NoteTagRecord::with_note_source(*tag, note.id()))
-                } else {
-                    None
-                }
-            })
+            .filter_map(NoteTagRecord::for_expected_note)
             .collect();
 
+        let submitted_at = self.current_timestamp();
+
+        let future_note_links = FutureNoteLink::from_created_notes(
+            account_id,
+            tx_result.created_notes().iter(),
+            &created_input_notes,
+        );
+
         // Save all output notes
         let created_output_notes = tx_result
             .created_notes()
             .iter()
             .cloned()
             .filter_map(|output_note| {
-                OutputNoteRecord::try_from_output_note(output_note, sync_height).ok()
+                OutputNoteRecord::try_from_output_note(output_note, sync_height)
+                    .ok()
+                    .map(|record| record.with_created_at(submitted_at))
             })
             .collect::<Vec<_>>();
 
@@ -478,7 +1085,7 @@ impl<R: FeltRng> Client<R> {
 
         let mut updated_input_notes = vec![];
         for mut input_note_record in consumed_notes {
-            if input_note_record.consumed_locally(account_id, transaction_id)? {
+            if input_note_record.consumed_locally(account_id, transaction_id, submitted_at)? {
                 updated_input_notes.push(input_note_record);
             }
         }
@@ -490,10 +1097,27 @@ impl<R: FeltRng> Client<R> {
             created_output_notes,
             updated_input_notes,
             new_tags,
-        );
+        )
+        .with_provenance(provenance)
+        .with_future_note_links(future_note_links);
 
+        let submitted = tx_update.provenance().is_some();
         self.store.apply_transaction(tx_update).await?;
         info!("Transaction stored.");
+
+        if submitted {
+            self.store.remove_submission_attempts(transaction_id).await?;
+        }
+
+        // The transaction is already stored, so failing to check the issuance must not be reported
+        // as a failure to apply it
+        if account_id.account_type() == AccountType::FungibleFaucet {
+            match self.get_faucet_issuance(account_id).await {
+                Ok(issuance) => issuance.warn_if_needed(),
+                Err(err) => warn!("Failed to check the issuance of faucet {account_id}: {err}"),
+            }
+        }
+
         Ok(())
     }
 
@@ -583,11 +1207,14 @@ impl<R: FeltRng> Client<R> {
         Ok(collect_assets(all_incoming_assets))
     }
 
-    async fn validate_basic_account_request(
+    /// Returns every reason why the specified basic account can't cover the outgoing assets of
+    /// the transaction request, in a deterministic order: fungible shortfalls sorted by faucet ID
+    /// followed by the missing non-fungible assets.
+    async fn get_basic_account_request_failures(
         &self,
         transaction_request: &TransactionRequest,
         account: &Account,
-    ) -> Result<(), ClientError> {
+    ) -> Result<Vec<ClientError>, ClientError> {
         // Get outgoing assets
         let (fungible_balance_map, non_fungible_set) =
             self.get_outgoing_assets(transaction_request);
@@ -596,13 +1223,15 @@ impl<R: FeltRng> Client<R> {
         let (incoming_fungible_balance_map, incoming_non_fungible_balance_set) =
             self.get_incoming_assets(transaction_request).await?;
 
+        let mut failures = Vec::new();
+
         // Check if the account balance plus incoming assets is greater than or equal to the
         // outgoing fungible assets
         for (faucet_id, amount) in fungible_balance_map {
             let account_asset_amount = account.vault().get_balance(faucet_id).unwrap_or(0);
             let incoming_balance = incoming_fungible_balance_map.get(&faucet_id).unwrap_or(&0);
             if account_asset_amount + incoming_balance < amount {
-                return Err(ClientError::AssetError(AssetError::AssetAmountNotSufficient(
+                failures.push(ClientError::AssetError(AssetError::AssetAmountNotSufficient(
                     account_asset_amount,
                     amount,
                 )));
@@ -612,25 +1241,19 @@ impl<R: FeltRng> Client<R> {
         // Check if the account balance plus incoming assets is greater than or equal to the
         // outgoing non fungible assets
         for non_fungible in non_fungible_set {
-            match account.vault().has_non_fungible_asset(non_fungible.into()) {
-                Ok(true) => (),
-                Ok(false) => {
-                    // Check if the non fungible asset is in the incoming assets
-                    if !incoming_non_fungible_balance_set.contains(&non_fungible) {
-                        return Err(ClientError::AssetError(AssetError::AssetAmountNotSufficient(
-                            0, 1,
-                        )));
-                    }
-                },
-                _ => {
-                    return Err(ClientError::AssetError(AssetError::AssetAmountNotSufficient(
-                        0, 1,
-                    )));
-                },
+            let missing = match account.vault().has_non_fungible_asset(non_fungible.into()) {
+                Ok(true) => false,
+                // Check if the non fungible asset is in the incoming assets
+                Ok(false) => !incoming_non_fungible_balance_set.contains(&non_fungible),
+                _ => true,
+            };
+
+            if missing {
+                failures.push(ClientError::AssetError(AssetError::AssetAmountNotSufficient(0, 1)));
             }
         }
 
-        Ok(())
+        Ok(failures)
     }
 
     /// Validates that the specified transaction request can be executed by the specified account.
@@ -638,17 +1261,80 @@ impl<R: FeltRng> Client<R> {
     /// This function checks that the account has enough balance to cover the outgoing assets. This
     /// does't guarantee that the transaction will succeed, but it's useful to avoid submitting
     /// transactions that are guaranteed to fail.
+    ///
+    /// Returns on the first failure found. Use [Client::validate_request_verbose] to get all of
+    /// them.
     pub async fn validate_request(
         &self,
         account_id: AccountId,
         transaction_request: &TransactionRequest,
     ) -> Result<(), ClientError> {
+        if self.store.get_account_lock_status(account_id).await?.is_locked() {
+            return Err(ClientError::AccountLocked(account_id));
+        }
+
+        if self.is_watch_only_account(account_id).await? {
+            return Err(ClientError::AccountIsWatchOnly(account_id));
+        }
+
         let (account, _) = self.get_account(account_id).await?;
         if account.is_faucet() {
             // TODO(SantiagoPittella): Add faucet validations.
             Ok(())
         } else {
-            self.validate_basic_account_request(transaction_request, &account).await
+            match self
+                .get_basic_account_request_failures(transaction_request, &account)
+                .await?
+                .into_iter()
+                .next()
+            {
+                Some(err) => Err(err),
+                None => Ok(()),
+            }
+        }
+    }
+
+    /// Validates that the specified transaction request can be executed by the specified account,
+    /// like [Client::validate_request], but collects every failure instead of returning on the
+    /// first one. This is meant for tools that compose transactions and need to report all the
+    /// problems of a request at once.
+    ///
+    /// The first returned error is always the one [Client::validate_request] would return. If the
+    /// validation itself can't be performed, for example because the account is not tracked, the
+    /// returned vector only contains that error.
+    pub async fn validate_request_verbose(
+        &self,
+        account_id: AccountId,
+        transaction_request: &TransactionRequest,
+    ) -> Result<(), Vec<ClientError>> {
+        let mut failures = Vec::new();
+
+        let lock_status = self
+            .store
+            .get_account_lock_status(account_id)
+            .await
+            .map_err(|err| vec![err.into()])?;
+        if lock_status.is_locked() {
+            failures.push(ClientError::AccountLocked(account_id));
+        }
+
+        if self.is_watch_only_account(account_id).await.map_err(|err| vec![err])? {
+            failures.push(ClientError::AccountIsWatchOnly(account_id));
+        }
+
+        let (account, _) = self.get_account(account_id).await.map_err(|err| vec![err])?;
+        if !account.is_faucet() {
+            let asset_failures = self
+                .get_basic_account_request_failures(transaction_request, &account)
+                .await
+                .map_err(|err| vec![err])?;
+            failures.extend(asset_failures);
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
         }
     }
 
@@ -660,20 +1346,7 @@ impl<R: FeltRng> Client<R> {
         let account = self.get_account(account_id).await?.0;
         let account_auth = self.get_account_auth(account_id).await?;
 
-        // TODO: we should check if the account actually exposes the interfaces we're trying to use
-        let account_capabilities = match account.account_type() {
-            AccountType::FungibleFaucet => AccountInterface::BasicFungibleFaucet,
-            AccountType::NonFungibleFaucet => todo!("Non fungible faucet not supported yet"),
-            AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
-                AccountInterface::BasicWallet
-            },
-        };
-
-        Ok(AccountCapabilities {
-            account_id,
-            auth: account_auth,
-            interfaces: account_capabilities,
-        })
+        Ok(account_capabilities(&account, account_auth)?)
     }
 
     /// Fetches foreign public account data as needed and returns advice inputs and account codes.
@@ -681,14 +1354,16 @@ impl<R: FeltRng> Client<R> {
     ///
     /// Account data is retrieved for the node's current chain tip, so we need to check whether we
     /// currently have the corresponding block header data. Otherwise, we additionally need to
-    /// retrieve it.
+    /// retrieve it, and the inputs are only returned once the header is tracked.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::ForeignAccountBlockUnavailable] if the header of the block can't be
+    /// retrieved and authenticated after [FOREIGN_ACCOUNT_BLOCK_ATTEMPTS] attempts.
     async fn get_foreign_account_inputs(
         &mut self,
         account_ids: &BTreeSet<AccountId>,
     ) -> Result<(AdviceInputs, Vec<AccountCode>, Option<u32>), ClientError> {
-        let mut advice_inputs = AdviceInputs::default();
-        let mut account_codes = Vec::new();
-
         if account_ids.is_empty() {
             return Ok((AdviceInputs::default(), vec![], None));
         }
@@ -697,38 +1372,102 @@ impl<R: FeltRng> Client<R> {
         let (block_num, account_proofs) =
             self.rpc_api.get_account_proofs(account_ids, &[], true).await?;
 
-        for account_proof in account_proofs.into_iter() {
+        let foreign_account_inputs = account_proofs.into_iter().map(|account_proof| {
             let account_header = account_proof.account_header().expect("RPC response should include this field becuase `include_headers` is on and no code commitments were sent");
             let account_code = account_proof.account_code().expect("RPC response should include this field becuase `include_headers` is on and no code commitments were sent");
             let storage_header = account_proof.storage_header().expect("RPC response should include this field becuase `include_headers` is on and no code commitments were sent");
 
-            account_codes.push(account_code.clone());
+            ForeignAccountInputs::new(
+                account_header.clone(),
+                storage_header.clone(),
+                account_code.clone(),
+                account_proof.merkle_proof().clone(),
+            )
+        }).collect::<Vec<_>>();
+
+        let (advice_inputs, account_codes) =
+            build_foreign_account_advice_inputs(foreign_account_inputs.iter())?;
+
+        let mut attempt = 1;
+        loop {
+            match self.get_foreign_account_block(block_num).await {
+                Ok(()) => break,
+                Err(err) if attempt < FOREIGN_ACCOUNT_BLOCK_ATTEMPTS => {
+                    warn!("Attempt {attempt} to get block {block_num} for foreign accounts failed: {err}");
+                    attempt += 1;
+                },
+                Err(err) => {
+                    warn!("Attempt {attempt} to get block {block_num} for foreign accounts failed: {err}");
+                    return Err(ClientError::ForeignAccountBlockUnavailable { block_num });
+                },
+            }
+        }
 
-            let merkle_path = account_proof.merkle_proof();
+        Ok((advice_inputs, account_codes, Some(block_num)))
+    }
 
-            // Extend advice inputs using the extracted data
-            extend_advice_inputs_for_account(
-                &mut advice_inputs,
-                account_header,
-                account_code,
-                storage_header,
-                merkle_path,
-            )?;
+    /// Makes sure the header of the block foreign account data was retrieved at is tracked by
+    /// the client, retrieving and authenticating it if it isn't.
+    async fn get_foreign_account_block(&mut self, block_num: u32) -> Result<(), ClientError> {
+        if !self.store.get_block_headers(&[block_num]).await?.is_empty() {
+            return Ok(());
         }
 
-        // Optionally retrieve block header if we don't have it
-        if self.store.get_block_headers(&[block_num]).await?.is_empty() {
+        // The block can only be authenticated once the client's MMR covers it
+        if self.store.get_sync_height().await? < block_num {
             info!("Getting current block header data to execute transaction with foreign account requirements");
-            let summary = self.sync_state().await?;
+            self.sync_state().await?;
+        }
 
-            if summary.block_num != block_num {
+        let block_header = match self.store.get_block_headers(&[block_num]).await?.pop() {
+            Some((block_header, _)) => block_header,
+            None => {
                 let mut current_partial_mmr = self.build_current_partial_mmr(true).await?;
+                if current_partial_mmr.forest() <= block_num as usize {
+                    return Err(StoreError::BlockHeaderNotFound(block_num).into());
+                }
                 self.get_and_store_authenticated_block(block_num, &mut current_partial_mmr)
-                    .await?;
-            }
+                    .await?
+            },
+        };
+
+        // The proofs are only valid against the state of the block they were retrieved at
+        if block_header.block_num() != block_num {
+            return Err(ClientError::RpcError(RpcError::InvalidResponse(format!(
+                "Requested block {block_num}, but got the header of block {}",
+                block_header.block_num()
+            ))));
         }
 
-        Ok((advice_inputs, account_codes, Some(block_num)))
+        Ok(())
+    }
+
+    /// Checks that pre-supplied foreign account inputs can be used to execute a transaction.
+    ///
+    /// # Errors
+    ///
+    /// - If a foreign account of the transaction has no supplied inputs.
+    /// - If the header of the block the inputs were retrieved at is not tracked by the client, as
+    ///   the transaction is executed against that block.
+    async fn validate_supplied_foreign_account_inputs(
+        &self,
+        account_ids: &BTreeSet<AccountId>,
+        supplied_inputs: &BTreeMap<AccountId, ForeignAccountInputs>,
+        block_num: u32,
+    ) -> Result<(), ClientError> {
+        if let Some(account_id) =
+            account_ids.iter().find(|account_id| !supplied_inputs.contains_key(account_id))
+        {
+            return Err(ClientError::TransactionRequestError(
+                TransactionRequestError::MissingForeignAccountInputs(*account_id),
+            ));
+        }
+
+        if self.store.get_block_headers(&[block_num]).await?.is_empty() {
+            return Err(StoreError::BlockHeaderNotFound(block_num).into());
+        }
+
+        Ok(())
     }
 }
 
@@ -741,24 +1480,49 @@ impl<R: FeltRng> Client<R> {
         &mut self,
         tx_result: &TransactionResult,
     ) -> Result<ProvenTransaction, ClientError> {
-        self.prove_transaction(tx_result).await
+        prove_transaction(self.tx_prover.as_ref(), tx_result).await
     }
 
     pub async fn testing_submit_proven_transaction(
         &mut self,
         proven_transaction: ProvenTransaction,
     ) -> Result<(), ClientError> {
-        self.submit_proven_transaction(proven_transaction).await
+        self.submit_proven_transaction(proven_transaction).await?;
+        Ok(())
     }
 
     pub async fn testing_apply_transaction(
         &self,
         tx_result: TransactionResult,
     ) -> Result<(), ClientError> {
-        self.apply_transaction(tx_result).await
+        self.apply_transaction(tx_result, None).await
     }
 }
 
+/// Builds the advice inputs that load the provided foreign accounts into a transaction, and
+/// returns them along with the code of the accounts.
+fn build_foreign_account_advice_inputs<'a>(
+    foreign_account_inputs: impl Iterator<Item = &'a ForeignAccountInputs>,
+) -> Result<(AdviceInputs, Vec<AccountCode>), ClientError> {
+    let mut advice_inputs = AdviceInputs::default();
+    let mut account_codes = Vec::new();
+
+    for account_inputs in foreign_account_inputs {
+        account_codes.push(account_inputs.account_code().clone());
+
+        // Extend advice inputs using the extracted data
+        extend_advice_inputs_for_account(
+            &mut advice_inputs,
+            account_inputs.account_header(),
+            account_inputs.account_code(),
+            account_inputs.storage_header(),
+            account_inputs.merkle_path(),
+        )?;
+    }
+
+    Ok((advice_inputs, account_codes))
+}
+
 /// Extends the advice inputs with account data and Merkle proofs.
 fn extend_advice_inputs_for_account(
     advice_inputs: &mut AdviceInputs,
@@ -814,6 +1578,21 @@ fn extend_advice_inputs_for_account(
 // HELPERS
 // ================================================================================================
 
+/// Proves the transaction of the provided result with the given prover.
+async fn prove_transaction(
+    tx_prover: &dyn TransactionProver,
+    tx_result: &TransactionResult,
+) -> Result<ProvenTransaction, ClientError> {
+    info!("Proving transaction...");
+
+    let proven_transaction =
+        tx_prover.prove(tx_result.executed_transaction().clone().into()).await?;
+
+    info!("Transaction proven.");
+
+    Ok(proven_transaction)
+}
+
 fn collect_assets<'a>(
     assets: impl Iterator<Item = &'a Asset>,
 ) -> (BTreeMap<AccountId, u64>, BTreeSet<NonFungibleAsset>) {
@@ -824,7 +1603,7 @@ fn collect_assets<'a>(
         Asset::Fungible(fungible) => {
             fungible_balance_map
                 .entry(fungible.faucet_id())
-                .and_modify(|balance| *balance += fungible.amount())
+                .and_modify(|balance| *balance = balance.saturating_add(fungible.amount()))
                 .or_insert(fungible.amount());
         },
         Asset::NonFungible(non_fungible) => {
@@ -843,18 +1622,99 @@ pub(crate) fn prepare_word(word: &Word) -> String {
 /// Used for:
 /// - checking the relevance of notes to save them as input notes
 /// - validate hashes versus expected output notes after a transaction is executed
+///
+/// Notes for which only partial details are known are skipped.
 pub fn notes_from_output(output_notes: &OutputNotes) -> impl Iterator<Item = &Note> {
-    output_notes
+    output_notes.iter().filter_map(|n| match n {
+        OutputNote::Full(n) => Some(n),
+        OutputNote::Header(_) | OutputNote::Partial(_) => None,
+    })
+}
+
+/// Returns the capabilities of the specified account, which is authenticated with `auth`.
+///
+/// # Errors
+///
+/// Returns [TransactionScriptBuilderError::UnsupportedAccountType] for non-fungible faucets.
+fn account_capabilities(
+    account: &Account,
+    auth: AuthSecretKey,
+) -> Result<AccountCapabilities, TransactionScriptBuilderError> {
+    // TODO: we should check if the account actually exposes the interfaces we're trying to use
+    let interfaces = match account.account_type() {
+        AccountType::FungibleFaucet => AccountInterface::BasicFungibleFaucet,
+        AccountType::NonFungibleFaucet => {
+            return Err(TransactionScriptBuilderError::UnsupportedAccountType(account.id()))
+        },
+        AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
+            AccountInterface::BasicWallet
+        },
+    };
+
+    Ok(AccountCapabilities {
+        account_id: account.id(),
+        auth,
+        interfaces,
+    })
+}
+
+/// Checks that the inclusion proofs of the authenticated input notes can be verified against
+/// the block the foreign account data is at, which is the block the transaction is executed
+/// against. Notes committed at or before that block are part of its chain MMR, so only notes
+/// committed after it are rejected.
+fn check_fpi_block_consistency(
+    fpi_block_num: u32,
+    authenticated_note_records: &[InputNoteRecord],
+) -> Result<(), ClientError> {
+    for record in authenticated_note_records {
+        let Some(inclusion_proof) = record.inclusion_proof() else {
+            continue;
+        };
+        let note_block_num = inclusion_proof.location().block_num();
+        if note_block_num > fpi_block_num {
+            return Err(ClientError::FpiBlockMismatch {
+                fpi_block_num,
+                note_id: record.id(),
+                note_block_num,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares the expected output notes of a transaction request with the notes produced by the
+/// executed transaction, returning the expected notes that weren't produced as they are.
+///
+/// We compare authentication hashes where possible since that involves note IDs + metadata (as
+/// opposed to just note ID which remains the same regardless of metadata). Notes with a matching
+/// ID but a different hash are reported along with the metadata of both.
+fn find_output_note_mismatches(
+    expected_notes: &[Note],
+    output_notes: &OutputNotes,
+) -> Vec<OutputNoteMismatch> {
+    let produced_notes: BTreeMap<NoteId, &Note> =
+        notes_from_output(output_notes).map(|note| (note.id(), note)).collect();
+
+    expected_notes
         .iter()
-        .filter(|n| matches!(n, OutputNote::Full(_)))
-        .map(|n| match n {
-            OutputNote::Full(n) => n,
-            // The following todo!() applies until we have a way to support flows where we have
-            // partial details of the note
-            OutputNote::Header(_) | OutputNote::Partial(_) => {
-                todo!("For now, all details should be held in OutputNote::Fulls")
+        .filter_map(|expected| match produced_notes.get(&expected.id()) {
+            None => Some(OutputNoteMismatch::Missing {
+                note_id: expected.id(),
+                expected_commitment: expected.hash(),
+            }),
+            Some(actual) if actual.hash() != expected.hash() => {
+                Some(OutputNoteMismatch::Mismatched {
+                    note_id: expected.id(),
+                    expected_commitment: expected.hash(),
+                    actual_commitment: actual.hash(),
+                    expected_metadata: *expected.metadata(),
+                    actual_metadata: *actual.metadata(),
+                })
             },
+            Some(_) => None,
         })
+        .collect()
 }
 
 #[cfg(test)]
@@ -866,17 +1726,22 @@ mod test {
                 ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
                 ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
             },
-            AccountBuilder, AccountComponent, AccountData, StorageMap, StorageSlot,
+            AccountBuilder, AccountComponent, AccountData, AccountType, AuthSecretKey, StorageMap,
+            StorageSlot,
         },
         assets::{Asset, FungibleAsset},
-        crypto::dsa::rpo_falcon512::SecretKey,
-        notes::NoteType,
+        crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
+        notes::{Note, NoteExecutionHint, NoteMetadata, NoteType},
         testing::account_component::BASIC_WALLET_CODE,
+        transaction::{OutputNote, OutputNotes},
         Felt, FieldElement, Word,
     };
 
-    use super::{PaymentTransactionData, TransactionRequest};
-    use crate::mock::create_test_client;
+    use super::{
+        account_capabilities, find_output_note_mismatches, PaymentTransactionData,
+        TransactionRequest, TransactionScriptBuilderError,
+    };
+    use crate::{mock::create_test_client, notes::create_p2id_note, OutputNoteMismatch};
 
     #[tokio::test]
     async fn test_transaction_creates_two_notes() {
@@ -910,11 +1775,14 @@ mod test {
             .unwrap();
 
         client
-            .import_account(AccountData::new(
-                account.clone(),
-                None,
-                miden_objects::accounts::AuthSecretKey::RpoFalcon512(secret_key.clone()),
-            ))
+            .import_account(
+                AccountData::new(
+                    account.clone(),
+                    None,
+                    miden_objects::accounts::AuthSecretKey::RpoFalcon512(secret_key.clone()),
+                ),
+                false,
+            )
             .await
             .unwrap();
         client.sync_state().await.unwrap();
@@ -939,4 +1807,87 @@ mod test {
         // Prove and apply transaction
         client.testing_apply_transaction(tx_result.clone()).await.unwrap();
     }
+
+    #[test]
+    fn test_non_fungible_faucet_capabilities_are_unsupported() {
+        let secret_key = SecretKey::new();
+        let (faucet, _) = AccountBuilder::new()
+            .init_seed(Default::default())
+            .account_type(AccountType::NonFungibleFaucet)
+            .with_component(RpoFalcon512::new(secret_key.public_key()))
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            account_capabilities(&faucet, AuthSecretKey::RpoFalcon512(secret_key)),
+            Err(TransactionScriptBuilderError::UnsupportedAccountType(account_id))
+                if account_id == faucet.id()
+        ));
+    }
+
+    #[test]
+    fn test_output_note_mismatches() {
+        let sender = ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap();
+        let faucet_id = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let mut rng = RpoRandomCoin::new(Word::default());
+
+        let produced_note = create_p2id_note(
+            sender,
+            faucet_id,
+            vec![asset],
+            NoteType::Private,
+            Felt::ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        let missing_note = create_p2id_note(
+            sender,
+            faucet_id,
+            vec![asset],
+            NoteType::Private,
+            Felt::ZERO,
+            &mut rng,
+        )
+        .unwrap();
+
+        let changed_metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            produced_note.metadata().tag(),
+            NoteExecutionHint::always(),
+            Felt::ZERO,
+        )
+        .unwrap();
+        let mismatched_note = Note::new(
+            produced_note.assets().clone(),
+            changed_metadata,
+            produced_note.recipient().clone(),
+        );
+        let output_notes =
+            OutputNotes::new(vec![OutputNote::Full(mismatched_note.clone())]).unwrap();
+
+        assert!(find_output_note_mismatches(&[mismatched_note.clone()], &output_notes).is_empty());
+
+        let mismatches = find_output_note_mismatches(
+            &[produced_note.clone(), missing_note.clone()],
+            &output_notes,
+        );
+        assert_eq!(
+            mismatches,
+            vec![
+                OutputNoteMismatch::Mismatched {
+                    note_id: produced_note.id(),
+                    expected_commitment: produced_note.hash(),
+                    actual_commitment: mismatched_note.hash(),
+                    expected_metadata: *produced_note.metadata(),
+                    actual_metadata: changed_metadata,
+                },
+                OutputNoteMismatch::Missing {
+                    note_id: missing_note.id(),
+                    expected_commitment: missing_note.hash(),
+                },
+            ]
+        );
+    }
 }
@@ -0,0 +1,257 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Mutex, PoisonError,
+    },
+    thread,
+};
+
+use async_trait::async_trait;
+use futures::channel::oneshot;
+use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
+use miden_tx::{LocalTransactionProver, ProvingOptions, TransactionProver, TransactionProverError};
+use pollster::FutureExt as _;
+
+// PROVER POOL CONFIG
+// ================================================================================================
+
+/// Configuration of a [ProverPool].
+#[derive(Debug, Clone)]
+pub struct ProverPoolConfig {
+    /// Maximum number of transactions proven at the same time.
+    pub parallelism: usize,
+    /// Estimated memory, in bytes, used by a single prover while proving a transaction.
+    pub memory_per_prover: Option<u64>,
+    /// Memory, in bytes, that the pool is allowed to use. Together with
+    /// [ProverPoolConfig::memory_per_prover], it caps the number of concurrent provers.
+    pub memory_budget: Option<u64>,
+    /// If set, transactions are proven one at a time regardless of the rest of the settings.
+    pub low_memory: bool,
+    /// Options used by every prover of the pool.
+    pub proving_options: ProvingOptions,
+}
+
+impl ProverPoolConfig {
+    /// Returns the number of provers that can run concurrently under this configuration, which is
+    /// always at least one.
+    pub fn effective_parallelism(&self) -> usize {
+        if self.low_memory {
+            return 1;
+        }
+
+        let memory_cap = match (self.memory_per_prover, self.memory_budget) {
+            (Some(per_prover), Some(budget)) if per_prover > 0 => {
+                usize::try_from(budget / per_prover).unwrap_or(usize::MAX)
+            },
+            _ => usize::MAX,
+        };
+
+        self.parallelism.min(memory_cap).max(1)
+    }
+}
+
+impl Default for ProverPoolConfig {
+    /// Uses all the available cores but one, without any memory constraints.
+    fn default() -> Self {
+        let parallelism = thread::available_parallelism()
+            .map(|cores| cores.get().saturating_sub(1))
+            .unwrap_or(1)
+            .max(1);
+
+        Self {
+            parallelism,
+            memory_per_prover: None,
+            memory_budget: None,
+            low_memory: false,
+            proving_options: ProvingOptions::default(),
+        }
+    }
+}
+
+// PROVER POOL
+// ================================================================================================
+
+/// [TransactionProver] that proves transactions concurrently on worker threads.
+///
+/// The pool spawns a worker thread with its own [LocalTransactionProver] for each transaction that
+/// can be proven at the same time. Every call to [TransactionProver::prove] queues the transaction
+/// for the first worker that becomes available, so the calling task is never blocked. Awaiting
+/// several proofs at once, as done by
+/// [Client::submit_transactions](crate::Client::submit_transactions), proves them in parallel.
+///
+/// A worker that panics while proving a transaction resolves its proof to an error and keeps
+/// serving the pool. The workers exit once the pool is dropped.
+pub struct ProverPool {
+    jobs: Sender<ProofJob<TransactionWitness, ProvenTransaction>>,
+    parallelism: usize,
+}
+
+/// Input of a proof queued in a [ProverPool], along with the channel through which its result is
+/// delivered. Dropping the job without sending a result resolves the proof to an error.
+struct ProofJob<I, O> {
+    input: I,
+    result_sender: oneshot::Sender<Result<O, TransactionProverError>>,
+}
+
+impl ProverPool {
+    /// Returns a new [ProverPool] with as many worker threads as the effective parallelism of the
+    /// provided configuration.
+    pub fn new(config: ProverPoolConfig) -> Self {
+        let parallelism = config.effective_parallelism();
+        let (jobs, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..parallelism {
+            let prover = LocalTransactionProver::new(config.proving_options.clone());
+            let receiver = receiver.clone();
+            thread::spawn(move || {
+                run_worker(&receiver, |tx_witness| prover.prove(tx_witness).block_on())
+            });
+        }
+
+        Self { jobs, parallelism }
+    }
+
+    /// Returns the maximum number of transactions proven at the same time by the pool.
+    pub fn parallelism(&self) -> usize {
+        self.parallelism
+    }
+}
+
+impl Default for ProverPool {
+    fn default() -> Self {
+        Self::new(ProverPoolConfig::default())
+    }
+}
+
+#[async_trait(?Send)]
+impl TransactionProver for ProverPool {
+    async fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.jobs.send(ProofJob { input: tx_witness, result_sender }).map_err(|_| {
+            TransactionProverError::InternalError(
+                "the workers of the prover pool stopped".to_string(),
+            )
+        })?;
+
+        result_receiver.await.unwrap_or_else(|_| {
+            Err(TransactionProverError::InternalError(
+                "the prover pool dropped the transaction before proving it".to_string(),
+            ))
+        })
+    }
+}
+
+// WORKER
+// ================================================================================================
+
+/// Runs the loop of a worker thread, which proves the queued jobs with `prove` until the pool that
+/// queues them is dropped.
+///
+/// A panic while proving a job is caught and reported as the job's result, so the worker keeps
+/// taking jobs and the lock of the queue is never held while proving.
+fn run_worker<I, O>(
+    jobs: &Mutex<Receiver<ProofJob<I, O>>>,
+    prove: impl Fn(I) -> Result<O, TransactionProverError>,
+) {
+    loop {
+        // The lock is released before proving, so other workers can take jobs in the meantime
+        let job = jobs.lock().unwrap_or_else(PoisonError::into_inner).recv();
+        let Ok(ProofJob { input, result_sender }) = job else {
+            return;
+        };
+
+        let result =
+            panic::catch_unwind(AssertUnwindSafe(|| prove(input))).unwrap_or_else(|payload| {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(TransactionProverError::InternalError(format!(
+                    "the prover panicked while proving the transaction: {message}"
+                )))
+            });
+
+        // The caller might have stopped waiting for the proof
+        let _ = result_sender.send(result);
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{mpsc, Mutex},
+        thread,
+    };
+
+    use futures::channel::oneshot;
+    use miden_tx::TransactionProverError;
+    use pollster::FutureExt as _;
+
+    use super::{run_worker, ProofJob, ProverPoolConfig};
+
+    #[test]
+    fn test_effective_parallelism() {
+        let config = ProverPoolConfig {
+            parallelism: 8,
+            memory_per_prover: Some(2 << 30),
+            memory_budget: Some(5 << 30),
+            ..Default::default()
+        };
+        assert_eq!(config.effective_parallelism(), 2);
+
+        let config = ProverPoolConfig { memory_budget: Some(1 << 30), ..config };
+        assert_eq!(config.effective_parallelism(), 1);
+
+        let config = ProverPoolConfig {
+            parallelism: 8,
+            memory_per_prover: None,
+            ..config
+        };
+        assert_eq!(config.effective_parallelism(), 8);
+
+        let config = ProverPoolConfig { low_memory: true, ..config };
+        assert_eq!(config.effective_parallelism(), 1);
+    }
+
+    #[test]
+    fn test_worker_survives_panicking_proofs() {
+        let (jobs, receiver) = mpsc::channel::<ProofJob<u32, u32>>();
+        let worker = thread::spawn(move || {
+            run_worker(&Mutex::new(receiver), |input| {
+                assert_ne!(input, 0, "can't prove zero");
+                Ok(input * 2)
+            })
+        });
+
+        let mut results = vec![];
+        for input in [0, 1] {
+            let (result_sender, result_receiver) = oneshot::channel();
+            jobs.send(ProofJob { input, result_sender }).unwrap();
+            results.push(result_receiver.block_on().unwrap());
+        }
+
+        assert!(matches!(
+            &results[0],
+            Err(TransactionProverError::InternalError(message)) if message.contains("can't prove zero")
+        ));
+        assert!(matches!(results[1], Ok(2)));
+
+        // The worker exits once the pool's queue is dropped
+        drop(jobs);
+        worker.join().unwrap();
+    }
+}
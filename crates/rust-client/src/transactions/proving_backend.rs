@@ -0,0 +1,181 @@
+use core::fmt;
+
+use miden_objects::crypto::rand::FeltRng;
+use tracing::info;
+
+use crate::{Client, ClientError};
+
+// PROVING BACKEND
+// ================================================================================================
+
+/// Backend used by the local prover to compute the hashes of the proofs.
+///
+/// The accelerated backends of the proving crates are chosen when the client is compiled: the
+/// `metal` feature enables the Metal GPU backend on Apple Silicon, and building with the `avx2`
+/// (x86_64) or `sve` (aarch64) target features enables the vectorized RPO implementations. The
+/// backend selected for a client can only be one that its build includes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProvingBackend {
+    /// Uses the accelerated backend if the build includes one for the running target, and the
+    /// portable one otherwise.
+    #[default]
+    Auto,
+    /// Requires the portable backend, which is the same on every target.
+    Portable,
+    /// Requires an accelerated backend.
+    Accelerated,
+}
+
+impl fmt::Display for ProvingBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProvingBackend::Auto => write!(f, "auto"),
+            ProvingBackend::Portable => write!(f, "portable"),
+            ProvingBackend::Accelerated => write!(f, "accelerated"),
+        }
+    }
+}
+
+/// Hardware acceleration used while proving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acceleration {
+    /// Metal GPU backend for Apple Silicon.
+    Metal,
+    /// AVX2 implementation of the RPO permutation.
+    Avx2,
+    /// SVE implementation of the RPO permutation.
+    Sve,
+}
+
+impl Acceleration {
+    /// Returns the acceleration included in this build of the client for the current target, if
+    /// any. The Metal backend takes precedence over the vectorized hash implementations. Builds
+    /// without the `local-prover` feature have no acceleration.
+    pub fn available() -> Option<Self> {
+        if !cfg!(feature = "local-prover") {
+            None
+        } else if cfg!(all(feature = "metal", target_arch = "aarch64", target_os = "macos")) {
+            Some(Acceleration::Metal)
+        } else if cfg!(all(target_arch = "x86_64", target_feature = "avx2")) {
+            Some(Acceleration::Avx2)
+        } else if cfg!(all(target_arch = "aarch64", target_feature = "sve")) {
+            Some(Acceleration::Sve)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Acceleration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Acceleration::Metal => write!(f, "Metal"),
+            Acceleration::Avx2 => write!(f, "AVX2"),
+            Acceleration::Sve => write!(f, "SVE"),
+        }
+    }
+}
+
+/// Proving backend selected for a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProvingBackendInfo {
+    /// Backend requested when the client was configured.
+    pub requested: ProvingBackend,
+    /// Acceleration used by the local prover, or `None` if it uses the portable backend.
+    pub acceleration: Option<Acceleration>,
+}
+
+impl ProvingBackendInfo {
+    /// Resolves the requested backend against the accelerations available in this build.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::ProvingBackendUnavailable] if an accelerated backend is requested
+    /// but the build doesn't include one, or if the portable backend is requested but the build
+    /// always uses an accelerated one. Returns a [ClientError::LocalProverUnavailable] if a
+    /// backend other than [ProvingBackend::Auto] is requested from a build without the
+    /// `local-prover` feature.
+    pub fn resolve(requested: ProvingBackend) -> Result<Self, ClientError> {
+        if !cfg!(feature = "local-prover") {
+            return match requested {
+                ProvingBackend::Auto => Ok(Self { requested, acceleration: None }),
+                _ => Err(ClientError::LocalProverUnavailable),
+            };
+        }
+
+        let acceleration = Acceleration::available();
+        match (requested, acceleration) {
+            (ProvingBackend::Accelerated, None) | (ProvingBackend::Portable, Some(_)) => {
+                Err(ClientError::ProvingBackendUnavailable(requested))
+            },
+            _ => Ok(Self { requested, acceleration }),
+        }
+    }
+}
+
+impl fmt::Display for ProvingBackendInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.acceleration {
+            Some(acceleration) => write!(f, "accelerated ({acceleration})"),
+            None => write!(f, "portable"),
+        }
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // PROVING BACKEND
+    // --------------------------------------------------------------------------------------------
+
+    /// Selects the backend used by the local prover and logs the selected one.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::ProvingBackendUnavailable] if the requested backend is not the one
+    /// included in this build of the client.
+    pub fn with_proving_backend(mut self, backend: ProvingBackend) -> Result<Self, ClientError> {
+        self.proving_backend = ProvingBackendInfo::resolve(backend)?;
+        info!("Using the {} proving backend.", self.proving_backend);
+
+        Ok(self)
+    }
+
+    /// Returns the backend used by the local prover. It doesn't apply to remote provers.
+    pub fn proving_backend_info(&self) -> ProvingBackendInfo {
+        self.proving_backend
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Acceleration, ProvingBackend, ProvingBackendInfo};
+
+    #[cfg(feature = "local-prover")]
+    #[test]
+    fn test_resolve_proving_backend() {
+        let auto = ProvingBackendInfo::resolve(ProvingBackend::Auto).unwrap();
+        assert_eq!(auto.acceleration, Acceleration::available());
+
+        let portable = ProvingBackendInfo::resolve(ProvingBackend::Portable);
+        let accelerated = ProvingBackendInfo::resolve(ProvingBackend::Accelerated);
+        assert_eq!(portable.is_ok(), Acceleration::available().is_none());
+        assert_eq!(accelerated.is_ok(), Acceleration::available().is_some());
+    }
+
+    #[cfg(not(feature = "local-prover"))]
+    #[test]
+    fn test_resolve_proving_backend_without_local_prover() {
+        let auto = ProvingBackendInfo::resolve(ProvingBackend::Auto).unwrap();
+        assert_eq!(auto.acceleration, None);
+        assert_eq!(Acceleration::available(), None);
+
+        for backend in [ProvingBackend::Portable, ProvingBackend::Accelerated] {
+            assert!(matches!(
+                ProvingBackendInfo::resolve(backend),
+                Err(crate::ClientError::LocalProverUnavailable)
+            ));
+        }
+    }
+}
@@ -7,16 +7,22 @@ use alloc::{
 };
 use core::fmt;
 
-use miden_lib::notes::{create_p2id_note, create_p2idr_note, create_swap_note};
+use miden_lib::{
+    notes::{create_p2id_note, create_p2idr_note, create_swap_note, utils::build_swap_tag},
+    transaction::TransactionKernel,
+};
 use miden_objects::{
-    accounts::AccountId,
+    accounts::{AccountCode, AccountHeader, AccountId, AccountStorageHeader},
     assembly::AssemblyError,
     assets::{Asset, FungibleAsset},
     crypto::{
-        merkle::{InnerNodeInfo, MerkleStore},
+        dsa::rpo_falcon512::PublicKey,
+        merkle::{InnerNodeInfo, MerklePath, MerkleStore},
         rand::FeltRng,
     },
-    notes::{Note, NoteDetails, NoteExecutionMode, NoteId, NoteTag, NoteType, PartialNote},
+    notes::{
+        Note, NoteAssets, NoteDetails, NoteExecutionMode, NoteId, NoteTag, NoteType, PartialNote,
+    },
     transaction::{OutputNote, TransactionArgs, TransactionScript},
     vm::AdviceMap,
     Digest, Felt, FieldElement, NoteError, Word,
@@ -24,10 +30,20 @@ use miden_objects::{
 use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{
+    prepare_word,
+    script_args::{add_script_arg_prologue, ScriptArg},
     script_builder::{AccountCapabilities, TransactionScriptBuilder},
-    TransactionScriptBuilderError,
+    SigningRequest, TransactionScriptBuilderError,
+};
+use crate::{
+    accounts::AuthKeyRotation,
+    notes::memo::{create_p2id_memo_note, MAX_MEMO_LEN},
 };
 
+/// Default maximum size, in bytes, of the serialized advice map and merkle store of a
+/// [TransactionRequest]. Requests over the limit are rejected when executed and when deserialized.
+pub const DEFAULT_MAX_ADVICE_DATA_SIZE: usize = 4 * 1024 * 1024;
+
 // TRANSACTION REQUEST
 // ================================================================================================
 
@@ -49,6 +65,26 @@ pub enum TransactionScriptTemplate {
     SendNotes(Vec<PartialNote>),
 }
 
+/// Specifies how an input note of a [TransactionRequest] is provided to the transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputNoteSpec {
+    /// The note is tracked by the client and authenticated against the chain with its inclusion
+    /// proof.
+    Authenticated(NoteId),
+    /// The note is provided in full and consumed without an inclusion proof.
+    Unauthenticated(Note),
+}
+
+impl InputNoteSpec {
+    /// Returns the ID of the specified note.
+    pub fn id(&self) -> NoteId {
+        match self {
+            InputNoteSpec::Authenticated(note_id) => *note_id,
+            InputNoteSpec::Unauthenticated(note) => note.id(),
+        }
+    }
+}
+
 /// A request for a transaction that can be executed by an account.
 ///
 /// A request contains information about input notes to be consumed by the transaction (if any),
@@ -78,9 +114,18 @@ pub struct TransactionRequest {
     /// the network, and injected as advice inputs. Additionally, the account's code will be
     /// added to the executor and prover.
     foreign_account_ids: BTreeSet<AccountId>,
+    /// Pre-supplied inputs of foreign accounts, along with the number of the block they were
+    /// retrieved at. When set, they are used instead of retrieving the foreign accounts from the
+    /// network.
+    foreign_account_inputs: Option<(u32, BTreeMap<AccountId, ForeignAccountInputs>)>,
     /// The number of blocks in relation to the transaction's reference block after which the
     /// transaction will expire.
     expiration_delta: Option<u16>,
+    /// Maximum size of the serialized advice data of this request, overriding the client's limit.
+    max_advice_data_size: Option<usize>,
+    /// Minimum amount of the fungible asset of each faucet that the input notes carrying it must
+    /// hold.
+    min_note_values: BTreeMap<AccountId, u64>,
 }
 
 impl TransactionRequest {
@@ -99,6 +144,9 @@ impl TransactionRequest {
             merkle_store: MerkleStore::default(),
             expiration_delta: None,
             foreign_account_ids: BTreeSet::default(),
+            foreign_account_inputs: None,
+            max_advice_data_size: None,
+            min_note_values: BTreeMap::new(),
         }
     }
 
@@ -125,6 +173,22 @@ impl TransactionRequest {
         self
     }
 
+    /// Adds the specified notes as input notes to the transaction request, each one either as
+    /// authenticated or unauthenticated according to its [InputNoteSpec], together with its
+    /// (optional) argument.
+    pub fn with_input_notes_mixed(
+        mut self,
+        notes: impl IntoIterator<Item = (InputNoteSpec, Option<NoteArgs>)>,
+    ) -> Self {
+        for (note_spec, argument) in notes {
+            self.input_notes.insert(note_spec.id(), argument);
+            if let InputNoteSpec::Unauthenticated(note) = note_spec {
+                self.unauthenticated_input_notes.push(note);
+            }
+        }
+        self
+    }
+
     /// Specifies the output notes that should be created in the transaction script and will
     /// be used as a transaction script template. These notes will also be added the the expected
     /// output notes of the transaction.
@@ -179,6 +243,34 @@ impl TransactionRequest {
         Ok(self)
     }
 
+    /// Specifies a custom transaction script compiled from `script_source`, which receives the
+    /// provided named arguments.
+    ///
+    /// The script reads each argument with `exec.arg_<name>`, a procedure generated for it that
+    /// loads its value from the advice map of the request, where it's stored under
+    /// [script_arg_key](super::script_arg_key). Felts and words are pushed onto the stack, while
+    /// felt arrays are written to memory starting at the address on top of the stack. See
+    /// [ScriptArg] for the details of each kind.
+    ///
+    /// # Errors
+    ///
+    /// - If a script template is already set, like with [TransactionRequest::with_custom_script].
+    /// - If the script invokes the procedure of an argument that isn't provided, or doesn't invoke
+    ///   the procedure of a provided argument, which is usually a misspelled name.
+    /// - If an argument name has characters other than ASCII letters, digits and underscores.
+    /// - If the script fails to compile.
+    pub fn with_custom_script_args(
+        self,
+        script_source: &str,
+        args: BTreeMap<String, ScriptArg>,
+    ) -> Result<Self, TransactionRequestError> {
+        let (source, advice_entries) = add_script_arg_prologue(script_source, &args)?;
+        let script = TransactionScript::compile(source, vec![], TransactionKernel::assembler())
+            .map_err(TransactionScriptBuilderError::InvalidTransactionScript)?;
+
+        Ok(self.with_custom_script(script)?.extend_advice_map(advice_entries))
+    }
+
     /// Specifies public account IDs that contain data that the transaction will utilize.
     ///
     /// At execution, the client queries the node and retrieves the state and current code for
@@ -201,6 +293,45 @@ impl TransactionRequest {
         Ok(self)
     }
 
+    /// Specifies foreign accounts that the transaction will utilize, along with the data needed
+    /// to load them, as of block `block_num`.
+    ///
+    /// Unlike [TransactionRequest::with_public_foreign_accounts], no request is made to the node
+    /// to retrieve the accounts at execution, so the inputs can also be used to load private
+    /// accounts. The header of block `block_num` must be tracked by the client executing the
+    /// transaction, and the transaction is executed against that block.
+    ///
+    /// # Errors
+    ///
+    /// - If the code of an account doesn't match the code commitment of its account header.
+    /// - If foreign account inputs were already specified for a different block.
+    pub fn with_foreign_account_inputs(
+        mut self,
+        inputs: Vec<ForeignAccountInputs>,
+        block_num: u32,
+    ) -> Result<Self, TransactionRequestError> {
+        let (supplied_block_num, supplied_inputs) =
+            self.foreign_account_inputs.get_or_insert_with(|| (block_num, BTreeMap::new()));
+        if *supplied_block_num != block_num {
+            return Err(TransactionRequestError::ForeignAccountInputsBlockMismatch(
+                *supplied_block_num,
+                block_num,
+            ));
+        }
+
+        for account_inputs in inputs {
+            let account_id = account_inputs.account_header().id();
+            if !account_inputs.is_consistent() {
+                return Err(TransactionRequestError::InvalidForeignAccountInputs(account_id));
+            }
+
+            self.foreign_account_ids.insert(account_id);
+            supplied_inputs.insert(account_id, account_inputs);
+        }
+
+        Ok(self)
+    }
+
     /// Specifies a transaction's expected output notes.
     ///
     /// The set of specified notes is treated as a subset of the notes that may be created by a
@@ -232,12 +363,36 @@ impl TransactionRequest {
         self
     }
 
+    /// Provides the signature produced by an external signer for a [SigningRequest] returned by
+    /// [Client::signing_requests](crate::Client::signing_requests), so that the transaction is
+    /// executed without asking the client's authenticator for it.
+    pub fn with_signature(self, request: &SigningRequest, signature: Vec<Felt>) -> Self {
+        self.extend_advice_map([(request.signature_key(), signature)])
+    }
+
     /// Extends the merkle store with the specified [InnerNodeInfo] elements.
     pub fn extend_merkle_store<T: IntoIterator<Item = InnerNodeInfo>>(mut self, iter: T) -> Self {
         self.merkle_store.extend(iter);
         self
     }
 
+    /// Sets the maximum size, in bytes, of the serialized advice map and merkle store of the
+    /// request, replacing the limit of the client that executes it.
+    pub fn with_max_advice_data_size(mut self, max_size: usize) -> Self {
+        self.max_advice_data_size = Some(max_size);
+        self
+    }
+
+    /// Sets the minimum amount of the fungible asset issued by `faucet_id` that each input note
+    /// carrying the asset must hold. [Client::new_transaction](crate::Client::new_transaction)
+    /// rejects the request before executing it if an input note holds less, so that wallets can
+    /// refuse dust notes without inspecting them one by one. Notes without assets from the faucet
+    /// are not affected.
+    pub fn with_min_note_value(mut self, faucet_id: AccountId, min_amount: u64) -> Self {
+        self.min_note_values.insert(faucet_id, min_amount);
+        self
+    }
+
     /// The number of blocks in relation to the transaction's reference block after which the
     /// transaction will expire.
     ///
@@ -272,6 +427,9 @@ impl TransactionRequest {
     /// Returns a new [TransactionRequest] for a transaction to mint fungible assets. This request
     /// must be executed against a fungible faucet account.
     ///
+    /// Faucets can't consume the notes they mint, so minting to the faucet's own account returns
+    /// a [TransactionRequestError::SelfTargetedNote].
+    ///
     /// - `asset` is the fungible asset to be minted.
     /// - `target_id` is the account ID of the account to receive the minted asset.
     /// - `note_type` determines the visibility of the note to be created.
@@ -283,6 +441,10 @@ impl TransactionRequest {
         note_type: NoteType,
         rng: &mut impl FeltRng,
     ) -> Result<Self, TransactionRequestError> {
+        if target_id == asset.faucet_id() {
+            return Err(TransactionRequestError::SelfTargetedNote(target_id));
+        }
+
         let created_note = create_p2id_note(
             asset.faucet_id(),
             target_id,
@@ -305,41 +467,101 @@ impl TransactionRequest {
     /// - `note_type` determines the visibility of the note to be created.
     /// - `rng` is the random number generator used to generate the serial number for the created
     ///   note.
+    ///
+    /// Fungible assets issued by the same faucet are merged into a single asset before the note is
+    /// created.
+    ///
+    /// # Errors
+    ///
+    /// - [TransactionRequestError::SelfTargetedNote] if the sender and target accounts are the
+    ///   same, unless the payment data allows it with
+    ///   [PaymentTransactionData::allow_self_transfer].
+    /// - [TransactionRequestError::ZeroAmountAsset] if a fungible asset has an amount of zero.
+    /// - [TransactionRequestError::FungibleAssetOverflow] if the merged amount of a faucet's assets
+    ///   exceeds the maximum amount of a fungible asset.
+    /// - [TransactionRequestError::TooManyAssets] if, once merged, there are more assets than a
+    ///   note can carry.
     pub fn pay_to_id(
         payment_data: PaymentTransactionData,
         recall_height: Option<u32>,
         note_type: NoteType,
         rng: &mut impl FeltRng,
     ) -> Result<Self, TransactionRequestError> {
-        let PaymentTransactionData {
-            assets,
-            sender_account_id,
-            target_account_id,
-        } = payment_data;
-        let created_note = if let Some(recall_height) = recall_height {
-            create_p2idr_note(
-                sender_account_id,
-                target_account_id,
-                assets,
-                note_type,
-                Felt::ZERO,
-                recall_height,
-                rng,
-            )?
-        } else {
-            create_p2id_note(
-                sender_account_id,
-                target_account_id,
-                assets,
-                note_type,
-                Felt::ZERO,
-                rng,
-            )?
-        };
+        let created_note = create_payment_note(payment_data, recall_height, note_type, rng)?;
 
         TransactionRequest::new().with_own_output_notes(vec![OutputNote::Full(created_note)])
     }
 
+    /// Returns a new [TransactionRequest] for a transaction that sends a P2ID or P2IDR note for
+    /// each of the `payments`, like [TransactionRequest::pay_to_id] does for a single payment.
+    /// This request must be executed against the wallet sender account, shared by every payment.
+    ///
+    /// The created notes are returned by [TransactionRequest::expected_output_notes] in the order
+    /// of their IDs, not of the payments.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [TransactionRequest::pay_to_id] for any of the payments, and:
+    /// - [TransactionRequestError::NoPayments] if `payments` is empty.
+    /// - [TransactionRequestError::InvalidSenderAccount] if a payment is sent from another account
+    ///   than the first one.
+    /// - [TransactionRequestError::FungibleAssetOverflow] if the total amount of a faucet's assets
+    ///   across all the payments exceeds the maximum amount of a fungible asset.
+    /// - [TransactionRequestError::DuplicateNonFungibleAsset] if a non-fungible asset is sent in
+    ///   more than one payment.
+    pub fn pay_to_ids(
+        payments: Vec<PaymentTransactionData>,
+        recall_height: Option<u32>,
+        note_type: NoteType,
+        rng: &mut impl FeltRng,
+    ) -> Result<Self, TransactionRequestError> {
+        let Some(sender_account_id) = payments.first().map(PaymentTransactionData::account_id)
+        else {
+            return Err(TransactionRequestError::NoPayments);
+        };
+
+        // The assets of all the notes leave the sender's vault, so they are checked as a whole
+        let mut fungible_totals: BTreeMap<AccountId, FungibleAsset> = BTreeMap::new();
+        let mut non_fungible_assets = BTreeSet::new();
+        for payment in payments.iter() {
+            if payment.account_id() != sender_account_id {
+                return Err(TransactionRequestError::InvalidSenderAccount(payment.account_id()));
+            }
+
+            for asset in payment.assets() {
+                match asset {
+                    Asset::Fungible(fungible_asset) => {
+                        let faucet_id = fungible_asset.faucet_id();
+                        let total = match fungible_totals.get(&faucet_id) {
+                            Some(total) => total.add(*fungible_asset).map_err(|_| {
+                                TransactionRequestError::FungibleAssetOverflow(faucet_id)
+                            })?,
+                            None => *fungible_asset,
+                        };
+                        fungible_totals.insert(faucet_id, total);
+                    },
+                    Asset::NonFungible(non_fungible_asset) => {
+                        if !non_fungible_assets.insert(*non_fungible_asset) {
+                            return Err(TransactionRequestError::DuplicateNonFungibleAsset(
+                                non_fungible_asset.faucet_id(),
+                            ));
+                        }
+                    },
+                }
+            }
+        }
+
+        let created_notes = payments
+            .into_iter()
+            .map(|payment_data| {
+                create_payment_note(payment_data, recall_height, note_type, rng)
+                    .map(OutputNote::Full)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        TransactionRequest::new().with_own_output_notes(created_notes)
+    }
+
     /// Returns a new [TransactionRequest] for a transaction to send a SWAP note. This request must
     /// be executed against the wallet sender account.
     ///
@@ -348,6 +570,9 @@ impl TransactionRequest {
     /// - `note_type` determines the visibility of the note to be created.
     /// - `rng` is the random number generator used to generate the serial number for the created
     ///   note.
+    ///
+    /// The payback note is tracked under the tag set with [SwapTransactionData::with_payback_tag],
+    /// or under the sender account's tag if none was set.
     pub fn swap(
         swap_data: SwapTransactionData,
         note_type: NoteType,
@@ -364,14 +589,49 @@ impl TransactionRequest {
             rng,
         )?;
 
-        let payback_tag =
-            NoteTag::from_account_id(swap_data.account_id(), NoteExecutionMode::Local)?;
+        let payback_tag = match swap_data.payback_tag() {
+            Some(tag) => tag,
+            None => NoteTag::from_account_id(swap_data.account_id(), NoteExecutionMode::Local)?,
+        };
 
         TransactionRequest::new()
             .with_expected_future_notes(vec![(payback_note_details, payback_tag)])
             .with_own_output_notes(vec![OutputNote::Full(created_note)])
     }
 
+    /// Returns a new [TransactionRequest] for a transaction that replaces the public key of the
+    /// account's authentication component with `new_pub_key`. This request must be executed
+    /// against an account with updatable code that includes the [AuthKeyRotation] component,
+    /// like the wallets created with
+    /// [AccountTemplate::RotatableWallet](crate::accounts::AccountTemplate::RotatableWallet). The
+    /// request can't consume any note, since the component rejects key updates in transactions
+    /// that do.
+    ///
+    /// The transaction is authenticated before the key is replaced, so it must be signed with the
+    /// account's current key. [Client::rotate_auth_key](crate::Client::rotate_auth_key) executes
+    /// this request and replaces the key in the client's keystore once it's committed.
+    pub fn rotate_auth_key(new_pub_key: PublicKey) -> Result<Self, TransactionRequestError> {
+        let script = format!(
+            "
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+                call.auth_tx::auth_tx_rpo_falcon512
+
+                push.{new_pub_key}
+                call.{set_auth_key_root}
+                dropw
+            end
+            ",
+            new_pub_key = prepare_word(&Word::from(new_pub_key)),
+            set_auth_key_root = AuthKeyRotation::set_auth_key_root(),
+        );
+        let script = TransactionScript::compile(script, vec![], TransactionKernel::assembler())
+            .map_err(TransactionScriptBuilderError::InvalidTransactionScript)?;
+
+        TransactionRequest::new().with_custom_script(script)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -440,11 +700,37 @@ impl TransactionRequest {
         &self.merkle_store
     }
 
+    /// Returns the size, in bytes, of the serialized advice map and merkle store of the
+    /// transaction request, which is what the advice data limits are checked against.
+    pub fn estimated_size(&self) -> usize {
+        self.advice_data_bytes().len()
+    }
+
+    /// Returns the maximum size of the advice data set for this request, if any.
+    pub fn max_advice_data_size(&self) -> Option<usize> {
+        self.max_advice_data_size
+    }
+
+    /// Returns the minimum amounts set with [TransactionRequest::with_min_note_value], by faucet.
+    pub fn min_note_values(&self) -> &BTreeMap<AccountId, u64> {
+        &self.min_note_values
+    }
+
     /// Returns the IDs of the required foreign accounts for the transaction request.
     pub fn foreign_accounts(&self) -> &BTreeSet<AccountId> {
         &self.foreign_account_ids
     }
 
+    /// Returns the pre-supplied inputs of foreign accounts for the transaction request, along
+    /// with the number of the block they were retrieved at, if any.
+    pub fn foreign_account_inputs(
+        &self,
+    ) -> Option<(u32, &BTreeMap<AccountId, ForeignAccountInputs>)> {
+        self.foreign_account_inputs
+            .as_ref()
+            .map(|(block_num, inputs)| (*block_num, inputs))
+    }
+
     /// Converts the [TransactionRequest] into [TransactionArgs] in order to be executed by a Miden
     /// host.
     pub(super) fn into_transaction_args(self, tx_script: TransactionScript) -> TransactionArgs {
@@ -464,6 +750,68 @@ impl TransactionRequest {
         tx_args
     }
 
+    /// Checks that the advice data of the request fits in its own limit, or in `default_limit` if
+    /// the request doesn't set one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TransactionRequestError::AdviceDataTooLarge] if the advice data exceeds the limit.
+    pub(crate) fn check_advice_data_size(
+        &self,
+        default_limit: usize,
+    ) -> Result<(), TransactionRequestError> {
+        let limit = self.max_advice_data_size.unwrap_or(default_limit);
+        let bytes = self.estimated_size();
+        if bytes > limit {
+            return Err(TransactionRequestError::AdviceDataTooLarge { bytes, limit });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the fungible assets of the provided input notes hold at least the minimum
+    /// amounts of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TransactionRequestError::NoteValueBelowMinimum] for the first asset under the
+    /// minimum amount of its faucet.
+    pub(crate) fn check_min_note_values<'a>(
+        &self,
+        notes: impl IntoIterator<Item = (NoteId, &'a NoteAssets)>,
+    ) -> Result<(), TransactionRequestError> {
+        if self.min_note_values.is_empty() {
+            return Ok(());
+        }
+
+        for (note_id, assets) in notes {
+            for asset in assets.iter() {
+                let Asset::Fungible(asset) = asset else { continue };
+                match self.min_note_values.get(&asset.faucet_id()) {
+                    Some(min_amount) if asset.amount() < *min_amount => {
+                        return Err(TransactionRequestError::NoteValueBelowMinimum {
+                            note_id,
+                            faucet_id: asset.faucet_id(),
+                            amount: asset.amount(),
+                            min_amount: *min_amount,
+                        });
+                    },
+                    _ => {},
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the advice map and merkle store of the request, serialized.
+    fn advice_data_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.advice_map.clone().into_iter().collect::<Vec<_>>().write_into(&mut bytes);
+        self.merkle_store.write_into(&mut bytes);
+        bytes
+    }
+
     pub(crate) fn build_transaction_script(
         &self,
         account_capabilities: AccountCapabilities,
@@ -507,15 +855,38 @@ impl Serializable for TransactionRequest {
         }
         self.expected_output_notes.write_into(target);
         self.expected_future_notes.write_into(target);
-        self.advice_map.clone().into_iter().collect::<Vec<_>>().write_into(target);
-        self.merkle_store.write_into(target);
+        // The advice data is length-prefixed, so that its size can be checked before reading it
+        let advice_data = self.advice_data_bytes();
+        target.write_usize(advice_data.len());
+        target.write_bytes(&advice_data);
         self.foreign_account_ids.write_into(target);
         self.expiration_delta.write_into(target);
+        self.foreign_account_inputs.write_into(target);
+        self.max_advice_data_size.map(|max_size| max_size as u64).write_into(target);
+        self.min_note_values.write_into(target);
     }
 }
 
 impl Deserializable for TransactionRequest {
+    /// Reads a [TransactionRequest], rejecting advice data larger than
+    /// [DEFAULT_MAX_ADVICE_DATA_SIZE]. Use [TransactionRequest::read_with_advice_data_limit] to
+    /// read requests with a different limit.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_with_advice_data_limit(source, DEFAULT_MAX_ADVICE_DATA_SIZE)
+    }
+}
+
+impl TransactionRequest {
+    /// Reads a [TransactionRequest] whose serialized advice map and merkle store take at most
+    /// `max_advice_data_size` bytes. The size is checked before the advice data is read, so
+    /// oversized inputs are rejected without allocating them.
+    ///
+    /// The limit set on the request itself with [TransactionRequest::with_max_advice_data_size]
+    /// is not used, as it's part of the untrusted input.
+    pub fn read_with_advice_data_limit<R: ByteReader>(
+        source: &mut R,
+        max_advice_data_size: usize,
+    ) -> Result<Self, DeserializationError> {
         let unauthenticated_input_notes = Vec::<Note>::read_from(source)?;
         let input_notes = BTreeMap::<NoteId, Option<NoteArgs>>::read_from(source)?;
 
@@ -539,12 +910,22 @@ impl Deserializable for TransactionRequest {
         let expected_output_notes = BTreeMap::<NoteId, Note>::read_from(source)?;
         let expected_future_notes = BTreeMap::<NoteId, (NoteDetails, NoteTag)>::read_from(source)?;
 
-        let mut advice_map = AdviceMap::new();
-        let advice_vec = Vec::<(Digest, Vec<Felt>)>::read_from(source)?;
-        advice_map.extend(advice_vec);
-        let merkle_store = MerkleStore::read_from(source)?;
+        let advice_data_size = source.read_usize()?;
+        if advice_data_size > max_advice_data_size {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Advice data takes {advice_data_size} bytes, but at most {max_advice_data_size} bytes are allowed"
+            )));
+        }
+        let AdviceData { advice_map, merkle_store } =
+            AdviceData::read_from_bytes(&source.read_vec(advice_data_size)?)?;
+
         let foreign_account_ids = BTreeSet::<AccountId>::read_from(source)?;
         let expiration_delta = Option::<u16>::read_from(source)?;
+        let foreign_account_inputs =
+            Option::<(u32, BTreeMap<AccountId, ForeignAccountInputs>)>::read_from(source)?;
+        let max_advice_data_size =
+            Option::<u64>::read_from(source)?.map(|max_size| max_size as usize);
+        let min_note_values = BTreeMap::<AccountId, u64>::read_from(source)?;
 
         Ok(TransactionRequest {
             unauthenticated_input_notes,
@@ -555,34 +936,166 @@ impl Deserializable for TransactionRequest {
             advice_map,
             merkle_store,
             foreign_account_ids,
+            foreign_account_inputs,
             expiration_delta,
+            max_advice_data_size,
+            min_note_values,
         })
     }
 }
 
+/// Advice map and merkle store of a serialized [TransactionRequest], read from the
+/// length-prefixed bytes that hold them.
+struct AdviceData {
+    advice_map: AdviceMap,
+    merkle_store: MerkleStore,
+}
+
+impl Deserializable for AdviceData {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut advice_map = AdviceMap::new();
+        let advice_vec = Vec::<(Digest, Vec<Felt>)>::read_from(source)?;
+        advice_map.extend(advice_vec);
+        let merkle_store = MerkleStore::read_from(source)?;
+
+        Ok(Self { advice_map, merkle_store })
+    }
+}
+
 impl Default for TransactionRequest {
     fn default() -> Self {
         Self::new()
     }
 }
 
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// Data required to load a foreign account into a transaction, as of a specific block.
+///
+/// This is the same data the client retrieves from the node for the accounts specified with
+/// [TransactionRequest::with_public_foreign_accounts], so it can be supplied with
+/// [TransactionRequest::with_foreign_account_inputs] to execute the transaction without
+/// contacting the node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    /// Header of the account's state.
+    account_header: AccountHeader,
+    /// Header of the account's storage.
+    storage_header: AccountStorageHeader,
+    /// Code of the account.
+    account_code: AccountCode,
+    /// Authentication path from the `account_root` of the block header to the account.
+    merkle_path: MerklePath,
+}
+
+impl ForeignAccountInputs {
+    /// Creates a new [ForeignAccountInputs].
+    pub fn new(
+        account_header: AccountHeader,
+        storage_header: AccountStorageHeader,
+        account_code: AccountCode,
+        merkle_path: MerklePath,
+    ) -> Self {
+        Self {
+            account_header,
+            storage_header,
+            account_code,
+            merkle_path,
+        }
+    }
+
+    /// Returns the header of the account's state.
+    pub fn account_header(&self) -> &AccountHeader {
+        &self.account_header
+    }
+
+    /// Returns the header of the account's storage.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the code of the account.
+    pub fn account_code(&self) -> &AccountCode {
+        &self.account_code
+    }
+
+    /// Returns the authentication path of the account in the account tree.
+    pub fn merkle_path(&self) -> &MerklePath {
+        &self.merkle_path
+    }
+
+    /// Returns whether the account's code matches the code commitment of its account header.
+    fn is_consistent(&self) -> bool {
+        self.account_code.commitment() == self.account_header.code_commitment()
+    }
+}
+
+impl Serializable for ForeignAccountInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_header.write_into(target);
+        self.storage_header.write_into(target);
+        self.account_code.write_into(target);
+        self.merkle_path.write_into(target);
+    }
+}
+
+impl Deserializable for ForeignAccountInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_header = AccountHeader::read_from(source)?;
+        let storage_header = AccountStorageHeader::read_from(source)?;
+        let account_code = AccountCode::read_from(source)?;
+        let merkle_path = MerklePath::read_from(source)?;
+
+        Ok(Self::new(account_header, storage_header, account_code, merkle_path))
+    }
+}
+
 // TRANSACTION REQUEST ERROR
 // ================================================================================================
 
 /// Errors related to a [TransactionRequest]
 #[derive(Debug)]
 pub enum TransactionRequestError {
+    AdviceDataTooLarge {
+        bytes: usize,
+        limit: usize,
+    },
+    DuplicateNonFungibleAsset(AccountId),
+    FungibleAssetOverflow(AccountId),
     InvalidForeignAccountId(AccountId),
+    InvalidForeignAccountInputs(AccountId),
+    ForeignAccountInputsBlockMismatch(u32, u32),
+    ForeignAccountsInStatelessExecution,
+    MissingForeignAccountInputs(AccountId),
     InputNoteNotAuthenticated,
     InputNotesMapMissingUnauthenticatedNotes,
     InvalidNoteVariant,
     InvalidSenderAccount(AccountId),
     InvalidTransactionScript(AssemblyError),
+    MemoTooLarge(usize),
+    MemoWithRecallHeight,
+    InvalidScriptArgName(String),
+    MissingScriptArg(String),
     NoInputNotes,
+    NoPayments,
+    NoteValueBelowMinimum {
+        note_id: NoteId,
+        faucet_id: AccountId,
+        amount: u64,
+        min_amount: u64,
+    },
     ScriptTemplateError(String),
+    SelfTargetedNote(AccountId),
     NoteNotFound(String),
     NoteCreationError(NoteError),
+    TooManyAssets {
+        count: usize,
+        max: usize,
+    },
     TransactionScriptBuilderError(TransactionScriptBuilderError),
+    UnusedScriptArg(String),
+    ZeroAmountAsset(AccountId),
 }
 
 impl From<TransactionScriptBuilderError> for TransactionRequestError {
@@ -594,17 +1107,34 @@ impl From<TransactionScriptBuilderError> for TransactionRequestError {
 impl fmt::Display for TransactionRequestError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::AdviceDataTooLarge { bytes, limit } => write!(f, "The advice map and merkle store of the request take {bytes} bytes, over the limit of {limit} bytes"),
+            Self::DuplicateNonFungibleAsset(faucet_id) => write!(f, "A non-fungible asset issued by faucet {faucet_id} is sent in more than one payment"),
+            Self::FungibleAssetOverflow(faucet_id) => write!(f, "The merged amount of the assets issued by faucet {faucet_id} exceeds the maximum amount of a fungible asset"),
             Self::InvalidForeignAccountId(acc_id) => write!(f, "Requested foreign account with ID {acc_id} is not public"),
+            Self::InvalidForeignAccountInputs(acc_id) => write!(f, "The code provided for foreign account {acc_id} doesn't match its code commitment"),
+            Self::ForeignAccountInputsBlockMismatch(supplied, requested) => write!(f, "Foreign account inputs were already supplied for block {supplied}, but new ones are for block {requested}"),
+            Self::MissingForeignAccountInputs(acc_id) => write!(f, "Foreign account {acc_id} has no supplied inputs, but inputs were supplied for other foreign accounts"),
+            Self::ForeignAccountsInStatelessExecution => write!(f, "Foreign accounts can't be used in transactions executed from stateless inputs"),
             Self::InputNoteNotAuthenticated => write!(f, "Every authenticated note to be consumed should be committed and contain a valid inclusion proof"),
             Self::InputNotesMapMissingUnauthenticatedNotes => write!(f, "The input notes map should include keys for all provided unauthenticated input notes"),
             Self::InvalidNoteVariant => write!(f, "Own notes should be either full or partial, but not header"),
             Self::InvalidSenderAccount(account_id) => write!(f, "Invalid sender account ID: {}", account_id),
             Self::InvalidTransactionScript(err) => write!(f, "Invalid transaction script: {}", err),
+            Self::MemoTooLarge(len) => write!(f, "Payment memo is {len} bytes long, but at most {MAX_MEMO_LEN} bytes fit in the note inputs"),
+            Self::MemoWithRecallHeight => write!(f, "Payment memos can't be attached to notes with a recall height"),
+            Self::InvalidScriptArgName(name) => write!(f, "Script argument name `{name}` can only have ASCII letters, digits and underscores"),
+            Self::MissingScriptArg(name) => write!(f, "The script reads argument `{name}` with `exec.arg_{name}`, but no value was provided for it"),
             Self::NoInputNotes => write!(f, "A transaction without output notes must have at least one input note"),
+            Self::NoPayments => write!(f, "A payment transaction must have at least one payment"),
+            Self::NoteValueBelowMinimum { note_id, faucet_id, amount, min_amount } => write!(f, "Input note {note_id} holds {amount} units of the asset issued by faucet {faucet_id}, below the minimum of {min_amount}"),
             Self::ScriptTemplateError(err) => write!(f, "Transaction script template error: {}", err),
+            Self::SelfTargetedNote(account_id) => write!(f, "The created note would target its own creator account {account_id}"),
             Self::NoteNotFound(err) => write!(f, "Note not found: {}", err),
             Self::NoteCreationError(err) => write!(f, "Note creation error: {}", err),
+            Self::TooManyAssets { count, max } => write!(f, "The note would carry {count} assets, but at most {max} fit in a note"),
             Self::TransactionScriptBuilderError(err) => write!(f, "Transaction script builder error: {}", err),
+            Self::UnusedScriptArg(name) => write!(f, "Argument `{name}` was provided, but the script never reads it with `exec.arg_{name}`"),
+            Self::ZeroAmountAsset(faucet_id) => write!(f, "The asset issued by faucet {faucet_id} has an amount of zero"),
         }
     }
 }
@@ -630,6 +1160,10 @@ pub struct PaymentTransactionData {
     sender_account_id: AccountId,
     /// Account ID of the receiver account.
     target_account_id: AccountId,
+    /// Memo for the receiver, carried in the inputs of the created note.
+    memo: Option<Vec<u8>>,
+    /// Whether the sender account is allowed to be the target of the payment.
+    allow_self_transfer: bool,
 }
 
 impl PaymentTransactionData {
@@ -646,9 +1180,36 @@ impl PaymentTransactionData {
             assets,
             sender_account_id,
             target_account_id,
+            memo: None,
+            allow_self_transfer: false,
         }
     }
 
+    /// Allows the sender account to be the target of the payment, which is otherwise rejected by
+    /// [TransactionRequest::pay_to_id]. Useful to split the sender's assets into several notes.
+    pub fn allow_self_transfer(mut self) -> Self {
+        self.allow_self_transfer = true;
+        self
+    }
+
+    /// Attaches a memo to the payment. The created note carries it in its inputs, so the target
+    /// account can read it with [read_memo](crate::notes::memo::read_memo).
+    ///
+    /// Notes with a memo use their own script instead of the P2ID one, and can't be recalled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TransactionRequestError::MemoTooLarge] if the memo is longer than
+    /// [MAX_MEMO_LEN] bytes.
+    pub fn with_memo(mut self, memo: Vec<u8>) -> Result<Self, TransactionRequestError> {
+        if memo.len() > MAX_MEMO_LEN {
+            return Err(TransactionRequestError::MemoTooLarge(memo.len()));
+        }
+
+        self.memo = Some(memo);
+        Ok(self)
+    }
+
     /// Returns the executor [AccountId]
     pub fn account_id(&self) -> AccountId {
         self.sender_account_id
@@ -663,6 +1224,11 @@ impl PaymentTransactionData {
     pub fn assets(&self) -> &Vec<Asset> {
         &self.assets
     }
+
+    /// Returns the memo attached to the payment, if any
+    pub fn memo(&self) -> Option<&[u8]> {
+        self.memo.as_deref()
+    }
 }
 
 // SWAP TRANSACTION DATA
@@ -681,6 +1247,8 @@ pub struct SwapTransactionData {
     offered_asset: Asset,
     /// Asset that is expected in the payback note generated as a result of the swap.
     requested_asset: Asset,
+    /// Tag under which the payback note is tracked, instead of the sender account's tag.
+    payback_tag: Option<NoteTag>,
 }
 
 impl SwapTransactionData {
@@ -697,9 +1265,18 @@ impl SwapTransactionData {
             sender_account_id,
             offered_asset,
             requested_asset,
+            payback_tag: None,
         }
     }
 
+    /// Sets the tag under which the payback note is tracked. By default, it's tracked under the
+    /// sender account's tag. Makers that advertise the swap can use the
+    /// [swap tag](Self::swap_tag) instead.
+    pub fn with_payback_tag(mut self, payback_tag: NoteTag) -> Self {
+        self.payback_tag = Some(payback_tag);
+        self
+    }
+
     /// Returns the executor [AccountId]
     pub fn account_id(&self) -> AccountId {
         self.sender_account_id
@@ -714,6 +1291,116 @@ impl SwapTransactionData {
     pub fn requested_asset(&self) -> Asset {
         self.requested_asset
     }
+
+    /// Returns the tag under which the payback note is tracked, if it was overridden.
+    pub fn payback_tag(&self) -> Option<NoteTag> {
+        self.payback_tag
+    }
+
+    /// Returns the tag of the SWAP note created with the specified type, through which takers can
+    /// discover the offer. It's derived from the note type and the faucets of the offered and
+    /// requested assets with [build_swap_tag].
+    pub fn swap_tag(&self, note_type: NoteType) -> Result<NoteTag, NoteError> {
+        build_swap_tag(note_type, &self.offered_asset, &self.requested_asset)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Creates the P2ID, P2IDR or memo note of a payment, as described in
+/// [TransactionRequest::pay_to_id].
+fn create_payment_note(
+    payment_data: PaymentTransactionData,
+    recall_height: Option<u32>,
+    note_type: NoteType,
+    rng: &mut impl FeltRng,
+) -> Result<Note, TransactionRequestError> {
+    let PaymentTransactionData {
+        assets,
+        sender_account_id,
+        target_account_id,
+        memo,
+        allow_self_transfer,
+    } = payment_data;
+    if sender_account_id == target_account_id && !allow_self_transfer {
+        return Err(TransactionRequestError::SelfTargetedNote(sender_account_id));
+    }
+    let assets = merge_payment_assets(assets)?;
+
+    let created_note = if let Some(memo) = memo {
+        if recall_height.is_some() {
+            return Err(TransactionRequestError::MemoWithRecallHeight);
+        }
+
+        create_p2id_memo_note(
+            sender_account_id,
+            target_account_id,
+            assets,
+            note_type,
+            Felt::ZERO,
+            &memo,
+            rng,
+        )?
+    } else if let Some(recall_height) = recall_height {
+        create_p2idr_note(
+            sender_account_id,
+            target_account_id,
+            assets,
+            note_type,
+            Felt::ZERO,
+            recall_height,
+            rng,
+        )?
+    } else {
+        create_p2id_note(sender_account_id, target_account_id, assets, note_type, Felt::ZERO, rng)?
+    };
+
+    Ok(created_note)
+}
+
+/// Merges the fungible assets issued by the same faucet, keeping the order in which faucets first
+/// appear, and checks that the result can be carried by a single note.
+fn merge_payment_assets(assets: Vec<Asset>) -> Result<Vec<Asset>, TransactionRequestError> {
+    let mut merged_assets: Vec<Asset> = Vec::with_capacity(assets.len());
+    let mut fungible_positions: BTreeMap<AccountId, usize> = BTreeMap::new();
+
+    for asset in assets {
+        let Asset::Fungible(fungible_asset) = asset else {
+            merged_assets.push(asset);
+            continue;
+        };
+
+        let faucet_id = fungible_asset.faucet_id();
+        if fungible_asset.amount() == 0 {
+            return Err(TransactionRequestError::ZeroAmountAsset(faucet_id));
+        }
+
+        match fungible_positions.get(&faucet_id) {
+            Some(&position) => {
+                let Asset::Fungible(merged_asset) = merged_assets[position] else {
+                    unreachable!("positions of fungible assets point to fungible assets");
+                };
+                merged_assets[position] = merged_asset
+                    .add(fungible_asset)
+                    .map_err(|_| TransactionRequestError::FungibleAssetOverflow(faucet_id))?
+                    .into();
+            },
+            None => {
+                fungible_positions.insert(faucet_id, merged_assets.len());
+                merged_assets.push(asset);
+            },
+        }
+    }
+
+    if merged_assets.len() > NoteAssets::MAX_NUM_ASSETS {
+        return Err(TransactionRequestError::TooManyAssets {
+            count: merged_assets.len(),
+            max: NoteAssets::MAX_NUM_ASSETS,
+        });
+    }
+
+    Ok(merged_assets)
 }
 
 // TESTS
@@ -725,16 +1412,21 @@ mod tests {
 
     use miden_lib::notes::create_p2id_note;
     use miden_objects::{
-        accounts::{AccountId, AccountType},
-        assets::FungibleAsset,
+        accounts::{
+            account_id::testing::ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN, AccountId, AccountType,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset},
         crypto::rand::{FeltRng, RpoRandomCoin},
-        notes::{NoteExecutionMode, NoteTag, NoteType},
+        notes::{NoteAssets, NoteExecutionMode, NoteTag, NoteType},
         transaction::OutputNote,
         Digest, Felt, ZERO,
     };
-    use miden_tx::utils::{Deserializable, Serializable};
+    use miden_tx::utils::{Deserializable, DeserializationError, Serializable, SliceReader};
 
-    use super::TransactionRequest;
+    use super::{
+        InputNoteSpec, PaymentTransactionData, SwapTransactionData, TransactionRequest,
+        TransactionRequestError, DEFAULT_MAX_ADVICE_DATA_SIZE,
+    };
 
     #[test]
     fn transaction_request_serialization() {
@@ -786,4 +1478,344 @@ mod tests {
         let deserialized_tx_request = TransactionRequest::read_from_bytes(&buffer).unwrap();
         assert_eq!(tx_request, deserialized_tx_request);
     }
+
+    #[test]
+    fn mixed_input_notes() {
+        let sender_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let target_id = AccountId::new_dummy([1u8; 32], AccountType::RegularAccountImmutableCode);
+        let faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let mut rng = RpoRandomCoin::new(Default::default());
+
+        let notes: Vec<_> = (0..2)
+            .map(|i| {
+                create_p2id_note(
+                    sender_id,
+                    target_id,
+                    vec![FungibleAsset::new(faucet_id, 100 + i).unwrap().into()],
+                    NoteType::Private,
+                    ZERO,
+                    &mut rng,
+                )
+                .unwrap()
+            })
+            .collect();
+        let note_args = [Felt::new(7), ZERO, ZERO, ZERO];
+
+        let mixed_request = TransactionRequest::new().with_input_notes_mixed([
+            (InputNoteSpec::Authenticated(notes[0].id()), None),
+            (InputNoteSpec::Unauthenticated(notes[1].clone()), Some(note_args)),
+        ]);
+        let two_call_request = TransactionRequest::new()
+            .with_authenticated_input_notes([(notes[0].id(), None)])
+            .with_unauthenticated_input_notes([(notes[1].clone(), Some(note_args))]);
+
+        assert_eq!(mixed_request, two_call_request);
+        assert_eq!(mixed_request.unauthenticated_input_notes(), &notes[1..]);
+        assert_eq!(
+            mixed_request.authenticated_input_note_ids().collect::<Vec<_>>(),
+            [notes[0].id()]
+        );
+    }
+
+    #[test]
+    fn self_targeted_notes() {
+        let account_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let mut rng = RpoRandomCoin::new(Default::default());
+
+        let result =
+            TransactionRequest::mint_fungible_asset(asset, faucet_id, NoteType::Private, &mut rng);
+        assert!(
+            matches!(result, Err(TransactionRequestError::SelfTargetedNote(id)) if id == faucet_id)
+        );
+
+        let payment_data = PaymentTransactionData::new(vec![asset.into()], account_id, account_id);
+        let result =
+            TransactionRequest::pay_to_id(payment_data.clone(), None, NoteType::Private, &mut rng);
+        assert!(
+            matches!(result, Err(TransactionRequestError::SelfTargetedNote(id)) if id == account_id)
+        );
+
+        let result = TransactionRequest::pay_to_id(
+            payment_data.allow_self_transfer(),
+            None,
+            NoteType::Private,
+            &mut rng,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn pay_to_id_asset_validation() {
+        let sender_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let target_id = AccountId::new_dummy([1u8; 32], AccountType::RegularAccountImmutableCode);
+        let faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let other_faucet_id = AccountId::new_dummy([3u8; 32], AccountType::FungibleFaucet);
+        let mut rng = RpoRandomCoin::new(Default::default());
+        let pay = |assets: Vec<Asset>, rng: &mut RpoRandomCoin| {
+            let payment_data = PaymentTransactionData::new(assets, sender_id, target_id);
+            TransactionRequest::pay_to_id(payment_data, None, NoteType::Private, rng)
+        };
+
+        // assets from the same faucet are merged into one
+        let tx_request = pay(
+            vec![
+                FungibleAsset::new(faucet_id, 40).unwrap().into(),
+                FungibleAsset::new(other_faucet_id, 10).unwrap().into(),
+                FungibleAsset::new(faucet_id, 60).unwrap().into(),
+            ],
+            &mut rng,
+        )
+        .unwrap();
+        let note = tx_request.expected_output_notes().next().unwrap();
+        let note_assets: Vec<Asset> = note.assets().iter().copied().collect();
+        assert_eq!(
+            note_assets,
+            [
+                FungibleAsset::new(faucet_id, 100).unwrap().into(),
+                FungibleAsset::new(other_faucet_id, 10).unwrap().into(),
+            ]
+        );
+
+        let result = pay(vec![FungibleAsset::new(faucet_id, 0).unwrap().into()], &mut rng);
+        assert!(
+            matches!(result, Err(TransactionRequestError::ZeroAmountAsset(id)) if id == faucet_id)
+        );
+
+        let max_amount = FungibleAsset::MAX_AMOUNT;
+        let result = pay(
+            vec![
+                FungibleAsset::new(faucet_id, max_amount).unwrap().into(),
+                FungibleAsset::new(faucet_id, 1).unwrap().into(),
+            ],
+            &mut rng,
+        );
+        assert!(matches!(
+            result,
+            Err(TransactionRequestError::FungibleAssetOverflow(id)) if id == faucet_id
+        ));
+
+        let too_many_assets = (0..=NoteAssets::MAX_NUM_ASSETS)
+            .map(|i| {
+                let mut seed = [2u8; 32];
+                seed[3..5].copy_from_slice(&(i as u16).to_le_bytes());
+                let faucet_id = AccountId::new_dummy(seed, AccountType::FungibleFaucet);
+                FungibleAsset::new(faucet_id, 1).unwrap().into()
+            })
+            .collect();
+        let result = pay(too_many_assets, &mut rng);
+        assert!(matches!(
+            result,
+            Err(TransactionRequestError::TooManyAssets { count, max })
+                if count == NoteAssets::MAX_NUM_ASSETS + 1 && max == NoteAssets::MAX_NUM_ASSETS
+        ));
+    }
+
+    #[test]
+    fn pay_to_ids_validation() {
+        let sender_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let target_ids = [1u8, 4, 5]
+            .map(|seed| AccountId::new_dummy([seed; 32], AccountType::RegularAccountImmutableCode));
+        let faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let mut rng = RpoRandomCoin::new(Default::default());
+
+        // one note is created for each payment
+        let payments: Vec<_> = target_ids
+            .iter()
+            .map(|target_id| {
+                let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+                PaymentTransactionData::new(vec![asset.into()], sender_id, *target_id)
+            })
+            .collect();
+        let tx_request =
+            TransactionRequest::pay_to_ids(payments.clone(), None, NoteType::Private, &mut rng)
+                .unwrap();
+        let notes: Vec<_> = tx_request.expected_output_notes().collect();
+        assert_eq!(notes.len(), target_ids.len());
+        for target_id in target_ids {
+            let tag = NoteTag::from_account_id(target_id, NoteExecutionMode::Local).unwrap();
+            assert!(notes.iter().any(|note| note.metadata().tag() == tag));
+        }
+
+        let result = TransactionRequest::pay_to_ids(vec![], None, NoteType::Private, &mut rng);
+        assert!(matches!(result, Err(TransactionRequestError::NoPayments)));
+
+        let mut mixed_senders = payments.clone();
+        mixed_senders.push(PaymentTransactionData::new(
+            payments[0].assets().clone(),
+            target_ids[0],
+            target_ids[1],
+        ));
+        let result =
+            TransactionRequest::pay_to_ids(mixed_senders, None, NoteType::Private, &mut rng);
+        assert!(matches!(
+            result,
+            Err(TransactionRequestError::InvalidSenderAccount(id)) if id == target_ids[0]
+        ));
+
+        // the amounts of a faucet are added up across all the payments
+        let max_amount = FungibleAsset::MAX_AMOUNT;
+        let overflowing_payments = target_ids[..2]
+            .iter()
+            .map(|target_id| {
+                let asset = FungibleAsset::new(faucet_id, max_amount / 2 + 1).unwrap();
+                PaymentTransactionData::new(vec![asset.into()], sender_id, *target_id)
+            })
+            .collect();
+        let result =
+            TransactionRequest::pay_to_ids(overflowing_payments, None, NoteType::Private, &mut rng);
+        assert!(matches!(
+            result,
+            Err(TransactionRequestError::FungibleAssetOverflow(id)) if id == faucet_id
+        ));
+
+        // a non-fungible asset can only be sent once
+        let non_fungible_asset =
+            NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN, &[1, 2, 3]);
+        let duplicated_payments = target_ids[..2]
+            .iter()
+            .map(|target_id| {
+                PaymentTransactionData::new(vec![non_fungible_asset], sender_id, *target_id)
+            })
+            .collect();
+        let result =
+            TransactionRequest::pay_to_ids(duplicated_payments, None, NoteType::Private, &mut rng);
+        assert!(matches!(
+            result,
+            Err(TransactionRequestError::DuplicateNonFungibleAsset(id))
+                if id == AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap()
+        ));
+    }
+
+    #[test]
+    fn advice_data_size_limits() {
+        let mut rng = RpoRandomCoin::new(Default::default());
+        let tx_request = TransactionRequest::new()
+            .extend_advice_map([(Digest::new(rng.draw_word()), vec![Felt::new(1); 100])]);
+        let size = tx_request.estimated_size();
+        assert!(size > 800);
+
+        assert!(tx_request.check_advice_data_size(size).is_ok());
+        assert!(matches!(
+            tx_request.check_advice_data_size(size - 1),
+            Err(TransactionRequestError::AdviceDataTooLarge { bytes, limit })
+                if bytes == size && limit == size - 1
+        ));
+
+        // the limit of the request replaces the one of the client
+        let tx_request = tx_request.with_max_advice_data_size(size);
+        assert!(tx_request.check_advice_data_size(size - 1).is_ok());
+        let tx_request = tx_request.with_max_advice_data_size(size - 1);
+        assert!(tx_request.check_advice_data_size(size).is_err());
+    }
+
+    #[test]
+    fn advice_data_size_limits_on_deserialization() {
+        let mut rng = RpoRandomCoin::new(Default::default());
+        let tx_request = TransactionRequest::new()
+            .extend_advice_map([(Digest::new(rng.draw_word()), vec![Felt::new(1); 100])]);
+        let size = tx_request.estimated_size();
+        let bytes = tx_request.to_bytes();
+
+        let read_request =
+            TransactionRequest::read_with_advice_data_limit(&mut SliceReader::new(&bytes), size)
+                .unwrap();
+        assert_eq!(read_request, tx_request);
+        assert!(matches!(
+            TransactionRequest::read_with_advice_data_limit(
+                &mut SliceReader::new(&bytes),
+                size - 1
+            ),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+
+        // requests over the default limit can't be read with the default deserializer, even if
+        // they set a larger limit themselves
+        let num_elements = DEFAULT_MAX_ADVICE_DATA_SIZE / 8 + 1;
+        let large_request = TransactionRequest::new()
+            .extend_advice_map([(Digest::new(rng.draw_word()), vec![Felt::new(1); num_elements])])
+            .with_max_advice_data_size(2 * DEFAULT_MAX_ADVICE_DATA_SIZE);
+        assert!(large_request.estimated_size() > DEFAULT_MAX_ADVICE_DATA_SIZE);
+        assert!(large_request.check_advice_data_size(DEFAULT_MAX_ADVICE_DATA_SIZE).is_ok());
+        assert!(matches!(
+            TransactionRequest::read_from_bytes(&large_request.to_bytes()),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn swap_tag_derivation() {
+        let sender_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let offered_faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let requested_faucet_id = AccountId::new_dummy([3u8; 32], AccountType::FungibleFaucet);
+        let swap_data = SwapTransactionData::new(
+            sender_id,
+            FungibleAsset::new(offered_faucet_id, 100).unwrap().into(),
+            FungibleAsset::new(requested_faucet_id, 50).unwrap().into(),
+        );
+
+        // the payload is made of bits 52..60 of the offered and requested faucet IDs
+        let faucet_bits = |faucet_id: AccountId| (u64::from(faucet_id) >> 52) as u8 as u16;
+        let payload = (faucet_bits(offered_faucet_id) << 8) | faucet_bits(requested_faucet_id);
+
+        assert_eq!(
+            swap_data.swap_tag(NoteType::Private).unwrap(),
+            NoteTag::for_local_use_case(0, payload).unwrap()
+        );
+        assert_eq!(
+            swap_data.swap_tag(NoteType::Public).unwrap(),
+            NoteTag::for_public_use_case(0, payload, NoteExecutionMode::Local).unwrap()
+        );
+
+        // the amounts don't take part in the derivation, but the direction of the swap does
+        let other_amounts = SwapTransactionData::new(
+            sender_id,
+            FungibleAsset::new(offered_faucet_id, 1).unwrap().into(),
+            FungibleAsset::new(requested_faucet_id, 1).unwrap().into(),
+        );
+        assert_eq!(
+            other_amounts.swap_tag(NoteType::Private).unwrap(),
+            swap_data.swap_tag(NoteType::Private).unwrap()
+        );
+        let reversed = SwapTransactionData::new(
+            sender_id,
+            FungibleAsset::new(requested_faucet_id, 50).unwrap().into(),
+            FungibleAsset::new(offered_faucet_id, 100).unwrap().into(),
+        );
+        assert_ne!(
+            reversed.swap_tag(NoteType::Private).unwrap(),
+            swap_data.swap_tag(NoteType::Private).unwrap()
+        );
+    }
+
+    #[test]
+    fn swap_payback_tag() {
+        let sender_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let offered_faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let requested_faucet_id = AccountId::new_dummy([3u8; 32], AccountType::FungibleFaucet);
+        let mut rng = RpoRandomCoin::new(Default::default());
+        let swap_data = SwapTransactionData::new(
+            sender_id,
+            FungibleAsset::new(offered_faucet_id, 100).unwrap().into(),
+            FungibleAsset::new(requested_faucet_id, 50).unwrap().into(),
+        );
+        let payback_tag = |swap_data: SwapTransactionData, rng: &mut RpoRandomCoin| {
+            let tx_request = TransactionRequest::swap(swap_data, NoteType::Private, rng).unwrap();
+            let future_notes: Vec<_> = tx_request.expected_future_notes().collect();
+            assert_eq!(future_notes.len(), 1);
+            future_notes[0].1
+        };
+
+        assert_eq!(swap_data.payback_tag(), None);
+        assert_eq!(
+            payback_tag(swap_data.clone(), &mut rng),
+            NoteTag::from_account_id(sender_id, NoteExecutionMode::Local).unwrap()
+        );
+
+        let swap_tag = swap_data.swap_tag(NoteType::Private).unwrap();
+        let swap_data = swap_data.with_payback_tag(swap_tag);
+        assert_eq!(swap_data.payback_tag(), Some(swap_tag));
+        assert_eq!(payback_tag(swap_data, &mut rng), swap_tag);
+    }
 }
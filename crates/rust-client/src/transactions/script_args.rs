@@ -0,0 +1,177 @@
+//! Named arguments of custom transaction scripts.
+//!
+//! Arguments are passed to a script through the advice map of the transaction request. The value
+//! of each argument is stored under a key derived from its name with [script_arg_key], and the
+//! script is compiled with a generated prologue that defines one procedure per argument, named
+//! `arg_<name>`, which loads the value:
+//!
+//! - [ScriptArg::Felt] and [ScriptArg::Word] are pushed onto the stack. The elements are pushed in
+//!   order, so the stack ends up as with `push.a.b.c.d`, with the last element on top.
+//! - [ScriptArg::Felts] are written to memory. The procedure expects the destination address on top
+//!   of the stack and consumes it, writing the elements in words starting at that address. The last
+//!   word is padded with zeros.
+//!
+//! The procedures are invoked with `exec.arg_<name>`. A script that invokes the procedure of an
+//! argument that isn't provided, or that doesn't invoke the procedure of a provided argument, is
+//! rejected before it's compiled. Scripts can also read an argument at any point of the
+//! execution by pushing its key and using `adv.push_mapval`.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{Digest, Felt, FieldElement, Hasher, Word};
+
+use super::TransactionRequestError;
+
+/// Prefix of the procedures generated for the arguments of a script.
+const ARG_PROCEDURE_PREFIX: &str = "arg_";
+
+/// Domain separator of the advice map keys of script arguments.
+const ARG_KEY_DOMAIN: &str = "miden-client::script-arg::";
+
+// SCRIPT ARG
+// ================================================================================================
+
+/// Value of a named argument of a custom transaction script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptArg {
+    /// Single element, pushed onto the stack.
+    Felt(Felt),
+    /// Word, pushed onto the stack.
+    Word(Word),
+    /// Array of elements, written to memory.
+    Felts(Vec<Felt>),
+}
+
+impl ScriptArg {
+    /// Returns the elements stored in the advice map for the argument. Arrays are padded to a
+    /// whole number of words.
+    fn advice_values(&self) -> Vec<Felt> {
+        match self {
+            ScriptArg::Felt(felt) => vec![*felt],
+            ScriptArg::Word(word) => word.to_vec(),
+            ScriptArg::Felts(felts) => {
+                let mut values = felts.clone();
+                values.resize(felts.len().div_ceil(4) * 4, Felt::ZERO);
+                values
+            },
+        }
+    }
+
+    /// Returns the body of the procedure that loads the argument stored under `key`.
+    fn load_procedure_body(&self, key: Digest) -> String {
+        let push_values = format!("push.{key} adv.push_mapval dropw");
+        match self {
+            ScriptArg::Felt(_) => format!("{push_values} adv_push.1"),
+            ScriptArg::Word(_) => format!("{push_values} adv_push.4"),
+            ScriptArg::Felts(felts) => {
+                // => [dest_addr, ...]
+                let store_words: String = (0..felts.len().div_ceil(4))
+                    .map(|offset| format!(" padw adv_loadw dup.4 add.{offset} mem_storew dropw"))
+                    .collect();
+                format!("{push_values}{store_words} drop")
+            },
+        }
+    }
+}
+
+impl From<Felt> for ScriptArg {
+    fn from(felt: Felt) -> Self {
+        ScriptArg::Felt(felt)
+    }
+}
+
+impl From<Word> for ScriptArg {
+    fn from(word: Word) -> Self {
+        ScriptArg::Word(word)
+    }
+}
+
+impl From<Vec<Felt>> for ScriptArg {
+    fn from(felts: Vec<Felt>) -> Self {
+        ScriptArg::Felts(felts)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the advice map key under which the value of the script argument named `name` is
+/// stored.
+pub fn script_arg_key(name: &str) -> Digest {
+    Hasher::hash(format!("{ARG_KEY_DOMAIN}{name}").as_bytes())
+}
+
+/// Returns the source of the script with the procedures that load the provided arguments, along
+/// with the advice map entries that hold their values.
+///
+/// # Errors
+///
+/// - If an argument name is not a valid procedure name suffix.
+/// - If the script invokes the procedure of an argument that isn't provided.
+/// - If the script doesn't invoke the procedure of a provided argument.
+/// - If the script has no `begin` block to insert the procedures before.
+pub(crate) fn add_script_arg_prologue(
+    script_source: &str,
+    args: &BTreeMap<String, ScriptArg>,
+) -> Result<(String, Vec<(Digest, Vec<Felt>)>), TransactionRequestError> {
+    for name in args.keys() {
+        if name.is_empty() || !name.chars().all(is_arg_name_char) {
+            return Err(TransactionRequestError::InvalidScriptArgName(name.clone()));
+        }
+    }
+
+    let referenced = referenced_arg_names(script_source);
+    if let Some(name) = referenced.iter().find(|name| !args.contains_key(*name)) {
+        return Err(TransactionRequestError::MissingScriptArg(name.clone()));
+    }
+    if let Some(name) = args.keys().find(|name| !referenced.contains(*name)) {
+        return Err(TransactionRequestError::UnusedScriptArg(name.clone()));
+    }
+
+    let begin = script_source
+        .lines()
+        .find(|line| line.split_whitespace().next() == Some("begin"))
+        .map(|line| line.as_ptr() as usize - script_source.as_ptr() as usize)
+        .ok_or_else(|| {
+            TransactionRequestError::ScriptTemplateError(
+                "The script has no `begin` block".to_string(),
+            )
+        })?;
+
+    let mut source = String::from(&script_source[..begin]);
+    let mut advice_entries = Vec::with_capacity(args.len());
+    for (name, arg) in args {
+        let key = script_arg_key(name);
+        source.push_str(&format!(
+            "proc.{ARG_PROCEDURE_PREFIX}{name}\n    {}\nend\n\n",
+            arg.load_procedure_body(key)
+        ));
+        advice_entries.push((key, arg.advice_values()));
+    }
+    source.push_str(&script_source[begin..]);
+
+    Ok((source, advice_entries))
+}
+
+/// Returns the names of the arguments whose procedures are invoked by the script.
+fn referenced_arg_names(script_source: &str) -> BTreeSet<String> {
+    let invocation = format!("exec.{ARG_PROCEDURE_PREFIX}");
+    script_source
+        .match_indices(&invocation)
+        .map(|(index, _)| {
+            script_source[index + invocation.len()..]
+                .chars()
+                .take_while(|c| is_arg_name_char(*c))
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn is_arg_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
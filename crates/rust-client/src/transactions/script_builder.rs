@@ -222,6 +222,7 @@ pub enum TransactionScriptBuilderError {
     InvalidTransactionScript(TransactionScriptError),
     InvalidSenderAccount(AccountId),
     TransactionExecutorError(TransactionExecutorError),
+    UnsupportedAccountType(AccountId),
 }
 
 impl core::fmt::Display for TransactionScriptBuilderError {
@@ -239,6 +240,14 @@ impl core::fmt::Display for TransactionScriptBuilderError {
             TransactionScriptBuilderError::TransactionExecutorError(err) => {
                 write!(f, "Transaction executor error: {}", err)
             },
+            TransactionScriptBuilderError::UnsupportedAccountType(account_id) => {
+                write!(
+                    f,
+                    "Transaction scripts for account {} of type {:?} are not supported yet",
+                    account_id,
+                    account_id.account_type()
+                )
+            },
         }
     }
 }
@@ -0,0 +1,156 @@
+use alloc::{string::ToString, sync::Arc, vec::Vec};
+
+use miden_objects::{
+    accounts::{AccountDelta, AccountId, AuthSecretKey},
+    crypto::rand::FeltRng,
+    Digest, Felt, Hasher, Word,
+};
+use miden_tx::{
+    auth::{signatures::get_falcon_signature, TransactionAuthenticator},
+    utils::{
+        sync::RwLock, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+    },
+    AuthenticationError, DataStore, TransactionExecutor,
+};
+use rand::Rng;
+use tracing::info;
+
+use super::TransactionRequest;
+use crate::{store::data_store::ClientDataStore, Client, ClientError};
+
+impl<R: FeltRng> Client<R> {
+    /// Returns the signatures requested by the VM to execute the transaction described by
+    /// `transaction_request` against the account, so that they can be produced by a signer that
+    /// isn't connected to the client, like a hardware wallet or an air-gapped device.
+    ///
+    /// The transaction is executed without signing anything, up to the point where a signature is
+    /// requested. Once the signer produces the signatures, they're attached to the request with
+    /// [TransactionRequest::with_signature], and the request can be executed with
+    /// [Client::new_transaction] and proven without the client's authenticator.
+    ///
+    /// The signed message commits to the state the transaction is executed against, so the
+    /// request must be executed before the account changes or the client syncs to a new block.
+    /// Otherwise, a new message is requested and the provided signature is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns any of the errors of [Client::new_transaction] if the transaction fails before a
+    /// signature is requested.
+    pub async fn signing_requests(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: &TransactionRequest,
+    ) -> Result<Vec<SigningRequest>, ClientError> {
+        let recorder = Arc::new(SigningRequestRecorder::default());
+        let data_store = Arc::new(ClientDataStore::new(self.store.clone())) as Arc<dyn DataStore>;
+        let recording_executor = TransactionExecutor::new(
+            data_store,
+            Some(recorder.clone() as Arc<dyn TransactionAuthenticator>),
+        )
+        .with_debug_mode(self.in_debug_mode);
+
+        let tx_executor = core::mem::replace(&mut self.tx_executor, recording_executor);
+        let result = self.new_transaction(account_id, transaction_request.clone()).await;
+        self.tx_executor = tx_executor;
+
+        // The execution stops at the first signature that isn't provided by the request
+        let requests = recorder.requests.read().clone();
+        match result {
+            Err(err) if requests.is_empty() => Err(err),
+            _ => {
+                info!("Transaction against {account_id} requests {} signatures", requests.len());
+                Ok(requests)
+            },
+        }
+    }
+}
+
+// SIGNING REQUEST
+// ================================================================================================
+
+/// Signature requested by the VM while executing a transaction, as returned by
+/// [Client::signing_requests].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningRequest {
+    /// Public key of the account whose signature is requested.
+    pub pub_key: Word,
+    /// Message to sign, which commits to the transaction.
+    pub message: Word,
+    /// Changes made to the account by the transaction up to the signature, so that the signer can
+    /// review them before signing. The delta is not part of the signed message.
+    pub account_delta: AccountDelta,
+}
+
+impl SigningRequest {
+    /// Signs the message with the provided secret key, which must match the requested public
+    /// key, and returns the signature in the format expected by the VM.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [AuthenticationError::UnknownKey] if the key doesn't match the requested one.
+    pub fn sign(
+        &self,
+        secret_key: &AuthSecretKey,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        let AuthSecretKey::RpoFalcon512(secret_key) = secret_key;
+        if Word::from(secret_key.public_key()) != self.pub_key {
+            return Err(AuthenticationError::UnknownKey(Digest::from(self.pub_key).to_string()));
+        }
+
+        get_falcon_signature(secret_key, self.message, rng)
+    }
+
+    /// Returns the key under which the VM looks for the signature in the advice map of the
+    /// transaction before asking the authenticator for it.
+    pub(crate) fn signature_key(&self) -> Digest {
+        Hasher::merge(&[self.pub_key.into(), self.message.into()])
+    }
+}
+
+impl Serializable for SigningRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.pub_key.write_into(target);
+        self.message.write_into(target);
+        self.account_delta.write_into(target);
+    }
+}
+
+impl Deserializable for SigningRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_key = Word::read_from(source)?;
+        let message = Word::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+
+        Ok(Self { pub_key, message, account_delta })
+    }
+}
+
+// SIGNING REQUEST RECORDER
+// ================================================================================================
+
+/// [TransactionAuthenticator] that records the signatures it's asked for and refuses to produce
+/// them.
+#[derive(Default)]
+struct SigningRequestRecorder {
+    requests: RwLock<Vec<SigningRequest>>,
+}
+
+impl TransactionAuthenticator for SigningRequestRecorder {
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        account_delta: &AccountDelta,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        self.requests.write().push(SigningRequest {
+            pub_key,
+            message,
+            account_delta: account_delta.clone(),
+        });
+
+        Err(AuthenticationError::RejectedSignature(
+            "the signature is produced by an external signer".to_string(),
+        ))
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::{boxed::Box, collections::BTreeSet, sync::Arc, vec::Vec};
+
+use miden_objects::{
+    accounts::{Account, AccountId, AuthSecretKey},
+    crypto::rand::{FeltRng, RpoRandomCoin},
+    notes::{Note, NoteDetails, NoteId, NoteTag},
+    transaction::{InputNote, InputNotes, ProvenTransaction, TransactionInputs},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeader, Word,
+};
+use miden_tx::{
+    auth::BasicAuthenticator, DataStore, DataStoreError, TransactionExecutor,
+    TransactionExecutorError,
+};
+
+use super::{
+    account_capabilities, find_output_note_mismatches, prove_transaction, TransactionRequest,
+    TransactionRequestError, TransactionResult,
+};
+use crate::{notes::NoteScreener, store::data_store::ClientDataStore, Client, ClientError};
+
+// STATELESS TRANSACTION INPUTS
+// ================================================================================================
+
+/// Data needed to execute a transaction against an account whose state is kept outside of the
+/// client, with [Client::execute_transaction_stateless].
+///
+/// The inputs consist of the [TransactionInputs] of the transaction, which hold the account (and
+/// its seed, if it's new), the reference block header, the partial chain MMR at that block and
+/// the notes to consume with their inclusion proofs, plus the key that signs the transaction.
+#[derive(Debug, Clone)]
+pub struct StatelessTransactionInputs {
+    tx_inputs: TransactionInputs,
+    auth: AuthSecretKey,
+}
+
+impl StatelessTransactionInputs {
+    /// Returns new stateless inputs for a transaction executed with `tx_inputs` and signed with
+    /// `auth`.
+    pub fn new(tx_inputs: TransactionInputs, auth: AuthSecretKey) -> Self {
+        Self { tx_inputs, auth }
+    }
+
+    /// Returns the account that executes the transaction.
+    pub fn account(&self) -> &Account {
+        self.tx_inputs.account()
+    }
+
+    /// Returns the header of the block the transaction is executed against.
+    pub fn block_header(&self) -> &BlockHeader {
+        self.tx_inputs.block_header()
+    }
+
+    /// Returns the notes that can be consumed by the transaction.
+    pub fn input_notes(&self) -> &InputNotes<InputNote> {
+        self.tx_inputs.input_notes()
+    }
+
+    /// Returns the inputs of the transaction.
+    pub fn tx_inputs(&self) -> &TransactionInputs {
+        &self.tx_inputs
+    }
+
+    /// Returns the public key that matches the inputs' secret key.
+    fn public_key(&self) -> Word {
+        match &self.auth {
+            AuthSecretKey::RpoFalcon512(secret_key) => secret_key.public_key().into(),
+        }
+    }
+}
+
+impl Serializable for StatelessTransactionInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_inputs.write_into(target);
+        self.auth.write_into(target);
+    }
+}
+
+impl Deserializable for StatelessTransactionInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tx_inputs = TransactionInputs::read_from(source)?;
+        let auth = AuthSecretKey::read_from(source)?;
+
+        Ok(Self { tx_inputs, auth })
+    }
+}
+
+// STATELESS DATA STORE
+// ================================================================================================
+
+/// [DataStore] that serves the inputs of a single transaction from memory.
+struct StatelessDataStore {
+    tx_inputs: TransactionInputs,
+}
+
+#[async_trait::async_trait(?Send)]
+impl DataStore for StatelessDataStore {
+    async fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        if account_id != self.tx_inputs.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+        if block_num != self.tx_inputs.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_num));
+        }
+
+        // Only the requested notes are consumed, even if the inputs have more of them
+        let mut input_notes = Vec::with_capacity(notes.len());
+        for note_id in notes {
+            let note = self
+                .tx_inputs
+                .input_notes()
+                .iter()
+                .find(|note| note.id() == *note_id)
+                .ok_or(DataStoreError::NoteNotFound(*note_id))?;
+            input_notes.push(note.clone());
+        }
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        TransactionInputs::new(
+            self.tx_inputs.account().clone(),
+            self.tx_inputs.account_seed(),
+            *self.tx_inputs.block_header(),
+            self.tx_inputs.block_chain().clone(),
+            input_notes,
+        )
+        .map_err(DataStoreError::InvalidTransactionInput)
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // STATELESS EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes a transaction for an account whose state is provided in `inputs` instead of being
+    /// read from the store, and returns the result without applying it.
+    ///
+    /// The transaction is executed against the reference block of the inputs, and the notes it
+    /// consumes must be part of the inputs, except for the unauthenticated input notes of the
+    /// request, which are added to them. Nothing is written to the store, so the result is meant
+    /// to be proven and submitted by the caller, who also keeps track of the account's new state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request uses foreign accounts, if the inputs lack a note consumed
+    /// by the request, if the transaction fails or if its output notes don't match the expected
+    /// ones.
+    pub async fn execute_transaction_stateless(
+        &mut self,
+        inputs: StatelessTransactionInputs,
+        transaction_request: TransactionRequest,
+    ) -> Result<TransactionResult, ClientError> {
+        if !transaction_request.foreign_accounts().is_empty() {
+            return Err(TransactionRequestError::ForeignAccountsInStatelessExecution.into());
+        }
+        transaction_request.check_advice_data_size(self.max_advice_data_size)?;
+
+        let account_id = inputs.account().id();
+        let block_num = inputs.block_header().block_num();
+        let tx_inputs = with_unauthenticated_notes(
+            inputs.tx_inputs.clone(),
+            transaction_request.unauthenticated_input_notes(),
+        )
+        .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        let note_ids = transaction_request.get_input_note_ids();
+        let output_notes: Vec<Note> =
+            transaction_request.expected_output_notes().cloned().collect();
+        let future_notes: Vec<(NoteDetails, NoteTag)> =
+            transaction_request.expected_future_notes().cloned().collect();
+
+        let tx_script = transaction_request.build_transaction_script(account_capabilities(
+            inputs.account(),
+            inputs.auth.clone(),
+        )?)?;
+        let tx_args = transaction_request.into_transaction_args(tx_script);
+
+        let authenticator = BasicAuthenticator::<RpoRandomCoin>::new_with_rng(
+            &[(inputs.public_key(), inputs.auth.clone())],
+            RpoRandomCoin::new(self.rng.draw_word()),
+        );
+        let data_store = Arc::new(StatelessDataStore { tx_inputs }) as Arc<dyn DataStore>;
+        let tx_executor = TransactionExecutor::new(data_store, Some(Arc::new(authenticator)))
+            .with_debug_mode(self.in_debug_mode);
+
+        let executed_transaction = tx_executor
+            .execute_transaction(account_id, block_num, &note_ids, tx_args)
+            .await?;
+
+        let mismatches =
+            find_output_note_mismatches(&output_notes, executed_transaction.output_notes());
+        if !mismatches.is_empty() {
+            return Err(ClientError::MissingOutputNotes(mismatches));
+        }
+
+        let screener = NoteScreener::new(self.store.clone());
+
+        TransactionResult::new(executed_transaction, screener, future_notes).await
+    }
+
+    /// Proves a transaction executed with [Client::execute_transaction_stateless] with the
+    /// client's prover. The proven transaction is neither submitted nor stored.
+    pub async fn prove_transaction_stateless(
+        &self,
+        tx_result: &TransactionResult,
+    ) -> Result<ProvenTransaction, ClientError> {
+        prove_transaction(self.tx_prover.as_ref(), tx_result).await
+    }
+
+    /// Returns the stateless inputs of a transaction executed by a tracked account at the
+    /// current sync height that consumes the specified notes, along with the account's key.
+    ///
+    /// The inputs can be used to execute the transaction with
+    /// [Client::execute_transaction_stateless] on a client that doesn't track the account.
+    pub async fn export_stateless_inputs(
+        &self,
+        account_id: AccountId,
+        note_ids: &[NoteId],
+    ) -> Result<StatelessTransactionInputs, ClientError> {
+        let block_num = self.store.get_sync_height().await?;
+        let tx_inputs = ClientDataStore::new(self.store.clone())
+            .get_transaction_inputs(account_id, block_num, note_ids)
+            .await
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+        let auth = self.get_account_auth(account_id).await?;
+
+        Ok(StatelessTransactionInputs::new(tx_inputs, auth))
+    }
+}
+
+/// Adds the notes that are not part of the transaction inputs yet to them, as unauthenticated
+/// notes.
+fn with_unauthenticated_notes(
+    tx_inputs: TransactionInputs,
+    notes: &[Note],
+) -> Result<TransactionInputs, DataStoreError> {
+    let known_notes: BTreeSet<NoteId> = tx_inputs.input_notes().iter().map(InputNote::id).collect();
+    let new_notes: Vec<InputNote> = notes
+        .iter()
+        .filter(|note| !known_notes.contains(&note.id()))
+        .cloned()
+        .map(InputNote::unauthenticated)
+        .collect();
+    if new_notes.is_empty() {
+        return Ok(tx_inputs);
+    }
+
+    let (account, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+    let input_notes =
+        InputNotes::new(input_notes.into_vec().into_iter().chain(new_notes).collect())
+            .map_err(DataStoreError::InvalidTransactionInput)?;
+
+    TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+        .map_err(DataStoreError::InvalidTransactionInput)
+}
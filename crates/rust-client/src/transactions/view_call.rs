@@ -0,0 +1,441 @@
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use miden_lib::{transaction::TransactionKernel, MidenLib};
+use miden_objects::{
+    accounts::AccountId,
+    assembly::Library,
+    crypto::rand::FeltRng,
+    transaction::{TransactionArgs, TransactionScript},
+    Digest, Felt, Hasher,
+};
+use miden_tx::TransactionExecutorError;
+
+use crate::{Client, ClientError};
+
+/// Maximum number of inputs that can be passed to a procedure called from the account itself.
+pub const MAX_VIEW_CALL_INPUTS: usize = 16;
+
+/// Maximum number of inputs that can be passed to a procedure of a foreign account. The foreign
+/// account ID and the procedure root take the other 5 elements of the stack.
+pub const MAX_FOREIGN_VIEW_CALL_INPUTS: usize = 11;
+
+/// Number of stack elements returned by a view call.
+const VIEW_CALL_OUTPUTS: usize = 16;
+
+// VIEW PROCEDURE
+// ================================================================================================
+
+/// Procedure targeted by [Client::call_view_function].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViewProcedure {
+    /// Fully qualified name of a procedure exported by the Miden library, such as
+    /// `miden::contracts::wallets::basic::receive_asset`.
+    Name(String),
+    /// MAST root of the procedure.
+    Root(Digest),
+}
+
+impl From<Digest> for ViewProcedure {
+    fn from(root: Digest) -> Self {
+        ViewProcedure::Root(root)
+    }
+}
+
+impl From<&str> for ViewProcedure {
+    fn from(name: &str) -> Self {
+        ViewProcedure::Name(name.to_string())
+    }
+}
+
+impl From<String> for ViewProcedure {
+    fn from(name: String) -> Self {
+        ViewProcedure::Name(name)
+    }
+}
+
+// VIEW CALL ERROR
+// ================================================================================================
+
+/// Errors generated while executing a view call.
+#[derive(Debug)]
+pub enum ViewCallError {
+    /// The procedure failed while executing. This is also the case for procedures that try to
+    /// modify the account, as the kernel rejects state changes that are not followed by a nonce
+    /// increment.
+    ExecutionFailed(TransactionExecutorError),
+    /// There is no tracked account other than the foreign one to execute the call from.
+    NoNativeAccount(AccountId),
+    /// The procedure is not part of the account's code.
+    ProcedureNotInAccount {
+        account_id: AccountId,
+        procedure_root: Digest,
+    },
+    /// The procedure executed successfully but changed the account's state.
+    StateMutation(AccountId),
+    /// More inputs were provided than the procedure can receive.
+    TooManyInputs { max: usize, actual: usize },
+    /// No procedure with the provided name is exported by the Miden library.
+    UnknownProcedureName(String),
+}
+
+impl fmt::Display for ViewCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViewCallError::ExecutionFailed(err) => {
+                write!(f, "The procedure failed or tried to modify the account state: {err}")
+            },
+            ViewCallError::NoNativeAccount(account_id) => write!(
+                f,
+                "A tracked account other than {account_id} is needed to call its procedures as a foreign account"
+            ),
+            ViewCallError::ProcedureNotInAccount { account_id, procedure_root } => {
+                write!(f, "Account {account_id} has no procedure with root {procedure_root}")
+            },
+            ViewCallError::StateMutation(account_id) => {
+                write!(f, "The procedure modified the state of account {account_id}")
+            },
+            ViewCallError::TooManyInputs { max, actual } => {
+                write!(f, "The procedure can receive at most {max} inputs, but {actual} were provided")
+            },
+            ViewCallError::UnknownProcedureName(name) => {
+                write!(f, "No procedure named {name} is exported by the Miden library")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ViewCallError {}
+
+impl<R: FeltRng> Client<R> {
+    // VIEW CALLS
+    // --------------------------------------------------------------------------------------------
+
+    /// Calls a read-only procedure of an account and returns the 16 elements at the top of the
+    /// stack when it returns, with the top of the stack first.
+    ///
+    /// The call is executed as a transaction that is neither proven nor submitted. If `foreign`
+    /// is `false`, the account must be tracked by the client and the procedure is called by the
+    /// account itself. Otherwise, the public account's data is fetched from the node and the
+    /// procedure is called through foreign procedure invocation from another tracked account.
+    ///
+    /// `args` are placed on the stack so that the first one is at the top when the procedure is
+    /// called.
+    ///
+    /// # Errors
+    ///
+    /// - Returns a [ViewCallError::UnknownProcedureName] if the procedure is specified by name and
+    ///   it isn't exported by the Miden library.
+    /// - Returns a [ViewCallError::ProcedureNotInAccount] if the account's code doesn't have the
+    ///   procedure.
+    /// - Returns a [ViewCallError::ExecutionFailed] or a [ViewCallError::StateMutation] if the
+    ///   procedure fails or modifies the account.
+    pub async fn call_view_function(
+        &mut self,
+        account_id: AccountId,
+        procedure: impl Into<ViewProcedure>,
+        args: Vec<Felt>,
+        foreign: bool,
+    ) -> Result<Vec<Felt>, ClientError> {
+        let max_inputs = if foreign {
+            MAX_FOREIGN_VIEW_CALL_INPUTS
+        } else {
+            MAX_VIEW_CALL_INPUTS
+        };
+        if args.len() > max_inputs {
+            return Err(ViewCallError::TooManyInputs { max: max_inputs, actual: args.len() }.into());
+        }
+
+        let procedure_root = match procedure.into() {
+            ViewProcedure::Root(root) => root,
+            ViewProcedure::Name(name) => resolve_procedure_name(&name)?,
+        };
+
+        let (native_account_id, tx_args, block_num) = if foreign {
+            let native_account_id = self
+                .get_account_headers()
+                .await?
+                .into_iter()
+                .map(|(header, _)| header.id())
+                .find(|id| *id != account_id)
+                .ok_or(ViewCallError::NoNativeAccount(account_id))?;
+
+            let (advice_inputs, account_codes, block_num) =
+                self.get_foreign_account_inputs(&BTreeSet::from([account_id])).await?;
+            if !account_codes.iter().any(|code| code.has_procedure(procedure_root)) {
+                return Err(
+                    ViewCallError::ProcedureNotInAccount { account_id, procedure_root }.into()
+                );
+            }
+            account_codes.iter().for_each(|code| self.tx_executor.load_account_code(code));
+
+            let tx_script = compile_view_script(procedure_root, &args, Some(account_id))?;
+            let tx_args =
+                TransactionArgs::with_tx_script(tx_script).with_advice_inputs(advice_inputs);
+            let block_num = match block_num {
+                Some(block_num) => block_num,
+                None => self.store.get_sync_height().await?,
+            };
+
+            (native_account_id, tx_args, block_num)
+        } else {
+            let (account, _) = self.store.get_account(account_id).await?;
+            if !account.code().has_procedure(procedure_root) {
+                return Err(
+                    ViewCallError::ProcedureNotInAccount { account_id, procedure_root }.into()
+                );
+            }
+
+            let tx_script = compile_view_script(procedure_root, &args, None)?;
+            let block_num = self.store.get_sync_height().await?;
+
+            (account_id, TransactionArgs::with_tx_script(tx_script), block_num)
+        };
+
+        let executed_transaction = self
+            .tx_executor
+            .execute_transaction(native_account_id, block_num, &[], tx_args)
+            .await
+            .map_err(ViewCallError::ExecutionFailed)?;
+
+        if !executed_transaction.account_delta().is_empty() {
+            return Err(ViewCallError::StateMutation(native_account_id).into());
+        }
+
+        // The script stores the outputs in the advice map under this key and reads them back, so
+        // that they are recorded in the transaction's advice witness
+        let outputs_key = view_call_outputs_key(procedure_root, &args);
+        let outputs = executed_transaction
+            .advice_witness()
+            .mapped_values(&outputs_key)
+            .expect("the view call script should record its outputs in the advice map");
+
+        Ok(stack_outputs_from_memory(outputs))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the MAST root of the procedure exported by the Miden library with the provided fully
+/// qualified name.
+fn resolve_procedure_name(name: &str) -> Result<Digest, ViewCallError> {
+    let miden_lib = MidenLib::default();
+    let library: &Library = miden_lib.as_ref();
+
+    library
+        .exports()
+        .find(|export| export.to_string() == name)
+        .map(|export| library.mast_forest()[library.get_export_node_id(export)].digest())
+        .ok_or_else(|| ViewCallError::UnknownProcedureName(name.to_string()))
+}
+
+/// Returns the key under which the view call script stores the procedure's outputs.
+fn view_call_outputs_key(procedure_root: Digest, args: &[Felt]) -> Digest {
+    let mut elements = procedure_root.as_elements().to_vec();
+    elements.extend_from_slice(args);
+    Hasher::hash_elements(&elements)
+}
+
+/// Compiles the script that calls the procedure and stores its outputs in the advice map.
+///
+/// If `foreign_account_id` is provided, the procedure is called through the kernel's foreign
+/// procedure invocation, otherwise it's called directly on the native account.
+fn compile_view_script(
+    procedure_root: Digest,
+    args: &[Felt],
+    foreign_account_id: Option<AccountId>,
+) -> Result<TransactionScript, ClientError> {
+    let push_args = if args.is_empty() {
+        String::new()
+    } else {
+        let args: Vec<String> = args.iter().rev().map(|arg| arg.as_int().to_string()).collect();
+        format!("push.{}", args.join("."))
+    };
+
+    let call = match foreign_account_id {
+        Some(account_id) => format!(
+            "push.{procedure_root}\n    push.{}\n    exec.tx::execute_foreign_procedure",
+            u64::from(account_id)
+        ),
+        None => format!("call.{procedure_root}"),
+    };
+
+    let outputs_key = view_call_outputs_key(procedure_root, args);
+
+    let code = format!(
+        "
+        use.miden::tx
+
+        begin
+            {push_args}
+            {call}
+            # => [OUTPUTS(16)]
+
+            mem_storew.0 dropw
+            mem_storew.1 dropw
+            mem_storew.2 dropw
+            mem_storew.3 dropw
+
+            push.4.0 push.{outputs_key}
+            # => [KEY, start_addr, end_addr]
+
+            adv.insert_mem
+            adv.push_mapval
+            adv_loadw adv_loadw adv_loadw adv_loadw
+            dropw drop drop
+        end
+        "
+    );
+
+    TransactionScript::compile(code, vec![], TransactionKernel::assembler())
+        .map_err(ClientError::TransactionScriptError)
+}
+
+/// Converts the words stored by the view call script back into stack order. Each word is stored
+/// with the element at the top of the stack last.
+fn stack_outputs_from_memory(values: &[Felt]) -> Vec<Felt> {
+    values
+        .chunks(4)
+        .flat_map(|word| word.iter().rev().copied())
+        .take(VIEW_CALL_OUTPUTS)
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_lib::{accounts::auth::RpoFalcon512, transaction::TransactionKernel};
+    use miden_objects::{
+        accounts::{
+            AccountBuilder, AccountComponent, AccountData, AuthSecretKey, StorageMap, StorageSlot,
+        },
+        crypto::dsa::rpo_falcon512::SecretKey,
+        testing::account_component::BASIC_WALLET_CODE,
+        Felt, FieldElement, Word,
+    };
+
+    use super::{resolve_procedure_name, stack_outputs_from_memory, ViewCallError, ViewProcedure};
+    use crate::{mock::create_test_client, ClientError};
+
+    const STORAGE_VALUE: Word = [Felt::new(9), Felt::new(12), Felt::new(18), Felt::new(30)];
+
+    #[tokio::test]
+    async fn test_call_view_function() {
+        let (mut client, _) = create_test_client().await;
+
+        let wallet_component = AccountComponent::compile(
+            BASIC_WALLET_CODE,
+            TransactionKernel::assembler(),
+            vec![StorageSlot::Value(STORAGE_VALUE), StorageSlot::Map(StorageMap::default())],
+        )
+        .unwrap()
+        .with_supports_all_types();
+        let getter_component = AccountComponent::compile(
+            "
+            export.get_value
+                push.0
+                exec.::miden::account::get_item
+                swapw dropw
+            end
+            ",
+            TransactionKernel::assembler(),
+            vec![],
+        )
+        .unwrap()
+        .with_supports_all_types();
+        let setter_component = AccountComponent::compile(
+            "
+            export.set_value
+                push.1.2.3.4 push.0
+                exec.::miden::account::set_item
+                dropw dropw
+            end
+            ",
+            TransactionKernel::assembler(),
+            vec![],
+        )
+        .unwrap()
+        .with_supports_all_types();
+
+        let getter_root = getter_component.mast_forest().procedure_digests().next().unwrap();
+        let setter_root = setter_component.mast_forest().procedure_digests().next().unwrap();
+
+        let secret_key = SecretKey::new();
+        let (account, _) = AccountBuilder::new()
+            .init_seed(Default::default())
+            .nonce(Felt::ONE)
+            .with_component(wallet_component)
+            .with_component(getter_component)
+            .with_component(setter_component)
+            .with_component(RpoFalcon512::new(secret_key.public_key()))
+            .build()
+            .unwrap();
+
+        client
+            .import_account(
+                AccountData::new(account.clone(), None, AuthSecretKey::RpoFalcon512(secret_key)),
+                false,
+            )
+            .await
+            .unwrap();
+        client.sync_state().await.unwrap();
+
+        let outputs = client
+            .call_view_function(account.id(), getter_root, vec![], false)
+            .await
+            .unwrap();
+        assert_eq!(outputs.len(), 16);
+        assert_eq!(outputs[..4], STORAGE_VALUE.iter().rev().copied().collect::<Vec<_>>()[..]);
+
+        let mutation = client.call_view_function(account.id(), setter_root, vec![], false).await;
+        assert!(matches!(
+            mutation,
+            Err(ClientError::ViewCallError(
+                ViewCallError::ExecutionFailed(_) | ViewCallError::StateMutation(_)
+            ))
+        ));
+
+        let faucet_procedure =
+            ViewProcedure::from("miden::contracts::faucets::basic_fungible::distribute");
+        assert!(matches!(
+            client.call_view_function(account.id(), faucet_procedure, vec![], false).await,
+            Err(ClientError::ViewCallError(ViewCallError::ProcedureNotInAccount { .. }))
+        ));
+
+        let too_many_inputs = vec![Felt::ZERO; 17];
+        assert!(matches!(
+            client
+                .call_view_function(account.id(), getter_root, too_many_inputs, false)
+                .await,
+            Err(ClientError::ViewCallError(ViewCallError::TooManyInputs { max: 16, actual: 17 }))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_procedure_name() {
+        assert!(resolve_procedure_name("miden::contracts::wallets::basic::receive_asset").is_ok());
+        assert!(matches!(
+            resolve_procedure_name("miden::contracts::wallets::basic::get_balance"),
+            Err(ViewCallError::UnknownProcedureName(_))
+        ));
+    }
+
+    #[test]
+    fn test_stack_outputs_from_memory() {
+        let values: Vec<Felt> = (0..16u64).map(Felt::new).collect();
+        let outputs: Vec<u64> =
+            stack_outputs_from_memory(&values).iter().map(Felt::as_int).collect();
+
+        assert_eq!(outputs, [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12]);
+    }
+}
@@ -25,6 +25,7 @@ const {
   NoteInputs,
   NoteMetadata,
   NoteRecipient,
+  NoteStateCounts,
   NoteTag,
   NoteType,
   OutputNote,
@@ -67,6 +68,7 @@ export {
   NoteInputs,
   NoteMetadata,
   NoteRecipient,
+  NoteStateCounts,
   NoteTag,
   NoteType,
   OutputNote,
@@ -25,6 +25,7 @@ export {
   NoteInputs,
   NoteMetadata,
   NoteRecipient,
+  NoteStateCounts,
   NoteTag,
   NoteType,
   OutputNote,
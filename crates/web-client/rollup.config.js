@@ -5,13 +5,18 @@ import commonjs from "@rollup/plugin-commonjs";
 // Flag that indicates if the build is meant for testing purposes.
 const testing = process.env.MIDEN_WEB_TESTING === "true";
 
+// Flag that indicates if the build leaves out the local prover, which makes the WASM module much
+// smaller. Clients of a slim build can only prove transactions with a remote prover.
+const slim = process.env.MIDEN_WEB_SLIM === "true";
+
 /**
  * Rollup configuration file for building a Cargo project and creating a WebAssembly (WASM) module.
  * The configuration sets up two build processes:
  * 1. Compiling Rust code into WASM using the @wasm-tool/rollup-plugin-rust plugin, with specific
  *    cargo arguments to enable WebAssembly features and set maximum memory limits. If the build is
  *    meant for testing, the WASM optimization level is set to 0 to improve build times, this is
- *    aimed at reducing the feedback loop during development.
+ *    aimed at reducing the feedback loop during development. Slim builds are compiled without the
+ *    `local-prover` feature.
  * 2. Resolving and bundling the generated WASM module along with the main JavaScript file
  *    (`index.js`) into the `dist` directory.
  *
@@ -34,7 +39,7 @@ export default [
       rust({
         cargoArgs: [
           "--features",
-          "testing",
+          slim ? "testing" : "testing,local-prover",
           "--config",
           `build.rustflags=["-C", "target-feature=+atomics,+bulk-memory,+mutable-globals", "-C", "link-arg=--max-memory=4294967296"]`,
           "--no-default-features",
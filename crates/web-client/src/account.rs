@@ -52,6 +52,16 @@ impl WebClient {
         }
     }
 
+    pub async fn can_sign_for(&mut self, account_id: &AccountId) -> Result<bool, JsValue> {
+        if let Some(client) = self.get_mut_inner() {
+            client.can_sign_for(account_id.into()).await.map_err(|err| {
+                JsValue::from_str(&format!("Failed to check account signing key: {}", err))
+            })
+        } else {
+            Err(JsValue::from_str("Client not initialized"))
+        }
+    }
+
     pub async fn fetch_and_cache_account_auth_by_pub_key(
         &mut self,
         account_id: &AccountId,
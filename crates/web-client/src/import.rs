@@ -6,14 +6,18 @@ use crate::WebClient;
 
 #[wasm_bindgen]
 impl WebClient {
-    pub async fn import_account(&mut self, account_bytes: JsValue) -> Result<JsValue, JsValue> {
+    pub async fn import_account(
+        &mut self,
+        account_bytes: JsValue,
+        force: Option<bool>,
+    ) -> Result<JsValue, JsValue> {
         if let Some(client) = self.get_mut_inner() {
             let account_bytes_result: Vec<u8> = from_value(account_bytes).unwrap();
             let account_data = AccountData::read_from_bytes(&account_bytes_result)
                 .map_err(|err| err.to_string())?;
             let account_id = account_data.account.id().to_string();
 
-            match client.import_account(account_data).await {
+            match client.import_account(account_data, force.unwrap_or(false)).await {
                 Ok(_) => {
                     let message = format!("Imported account with ID: {}", account_id);
                     Ok(JsValue::from_str(&message))
@@ -2,10 +2,13 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use console_error_panic_hook::set_once;
+#[cfg(feature = "local-prover")]
+use miden_client::transactions::LocalTransactionProver;
 use miden_client::{
     rpc::WebTonicRpcClient,
     store::{web_store::WebStore, StoreAuthenticator},
-    transactions::{LocalTransactionProver, TransactionProver},
+    time::TimeSource,
+    transactions::TransactionProver,
     Client,
 };
 use miden_objects::{crypto::rand::RpoRandomCoin, Felt};
@@ -66,21 +69,41 @@ impl WebClient {
             &node_url.unwrap_or_else(|| "http://18.203.155.106:57291".to_string()),
         ));
 
-        let tx_prover: Arc<dyn TransactionProver> = match proving_url {
-            Some(proving_url) => Arc::new(RemoteTransactionProver::new(&proving_url.to_string())),
-            None => Arc::new(LocalTransactionProver::new(Default::default())),
-        };
+        let (tx_prover, prover_description): (Arc<dyn TransactionProver>, String) =
+            match proving_url {
+                Some(proving_url) => (
+                    Arc::new(RemoteTransactionProver::new(&proving_url.to_string())),
+                    format!("remote ({proving_url})"),
+                ),
+                #[cfg(feature = "local-prover")]
+                None => {
+                    (Arc::new(LocalTransactionProver::new(Default::default())), "local".to_string())
+                },
+                #[cfg(not(feature = "local-prover"))]
+                None => {
+                    return Err(JsValue::from_str(&format!(
+                        "{}. Provide a proving URL to create the client.",
+                        miden_client::ClientError::LocalProverUnavailable
+                    )));
+                },
+            };
 
-        self.inner = Some(Client::new(
-            web_rpc_client,
-            rng,
-            web_store.clone(),
-            authenticator,
-            tx_prover,
-            false,
-        ));
+        self.inner = Some(
+            Client::new(web_rpc_client, rng, web_store.clone(), authenticator, tx_prover, false)
+                .with_time_source(Arc::new(DateNowTimeSource))
+                .with_prover_description(prover_description),
+        );
         self.store = Some(web_store);
 
         Ok(JsValue::from_str("Client created successfully"))
     }
 }
+
+/// [TimeSource] backed by the JavaScript `Date.now()` function.
+struct DateNowTimeSource;
+
+impl TimeSource for DateNowTimeSource {
+    fn now(&self) -> u64 {
+        (wasm_bindgen_futures::js_sys::Date::now() / 1000.0) as u64
+    }
+}
@@ -0,0 +1,33 @@
+use miden_client::rpc::ConnectionHealth as NativeConnectionHealth;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct ConnectionHealth(NativeConnectionHealth);
+
+#[wasm_bindgen]
+impl ConnectionHealth {
+    pub fn endpoint(&self) -> Option<String> {
+        self.0.endpoint.clone()
+    }
+
+    pub fn latency_ms(&self) -> Option<f64> {
+        self.0.latency.map(|latency| latency.as_secs_f64() * 1000.0)
+    }
+
+    pub fn chain_tip(&self) -> u32 {
+        self.0.chain_tip
+    }
+
+    pub fn genesis_matches(&self) -> Option<bool> {
+        self.0.genesis_matches
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NativeConnectionHealth> for ConnectionHealth {
+    fn from(native_connection_health: NativeConnectionHealth) -> Self {
+        ConnectionHealth(native_connection_health)
+    }
+}
@@ -20,6 +20,18 @@ impl InputNoteRecord {
         self.0.state().into()
     }
 
+    pub fn state_code(&self) -> String {
+        self.0.state().code().as_str().to_string()
+    }
+
+    pub fn state_label(&self) -> String {
+        self.0.state().code().human_label().to_string()
+    }
+
+    pub fn state_details(&self) -> String {
+        self.0.state().to_string()
+    }
+
     pub fn details(&self) -> NoteDetails {
         self.0.details().into()
     }
@@ -36,6 +48,10 @@ impl InputNoteRecord {
         self.0.consumer_transaction_id().map(|id| id.to_string())
     }
 
+    pub fn created_at(&self) -> Option<u64> {
+        self.0.created_at()
+    }
+
     pub fn nullifier(&self) -> String {
         self.0.nullifier().to_hex()
     }
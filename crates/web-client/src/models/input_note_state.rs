@@ -13,6 +13,7 @@ pub enum InputNoteState {
     ConsumedAuthenticatedLocal,
     ConsumedUnauthenticatedLocal,
     ConsumedExternal,
+    Rejected,
 }
 
 // CONVERSIONS
@@ -36,6 +37,7 @@ impl From<NativeNoteState> for InputNoteState {
                 InputNoteState::ConsumedUnauthenticatedLocal
             },
             NativeNoteState::ConsumedExternal(_) => InputNoteState::ConsumedExternal,
+            NativeNoteState::Rejected(_) => InputNoteState::Rejected,
         }
     }
 }
@@ -58,6 +60,7 @@ impl From<&NativeNoteState> for InputNoteState {
                 InputNoteState::ConsumedUnauthenticatedLocal
             },
             NativeNoteState::ConsumedExternal(_) => InputNoteState::ConsumedExternal,
+            NativeNoteState::Rejected(_) => InputNoteState::Rejected,
         }
     }
 }
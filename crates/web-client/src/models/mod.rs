@@ -38,6 +38,7 @@ pub mod advice_map;
 pub mod asset_vault;
 pub mod auth_secret_key;
 pub mod block_header;
+pub mod connection_health;
 pub mod consumable_note_record;
 pub mod executed_transaction;
 pub mod felt;
@@ -61,13 +62,17 @@ pub mod note_location;
 pub mod note_metadata;
 pub mod note_recipient;
 pub mod note_script;
+pub mod note_state_counts;
 pub mod note_tag;
 pub mod note_type;
 pub mod output_note;
+pub mod output_note_record;
+pub mod output_note_state;
 pub mod output_notes;
 pub mod partial_note;
 pub mod rpo256;
 pub mod rpo_digest;
+pub mod sync_estimate;
 pub mod sync_summary;
 #[cfg(feature = "testing")]
 pub mod test_utils;
@@ -1,7 +1,7 @@
 use miden_client::store::NoteFilter as NativeNoteFilter;
 use wasm_bindgen::prelude::*;
 
-use super::note_id::NoteId;
+use super::{felt::Felt, note_id::NoteId};
 
 // TODO: Add nullfiier support
 
@@ -10,13 +10,18 @@ use super::note_id::NoteId;
 pub struct NoteFilter {
     note_type: NoteFilterTypes,
     note_ids: Option<Vec<NoteId>>,
+    aux: Option<Felt>,
 }
 
 #[wasm_bindgen]
 impl NoteFilter {
     #[wasm_bindgen(constructor)]
-    pub fn new(note_type: NoteFilterTypes, note_ids: Option<Vec<NoteId>>) -> NoteFilter {
-        NoteFilter { note_type, note_ids }
+    pub fn new(
+        note_type: NoteFilterTypes,
+        note_ids: Option<Vec<NoteId>>,
+        aux: Option<Felt>,
+    ) -> NoteFilter {
+        NoteFilter { note_type, note_ids, aux }
     }
 }
 
@@ -24,6 +29,7 @@ impl NoteFilter {
 #[wasm_bindgen]
 pub enum NoteFilterTypes {
     All,
+    Aux,
     Consumed,
     Committed,
     Expected,
@@ -32,6 +38,7 @@ pub enum NoteFilterTypes {
     Unique,
     Nullifiers,
     Unverified,
+    Rejected,
 }
 
 // CONVERSIONS
@@ -41,6 +48,10 @@ impl From<NoteFilter> for NativeNoteFilter {
     fn from(filter: NoteFilter) -> Self {
         match filter.note_type {
             NoteFilterTypes::All => NativeNoteFilter::All,
+            NoteFilterTypes::Aux => {
+                let aux = filter.aux.unwrap_or_else(|| panic!("Aux required for Aux filter"));
+                NativeNoteFilter::Aux(aux.into())
+            },
             NoteFilterTypes::Consumed => NativeNoteFilter::Consumed,
             NoteFilterTypes::Committed => NativeNoteFilter::Committed,
             NoteFilterTypes::Expected => NativeNoteFilter::Expected,
@@ -62,6 +73,7 @@ impl From<NoteFilter> for NativeNoteFilter {
             },
             NoteFilterTypes::Nullifiers => NativeNoteFilter::Nullifiers(vec![]),
             NoteFilterTypes::Unverified => NativeNoteFilter::Unverified,
+            NoteFilterTypes::Rejected => NativeNoteFilter::Rejected,
         }
     }
 }
@@ -70,6 +82,10 @@ impl From<&NoteFilter> for NativeNoteFilter {
     fn from(filter: &NoteFilter) -> Self {
         match filter.note_type {
             NoteFilterTypes::All => NativeNoteFilter::All,
+            NoteFilterTypes::Aux => {
+                let aux = filter.aux.unwrap_or_else(|| panic!("Aux required for Aux filter"));
+                NativeNoteFilter::Aux(aux.into())
+            },
             NoteFilterTypes::Consumed => NativeNoteFilter::Consumed,
             NoteFilterTypes::Committed => NativeNoteFilter::Committed,
             NoteFilterTypes::Expected => NativeNoteFilter::Expected,
@@ -95,6 +111,7 @@ impl From<&NoteFilter> for NativeNoteFilter {
             },
             NoteFilterTypes::Nullifiers => NativeNoteFilter::Nullifiers(vec![]),
             NoteFilterTypes::Unverified => NativeNoteFilter::Unverified,
+            NoteFilterTypes::Rejected => NativeNoteFilter::Rejected,
         }
     }
 }
@@ -42,6 +42,10 @@ impl NoteMetadata {
     pub fn note_type(&self) -> NoteType {
         self.0.note_type().into()
     }
+
+    pub fn aux(&self) -> Felt {
+        self.0.aux().into()
+    }
 }
 
 // CONVERSIONS
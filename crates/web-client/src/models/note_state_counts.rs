@@ -0,0 +1,50 @@
+use miden_client::notes::NoteStateCounts as NativeNoteStateCounts;
+use wasm_bindgen::prelude::*;
+
+#[derive(Clone, Copy)]
+#[wasm_bindgen]
+pub struct NoteStateCounts(NativeNoteStateCounts);
+
+#[wasm_bindgen]
+impl NoteStateCounts {
+    pub fn expected(&self) -> usize {
+        self.0.expected
+    }
+
+    pub fn unverified(&self) -> usize {
+        self.0.unverified
+    }
+
+    pub fn committed(&self) -> usize {
+        self.0.committed
+    }
+
+    pub fn invalid(&self) -> usize {
+        self.0.invalid
+    }
+
+    pub fn processing(&self) -> usize {
+        self.0.processing
+    }
+
+    pub fn consumed(&self) -> usize {
+        self.0.consumed
+    }
+
+    pub fn rejected(&self) -> usize {
+        self.0.rejected
+    }
+
+    pub fn total(&self) -> usize {
+        self.0.total()
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NativeNoteStateCounts> for NoteStateCounts {
+    fn from(native_note_state_counts: NativeNoteStateCounts) -> Self {
+        NoteStateCounts(native_note_state_counts)
+    }
+}
@@ -0,0 +1,102 @@
+use miden_client::store::OutputNoteRecord as NativeOutputNoteRecord;
+use wasm_bindgen::prelude::*;
+
+use super::{
+    note_assets::NoteAssets, note_id::NoteId, note_inclusion_proof::NoteInclusionProof,
+    note_metadata::NoteMetadata, note_recipient::NoteRecipient, output_note_state::OutputNoteState,
+    rpo_digest::RpoDigest,
+};
+
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct OutputNoteRecord(NativeOutputNoteRecord);
+
+#[wasm_bindgen]
+impl OutputNoteRecord {
+    pub fn id(&self) -> NoteId {
+        self.0.id().into()
+    }
+
+    pub fn state(&self) -> OutputNoteState {
+        self.0.state().into()
+    }
+
+    pub fn state_code(&self) -> String {
+        self.0.state().code().as_str().to_string()
+    }
+
+    pub fn state_label(&self) -> String {
+        self.0.state().code().human_label().to_string()
+    }
+
+    pub fn state_details(&self) -> String {
+        self.0.state().to_string()
+    }
+
+    pub fn recipient_digest(&self) -> RpoDigest {
+        self.0.recipient_digest().into()
+    }
+
+    pub fn recipient(&self) -> Option<NoteRecipient> {
+        self.0.recipient().map(|recipient| recipient.into())
+    }
+
+    pub fn assets(&self) -> NoteAssets {
+        self.0.assets().into()
+    }
+
+    pub fn metadata(&self) -> NoteMetadata {
+        self.0.metadata().into()
+    }
+
+    pub fn inclusion_proof(&self) -> Option<NoteInclusionProof> {
+        self.0.inclusion_proof().map(|proof| proof.into())
+    }
+
+    pub fn nullifier(&self) -> Option<String> {
+        self.0.nullifier().map(|nullifier| nullifier.to_hex())
+    }
+
+    pub fn expected_height(&self) -> u32 {
+        self.0.expected_height()
+    }
+
+    pub fn created_at(&self) -> Option<u64> {
+        self.0.created_at()
+    }
+
+    pub fn committed_at_block(&self) -> Option<u32> {
+        self.0.committed_at_block()
+    }
+
+    pub fn committed_timestamp(&self) -> Option<u64> {
+        self.0.committed_timestamp()
+    }
+
+    pub fn consumed_at_block(&self) -> Option<u32> {
+        self.0.consumed_at_block()
+    }
+
+    pub fn is_committed(&self) -> bool {
+        self.0.is_committed()
+    }
+
+    pub fn is_consumed(&self) -> bool {
+        self.0.is_consumed()
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NativeOutputNoteRecord> for OutputNoteRecord {
+    fn from(native_note: NativeOutputNoteRecord) -> Self {
+        OutputNoteRecord(native_note)
+    }
+}
+
+impl From<&NativeOutputNoteRecord> for OutputNoteRecord {
+    fn from(native_note: &NativeOutputNoteRecord) -> Self {
+        OutputNoteRecord(native_note.clone())
+    }
+}
@@ -0,0 +1,33 @@
+use miden_client::store::OutputNoteState as NativeOutputNoteState;
+use wasm_bindgen::prelude::*;
+
+#[derive(Clone)]
+#[wasm_bindgen]
+pub enum OutputNoteState {
+    ExpectedPartial,
+    ExpectedFull,
+    CommittedPartial,
+    CommittedFull,
+    Consumed,
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<&NativeOutputNoteState> for OutputNoteState {
+    fn from(native_state: &NativeOutputNoteState) -> Self {
+        match native_state {
+            NativeOutputNoteState::ExpectedPartial => OutputNoteState::ExpectedPartial,
+            NativeOutputNoteState::ExpectedFull { .. } => OutputNoteState::ExpectedFull,
+            NativeOutputNoteState::CommittedPartial { .. } => OutputNoteState::CommittedPartial,
+            NativeOutputNoteState::CommittedFull { .. } => OutputNoteState::CommittedFull,
+            NativeOutputNoteState::Consumed { .. } => OutputNoteState::Consumed,
+        }
+    }
+}
+
+impl From<NativeOutputNoteState> for OutputNoteState {
+    fn from(native_state: NativeOutputNoteState) -> Self {
+        (&native_state).into()
+    }
+}
@@ -0,0 +1,50 @@
+use miden_client::sync::SyncEstimate as NativeSyncEstimate;
+use wasm_bindgen::prelude::*;
+
+#[derive(Clone, Copy)]
+#[wasm_bindgen]
+pub struct SyncEstimate(NativeSyncEstimate);
+
+#[wasm_bindgen]
+impl SyncEstimate {
+    pub fn sync_height(&self) -> u32 {
+        self.0.sync_height
+    }
+
+    pub fn chain_tip(&self) -> u32 {
+        self.0.chain_tip
+    }
+
+    pub fn block_gap(&self) -> u32 {
+        self.0.block_gap
+    }
+
+    pub fn max_iterations(&self) -> u32 {
+        self.0.max_iterations
+    }
+
+    pub fn tracked_tags(&self) -> usize {
+        self.0.tracked_tags
+    }
+
+    pub fn tracked_accounts(&self) -> usize {
+        self.0.tracked_accounts
+    }
+
+    pub fn max_response_bytes(&self) -> u64 {
+        self.0.max_response_bytes
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.0.is_synced()
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NativeSyncEstimate> for SyncEstimate {
+    fn from(native_sync_estimate: NativeSyncEstimate) -> Self {
+        SyncEstimate(native_sync_estimate)
+    }
+}
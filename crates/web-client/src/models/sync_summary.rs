@@ -35,6 +35,22 @@ impl SyncSummary {
             .map(|transaction_id| transaction_id.into())
             .collect()
     }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.0.warnings.clone()
+    }
+
+    pub fn future_note_resolutions(&self) -> Vec<String> {
+        self.0
+            .future_note_resolutions
+            .iter()
+            .map(|resolution| resolution.to_string())
+            .collect()
+    }
+
+    pub fn imported_external_notes(&self) -> Vec<NoteId> {
+        self.0.imported_external_notes.iter().map(|note_id| note_id.into()).collect()
+    }
 }
 
 // CONVERSIONS
@@ -12,6 +12,7 @@ use miden_objects::{
 use wasm_bindgen::prelude::*;
 
 use super::{
+    account_id::AccountId,
     advice_map::AdviceMap,
     note::{Note, NotesArray},
     note_details::NoteDetails,
@@ -294,11 +295,22 @@ impl TransactionRequest {
         self.0 = self.0.clone().extend_advice_map(native_advice_map);
         self
     }
+
+    pub fn with_min_note_value(mut self, faucet_id: &AccountId, min_amount: u64) -> Self {
+        self.0 = self.0.clone().with_min_note_value(faucet_id.into(), min_amount);
+        self
+    }
 }
 
 // CONVERSIONS
 // ================================================================================================
 
+impl From<NativeTransactionRequest> for TransactionRequest {
+    fn from(native_transaction_request: NativeTransactionRequest) -> Self {
+        TransactionRequest(native_transaction_request)
+    }
+}
+
 impl From<TransactionRequest> for NativeTransactionRequest {
     fn from(transaction_request: TransactionRequest) -> Self {
         transaction_request.0
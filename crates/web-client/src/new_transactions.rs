@@ -1,10 +1,10 @@
 use miden_client::{
-    notes::get_input_note_with_id_prefix,
     transactions::{
         PaymentTransactionData, SwapTransactionData,
         TransactionRequest as NativeTransactionRequest,
         TransactionResult as NativeTransactionResult,
     },
+    utils::{Deserializable, Serializable},
 };
 use miden_lib::notes::utils::build_swap_tag;
 use miden_objects::{accounts::AccountId as NativeAccountId, assets::FungibleAsset};
@@ -176,9 +176,15 @@ impl WebClient {
         if let Some(client) = self.get_mut_inner() {
             let mut result = Vec::new();
             for note_id in list_of_note_ids {
-                let note_record =
-                    get_input_note_with_id_prefix(client, &note_id).await.map_err(|err| {
+                let note_record = client
+                    .find_note_by_prefix(&note_id)
+                    .await
+                    .map_err(|err| {
                         JsValue::from_str(&format!("Failed to get input note: {}", err))
+                    })?
+                    .input_note
+                    .ok_or_else(|| {
+                        JsValue::from_str(&format!("Note {} is not an input note", note_id))
                     })?;
                 result.push(note_record.id());
             }
@@ -280,4 +286,27 @@ impl WebClient {
             Err(JsValue::from_str("Client not initialized"))
         }
     }
+
+    /// Serializes the transaction request so that it can be stored and executed later.
+    pub fn serialize_transaction_request(
+        &self,
+        transaction_request: &TransactionRequest,
+    ) -> Vec<u8> {
+        let native_transaction_request: NativeTransactionRequest = transaction_request.into();
+        native_transaction_request.to_bytes()
+    }
+
+    /// Restores a transaction request from the bytes returned by
+    /// `serialize_transaction_request`.
+    pub fn deserialize_transaction_request(
+        &self,
+        bytes: &[u8],
+    ) -> Result<TransactionRequest, JsValue> {
+        let native_transaction_request =
+            NativeTransactionRequest::read_from_bytes(bytes).map_err(|err| {
+                JsValue::from_str(&format!("Failed to deserialize transaction request: {}", err))
+            })?;
+
+        Ok(native_transaction_request.into())
+    }
 }
@@ -1,4 +1,3 @@
-use miden_client::store::OutputNoteRecord;
 use miden_objects::{
     notes::{NoteId, NoteScript as NativeNoteScript},
     Digest,
@@ -10,6 +9,7 @@ use crate::{
     models::{
         account_id::AccountId, consumable_note_record::ConsumableNoteRecord,
         input_note_record::InputNoteRecord, note_filter::NoteFilter,
+        note_state_counts::NoteStateCounts, output_note_record::OutputNoteRecord,
     },
     WebClient,
 };
@@ -48,27 +48,44 @@ impl WebClient {
         }
     }
 
-    pub async fn get_output_notes(&mut self, filter: NoteFilter) -> Result<JsValue, JsValue> {
+    pub async fn count_notes_by_state(&mut self) -> Result<NoteStateCounts, JsValue> {
         if let Some(client) = self.get_mut_inner() {
-            let notes: Vec<OutputNoteRecord> =
-                client.get_output_notes(filter.into()).await.unwrap();
-            let note_ids = notes.iter().map(|note| note.id().to_string()).collect::<Vec<String>>();
+            let counts = client.count_notes_by_state().await.map_err(|err| {
+                JsValue::from_str(&format!("Failed to count notes by state: {}", err))
+            })?;
+
+            Ok(counts.into())
+        } else {
+            Err(JsValue::from_str("Client not initialized"))
+        }
+    }
+
+    pub async fn get_output_notes(
+        &mut self,
+        filter: NoteFilter,
+    ) -> Result<Vec<OutputNoteRecord>, JsValue> {
+        if let Some(client) = self.get_mut_inner() {
+            let result = client.get_output_notes(filter.into()).await.map_err(|err| {
+                JsValue::from_str(&format!("Failed to get output notes: {}", err))
+            })?;
 
-            serde_wasm_bindgen::to_value(&note_ids).map_err(|e| JsValue::from_str(&e.to_string()))
+            Ok(result.into_iter().map(|note| note.into()).collect())
         } else {
             Err(JsValue::from_str("Client not initialized"))
         }
     }
 
-    pub async fn get_output_note(&mut self, note_id: String) -> Result<JsValue, JsValue> {
+    pub async fn get_output_note(&mut self, note_id: String) -> Result<OutputNoteRecord, JsValue> {
         if let Some(client) = self.get_mut_inner() {
             let note_id: NoteId = Digest::try_from(note_id)
                 .map_err(|err| format!("Failed to parse output note id: {}", err))?
                 .into();
-            let note: OutputNoteRecord = client.get_output_note(note_id).await.unwrap();
+            let result = client
+                .get_output_note(note_id)
+                .await
+                .map_err(|err| JsValue::from_str(&format!("Failed to get output note: {}", err)))?;
 
-            serde_wasm_bindgen::to_value(&note.id().to_string())
-                .map_err(|e| JsValue::from_str(&e.to_string()))
+            Ok(result.into())
         } else {
             Err(JsValue::from_str("Client not initialized"))
         }
@@ -87,12 +104,16 @@ impl WebClient {
     pub async fn get_consumable_notes(
         &mut self,
         account_id: Option<AccountId>,
+        include_processing: Option<bool>,
     ) -> Result<Vec<ConsumableNoteRecord>, JsValue> {
         if let Some(client) = self.get_mut_inner() {
             let native_account_id = account_id.map(|id| id.into());
-            let result = client.get_consumable_notes(native_account_id).await.map_err(|err| {
-                JsValue::from_str(&format!("Failed to get consumable notes: {}", err))
-            })?;
+            let result = client
+                .get_consumable_notes(native_account_id, include_processing.unwrap_or(false))
+                .await
+                .map_err(|err| {
+                    JsValue::from_str(&format!("Failed to get consumable notes: {}", err))
+                })?;
 
             Ok(result.into_iter().map(|record| record.into()).collect())
         } else {
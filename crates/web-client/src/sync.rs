@@ -1,6 +1,14 @@
+use core::time::Duration;
+
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::js_sys::Date;
 
-use crate::{models::sync_summary::SyncSummary, WebClient};
+use crate::{
+    models::{
+        connection_health::ConnectionHealth, sync_estimate::SyncEstimate, sync_summary::SyncSummary,
+    },
+    WebClient,
+};
 
 #[wasm_bindgen]
 impl WebClient {
@@ -16,4 +24,68 @@ impl WebClient {
             Err(JsValue::from_str("Client not initialized"))
         }
     }
+
+    pub async fn sync_state_up_to(&mut self, max_iterations: u32) -> Result<SyncSummary, JsValue> {
+        if let Some(client) = self.get_mut_inner() {
+            let sync_summary = client
+                .sync_state_up_to(max_iterations)
+                .await
+                .map_err(|err| JsValue::from_str(&format!("Failed to sync state: {}", err)))?;
+
+            Ok(sync_summary.into())
+        } else {
+            Err(JsValue::from_str("Client not initialized"))
+        }
+    }
+
+    /// Opens the store and prepares the chain data the next sync needs. Meant to be called in
+    /// the background once the application has rendered, so that neither `create_client` nor
+    /// the first sync wait for it.
+    pub async fn warmup(&mut self) -> Result<(), JsValue> {
+        let store = self.store.clone();
+        if let (Some(client), Some(store)) = (self.get_mut_inner(), store) {
+            store
+                .warmup()
+                .await
+                .map_err(|err| JsValue::from_str(&format!("Failed to open the store: {}", err)))?;
+            client
+                .warmup()
+                .await
+                .map_err(|err| JsValue::from_str(&format!("Failed to warm up: {}", err)))?;
+
+            Ok(())
+        } else {
+            Err(JsValue::from_str("Client not initialized"))
+        }
+    }
+
+    pub async fn estimate_sync_work(&mut self) -> Result<SyncEstimate, JsValue> {
+        if let Some(client) = self.get_mut_inner() {
+            let estimate = client.estimate_sync_work().await.map_err(|err| {
+                JsValue::from_str(&format!("Failed to estimate sync work: {}", err))
+            })?;
+
+            Ok(estimate.into())
+        } else {
+            Err(JsValue::from_str("Client not initialized"))
+        }
+    }
+
+    pub async fn check_connection(&mut self) -> Result<ConnectionHealth, JsValue> {
+        if let Some(client) = self.get_mut_inner() {
+            // The client can't measure time without `std`, so the latency is measured with the
+            // browser's clock and also covers the genesis check of synced clients
+            let started = Date::now();
+            let mut health = client.check_connection().await.map_err(|err| {
+                JsValue::from_str(&format!("Failed to check connection: {}", err))
+            })?;
+            if health.latency.is_none() {
+                health.latency = Some(Duration::from_secs_f64((Date::now() - started) / 1000.0));
+            }
+
+            Ok(health.into())
+        } else {
+            Err(JsValue::from_str("Client not initialized"))
+        }
+    }
 }
@@ -70,6 +70,9 @@ declare global {
     TransactionRequest: typeof TransactionRequest;
     TransactionScriptInputPair: typeof TransactionScriptInputPair;
     TransactionScriptInputPairArray: typeof TransactionScriptInputPairArray;
+    WebClient: typeof WebClient;
+    rpcUrl: string;
+    proverUrl: string | null;
     create_client: () => Promise<void>;
   }
 }
@@ -117,6 +117,9 @@ before(async () => {
       window.TransactionRequest = TransactionRequest;
       window.TransactionScriptInputPair = TransactionScriptInputPair;
       window.TransactionScriptInputPairArray = TransactionScriptInputPairArray;
+      window.WebClient = WebClient;
+      window.rpcUrl = rpc_url;
+      window.proverUrl = prover_url;
     },
     LOCAL_MIDEN_NODE_PORT,
     env.REMOTE_PROVER ? REMOTE_TX_PROVER_PORT : null
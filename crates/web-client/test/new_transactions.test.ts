@@ -405,3 +405,49 @@ describe("custom transaction tests", () => {
   //     expect(1).to.equal(1);
   // });
 });
+
+// TRANSACTION REQUEST SERIALIZATION TESTS
+// =======================================================================================================
+
+describe("transaction request serialization tests", () => {
+  it("serialized transaction request round-trips", async () => {
+    const result = await testingPage.evaluate(async () => {
+      const client = window.client;
+
+      const script = await client.compile_tx_script(
+        "begin push.0 drop end",
+        new window.TransactionScriptInputPairArray()
+      );
+      const transactionRequest = new window.TransactionRequest().with_custom_script(
+        script
+      );
+
+      const bytes = client.serialize_transaction_request(transactionRequest);
+      const restoredRequest = client.deserialize_transaction_request(bytes);
+      const restoredBytes = client.serialize_transaction_request(restoredRequest);
+
+      return {
+        bytes: Array.from(bytes),
+        restoredBytes: Array.from(restoredBytes),
+      };
+    });
+
+    expect(result.bytes.length).to.be.greaterThan(0);
+    expect(result.restoredBytes).to.deep.equal(result.bytes);
+  });
+
+  it("deserializing malformed bytes fails", async () => {
+    const errorMessage = await testingPage.evaluate(async () => {
+      const client = window.client;
+
+      try {
+        client.deserialize_transaction_request(new Uint8Array([1, 2, 3]));
+        return "";
+      } catch (error) {
+        return String(error);
+      }
+    });
+
+    expect(errorMessage).to.include("Failed to deserialize transaction request");
+  });
+});
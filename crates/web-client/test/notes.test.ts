@@ -16,6 +16,8 @@ const getInputNote = async (noteId: string) => {
     const note = await client.get_input_note(_noteId);
     return {
       noteId: note.id().to_string(),
+      stateCode: note.state_code(),
+      stateLabel: note.state_label(),
     };
   }, noteId);
 };
@@ -79,8 +81,10 @@ describe("get_input_note", () => {
   it("retrieve an input note that does exist", async () => {
     const { consumedNoteId } = await setupConsumedNote();
 
-    const { noteId } = await getInputNote(consumedNoteId);
+    const { noteId, stateCode, stateLabel } = await getInputNote(consumedNoteId);
     expect(noteId).to.equal(consumedNoteId);
+    expect(stateCode).to.equal("consumed_local");
+    expect(stateLabel).to.equal("Consumed (local)");
   });
 });
 
@@ -0,0 +1,70 @@
+import { expect } from "chai";
+import { testingPage } from "./mocha.global.setup.mjs";
+import { mintTransaction, setupWalletAndFaucet } from "./webClientTestUtils";
+
+// Time `create_client` may take on a store with history, in milliseconds. The store is no longer
+// read when the client is created, so this only covers the wasm setup.
+const CREATE_CLIENT_BUDGET_MS = 500;
+
+// WARMUP TESTS
+// =======================================================================================================
+
+interface SyncedState {
+  createClientMs: number;
+  blockNum: number;
+  accountHashes: string[];
+  noteIds: string[];
+}
+
+// Creates a new client over the store of the test client and syncs it, warming it up first if
+// requested
+export const syncNewClient = async (warmup: boolean): Promise<SyncedState> => {
+  return await testingPage.evaluate(async (_warmup) => {
+    const started = performance.now();
+    const client = new window.WebClient();
+    await client.create_client(window.rpcUrl, window.proverUrl);
+    const createClientMs = performance.now() - started;
+
+    if (_warmup) {
+      await client.warmup();
+    }
+    const summary = await client.sync_state();
+
+    const accounts = await client.get_accounts();
+    const notes = await client.get_input_notes(
+      new window.NoteFilter(window.NoteFilterTypes.All)
+    );
+
+    return {
+      createClientMs: createClientMs,
+      blockNum: summary.block_num(),
+      accountHashes: accounts.map((account) => account.hash().to_hex()).sort(),
+      noteIds: notes.map((note) => note.id().to_string()).sort(),
+    };
+  }, warmup);
+};
+
+describe("warmup tests", () => {
+  it("creates clients within budget and syncs them like the eager path", async () => {
+    const { accountId, faucetId } = await setupWalletAndFaucet();
+    await mintTransaction(accountId, faucetId);
+
+    const lazy = await syncNewClient(false);
+    const warmedUp = await syncNewClient(true);
+
+    expect(lazy.createClientMs).to.be.below(CREATE_CLIENT_BUDGET_MS);
+    expect(warmedUp.createClientMs).to.be.below(CREATE_CLIENT_BUDGET_MS);
+    expect(warmedUp.blockNum).to.be.at.least(lazy.blockNum);
+    expect(warmedUp.accountHashes).to.deep.equal(lazy.accountHashes);
+    expect(warmedUp.noteIds).to.deep.equal(lazy.noteIds);
+  });
+
+  it("fails to warm up an uninitialized client", async () => {
+    const result = testingPage.evaluate(async () => {
+      const client = new window.WebClient();
+      await client.warmup();
+    });
+
+    await expect(result).to.be.rejectedWith("Client not initialized");
+  });
+});
@@ -273,7 +273,7 @@ pub async fn mint_note(
     println!("Fetching Committed Notes...");
     let note_id = tx_request.expected_output_notes().next().unwrap().id();
     let note = client.get_input_note(note_id).await.unwrap();
-    note.try_into().unwrap()
+    note.as_authenticated_input_note().unwrap()
 }
 
 /// Consumes and wait until the transaction gets committed
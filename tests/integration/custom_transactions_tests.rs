@@ -1,7 +1,7 @@
 use miden_client::{
     accounts::AccountTemplate,
     notes::NoteExecutionHint,
-    transactions::TransactionRequest,
+    transactions::{ConsumabilityVerdict, TransactionRequest},
     utils::{Deserializable, Serializable},
     ZERO,
 };
@@ -232,6 +232,53 @@ async fn test_merkle_store() {
     client.sync_state().await.unwrap();
 }
 
+#[tokio::test]
+async fn test_can_consume_open_note() {
+    let mut client = create_test_client().await;
+    wait_for_node(&mut client).await;
+
+    let (first_account, second_account, faucet_account) =
+        setup(&mut client, AccountStorageMode::Private).await;
+
+    // A note that any account can consume. Its tag is derived from the first account so that the
+    // client receives its inclusion, but it doesn't restrict who can consume it
+    let mut random_coin = RpoRandomCoin::new(Default::default());
+    let tag = NoteTag::from_account_id(first_account.id(), NoteExecutionMode::Local).unwrap();
+    let open_note = create_open_note(&client, faucet_account.id(), tag, &mut random_coin);
+    let transaction_request = TransactionRequest::new()
+        .with_own_output_notes(vec![OutputNote::Full(open_note.clone())])
+        .unwrap();
+    execute_tx_and_sync(&mut client, faucet_account.id(), transaction_request).await;
+
+    assert_eq!(
+        client.can_consume(open_note.id(), second_account.id()).await.unwrap(),
+        ConsumabilityVerdict::Consumable
+    );
+
+    // A P2ID note can only be consumed by its target
+    let p2id_note =
+        mint_note(&mut client, first_account.id(), faucet_account.id(), NoteType::Private).await;
+    assert!(matches!(
+        client.can_consume(p2id_note.id(), second_account.id()).await.unwrap(),
+        ConsumabilityVerdict::NotConsumable(_)
+    ));
+    assert!(client
+        .can_consume(p2id_note.id(), first_account.id())
+        .await
+        .unwrap()
+        .is_consumable());
+
+    let transaction_request = TransactionRequest::consume_notes(vec![open_note.id()]);
+    execute_tx_and_sync(&mut client, second_account.id(), transaction_request).await;
+    assert_account_has_single_asset(&client, second_account.id(), faucet_account.id(), 10).await;
+
+    // Once consumed, the note can't be consumed by any account
+    assert!(matches!(
+        client.can_consume(open_note.id(), first_account.id()).await.unwrap(),
+        ConsumabilityVerdict::NotConsumable(_)
+    ));
+}
+
 async fn mint_custom_note(
     client: &mut TestClient,
     faucet_account_id: AccountId,
@@ -281,3 +328,26 @@ fn create_custom_note(
     let note_recipient = NoteRecipient::new(serial_num, note_script, inputs);
     Note::new(note_assets, note_metadata, note_recipient)
 }
+
+fn create_open_note(
+    client: &TestClient,
+    faucet_account_id: AccountId,
+    tag: NoteTag,
+    rng: &mut RpoRandomCoin,
+) -> Note {
+    let note_script = client.compile_note_script(include_str!("asm/open_note.masm")).unwrap();
+
+    let serial_num = rng.draw_word();
+    let note_metadata = NoteMetadata::new(
+        faucet_account_id,
+        NoteType::Private,
+        tag,
+        NoteExecutionHint::None,
+        Default::default(),
+    )
+    .unwrap();
+    let note_assets =
+        NoteAssets::new(vec![FungibleAsset::new(faucet_account_id, 10).unwrap().into()]).unwrap();
+    let note_recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::default());
+    Note::new(note_assets, note_metadata, note_recipient)
+}
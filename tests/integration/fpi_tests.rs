@@ -1,5 +1,6 @@
 use miden_client::{
     accounts::{Account, AccountData, AccountTemplate, StorageSlot},
+    rpc::AccountStorageSlotData,
     testing::prepare_word,
     transactions::{TransactionKernel, TransactionRequest},
     Felt, Word,
@@ -30,11 +31,14 @@ async fn test_fpi() {
     let foreign_account_id = foreign_account.id();
 
     client
-        .import_account(AccountData::new(
-            foreign_account,
-            Some(foreign_seed),
-            AuthSecretKey::RpoFalcon512(secret_key.clone()),
-        ))
+        .import_account(
+            AccountData::new(
+                foreign_account,
+                Some(foreign_seed),
+                AuthSecretKey::RpoFalcon512(secret_key.clone()),
+            ),
+            false,
+        )
         .await
         .unwrap();
 
@@ -60,6 +64,9 @@ async fn test_fpi() {
     client.submit_transaction(tx).await.unwrap();
     wait_for_tx(&mut client, tx_id).await;
 
+    let storage_slot = client.fetch_account_storage_slot(foreign_account_id, 0, &[]).await.unwrap();
+    assert_eq!(storage_slot, AccountStorageSlotData::Value(FPI_STORAGE_VALUE));
+
     println!("Calling FPI functions with new account");
 
     let (native_account, _native_seed) = client
@@ -111,6 +118,14 @@ async fn test_fpi() {
         .unwrap();
 
     client.submit_transaction(tx_result).await.unwrap();
+
+    // The same procedure can be called without writing the script
+    let outputs = client
+        .call_view_function(foreign_account_id, proc_root, vec![], true)
+        .await
+        .unwrap();
+    let expected_value: Vec<Felt> = FPI_STORAGE_VALUE.iter().rev().copied().collect();
+    assert_eq!(outputs[..4], expected_value[..]);
 }
 
 /// Builds an account using the auth component and a custom component which just retrieves the
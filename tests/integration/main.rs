@@ -8,8 +8,10 @@ use miden_client::{
     },
     sync::NoteTagSource,
     transactions::{
-        PaymentTransactionData, TransactionExecutorError, TransactionRequest, TransactionStatus,
+        InputNoteSpec, PaymentTransactionData, StatelessTransactionInputs,
+        TransactionExecutorError, TransactionRequest, TransactionStatus,
     },
+    utils::{Deserializable, Serializable},
     ClientError,
 };
 use miden_objects::{
@@ -230,6 +232,70 @@ async fn test_p2id_transfer() {
     assert_note_cannot_be_consumed_twice(&mut client, to_account_id, notes[0].id()).await;
 }
 
+#[tokio::test]
+async fn test_p2id_transfer_stateless() {
+    let mut client = create_test_client().await;
+    wait_for_node(&mut client).await;
+
+    let (first_regular_account, second_regular_account, faucet_account_header) =
+        setup(&mut client, AccountStorageMode::Private).await;
+
+    let from_account_id = first_regular_account.id();
+    let to_account_id = second_regular_account.id();
+    let faucet_account_id = faucet_account_header.id();
+
+    let note = mint_note(&mut client, from_account_id, faucet_account_id, NoteType::Private).await;
+    consume_notes(&mut client, from_account_id, &[note]).await;
+    assert_account_has_single_asset(&client, from_account_id, faucet_account_id, MINT_AMOUNT).await;
+
+    // Export the sender's state and run the transfer on a client that doesn't track it
+    let inputs = client.export_stateless_inputs(from_account_id, &[]).await.unwrap();
+    let inputs = StatelessTransactionInputs::read_from_bytes(&inputs.to_bytes()).unwrap();
+
+    let mut stateless_client = create_test_client().await;
+    let asset = FungibleAsset::new(faucet_account_id, TRANSFER_AMOUNT).unwrap();
+    let tx_request = TransactionRequest::pay_to_id(
+        PaymentTransactionData::new(vec![Asset::Fungible(asset)], from_account_id, to_account_id),
+        None,
+        NoteType::Public,
+        stateless_client.rng(),
+    )
+    .unwrap();
+
+    println!("Running stateless P2ID tx...");
+    let tx_result = stateless_client
+        .execute_transaction_stateless(inputs, tx_request)
+        .await
+        .unwrap();
+    let proven_transaction =
+        stateless_client.prove_transaction_stateless(&tx_result).await.unwrap();
+    stateless_client
+        .testing_submit_proven_transaction(proven_transaction)
+        .await
+        .unwrap();
+
+    assert!(stateless_client.get_account_headers().await.unwrap().is_empty());
+    assert!(stateless_client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // The receiver, tracked by the original client, gets the note once it's committed
+    let note_id = loop {
+        wait_for_blocks(&mut client, 1).await;
+        let notes = client.get_input_notes(NoteFilter::Committed).await.unwrap();
+        if let Some(note) = notes.first() {
+            break note.id();
+        }
+    };
+
+    let tx_request = TransactionRequest::consume_notes(vec![note_id]);
+    execute_tx_and_sync(&mut client, to_account_id, tx_request).await;
+    assert_account_has_single_asset(&client, to_account_id, faucet_account_id, TRANSFER_AMOUNT)
+        .await;
+}
+
 #[tokio::test]
 async fn test_p2id_transfer_failing_not_enough_balance() {
     let mut client = create_test_client().await;
@@ -306,6 +372,11 @@ async fn test_p2idr_transfer_consumed_by_target() {
     } else {
         panic!("Note should be consumed");
     }
+    assert_eq!(
+        client.get_note_consumer(note.id()).await.unwrap().as_ref(),
+        input_note.consumer_transaction_id()
+    );
+    assert!(input_note.consumer_transaction_id().is_some());
 
     // Do a transfer from first account to second account with Recall. In this situation we'll do
     // the happy path where the `to_account_id` consumes the note
@@ -476,20 +547,28 @@ async fn test_get_consumable_notes() {
     let faucet_account_id = faucet_account_header.id();
 
     //No consumable notes initially
-    assert!(client.get_consumable_notes(None).await.unwrap().is_empty());
+    assert!(client.get_consumable_notes(None, false).await.unwrap().is_empty());
 
     // First Mint necesary token
     let note = mint_note(&mut client, from_account_id, faucet_account_id, NoteType::Private).await;
 
     // Check that note is consumable by the account that minted
-    assert!(!client.get_consumable_notes(None).await.unwrap().is_empty());
-    assert!(!client.get_consumable_notes(Some(from_account_id)).await.unwrap().is_empty());
-    assert!(client.get_consumable_notes(Some(to_account_id)).await.unwrap().is_empty());
+    assert!(!client.get_consumable_notes(None, false).await.unwrap().is_empty());
+    assert!(!client
+        .get_consumable_notes(Some(from_account_id), false)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(client
+        .get_consumable_notes(Some(to_account_id), false)
+        .await
+        .unwrap()
+        .is_empty());
 
     consume_notes(&mut client, from_account_id, &[note]).await;
 
     //After consuming there are no more consumable notes
-    assert!(client.get_consumable_notes(None).await.unwrap().is_empty());
+    assert!(client.get_consumable_notes(None, false).await.unwrap().is_empty());
 
     // Do a transfer from first account to second account
     let asset = FungibleAsset::new(faucet_account_id, TRANSFER_AMOUNT).unwrap();
@@ -504,11 +583,19 @@ async fn test_get_consumable_notes() {
     execute_tx_and_sync(&mut client, from_account_id, tx_request).await;
 
     // Check that note is consumable by both accounts
-    let consumable_notes = client.get_consumable_notes(None).await.unwrap();
+    let consumable_notes = client.get_consumable_notes(None, false).await.unwrap();
     let relevant_accounts = &consumable_notes.first().unwrap().1;
     assert_eq!(relevant_accounts.len(), 2);
-    assert!(!client.get_consumable_notes(Some(from_account_id)).await.unwrap().is_empty());
-    assert!(!client.get_consumable_notes(Some(to_account_id)).await.unwrap().is_empty());
+    assert!(!client
+        .get_consumable_notes(Some(from_account_id), false)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(!client
+        .get_consumable_notes(Some(to_account_id), false)
+        .await
+        .unwrap()
+        .is_empty());
 
     // Check that the note is only consumable after block 100 for the account that sent the
     // transaction
@@ -629,7 +716,8 @@ async fn test_import_expected_notes() {
     assert!(input_note.inclusion_proof().is_some());
 
     // If client 2 succesfully consumes the note, we confirm we have MMR and block header data
-    consume_notes(&mut client_2, client_2_account.id(), &[input_note.try_into().unwrap()]).await;
+    consume_notes(&mut client_2, client_2_account.id(), &[input_note.as_input_note().unwrap()])
+        .await;
 
     let tx_request = TransactionRequest::mint_fungible_asset(
         FungibleAsset::new(faucet_account.id(), MINT_AMOUNT).unwrap(),
@@ -664,7 +752,8 @@ async fn test_import_expected_notes() {
     assert!(input_note.inclusion_proof().is_some());
 
     // If inclusion proof is invalid this should panic
-    consume_notes(&mut client_1, first_basic_account.id(), &[input_note.try_into().unwrap()]).await;
+    consume_notes(&mut client_1, first_basic_account.id(), &[input_note.as_input_note().unwrap()])
+        .await;
 }
 
 #[tokio::test]
@@ -1041,16 +1130,16 @@ async fn test_consume_multiple_expected_notes() {
         expected_notes.partition(|note| client_notes_ids.contains(&note.id()));
 
     // Create and execute transactions
-    let tx_request_1 = TransactionRequest::consume_notes(
-        client_owned_notes.iter().map(|note| note.id()).collect(),
-    )
-    .with_authenticated_input_notes(client_owned_notes.iter().map(|note| (note.id(), None)));
+    let tx_request_1 = TransactionRequest::new().with_input_notes_mixed(
+        client_owned_notes
+            .iter()
+            .map(|note| (InputNoteSpec::Authenticated(note.id()), None)),
+    );
 
-    let tx_request_2 = TransactionRequest::consume_notes(
-        unauth_owned_notes.iter().map(|note| note.id()).collect(),
-    )
-    .with_unauthenticated_input_notes(
-        unauth_owned_notes.iter().map(|note| ((*note).clone(), None)),
+    let tx_request_2 = TransactionRequest::new().with_input_notes_mixed(
+        unauth_owned_notes
+            .iter()
+            .map(|note| (InputNoteSpec::Unauthenticated((*note).clone()), None)),
     );
 
     let tx_id_1 = execute_tx(&mut client, to_account_ids[0], tx_request_1).await;
@@ -1145,7 +1234,7 @@ async fn test_import_consumed_note_with_proof() {
     // Import the consumed note
     client_2
         .import_note(NoteFile::NoteWithProof(
-            note.clone().try_into().unwrap(),
+            note.to_note().unwrap(),
             note.inclusion_proof().unwrap().clone(),
         ))
         .await
@@ -1,6 +1,6 @@
 use miden_client::{
     accounts::AccountTemplate,
-    notes::Note,
+    notes::{FutureNoteResolution, Note},
     transactions::{SwapTransactionData, TransactionRequest},
 };
 use miden_objects::{
@@ -92,7 +92,7 @@ async fn test_swap_fully_onchain() {
     // Sync and consume note for accountA
     client1.sync_state().await.unwrap();
     let client_1_consumable_notes =
-        client1.get_consumable_notes(Some(account_a.id())).await.unwrap();
+        client1.get_consumable_notes(Some(account_a.id()), false).await.unwrap();
     assert!(client_1_consumable_notes
         .iter()
         .any(|(note, _)| note.id() == account_a_mint_note_id));
@@ -105,7 +105,7 @@ async fn test_swap_fully_onchain() {
     // Sync and consume note for accountB
     client2.sync_state().await.unwrap();
     let client_2_consumable_notes =
-        client2.get_consumable_notes(Some(account_b.id())).await.unwrap();
+        client2.get_consumable_notes(Some(account_b.id()), false).await.unwrap();
     assert!(client_2_consumable_notes
         .iter()
         .any(|(note, _)| note.id() == account_b_mint_note_id));
@@ -162,9 +162,17 @@ async fn test_swap_fully_onchain() {
 
     // sync on client 1, we should get the missing payback note details.
     // try consuming the received note with accountA, it should now have 25 ETH
-    client1.sync_state().await.unwrap();
+    let sync_summary = client1.sync_state().await.unwrap();
     println!("Consuming swap payback note on first client...");
 
+    // the swap note was consumed by another account, so its payback is still expected
+    assert!(sync_summary.future_note_resolutions.iter().any(|resolution| matches!(
+        resolution,
+        FutureNoteResolution::Promoted { source_note_id, future_note_id, .. }
+            if *source_note_id == expected_output_notes[0].id()
+                && *future_note_id == expected_payback_note_details[0].id()
+    )));
+
     let tx_request = TransactionRequest::consume_notes(vec![expected_payback_note_details[0].id()]);
     execute_tx_and_sync(&mut client1, account_a.id(), tx_request).await;
 
@@ -305,7 +313,7 @@ async fn test_swap_offchain() {
     // Sync and consume note for accountA
     client1.sync_state().await.unwrap();
     let client_1_consumable_notes =
-        client1.get_consumable_notes(Some(account_a.id())).await.unwrap();
+        client1.get_consumable_notes(Some(account_a.id()), false).await.unwrap();
     assert!(client_1_consumable_notes
         .iter()
         .any(|(note, _)| note.id() == account_a_mint_note_id));
@@ -318,7 +326,7 @@ async fn test_swap_offchain() {
     // Sync and consume note for accountB
     client2.sync_state().await.unwrap();
     let client_2_consumable_notes =
-        client2.get_consumable_notes(Some(account_b.id())).await.unwrap();
+        client2.get_consumable_notes(Some(account_b.id()), false).await.unwrap();
     assert!(client_2_consumable_notes
         .iter()
         .any(|(note, _)| note.id() == account_b_mint_note_id));